@@ -47,3 +47,117 @@ mod gen_u32 {
     def_bench!(xorshift, u32, XorShiftRng::from_entropy());
     def_bench!(sfmt, u32, SFMT::from_entropy());
 }
+
+macro_rules! def_bench_gen_all {
+    ($name:ident, $gen_all:ident, $rng:ty) => {
+        #[bench]
+        fn $name(b: &mut Bencher) {
+            let mut rng = <$rng>::seed_from_u64(0);
+            b.iter(|| rng.$gen_all());
+        }
+    };
+} // def_bench_gen_all!
+
+/// Refill of the whole state by the 128-bit (SSE2) kernel and the AVX2 kernel
+///
+/// The kernels are exposed only with the `bench` feature, e.g. `cargo bench --features bench`.
+/// The AVX2 benchmarks are skipped if AVX2 is not available.
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "bench"))]
+mod gen_all {
+    use super::*;
+    use sfmt::{SFMT19937, SFMT216091, SFMT607};
+
+    macro_rules! def_bench_gen_all_avx2 {
+        ($name:ident, $rng:ty) => {
+            #[bench]
+            fn $name(b: &mut Bencher) {
+                if !is_x86_feature_detected!("avx2") {
+                    return;
+                }
+                let mut rng = <$rng>::seed_from_u64(0);
+                b.iter(|| rng.bench_gen_all_avx2());
+            }
+        };
+    } // def_bench_gen_all_avx2!
+
+    def_bench_gen_all!(sse2_607, bench_gen_all_128, SFMT607);
+    def_bench_gen_all_avx2!(avx2_607, SFMT607);
+    def_bench_gen_all!(sse2_19937, bench_gen_all_128, SFMT19937);
+    def_bench_gen_all_avx2!(avx2_19937, SFMT19937);
+    def_bench_gen_all!(sse2_216091, bench_gen_all_128, SFMT216091);
+    def_bench_gen_all_avx2!(avx2_216091, SFMT216091);
+}
+
+/// Jump ahead with the precomputed polynomial
+mod jump {
+    use super::*;
+    use sfmt::{SFMT19937, SFMT607};
+    def_bench_gen_all!(jump_2_64_607, jump_2_64, SFMT607);
+    def_bench_gen_all!(jump_2_64_19937, jump_2_64, SFMT19937);
+}
+
+/// Fill 10000 integers directly by the recursion, compared with `next_u32` one at a time
+mod fill_array {
+    use super::*;
+    use sfmt::SFMT19937;
+
+    #[bench]
+    fn fill_array32(b: &mut Bencher) {
+        let mut rng = SFMT19937::seed_from_u64(0);
+        let mut array = vec![0_u32; 10000];
+        b.iter(|| rng.fill_array32(&mut array));
+    }
+
+    #[bench]
+    fn next_u32(b: &mut Bencher) {
+        let mut rng = SFMT19937::seed_from_u64(0);
+        let mut array = vec![0_u32; 10000];
+        b.iter(|| {
+            for val in array.iter_mut() {
+                *val = rng.next_u32();
+            }
+        });
+    }
+}
+
+/// Fill 1 MiB of bytes
+mod fill_bytes {
+    use super::*;
+
+    #[bench]
+    fn xorshift(b: &mut Bencher) {
+        let mut rng = XorShiftRng::from_entropy();
+        let mut bytes = vec![0_u8; 1 << 20];
+        b.iter(|| rng.fill_bytes(&mut bytes));
+    }
+
+    #[bench]
+    fn sfmt(b: &mut Bencher) {
+        let mut rng = SFMT::from_entropy();
+        let mut bytes = vec![0_u8; 1 << 20];
+        b.iter(|| rng.fill_bytes(&mut bytes));
+    }
+}
+
+/// Fill 10000 floating point numbers in `[0, 1)`
+mod fill_f64 {
+    use super::*;
+
+    #[bench]
+    fn fill_f64_co(b: &mut Bencher) {
+        let mut rng = SFMT::from_entropy();
+        let mut dest = vec![0.0; 10000];
+        b.iter(|| rng.fill_f64_co(&mut dest));
+    }
+
+    #[bench]
+    fn gen_f64(b: &mut Bencher) {
+        let mut rng = SFMT::from_entropy();
+        let mut dest = vec![0.0; 10000];
+        b.iter(|| {
+            for x in dest.iter_mut() {
+                *x = rng.gen::<f64>();
+            }
+        });
+    }
+}
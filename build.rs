@@ -0,0 +1,31 @@
+//! Enable the SIMD backends whose intrinsics are stable on the running rustc
+//!
+//! NEON intrinsics on aarch64 are stabilized in 1.59, and simd128 intrinsics on wasm32 in 1.54.
+//! Older compilers use the scalar backend on these targets.
+
+use std::{env, process::Command};
+
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = env::var_os("RUSTC")?;
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = String::from_utf8(output.stdout).ok()?;
+    // e.g. "rustc 1.53.0 (53cb7b09b 2021-06-17)"
+    let mut pieces = version.split_whitespace().nth(1)?.split('.');
+    if pieces.next()? != "1" {
+        return None;
+    }
+    pieces.next()?.parse().ok()
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(sfmt_neon)");
+    println!("cargo:rustc-check-cfg=cfg(sfmt_simd128)");
+    let minor = rustc_minor_version().unwrap_or(0);
+    if minor >= 59 {
+        println!("cargo:rustc-cfg=sfmt_neon");
+    }
+    if minor >= 54 {
+        println!("cargo:rustc-cfg=sfmt_simd128");
+    }
+}
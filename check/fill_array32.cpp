@@ -0,0 +1,34 @@
+/**
+ * Generate u32 random integers by sfmt_fill_array32 of original SFMT implementation
+ *
+ * - Seed is fixed value (seed=1234)
+ * - Fill 3 * SFMT_N32 + 4 integers, and then SFMT_N32 + 4 integers by sfmt_fill_array32
+ * - Generate 100 integers by sfmt_genrand_uint32 following them
+ * - `SFMT_MEXP` will be set as a compiler flag. See Makefile.
+ */
+#include "./SFMT-src-1.5.1/SFMT.h"
+#include <iostream>
+#include <stdlib.h>
+
+static void fill(sfmt_t *sfmt, int size) {
+  // The array must be aligned to 16 bytes for SIMD
+  uint32_t *array = (uint32_t *)aligned_alloc(16, sizeof(uint32_t) * size);
+  sfmt_fill_array32(sfmt, array, size);
+  for (int i = 0; i < size; i++) {
+    std::cout << array[i] << "\n";
+  }
+  free(array);
+}
+
+int main(int argc, char *argv[]) {
+  sfmt_t sfmt;
+  sfmt_init_gen_rand(&sfmt, 1234);
+  fill(&sfmt, 3 * SFMT_N32 + 4);
+  fill(&sfmt, SFMT_N32 + 4);
+  for (int i = 0; i < 100; i++) {
+    uint32_t x = sfmt_genrand_uint32(&sfmt);
+    std::cout << x << "\n";
+  }
+  std::cout << std::flush;
+  return 0;
+}
@@ -0,0 +1,35 @@
+/**
+ * Generate u64 random integers by sfmt_fill_array64 of original SFMT implementation initialized by an array
+ *
+ * - Key is fixed value (key={0x1234, 0x5678, 0x9abc, 0xdef0}) as test.c of SFMT
+ * - Fill 3 * SFMT_N64 + 2 integers, and then SFMT_N64 + 2 integers by sfmt_fill_array64
+ * - Generate 100 integers by sfmt_genrand_uint64 following them
+ * - `SFMT_MEXP` will be set as a compiler flag. See Makefile.
+ */
+#include "./SFMT-src-1.5.1/SFMT.h"
+#include <iostream>
+#include <stdlib.h>
+
+static void fill(sfmt_t *sfmt, int size) {
+  // The array must be aligned to 16 bytes for SIMD
+  uint64_t *array = (uint64_t *)aligned_alloc(16, sizeof(uint64_t) * size);
+  sfmt_fill_array64(sfmt, array, size);
+  for (int i = 0; i < size; i++) {
+    std::cout << array[i] << "\n";
+  }
+  free(array);
+}
+
+int main(int argc, char *argv[]) {
+  sfmt_t sfmt;
+  uint32_t key[] = {0x1234, 0x5678, 0x9abc, 0xdef0};
+  sfmt_init_by_array(&sfmt, key, 4);
+  fill(&sfmt, 3 * SFMT_N64 + 2);
+  fill(&sfmt, SFMT_N64 + 2);
+  for (int i = 0; i < 100; i++) {
+    uint64_t x = sfmt_genrand_uint64(&sfmt);
+    std::cout << x << "\n";
+  }
+  std::cout << std::flush;
+  return 0;
+}
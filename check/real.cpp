@@ -0,0 +1,35 @@
+/**
+ * Generate floating point numbers using original SFMT implementation
+ *
+ * - Seed is fixed value (seed=1234)
+ * - Generate 200 numbers for each of sfmt_genrand_real1, sfmt_genrand_real2, sfmt_genrand_real3,
+ *   sfmt_genrand_res53, and sfmt_genrand_res53_mix in this order
+ * - Numbers are printed with 17 significant digits to be read exactly
+ * - `SFMT_MEXP` will be set as a compiler flag. See Makefile.
+ */
+#include "./SFMT-src-1.5.1/SFMT.h"
+#include <iomanip>
+#include <iostream>
+
+int main(int argc, char *argv[]) {
+  sfmt_t sfmt;
+  sfmt_init_gen_rand(&sfmt, 1234);
+  std::cout << std::setprecision(17);
+  for (int i = 0; i < 200; i++) {
+    std::cout << sfmt_genrand_real1(&sfmt) << "\n";
+  }
+  for (int i = 0; i < 200; i++) {
+    std::cout << sfmt_genrand_real2(&sfmt) << "\n";
+  }
+  for (int i = 0; i < 200; i++) {
+    std::cout << sfmt_genrand_real3(&sfmt) << "\n";
+  }
+  for (int i = 0; i < 200; i++) {
+    std::cout << sfmt_genrand_res53(&sfmt) << "\n";
+  }
+  for (int i = 0; i < 200; i++) {
+    std::cout << sfmt_genrand_res53_mix(&sfmt) << "\n";
+  }
+  std::cout << std::flush;
+  return 0;
+}
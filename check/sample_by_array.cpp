@@ -0,0 +1,21 @@
+/**
+ * Generate u64 random integers using original SFMT implementation initialized by an array
+ *
+ * - Key is fixed value (key={0x1234, 0x5678, 0x9abc, 0xdef0}) as test.c of SFMT
+ * - Generate 10000 integers
+ * - `SFMT_MEXP` will be set as a compiler flag. See Makefile.
+ */
+#include "./SFMT-src-1.5.1/SFMT.h"
+#include <iostream>
+
+int main(int argc, char *argv[]) {
+  sfmt_t sfmt;
+  uint32_t key[] = {0x1234, 0x5678, 0x9abc, 0xdef0};
+  sfmt_init_by_array(&sfmt, key, 4);
+  for (int i = 0; i < 10000; i++) {
+    uint64_t x = sfmt_genrand_uint64(&sfmt);
+    std::cout << x << "\n";
+  }
+  std::cout << std::flush;
+  return 0;
+}
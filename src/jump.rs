@@ -0,0 +1,509 @@
+//! Jump ahead by polynomial arithmetic over GF(2)
+//!
+//! The recursion `r` generating a 128-bit word from the last `N = MEXP_N` words is linear over GF(2),
+//! and `r^k = q(r)` holds for `q(x) = x^k mod p(x)` where `p` is the minimal polynomial of `r`.
+//! The regeneration of the whole state is `r^N`, and `q(r)` is applied by stepping one word
+//! for each coefficient of `q` as SFMT-jump of the original authors.
+//! The minimal polynomials are precomputed in `jump/minpoly.{MEXP}.rs`,
+//! and the jump polynomials for `2^k` steps in `jump/jump_2_{k}.{MEXP}.rs`
+//! as the words of [Poly::words] embedded in the binary. They are checked by the tests.
+
+use crate::{
+    packed::*,
+    paramed::SFMT,
+    poly::{berlekamp_massey, Poly},
+    sfmt::{SfmtParams, SFMTMEXP},
+};
+use alloc::{vec, vec::Vec};
+
+impl<const MEXP: usize, const MEXP_N: usize> SFMT<MEXP, MEXP_N>
+where
+    SFMTMEXP<MEXP, MEXP_N>: SfmtParams<MEXP, MEXP_N>,
+{
+    /// Jump ahead by `steps` regenerations of the state
+    ///
+    /// This skips `steps * N32` 32-bit outputs where `N32 = 4 * MEXP_N`,
+    /// and the position in the current state is kept.
+    /// It computes `x^(steps * MEXP_N)` modulo the minimal polynomial of the recursion,
+    /// and takes `O(MEXP^2)` time, e.g. seconds for [crate::SFMT216091].
+    ///
+    /// ```
+    /// use rand_core::RngCore;
+    /// let mut rng = sfmt::SFMT607::new(1234);
+    /// let mut jumped = rng.clone();
+    /// jumped.jump(3);
+    /// for _ in 0..(3 * 4 * 5) {
+    ///     rng.next_u32();
+    /// }
+    /// assert_eq!(rng.next_u32(), jumped.next_u32());
+    /// ```
+    pub fn jump(&mut self, steps: u128) {
+        let p = Self::precomputed_minimal_polynomial();
+        let q = p.pow_x_mod(steps).pow_mod(MEXP_N as u64, &p);
+        self.jump_by_polynomial(q.words());
+        self.count_blocks(steps);
+    }
+
+    /// Jump ahead by `2^64` regenerations of the state with a precomputed polynomial
+    ///
+    /// This is equivalent to `jump(1 << 64)` without polynomial arithmetic.
+    /// It steps the recursion `MEXP` times and adds up the state for about half of them,
+    /// i.e. `O(MEXP^2 / 256)` operations on 128-bit words,
+    /// e.g. about a millisecond for [crate::SFMT19937] and 0.1 second for [crate::SFMT216091].
+    pub fn jump_2_64(&mut self) {
+        self.jump_by_polynomial(SFMTMEXP::<MEXP, MEXP_N>::JUMP_2_64);
+        self.count_blocks(1 << 64);
+    }
+
+    /// Jump ahead by `2^128` regenerations of the state with a precomputed polynomial
+    ///
+    /// See [SFMT::jump_2_64].
+    pub fn jump_2_128(&mut self) {
+        self.jump_by_polynomial(SFMTMEXP::<MEXP, MEXP_N>::JUMP_2_128);
+        self.stream = self.stream.wrapping_add(1);
+    }
+
+    /// Jump ahead by `2^256` regenerations of the state with a precomputed polynomial
+    ///
+    /// See [SFMT::jump_2_64].
+    pub fn jump_2_256(&mut self) {
+        self.jump_by_polynomial(SFMTMEXP::<MEXP, MEXP_N>::JUMP_2_256);
+        // The stream index overflows, and the state is no longer reproducible from the seed
+        self.seed = None;
+    }
+
+    /// Independent streams from a seed, spaced by `2^128` regenerations of the state
+    ///
+    /// The stream `i` starts from [SFMT::new_u64] with the seed jumped by `i * 2^128` regenerations,
+    /// and it is the same as [SFMT::stream] with the index `i`.
+    /// The streams do not overlap unless more than `2^128 * N32` outputs are taken from one of them.
+    /// Each stream after the first takes as long as [SFMT::jump_2_128].
+    ///
+    /// ```
+    /// use rand_core::RngCore;
+    /// let mut streams = sfmt::SFMT19937::streams(42, 2);
+    /// let mut second = sfmt::SFMT19937::stream(42, 1);
+    /// assert_eq!(streams[1].next_u32(), second.next_u32());
+    /// ```
+    pub fn streams(seed: u64, n: usize) -> Vec<Self> {
+        let mut streams = Vec::with_capacity(n);
+        let mut sfmt = Self::new_u64(seed);
+        for i in 0..n {
+            if i > 0 {
+                sfmt.jump_by_polynomial(SFMTMEXP::<MEXP, MEXP_N>::JUMP_2_128);
+                sfmt.stream += 1;
+            }
+            streams.push(sfmt.clone());
+        }
+        streams
+    }
+
+    /// The stream `index` of [SFMT::streams] for the seed
+    ///
+    /// This computes `x^(index * 2^128 * MEXP_N)` modulo the minimal polynomial of the recursion,
+    /// and takes `O(log(index) * MEXP^2)` time.
+    pub fn stream(seed: u64, index: u64) -> Self {
+        let mut sfmt = Self::new_u64(seed);
+        sfmt.jump_streams(index);
+        sfmt
+    }
+
+    /// Jump ahead by `n * 2^128` regenerations of the state, i.e. to the `n`-th next stream
+    pub(crate) fn jump_streams(&mut self, n: u64) {
+        let q = Poly::from_words(SFMTMEXP::<MEXP, MEXP_N>::JUMP_2_128.to_vec())
+            .pow_mod(n, &Self::precomputed_minimal_polynomial());
+        self.jump_by_polynomial(q.words());
+        self.stream = self.stream.wrapping_add(n);
+    }
+
+    /// The 32-bit output at `index` of [SFMT::new_u64] with the seed
+    ///
+    /// This is the value returned by [rand_core::RngCore::next_u32] after skipping `index` outputs.
+    /// The generator is jumped to the block containing the output,
+    /// and this takes `O(MEXP^2)` time regardless of `index`.
+    ///
+    /// ```
+    /// use rand_core::RngCore;
+    /// let mut rng = sfmt::SFMT607::new_u64(42);
+    /// rng.discard(1000);
+    /// assert_eq!(sfmt::SFMT607::value_at(42, 1000), rng.next_u32());
+    /// ```
+    pub fn value_at(seed: u64, index: u128) -> u32 {
+        let n32 = SFMTMEXP::<MEXP, MEXP_N>::SFMT_N32 as u128;
+        let sfmt = Self::block_at(seed, index / n32);
+        as_u32_slice(&sfmt.state)[(index % n32) as usize]
+    }
+
+    /// The 64-bit output at `index` of [SFMT::new_u64] with the seed
+    ///
+    /// This is the value returned by [rand_core::RngCore::next_u64] after skipping `index` 64-bit outputs,
+    /// i.e. the 32-bit outputs at `2 * index` and `2 * index + 1`. See [SFMT::value_at].
+    ///
+    /// ```
+    /// use rand_core::RngCore;
+    /// let mut rng = sfmt::SFMT607::new_u64(42);
+    /// for _ in 0..1000 {
+    ///     rng.next_u64();
+    /// }
+    /// assert_eq!(sfmt::SFMT607::value_at_u64(42, 1000), rng.next_u64());
+    /// ```
+    pub fn value_at_u64(seed: u64, index: u128) -> u64 {
+        let n64 = SFMTMEXP::<MEXP, MEXP_N>::SFMT_N32 as u128 / 2;
+        let sfmt = Self::block_at(seed, index / n64);
+        let i = 2 * (index % n64) as usize;
+        let st = as_u32_slice(&sfmt.state);
+        st[i] as u64 | (st[i + 1] as u64) << 32
+    }
+
+    /// [SFMT::new_u64] regenerated `block + 1` times, i.e. holding the outputs of the block
+    fn block_at(seed: u64, block: u128) -> Self {
+        let mut sfmt = Self::new_u64(seed);
+        sfmt.jump(block + 1);
+        sfmt.idx = 0;
+        sfmt
+    }
+
+    /// Minimal polynomial of the recursion generating a 128-bit word computed by [berlekamp_massey]
+    ///
+    /// This is the polynomial `p` of the least degree with `p(r) = 0` for the recursion `r`
+    /// from the last `MEXP_N` words to the next word, and [SFMT::jump] computes modulo it.
+    /// The degree is `128 * MEXP_N` at most,
+    /// and it has the primitive factor of degree `MEXP` which gives the period `2^MEXP - 1`.
+    ///
+    /// It is determined from twice as many steps of eight pseudo-random states,
+    /// observed through 32 linear functionals of the new words.
+    /// A single bit sequence can miss the factors of small degree, e.g. `x + 1` for fixed points,
+    /// and they are recovered from the others.
+    /// The polynomials of the built-in parameters are precomputed by this for the jumps.
+    ///
+    /// This takes `O(MEXP^2)` time, e.g. minutes for [crate::SFMT216091].
+    ///
+    /// ```
+    /// let p = sfmt::SFMT607::minimal_polynomial();
+    /// assert_eq!(p.degree(), Some(640));
+    /// ```
+    pub fn minimal_polynomial() -> Poly {
+        let bound = 128 * MEXP_N;
+        let len = 2 * bound;
+        let mut x = 0x1234_5678;
+        let mut states = vec![[zero(); MEXP_N]; 8];
+        for s in states
+            .iter_mut()
+            .flat_map(|s| as_u32_slice_mut(s).iter_mut())
+        {
+            *s = xorshift(&mut x);
+        }
+        let mask: Vec<u32> = (0..(4 * 8)).map(|_| xorshift(&mut x)).collect();
+        let seqs = Self::observe(&mut states, &mask, len);
+
+        let mut p = berlekamp_massey(&seqs[0], len);
+        for seq in &seqs[1..] {
+            // The rest annihilated by `p` has the linear complexity `bound - deg` at most
+            let rest = 2 * (bound - p.degree().unwrap());
+            if rest > 0 {
+                p = &p * &berlekamp_massey(&p.apply(seq, rest), rest);
+            }
+        }
+        p
+    }
+
+    /// Bit sequences of 32 linear functionals on the states over `len` steps of the recursion
+    ///
+    /// The functional `k` takes the bit `k` of the XOR of the new words of the states
+    /// masked by `mask` and rotated by their indices.
+    /// The states are left as ring buffers rotated by `len` words.
+    fn observe(states: &mut [[i32x4; MEXP_N]], mask: &[u32], len: usize) -> Vec<Vec<u64>> {
+        let mut seqs = vec![vec![0_u64; (len + 63) / 64]; 32];
+        let mut p = 0;
+        for n in 0..len {
+            let mut obs = 0_u32;
+            let mut j = 0;
+            for (s, m) in states.iter_mut().zip(mask.chunks_exact(4)) {
+                Self::step(s, p);
+                for (w, m) in as_u32_slice(&s[p..=p]).iter().zip(m) {
+                    obs ^= (w & m).rotate_left(j);
+                    j += 1;
+                }
+            }
+            for (k, seq) in seqs.iter_mut().enumerate() {
+                seq[n / 64] |= (((obs >> k) & 1) as u64) << (n % 64);
+            }
+            p = if p + 1 == MEXP_N { 0 } else { p + 1 };
+        }
+        seqs
+    }
+
+    /// Replace the oldest word `ring[p]` of the state in a ring buffer by the next word
+    fn step(ring: &mut [i32x4; MEXP_N], p: usize) {
+        let at = |i: usize| {
+            ring[if p + i < MEXP_N {
+                p + i
+            } else {
+                p + i - MEXP_N
+            }]
+        };
+        ring[p] = SFMTMEXP::<MEXP, MEXP_N>::mm_recursion(
+            ring[p],
+            at(SFMTMEXP::<MEXP, MEXP_N>::SFMT_POS1),
+            at(MEXP_N - 2),
+            at(MEXP_N - 1),
+        );
+    }
+
+    /// Precomputed [SFMT::minimal_polynomial]
+    pub(crate) fn precomputed_minimal_polynomial() -> Poly {
+        Poly::from_words(SFMTMEXP::<MEXP, MEXP_N>::MINIMAL_POLYNOMIAL.to_vec())
+    }
+
+    /// Replace the state `s` by `q(r)s` where `r` is the recursion generating a 128-bit word
+    ///
+    /// The polynomial `q` is given by its coefficients packed in words, see [Poly::words].
+    /// The state is stepped by one word in a ring buffer for each coefficient,
+    /// and added up for the nonzero coefficients.
+    pub(crate) fn jump_by_polynomial(&mut self, q: &[u64]) {
+        let mut ring = self.state;
+        self.state = [zero(); MEXP_N];
+        let len = match q.iter().rposition(|&w| w != 0) {
+            Some(i) => 64 * i + 64 - q[i].leading_zeros() as usize,
+            None => return,
+        };
+        // `ring[p]` is the oldest word of the state
+        let mut p = 0;
+        for i in 0..len {
+            if (q[i / 64] >> (i % 64)) & 1 == 1 {
+                let (head, tail) = as_u32_slice_mut(&mut self.state).split_at_mut(4 * (MEXP_N - p));
+                let (front, back) = as_u32_slice(&ring).split_at(4 * p);
+                for (s, t) in head.iter_mut().zip(back) {
+                    *s ^= t;
+                }
+                for (s, t) in tail.iter_mut().zip(front) {
+                    *s ^= t;
+                }
+            }
+            if i + 1 < len {
+                Self::step(&mut ring, p);
+                p = if p + 1 == MEXP_N { 0 } else { p + 1 };
+            }
+        }
+    }
+}
+
+/// Pseudo-random numbers for the states and the functionals of [SFMT::minimal_polynomial]
+fn xorshift(x: &mut u32) -> u32 {
+    *x ^= *x << 13;
+    *x ^= *x >> 17;
+    *x ^= *x << 5;
+    *x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::xorshift;
+    use crate::{packed::*, poly::*, sfmt::*, *};
+
+    macro_rules! minimal_polynomial {
+        ($mexp:expr $(, #[$attr:meta])*) => {
+            paste::item! {
+                #[test]
+                #[cfg_attr(miri, ignore)] // too slow on Miri
+                $(#[$attr])*
+                fn [<minimal_polynomial_ $mexp>]() {
+                    assert_eq!(
+                        [<SFMT $mexp>]::minimal_polynomial(),
+                        [<SFMT $mexp>]::precomputed_minimal_polynomial()
+                    );
+                }
+            }
+        };
+    }
+
+    minimal_polynomial!(607);
+    minimal_polynomial!(1279);
+    minimal_polynomial!(2281);
+    minimal_polynomial!(4253);
+    minimal_polynomial!(11213, #[ignore]); // slow
+    minimal_polynomial!(19937, #[ignore]); // slow
+    minimal_polynomial!(44497, #[ignore]); // slow
+    minimal_polynomial!(86243, #[ignore]); // slow
+    minimal_polynomial!(132049, #[ignore]); // slow
+    minimal_polynomial!(216091, #[ignore]); // slow
+
+    macro_rules! annihilate {
+        ($mexp:expr $(, #[$attr:meta])*) => {
+            paste::item! {
+                #[test]
+                #[cfg_attr(miri, ignore)] // too slow on Miri
+                $(#[$attr])*
+                fn [<annihilate_ $mexp>]() {
+                    const N: usize = $mexp / 128 + 1;
+                    let p = [<SFMT $mexp>]::precomputed_minimal_polynomial();
+                    let deg = p.degree().unwrap();
+
+                    // The recurrence holds for other states and functionals, and beyond the degree bound
+                    let mut x = 4357;
+                    let mut states = [[zero(); N]];
+                    for w in as_u32_slice_mut(&mut states[0]) {
+                        *w = xorshift(&mut x);
+                    }
+                    let mask: Vec<u32> = (0..4).map(|_| xorshift(&mut x)).collect();
+                    let len = 3 * 128 * N;
+                    for seq in [<SFMT $mexp>]::observe(&mut states, &mask, len) {
+                        assert!(p.apply(&seq, len - deg).iter().all(|&t| t == 0));
+                    }
+                }
+            }
+        };
+    }
+
+    annihilate!(607);
+    annihilate!(1279);
+    annihilate!(2281);
+    annihilate!(4253);
+    annihilate!(11213, #[ignore]); // slow
+    annihilate!(19937, #[ignore]); // slow
+    annihilate!(44497, #[ignore]); // slow
+    annihilate!(86243, #[ignore]); // slow
+    annihilate!(132049, #[ignore]); // slow
+    annihilate!(216091, #[ignore]); // slow
+
+    macro_rules! jump_2k {
+        ($mexp:expr $(, #[$attr:meta])*) => {
+            paste::item! {
+                #[test]
+                #[cfg_attr(miri, ignore)] // too slow on Miri
+                $(#[$attr])*
+                fn [<jump_2k_ $mexp>]() {
+                    let p = [<SFMT $mexp>]::precomputed_minimal_polynomial();
+                    let params = [
+                        (64, <SFMTMEXP<$mexp, { $mexp / 128 + 1 }>>::JUMP_2_64),
+                        (128, <SFMTMEXP<$mexp, { $mexp / 128 + 1 }>>::JUMP_2_128),
+                        (256, <SFMTMEXP<$mexp, { $mexp / 128 + 1 }>>::JUMP_2_256),
+                    ];
+                    for &(k, words) in &params {
+                        let q = p.pow_x_2k_mod(k).pow_mod($mexp / 128 + 1, &p);
+                        assert_eq!(Poly::from_words(words.to_vec()), q);
+                    }
+                }
+            }
+        };
+    }
+
+    jump_2k!(607);
+    jump_2k!(1279);
+    jump_2k!(2281);
+    jump_2k!(4253);
+    jump_2k!(11213, #[ignore]); // slow
+    jump_2k!(19937, #[ignore]); // slow
+    jump_2k!(44497, #[ignore]); // slow
+    jump_2k!(86243, #[ignore]); // slow
+    jump_2k!(132049, #[ignore]); // slow
+    jump_2k!(216091, #[ignore]); // slow
+
+    macro_rules! jump {
+        ($mexp:expr $(, #[$attr:meta])*) => {
+            paste::item! {
+                #[test]
+                #[cfg_attr(miri, ignore)] // too slow on Miri
+                $(#[$attr])*
+                fn [<jump_ $mexp>]() {
+                    let p = [<SFMT $mexp>]::precomputed_minimal_polynomial();
+                    let mut x = 4357;
+                    for &steps in &[0, 1, 2, 3, 17] {
+                        // Arbitrary states, which are not always reachable by the seeding
+                        let mut naive = [<SFMT $mexp>]::new(0);
+                        for s in as_u32_slice_mut(&mut naive.state) {
+                            *s = xorshift(&mut x);
+                        }
+                        let mut jumped = naive.clone();
+                        jumped.jump_by_polynomial(p.pow_x_mod(steps * ($mexp / 128 + 1)).words());
+                        for _ in 0..steps {
+                            (naive.gen_rand_all)(&mut naive.state);
+                        }
+                        assert_eq!(as_u32_slice(&naive.state), as_u32_slice(&jumped.state));
+                    }
+                }
+            }
+        };
+    }
+
+    jump!(607);
+    jump!(1279);
+    jump!(2281);
+    jump!(4253);
+    jump!(11213);
+    jump!(19937);
+    jump!(44497, #[ignore]); // slow
+    jump!(86243, #[ignore]); // slow
+    jump!(132049, #[ignore]); // slow
+    jump!(216091, #[ignore]); // slow
+
+    #[test]
+    fn jump_2_64() {
+        use rand_core::RngCore;
+        let mut rng = SFMT607::new(1234);
+        rng.next_u32();
+        let mut jumped = rng.clone();
+        jumped.jump_2_64();
+        rng.jump(1 << 64);
+        for _ in 0..1000 {
+            assert_eq!(rng.next_u32(), jumped.next_u32());
+        }
+    }
+
+    #[test]
+    fn streams() {
+        use rand_core::RngCore;
+        let mut streams = SFMT607::streams(1234, 5);
+        let mut first = SFMT607::new_u64(1234);
+        let mut second = first.clone();
+        second.jump_2_128();
+        assert_eq!(streams[0].clone().next_u32(), first.next_u32());
+        assert_eq!(streams[1].clone().next_u32(), second.next_u32());
+        for (i, s) in streams.iter_mut().enumerate() {
+            let mut stream = SFMT607::stream(1234, i as u64);
+            for _ in 0..1000 {
+                assert_eq!(s.next_u32(), stream.next_u32());
+            }
+        }
+    }
+
+    #[test]
+    fn value_at() {
+        use rand_core::RngCore;
+        let mut rng = SFMT607::new_u64(42);
+        let expected: Vec<u32> = (0..100).map(|_| rng.next_u32()).collect();
+        for (i, &v) in expected.iter().enumerate() {
+            assert_eq!(SFMT607::value_at(42, i as u128), v);
+        }
+        let mut rng = SFMT607::new_u64(42);
+        let expected: Vec<u64> = (0..50).map(|_| rng.next_u64()).collect();
+        for (i, &v) in expected.iter().enumerate() {
+            assert_eq!(SFMT607::value_at_u64(42, i as u128), v);
+        }
+        let index = (3 << 64) + 17;
+        let mut rng = SFMT607::new_u64(42);
+        rng.discard(index);
+        assert_eq!(SFMT607::value_at(42, index), rng.next_u32());
+        assert_eq!(SFMT607::value_at_u64(42, (3 << 63) + 9), {
+            let v = rng.next_u32() as u64;
+            v | (rng.next_u32() as u64) << 32
+        });
+    }
+
+    #[test]
+    fn jump_seeded() {
+        use rand_core::RngCore;
+        let mut rng = SFMT19937::new(1234);
+        for _ in 0..100 {
+            rng.next_u32();
+        }
+        let mut jumped = rng.clone();
+        jumped.jump(5);
+        for _ in 0..(5 * 624) {
+            rng.next_u32();
+        }
+        for _ in 0..1000 {
+            assert_eq!(rng.next_u32(), jumped.next_u32());
+        }
+    }
+}
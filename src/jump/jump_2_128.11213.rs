@@ -0,0 +1,46 @@
+[
+    0x713cd068defec513, 0xbe2cf36bca4985b2, 0x764aa7c15b42e87c, 0xf0865e0429c96e26,
+    0x1b41ef2ec5292ebf, 0x3d57839e69779710, 0x1ae9ba544243e0ed, 0xbfddec3c9a01ad81,
+    0x2eb21d0b0092b4e0, 0x7a4bb15b36088f58, 0xbd1b793dd704a4f3, 0x00b9e83f76079301,
+    0xb02e3c24ebec346e, 0xa6fe2f54cee4d59f, 0x0a2b7842389eb63a, 0xabe692835c727d75,
+    0xf74fa65c0439479a, 0x6076a02e7341421b, 0x6a746c8731f7d58f, 0xc7daa237980683af,
+    0x60e9e3bf19564828, 0xd2eb2ff817ba4a9a, 0x1aed5bc4441b8e49, 0x4e285c5639954f88,
+    0x13ae1d28541fc3f0, 0x842f91dada3ae4d5, 0xd594e0782be9ad16, 0x91cbfff91c7bd797,
+    0x9c38791968dea882, 0xfecde18d8ebf1b71, 0x8a7452359ec3d9ce, 0x44c48ac67935b5b4,
+    0xb12c9eaa23ee5fb5, 0x967d074e9ae920c7, 0x2334d43ea047b278, 0x7a3fa742f644f663,
+    0x890e2f4119b8e9d9, 0xae4f5629407e09e2, 0xd969ffac26a6e726, 0x40a6b6627f2a625c,
+    0x651a27f14e068927, 0xd9a1d99f5d9f0916, 0xad910b737867d9ad, 0x9e36a1202678371a,
+    0xacb5a4c5ff20d7c0, 0x0641ddb35da43d1d, 0xdea48a37352428f2, 0x988981f5eec40eb5,
+    0x40b9cfbe12630b12, 0x683c702ce09748a3, 0x5df34310ca366218, 0x1dbac358df3643e0,
+    0xddf6ba8ccef3ac10, 0x08c28c1f18a4e16c, 0x341ff3ca0191ef4a, 0xe84dcff6841e79f9,
+    0xd202bc095456ceb4, 0x53a86e31da4f67b0, 0xca170f53acb3b527, 0x2cebdcd505893b4f,
+    0x245a60ea6d66ad88, 0xb699fc02dba27db7, 0x194e8fb5d381a067, 0xe8af6ce1bbf02b33,
+    0xb61bcc47d70e0368, 0xf904cb39bfe038e2, 0x1ab145e380c97770, 0x21def33f585bb4b2,
+    0xeafb1222008e1601, 0x4cf12d8f4e505c5b, 0x48cdf4a6b83033ff, 0x3344a9d5ac9c8a88,
+    0xb6384494a0159b79, 0x7e01cce4974cd133, 0xc7b9673973beafa1, 0xe0273639e81ebe6a,
+    0xf0c799a65c941b06, 0x2e36944585346f82, 0xa76176c765fc123b, 0x60fc7f39c490418f,
+    0x1a4db78c21b2fd55, 0x8afc7be17105ecef, 0x8098591fc9348f0c, 0xfa1bb9aa193fc884,
+    0xe53b00dddf712dc0, 0xc880c2c6942f7845, 0xf4c7c5e74efbe04f, 0x485c31371e31685f,
+    0x4485113d216b1399, 0x2fefa45feb4f06ff, 0x60f69441cf54bc58, 0xb5d9fadc2fefa446,
+    0x6c6558a9be4c77ed, 0xcc66fcc1faa6033d, 0xd6c7e493dc421aba, 0x20f389b936c671e1,
+    0x4a62299bcba85b3c, 0xfc62acdfcaf256a4, 0xc863c6eb91c2f39b, 0xc902952e535fa63d,
+    0xb114de9d69100ad6, 0x6416b6d2504807e2, 0xff2cb41a665da263, 0x1b48b2baa1f4607f,
+    0x251dec9c6edd7f1b, 0xf5d8ddd00e8e2f4d, 0xa1f063ac7838d334, 0xfe707c169f966a6f,
+    0x0ba1f382f1746270, 0xc8e6b1ec583cdd28, 0x4cf012fd02856c8b, 0x411bf21aa540494d,
+    0xc99ea62e381950b5, 0xab28d222d570558a, 0x2709b273dbeb9fe3, 0x3ec0c4dbc1298176,
+    0xa0538ef230248d5c, 0xe0717a75537627b3, 0xe27e5b9c436a4039, 0x02bf88c8c0ae3513,
+    0x90a66d76e207fe4b, 0xb462122f6c2bdc4d, 0x8185263681cb3c85, 0x8b601d1932283a08,
+    0x2dd7e7cac349a567, 0xa163f6801f7d67d7, 0xf00bca087375876b, 0xffde5299dc6197be,
+    0xf8ae4842decbf2c0, 0x2e5c6fa4b2904d70, 0xf7627ba937121c78, 0xbba456437ef5d35a,
+    0x619043ce7a590802, 0x5707e03e1ae1c544, 0xd926125f41a5fa0b, 0x8b924dfc440276f2,
+    0x86af6caae998315e, 0x7bba69883837769d, 0x29973839e5538af1, 0x6c93805612bc5b59,
+    0xd0e37be42e6d7721, 0x635e7a51435d7cdd, 0xf3aa3fcd4b95f779, 0x2c02ac295eb023f7,
+    0x6618242571c8a927, 0xac9a241920596d08, 0xa258f93b030e7b73, 0x521f9d7fb4bb05c4,
+    0x5c433638f77da18c, 0x15657121b1717ff1, 0x7fb1e1622c721094, 0x7d432e9db4271bfb,
+    0x698ddab5e9405584, 0x3e59783eba8fe997, 0xf07148f8f77026f3, 0x41e12dcc411e0820,
+    0xe2adcf19a9233e17, 0xabf4c35c5b8418f0, 0x40dca5b4751a46fd, 0x10b7e4a05432e977,
+    0xa029ffe2cd337a2b, 0x9d632ff25d2bea2d, 0x289f63b94165693f, 0x05a237e05a6680f2,
+    0x377429dbf00b126e, 0xf9a740bffd10d084, 0xcb6b27edce9e7db0, 0xeafa419d30d7308c,
+    0x27d8b13f72198701, 0xd18b933da9017a6f, 0x6cb8487a2153f08d, 0xcb417d501cc102bf,
+    0x30966f687226e895, 0x5653ad9f4f454db3, 0x5e520d9c0b1a75e3, 0x4b1f31570c395dcf,
+]
@@ -0,0 +1,7 @@
+[
+    0x30b3c1a8585ce286, 0x6b069b79909bee82, 0xeabe402a857a9d0a, 0xd79ec01f22fefabc,
+    0x6c11a2353e60befd, 0x6e33b07703e6b05c, 0xc1ece4d6d4e6f1b9, 0xf384883a3fd1dfc1,
+    0x90db256a851fc2e2, 0xb6e7d3803e69db3c, 0xdfb7c95f065b7275, 0x5908a70f96788fed,
+    0x4c4d911f844e248c, 0x25b4e84d7a320990, 0x312e5f7a4aa14b65, 0x181e6dad24632aff,
+    0x948c164473c1e91c, 0x3d3d4792d9caae7a, 0x513287c0a9a1af85, 0x354c027f7fcb818f,
+]
@@ -0,0 +1,518 @@
+[
+    0x9cf6063275f65b96, 0x34d1980e8b633f7b, 0x594fc98bbb1eca54, 0xbd17855bdb39ad37,
+    0x034105bc2bc2c4fe, 0x7d3d688bef456c59, 0x3b781c20f8e9fd76, 0x3e4a5748e84c1c0e,
+    0x0e5111b5e21466c6, 0x5ed6bde0e415e9a0, 0xbeb10b9eda397759, 0x4b6657844b9f7122,
+    0x05a137c3d12f1e62, 0x50bd861eed826e3b, 0x620ed152403017ad, 0x8335d67efe38220f,
+    0xa36946fd88b46fb9, 0xf19f02c2a6ddc029, 0xe0e73221c513910d, 0x33bc1342133f2d5d,
+    0xf5c6a7ad87466f73, 0xc902225a517fccac, 0x73f052881914839c, 0xf7ec7feb4f902812,
+    0x12f0be2b5c95212f, 0xf0b42de7de45cf3f, 0x7761a1f0e7af4262, 0x814a3bdeb6cea540,
+    0xe3f774be988afb0c, 0x2db53e5aaa2e0b2e, 0xa5af64ebdf34f95a, 0xe5700cc88e4a663f,
+    0x18082b32bb9bb58e, 0xd18a130cf7e798ce, 0x06c37feb8fcfc8f6, 0x685e28b15acd2933,
+    0x2afc2e10a31b63c2, 0xb11c0b3eebc3892b, 0xdcc3593d20a3ed4a, 0x7d911b3840749b45,
+    0xf06b5630aa28b297, 0xf5454614956d9cd3, 0xb8b69dd136913e61, 0xb2c6f16aae4f01af,
+    0x1ec64cf4d6b429c7, 0xfd0d26f3562908d2, 0xaa53b8f42868755a, 0x55328ddbecee8ccd,
+    0xd011290405b4506d, 0x1fb5506564d40894, 0x63c70efbc7d8955a, 0x200a73909346b83c,
+    0x8dfebd68a931afa9, 0x2553bad2dac8cd75, 0xd572769ec62e38e5, 0x49e945b958f268dc,
+    0x19191b9df9456ce2, 0xda293149b905a4dd, 0xdf9fd6e7fcc7b7de, 0x5dcf0b7d0ba077f1,
+    0xf555c5d78ee4d31d, 0x1706f1e839efac8e, 0x73ca2d7803c12d83, 0x5c9d359cbe66eb57,
+    0xee248a8f134dee85, 0x981b76139f1d8b0e, 0x19b2a5e87308c4de, 0xd70f1aa5b221fe99,
+    0xf60ae9110011f94b, 0x2cb1ef9e82aadd7e, 0x05a14b2116b7a8f6, 0x2d2ec21556dcce95,
+    0x02bff053a1e86891, 0x696c2c2ea38d1e7d, 0xa1ddb5037797fad5, 0x695c0dd9848ac2f6,
+    0x5285852066abee8b, 0x6b6f8bb0f1467972, 0x6bbf5e3cdab70b4a, 0xa6a5f56d7e41eb7e,
+    0x3835009456d2c591, 0xd9c4de41a4d17658, 0x2db031169a94b13c, 0x700e6d21a9080598,
+    0xf9b7147c48a86a9e, 0x8594faf83bece619, 0x6509e57b6b5a81d1, 0xa9e517fdfd678886,
+    0xf74d24427f56a24a, 0x2512e544ccebe5a9, 0xc427524a0494a369, 0x9158031c0d2d4659,
+    0xe4e014ede1dfa25e, 0xbf7bd59151d91b17, 0x13b4090f47e8eb38, 0xa7f1119bd2d9598d,
+    0xab993c23629e8b51, 0xcbfcc73c489bc531, 0xc4055974212ce32c, 0x4fc0942e45251158,
+    0x9fcbc5438b907902, 0x463f2cc0a3047331, 0xe8c11908c8d6c800, 0x0b542c804c4d89e6,
+    0xe71e7a8afd07e559, 0x205d2482619d9420, 0x10bb83c807414a7b, 0x9c6cdac2400ac28f,
+    0x0bfde3dbd903faaa, 0x85f40a8d93d1f81a, 0xe60b9dcee7ea6961, 0x5a4551cb3fe33287,
+    0xf8c296afc1fc6a21, 0xb27874a85486d4b7, 0x5a74cafdf125efc2, 0xbcd8c8c8345a7490,
+    0x1265be11b9775004, 0x5d939a587800d83d, 0xbbb1ccc6f3362e70, 0x7f897513dd866157,
+    0x19ff94a28ab63565, 0x2517555cff4b1337, 0xaf93727ba4037d6c, 0x11668616553bab8f,
+    0x4e883a7fcb764c22, 0x362f8cacdd3bbd37, 0x6305c6e77a0a678f, 0x13d3145b6f854d65,
+    0x29bfc80fae6d42ff, 0x3d6fd3a832fe7eb1, 0xad8bee3927bfeeab, 0x09d1383a8b3538d4,
+    0x046e0bd147f4ef27, 0xb3784d780831b458, 0x5fecb03b8e96f100, 0xe10aaac382860293,
+    0x6b6e07fedbdb85f2, 0x9cff063183121aba, 0xd09a2f631c3b0b17, 0xe8af34ecdb5398bc,
+    0x719b0d0943d52d62, 0x0a79e0d50b55c741, 0x839318f1220c3809, 0x9ab02a13b369763f,
+    0x61ca5f0e74e71521, 0x3d7db989b44311b6, 0x352e1c2e5f4e855c, 0x5051ad376e7603d9,
+    0x53686ab24a90c7de, 0xa7535ad176642acf, 0x7a20b5bdea187ef6, 0x506a23bb192b404c,
+    0x46001f73a7766ede, 0x4f42e083f34973e3, 0x6d3f151724a77710, 0x94d6b23aa5c28d0f,
+    0x4f33f70b2dbaa1c8, 0xf6f2a33d38d047ac, 0x7031222ef403e889, 0x11a1505c04fbcf11,
+    0x6d4be7ae83b9d7a5, 0x8db7218b364b2dca, 0xe954600bcc114b63, 0xecd1a5d5db0d9bc8,
+    0x8fe40f8b035a9160, 0x3e5969c14e1334d7, 0x4aa0d649babe2157, 0x516a919a8c3c9b92,
+    0xe8cd03df5f2ad861, 0x6d600735e0bd0e7c, 0xa6bd17d757064780, 0xb337e0f08cc61cdc,
+    0xff7066b2bf3b5006, 0xf1ab6c19c5cd0cd7, 0xf861e9a1cfb0d111, 0x50513a41db5789c4,
+    0x6f8676438f3b0edf, 0xd7eb463c744522cf, 0x0d96603dd488998e, 0xe6d7e5a3a0b3f9ce,
+    0x720dc0ce838f7633, 0x15a9eca0fbeec9a7, 0x687be8698c30e91d, 0x3a7a33963dbb10a9,
+    0xba013e0c4607bd4c, 0xf271dae845d93c25, 0x726707514685a6f8, 0x5480b5649a80356c,
+    0x6767a91e3b6f4f30, 0x90cb4f74fd1933c3, 0xe7ae5e57118de7f2, 0x627addaf2279beb9,
+    0xe3a5ffb58967b7e2, 0xaaf7cb2c5ad83fbb, 0x9ff9842fff284f62, 0x9ab494109f286755,
+    0x4ccf9d6e458d8d2e, 0x305a5f25ba1eb168, 0x6f93c6f968151163, 0x2b4abf3b39cc62f4,
+    0x6becf6ec61be154f, 0x83b9903bd1c568de, 0x9a32ba854f65e09c, 0x23bb8c7c59d699c5,
+    0xc19d6adad8c93530, 0x563a04dafb163700, 0xde75d6d10179216f, 0x19b879f6c734014d,
+    0x795f6676efef98fc, 0xcad30ee2f5511423, 0x172f5e962cca36e9, 0x2f23ca7410f93893,
+    0x36790ed5e60e722e, 0x9765748179bc9640, 0x9a5cc9b7fdc85461, 0xe6f087d88cacb11f,
+    0x838e6b607cbe8363, 0x78ba1349dd8c3960, 0x381f6faca6a12cef, 0x68b554ac64e9f578,
+    0x77096de7ddc2e5d3, 0x614db08e82147a85, 0x40007514aa1f49b7, 0xa419eae842f09317,
+    0x63f46147640e63d3, 0x00e0863d5fe674ee, 0x10c86cf3f9260422, 0x42cd5162aeff6c82,
+    0xd0328e4902229193, 0x1ce063958034dcda, 0x5bdf2de13e21fbf6, 0x53aa4e3f275e571c,
+    0x6a7e27b45c3faf2a, 0x895c45a3c9554de6, 0x50f15c0c6b92b4ed, 0xeeadd47f05ce91d9,
+    0xae15fa4f6882954c, 0x65fdeaf18783d7e5, 0x4da93fe8a80e3288, 0xd15d4669b69cdf7d,
+    0xd485efbe8154c645, 0x17f6f7c5e430561c, 0x881613c2c9b7c796, 0xdf9a08223bb20a21,
+    0x1df5ab1d641e0ec7, 0x940f67d6314f4ff4, 0x7f55b008ad27bc3b, 0x2418d5b34b5a5801,
+    0xefcf5f9256f0df9f, 0x38dfc1ccc0ed9627, 0x7705c2b81c154013, 0xe6704cfcbc15e44b,
+    0x2b92317f3b68c348, 0x228fc2754c324c4a, 0x4afa5112fdde86af, 0xaca38602c2169d3e,
+    0x3151c2a71b7d1ba0, 0x05c69086fe3bfbc8, 0x5d1cefcb04e4d3be, 0xe936a21277dfd1e2,
+    0xaac2c18c69885103, 0x1fdcbe312b4d7cc8, 0x282f7e6a70127ad8, 0xac64737cf89ff7a5,
+    0x5dbc4457400d5292, 0x74417ff36104e8a5, 0x8cf282cabdbb503d, 0x82b8c19111b03daa,
+    0xdf52cb106855badf, 0xf25beb409649db44, 0x397dc62b5ed8ede3, 0x09b30f9ab75f174d,
+    0x31ad7eb9ce253b0a, 0xc2cdc740d68ccd50, 0xe14da66ecfb747b4, 0x820841309a151cbb,
+    0xf278e60e24b293e3, 0x8a9302bc7f12efe0, 0xff1d000f3a9f1747, 0x6f60c1e0bd03d727,
+    0x2c14b8638a78fdbc, 0x5e03d7cfc5678568, 0x948802a9e5a2651f, 0x415c4667c0452ce5,
+    0x7fa26afa0b0a54ec, 0x7fe780b9a39aca04, 0xb6599ca838350371, 0xeade50081a626129,
+    0xec7100d1716778ef, 0xe62b31f3c6b4c58e, 0x4d5ebae312066592, 0x5ab07e3b46c8e5a7,
+    0x186029bb3e5b7eca, 0xe340c274f82a5966, 0x724220394719eaed, 0x464039293ae37c2c,
+    0x23b4d28be1cea675, 0x9245d33bb14fcb45, 0x735b10d463b1dcb4, 0xc94de1d79b1d06f1,
+    0xd1ece88c91db509f, 0x409e0977bf7172c4, 0x18f4912c54a4ba3a, 0xbd8a1c85b17fc195,
+    0x341f3f65f30a8ae5, 0xd8b344ab63ad3999, 0x2a2ea9809af48a5b, 0x50f790dbb3ec6521,
+    0x3373efb60b762619, 0xd86a65c1d5edd180, 0x84a7bc8ef6bd0ad6, 0xab01283f11951741,
+    0xc9bcefb07bdf37ac, 0x7c20008e48a73788, 0xf7df617670833474, 0x7d791ff157e745a9,
+    0x33f699e996c2dfde, 0xc9b78c21e5fe89b8, 0xf075a398d90db1e3, 0x1614e6455aa9a886,
+    0xb21ccf8fe4195b4f, 0x8120b371736fea8c, 0x8cf4ffb1b3d8b67f, 0xe09c5548e01dc137,
+    0x6a3b8a611253ca0e, 0x9a4dc50121b974e0, 0xe7e84ef8f66aa37d, 0xb89829cba17cf4ac,
+    0x6ffb096b4e09f8e4, 0x1a16613d5880d720, 0x8f49d224e2ffec9f, 0x1d7c74c9d092110d,
+    0xe49cdedbf93c65ad, 0xfbb3526248a2b945, 0x3911de137bf99a18, 0x24df6de838cf006f,
+    0xa7065bc47f586afd, 0x183bc4b1187bc6b9, 0x1a70bec1b3bc425a, 0x0c9c011da0428daf,
+    0x42ca9c6d9d59b927, 0xd1aa9b36fc7839ec, 0x877e3ad4f68abf12, 0xaee562cc6ab63aa4,
+    0x901edad951682056, 0x5270b8b559108b17, 0x86d520c380d223d7, 0xaec7e9bf8480aa2a,
+    0x533e9cbe02c54901, 0x74a77a1abe179f47, 0xcf69e130b9a2a11a, 0x2807c2e1231d74cb,
+    0xd678944945cada3f, 0xf90e60363b2c4bd5, 0x472e8db73280806f, 0xeeef7c16534842e3,
+    0xcdf097abf0d6a91b, 0x098615eb2fa20dfa, 0xec2432c36d8fde13, 0xf78e4c2c9f734d3a,
+    0x348d4817a9026824, 0x2b0a2e6c036ce27f, 0x921e75bbcc64c729, 0xe6ea0885c67334e6,
+    0xa98f44cc93c91008, 0x2b08997660919556, 0x70b07eeb8d287f70, 0x59e64a1042bbbcb5,
+    0x313a860e0134e09b, 0x85e3a28406e4cf57, 0x2e30ea61ce0b0c6c, 0x8af9ce75ad037be8,
+    0xe91864a1b1b1b0cb, 0xfc5ea7da3d05f180, 0x321517ceeedab98e, 0x2e3887246c0c8acc,
+    0xeae7cdee8e3c9c56, 0xefcd750836f5da37, 0xa80899d176cea932, 0x685b0cb3fd0b9d49,
+    0x09e8087205d7c12c, 0x4f9f0b9cdb6a8000, 0x978c1a781a790f66, 0xa15ea0f97a8be954,
+    0xdff5106e7775b0e3, 0x480dcfacf33365e1, 0x64185f59c5ff1082, 0xc8cd28a90b528617,
+    0xf35b2a6639503ab8, 0xe80ff2564a779804, 0x44c8db4735ba2166, 0x402003b3703014e3,
+    0x96b82fc714ff3e6b, 0xd00c9ecd8ca1c708, 0x753f4d3361118b10, 0x75c4326ff3c078d8,
+    0x29607e33d69b9c85, 0x76a1840dcda6f272, 0x8bf059f7f0c25995, 0x12a4a95cb48de3e5,
+    0xa3aceba73903599b, 0x16066d5ea2ae812d, 0x4ad0cef5744d13da, 0xc300ef6549c395b4,
+    0x6fc2190b2936616a, 0x17294bb0d39ec3df, 0xaaa3c0246c3e32cb, 0xf5fdba248f541d12,
+    0xf135476703de71e9, 0x24d055398847f801, 0x206b564749a5ad00, 0xc927cff4bed9076d,
+    0x80855f5741c6900c, 0x6a0d469ea7cddfab, 0xd6ca7aaa9acf57ba, 0x1bf17d1d2923cbdd,
+    0x739de21bf1318e4a, 0xe2ce2c7a0c0bf9fc, 0xfc7259e667fbeafb, 0xc22981f4cdf839e1,
+    0xa44f08b47fb5f6a2, 0xee94b87d210ea182, 0x279d89e4620a08ca, 0x3be44e9a309af453,
+    0x411607b053007e58, 0x8e016cad28c3f3c7, 0xd88394677dc6c377, 0xf03dc261ef78defb,
+    0x8507974f4f87c1f5, 0x20cabcece1788664, 0x9bb410f6ae1dc099, 0xd1b9cf816d41be87,
+    0x4326287ee3b4c9d7, 0x03d08f2632fc0d2e, 0xca8b3893a6334f71, 0x7219a57c56479017,
+    0x41253f7fb0ad1519, 0x7cfbefce75c01973, 0x2835bad687770903, 0x9f20dc689185ccce,
+    0xc85ab7c95f0e21a9, 0x704a0869381553af, 0x46da0b6a91189aa6, 0x10987288a34ec8f9,
+    0x9ea5d2015f0ee31f, 0xa6dc8be896311f8a, 0x33fad5b23f1b4922, 0x847fd3e3c5501ab5,
+    0x32b1ea6f9cba8f09, 0x0ab39376b6072665, 0xc66ec6fd281cfd18, 0x70cc3048e6bdbea4,
+    0x297c0f85c3e03b54, 0xe3843fe0acbb50e4, 0x249b49ab5986ca9f, 0xaab608d45ae7e55b,
+    0x6e1c88673d307559, 0x69aec083b9480cf6, 0xf592030476409140, 0x9e61b078469d7904,
+    0x12ddf297d62bc447, 0xeb96bd448dafddf6, 0xf28d16b19caaaf86, 0x516bc5372bcb80ce,
+    0x4dc7f32401666cd4, 0xa7762b93ace2c6a4, 0x7d379c2cc36349bf, 0xa93ad555072f70bb,
+    0x46619b25701fab1d, 0xe7c40646c0d58170, 0x6e6dbef776cfd4ff, 0xe6026cf6d7ea3aca,
+    0x90b4cfdae684264d, 0x400b6679a11acfbb, 0xd4935f535504fdf9, 0x5231be168ef2471e,
+    0xc4167643e9fcfee3, 0x925b6fcdc23ec219, 0x1c4c391d6a2ffb91, 0xdd5ef4c994f9b63c,
+    0xa0844bdad42f33db, 0x16d7ac062ff97a39, 0x5ffdcd12ecf19520, 0x98d48d24ab8223e5,
+    0x693a5b17feb79c93, 0x03523280f525d7ba, 0x468c552c499b2b61, 0xcdc2d55b2698a370,
+    0x5e686b324f6d6e04, 0x6ac6da62f1b47f8e, 0xeb28759fb5b80f10, 0x9d18f531f0ee30a7,
+    0xc8f6875ec56bf3ae, 0x1bd1be72d59b1913, 0xc57d7f1b805e6938, 0x89265683b6361ef0,
+    0xb9d00fa4bbbbbce7, 0x60ce816edc67a8aa, 0x0a98352da152fe20, 0xf18fed24814b7ef4,
+    0x877577ed4cfa9f11, 0xc1120a9713f543f8, 0x6859261b3abe1a92, 0x2a376af205248475,
+    0xe385c73fa49bc160, 0x1dad58095a1918aa, 0xb9b11dc262b7b274, 0xf64478e864a70ac9,
+    0x389fbe3513433404, 0x8e8432f2a0370d9f, 0x73263eabcd5dbb1b, 0x608cbe274363dfd2,
+    0x1c406218bdc7dbe0, 0xadd3f4feb257bb93, 0x236a1b328e0be973, 0x2e85ccfad38a20f4,
+    0x79b3dd6a35fc3524, 0x5dee842c3e457469, 0x0df85a567a2e5052, 0x8830e27b804620b0,
+    0x477af5c0b485ee09, 0xd71e3f479160c2e4, 0xe3254da35d93f6e5, 0xbfc7108085106c2e,
+    0xf9c4d3226d0232c5, 0x651279ef5f42309b, 0x0ba9157e9175ebcd, 0x87f12f8f22a3edfc,
+    0x7765521be8532d35, 0x3495ef38f94aaff7, 0xbc24817676e30dcb, 0xec1b566b8d651a9c,
+    0x1109283d3963dfcc, 0xc2e8f5eb0d2bc657, 0xa23922af8db065b6, 0xaec8ab3eeb16b3a5,
+    0xb8d3c6af3234712c, 0x41289a07f38a3afd, 0xaba8c1514bd7a5b1, 0x77bd84f4f3380352,
+    0x8337da9da2e96785, 0xe44e329bcb9affa4, 0x637724bcd45acae9, 0x180f9ab91c2df3d4,
+    0xbb80d2cdb9a9ed67, 0x815960fd97ff9f79, 0x29984a01ed4d12de, 0xd75fe6959f9dab15,
+    0x38dc811db77df424, 0xce3f6e34946850c4, 0x40f0924fc0577f73, 0xea7b123039b4e0e4,
+    0x47b929849ae41a70, 0xdbb153e547f89d79, 0x7d0e8bb042be24e6, 0x12727c6e55ba1312,
+    0x9c827da3fc4f3311, 0xc596ec1c259e88cf, 0xc0ecce653408a510, 0x2b257cdee3b48be5,
+    0xd5e4b6a0ad96971a, 0xb6d31d0dce122b4d, 0x90a651dfaa6eca47, 0x6b19089bcb02783a,
+    0x1fe671741e035de6, 0xbe7a28b32c71eb05, 0xcd63a15950d4b36d, 0x444a48e52513e759,
+    0xa88b2ae9f845df2e, 0x29a63c71a1f8659b, 0x09a0776c1bdde601, 0x2570282ee9d4251d,
+    0xde90bd9150695e32, 0xe0f6c5ba59e6721b, 0x7bcc7bdd7f971e4c, 0xf6432102ebad2329,
+    0xed021a49ce66d337, 0x78ec16c07958ed23, 0xb9e9be82e9c3a17b, 0xc3166c9c16512573,
+    0xe81df49f07ef5064, 0xf14d3b31918d4e76, 0x4f237ef5c349413c, 0xef5ccccc8c4677e0,
+    0xff4bb947a21901f0, 0x0deb5fe3dc3753fe, 0xa676852bd105e197, 0x86baf0950acfd22a,
+    0xc2ceb8feb5cf35d6, 0x7448b549b892e2bb, 0x1fdfa3eed87af8ca, 0xe6378285fa2341dc,
+    0x4e2c125adfb826d3, 0x444c1ed81a88df45, 0xb366d63c50f1e10d, 0x457e61388367640c,
+    0xd7b93011ab04b276, 0xaf0d383f7addd9e1, 0xcad77dc02aade3ca, 0xc9abee407cf8fa71,
+    0x15441a7af9bf032f, 0xcd53533db579abf7, 0x0d31eccf4b922746, 0x1f8f47370effb44f,
+    0xc42eb191e003a755, 0xbec40282831e60b0, 0xb1d358a8c1d3568b, 0xd5d670caa6f89dbc,
+    0xb9efcaa6215a7a45, 0xc9fd902b99a491c8, 0xd5c98175d1105e53, 0x26a797c3fd038bcf,
+    0x0b5e4d7aedf21fa3, 0xc9dc0ca13df68427, 0x597e43b4075b6b8c, 0x15637d1d935acb8c,
+    0x497445d29f867550, 0x4eadb6c72c851d58, 0x56a5e08d373b6bde, 0x3ffea1ec416d7705,
+    0xf761f77dea01bdf6, 0x2a690cbd9826f2a3, 0xd7aa82d6fba059a5, 0xaab90d0a45060872,
+    0x865fa48a0d22115c, 0x131f96703b762eed, 0xf2641f730fbb06f3, 0x13778f813ba805a3,
+    0x9fc00b4f9e964ed3, 0x06b9b93c7f84e6dd, 0x79464df2a1416d66, 0xcb32c8add6728d1a,
+    0x3892e04bd12444ae, 0xdde6bb072a5b2901, 0x580abfc94a3bb658, 0x5a18de5c945091af,
+    0xfd3e6d4e08c87a20, 0x93e21ced6d3bc747, 0xa2b01fa0cc8b412d, 0x2edf8b01291435dd,
+    0x13e445b61dfa5147, 0xe430a5e0d3aafaa8, 0x705baa64508b2784, 0xbe151ae2f462a71b,
+    0x654c243b4400d4dd, 0x09aa42ea11d960cb, 0xf420402a5fe85dfc, 0x57b727beda6087b8,
+    0xbbed3fd15f61b862, 0xd3795d483e82c34d, 0x4aaeefa5d77fa246, 0x8f9408c8013cd237,
+    0x7826abde3556046e, 0xc8a235a71f77fe18, 0xabedeb1b0ee6a1a4, 0xc419c7c19b98267b,
+    0x6aa453840c1a6cae, 0xa5df243064b2456e, 0x419497f75aeb76e4, 0x4283dfdd07e3d852,
+    0x5573e4b7264bf5ba, 0x22273c0d039ced58, 0x5a8da5c00a4983fa, 0x2af899ba2b3909de,
+    0xa16fb88969a3981b, 0xf67219e89da978c0, 0x133b05b9458b1c75, 0x4ffa71f154415336,
+    0x20066ad9839850b7, 0x2c32f8763174efef, 0xa6081a054fddbd53, 0x412dd7d8e33eb486,
+    0xba54154f749c712b, 0x8bb52066c0798b8c, 0xd423741a86e94efa, 0xeca65f2154a7e394,
+    0xf7bbd119997fa7c4, 0x592187a5296ff085, 0xf5a91c2e223745d1, 0x7f4b129721b81e2a,
+    0x7604ec91afb27724, 0x85d4f37dd1013e4e, 0x24ba287c824a3ffd, 0xee58dbbcde53d52c,
+    0x1d445f80480bb921, 0xeb78e7ca2be77882, 0x40e277a48d48028a, 0xa40639f96d89fa43,
+    0xadc1e1c3cdd2f44d, 0x54f8f7992c1fd386, 0xbec7130955d93ffd, 0x8daa000f9550cfa5,
+    0xbe1c353232080cc8, 0x9e0b43257a373b34, 0x2acaa90efb35df72, 0x5ba11743a568ef9a,
+    0x0475a94bbba3fbc4, 0x23e772dbbbae194e, 0x2d2857175c64a982, 0xd70998c003b1fa12,
+    0x754c70eadf92503a, 0x811d031d0f011a06, 0x97926ecfabb03d16, 0x46eef918ddcb3702,
+    0x0c83afbd3aa6dd03, 0x38553bb5fa53b9af, 0x6d9d8e734ba387e8, 0xba0160982528f43e,
+    0x9b7cc65024beeb00, 0x61206ec832507dc6, 0xd2265122d991c4e0, 0x92dc5921dc769ea9,
+    0x16b79ab2149d9735, 0x1ea7f00278deb597, 0x0bc3a0cf9e6be908, 0xbe2b2224f98f50d9,
+    0xb2a292226c28bd73, 0x1df483e4506a9c23, 0x8e4bb65767f05856, 0x13590b3d441f8efe,
+    0x85ce4eac58a0b7c2, 0x2a25e67e8f33d9c9, 0x551861289a5c56c2, 0xac42c856b76ab85c,
+    0x828847cc8363eb21, 0x9ddfcd4bb737ae7e, 0x8c866e4e447b6c59, 0x5ee727b8e90e357c,
+    0x4770a82dfed2fc17, 0xf655fa171ebfa90f, 0xff7b3be93ccc0da2, 0x7ea384187bd7f56b,
+    0xe925bf3b4bbed931, 0x428172cd0d9f3016, 0xb9c73d7743c52780, 0x2d94ef51ac20f1a3,
+    0x4a3acaae84689dbf, 0x44acb44842a6987e, 0x78272c3c24dac6fb, 0xb573ca3dc7235b2d,
+    0x13808204c13ed6e3, 0x21b4acecb850bf91, 0x21e1552a200d1493, 0x15e5480db4315212,
+    0xb50c5e305924283d, 0x712b3c4e47038692, 0x5b11b647a26f5c2f, 0x687c3a48b7bfabbf,
+    0x253d726ecb486e3f, 0x2a32be7e18f1c65e, 0x8944d8a24476971e, 0xaa228cfd3c45d927,
+    0x4edf22c09d6ac49f, 0xc425e3d57f9fe4da, 0xfa264b05ddb01d5d, 0x44280ab6ad014125,
+    0x6d781c03047cc764, 0xec5678b56e605b00, 0x6c1a0f93971f53c6, 0x81ae1d1dc1eb921e,
+    0x67332615b843ff28, 0x2812244bd7b01347, 0x7d77b08f917ec115, 0x64eb231ca1cea872,
+    0x968c5003445303f3, 0x20ac431f7cfa4aea, 0xdca4ac0ce534d02c, 0xe975d028ba405055,
+    0x7eb197d1aabaacb0, 0x16bed5d7a6844cd9, 0x3f9281ee852d2e1b, 0x75b1293212c370c1,
+    0x74c42d2fe1247bb4, 0x81265551c85f4fb9, 0x08475966956e8be9, 0x38a9a428e22ae004,
+    0xa78c1f91c343d094, 0x74c49322541e57cd, 0xc4a12b7c08afefec, 0x19b9f215b702e263,
+    0xad7b771112cb16ec, 0x2881a5f06ebbac9c, 0x55810447cdc77b92, 0x2e034fa9b65acb69,
+    0x5c7e0d5a28e85810, 0x4631dcc051c048b7, 0x93f913debe377d27, 0x048c523b10679cb3,
+    0x36701299bcf6a357, 0x381df469e2569cf5, 0xe50fdaee5fe37b17, 0xd84119ee33f4670e,
+    0xce1258f3f6d0bb14, 0xe463bb1c2ceb3bdc, 0x2cc91ef0c2bd6705, 0xf3d2104c22f0085e,
+    0x421f9c5b172bd703, 0x09b50e2f9dc6555e, 0x9796f7356ba853da, 0x89e334adf73c2c2c,
+    0xccef1e9a36f1742a, 0x5531827a3ef2d7d2, 0x4e2fac87d34ba467, 0xa0c87a0da3d93392,
+    0xe2a7c4e848951411, 0xb14d683ab986117d, 0x6a4b2ef68cf93afa, 0x7fa6e9e192ed155e,
+    0xd9446760c9d6f390, 0xc044785ae858c767, 0x7c84acf234a4df98, 0xb493f60901d5c47c,
+    0xbf476f01fd9d263e, 0xcfd5e0c6cee8d92f, 0x671833cbfb4e3728, 0xf10df44c269d751b,
+    0x252c8e634444414f, 0x6eed4ec3496a5b80, 0x3dd447be2c7d7051, 0x34c40eead82506d8,
+    0x7f1fb5c466a2a441, 0x7c0462340a2edcbe, 0xd07b9278a20f19ac, 0x13e2f7c6b0089b06,
+    0x49dcdb57aa5305c1, 0x8043411e2c3fc278, 0x4e61fc9b27be2601, 0xa901873324218749,
+    0x043289dec3a005fe, 0x6aa94bece822313c, 0xb40a980706b82582, 0x9e94c7ef24ea82a6,
+    0x242462e424e6aff5, 0x6d3bc5d633296ce0, 0x72b3f99fc4a79c36, 0x09c5d2f3d6d35ba6,
+    0xd21591d1dd9d8418, 0x82612d40043dce65, 0x8f324a4c863f2030, 0xfb73928d3cf58111,
+    0x4acc7f776610bcc7, 0x427e27eea2f2223a, 0xd72b551f3e63d73a, 0xadf1571573940662,
+    0x139e19c69ea5bc88, 0xa2f062894b6c65e5, 0xe55bcdf6e93d124c, 0x2cf3f3ab458db2d0,
+    0x6ea99897111966bb, 0x554616524fba3c29, 0x80993b2ad52d3743, 0xdfe1bc53aa439967,
+    0xc7f7038d29c89141, 0xe5ce5e9960fd94b8, 0xdaf28ee4c2dcc98a, 0x8d7bb81a92f6e277,
+    0xd3f5c08a7978e76d, 0x1924eb5ed99089c4, 0x0c1d461caf236f72, 0xab9321cae3fb257a,
+    0x5ef3cf896c7715fc, 0x3e8f311277968ef9, 0x9523ff156eef45c8, 0x4f2356bc91a81fd0,
+    0x17d67b178fd1c940, 0x3938aaef84a9b295, 0x7b0d91121de007bc, 0xac4a3b0808e0df10,
+    0x6c743285cae05f41, 0x2e528ed272ab5ddd, 0x831959950ce75437, 0x9c54ceec3ea4a20f,
+    0x424ff6c60f18e18f, 0x78b603efc148c36f, 0x3c5f61868de72eee, 0x8ceeb65c174f870a,
+    0xfc2850c83916db90, 0x13b750996936af4d, 0xf09b59383ffbd8eb, 0x6c7d146a2a7c5ea4,
+    0x4368b4acd2c9700e, 0xfb63921e64f87f78, 0xced937f3c6e6fcc6, 0x9d5b7fceaf40cd7c,
+    0xda0fc614c9969784, 0x9fdee9e6602b335d, 0x2df6296cdeecac91, 0x08cc908f6a2daad4,
+    0xf4969a2a53ee4e0d, 0xca72d333419dac4c, 0x9c29abb27c14088b, 0x03234a228ccea2f0,
+    0xb882edbe8d13fb3c, 0x87ae44b8b265d3e9, 0x8e8de1436d8d5f38, 0xb3f218c4b25423c4,
+    0x5610251fd6b62c3e, 0xb3bcec7a65117bde, 0xb43461eae6bb2d06, 0x2ebe94e417c21074,
+    0xf51f87bfd40c0809, 0xbe9b3e241ffc7038, 0xa181666c24d5879f, 0xf35fcd7640dab3fb,
+    0x8f2f09557d9714f5, 0x527d4ee254944141, 0x4960524e630fb287, 0xc4284a63b91555a5,
+    0x7d512368622d5bc2, 0xc5d707f3e9231c5a, 0x614f9eaea9ae9aa7, 0x2661d08466063272,
+    0x85574762a5849c25, 0x3deab26929eef127, 0xa427ee59bf005617, 0x0411368ee509b60e,
+    0xa2c40840ddad18ab, 0xd5b76be6efe9cb51, 0xc245d7614c4fb949, 0xe79edb838603fc94,
+    0xb876897ae42fe0d3, 0xf953fc54f7f0079d, 0x1b3bc3a4a252f4e4, 0x0674613181ac939c,
+    0x62719c7083cf8a9f, 0xdf957b85e6133b2c, 0xf8c1402f23fa4047, 0xf81f901fdd21bd82,
+    0x7cc7b2641d4203c3, 0x0ad3decac24e70f6, 0xa460e96a34ee12e6, 0xc33c1c1d99d8bf64,
+    0xcda9e8ecc7215233, 0x3c33f27398211d99, 0x027bea4a4293539f, 0x622ebe0d9498d234,
+    0xffb97696e2cc6e19, 0x2646d85cc60a6e25, 0xa727e62f562e1348, 0x7a19488c5b5134c2,
+    0x06288f355d0c19a9, 0x2fb125f554042ba6, 0x167c5756d6c2f38c, 0x2f7d66d8b6cc6d4f,
+    0x056728ab8664e27e, 0x27e3500341fcbf8a, 0x70c142a9d6778f49, 0xe163937d9bae3ef2,
+    0xcc70816b640b9136, 0x103d1d1017f77171, 0xcc652252685c4cf2, 0xf93937841fc4a9f6,
+    0x1ffe0263ae3aaad0, 0x2e72a0d8c82f4c07, 0xbb1f569d8cc6c840, 0xb2b0e52bf89cb8ef,
+    0x26de17e32e4d03f6, 0x3cf6ab55da2f505e, 0xc30ec9f27ac8e10b, 0x287639d499d70a0e,
+    0xb00d4838387473ab, 0x59cf3737df15dafc, 0xb8fdc15781c13905, 0x12dacabaddede01f,
+    0xbab844f57c144d3f, 0x3c8a9a2403724894, 0xacaa742dded0f83f, 0xe2bd90cd64201b6d,
+    0x96f3e477b0402327, 0x8c62e26510f57fa5, 0x29436faa4afd3811, 0x8e744912fad2f0ab,
+    0xdc20ba5d6b69d2d9, 0x42eb896192af9464, 0x4559ab47c58ce4d0, 0xcde59ad834581bdf,
+    0xd0d9a5f31743bbf0, 0x44680f5ef4fc221d, 0x38770588097433af, 0xd85b7e2037b43e43,
+    0xbb7078efb05ef894, 0x390a0c0db7a8f7b8, 0xed3677b07be0f4c8, 0xaa84f2f665abb936,
+    0x1354f640311c067f, 0xd1603fd111f1417f, 0xe1cbb4c07153b091, 0x2a04b148497ed2ce,
+    0x73c70f9fb95694f2, 0xbafad191540ca4fc, 0xa7a3ea9e0d2951d3, 0x79d9eba19641bf64,
+    0x23c2d5074a627450, 0xb619bc00ef2ab2c6, 0x6eb71d1632111e65, 0xe6a2a04274d4e7ee,
+    0xf3d6e1574a4ef98e, 0x66226124b4b22bed, 0x01bdf752475bcb1a, 0xda25c0346a97c34f,
+    0x363a21f110d0ba83, 0x18fe0dadd38e4d47, 0x5a01baff03a1d0e3, 0xf5e105a7435b0070,
+    0x8eecf11b56ad6436, 0x1d85c5480d6ece03, 0x091a070c1b5c1350, 0xec768016b9d41450,
+    0xfb66955b9eab9d4e, 0x8fd8533ffce41e03, 0xe5c7b1150d068aae, 0x4d6ae6bb7a3096be,
+    0x984380a1da9ebf7f, 0xfdb20426f3b1a13b, 0x485182c9b8fea8e4, 0x28e74f84297c2278,
+    0xf93265f1f3d9028c, 0x053c46330cde4c23, 0x5e7fcee29298be5a, 0xff6d4c09ebee944f,
+    0x34b0bdad6a8a4472, 0x52cf13db9f8b7650, 0x773e525c4b5b27ad, 0xaa0dbd7cedf2da48,
+    0x81f558def96ff573, 0xcf8c3e596b49b1de, 0x3527465e88cebf10, 0xcb3b24e8101f29b4,
+    0xfd86b2fe67605a99, 0x17e0f6e8b4f9fee6, 0xb2d061f551488942, 0x1eec07eafb1947bf,
+    0xf73c2c5e3b94b08a, 0xb5634cddbda71676, 0xb587d698b98d5d50, 0x4bb080684e4668a5,
+    0xe9e4138548b9dfda, 0x3eee6c5d05478b83, 0x34fb0e068f495401, 0x236e2845c624c995,
+    0x3ccb81dd6d339049, 0x0b5262e1fb17e4c9, 0x5fc89945c639ca54, 0xbefc6aec923b7961,
+    0x0453e00cb4bc73f0, 0x5b26767d368e3ae3, 0x630ef49646b7552a, 0x7e509a5a9ef871c4,
+    0x13ef2b3314f4d49e, 0x58830f06af644621, 0x6fb9401b866abb4b, 0xf85f78d58293e865,
+    0xfb4d777c8867a56f, 0x0bd818a37ae94056, 0x44a696077ca3655d, 0x420beb0fa762e2e0,
+    0xa2acda26013b2142, 0x623f59e59fdc48f5, 0x0e471651cbfccfc4, 0x2418c913ba0bf5d9,
+    0x572833f01260cb5d, 0x8d573f8a4d2cbac3, 0x1e1cf4020df321ac, 0x7ffeddc759545d0f,
+    0xf1ad0fa6c75a2c18, 0xbd14bfc3b28c0ca8, 0x72fc623fb62ea5d8, 0xcd2c94e1f8b0c156,
+    0x06974361d738b21e, 0x906051cd15615a52, 0x0d1a5a94da91d88b, 0xd7e76635442cf7da,
+    0xa7cedc529cb458c0, 0xa2aaa3193ce593cc, 0xd9515d86e548c2c4, 0xce4386d0c348acee,
+    0xbbcea16d641ea459, 0x36e14c1a446d4067, 0xfb5c11bbbebd2889, 0x0bdbdb019702aaf0,
+    0x382d2790a8eb2dd9, 0x802975305e9ae9f7, 0xdb14b2386b4b552a, 0x314454fd13f67b56,
+    0x1fb515729bd47d5a, 0xcf69eec9ab6ed451, 0x3aa42fef37252028, 0xc19932fe385ec812,
+    0x759ebd3c5bee11ae, 0xc3e08275dd274f41, 0xc4d8a66529c2e996, 0x77755fd30972f734,
+    0xe067367efe255520, 0x4ce3adad39fbb088, 0x2ae2e6315609c789, 0x714b34fd1e7cc831,
+    0xf46229ed476b80c4, 0x49556436f67085e3, 0x3ee533a446992875, 0xb2a02779e625366f,
+    0x9f00ec088b0b0674, 0x5fdf35909770a4b8, 0x204d648e2eeb4491, 0xb2f8810e445b769c,
+    0xec10353c7dea8690, 0xbcc429852705cad7, 0x6123c66a02ab2b58, 0x2ebbb6f2f01d68a1,
+    0x8ed8cd6eed3e474f, 0xe2d746e0cd048190, 0xe14735cb45d294df, 0x3da05a07e44b9957,
+    0x00ed8a305b877471, 0xc561f665793fc89b, 0x0cb319473b0cabac, 0x7decf43f74f2e734,
+    0x6339d6895f956a6f, 0x18fefa334d053bbf, 0x6ada51d95396dacc, 0xf90cfcf35a03ba98,
+    0x26bd5a4a7c7452e3, 0x5a7a3dcb7394f50d, 0x1a0f5c16471861ae, 0xd4ecda592d89ca6d,
+    0x6676173b30c3ebe6, 0xdd41619cc87c4607, 0xa904bb2b3c77e520, 0xc73a878f11486273,
+    0x4de337b2bad3a1eb, 0x4dc93fafd636bc0f, 0x58e754b64ea1e08a, 0x2bb53f33ba57fb56,
+    0x67272be31d7bca6a, 0x5c4974fed5fa2e54, 0x5949e1d5bb3015be, 0x2e3009d1833bbf30,
+    0x69649cc38c3e28dc, 0xd08e9e2f7c4129bd, 0xc2eafde9f6313e8a, 0x357d53c5204fdb2c,
+    0xccfa53241c67dff5, 0xf9ac787670bc851a, 0x4cd351e048804fd0, 0xad249965961c8703,
+    0x5464feb46c660e10, 0xcac62bed40526473, 0xa0277d950f0d169a, 0xafaf1b31e9ebc028,
+    0x6bedcbbf034a5eb9, 0x74cae94aac6b72ea, 0xed50dfcb26253c86, 0x58113f9d2ecd5863,
+    0xe04782ea8b3fbcb9, 0x646fc79ffd68db5e, 0x6bc535a258cb8c2b, 0xa55dee12b43961d0,
+    0x61f8c875c4a7276a, 0x6a0acc176d8a273a, 0x630329e075e80ad0, 0x6c977b74b85f8e85,
+    0xf7e6c8604ef9be6c, 0x2832ea5a2ec1d913, 0xcc1b15474b3f26d6, 0xe75320b37d850092,
+    0xcf952e042d2c59db, 0xe2a67a3058b65dfc, 0xbbc06a9f3e24bd96, 0x6ab87e2afb6ac967,
+    0x47155203decbc920, 0x09d7c71ea3eaff76, 0x82b6fc407bebfd7d, 0x2cb0904e0257afcc,
+    0x1d521cd9bb670d27, 0x34d40cdd82b5e43f, 0xa7cf5d191b4b5656, 0x223fc8b3f98ac53e,
+    0x34328ddedf9281c4, 0xd233394b092580f4, 0x56a46e62bf9bc9e4, 0x8d713d80086019de,
+    0x2e38beb062d2590a, 0x530651d35efa19b4, 0xee1e54c310e9dfb1, 0xd1cba1a18a6fa36b,
+    0x922cd926ec660faa, 0x44edbe03c0efcb57, 0xf23a43933966557d, 0xb1fb1eb206491ab1,
+    0x230cfb68f21c7d91, 0x597e30efb9ec5ed8, 0x2bb9421a058878cd, 0x23ba68c6849d3213,
+    0xbaf17941ea7da3ba, 0x42cc5b5fe91f8ec3, 0x965c93163b202f92, 0x41b32e0082e33062,
+    0xa176281622316f19, 0x6e668b3a73bdcafc, 0xb0dccc759a176731, 0x5ab92af1074fcd90,
+    0xc6d7ee2819cc7b6f, 0xe4d2631fd228c846, 0xb206b5c562beb6d5, 0xb066fa9827b43248,
+    0xd2abb090562f91d8, 0x67ed9ff728a81225, 0xfae1f04aa6a4dda9, 0x4e0b24337fd018d2,
+    0x48b3e30bdd3d845c, 0x24d33e4774cdd5e5, 0xf45b0aa5664e1d7d, 0xaf9c386c5bac9a06,
+    0x352534aa348f7ebf, 0x5adc8e944874be69, 0x22754aa5d07f196f, 0x03b4407ee8c79f55,
+    0xb7fef5e961620d70, 0xc505065f0970ccdd, 0x080cbd1d1dea4aab, 0x5128022fba6594be,
+    0x43b2badf1feea5f3, 0xe5bfbe003b1479ce, 0x245aad9896521ed8, 0xeeafcddaf9d267eb,
+    0x830b5015c23f6425, 0x85986d431826bbac, 0x3f5ceaeaec44ed34, 0xb8199cb27bc6c193,
+    0x22b7bd6cf2e83ed4, 0x1658f9ebf40b23aa, 0x3746e0de69dea26e, 0x6d33a0693fec5307,
+    0x55d7287ca718b734, 0xe4fcdc364035d1f3, 0xf72dd470ff12ffa1, 0x5bbc7d24b1bdc905,
+    0x6ea6f959fe1421d3, 0x7b019faab6a49bcc, 0xbb87939450f30f39, 0xf70ede97ef97c321,
+    0x15844862951d70fa, 0x2cc9c94d3397b419, 0xebb8ad84c09c9557, 0x077dfdd836987a22,
+    0x12707e3e9bc3441d, 0xa3427b8ba2244522, 0xd5b17c0f089b766c, 0xba6bd4a0d94790ef,
+    0x5fad57aa20feb760, 0x635481711e995470, 0x0fac2da997b558b7, 0x811f70a3fa1e0cb1,
+    0xfa60bf7b7a4afa4b, 0x94c6d7774be562e6, 0xc5c07da648da3812, 0x22abfd7389c0cfc0,
+    0xb0cb319591bf9cda, 0x9e868c49e75b4d93, 0xac5772fd60f91032, 0x0323781cb11412e7,
+    0x615d959d93b9d2d4, 0x8217e1d723ddcd8b, 0xc1d550072e4d1f82, 0x48dc0b5d82206383,
+    0xc28953471adeff8d, 0xc12de58301aed586, 0x6f6d8bfca49f5cac, 0x00232bdb1b206ea3,
+    0x348c702bcda331b9, 0xb82cf6d712278639, 0xfa48eb45f7293882, 0x2d4e7c3d76692aea,
+    0xcd5e137c424f571b, 0x02839cb8e4dc88f8, 0x55b95aba532cbd2a, 0x0a4b2a84b5b103ce,
+    0x8c663c85231f2cb7, 0xfcf06ef7c1e8b62c, 0x9fb8897e2d4a7bd9, 0x3b4d62e5ca2fcd76,
+    0x124cd063bba469e5, 0x5780311de0c797c3, 0x0eb1819ee498133d, 0x86f01a20f8010ea0,
+    0xbd4c9ed0c6d1d36c, 0xb3600bd3e57d69b4, 0x7aa380677461cc6f, 0x05486808baa957c3,
+    0xec68fddfff1171e9, 0x09b5dbb452cb0287, 0x65a4598ac8c97195, 0xcdca7f2afc10c847,
+    0x8b395ff32717ad7f, 0x117e5f62d8a03572, 0x1bdd06ee9bbfbc53, 0xf703bb1391056423,
+    0x1cd6e18c7cd9b0e1, 0x4e82624234bd4fc7, 0xb7f3460d8e5dbff2, 0xb7e64878e927fbd8,
+    0xf9df6b6507541643, 0x067db642d28367a1, 0xb59e70bd74485bef, 0xf5e25eb7f7012942,
+    0x43b7a1c614013126, 0xa1466f79fa67c598, 0x1bc2cbdba35fb951, 0x44016f11b4427a7b,
+    0xc0cbeda9f9b791a1, 0x762ec00fdc2d35db, 0x7943228fc04883aa, 0xc3563d9ca3e44e38,
+    0x97d04faa6bf3757e, 0x05d0098f383585db, 0xe47a80d9006ccf5a, 0xc4dd3eb36d2b562c,
+    0xa2dbfcf8a9c8b334, 0x2106b3ffeacbe453, 0x21eb43b8bb53166f, 0xaacab84a806be2f9,
+    0x3b451b002bf89d55, 0xd857367ca771b8c8, 0x1da78db367fd7f1c, 0x42dea822b50f2fde,
+    0x0d780aefac413cc4, 0xe4caccc10b417f67, 0xe0ab59f6db616c0b, 0x79882b617c972fcd,
+    0x0e1aca9db6f18cad, 0xdb7215e2ea575dde, 0x3eadfe3d7552c523, 0x95645cf6a2151765,
+    0xe6036c747d37fbc5, 0xbe2c20eeca630a71, 0xe9c8eac7d29bbfac, 0x2d5fdb207e5bee95,
+    0xb3ffba1620bd1b2b, 0x99a60b3eeafc5857, 0xcc215f06941b4bdf, 0x9d63af37eedcf48f,
+    0x8999255cbc1ce922, 0xb58747f59b90e95c, 0xd74559f15c236af4, 0x9453d9859773981f,
+    0x00c650ff284d8a2e, 0xbb80b92d254203fb, 0x9309343ad7a09bfc, 0x61435b4480ba8e2e,
+    0xbb92472d3b1c6cef, 0x18844891713a7f46, 0x3769b930bd2ea30b, 0x97dc63f23a226b49,
+    0x07961fa0515f3e63, 0x59110f3d2aaee34a, 0x9dcff4fd3c6ce04e, 0xb83f40ad12cd1714,
+    0x9fd9b9fdf9da854c, 0x577ac7673c119819, 0x45588b99e9280463, 0xfc2f047eb0a2d2b2,
+    0x7ceb8c86ec32d8aa, 0x9a3aca637c42572d, 0x777fd2b26bba46df, 0xf436c187aad12fd2,
+    0x0cb66a5e6d25df2e, 0x21fe70df8d663818, 0x68f9098147c1363d, 0x38b01bbbdbba4f3b,
+    0xfc8ad0e624440f4b, 0xa84ec7d040c3d755, 0x5f47726e031677b4, 0x04a36a2547d6dde4,
+    0x29f2fd2a71131756, 0x57bef263912ce9bf, 0xf83818e409186d8d, 0xebcda7654a4917bf,
+    0x2b382a500bf439ee, 0x24d49625f3101aec, 0xdcd90ed8688da6dd, 0x2e000c27f854894e,
+    0x472b71e07723d877, 0xc90a8dbaaa32eb07, 0xedd76f60111f67c5, 0x669d8458348065b4,
+    0x5e9afefc06f1bbe8, 0xd0d2c552c745a654, 0x37fcc135d2f742b3, 0x633e9f078386be10,
+    0x6db86cecb4b20649, 0xd6f9bd6d9b992747, 0xfd89543776695804, 0xc56b49b24e46db14,
+    0xd983a86b8bb56678, 0xfcb9426eb14a36e2, 0xe1b0ddf1023fddf6, 0x727f21b9a12750b6,
+    0x56acf2d8febb3e30, 0xb6e245cc8bfca526, 0x37fe9f620c16d615, 0xca7129e0467a4cb9,
+    0xe5550429b4af80b1, 0x5c14ecdc290e38b0, 0x3ecea647e5ee12b6, 0xc07c4ceba7e72486,
+    0x02dd9c4a42ff680a, 0x69ba6b8b25f09d67, 0xc0e4919fccac954e, 0x41a71b8498359705,
+    0xdaf339537211e4cb, 0xdb243d85c7d326fd, 0x80cdd5558987e558, 0xcd33dae8046d5253,
+    0xfe08b4321a13a100, 0xf285b682c82ecc63, 0x2a83def0262ce678, 0xd14059f0b7386499,
+    0x946d4d68e2ff1d2e, 0xbce63318d518167c, 0xd91665a81eaa8ecd, 0xcf0f695fd2821c60,
+    0x88ec055754e8f6ba, 0x4323fc001d286f9a, 0x992656df1bec9ea5, 0x52fffdef332f5c6c,
+    0x77bd7f4561e9a549, 0x385c01d6e6d0b025, 0xa1f1cf9bd0d6224d, 0x0dbff6570b5c730c,
+    0x28dfcc8ff34d3d3c, 0x3d2f3cdb0a1edf0c, 0xdf54e326344190d7, 0x8236a0dc03563a02,
+    0x4cbe1da6cd4fde0e, 0x28206f94fa4c573e, 0x7425579789b432b0, 0xe4649636bb80fed5,
+    0x2c6cea81d0188d45, 0xc187715f9ff1376f, 0xae1dc28f6bab07cf, 0x544cea057623ad6b,
+    0x91da40e9c3b2c7cc, 0x9de6e5ea2f3fef49, 0xdeb6fd5f84b1e665, 0x82003fe995e384d0,
+    0x3464dec9f9e91bea, 0xee3f45bd3fc1dbf3, 0x31733457a3ea586f, 0xaff66540193c2208,
+    0x661bfe663705bf04, 0x413f9d70515c85fe, 0x21c2ad677b9ed7b8, 0x204091023b51b75c,
+    0x1ad6594483bd9e17, 0xbe53c45c87e8aef9, 0xc3c3fed4072aa8e3, 0x7a7dc3d9494b3029,
+    0x32258f12971f8d72, 0xf8eccaf2a23f95d8, 0x5f075b5e6a1026de, 0x10dd04b6004274d0,
+    0x0f60b4d31fca6575, 0x53fedd95903ea191, 0x5cd646e81d950002, 0xd9e872c192fa4a00,
+    0x4dfeaa51e838259c, 0xd9207ea9685b1199, 0xa79671916c48bbcc, 0x646956121262b656,
+    0x4d5557df8e136150, 0x2bdb999d1b979eb9, 0x829f2d97c24bde83, 0xe33c6e852999b459,
+    0x774e63588e07a915, 0xd79aab88d1fa4f7b, 0xf6bb9f55f3a4b833, 0x9098bb782cbc6bbf,
+    0x8d7defbc3d5cd7cb, 0xa7ba312ae0c8cf00, 0x3e64095cb53963f3, 0xa2e25fbcd67c2343,
+    0x89702670f4c1f8e8, 0x3dc50df8ab89daad, 0xee9c62a162300195, 0x3e4bf1182a12a19e,
+    0xf3ce4d17c4a19aad, 0x4fa14c3ccc63a258, 0x256467ed3999f19e, 0x5a6d2b084280edbe,
+    0x1dba2183f44299ef, 0x19a327188ee0a785, 0x38d7045a176e5cfb, 0xb46770536357da46,
+    0xf6b9bed1e7eef7b7, 0x21fb63b4ee7fc2a7, 0xc5a9e25a2565eda8, 0xcfa2b030c3a39fb8,
+    0xb0b42fd4dae17856, 0xbd89659f78ac2708, 0x3764594dee9fbfdf, 0xf7e18f6a2ebd1717,
+    0x436219322da01f2b, 0x0311ce4495e504f2, 0x9c1f8714f0c331d5, 0xc80ff29c2f65ffd5,
+    0xe55a3c25ba4b3554, 0xcf7e26ad6d5a7538, 0xe70b3d99e4cab93d, 0xc11f67cfebce6355,
+    0x0c8e5a9a331cf284, 0x749cdba9344e035f, 0x5f2e45e0cbb7c1fe, 0x007f277a0ee5c025,
+    0xa2f4f83e55ca6070, 0xe66f085a4bae09b5, 0x32991d725d030b09, 0xa7d714a8ba137223,
+    0x9439b8c0b9ee8f3b, 0xc1771a6bdbb7468d, 0x49eb7f5020c034d3, 0xc4e4e95c8e0c3027,
+    0x3e558040f09cccf6, 0xe4cf40362626cb17, 0xd788fb0abe36cd89, 0x70d886cdc3fb9c00,
+    0x28104daab9b91184, 0x5dec1050abf77a32, 0x46f8bb221225af96, 0xda623622528dd88e,
+    0xff5822caf205309e, 0x72b53f41e72da60b, 0xe7aff00ef97fde39, 0x46737d9aed7b9f6c,
+    0x081ded672838a748, 0x78a453b190ad3662, 0x00c5a4fea34674ee, 0xa0e6c0839031ac29,
+    0x5cd0e48b705f96b3, 0xe8214407798318b9, 0x57af48d4560d3b5b, 0x7198536417100c52,
+    0xfbbc278bed5c2427, 0xd0a63d4827e91421, 0xb2b466b20fba4968, 0x6cf39ac1662d475b,
+    0x31bc1718be0aa11f, 0xdcea2d0dd4d5f1c6, 0x2a9b7507a957592d, 0xed7f337140f3cb0e,
+    0x3c2adcb26a8ebae3, 0xbcf26a21ea43f582, 0x082b30182e7f5203, 0x18adfb8bf712d13e,
+    0x00c9d91d07336939, 0xc59a788ac25a5000, 0x0a7fcef6f4642f6b, 0x37418487e08f9411,
+    0xf9f9a300b873311f, 0xb0955c317b16af8a, 0x3c6f7ea4550e0896, 0x6df65549c5e1272a,
+    0x2d6c2c9ba0680827, 0xe78538acbe1a02f2, 0x3e1e88851bf4a160, 0x3ab7f9a847f82142,
+    0xaca38306a5c0c3fd, 0xfc604d89ed558680, 0xcad99fe8e1e4e21c, 0x4b762398bd5095f4,
+    0xde4189768f202935, 0xc5e991a53115ceb3, 0x05c5757c47d8024e, 0xfd315d2b396586d2,
+    0x9067b6aed66e39eb, 0x73fcf33e1859d400, 0x2a072732d040492f, 0x3b196211df176fbe,
+    0x431bed6159bef5a6, 0xc00199def39e0059, 0x957e6a684bcc7bbe, 0xfe76b6daf067475e,
+    0xf1f9b7611613b2e2, 0x1474b8525b2c06c8, 0xc6c54429ab39391e, 0x1d13dea6625648c9,
+    0x57ce1456d7b14a43, 0xcfdc4a1ef6472a06, 0x38c22e4484d021ad, 0x788c301810a09430,
+    0x981c1c66460ea7ca, 0xdcc284a2b07c26f1, 0x3b79b288e0a079e3, 0x7b955b4480dfc395,
+    0xc9c661f43b2760f6, 0x78f013521042d90e, 0x211183dca2aceff9, 0xa0e2e854c999c3b9,
+    0x929db0d92e8bbe9c, 0xa2ae54eb08427d43, 0x1a20fbc79ee3f9d6, 0x8d74d84dfb3d7043,
+    0xb2affee0be56ac93, 0x086149118a527464, 0xba4235ccdf8821d7, 0x1dd60135883da0ed,
+    0x09e547b83afbdd9c, 0x3b5d76d0e80635fe, 0x0bcfd033c42a8105, 0xf556c4250d89f79c,
+    0xf3b816a819dd6ea6, 0x60dd5882e5b35f09, 0x9e8bd84bac915b0b, 0x58a6a056c919af6c,
+    0x099dcd9089804c3f, 0x1f93709d24cf024a, 0x32e6a70d1fabd60f, 0xb771b535e4984b30,
+    0x2a1e3dae722fbddd, 0x1e7a7a0b448f6edf, 0x894f8efa91a91c4f, 0xf65792bfb363c186,
+    0x9f4c5db901ed02bb, 0x48bfc192b46ea408, 0x25f3e1fea676ed25, 0xd8c960c8999dc9ce,
+    0xb6f29d6c07851361, 0xd47eb484bfd0e440, 0xdf5426f27974220a, 0x9d0675c63d5c787c,
+    0xff01dcdcad9b34c6, 0xa055cfec13c9976e, 0x1bff64712da64a95, 0xced0a656b4db33ee,
+    0x5e6ad8f083e12f94, 0x30021cb8fdb6fbb2, 0xe2cdc335450a27b7, 0x4126c1e81ddde0d1,
+    0xabfee7648dbfd269, 0xa73e08207e619c94, 0xb417d52f4d88c39c, 0x537e23e559d60c87,
+    0x56b0ea5e210ed51c, 0x043c1b2e30e4ff9e, 0x9df1f2c4547160a9, 0x32efe1defc2da78f,
+    0x1dd2fa5acd239537, 0x4503ec8fbae2889c, 0xe0539cc2b03cdb82, 0x8d9798d1a2968d0e,
+    0xf8031ddf048c14f9, 0xab77bf0fa0a103ce, 0xbfee003afd396f69, 0x35f3120b4a2e53ab,
+    0x3315f1d14c1bab1b, 0x07a70ade687200c2, 0x68ada90ebda65cb2, 0xed525c51fd47ace3,
+    0x758d313c8e7c0f83, 0x1f6b1fb2732f86d5, 0x0d95d474df4ef9a0, 0x8b3a346df98429b9,
+    0x582d49d35222456f, 0xd11f5bcee30f74b4, 0x59f3b53c4b7ba7e7, 0x464e356224fc833c,
+    0xc6884889a75f817f, 0x4138e62319203cf8, 0xb34612e493227b49, 0xf34a0289f82cbf47,
+    0x09d89797e5dab559, 0xa57bf597ad99256e, 0xc42bd1e2193ff9ab, 0x7103e4e863e48bb5,
+    0x348e8e869d42157a, 0xe521f20ca937e8d1, 0x0315518887092707, 0x40e04c6992fb9171,
+    0xddad5dd5bb8fbbbe, 0x9bd53790925d5ee1, 0x916553fa15ad065a, 0xb4f0228b96177b4f,
+    0x5dfba6170f0a07c6, 0xce02664217711bde, 0xa2c20f23ac92efa8, 0x449126a06a6bddba,
+    0x5ec9d5aa30aedfc8, 0x79e95b7473279aff, 0xffa347f06684766e, 0x427132a909e57e78,
+    0xb953fbbcffdadeb7, 0xbc7dceea1ef74125, 0xdeb942937dd343dd, 0xe00de77bff0b189a,
+    0xbfc40d072776c951, 0xb7cb18ea7ca42c73, 0xa732373477356819, 0xf6ebb3e6b8c7ca32,
+    0xd3c2cd5d5fb92718, 0x0b5b072bf63ffc0f, 0xedfa028d0aeec073, 0x1ee3971d4cc8247d,
+    0xf7bae7f2676bf4e0, 0x9866c9248a7797ae, 0xd2d48b1055275f5e, 0x4651160b9afa1f68,
+    0xdff79f35f2dd902c, 0x838f51b2f3512068, 0xc7f6858171c4d262, 0xe3d0794695ae96a0,
+    0xf28e36498dbf109e, 0xf87b6ac25c82d3ac, 0xef7188065d9dead0, 0xa65d3b3f02b1eb91,
+    0xba4508404d59af1c, 0x2d586f358c23fb0d, 0xe4763ef0e261e65e, 0xb08c19e4c89b03b9,
+    0xe143f3101c34dd0e, 0xb0c7e261bba98e61, 0xae1cd3ecb12f6fe5, 0x37c2772c28826143,
+    0x41cd938aac7fe85f, 0xcea184a2d8299e92, 0x4c2f63d14e4ae772, 0xd08dfb2fe5b30446,
+    0xd178661088f631c4, 0x37e77ff3b5dbb274, 0x8700f6e987334f08, 0x34346922eb1df571,
+    0x02e0dc2673015c2a, 0xc6fae68f10855d38, 0xb917295f093026d6, 0x31fd326bedc46fb2,
+    0x755c976dad7a2e13, 0x525a3b44a8c64767, 0xc7505c511c7fb61c, 0xa57dbd29a95b1cd9,
+    0x389620a3af97f043, 0xe09f9dc39dc962f0, 0x71822d1126f89900, 0x6940546861f83875,
+    0xec721618230da2c6, 0xc1d7680ef5446431, 0xb526ebf0b20f6a56, 0x5caead7df57fb803,
+    0x6d79349856923826, 0x3b200d937edf8479, 0xcd686165f82a9d1b, 0x7ac57a5e00b2207e,
+    0x895f3c14d356ed29, 0x15fd00fd0559ea20, 0x17e5fa93d0c5f35b, 0xb6f7b2be222e5d38,
+    0x9412b4f08cc63054, 0x56275debd99d8735, 0x129a34c59b64c91b, 0x65a6890de1e3c21c,
+    0x3499c772bbfd7455, 0x146119dad094834b, 0x432a3c23cb73d590, 0xf183fb596298da4a,
+    0xb2244cc7416c85d0, 0x5a3a8ba7635a91bf, 0xe4ab644434ae74eb, 0xa225349887d0d861,
+    0x475611752df13b67, 0x8d46302d2dd814e8, 0x3edd46b0360e2ff4, 0x2d4de6fd771c1c57,
+    0x1f6222f778160a98, 0x27a8940d8a58e284, 0xfe9dc7f293c61aa8, 0xec7f5a748bab065c,
+    0x38de0e0dfcb30261, 0xe6080719e15ab36f, 0xf39956a172ea71b6, 0x3ab03fec58cc6c94,
+    0xdffcfa1473a58b20, 0xb12d409ac3642bd2, 0x0a4a40a61f7a9b91, 0x42ef4f8ed3017de7,
+    0x90a7e075277a1dc8, 0x4e06b3e327df83a9, 0xccdc06df80740970, 0xbc2f9606b59f30fe,
+    0x2f0bc87781e05aff, 0x9556468b609fd7d5, 0xe6bc7e3d48ffd7cf, 0x34719d803c360341,
+    0x0ad7a8c1dd49f20d, 0xc270c28d993da91e, 0x3ddf8748b32927af, 0x2b704ffa048a6c37,
+    0x83097f7dc59ddd64, 0x018f70ea404145cc, 0x83b6d318926cfa64, 0x5a804b3e715991a7,
+    0xa6b4d81e88543c31, 0x8793dc401f4a85cf, 0xc9206a0497bccbd7, 0x7ff3febd9be46f5e,
+    0x4085783d3c58a403, 0xdb1b75672393104b, 0x77f6dec71cb957be, 0xb8ee8af19fd6b5b3,
+    0xe9c44a6aa84a654e, 0xdd4426b59d02b8af, 0x348961bfd607db82, 0xcb941e1c9ec048de,
+    0xf4d9ea29c1640726, 0xb996727d5a7e184f, 0xdc63c1c6e2e86ab1, 0x62fb7c8c3a5991dd,
+    0xda192bdaa4466ce1, 0xf10b1169c8d90a9b, 0xb19ecfc5fac49ac5, 0xc1ec3ac1b11e648f,
+    0x3020c76c7255257c, 0xc911c45ebf61adbc, 0x1e327587e59d43e9, 0x2187b5f58ce226b8,
+    0x17eea3c84caedb8c, 0xd90c9eeb80e66ddc, 0x182ef625333c74a9, 0xda437b0d8aff2725,
+    0x3b5483b7df5b6371, 0x75d3bd46cf09264a, 0x0330e5c8d4243cbb, 0x6eedbd49e920eb5a,
+    0x70ed3ece7c29a38f, 0x4fda64ab8cb38cf5, 0x153ea5504a869ccc, 0x2ac897b1098c2b8c,
+    0x65b468d850cb39a6, 0x6053e8c0277f3158, 0x2ad331f2b9231642, 0x21d53ffb431f4c1e,
+    0x0ae096a24ec771de, 0x3a092be796a62322, 0x86cac46e35b806f8, 0x02a7167020c45705,
+    0x53e8122dbff49d50, 0x02bd8fae71f369a4, 0xf577a6f761e9f5f0, 0xa3c600fc377d84b8,
+    0x42b840b3077eef7e, 0x314789920587d9c2, 0x04b0b34e774cb682, 0x165c36d150dcfb8c,
+    0x11a155fed5921038, 0xe10d60a7c81f9559, 0x61fca62201dd5e35, 0x1c3b39627e8ec2b6,
+    0xb083feefddd723d5, 0x156d54d2b0ebad66, 0x9622d77a76c88e63, 0x52d0f60d7e80acfa,
+    0xb41afb278a14bd56, 0x87e55eab89687dc0, 0x3fbf6805ebbc992f, 0xc97846685b1a2214,
+    0x68b589c32de30215, 0x421519ac5ab69023, 0x197f1340decd4f76, 0x351adfb6f6aa5ec5,
+    0xff963392e16be6f8, 0x4b065dd156308f9f, 0xaf1361481f08a639, 0xe385d26919c5c8ac,
+    0xf2618c71dab9a145, 0x84431ca983745cbe, 0x4cce4e45cc897789, 0x67f97afcf254da9f,
+    0x1d30fdfbafd6027f, 0x6db41969831de592, 0x2e4258c5b1f960e7, 0xaa140ee853f649e3,
+    0xff303a3cda09c1ad, 0x047e1bb44d3427b0, 0x3812adcaf898101a, 0xa302682f18f8a57b,
+    0xbe81187bc4e03fa5, 0x0c3b1be8c599bdf4, 0xae6e6026afd89d70, 0x61e828187b0ef7fb,
+    0x9491cf9ba8c3e6f9, 0xd04dbe366330d306, 0x058ff11bd24ce80f, 0x0b2a1839755cb9d6,
+    0x9950b7a3df7ce8dd, 0x5e599f75bc4db38f, 0x850e4ec5bce5b59e, 0x23d359425cb50f9d,
+    0x62bf02cc08f089fa, 0x68088303356b4304, 0x0dbe45d141559bd2, 0x60af259e75427ead,
+    0xf5d37eae66204557, 0xc079c49443d9254b, 0xd4c335a9dab8febe, 0xf6d46e679a820db3,
+    0xccf26e060d518885, 0x39f8f98af79dbbae, 0x90c4ebb128961f5f, 0x3679ed343dba31b2,
+    0xc3ecfc2025fe2491, 0xe4baeaa85e739d5b, 0x06c8c6a0b5977c89, 0x4feadad3ea501b57,
+    0x9007f6288b10af47, 0x0cb0dfd96b0f6ec7, 0x2fe58ece62ffa2eb, 0x755367e72c946c34,
+    0x8a252f33fccfd1c5, 0x4f38c479935dfe86, 0xe7c94c843a473fd8, 0x03d4bd053d142519,
+    0xc65b78a4de49d591, 0x0512d5e0e1a023e5, 0xba561b63184386e3, 0x57835f26a943c5e4,
+    0xcc48135cb18aa702, 0xecb9b9f0e1ae00d8, 0xd85c072b26d4648f, 0x1486b43e0ede0f37,
+    0xf5da5d5b302583bd, 0x5d3c8ac584e9af7e, 0x1d6eb70bd313a465, 0x020670407e6e7cb7,
+    0xef306e5eef94a135, 0x15b025bd593c7416, 0xe2e424f7685f3d85, 0x88b7e7a0d8c56ffc,
+    0x4d21b57c22df7665, 0x5051827c0fb42160, 0x3e40590bff248f56, 0xcb9b09e3b627277a,
+    0x6634383134fc465d, 0xd6e13dfa30d85c6f, 0x64f690000ab8ebce, 0xa53b513eeaf5e76d,
+    0xf3d3207b35d817e4, 0xe5761900ce381838, 0x9b44832e383307f7, 0x9d1084edc73c2c45,
+    0xed589b64fd3b52c7, 0x5816bb9030ec4722, 0xbe1e713f3a31d339, 0x1e50094c3b364ce9,
+    0x33411bf980864733, 0xa4994d01cb639bf5, 0xf5ec5836e117ef37, 0xaf23b07ec1ddd819,
+    0x5fdc83a9f5ebdbb5, 0x4d9dcb29351c956a, 0x9e806fb47f22b79c, 0x259d944cebb74857,
+    0x2ba8ceb6fabeab37, 0x7a18964545f445f9, 0xb28a0bcfa19a6713, 0xd5c9009179fd9cfb,
+    0xf8fb023de5d86cdf, 0xdc585678cbc6c013, 0x5b2db8f4ae7df3d7, 0x02bb31b8684db310,
+    0x9ec283c57f057512, 0x14426e36b744e540, 0xe376d865d6fa8be3, 0xa3e367a31ed39477,
+    0x50737531bc1e1568, 0x17cacdd74baafffb, 0x27ec3529ae5ff9b5, 0x9800c4a10b54ba52,
+    0x2cd40691b30fb2bb, 0xe31e3d8ee5e60354, 0x53b9a0b58578a931, 0xb4354160cf14e4fe,
+    0x4f2828f83f4ef2d7, 0xc84fab8b801a9abd, 0x49e613624113ebea, 0x1454cc1e7dbbeae4,
+    0xfafa3c5a857227c0, 0xcfbba6f353907096, 0x9c5986f1b01caac9, 0x99dfef62f57c48fe,
+    0x6d4460af4c110c4f, 0xa25bba0c28191d3d, 0xabe83f1c4b846ae5, 0x61726c5449f0245a,
+    0x342e5b98fe1800b2, 0x91a62381b04d8d42, 0xa5e0d13c59d49c35, 0x177b404a8221b0ba,
+    0x38dc239fc81ef72c, 0x4eb5c59b2df51185, 0x36da831eb3ee7baf, 0x22c9c38d09fbe20d,
+    0x02519870f02a3283, 0x890ea2873d4d38bb, 0x25b28d06a63a10a5, 0x2d0de6dbbf87e8af,
+    0xe8a93abdc066691d, 0xf69e1acd51d6ba9b, 0xe515056798d00385, 0xafb871286523af3b,
+    0xc56b92c6fb8bb46d, 0x38f9276b1c485133, 0x864e9bb6e7a0f2d0, 0x16452f0ebc5d5f94,
+    0x4b8aa4ca2a9055bd, 0xed032415b9804930, 0xca3d411c86d4b1d6, 0xf5fe43dbc1a95f4c,
+    0x5342382299a43332, 0xd7f0d5ec2f70daa6, 0x44840cd5917b104a, 0x2f43406d4ad97ad8,
+    0x0227b359aff84ec7, 0x7687cfc497ffa437, 0xdf0f6bf918f1510e, 0x466941a0bd9d4eeb,
+    0x3d74e44521ef601b, 0xd9fa08b395236022, 0x7e9c22fce360f281, 0xb0433969ef830c9e,
+    0x96dc9f5312f7a739, 0x04f7da5fb634bfce, 0xe9b58dad42b77f32, 0xb3833a2a722de102,
+    0x178efec6140d0d85, 0xef7e49833f1b4606, 0x1e844029d2cf1886, 0xc6011d401b507a7d,
+    0x3f2440280808b684, 0x23b098ec4f001dd8, 0x65a8421f1cc35416, 0xc864ad4f3db3b53e,
+    0xc15dcd68baae6469, 0x19925db75da02688, 0x2c5caf5ee02bc707, 0xf6bf87331bc3123e,
+    0xafba34f7d85a15b4, 0xf7c946c4de205b79, 0xbd3af51381a71ede, 0x918eda929e97c199,
+    0x4b187c23cd5db7c7, 0xdaa28cefe9947640, 0x1b4b83199925e86c, 0x258c26f557c13f8b,
+    0x1c6e7f2312cb41c7, 0x4858483bc768e98d, 0x7947bd1f1c45daca, 0x4931df0201341471,
+    0x7d5f0de2d07193b9, 0x6fed1d60842375c1, 0x786f34a566e47b50, 0xa53de6649738d899,
+    0xa010b2db52bbac69, 0x80dfa7d259bb6b79, 0x1674729a8aab2f75, 0x3223ab5a1719ffd0,
+    0xde01a284f6209e01, 0x8b5f34e39912cf1a, 0x125e6a151f90da9d, 0x4fdf02f369b88ab9,
+]
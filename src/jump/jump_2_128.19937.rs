@@ -0,0 +1,80 @@
+[
+    0x925f6dff58d0195a, 0x12832654fa1f2c62, 0x5b9937ef47b17763, 0xb3af6e9384d61d61,
+    0x85de9ac499598604, 0xa86f19111ac0e00f, 0xacf0706498becf56, 0x0d3e8da19d9228cc,
+    0x68e49857dc72d44a, 0xb31528af7c5ffdb6, 0x0bdf54665e3d606c, 0x5f4ba2274e550bb9,
+    0x4005a6cf1f69e831, 0x5bcad07aaaf7e50b, 0x7994ff09e9af7298, 0x961e8a8e27c54a26,
+    0x1e6cf6e10cea8e12, 0x47543e0fce70dc80, 0xb58affe60e55505a, 0xee048e35e796de26,
+    0x6bc837f28926ba7a, 0x15f4d8a2e0f882b4, 0x3ac37f11c21f51f9, 0x2c4efaf43574243c,
+    0x2a18c256a967282d, 0x46cfec30dc6340c7, 0xc589ef87bc0ccf03, 0x0ebb8038727162a4,
+    0xe10f6f992624083e, 0x5d5d7e63e116955e, 0xbd76ec750ea9614b, 0xb44fdd78209d4245,
+    0xa2a1226aa8c6c216, 0x62fc9bdef27cdd3e, 0x4298a72ec4cd4418, 0x52878cde0555e76c,
+    0x606647df4e8a8851, 0xbda1a55ea2987b05, 0xd80d424747f21202, 0x74fe06a6b8740225,
+    0x9995c95d6add2b53, 0x13f2dae81a2ec8b0, 0x4ff5eaede388132d, 0x10871c4f028d1909,
+    0x530b96e01a01d9cb, 0xb909febbb610a3e6, 0xc3916839b4348d66, 0x5df0bc6ada4e4a98,
+    0xa76f7c16f9d6a1f6, 0x07da65e43b8cdcb3, 0x19f06caf6a20526a, 0xc3273c55e15eb6d1,
+    0x2f089da7c30675f7, 0x53f21974798a441e, 0x241bf79a4daf3079, 0x205bb115fe8926e1,
+    0xc63d671491d38728, 0xb8a217b2aa12f8b0, 0x2a9a9b8f2542107e, 0x987aa512694f7bdc,
+    0x7c4ad8ed51a837a2, 0xf393f33a993c58cc, 0xb4babcd791c3c3b4, 0xebcaef43bb86b9d4,
+    0xad819f855403e3f6, 0x7444988d64cfc5ca, 0x054811add86f173b, 0x772e05ee679ef945,
+    0x04f1fb13c22caec0, 0xaec10dd9bdebb3e7, 0x3199a5325349c1f7, 0x14c037f4e81a8a5a,
+    0xee0fb651bf9c0b17, 0x616c1e5d026a3d56, 0x341f221a8e58c672, 0xce23ab10f1329883,
+    0x9d79eaea9ddb64fa, 0x59113add93f8a849, 0xfce71d29764d4750, 0x28c593a1a9efa97d,
+    0x653476209cbbc080, 0x4c45090c9bc98fc8, 0x359283fa1facc1fe, 0xeacc8bfaffaa7278,
+    0xf80935437189ace6, 0xb643c7718052b998, 0x7fed175774591a30, 0x843c1844e2e62a49,
+    0x778e94bd1ced8ca0, 0x0d32c2bd2c95fc76, 0x2ee6e52bcd1a0372, 0xb82728b884bdfc72,
+    0xa7de2f080453f295, 0x92280e84bcc00a99, 0xd49908cc1d0d78e6, 0xc952bffdadd6b754,
+    0x11af5be473253116, 0x51400a5367aea8cb, 0x048ab7ef43503e3d, 0xb50160fecc62e49d,
+    0x3d2f78c8a79bbef6, 0x55a6b1159c983bd3, 0x6989e972488a88dc, 0xf6216a075f8ca936,
+    0xe4c6fcd2a5b32475, 0x6483749e522661c1, 0xa0b30775a4b5bdf3, 0x0cc60c0dd020733d,
+    0x3ba355cebb16f604, 0xe5ee03d27ad45612, 0xe32fea198fa31b73, 0x9a4d543f7dd9f526,
+    0x9e581362c0677d5d, 0x7fcb778aa25a6b6d, 0xbc88f366ad0b4ffe, 0xe40a16639fcb7ad3,
+    0x96633ada41fc0a57, 0x93a729bb3b51cda2, 0x668a041e5ec3c901, 0xa97b1bfd0fb43f4a,
+    0xdfce43c235d91300, 0x7e4d557ea036b08c, 0x7524af1e09fa45ff, 0xc62006484953f2ce,
+    0xa16052184caefdc5, 0x8033c112296e16c1, 0x740af69cf2389e7e, 0x5f9beff57e21a8d9,
+    0xe0fa1c3cf01472b7, 0x9647a7efe9a13d53, 0x8452a8a978733bf8, 0x4290155f2b196256,
+    0x1d4a2755805f48b0, 0xba7ecb8d3eafa3d4, 0xe5c68c00973b5647, 0xb3f7bf78d0ed84e7,
+    0x94c2810445e055cd, 0xf01f6c10062d026f, 0x6cd3aa26c5dc0a9d, 0x0c99619d5f41a8be,
+    0xadd827c1398fa109, 0x56c8c6977e86b6e3, 0xc98ff661bfd98fb2, 0xf5cb465a83a97bf1,
+    0xc230ad76f9689eb6, 0xf9e5955e15e2334b, 0xa9df5df82afc22d8, 0x9a6a92b056447431,
+    0x5956c53c6af651bc, 0x7392fbf9c8d97ba6, 0x20339764edc65a2f, 0xdb2c8c8b17f6e623,
+    0x356699f452fe6a7f, 0xa43eddb3b2ff62cf, 0x062a01fdca25d800, 0x7368ecc940c55c51,
+    0x67c0d1d320e7fe0e, 0x2873388622969023, 0x05bf9ad3b041b968, 0xd9927b0b0a03eeac,
+    0x4bfe5903da281d42, 0xa7885f78b2817e4b, 0x2e80c02b651dc4c3, 0x56f0d70c7bc620b8,
+    0xf966d3a6e77473f8, 0x766292c4ce76e888, 0x45abfa9b98abc721, 0xf88f1308b82eae1a,
+    0x736a6637ec9c9652, 0xea69399db5212e1c, 0x74ffd278ee7ed32a, 0xb805bd9686e4b0b6,
+    0x0344922c8e5964c2, 0x3f04895e6a3c29c2, 0x0b1fbfb4c121ddfe, 0x3d5dcb3f4a145199,
+    0xc6b8e875c38043c7, 0xc98ddf384cdcacf1, 0xe37d11cd35910f3c, 0xb6d8ebc432c679f8,
+    0x9ab137f619eb7c38, 0xeb979e98ad0ea61d, 0x123912711d295205, 0xa6ed134525f3cdd0,
+    0x01f7b524bcc55ccd, 0xe17d65a91160c4b0, 0x34e3ad9134f9a619, 0x8c70f0d3cd651503,
+    0x5d4eecaee2be873d, 0x109cb150128e3f46, 0xc847ec63b8f8fa57, 0xe37be671ef77a2cc,
+    0x71c983039f8c67db, 0x8ea7a0a5b6784157, 0x51a1ce2e16214ffa, 0x2b252bb4b906f402,
+    0xa076da5bde850431, 0x18b9e9cff9dee210, 0xff94de8f3a455da0, 0xc9a76fb82f4317a0,
+    0x556ffa39d534c532, 0xd6cda40f86e56f44, 0xd9dcd74e93ac0729, 0x4af48e1150c4841c,
+    0x8823884a53f0291f, 0xcdea9045dcb2d613, 0x253152b255d2ce44, 0x723f34c36a3f39f6,
+    0xc3b970c37f503eed, 0xef56da535d246ea5, 0x4b2f0cb21917ce46, 0x7a5e30cc5433690b,
+    0xf9e9a42c6bccfb35, 0x183ff6f1c75271ce, 0x24f90bd54add74aa, 0x329871fbc5c7dfe1,
+    0xc9f4eb16ea384245, 0x5f7b4b2cf34fbd29, 0xc55ae3c0777cc0cf, 0x34c439112ace2d17,
+    0x0a1fd5a985593b3c, 0x3333b862882c91ce, 0x3003c9f79694e007, 0x2d94fa267bde6f5b,
+    0x1ed2e58dc012a468, 0x9c9cb9db788432b4, 0x0f87dd1dc8e09e98, 0xe12303586c0cdb71,
+    0x5518ae1fc6ba0f98, 0x5112cb702dfb81ee, 0x62ebe4453ba46b05, 0xcf610500a2f4ec46,
+    0x8168c3cc51ac8281, 0x76dd387ad4b6ca49, 0x392e84c5cad49c6b, 0x2936e15a44dafdeb,
+    0x9d9ff8032ebc1fe0, 0x72bd344d807fef1f, 0xde2f450249515313, 0x84acc220968ca603,
+    0x9fa41504c9d11740, 0xb78bcd3eb274faac, 0x9c5b3cc22fd4dd8b, 0x53682dc10bdedd2b,
+    0x3cb80afd1c274382, 0xc52985a1dd000ad0, 0x5cf35e9a04de84ba, 0xb1f5aca984836d5a,
+    0x136dfe74ae26f73d, 0x10fb96075a8ee6bb, 0x7509744a83324c77, 0x979ada26360ad378,
+    0x20b148bc2def2163, 0xbc9795a7443d1a43, 0xf7a9dcbd02f98d78, 0x9f271d53e45d0a6a,
+    0x9273584c923f9e4b, 0xfffe60bd5a781054, 0xabd17d770d292d44, 0x28684b82b5000952,
+    0xa16afcb9dee447f0, 0x58d8f694e8d7116d, 0x09f202fae1bc7ca2, 0xbfbf0031546740d2,
+    0xbcc533342c7803ed, 0xc5b0a57e9aeb4bc8, 0x3c62d37b7fa6aa01, 0xd52876b24c677ece,
+    0x55a54a575cd0e4c4, 0x7aa21dd685822e93, 0xf811385110377a85, 0xab0f92083272ad9a,
+    0x412ce7bf55f523fd, 0xbd0a4ca223ba96e6, 0x00b41423b103c91b, 0xd2a0e094a1b8321d,
+    0x223ebaf48c023a42, 0xedb3130c32f0ec2f, 0xba894e727fe7f701, 0x8aafd2f0917b69c4,
+    0x9080b024bc47b849, 0x6aa740d3cdae8247, 0xbf7c970dfda9c8b1, 0xa02510e5f3c9d9dc,
+    0xad83f4386e1f86d2, 0xe5abe5a202966c88, 0x7579fbb80c5cdf9a, 0xe5aadf8561117e38,
+    0xd5fffc3f860aefe5, 0x45997bce985d0361, 0xc8abe3b6f411ebd2, 0x8d69c0f47646bea8,
+    0xafbe11d0c8af1bd9, 0x80d37e4c2b55f12c, 0x5fcfd0624bc60bb7, 0xdb899a116cfc90d9,
+    0x84ca35b15841984d, 0xabd7222f2f3b73ac, 0x18509ef674fceea6, 0x3540bf217579c2b6,
+    0xc61db7c149be68b9, 0x2a938ed20a98522e, 0x2179b448ee958204, 0x1b0c52a1b5115cb1,
+    0xa558d7d1fec3c6fc, 0x6fac1f509d6b37c7, 0x4a3327312abd4eec, 0x849a490b889e322b,
+    0x37c286c53cc537fc, 0x1d619c992b8fe58b, 0x0636034427886a7f, 0x5fe02ea3bcd91ccf,
+]
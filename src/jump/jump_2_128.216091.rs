@@ -0,0 +1,847 @@
+[
+    0xcfed8967307fefbc, 0xfd26a64324abe620, 0x74422d719b50dc34, 0x14fa1fd7fd7bb500,
+    0xf0faa564463ac800, 0x43457b1be5aea55f, 0x2cbf72d97c13735b, 0xebf75d9f15a8ef78,
+    0x2a4557a79b895ca1, 0xab67ec401cdc3bcf, 0x68a62ae13f50f7ee, 0x4e022a764368582d,
+    0x4ae6346733fbf3f5, 0xfad7b5aa45804203, 0xbd4f9dff5bb8155d, 0x3759ea148f144f24,
+    0x8e22ad1dec34d9de, 0x7a21760e22537a64, 0xdc38ab2ef9b41740, 0x19d5be8bd2b1152e,
+    0x20c84d5ec69f840e, 0x4e4b3c3464f2e197, 0x4c19107ce64a956b, 0xe5ac1ab2765ca988,
+    0xb1d44706f6273e2f, 0x46f8326788216933, 0x3b9efa7a7d38a205, 0x22e17cb77b2a3fc6,
+    0x14679cab2884fd3b, 0x5f1d6a7032930638, 0x93604e2bd151a094, 0xebad7b6479dc6f5a,
+    0xfe935441239cf646, 0x3f2de8732d972cda, 0xe8c0bce07cea82ac, 0xb55b2763fe5c0b93,
+    0x780929cf245f2f61, 0x7d018b9bba26dbbe, 0x758680e2854a25f8, 0x0fc7956ca5da732b,
+    0x862a436dee590bfc, 0x388e77184b0676ca, 0x8fa2c21d6a6daa51, 0x3feab73107020a6c,
+    0x478e3831bdd6dc0e, 0x7a5632ff4cac8bba, 0xc02b2c65391a4ff6, 0x53598028d641b06a,
+    0xec79ad1256e60ad4, 0x67a439cfeed36a2d, 0xd79776e214a3fea0, 0x268bfa0ddc36d8b0,
+    0x1c5c9247b3c8d445, 0xc2f989a8cc0a7454, 0xa1bc7999d150cdb5, 0x5613655467635727,
+    0x42c11f46f42468ef, 0xcd98ec40994f3da7, 0x1b3b65864b874ba9, 0x20cce857bb569a70,
+    0x2df7826a2b399a21, 0xa3c1d59e3d365ae0, 0x0a3a9074e56110ee, 0x2d35aecec24577a3,
+    0xe5ea4eef39207f60, 0xadbea61ccf269802, 0x9396893a19f00d33, 0x602d9021b0e170db,
+    0x65392a18b48b4482, 0x80eb97fe3bb43590, 0x24cbe66a228546ef, 0x2eb001eafc754f38,
+    0xe3aeaf0c27c874ff, 0x18198eda38f43544, 0xcce0695cdc0d7a35, 0xcc32d248f6c63464,
+    0x46c19b45e9e0fb1d, 0x8f09ed591199081c, 0x8cbb94d335495427, 0x299d891daa850a52,
+    0x9196fe4a65320c98, 0x729e3ade00cbeb6b, 0xfa1daabc6683b7fd, 0x0b087d00f21e6a7f,
+    0xb0c2f885ddb05324, 0xfd54434011119e63, 0xea2342ad3a0e1d58, 0x25afa796270a0722,
+    0x8b7471fb409c9d57, 0xc77879a6cf7af8a8, 0xb292dc1b50d071a8, 0xfcc9aec47625d2a1,
+    0x3122aba5b5bcc439, 0x5d1da433417cb3b7, 0xb1c91b8bb81c97b9, 0x35db4534522fc662,
+    0x6d1debc32af66b02, 0xd920006ed64d1607, 0x7efcccbbbd9eaf66, 0xefd02d3440a49dd4,
+    0x8deca793b1ca2b74, 0x15532cfa2188d4b5, 0x3a03ae930c651f7a, 0x74c4a3b28de7f274,
+    0x1db247a93f3c5fba, 0x35a77eb91a3be549, 0x65cc83d68c3b8abf, 0x31ddb671c4a7250e,
+    0xf7619caff3a03e97, 0x71d9a91c682e05f1, 0xaac2f5447bd80f99, 0xf907ac69f59d253e,
+    0x59467c1e2e6bff55, 0xcc64565ae14e8543, 0x7a1b145bdad8d732, 0x98bb6ebe11f58976,
+    0xde0a1dd11a8ede9c, 0x1e2ef06509279c38, 0x9675b9ec7b870191, 0x52b2ff9af052be8f,
+    0x8eb826fb5b556062, 0xad2316e0643b4a57, 0x1c8198179c4b6bce, 0xa1c06fe4860e35ec,
+    0x5f979e0abb6f28f1, 0x75718518db93e64d, 0x7ba2b954d28cde0f, 0x879129c5bd765dfd,
+    0xe922d5a6f664875d, 0x605192d6d297bdc5, 0xacc23fe96f919d08, 0xfca2fb090fa364ab,
+    0x5a7bf2e4fea7d2d5, 0x6a7ed82b6fc8f775, 0x91fb61d8c03f76a9, 0x1301b313278ab327,
+    0xcfdaacb37bfe5ac9, 0x520a0b5038ffc30a, 0xda95a72be823490d, 0x8523c3ac274ee619,
+    0xd3476e7083cc9b74, 0x98870569210bb96d, 0xab87c3ad5d5f5d34, 0xbfc5552f01cab4b9,
+    0x85716cea4852d777, 0x2af0bafc55701e7d, 0x1a08b58aee755b5f, 0x729cdc9409bbaac9,
+    0xdc1b43066f392717, 0xca030ea5f2507b27, 0xf7c7ac89fdb838f9, 0x98c8c4eaddc80dd3,
+    0xe9317b7c98624031, 0x332f8ff5e5b8bd0e, 0x3f31c409c6a3cd3f, 0xf6bef405b979750e,
+    0x720ae3725330f187, 0xb9f2d6d7f5146678, 0x6084e56201e26179, 0x40a42d793480096c,
+    0xdb71747e97484afb, 0x3ba492d576495443, 0xbd2d983b25746e42, 0x47b655dbec9d64f4,
+    0x17fed3a3a4540375, 0xd1cca35298d40658, 0x0d4ffa18a5a4bb22, 0xda7598fdb778ac16,
+    0xba0b8a1dc67d15ab, 0xbbec1a92d09dd1dd, 0x8f41806d120d0372, 0xc89f47966bdfa9a0,
+    0x4bdfebaf7b8800ee, 0xf1f2f50b479ecb47, 0xd524448e38c1235a, 0x4761ad489409ee4d,
+    0xecea145a0fda675e, 0x067750d80ed9fb69, 0xfe38b219d749ee65, 0x61f5fed603a62649,
+    0x7ac5f2605ea9a574, 0x7fd0af571e381b43, 0xe22b2774d260c118, 0x6e5fea9401ea2f78,
+    0x3ca252b1d12cce20, 0x79ed88c3a0e91e9a, 0x05f2fbcefbce436f, 0xfbf6a9def76ba6b3,
+    0x817e9028390e4fd4, 0x15516e9824964af0, 0xcf943c68236a117c, 0xe9e58f9442587c98,
+    0xb90c01e121c39f86, 0xb97c499d875a6867, 0x3808deec5f2eb460, 0xa687025c83532489,
+    0xd6c2ccfa652994dd, 0x01467e0f609804c8, 0x289f27a94ff50f95, 0xfbcd2c7225e785de,
+    0x25591773b555d621, 0xbc0c1c140f160ddd, 0xead17730c281a8ba, 0x966c042f4cbce3b1,
+    0xef63817d9f6c214b, 0x6ddbeb0b48d70734, 0x1463046fe6f6908a, 0x983c9a6dbf901f0b,
+    0x530d6d6aab9b87bc, 0x7c6824d8425da617, 0x4463dc62ffe959ee, 0xfde3c79955dac8de,
+    0xd094949cd725822e, 0x1e0e9eff5c810e63, 0xf2ec87bf17be8387, 0xa24852b356d1f28e,
+    0x3a2742928486d391, 0x04cf4ba9b493f533, 0x50d919bf4a9c6636, 0xac89776f4c29f399,
+    0xc84c316527bcc83a, 0x48ba2fc9c39d439f, 0xd8d79d249b3f0b71, 0x2be039d271f4ed51,
+    0xd89d74c91ee2734d, 0xfdd7aa818ffab6c2, 0xaac80b97378356a4, 0xd3c59e48dfcfc6fe,
+    0xf033a36767cfc3e9, 0x597045ce8a6fabb4, 0xc776939d206e9ae5, 0xe5e5a4896dc0ff82,
+    0x45e176c562fc2a5f, 0x470c668766b784a1, 0x721d3f222f0c1e25, 0xfe15bdcf29993bcc,
+    0x446deb4dcd353c53, 0xacdda95854cb002d, 0x447fa788b9c2f105, 0x4874bc0354c1d203,
+    0x5451bcb6434aeb06, 0xd9e75606d9dadd7a, 0x6c5631671a4dc7cc, 0x0efb4a8772ef958d,
+    0xe7d3f84d3cefa6ce, 0x98dee196023c88e8, 0xf78efe2492772491, 0x1982b7fab82e247e,
+    0xa1058ea360c9993b, 0xbc4b8c37fa5e3aed, 0x1e4e021fc45d5b87, 0x8720562daa5cae10,
+    0xacb15ca6be53b492, 0xf6ea9047a44ca937, 0x2542674ce070a13a, 0xe846892b718ec787,
+    0xda99509fb16ca8fa, 0x029139eed76ebde1, 0x4b224cf2abf76531, 0x3e22ceff0726f66e,
+    0x48ecce0843abef43, 0xd2d1ec89e0fd96cf, 0x781d80aa0fde942e, 0xafea779165e2b9e8,
+    0x626f53072b9951eb, 0x5661dbc73d02d21c, 0xd00ca8e5aa5f7825, 0x45c16e8f5b0c9aee,
+    0xfb453e904f2e2f9f, 0x641b9b8a71bbd8cc, 0xab42c1b3f22271eb, 0x0922b7ac0077e7ba,
+    0x6ef142120f662f6c, 0xc095a2c8f9eddbc8, 0x75d8a4b2c44e4430, 0xe95799345cc7ad12,
+    0xb3a03e51a91ea0e2, 0xdeed2e82569c2a97, 0x8064907a79d6ed94, 0xb835e9019a5cc6f5,
+    0x52dd2dc1c7027c88, 0x673dd05b3261471f, 0x8b38de569a0f2de0, 0xef093d7df8a5f93f,
+    0xd4b0b6bae0081ff1, 0xba1cc20eb3bfb101, 0xb8a705888e7fde97, 0x8b31a3f236bd5af6,
+    0x15f7380814b7cd75, 0xd397cff7c039d48f, 0xf1fc53e536c25353, 0x1dc6bab99293ca75,
+    0x3f533cc2665f0f58, 0x5457a51e58f8f8a1, 0x237f62fbf711c069, 0xbb183c75a97650a7,
+    0x7f58f1dda17d79e2, 0x7a57221e0e310c86, 0x34bf0a0b39a981d2, 0xcf7708a4c4eb2b46,
+    0x65d87823730835a6, 0x768723ad0b73f990, 0x98c3b909869e2321, 0x066a3bd3b4dad839,
+    0x08588f77c57ac52d, 0x5ee20d8d0505efca, 0xaef30142d150af7c, 0x21d25ee052dded7c,
+    0x4e98c6a842ca5d75, 0x6f323a286735db1b, 0x0549f327cc4e5879, 0x9f3c39d36c701807,
+    0x3433c23516c1a27b, 0x2d7cb606758d075e, 0x4a3d4886ad6ba721, 0xaf07aab8619a1b9e,
+    0x35b0d6be3b1a1d51, 0x214ae6eb6338a996, 0xe4e1842eb14a622e, 0x9380028814549d46,
+    0x5dada4ff6ed71922, 0x9ce3bda52efe32c6, 0xdd5eef44f2fdfef1, 0x14b1d0ee81435916,
+    0xe5997d85b905b752, 0x8af8bda2eec81080, 0xabe54ce9eb88b9e4, 0xfe8af33e047ec3aa,
+    0x69343597465b8559, 0x149180189a13ce49, 0x8f16997640aa1b61, 0x9e4c029f8b3d335f,
+    0x0069154e77c29a1d, 0x01945e937dd7ee77, 0xe5949c84e8188573, 0xdcc22772e19fe3dc,
+    0xf4f5def2d2bc76a3, 0x52379abd4e1f4c2f, 0x11faafa994151fd2, 0xfa8b1cb9f8eee643,
+    0x9cb3a388b7bb2d91, 0xc92fa3a0bf15bb0b, 0xaea085dfe44d3efe, 0xe4207a58b2d2faee,
+    0x379b5e3c0a50bcea, 0x8b1cad07369a43a8, 0x2315dd559d14672a, 0x8eb6d30e5b358be0,
+    0x0492f4ab1e253ce7, 0xdda05666b3ea3a43, 0x8250551569143806, 0xd526b5b0f38759a0,
+    0x594ae43202e0a699, 0x79c05b7c31ae02aa, 0x490f550fc8e70b81, 0x6defcaf4741f21aa,
+    0x23c41ed4f7a7c5d6, 0x6fb442783ebffea8, 0xa6135bacdcc73691, 0x37a1e01093426499,
+    0x90f9de669ce8f5ba, 0x653aacfeacd33ddc, 0x89fad40e0506c5ce, 0x1696bb05f5c38b8e,
+    0x49ac7ab03f704b2c, 0x53cd9067c0168a2a, 0x4024470352fa9587, 0xa658ad4b8e3b19d7,
+    0x2336fbe6b5043688, 0x717e3187e10ebd9d, 0x545532279bfb221d, 0xd5497014db903f5d,
+    0x171d4188fa4a5d62, 0x7ba19541a2a54107, 0xc2f5e1c349c70eb4, 0x470f02990fbe399f,
+    0x82bed63b6cb458e5, 0xc7e1a837c66b2201, 0x95c73b96c6fbcfc1, 0x24c1b55b64455bed,
+    0xfeb15e535ea3c8ff, 0x7699a8d0f8ef581e, 0xd17c9a14b039d4a4, 0xc9d935986208fea4,
+    0x9f8c8cf8371147f8, 0x9479a056b1d276f5, 0x007e1087e4088a04, 0xb7ee894299e2428d,
+    0x6adcd729dd2874fa, 0x73ba95693cf265bb, 0x05fd36790f532589, 0x519b8f5366a1a8ab,
+    0xa86ab496d66f848e, 0x6a4e48e99b31ff1e, 0x4591135d150b87bf, 0x6a72ed42710331c7,
+    0xd5214c673e9bd101, 0x2ef15135a978afbd, 0x3ba5ff9e135694ca, 0xecc0e4bdbadb7f64,
+    0xf7bc243052b623c8, 0x4db505094f33ce59, 0xd6fbc05d685150ac, 0x66dcb268eca48fa3,
+    0x2195a48205a95489, 0x37f9ef3268e88439, 0x647bc4b48c65d754, 0xdfa69a3075301cfa,
+    0xbeddcc3ab3e3cdb6, 0xa955b850d62d7a03, 0x8f2b8b3d6e0cbf2a, 0x65aff09ebf06cc8f,
+    0x8d0dbad5da58fcb4, 0x456143cc35355bc3, 0x2185d4c1f28f000d, 0x05fe0692b476c549,
+    0x4cfe455f0ccf89d0, 0xc51674529c074b19, 0xcf1c4078ea8d0614, 0xa92e3c7d4744f2aa,
+    0x3b6bc997a78088a0, 0xcc95a97a029dbfe0, 0x4daecf00bd434f57, 0xf7f8f38a6ad54b38,
+    0xf0dd8341f873aa7a, 0xc7430639c7824da5, 0x8d3b47a44e31abbd, 0xa7da01ef2fd0dfdc,
+    0xd80c22040593ca91, 0x91ce2867716e4e21, 0xda31aea5950eada1, 0x282b34b8d9ca65ea,
+    0x2ed8e583b0d42614, 0x8b4fd4e7b671b6d6, 0x6d1019fbb7a3d06d, 0xd87815b4b8eabe59,
+    0x4f884ae09aa56287, 0xbb7beb3896d6d8b8, 0x9e3ce8d96eb44174, 0x6eb8d3e81037dc88,
+    0xbfc137a8b798b771, 0x3af6f96f0c20034a, 0xce20d7e5255bb48a, 0x273db9ab2145058c,
+    0x004867e3a714d486, 0x105503e9894ff715, 0x9d1d368c12093d0a, 0x7164b00fed9033bb,
+    0x58d6f8389a6a96cc, 0x681b20ad8637af59, 0xcaa9c824b7372d79, 0x8249568845cda7f4,
+    0x9fd6380bdf35e0a8, 0x3aaf3ab65955733f, 0x03d6af0cbe269363, 0x7acf2eae9154de84,
+    0x18a21f1ff5b04a59, 0x0a8a624cd036be1a, 0xb99032812cf0199e, 0x75639bd687fdd217,
+    0x3b273e779603e8c3, 0x1a25658d5acccd51, 0x246a5497f0bb056b, 0x19acf54b6b99f68e,
+    0x50285c46013764dd, 0x6d2ca518d1b8c194, 0xfe4e1f67a038fb14, 0x6e0d29aa28dafc39,
+    0xd55cb27bf7d530c1, 0x836768e92703593e, 0x3dc754c232636b1c, 0x2acb7c40dbe12d49,
+    0xe2c39629845960de, 0x40f2b911241b894e, 0x68396cb3ad6d93a8, 0x5b07fb91237fe627,
+    0xbfa76d5b5d26cc04, 0xabe6c9fababea702, 0xc26303365e2d2d80, 0xc6a544885c895387,
+    0x7adc5f210d1b96fd, 0xe41c82521fbc1444, 0x4c007fda585cb412, 0x877232b7cc7656cd,
+    0x3f60b06cd17422ef, 0xde8532875435a453, 0x17817ab26448cc0f, 0x51446fc14e468276,
+    0x5cdccb67c3b2c482, 0x47ada1f7faaccff2, 0x9a2d7c979d60ba9f, 0x3d4d4f1ed8271885,
+    0x788c0b7d7067a285, 0xbbb2502e2fbd205b, 0x161cababec4d3c19, 0x3b2095274391beaa,
+    0x686c3302a0459a94, 0xaeed298ea2bc39fd, 0x280f928dfbbf1551, 0xd09f2639fd6e40e1,
+    0x1ace707b9bcdaab0, 0xa1d2149d995727fb, 0x6279b94afe87fbad, 0xaa562cccc6394a27,
+    0xb832c939e856878b, 0x963e6e618d39899c, 0x0d9762e74365300a, 0x2df33c3b1ff3e1e5,
+    0x34a46982c306e7b1, 0x45ef0ffe73d82fec, 0x89a0ea340e9459da, 0xbe95c2f3f88e355f,
+    0xa9396c92515425df, 0x595340b17882dcc1, 0xe12751bd20be4347, 0xcefb85e6b01b27e1,
+    0xe40a1f67f4709f85, 0x9829c4bf32aeab18, 0x60542e18e138e8a3, 0x57224303cfc8f86e,
+    0x475bee83fb80183c, 0xddedaa961baa5beb, 0x05aa80bb4748d3c6, 0x4b7805a3ccef6807,
+    0x9602d012386ea71c, 0x9fc05e8b8af753c7, 0xdb3e7891a5ce8d03, 0x0b6328c9b8a0a276,
+    0x68d1e8a9439345dd, 0x994706073723396c, 0xfcfb308d7889a117, 0x63232ed6518848f1,
+    0xdf54ee1d0244656e, 0x34254214a1081c61, 0xca91b3ef127ca7aa, 0xed3d8c79bf296ce0,
+    0x9eaac45cc05e043b, 0x3966b0ce3f22bc69, 0xb086f6ae146ea72e, 0xe6141258c378e9d1,
+    0xaa479f15374906c8, 0xc509560573eb029c, 0x5907625a7b720229, 0xfcb86d2f643dd7fa,
+    0xb738fd3e01d8f318, 0xa9a6ec1bdbedbfbe, 0x5ef056b8d5d67cbc, 0x8cec6df59862ae03,
+    0x02d20b1d34ed3541, 0xb0e6e4abbf292108, 0xeac80b68fd6d2f30, 0xdfe9f637e7ab11d6,
+    0xb63b698a6826eb69, 0x884fa1ebb5ec9c12, 0x73c74babf4f9d87b, 0x5d50db88cc1dcea6,
+    0xc35b8685085825be, 0xf723f060a7008613, 0x7ae8cf2d3084fe03, 0x38843e2f593fc011,
+    0x8a3b2ae22a508771, 0x360f057e150f5917, 0x9195e251fc2880b1, 0x0858a77abbe4bf10,
+    0x2360ba6c7a6d1360, 0x2e4be2d3cc1ca3f0, 0xa9a8236b414fe4b3, 0x43ca80b073b21ff4,
+    0xff8d354d714d2d3c, 0x417f292bf7b350e9, 0x99444bac47732204, 0xd42bcae07be78284,
+    0xa89fbe00dcbb59ef, 0x267a6ca03d8af27e, 0x16c58ad7cdcd5d5e, 0x813d309894a8701a,
+    0xd7116457e3bf8a7a, 0x2b8a58571f3c1657, 0xf3c0490e79119db8, 0x74fb57908a002495,
+    0xdcded5a100ab7663, 0x6eeb9b5ec93e6ea5, 0x69bb5953b665b818, 0xb1b75422ab210f5e,
+    0xaffd1bce5db55c00, 0xb7351dc877b49f60, 0x90eb32654772010d, 0x9097cd58abea4005,
+    0x4e73302479137e0f, 0xc47329d77b33fbe1, 0xfe7973bed16547b2, 0xd44fcb9480ced7a8,
+    0xab17249f4b1630d0, 0x810a4b80cd1498d3, 0xd5e108eca02cab5d, 0xc584935c80b11256,
+    0x6cddcd8ce130eecb, 0x0742cc100ac23ec2, 0x18550260f4e11504, 0xe2eb1f321d25fff4,
+    0xde6e24ec4b18f7cd, 0xabd1b4c2ab39696f, 0xadcde0fd125e4e62, 0x5237676b40cc7396,
+    0xcba7ac37ab907cd1, 0xfb9eef1e24355530, 0x08cd2fde5a1213b0, 0xc0f5f456cd2ad0bd,
+    0x7e546cc19270bd8c, 0xd5a9915f74bf1042, 0xda35a2b0cb391d5e, 0x6a701bf21590b421,
+    0xbd91c8573cedac3d, 0x099dc2fa9ccf14e4, 0x784d60cb6e7597dd, 0xf71045373371593f,
+    0x331f5dc0836470e4, 0x15f9ee00d557c303, 0xa2390b841d010ca0, 0x8a53669458979852,
+    0x6f11edd95304c686, 0xd7a80422947a80c1, 0xddcf547e568ce77c, 0x6e540ff5611dd54b,
+    0x15a9d3f7cf864147, 0x3d953200542e3e17, 0x616b742d008cecb1, 0x2eef29895dd04dbc,
+    0xc270e86a8434cd02, 0x3bfd4a246cf62480, 0x37f6f3fc9ac459e6, 0x3bb256f789c59449,
+    0xdf39b34c559c628a, 0x214aa8efedc28d32, 0xcb9d4a2df3df9615, 0x8d1e604b2f4791cd,
+    0xd5c829eb35a346d0, 0xb275d1f9f5fa3944, 0xeefcb2f04bbe10d8, 0x4c40c72670e70f68,
+    0xcce14b52c44bb15b, 0x3572bcca8b1e4bab, 0xdb63b1ef1d60648c, 0x86c7fc590a1481fb,
+    0x00ceec33c985e944, 0x5b0c9e71fa2fa2ab, 0x3224d529ff55c22b, 0xf917da5850ec1529,
+    0x2b2722382759395a, 0x525e8031709d158c, 0xb02d90f48bb424fc, 0xabde3b956c8c3bc2,
+    0x5ed59060191d5fb8, 0xa8997f518319ae00, 0x9a18067b572b6d0c, 0xefc0d9f9b3175103,
+    0x6b8787f36d0f73b9, 0x8197090c073fe8f5, 0xab52b31e680f4d98, 0x491c922550409822,
+    0x264dff2fab11d2bb, 0x804f6166c0f52c1a, 0x9892a4360d3c5a4a, 0x05ffdea476e21968,
+    0xcd9e5eaba824e452, 0x4b88054507fb512d, 0x5654008e94f99a8e, 0x95c0577807e3ce5a,
+    0x15fe426439830726, 0xf1fc3bbaef2df07b, 0xbe9849b89f860645, 0x93b5d666d8fef1e8,
+    0x8cb4bc4d355199e7, 0x3fc9c0656ef0a49b, 0x436b9de769e3d633, 0x5c05d9c0778e7c6b,
+    0x15cfa422f11155ad, 0xf87b994748731974, 0xcc75753910211538, 0xfed4ae0dbc806b23,
+    0x067210a8894dae5a, 0x7c2d459a6fd5565b, 0x3eaff3778839edcc, 0xd0787cb662bdfeec,
+    0x041061380db91fd1, 0x1546ba43c869c715, 0xf0494194e988cac2, 0x0d2255e5ae0aadf1,
+    0xaa220dcb558497f3, 0x7b2b4e7d8ea93de7, 0x2db3211f9849f77b, 0x80e64a259c1ae6f3,
+    0x42080b99e5780603, 0x566e007b5e13a898, 0xc288cab6fbb82243, 0xfb25c4c5ce36195a,
+    0x11aef3a3ed388625, 0x8103edaa1754b3c8, 0x50b272be523966fd, 0xdf15a4448d8c10b3,
+    0x689e9448be0f4944, 0x3ff18fb05bb0309a, 0x1ef843ebbe0bedb9, 0x3e1b986c1d10bc28,
+    0x376bcec7e6ce1375, 0xd0ec313a180444d5, 0xb82fda1839899549, 0x1f8980ed63d58da4,
+    0xd5af96e54264f84c, 0xacf1a97ab0292646, 0x1ae43a371c95164e, 0xa374aa4469d768e4,
+    0x18adf3cef90a011d, 0xf57b25a8c0a89714, 0xba927349fef4ac86, 0xe8ce5accf4880999,
+    0x71df57ed4dab8d00, 0x9c9a2e71e023faf3, 0x91a6a33ed400796b, 0xec1b1d41d4f3a8b7,
+    0x32377e2f9d1fd84b, 0xcc96eb2e485b2c45, 0x5a694b333599e96c, 0x4089cc0b339f9ac2,
+    0xd9b90dc0c7f62150, 0x5ad1a4c383dc76fd, 0x29383a16db5ba1f7, 0xbf8d03941cb4dbdb,
+    0x41e386780d5ad52c, 0xae9f3c2e69e94f85, 0x2befe67d2d08a39b, 0x600f549ba0d56950,
+    0x458c95a318718e51, 0x728862c2862ad6ab, 0xa3463462520d3d2f, 0x844681354f65079f,
+    0xb8ba5ea805205a6d, 0x3c8c752d4fe6bd29, 0xde0dd87fa2b80bd8, 0xb899fd1da49c0f79,
+    0xf2f8d13d4546b20f, 0x67b11433f12ade65, 0x062f73ed172eb655, 0xb8139b643d24d49d,
+    0xec8c969b3184c262, 0xbdc5f13c455b7b84, 0xbb94b73a76f0dbb5, 0xc69f4d3369b05b30,
+    0x793018de3dd180af, 0x19fa565fb5ceaa2e, 0x588eedaedd00e402, 0x19cac48ce42dfb73,
+    0x931c8dab2c5d8704, 0x3cacb9e3a3041f35, 0xa93be9f102be41fe, 0x16ea55492938899c,
+    0x21048182f4b24f0c, 0x22bf93fa783271f3, 0x9d28d21aa8e34bbe, 0x5ef41abf1dd3d96f,
+    0x5dc11b93f38166b3, 0x05701ab4c36a7c3d, 0x362dbe29fafdb6f6, 0xd6950a097f84a307,
+    0x2cf54c9649f424bb, 0xdc738558631a3a2e, 0xa924c01ed404be80, 0x0622646d18654034,
+    0x3f93cb48f168ddd1, 0x20b65a421ea0b2f1, 0x10ba754100610288, 0x46e21142ffa5715b,
+    0xaa322e7ce3614313, 0x7bb9625053f84ad3, 0xfa9d9d9327f11ff2, 0x3a3f30d4faf8fc8c,
+    0xcc106af540843dcc, 0x70f3a9758e2bda23, 0xa14cc7c95e8f5f5c, 0xff99c4df6cd56fdb,
+    0xc43cd3203db99d64, 0xc2f1c7828d1644a3, 0xcd9aafacba3e0e65, 0x2303e1bab634f9a5,
+    0x8e8ae2a5c19c91b8, 0x59d851a705187c3e, 0x9931dede0cc48d16, 0xde2fc849b948aaa1,
+    0x07b4078d785e9d1f, 0x1c1348ba1a601dff, 0xa35722e6d3c3b301, 0x1a42868ad7bf5952,
+    0xdb91ed3fc735ecd9, 0x1024520d985bb9cf, 0x4b054321168bd218, 0xfbda8a9dd32b4540,
+    0x68d635f72991bff4, 0x9733b8795d370228, 0xf38cf9f11aa1955c, 0xc0bfa1cd6ed5592e,
+    0x997e65f70d53d4d0, 0x5235203a4253e0c2, 0x37f8c88494a3c327, 0xab3ddca5cf96dc30,
+    0xf2f6f693426aa300, 0xae2ac50d1a1286c9, 0x533c093efe391862, 0xcd5f7466e86c4b3f,
+    0x35fa623965e90c04, 0xd22718a579c3787d, 0xf2b586ddca3a1011, 0x8878a97c8f4c719a,
+    0xd7e9b40b44892506, 0x4430211c274219a4, 0x8a53ed2fc7b48564, 0x7a774cd8a59fe490,
+    0x8987b1bab9947058, 0xf04e53d655959b25, 0x2412634d81d86fc1, 0x414e170172c7b143,
+    0xa6b52189eccfe610, 0x96b84a0fd0c5487f, 0xa7810d7da803e7e1, 0xb4a76650f6b2376c,
+    0xe75c7b1ec54fbd55, 0x4dd0ff6aa44d9325, 0xe9eea55b9dc93967, 0xf5fd996747b04453,
+    0xed497b12d5f3ab37, 0xbf3ad3142c13b768, 0x8c1434a22359677e, 0x5655ddbf851f76e7,
+    0x535bc693d88d475e, 0xa62f7937d1aa9e31, 0xe3b68089bfb248c2, 0x4f6d61c283cca11c,
+    0x230d5e342e9a72ed, 0x669df139bd989ab0, 0x7ebf5fe0c037d9f1, 0x496cea6c0ac6ad18,
+    0xf26d4b4c0377dc94, 0x47c3451754fbaa28, 0xfb227bb7dca0337e, 0xa559559805c66b69,
+    0xf612eb9ef5aa34b8, 0xfa99ef73235fad84, 0x2eca15f4f60b7167, 0xc9a01add5a76e6d1,
+    0xf2586c223889e161, 0x5a1374ef78846778, 0xaeb65bf04cc3614b, 0xd98aa0e32773b9ff,
+    0x1bf75f31f8e98a7c, 0xbb749843bab9d89a, 0xab91aad6667ff1f6, 0x477f49e943166ca5,
+    0x1fe817b418555a36, 0xaca2e96368394f41, 0xab08077951c70506, 0x953283df0b74041a,
+    0xcede48be479b4f32, 0xf3b6f82fec9bd4f2, 0xf4f9a2e00f868d43, 0x8ad4c0ff5f82a49b,
+    0x609abb728d713958, 0x2495a12cbed274b2, 0xd89eccd7f2943a8a, 0xe8df5a30e2ea43ec,
+    0x74b9f14641af9c27, 0x44bd984ec6fe51dd, 0x7d5f4a839664eb79, 0x1318252e09a5cad5,
+    0xa6173d6373c85fce, 0xf88438fdc0df0562, 0xd2cc1fafe29ae858, 0x7f9a1f6ab5677205,
+    0x8fad519af7fbdc97, 0xff3bd76a5e3f0656, 0xe10a22979e29680f, 0x3cf0b0154c253d8b,
+    0x203196343517cd9e, 0x0dd75cfaae27963d, 0xae82975267995882, 0x5280239d8da6ce38,
+    0x501af5aef37bc796, 0x8bbdf7c46b054b4b, 0xa4f2ab53f33f86f1, 0xe28f62ccc48bf064,
+    0xfcbec7f0e3c34852, 0x73c8eb316b179194, 0xc7402f93301c546e, 0x3f98ff8f85445f2c,
+    0xfdc0f909e2d6a67f, 0xecc87df8975dfbfc, 0x1f32ef40b370129f, 0x1f12c4b6a74584ae,
+    0xf310458859ee74ee, 0xab236a8441d9a9cb, 0x0eeb542d40f5a924, 0xc4249f1c76070bb3,
+    0x5d286e49d7504817, 0xa2e7f46c91aaad83, 0xeb454274803ad781, 0x1343ac635439cad5,
+    0xd01e8f1c5e3b7785, 0x39e6f1784136b94a, 0xd52fd06583f19f88, 0x0423e9678e1c81dc,
+    0x9bfabba81e5dd5df, 0x1041e2354dadd428, 0x9eb77cde6ed583fe, 0xf67a55bd538d9347,
+    0x02f2cb34f04519f6, 0x48db10a069bebfa0, 0xab2cc0661bdf7c1d, 0xf48621b697f0d660,
+    0xc7d3471342df6f06, 0x3d816413a91aec42, 0xa7d3ea57cb169827, 0xedf7cde5c994e79a,
+    0x021b794ee2229d05, 0x64f8b8382c634c8d, 0x2ce8745cc47dab6b, 0xcdf14f3358c8816d,
+    0x40f7a124a8f77549, 0xef228eb80fb63041, 0xaeeb1499eab45824, 0xb795d82936a3b7b5,
+    0x52e20270eba5ca05, 0x17e465b57b797c3e, 0xee18c12fa9cc1b50, 0x0d72b68ade00af18,
+    0x3b241f727fff6f1d, 0xf835bf3005a377fd, 0x2b952324a9ed8443, 0xbf0949655b5f5898,
+    0xa82eac43e1e76a93, 0x47933e92351030ab, 0x481bc868c8a24efd, 0xd43b09b8ba3dd45b,
+    0xf98408f1f8d43d95, 0x4d7f41ea70213586, 0x4e720e6f7466524e, 0x840fc4817b0f8446,
+    0x99ea495510aade53, 0x469d53ad2b0e4a84, 0x1e217bb3cd2b2354, 0xee2f886e82046640,
+    0x106c4482806eeee1, 0x7dfb729fdecfc80c, 0xcc885cf4c0a010de, 0x306aeac97e26822b,
+    0x21ca35805f4a07a5, 0x396ed1601e9e3dfb, 0xc348532d2ed4cf77, 0xf95ad1973fca8062,
+    0xf482baa8c4a9a376, 0xbae9d65119c68a79, 0x5a9eae93e888080a, 0x6ba830228acd6ac6,
+    0xd9ff518f9148dd46, 0x97d21c346223aab4, 0xfc9791fd72bd2044, 0x4e86054221423810,
+    0x85ca7290ebd025b4, 0x8c65e6d7f61595fb, 0xcc8ba76d2f77648d, 0xf0460c47af7aa38e,
+    0x9c3067d72b057b51, 0x5daf2d2edf353f01, 0x4f6c5a180036803c, 0x9e1bd67917621d96,
+    0x2eb31e046189a1af, 0x20ecfa12e2cd7186, 0x9507d773761a396c, 0x14954dbbf4d2a694,
+    0x0dc7731e53d109ff, 0x58f2d7a7c121870f, 0xd6b518c69bcc88da, 0x8d2b6c76c04f1c47,
+    0x0437edc5ac0ed12f, 0xfd1b6c4854be1154, 0xed5a86386255529b, 0x74936c40ea62458e,
+    0x802afaea62716873, 0x1105c640909cd461, 0x5419c732313541af, 0xe16624c20713f267,
+    0x6762cf7cef677881, 0x775fdde72ff40f1e, 0xb1fb794a24d636fd, 0xe812d41a1406327b,
+    0x814a65054fdf6c98, 0x81646aaf2a0e5dba, 0xa1037c122e87faca, 0x0b85ee7a8295c51a,
+    0xa8583ddfc417540b, 0x30e567c73079e9e3, 0x2d7d023d92ad96f3, 0x09d956c588d3a7fd,
+    0x3f301f7fdcc40301, 0x1f288e6ebe43d543, 0xf9e9e095f744cdc9, 0xe86a861aa69952fb,
+    0xdefa68e0ad212970, 0x2373a431dd0271ff, 0x6abd06a82504cb86, 0x253b469960597232,
+    0x4a0ddf6b467d025d, 0x75031c2b295fafe5, 0x4d0194a186796be6, 0x33c3a444d8648b17,
+    0xd9e7bb4f7a14bc09, 0x3101fad4008de3cb, 0xf85acede8fdc0e1c, 0x576869f2f5d4afc0,
+    0xc06cb968b3f9fd9e, 0xc6fa67da5eb4b6bf, 0xae9bd3a0eccc4727, 0xec486e259a7fb68a,
+    0xbc754be4b36610de, 0x4eb8c887b2cdd340, 0xe444647e996a38da, 0x603600b20d6800ac,
+    0x716fdb21660022fe, 0x7b8826c46fcc7f8b, 0x934d81ae74afea81, 0x3c560952b8458380,
+    0xace726bbdf4746d5, 0x3f6212019236c38a, 0xd01d372eca4d0682, 0x1301273e21a770dc,
+    0xe6a104f88d7c05c9, 0xf6c5e8c2715881db, 0x658a65c4ed70c007, 0x9eb62ded4e27ad73,
+    0xafa48020859aef9a, 0x800536d6d4daead4, 0x9ec23ec260bb76e6, 0x648a9a4d2e63b4fb,
+    0x3cbc5cc6bf47a371, 0x9388c39509a931c3, 0x37751228d3d3d727, 0xd966237a618dc62d,
+    0x63ca6882c18405af, 0xfefcbd51e92728d6, 0xe80ff58fac650cc0, 0x34d7cc6a86db7de0,
+    0x1d5a88331a2aa253, 0x401722b2eaf65ee7, 0xcd060c2d0d9f726a, 0x4af076acf5f329f3,
+    0xb3fa2da9cc2fb5f8, 0x0dc0c386b0df1d32, 0xd2323b84727d971d, 0x19fa615b481ee202,
+    0x60a54a25750db1fe, 0xa1e42fe96c91cda6, 0x30c94f436fb5b670, 0x9a5d2184d097094a,
+    0xdbba3e321345d7bf, 0x7f67cb3eb08a9aa9, 0x96fbd9d6152d2b5b, 0x6f80671ac097973a,
+    0x0b63983de421ccc2, 0x2c250e5368612347, 0x495496672af4168a, 0x55c2dda7317bf9a3,
+    0x09a3705696ea6f20, 0x57b3d777904a67a2, 0x818cedf9faa8103b, 0xe23cd1340025830e,
+    0x64b1b3bb27e2c8b7, 0xaa8aade4164b6905, 0xcbe73d7441142e6d, 0x2429f1e70e30fb7c,
+    0xfd4fa8f1d6399857, 0x0855c00a31510027, 0xe8241ffa62f452a8, 0x63e562fadf2b2fe6,
+    0xda2397753227ae64, 0x08e91d1e00a02152, 0x3022254f103bb93f, 0xc31857c61300d267,
+    0xd38bb4ff4252df85, 0x419e718b8485531f, 0x4a753b9d67df370f, 0x5cc1f44a5951bf57,
+    0x975368ae741c2dcf, 0x0161a74faad2aed3, 0x082a43e78d07c057, 0x154a3081664e4289,
+    0x53d99951129c64ad, 0x0f13703929bbc690, 0xb854df4a5de6187b, 0xec30125958b79f30,
+    0xfe2f3244d3c043d5, 0x166f106b9ff02af3, 0xb7e445c6ab9f93b9, 0x828ca61174f2c961,
+    0x05e42941c6ab589e, 0xae627e64c5e1895e, 0xcecd45a747d1305c, 0xbd0571f607b9f8a8,
+    0x92d3e704d573d88d, 0x1c78ba79a469bc74, 0x6ef28d1378570a3e, 0x1ee0bdd6afcc8839,
+    0xc430be1c3fd74e46, 0x970aaa405ebdec2b, 0xe4b18011019a43bc, 0xb73a3d9a44ef16e9,
+    0x5bad8eb0ffbf3517, 0x4c88328c8c670f3e, 0xf74edc225ad5445f, 0x0465e2b5e05dbc7f,
+    0xda2dc1cbeb9952b2, 0xeb6ec040da1bd561, 0x6199aff871027125, 0x292e2f64c079611b,
+    0x9892a9a06e390ddb, 0x35c6700552875df6, 0x25ea6440589b6689, 0xc7f2efb99c675eb7,
+    0x77920c08272b4253, 0xfc5520e63f4295fb, 0xac2dcfe97b430bca, 0x56ad4fc7eda1e274,
+    0xc83091557a41d5cf, 0xa3bcf0fa8252cd50, 0x7333a24e50b7f66f, 0xde26db711808f6ce,
+    0xb9be82a6415ff17e, 0xeed7bb772d34303a, 0x85b7b0f0f7395d4c, 0xa3269bb84b07914a,
+    0xfd89d8ea09cc9992, 0xf8305d2d67b14e69, 0x032f5c0b72dca383, 0x6a61a8c73e581aaf,
+    0x437ac82447d90143, 0x1ba760fc52c0d832, 0x33b2f22a42a2d175, 0x45a173f082c9f6cc,
+    0x82308e2555ecdeb4, 0x00bed1c56689b6a3, 0x8a60bd6ae814fb38, 0xf4c2a5ef2a18f340,
+    0xd170b785388e17ca, 0xd220e9e6dcb4e92b, 0xa215396a2864c898, 0x21547e3618577667,
+    0xf719348dc923b619, 0x78730f35e909cdcc, 0x8dad936bd607dd37, 0x094c09fb6dd87685,
+    0xbdc5718a9d5c70b3, 0xdb828cee19f641ac, 0x25a2b1831616f65d, 0x1b02de927ea47481,
+    0x92cb5ce6a3626ed7, 0x960b64364123505a, 0x2923c70b715a1e10, 0xad1a5f5dfa34fa57,
+    0xeb9d3761b4ded4fe, 0xf54e626096d0fb8b, 0x69e2ce52a8081ff4, 0xe40b2512a9aed20f,
+    0xff588b42babca03e, 0x1a3b1144f03a5b8e, 0xd58c230b73d765da, 0x312ae75f0309cb39,
+    0x3d45cc83dbf7f291, 0x3830670f836695cb, 0x5cf228cb9638e387, 0xa17ae33d21d0ce98,
+    0xcd2f172bb9f694ad, 0xd869b8d52a26c43f, 0x48d87e2ea7140eab, 0xd7c2a5a8701d6098,
+    0x25006337da23f801, 0xa84bf5edc788dbcc, 0x189c39a0856859e0, 0x715f02c88c514809,
+    0x31a89400cefaf089, 0x7e2032a13b6fabfe, 0xaf8919a578e3c96a, 0x8e52ce6b2ec52df1,
+    0x845aeeebca37fc20, 0x689e28a658823796, 0xe0da80abc0d11724, 0xb759afcaed157bd7,
+    0x93f1cc0d8de28d91, 0x34a4d08dad2a20b8, 0x669f5ed13c3a8817, 0xaecae46d73ec7d61,
+    0xaf2805c4b60234e9, 0x9a2e225956e52e8b, 0x1a7c1d25e40404de, 0xc6f9a3a1ffca260f,
+    0xd27d5e0a206cd92d, 0x5ce29bc650e9fe26, 0xb708d445ed9a7259, 0x0770c67412f0ffa9,
+    0x6310c99ccb726be4, 0x583fe88c4a45a60f, 0x98b59f4c127ab3f2, 0xd495f001ebc4be86,
+    0x3dfcaf09fddd17f9, 0x9a4cb43fd5b416e6, 0x2dbcb63d6250a328, 0x032b39207bb9c639,
+    0x169d9ced6b9aec1d, 0x826a45eef04da9e7, 0xc136f65aafdfad67, 0xe2a4540c7b2190bc,
+    0x38fff44174a0ee99, 0xaa35fdd0ec7bb0ab, 0xef11771d3a157b04, 0x32a16b4920d13984,
+    0x9d53c58640b2c1dc, 0xffffcedb6753c6b5, 0x8a47813ef9540ac1, 0xf20d570c33a454ac,
+    0x5f15c680f0bc573c, 0x2c42171109c72a55, 0xc9615433e4fef99d, 0x0c34eb552732135d,
+    0x9942dbdbb9169d54, 0xd206349961dfed29, 0xc077bc6e854c850d, 0x0732d0a4461844dc,
+    0xe5c62f43a917d2cb, 0x2630ff0a4821511d, 0x0b38c92d1f03ee56, 0x0b82aadc4b13d1cc,
+    0x80cd687459f515d3, 0x374ff5d840e33e5a, 0xf952c8dfc7d56dfc, 0xa65ea397122f6ea1,
+    0x303532435a84b6f1, 0x21a55623ac296edb, 0xa6321f9bd1dd0e64, 0x27d6d5db22b61879,
+    0x6ad5dbdead240db1, 0xbe88c4da9c6bc835, 0x1d59345300340aad, 0xe0acefe77c848aed,
+    0x2c9cc523f22f3382, 0x098737d9cd8c1498, 0x560c7c513f4b699c, 0xc86a4537ef13beb8,
+    0x4733085326b10b3f, 0x5025831c0b483328, 0xda9f2aa4d4cf2161, 0xc02c829b95e8ed79,
+    0x59901f5dc07ec480, 0x13358ab2089296ac, 0xdaa4dc0a9b9cdab8, 0x5e0c33a5b8864f14,
+    0x5362eb0cd8995c03, 0x387cf1a642a37675, 0xdb6b5af1d90fd70d, 0x05d9545ef8763ddf,
+    0x072695767df2bc95, 0x966f8f4a75661404, 0xf2b7a6d916a4295b, 0xc58e74c7d2957e11,
+    0x204c72cea09a3df4, 0xad89202dc34ea277, 0x3ba1aae5fb65450d, 0x0c40cc4b79be8a4f,
+    0x069a4802affd5ab4, 0xaf24077516111604, 0x140afeef78b65b61, 0x2bc4288fa8cc9637,
+    0xfb78d9e8f45ecc47, 0x40a0079297d2842a, 0x5bf1e5ed8e5970f5, 0x33e2e095c5d00112,
+    0x5d76a305c8417fa2, 0xf0322d08e79a395d, 0xd5debe2f85557bc4, 0x88d3fc1ff10bb36b,
+    0xd65ea7f4a3a487dc, 0x478033d9330a2976, 0x7563b9b4bb5bc83f, 0x59a44ab342957f98,
+    0x83343962c44aee79, 0xd6eee0a556bf4545, 0x7f0dbf2467de759a, 0x8d06d05e831e9600,
+    0xa9f5b8f87d69573c, 0x6ff5fc111d79d867, 0xfddabf4807a699e0, 0x17ba464d4dbe7ac2,
+    0xfefe468f7c80afa0, 0x9b6a3dabaced885d, 0x7680179137bdbef2, 0x78d231b31ee7c78c,
+    0xf0163540b7aace13, 0x2fb56f984402c654, 0xc546b12f3dbd8c31, 0xc8c723923cc61bc5,
+    0x2d7028835f3fd7c4, 0x6a56e5f75ce9bdd6, 0xce26737bf972aede, 0x46fa1096fafd4d82,
+    0xc8739d0e923a6124, 0x989dca7a97f241e3, 0x06c197133d4de1ed, 0xee12cf6481c5b92f,
+    0xab153ec192492cf2, 0xa19c4fa94b057d1b, 0x5bd4b42243af8f2e, 0xcfdd0c4786770f29,
+    0xe860e7377266d88e, 0x9a4f60ccf7ca1728, 0x595f8e91adca3c14, 0xb0a53c35ca2c472e,
+    0xfc6266f7a5444373, 0xe8e6e10f766ed30f, 0xfc96c084ae9b794b, 0xaf63ee9ba71ce25e,
+    0x3b7aa1353f2e6304, 0x025d1a8a38a2205c, 0x2bce157ee2636d1c, 0x8c55e2c3678649c1,
+    0x439fd534f30435d1, 0x9e3bebbcb2489312, 0x555ac447fc65382c, 0x14559bc32ef06840,
+    0x49113036351343b5, 0xfc197ccf0343a701, 0xd5e7ff6f58bd20c6, 0x74d16c994c0d7dd8,
+    0x572aa5076958c7bb, 0xc53b87cc5026eec3, 0xa03fbc80876611bf, 0x84e81d46cedc3703,
+    0xe28c884428d73571, 0x808c00e69ea11bca, 0x0585bdc57f0cf83f, 0x72475a4a55e9e306,
+    0xa8891a525b027847, 0x0f9260575b944390, 0x120d494d29ec15ae, 0xf85eddbb8c5518b9,
+    0x1e6348abaa385775, 0xbcf66386ebb6be5a, 0x0091a4fb093a8042, 0x05deaf06484c55ea,
+    0x32fe20c225f64df8, 0x2a54aeeaf908eb7b, 0x9363d13540c5d3d8, 0x0324b3f8abeb8b16,
+    0x2041b5ae098afc28, 0xf563588f742ef588, 0xb045adb145cdb6d5, 0xd24f3b46791468aa,
+    0x2ab5d8319c0c130b, 0x470cd3ce299bc2ac, 0xf670d2d8ed9c6294, 0xb8701eed3cfc3ea4,
+    0x5f1744bc8efd9851, 0x30fcf1f0a61d61ce, 0xdc0ae5be3eb10470, 0x2aa1cc8596f5ab7a,
+    0xbfe5289a9bc7f58f, 0xc9aa1854b1d5632f, 0x3df2c1375ba8ae5e, 0xd1a550e1192139c3,
+    0xbfcd146d554fb1a0, 0x466762ce989efd9a, 0x997f0f65235b1751, 0x26a03e2a662dc4c1,
+    0xf70b02b975c47794, 0xfd24b1d727fadc08, 0xc23cc7ffca8f8684, 0x7c5a2cd9eee966ad,
+    0x65199cff373b4c96, 0x28e7e0f2696e64cb, 0xd9279466b3615cf9, 0x16bd36c54e7f372a,
+    0x26d99b475d057bb8, 0x7f46be24b0252974, 0x8ce7c645e52b7ad8, 0xa24b365283beb408,
+    0x77079b3058074f99, 0xff9d51474dddf5da, 0xc175ca73ee83d662, 0x37ad95ec1a611d05,
+    0xaa68cc2620b3851a, 0x0ed0452e2d42fc02, 0x8c2b660e30b80c12, 0x4e18085572015d0b,
+    0xc431c342b0987019, 0xbeaa9cce14ebfa2f, 0x7111d709188e0919, 0x73eeb8234a2f22af,
+    0x98af888c211f7b52, 0x41cd71c1de1df017, 0x1afab903cde1640f, 0x1cdfa32c28fb8c4e,
+    0xc308c92180b8e273, 0x415aa7ded267e663, 0x3045730408a54c6d, 0x366d84eed15dd4d1,
+    0x3198a2a3a9721570, 0x4edefca3e267fa94, 0xbc3b01bb4f7ebaab, 0x5acf7c3ce82f537d,
+    0x0b3676966dc25dbd, 0xef8427c6a8b727f1, 0x29ad8909ea0db65a, 0x33079108cc2eca86,
+    0x09506a265018e4bc, 0xb5eb283a73e55fc1, 0x9a85299bc45cbb1e, 0xfc0948be2992f6a4,
+    0x086edb6b72e4db0b, 0xc2e967ce3c59b731, 0xac84c8e918b4a763, 0x8155f1f14dd45875,
+    0xc9afca53f49e2c6e, 0x944b77c3c2180bf0, 0xd674c491a29943a2, 0x13c0ee99183de286,
+    0xbff32f8400ba1d51, 0x7da613d6608092e4, 0x0ef9e9a03cc845f3, 0xa10d260ec1f1352b,
+    0xfed8eae97690ff7c, 0xe99b24c48bda3cda, 0x09e7de2a6e51ea0e, 0xba6cb12974dd22e4,
+    0x00f42a3a94eb8660, 0x1dc5004a66f85937, 0x0d6bd8f21e239465, 0xb8634e04bc9dda00,
+    0x47f9086464716d97, 0xc20c228066253644, 0x97fd1c82124e3174, 0xf3f89d7870d3071f,
+    0x1dddbc69b1f28a1f, 0x8548063e7210e838, 0xda774b9520442783, 0x1e4b82d389ec3c2a,
+    0x04a5bbb4577d7b59, 0x9e8c234d068b0aff, 0x68e8bda990ca5c42, 0xb3f1bc41970b47e1,
+    0x5b75f212854d8e0e, 0xf516a05ad17d603c, 0x3f9b13c73c5767a2, 0xbfff5eea52e73bd8,
+    0x65d04b35529f401c, 0x70e56945a96e405a, 0x7fe0d283ff010122, 0xc77614eba610d59d,
+    0x310b5d05906dc01f, 0x554f0a2660a79d2b, 0x328b5616115e9177, 0x43c597f25bc6d7e8,
+    0x24b1e99896fcc082, 0xa2726a6e12a2e8cf, 0x237654c3147d2941, 0xf2949580fc8c3ea4,
+    0x384594f8e4ca917e, 0x1605277dbb1db637, 0x84751edd7df9f250, 0xdee51881ca19c532,
+    0xa91304ae8f4b0e46, 0x381b6abbc9c791e0, 0xc27af81d3422d419, 0x6fc40b70f1495e1e,
+    0xe8d805730a7c1576, 0xc17dc11884544cd7, 0xc0f7dbed71af975e, 0x28a6ba2696bf546d,
+    0x8d31a35eb66cfcc3, 0x114eabf91aeb82ea, 0x5fd750726036d87b, 0xfcc4286bfa576b52,
+    0x267e97d577c557dc, 0x37451d189f8b4f6e, 0xe7e3c51bae56f1ba, 0x1471f78aa5a93f2b,
+    0x1af3cb96d29929d9, 0x1c8272a564e82df1, 0xb91d1e573868a980, 0x44caeec265102b9c,
+    0x6f75accd2a98e2fa, 0x4b1bbe4b20b89ffb, 0xe4bc13b078788c17, 0xe483aea234ce2370,
+    0x9a5f01e7d7414684, 0x65c8a2a57a09c06c, 0x5dafc1932b21e41e, 0x4bba1c5469ab35c3,
+    0xe6db6287ee942b3f, 0xaf2b7391c63475dd, 0xf4c1780888be14d1, 0x724728793991df8f,
+    0x293be5abdc1510cd, 0xe1adbafab9a009d7, 0x72c97fee85b1d6b9, 0x89c7fb6adce5bd1c,
+    0x0e24ee3bfabcb1ad, 0xb7d7ac46a47c5748, 0x663507ae9b451528, 0x0b31fbd49e0b3e62,
+    0x048fab9dc5c6f411, 0x1f94b78142e36fe1, 0x3e11c10ac6882e94, 0xa490be1641b8ca28,
+    0xb74144e915fc1eac, 0x1a7d641d322ee738, 0xf8c7f06cb78a573e, 0x89fca65f0ba97dae,
+    0x37b92f420da7a1fd, 0xca6b5180378f3880, 0xca5291085f7a3cf1, 0x4a82fd8f698cace4,
+    0x9391ee4e6e898301, 0xc2ed3618368b489a, 0x64116f62e337db9d, 0xe29a0804b73beca6,
+    0x93ab63c9f77a2930, 0x9cbbe1892e7f2d09, 0x1ce8ff7105708da8, 0x6a2d7ef9b031a10c,
+    0x29501ffee0e6bb0a, 0xb87b4b6f93982e2d, 0x5c9fd6e61e05f27f, 0x5e26213491049d86,
+    0x211169440f0592d5, 0x1ff9e90ac939e403, 0x4a807d41cd803e28, 0xce5e260cb19f44f4,
+    0xdd34f61b82d74e20, 0x4a24d1ccc0649610, 0x01c80eddf2107d56, 0x648b3e7768452311,
+    0xe004e85fe344dbf5, 0xbcc4362e7a49249c, 0x2da195d511b8852b, 0x14fc5896b49ef8f9,
+    0xc641e263c4f32c41, 0xc6a2c8d64b88b5ed, 0xb8c832f9d36d0805, 0x67a872dae927f9c1,
+    0xd8db109f918c742c, 0x199993b0c3290abe, 0xe8b557c50bfa2970, 0x46d4223e14aff2f9,
+    0x3a217ea450770b3d, 0x009b55b6a06f8215, 0x22f9fdd50e6b5f90, 0x9ea1708b6684c219,
+    0x29e9290a4022d555, 0x90c77737879b59ce, 0x3021d91fa4610e46, 0x0ad3ad554a21c221,
+    0xb64988610c2af872, 0x666d93f5e52ac6eb, 0x16668e6123cf30f2, 0xbb6a2e4a4600f089,
+    0x7765b9f1e082c947, 0x272b2dcdccda0fc1, 0x19f81bdcfaa1e8a0, 0x731552da680ec770,
+    0xa12e32ee8c83b6e4, 0xe8b033a6060adbee, 0x1109182218f175c3, 0x4ca5bf97521afa5b,
+    0x1ad9edaacfdb1754, 0x9707d2155912a308, 0xa87e9572439fee62, 0xe8a75e76d91bea0c,
+    0x978cb2d7402d9d1f, 0xcd46e82ddfdb9108, 0x10c4c51b122224ab, 0x353cab5d7e3b756a,
+    0x4e1591509614d54b, 0xb594b3c7f8b16dff, 0x715d8e42fbd65aec, 0x3a633b90a73f7716,
+    0x691190cb4cb81b94, 0xf6e3e448ba1edf95, 0x41ab751d56d53e6b, 0xe601e1f4053676cf,
+    0xe77a954ac3baeca5, 0xe62d038666ec003f, 0xe4f963c9dde8888a, 0x9472181b505d5b88,
+    0x31c76d7262de5144, 0x042ecade3a764aa9, 0x0c4c96217e57e1fc, 0x4b665dabbcefc33e,
+    0x8df50b7c02448cf7, 0x6340b54e704c7f05, 0xfa958ab98e02a43c, 0xb1c2aea7204c6364,
+    0x104c227284230bf2, 0x033430b35e493866, 0x05e663fb1528fd28, 0x247fea8b91c2a86f,
+    0xe92910d352e9ac74, 0xfb91ff55285583cc, 0x684843b21e915d8b, 0xfbf9d19673f90171,
+    0x8978c0c1c1127071, 0x321760c82b1617c9, 0xaeb97c5dde0e63f9, 0x1b30fb5e94d6edf1,
+    0xf56ec78bedfe8ac3, 0x61ee035e1bfb651f, 0x57016f5018395ce4, 0x7831bb9f2fbef31b,
+    0x84523258fc93f3d7, 0xf3a1e4bcd912f866, 0xf331a906f892dfc7, 0xc22f219e89322db5,
+    0x379d244a4b5a1a64, 0xaa808c6b300324f9, 0x05e6d2d01e29f36d, 0xa03d5bf211ba61f3,
+    0xc3a83db9443699c8, 0xe9889144af674d5c, 0x1a29b4c093f13990, 0x86a2f4f24f1aeaa1,
+    0xb135008bd89e61b4, 0x627fdf65304d9578, 0x3221b5feadafc830, 0x1b96ea58dca57d80,
+    0x861cf358ed89b855, 0x85204eec7cdaf9ec, 0x09426a94a7a75d40, 0xfe806da59023a4ea,
+    0xdde840308ff0bad9, 0x68523d981580bbef, 0x3ef6a7fc21d74a25, 0xe37db4b5a605d0d0,
+    0x29aeb3653fa50a0b, 0xfde2b6188e857a47, 0xf95a3cd9a1c0d851, 0x43a4f85b95bfccf0,
+    0x268c873d84c5bfee, 0xc83cf32650983597, 0x9789aa95b35efaec, 0x8a52e1cfe1e20008,
+    0xd31e4b19ac166ee1, 0xff6c07fc2b7ac414, 0xba184c97804f6a4e, 0x0d1f9dc9b6437f8b,
+    0x6825671a3b39369b, 0x232b345db03999e5, 0xff469917d6a3dc23, 0xbd202fd3925ff241,
+    0x33e55a74ffb7e96c, 0x2ba543f6111aec5f, 0xaeff3dfa1d68b9f4, 0x187aa5e9a0ce6ad1,
+    0x0aad106b899b83ab, 0xb845bc758ce85ef0, 0x46da5b5b0aceeab5, 0xf02e7c1f4af28f66,
+    0x968e9385b0bc0f8c, 0xcb7725e349237caf, 0x66834f68c62ce1b4, 0x26ba37e8c75aed24,
+    0x23acc7029cadf52b, 0x3f0be7e98d684fe6, 0x2e5d834734197420, 0x5f8ff7a5f47ff009,
+    0x7973995a637cc36a, 0xad3878db4e2f9a22, 0x42f0ff386a0854fd, 0x67a70da0fcd2a64a,
+    0x572deceabc7b9c29, 0x403d84886d9b446d, 0x4ed9a3031b33f518, 0x69008211ecdf1019,
+    0x3af19d2c9363acbb, 0x717b354a37e972a4, 0xc67bd071b90c43a2, 0x137b72eeb584be2c,
+    0xf66928513f99df84, 0x4a784794549af01f, 0xf0763c9d4f768e5c, 0x54c868920810b7e6,
+    0xa80d2cca8d68eac2, 0x554d67d5a110e34c, 0x9a4193ddb9e6cea1, 0x580493bb87320ee5,
+    0x6c7b87bf561df00a, 0xda5e470457f94ed5, 0x8dbffd7bdb8acdb2, 0x29be82b3b14bde5c,
+    0x90deea2a457b7778, 0x2747bd553876372d, 0xdc0ca896336492e8, 0x1c37c2885d4db6bc,
+    0x70b3e1dff594e26a, 0x68bd071131afba5f, 0x294506ef1bd1d534, 0x025aa9f8239838f1,
+    0x6a7e4866b40c129f, 0x8861de9f60813bc8, 0x84c689dfa722b954, 0x7d101de4eaa69274,
+    0x0569ec81e63740cc, 0x719dd88c76bd7c14, 0x9fb1f61f81253ae3, 0x39794d35abf324cc,
+    0x931b0b8aa0d7f98d, 0x5d55a952148aa882, 0x429db8b869550b4f, 0x38457b247abe25bf,
+    0x9431efc328a541f0, 0x69e8aa547a0d710a, 0x3beb615cfd93364d, 0x1ee3a862f753595a,
+    0xceca3c3b564841ad, 0x0ce56d48f587db66, 0x29dfe982199695ca, 0xf04cdb1d705389ec,
+    0xe27154a53b282106, 0x5703949bff2b08f3, 0xde0c49197ff3914e, 0x10344d1169af28c8,
+    0x7e0ad61dc1091a9c, 0x8f5a97452d5d881d, 0xbffd7c1451645821, 0x9b67e47af554fecf,
+    0x01a0ce802ff652ce, 0x351d318917c93638, 0x0d01590a21ef7a33, 0x46da60252b9ad167,
+    0xbcec9a24e65dd67d, 0x59983a2eee45cbea, 0xd9b0308800bcb139, 0x211dbd6e088d4a33,
+    0x88f19ca84915a696, 0x43dc962f018fffa0, 0xc5c8c3005463373c, 0x8e1eb73a67617dcc,
+    0x32d2b28691bcddb0, 0x6777006f316134ea, 0xd444542a4871c259, 0x7c4ac8cbdc99c015,
+    0x25c5ccf5535bf884, 0xb6e90774f87cbb93, 0xd9acb608a04f62db, 0xb3be9a5dc0975698,
+    0x52dad96336df2be4, 0xa3d568288c0006c0, 0x0a5ff98113ba5fc7, 0xa0ee78518a7bbb69,
+    0xfebf56901f692ffa, 0x25ba1c932c2c88ad, 0xee83c6d269aac0c9, 0x487a22f85d3f6cbf,
+    0xcfdabe480032262e, 0x35f9a9cffb2d0e15, 0x18b043e31be47b06, 0x22264c965d8960df,
+    0x06a8cb390475f454, 0x2d61f53b4c2a82b1, 0x6f47aafe5b4db4e2, 0x9ff8bc0eb23c38b7,
+    0x48164dbc2a040e78, 0x3b20e7a1d5d32b55, 0x444de4c68fa48a0b, 0x614de2bfb905bf17,
+    0x83f59cd799de00cd, 0xeb54795836ed68ba, 0xb0c83c762b71482a, 0x22d58fdf6566a208,
+    0x5555bd35592cec05, 0xd784f191f1006e6b, 0xfa68af2194fe3a2a, 0x8491d0ab260c0566,
+    0xe690cbd616e99e5a, 0x67b268b1471617ab, 0x698a4a5e6f5ad099, 0x124846adad3115d3,
+    0x114fabb6fc440cf3, 0xb82f94a6d74cb129, 0x4aa3253d373ca860, 0x27376771f1aea7f7,
+    0x194ba54f04523f1e, 0x949d1851218821c8, 0x6d239324d4738af4, 0x292fdb03738d20ba,
+    0x41b39d43be86b518, 0x384588b9edcd8144, 0x1572d79c8f371706, 0x9c4a8ee9fd350923,
+    0xa56239fdfc34cfc4, 0xc43aae999a454c07, 0x930c0c2db68d8d3e, 0xe436d7ec5b4bd0ca,
+    0xac01dd5a7bd8cfc2, 0xb30bab30c2ca5e7d, 0x1902d9d195a8db67, 0x703f83534214155c,
+    0x169ba2d3c1bfdadb, 0xb812537765af9a4a, 0xc905aedbef5d0eaa, 0xc6f309baecaf9c3b,
+    0x13e7b6a1dd0c56d4, 0x1505447e0b3d7719, 0x1d47aba882a7b656, 0xac85dd55c8931c3e,
+    0x923c4d4abd256204, 0xabf9ae6c1b18d003, 0x48c7119584ab09c1, 0x4795eee6005a6ffa,
+    0xf2e134f96b4ae8a3, 0x5a4e81202732e5f4, 0xb24dc89036b86839, 0x2105e87a3e77eb38,
+    0x1c6dcb2aab605a4a, 0xff9fb66d274957d3, 0x44bf58159cb605d2, 0x49235c7a32b0e5ee,
+    0xc4fa7f54174035f8, 0x1601268510f80106, 0x0c3d10b610d9bbb6, 0xd90fec9678a8fbe0,
+    0x8ab7aec82fa7040e, 0xab1ad855dd8af6bc, 0x2544617ac55f05da, 0xbe223676f3b1a48d,
+    0xba232ab5e33b688b, 0xaf72db36e89c5ad6, 0x8397e7ef5b6da7e0, 0x9f804acf5615973f,
+    0x7a8cc372b627e890, 0x77de46700a132b90, 0x0a7f45c013410435, 0x673b072c450c96b3,
+    0x28f4a1d024f968a3, 0x9fb81effbdbd758c, 0x0ccfd838b8084712, 0x0ce02cf44b3ce082,
+    0xccb55a68a15cc623, 0x61f811e7703a400e, 0x0069f87c917d5324, 0xc7f0451a9ffc7674,
+    0x503e378c128f86c8, 0x9ae0329d81535ae2, 0x9c8a72e5f4cdffc0, 0x192a233babb3eb26,
+    0xdbb77508fe589e03, 0xf8a199db2377d702, 0x38270d5e49ee411a, 0x7e6151f0d1163531,
+    0x9a500e319279f60c, 0x41db621be4ba66bc, 0xd746d0e096a5b110, 0x31367504badd00cf,
+    0x5aacdb488f631f78, 0x572fb78afb4acedf, 0x14618bffec6f1e85, 0x46dbcacaacbdd10f,
+    0xa4724a21f1f1783d, 0x5b22a5cc89675319, 0x17fc158b1bf13ca9, 0x6094b878ab2736cc,
+    0x64d2090c66ba6655, 0xa53fe1166dbab33a, 0x943a9d06de66a8e3, 0x6fa90fbc0d1c61b4,
+    0x24642402a8203306, 0xe045221245643eed, 0x04103db79529054b, 0x1567c4eb0f136c5e,
+    0x2582159c7a8370cc, 0x183c56f9c2495af6, 0xa6af23966b02b2e2, 0x8f7e43d0c563d172,
+    0x10357146f7de540b, 0xc2bac81dbccee2c4, 0x78a18906c7e1f01f, 0x1d2672e2009ac201,
+    0x9234867390376fbc, 0x300ca68f40f20f73, 0xd4ea8b69d0d5cfd2, 0xb919d9963c49fb3c,
+    0xc514078687a86480, 0xc8989c573be36503, 0x37e7628e04d79584, 0x191ae587dc3398e5,
+    0x231c6a2c7d9a1428, 0xcdc7a06cf4379881, 0x340a58c6ed4c746d, 0x3e207e34c128ce6b,
+    0xecc7c4eb38fc53c0, 0x16f28c0927311116, 0xc14106deb5d61caa, 0xf9c30440f1d1897c,
+    0xe4c19a8747cab8e8, 0x7976a955b675be98, 0x1686c729eb7c4b05, 0xd4bf0c809475ac1c,
+    0x950910ca59c76135, 0x1706698738a058cf, 0x94e26c25e1462aa9, 0x821b73b2ffc28e4a,
+    0x739b853542fd0039, 0x5acf2bdf5e1fb9ae, 0xef0dbda6fc55cf82, 0xa9512ad8328fb85e,
+    0x27a030e4b258e16b, 0x23f0c2e7809feb5b, 0x9231b56a9780734b, 0xc485d47ddb10fa04,
+    0x77321d0229d70c80, 0xf7b3f5a7bc711a3d, 0xdddd91676a6173b1, 0xcc0f3f661356be0a,
+    0x7d833c72fbc1cdac, 0x36b7993e740613f1, 0xf1114a8cebb147ee, 0x2ff6c35185a6211d,
+    0x1d738b7969cc8d9c, 0xecb9fe4602eded20, 0x3f5564b11671b164, 0xf7233684ad7afb0f,
+    0x0ed5c6f010dac92b, 0x820cd8a9bc3cc6df, 0x569f98c71b4fd528, 0xf476c47380fdca3d,
+    0x6b3dd41f5b3a1f64, 0xc747f0f70dfca4b8, 0x06074a1eee8fd945, 0x3a6391d41f6e2ad4,
+    0x6273136bfd5ddacf, 0x2c80d364971a5365, 0xc9b53800fc79ec60, 0x1279adbf8c676779,
+    0xe07f07ef1e8e0136, 0x943e72f8c32b0b4b, 0x2b507ed2d85ec18f, 0xd232a65f6211f0fd,
+    0xe62304819e2393e2, 0x30e7a3fc69ac2e33, 0xe5c2d2960f90d970, 0xe6673f6c5c758926,
+    0xdeda1eb9b93c6ac3, 0x4ff7a251b473eb4d, 0xd59013b3dc4fbd89, 0x8a87f645913e209d,
+    0x754765f0203459d5, 0x84149aac27fba9e5, 0xb079f88cb8d4ea03, 0x3e2cf189dfcce4ce,
+    0x3c6c882a3397ca46, 0xe6eccc403157892f, 0x3d303d3e54bb78ef, 0x5e7a7cada8d7ff52,
+    0xadb72999f276d925, 0x54b8e19dfe6b72f9, 0xbff72835025deed3, 0x023025b09e9b9703,
+    0xb25a6e906c300abb, 0x1cd6926da04aea72, 0x25cfe150ba43f27a, 0x7b1a73b2f9ca088c,
+    0xdf1a59ccb2348706, 0xa1493e6a8f7b80fb, 0xc330ce90525f4dd1, 0xc0b3b9b3488e403a,
+    0xf8e10fa2442e650c, 0xc7e39bc9a37aebc3, 0x14f214bfc839d40a, 0x8dcdf4a07a1ab390,
+    0xe22e33a59d648f17, 0xe20d842516ab0900, 0x0875d71658943b74, 0xa4811fafff10a049,
+    0x84bea3e8cd6e34d2, 0xc0a267259d4c60cd, 0x36118a26eddc288c, 0x2a3156d8a6695cdd,
+    0xf65fd6ec057557e5, 0x2359f21c62c7954c, 0x267f90797bd898e8, 0xdde4e422269890d0,
+    0xcdeef0acdcc3c5a2, 0x7ec9f6ab5aeb7e8e, 0x69453a6dbc474beb, 0xb00de718924133d1,
+    0x3d0e278d5ec60e7f, 0xb0485429baee235d, 0x1e678cd39ab732a4, 0x3cac5fb797164115,
+    0x6781f98083354a8b, 0xc2c962c8c17fbf81, 0x100d886b8bac92b5, 0xb1d3448e008283db,
+    0xc5617a4cc548f1cd, 0xde0efe330a431fb2, 0xa24666f672eaf913, 0xc3dca2fe84839eba,
+    0xc9aea7288213cca5, 0x2dd44c742a97507b, 0xfa861a91a298aa23, 0x7dcb6ba22a3dbc9f,
+    0xe03fac0884522539, 0x095fd7bdb14f0d39, 0x667b4c84415a74b9, 0x104a4bb382de91f9,
+    0xd70f3fe894c3abc4, 0xedff40c070aebb02, 0xa5b5913a65b226aa, 0x66e12c16937d03e7,
+    0x7bebad29bebf0821, 0x0205ddec7dd9b05d, 0x22d1bd63318fb20b, 0xdca6ee7eae0e5d66,
+    0x81038e2aaaaeaed6, 0x3b2e83ab25ea087d, 0x324cdc533f758c43, 0x0611510e9110b8f3,
+    0xc85ee84246d4d6cd, 0x3363ccf6c0bfc688, 0xe70a9291d7213a0e, 0x993923238fe95c91,
+    0xbd40884f142af939, 0xe3cf62e4b1d1f940, 0x35487b8305aaa00b, 0xacddf1c48b4a468b,
+    0xe64ff130a05fe67c, 0x228d41ec9df6f6ae, 0xd7516f0627c77284, 0x7c79eef12e64094b,
+    0x659aa912049b2b63, 0x2d9f3f7259d4a2f7, 0xa3900c14a2166b7c, 0xf071371735169e7c,
+    0x4ca328a82a2924a7, 0x91c2fcf900c29f32, 0x889c5bcef65eced5, 0xdd00aac801fdd050,
+    0x5fc6421a3aef919b, 0x993fe6876c804efd, 0xcc47ecc6268dffe1, 0x02a7e7c585ecac87,
+    0x891fcbf2028da531, 0x8c5766573f8ce630, 0xc88e665cc2279297, 0x9b207ad756af03da,
+    0x20c844132e3ffcd7, 0x0cc8e4724e409dda, 0xe75be3637a5e4bca, 0xe795f169b7f0d48d,
+    0x63702c15c70c7c07, 0x0ef0a597ae0944ec, 0x3293b2b567cf5995, 0xd82a964f95690062,
+    0xe16c7bce349c5256, 0xcc81ecf1f6b8bb6d, 0xae55662af8f338e9, 0x98ecc4e92e0d878b,
+    0x1d09c872c877292c, 0xc939aaa30f7c824d, 0x2e69d299011855b0, 0x3d79c8699f516429,
+    0x49cfb5460a0fd82a, 0xc4c20d36036a31df, 0x176c170d8646c4ed, 0xed7c1b7159d5ed98,
+    0xe1d419152a34f31f, 0x92d9ac1f17fc2d9c, 0xe6f661617420149d, 0xc7fe83ee4616deb0,
+    0xe6b633bb89bf0f25, 0x0dd55d22e7de655c, 0x65c4f5e0670312d2, 0x36122396b6cab9f5,
+    0xcca0d89a3fa206cf, 0xe6570cbc079e75c4, 0x2f64f0817ca7334b, 0x2fa54104f0b2180d,
+    0x116d39b9bff4c3ac, 0x9be0fc912dc530be, 0x42af14155ea96db6, 0x345bcf03bf9a05ec,
+    0xa4bf4cd84496be68, 0x185e24892b15db48, 0xf204c0f3204fc2d6, 0x0bf7b133c238e905,
+    0xc52886acb9aa6ead, 0x67195f4ffe5741eb, 0x39cc4761ebce6bf5, 0xed06313e41e2dcb3,
+    0x78644f0d8d4a3b42, 0x4f80b3d7a37849d4, 0xea2f1b51471dbe27, 0x19641fa8f70a28fc,
+    0xf1c8bceedda13ddf, 0xbaea1f2e88201b71, 0xf2983161f73d0286, 0x55849c520a8b37f7,
+    0x180ee0444af3d62d, 0x9e92a9d57a275666, 0x1ec2effb4f9cf689, 0x22c0c862c8de8302,
+    0xa8d0d1140e4844f3, 0xb5c764bafc8d0b5b, 0x566cfd20f4093e71, 0xc74c51d4012a75d4,
+    0x535890ec17409ffe, 0x8286202cacd04096, 0xf9a1e967bea9f42c, 0x7f6beae2d346ed48,
+    0x48a82348f0488c5b, 0x438874217a806612, 0x2c565dd8f815a304, 0x9dfb6e180bed2010,
+    0x1c22508d1f986841, 0xf6742a3d27421d0a, 0xefc079491cf4bc3c, 0x377171e5ab5cbf83,
+    0x22fb1d83af0f3756, 0x71b32ad88f92151c, 0x33ee8abe878d6ce8, 0xc510618934dbc04f,
+    0xee81116872f50003, 0xb6ff2aeb766676b8, 0x938cf0bd2fe2efc4, 0x572292c98c5e3064,
+    0x85c8cec6172fcb86, 0xfd4d50712deeb9ac, 0xd8ae1222b0776f73, 0xddbfcc62a9970d39,
+    0x103da8b76b71dda9, 0x689cf02f5ac0faef, 0x45678a27f7b27b85, 0xd41843358435fd45,
+    0x4bc6b4b00196d7d9, 0xbe4d4e07566da680, 0x60d4944e9a6a79f5, 0xc65e8e74f0f68657,
+    0x5387f8f08d9ec8d8, 0x63bd1e3f2565a9ce, 0xa6e22750e0ff3a81, 0x26e8855a70f7011f,
+    0xd74b716a1a402ee5, 0x548b2ee05469a910, 0x9f080480b436a9d0, 0x6faca7c63304ebd1,
+    0xfc83deacb5c51e07, 0xf6cc4ecbb693bdda, 0xde269353a4536936, 0x6249c3328e500dc0,
+    0x7e3bebf28554415a, 0xcdc0bfb8cc13014b, 0x96714a19ecc0562e, 0x7a27d55e23a5f822,
+    0x9ba85c535d274bdd, 0x4b777137e6c8b899, 0x396046ca37f27dd3, 0x06be2c6de582469f,
+    0x34d38bcd6aaac84a, 0x44cb10fbbe2865c1, 0xfc0800910ff1c4c0, 0x8e28ed80f32ee9b1,
+    0xbc00e76867476126, 0x5107fd248b4dfbcb, 0xf87ccc2f571099d3, 0xde8cf499da5c3697,
+    0x1d98e5c6fa48e4db, 0x045e71a55a759839, 0xb32eec197d7926c9, 0x3475c63fc15a93bb,
+    0x9d265e24735c7c7a, 0x2f39d9ad0fa4f40c, 0x8cf5462e082b3e4b, 0xf23b113497be7051,
+    0x4713bb252a52cedf, 0x7f8d5f36fa71e00a, 0x361cab86c018a3c5, 0x0c860213e2fb36f4,
+    0x7bcef0ac7e6c7bf2, 0x817c79167ecb4aea, 0x655f0ad61337ea08, 0x540eaedde00ca36e,
+    0x6d378e97c3656e77, 0x957ec475a48d40f5, 0x6226ee24757345c1, 0x72ae50bcf1ea3711,
+    0x0398c227be42a459, 0x12ab17d4c3399dc2, 0x7140f4d1c63ea4c9, 0x7a22f03b3c28fa3b,
+    0x3bd3d3fe3d357881, 0x41b069ca379e2ac7, 0x5fa8b00eef7195a4, 0x23c4ecdcdb8981a5,
+    0x4aef781ccf8b1c30, 0xf03be2295fd0ad70, 0x9dadab6f88baf14d, 0x024165ab0f15e190,
+    0x15c97c3a8523ea8c, 0x985bbb330847d6bf, 0x00fed17287148f8a, 0x8d069b3dfefceed3,
+    0xff7433e986165b66, 0x3aca27cc43ced78d, 0x32163a2f7450f5f1, 0xf58387fdfbc1df04,
+    0x1be3dea0a5a7de23, 0x7baf67f4c29d4d80, 0x4bb0fa64f6d35d07, 0x007a51ead564c846,
+    0xcae2c84cf7bb24f4, 0xc25e096f181352a0, 0xbaf3d89c2ac8d5f5, 0x08c2f196a7d5fa56,
+    0x71e15257648a5b22, 0x2a7a8f861c6acef6, 0x619828c68f75e802, 0x4b9fd57c96f38a49,
+    0xb2d39cb88d3526bc, 0x78df37064113d336, 0x20b9cb3ac4afbf47, 0x4f312d2ce6a9eed2,
+    0x6bb7752dc32c119e, 0x1ba39e112d544fa3, 0x32208c9e60760f14, 0x0e758bb3338fbed3,
+    0x7cb0214d2b83a0f3, 0xa8c9d0a4cd62f770, 0x2511ecca1bed1328, 0x8a2c20e6358c2ecd,
+    0x7d238f9911b5181e, 0x83b9d75a3b9e9934, 0x6d876eaed2dd50d5, 0x020873d5f92bcea9,
+    0xa529422ef5a8b750, 0x6e84f3118e1de63d, 0x5dc47cd4d9d4d9ce, 0x650a0d6d208177ba,
+    0xa53d6f90e912ca53, 0xc7ade652913fcc84, 0xb676dbf380d4ce46, 0x63ee8ee1ab97e5a0,
+    0x534c961e239c1de5, 0x7833b865337638dd, 0x50e1e29656d9dac7, 0x7c8946df0c4b9ae6,
+    0x9d7a93ca5d558447, 0x4d05ef06c66c2121, 0x1f3f7edd2059d072, 0x57e9afd900d41538,
+    0x054d206fbc953f8f, 0x3dcf027f5c8dcc71, 0x9d439dcaba84d028, 0x6ee1dd794edc9590,
+    0xf1239ff6f203f039, 0xb31d0f4c59dfd355, 0x96555b0151b90fdd, 0xd4e571c9f1a8b8d5,
+    0x1984f9617de98de7, 0x9506925aec229e76, 0xf01074d21f5c2a85, 0x711988f68b41561a,
+    0x9b0a25df2a190f4e, 0xbba25af7541b8abe, 0xdad7d47642dc5952, 0x7ccecae0d742f9e6,
+    0x8ff70c4b53da9a66, 0xcd25f3480392e4ad, 0xcd19302a70df10d5, 0x2acd0003397bc87a,
+    0x0fe6966c1209d190, 0xb64d07748b3c2ab2, 0xc4213f3c6540a7d8, 0x9db4997d9f12e2c5,
+    0x0b0c893809eaca83, 0xc711c4a92f30e1a6, 0x64bba19cee0a955b, 0x420d32af4162d905,
+    0xa670c295549599e7, 0x6506ce5862664e20, 0x33682a8a9741620a, 0xa23ce1579220e218,
+    0x10e9278cc5aecc10, 0x54129c5b97ea50c0, 0x05475a0adeedd8c8, 0xcc4d11bd7318ef31,
+    0x0593cf23a0720972, 0xd9dd5f5a3a1b0c64, 0xbad4ebd59d17f140, 0x9207d9b527812f9a,
+    0x178edae52c144968, 0x5986e4da90319440, 0x97ecf6ad0ab23275, 0xc44d2a1c8f1ab2d5,
+    0xeadea59b4d33f7df, 0xa620fa5f5cbaf443, 0x84d257da92babaaf, 0x00eaafe03bbb56e0,
+    0xe3dbec7bd7d766b0, 0x2e8eeb8ed3045aa2, 0xcea4b08bb4eb8f8c, 0xdd0a1a0ba130c06a,
+    0xddee7d812ca0c0a5, 0xd1cabdcf2f40730a, 0xd29635083f911358, 0x1678b5be459b158e,
+    0x4826704cd3cc43ca, 0x43ad8fe03c735038, 0x8e571f52a1dd18f7, 0x5a9e7d5a0aa771e1,
+    0x7d8ea3e8f495131a, 0x66d02573e51548a9, 0xd167953d7f39fc9e, 0x2dc457b05bbd26e8,
+    0xe20990fd1350d1bc, 0xfb0439003e4ca680, 0xba2be98a818a64c4, 0x5b63e64eeb423c8a,
+    0x635bcdda8c8c7696, 0x03a0c85a7e9c703a, 0xc68d08a40ae9a64e, 0x7bafea3c4e220663,
+    0x191fd989d4dc0cca, 0x07af227cde8561aa, 0xf152902caf26f362, 0x5598f36a39d4d530,
+    0x71240d7cf63e9dd2, 0xc555f0a00a4c047c, 0x31f2d1a5af8687c9, 0x9b0a241d22a8b34c,
+    0x1843c2cb31f4b8c1, 0x0f3a460f684c913c, 0x328051496a076500, 0x76c3b1c5f9406140,
+    0xc0e6b0ef836c9785, 0xb87a466c19536b78, 0xac096f68d4d040bc, 0xd38a04cd28d888b6,
+    0xb1b5287146e8252d, 0x35c9e641188f21fc, 0x3da11089d83742d2, 0x27f6743731173eb4,
+    0x036664bd173c894a, 0x17c403082ffdb453, 0x1d6b2cb782e0b735, 0x4930eb7f6febcaed,
+    0xd4fbdc93cc154b6b, 0x5af1afaaa0c6640b, 0x9d12a380ed0ff7ef, 0x1bbb450e0ac3bb19,
+    0x15b9a78b596a6cbe, 0x34856a8473b1c16f, 0x7cb25e3bbe33664a, 0xecda248b7a8391d8,
+    0x32cf53e27abe6bdf, 0x17d7b86fbe12dfce, 0x5198f88a135e582e, 0x95a563716b3816bc,
+    0x4e7fdd8cd03eb82e, 0x2ab9a66623edce70, 0xb76e89e0e4096c9f, 0x8547742afae8021d,
+    0x416f15a3fc88eec2, 0x614e1c538e5720ae, 0xbe7cdff23701e364, 0x25487e65264a6e8b,
+    0x48a43d01f38b8cd6, 0x3c1cd9748c345360, 0x5e6248dcc6c184d6, 0x4e1041b7c41e3d95,
+    0xcea8d356fa233823, 0xa3b9bde0f6fd70b1, 0xd7e8fb64c9db033a, 0xb5b10b982e1821a4,
+    0xcf65e2798ca73f9e, 0xbdf21629cf036a1b, 0x50648aec3f7ec066, 0xbf8cf2da1f678c67,
+    0x0268d28cb3002ce9, 0x62c2fc741d03c37c, 0x337e908604fef8de, 0x1c5f2a91b433fa0d,
+    0x6d3ee5a74a9c5ccf, 0xe5da12449ac0ba00, 0x48637775674e369b, 0xf5d49ca6b1141663,
+    0xac16813d49dac992, 0x85446112036f6826, 0x87a262a28943eac0, 0xc59056f2bcf1ff03,
+    0xf12ff8b926cf5774, 0x830b43e2dd91e707, 0x33cadc9f6e89dad7, 0xaa866f82ffd944b0,
+    0xfae3f918c778da32, 0x1b9a8ad66662ee45, 0x113f4f0387468a6a, 0xd8283689326d0f51,
+    0x18cd138fd065a197, 0x60d3099d47fb3ec6, 0x1b759546b6cf0150, 0x0fd6254fbddcf5ed,
+    0x5d05dee0f9bf8840, 0x7b625d5f4a62d5f2, 0x81dae59b87dc032f, 0x3f754d34dc67124c,
+    0x5f71b548b0cf790f, 0x458d9d0f73e55b5f, 0x21bf70677ec37461, 0xb5db480a5b51555f,
+    0xadc2df29cd605ac0, 0x13c29b625faadfc5, 0xc10dbfb6ba0a0bff, 0x14c4dcef6f724241,
+    0xd9ea5a5bf440f3b0, 0x3ca89fc5d5d07ddd, 0x4638e346f4e6979c, 0x6c6ea5d2c7cd0f46,
+    0xdba80aae8e5b0204, 0x27714fd664222182, 0xaee8635e00259247, 0xbb6bd9793becf149,
+    0xeefe3c323d3c2e9f, 0x54d1d05325825b37, 0x3d27470379395bf5, 0x365b97e57b9338e2,
+    0x8437e8b340abe542, 0x843fc51b8a59bd2d, 0xf615b5cc2ef0dcc9, 0x086c67a91c0b26d9,
+    0x63dd6cbaacd2b8d8, 0xe7ef7cb66bc4e1c6, 0xd129e381c9fd7f87, 0x8cdfc8fb8d414514,
+    0xa2407350e99dcb17, 0xdf1b274e4a238ed1, 0xccb0c1c8adad2dc1, 0xccc97b258e0e578c,
+    0xcbbb6a36013d438b, 0x64c907d3f5b9efe0, 0xafbb3d3c71f1b486, 0x2aeff77c42b02066,
+    0xf86a07e9e5c64b4b, 0xbf34d2762417025d, 0xfb8cca2cef0d813a, 0xf7c4708e57bf42a8,
+    0x3ea958f7868606a3, 0x9c72089415d3547c, 0x5d3cef101e1e9b23, 0x2be097ce606afc8c,
+    0x6b77ac1a10869b09, 0x9277374e2ded2c15, 0x061d2f16e095bf50, 0xbc1df94029666c2d,
+    0x3a5f21cbb2f01445, 0x2fc5c80de7fcb9a8, 0xbbc5bc379e588331, 0x18338fac08a90c10,
+    0x997d27e6bef81568, 0xdc3713271e5f8aa5, 0x5cf7202bc9dce421, 0xd6cf05273ee9bf6a,
+    0x0739c6674dac95ac, 0x9228a05683d61d33, 0x7061fb763f8acb71, 0x19c6c95956f75c6a,
+    0xbc7445f92c9bdf78, 0x6ffb38af2e2e8d8a, 0x3cf433a91303db78, 0x5bbc8ab06ddb5429,
+    0x8376cf06f9d06d32, 0xe51cd325c78c9a6e, 0xd78ec454ffafacbb, 0xc5b7d86b201906ad,
+    0x2ec5b6c6ebd0aa31, 0x224ef5005ebf0ed0, 0x7591ab5ef98119be, 0x64b4b17ef677c0d2,
+    0x78a136b50d2c609d, 0xb2458324b4043f65, 0xbd055a91da0f18d2, 0x5da1c22e283a4771,
+    0xef2b94d3cfa7e2a2, 0x28c1a826172483c9, 0x9a26734a4fa56590, 0x3e420b304d9b88e4,
+    0xcd088c051294b694, 0x60bc54ae210ba0b9, 0xd28ab18629c70adb, 0x9d57ca73b423fb12,
+    0x585de966a2a61f31, 0xa6acb1b23d26f78f, 0x962351cbad0510f5, 0xb79db61d9e386e4d,
+    0x85ed067b39cb2f38, 0x0a7ef3c678a05560, 0xa7d71c8d722e62d1, 0x4dad6b3dbadadae9,
+    0x4e72cb427fd56c51, 0x8c7bf048d27e1e67, 0x96ba05ae54dc474b, 0xa429f15a07996aa9,
+    0xf69890305a32fb6d, 0x1eb8608137407a91, 0xc1b31404cb52c8d1, 0x262b77e7fde66381,
+    0xd3404073f1601587, 0x5bc22f80788d2be9, 0xd35fb3a0b7fd57df, 0x76aae2725fe43f34,
+    0x946a833bd21bc772, 0xb2b5663e4e2f3f1d, 0xe3eb10fc711f8f3f, 0x0bc6913025d47dc7,
+    0x6a885c5c28f3cbf1, 0xfc4a3ce5a8555653, 0x91b8d7623b976748, 0xf33291a98dcaab45,
+    0x6bbe6ce119399dc2, 0xa48b7ae61ee270ab, 0x9fa17082f9084a45, 0xa88f8831f3e38468,
+    0xd53bfcbd4169510b, 0x78d5ac23573f01ff, 0x7e586625acbf32a1, 0x03764f42517b0a0d,
+    0xdfff39dd617e1499, 0x7e001d1cd0fc987c, 0xc3bb114346de2245, 0x031f96a89d01707e,
+    0x08f981b1278a4e45, 0x64ff048228e36f1b, 0xecd4c0f9775f1f7d, 0x29fe3b59bcdb7958,
+    0xfec25f90e4a0d6db, 0x6f10779f5e5a0486, 0x206aaf68a8d2d40d, 0x43d486b4fb5d0399,
+    0x182c28b83dbc4c33, 0x83b0809d0a5a5857, 0xbdd26c810bf8d694, 0x8205fea8542db174,
+    0xe0fd437ba14c3051, 0x2425a7abbaa9ce85, 0x7b1154cd768777da, 0xab301ae01c441398,
+    0x5ef9c289e09d065e, 0x95f6206a518adcf0, 0x9de1204e8f931e25, 0x8447acc7380d9e5c,
+    0x1bc4582268f94315, 0xeb90b1e499974cc5, 0x6963760edd23772a, 0x84b018a64396e0d2,
+    0xb905ffee64700909, 0xd818015b2a4b9d09, 0x69b0769d9df61561, 0x954688f6dc713dbf,
+    0x6d4ff3f345457648, 0xfb101b1eaa387f79, 0xf0d3b1ebcb5afd0b, 0x66d27730808e3815,
+    0xd2d73b7149f2ff72, 0x815969e9c056fc4d, 0x6e952f2ff10c465a, 0x075d39d5b3ff4ad3,
+    0x061034d3ff6f05fd, 0x1ea7b36e75999b1f, 0x6b1f299c611dbdf8, 0x058d0a9c916a1e74,
+    0x1ab8897d5672f0aa, 0x2ec2f5567d5aec40, 0xad6e601b94ee932c, 0x09496fc669d82651,
+    0x5aafdd1230779ea4, 0x638cf56d9dc77b9b, 0x2fe25abc8d5f7ff0, 0x6a1abceede584c23,
+    0xb1041334e1870531, 0xf24ad30b8d727993, 0x92a2fd5f5ddf2218, 0xe1962645bf5dda67,
+    0x90fab54b5c561592, 0xa038f4e6e4dc06ae, 0x949b28e46b9b8f13, 0x41eba798bb3b1911,
+    0x57f2e8ae5c7391a2, 0xfa8f869577e7f0a7, 0x25e665ff8a9b1d51, 0x9645301fb0badfd0,
+    0x5011efa15e16114d, 0x48756c5c528af148, 0x1f025a36af2eed06, 0x8ffedc9204501bea,
+    0xc0bfc96902070182, 0xc06d6bfad1c9950e, 0x5203861aa1a03cb0, 0xf53b26d56d469690,
+    0xa190a5b6d0666c03, 0x452b5b7934c9bd6b, 0x37079bab467e1e5a, 0x9be13d935d593fab,
+    0x0be2a461d3264094, 0xb837824f2f973cdd, 0x7a0161c8914440d2, 0xcb5cb1b69874c191,
+    0x3c32532b7f08718f, 0xc47bf9a911fc6480, 0xcc3c35e49dbf9f42, 0xc955267164b8ed79,
+    0x90f62a1ab1af48da, 0xf122b5343887375c, 0xf7b049062ab3c481, 0xe82203e85eb7d8d1,
+    0xfde4b05f3b3cd56d, 0xe88ab25368b7c488, 0x8c3d32e80c506777, 0x1faa5bedc30f10b0,
+    0x845aa5352545dafa, 0x3b0af082a0d3054f, 0x75c3707113cdcfa6, 0xa640e0cf5a29defd,
+    0x88a7f3945837d0ed, 0x781fd39b22ae24f0, 0xc1595c0a84ac2980, 0x75cd331f4aeca1eb,
+    0x9510279f0677d1fe, 0xc1335ce0abb4c08d, 0x3fc840e5079e349d, 0x84d7e6bac546fcee,
+    0x29858e9cb1c71524, 0x75b4144f86916efe, 0x33391fa7b4cd86a7, 0x688cdf6da4c798e1,
+    0x14f7b22a0ee536a4, 0x0b1e954619fef187, 0x450d56925051a5b8, 0xb5fdf9b9e7681efb,
+    0xc79a7f5d6fbd95eb, 0x5dc27f3eadacfa02, 0x4d54143547e42c35, 0xfe63925963e3f05e,
+    0x5960f7507cbd7ec7, 0xe6c26d6fc8e67d0a, 0xfe81a56b20273627, 0xdf40d2cb31eff379,
+    0xec68c658a6207b91, 0xa772f137bc5e0f51, 0xff99842584d82a0a, 0xfbf647d425e219bf,
+    0xc694af1b7549ecd4, 0x61084f51acf0bca4, 0xc14a246cef385f31, 0x94d113f9492c64f1,
+    0x1d728232b2d72b9b, 0xf5982a26cfd3ea84, 0x698b8e349b9f930c, 0x2d4b4d6bb96f3f8a,
+    0xf9d94722757810f6, 0x0f4b8a029b3f042a, 0x7ad53a0ff54a5520, 0x466319e0e9c965aa,
+    0x9570a54ab991e346, 0x09048722bed39555, 0x6d1900248a9532e9, 0xee0a5b8bfb56839c,
+    0x57c174deaeb3932d, 0x0beb6bfc431f0edd, 0x589001c812d49390, 0xaf284aecb650493e,
+    0xae4446cb5f451d95, 0x931777642c1efc18, 0x98c85ec559bea9e4, 0x323a477a9ca64b7a,
+    0x3dabe5909986896b, 0x2b9f62a65e07cd6e, 0x0970e498f82c4414, 0xdf12a90602236fee,
+    0x45ae7918f1213610, 0xf5d1bfaf21a84b72, 0xbc1e30eb20104c6d, 0xf5c8f2348eec93fa,
+    0xf7a7ed7921f83ce4, 0xb8e49be2bb98f4d8, 0xfd504df3936c6c91, 0x61f5cf3ad2d0fbc8,
+    0x8d31d82ed6d4dc8c, 0xae3131292344a216, 0x038ef81212977935, 0xcc4ec98c2f2a4781,
+    0x70aec0a370ee5d8b, 0xe36a5e9dd48284f1, 0x553e469b65080375, 0x0ba91f27fa47aaac,
+    0xb194920a1a660ebe, 0x1145e2da249f6f93, 0x5bfb250e334da969, 0x730c83cbedb8fd7d,
+    0x831a1eda27be8410, 0x1103e6b19fe78a22, 0xe5d1cc06c5dc470b, 0x8f8596e2ba3c975b,
+    0x324c2b8e9d37d05c, 0x1e707eb28a0b26a1, 0x440d23032c1cd8bd, 0x92afe476894bd6ca,
+    0x35b1010031cb32d8, 0xfc100d73d54ad305, 0x5716f374db4f04da, 0x7ed97dad96904b3a,
+    0xc85474dc49aafbb6, 0x6fb54ac90326de57, 0xce2a5f872bfd4154, 0x6f544997c56e0acb,
+    0x4427fc17b219e7d1, 0x32920ec4ba62a14a, 0x90dcd6dcfba7e347, 0x420903d13f2c0dcc,
+    0x26f559b686b1f7a7, 0x8857e35bf1bf697e, 0x57581a7c825198f6, 0x89a6af7376411f95,
+    0x28f381aa453c216e, 0xf5688c151da9dd79, 0x5f898b69350f52bd, 0xbab48887b8ec315b,
+    0x737be50facb8c9d8, 0x775f7dda32d698dc, 0x5b856196be396774, 0x04a9b7752e4232f0,
+    0x2a3c6d370cfbdd54, 0x182189d515428bfc, 0x931e6674cb6ad756, 0x3943f63d5c664018,
+    0xa8081b0f11a180ec, 0x292e1aa7fe6eaf71, 0x445170113147dd33, 0x0178120f1ae58762,
+    0x4c184c42c6e75826, 0xfe344ede50afab24, 0x806bb678c6e215bf, 0x1da1911130dd5d99,
+    0x865340fb497a6c60, 0x9506431634f51400, 0xd2035bf0afae44fb, 0xb4c47f8f6f19d159,
+    0x54001dbaebb278d5, 0x13816c12a1fc9b8c, 0x5f3fa0a4d19bb7a4, 0xc59e72bfb1ef384d,
+    0x8c4e9acb5ef8f0c3, 0x139c650b783fa653, 0x52e27c783abe24b6, 0x818e8b5def3c6026,
+    0xfbe78497e4204bf9, 0x591ac8564dadbb19, 0x3c8309bd58c680e9, 0x8c1f7e5bba6c8c03,
+    0x62fda4d9470927f8, 0x9a5c575790503f68, 0x0c7452d584a8b2d1, 0xc5c733452ee1f6c8,
+    0x0dfe037e943629d9, 0xb011549e25d223d0, 0x02e3a4a7f6038a2f, 0xbd8154f06405142b,
+    0xd047f558a23f449e, 0x208fa2e616fd593c, 0xb85eba683a6a66af, 0xcc0bcd400b80ee1b,
+    0x562934e8eff1f921, 0x4304529646b80d78, 0x7bf901ce04e850db, 0x1188d9c956228d8f,
+    0x2335d240224eaf95, 0x4aaffabee17e8536, 0x4f3a1c97028dcd88, 0x3f7bc4404ff30be8,
+    0x097085649cc8049e, 0x55b0f0bfbd8277dd, 0x26e0c724f8122ac3, 0xa7d6d86e331a496f,
+    0x0568aeb9e3f3b053, 0x5cb8cc220fe7a238, 0x5ec17ef1fad7ca28, 0x24394719e2ae0de3,
+    0x62078aeabf694618, 0x40503212e2185a27, 0xb9868e3174b6f602, 0xfcf978b6506aeb8e,
+    0xec08f92e1facf7eb, 0x7450fdafd06abda2, 0xfaf1aa66d9ab131e, 0x2a08f671cca0f7b5,
+    0xa8f34202942795d6, 0x1d2e23433768a84c, 0xf5f04b9d5c934d82, 0x74d9997eba11eb4b,
+    0x9009d91c6d2c89f1, 0xcc15af065cd9d301, 0x9c7875f199e05029, 0x99894a90e1d740b6,
+    0x1fe58e418b4894ed, 0x203fea1b68aff879, 0x8b5022497ad9dacc, 0x3fc2b9653fdae10c,
+    0x35c7983badc8a26e, 0x87bdb0379b7a6c12, 0x593c24f2bad66ee0, 0x65a7fc28ada2e4ee,
+    0xb92feda6aa88a556, 0x8170645a07116d61, 0x25eb01b8f6d30d0c, 0xd902c259b39e61c6,
+    0x1f0dbb736e6a081e, 0x9c84f8c4ae9eeffd, 0x911357109df26310, 0x8d629dc09a29cb0a,
+    0x3518c78afecd0e1d, 0x3ed4fb6b0c109989, 0xf2f0a910e7626b33, 0x8ec62e6954321b77,
+    0x6c65ea4dbd17fdfe, 0x17a184c3acef6792, 0x024fcc3b98b34522, 0xd6d2731bb8afe4da,
+    0x5b8d60903e70033d, 0x48d9468c4f57e3a1, 0xa89444b7b595dc12, 0x4926b153c837a40c,
+    0xf51abc8f3c3fd8bb, 0xe19e25c3240c14ed, 0x02884d3c1170fde8, 0x38cf727d8e6403e8,
+    0x308af04803b8f597, 0x20d50a6e878afaee, 0xabffc482904232e5, 0xfd9e36098a7cedc1,
+    0xb4b84b2c7294ec56, 0xe00139b3e8905770, 0xab48d7a06b28b01d, 0xe95ffe6ef5c6cf4b,
+    0x022f975749ff7794, 0xd6c58e0a33d3335d, 0xe9ecb2d44191846c, 0xad156f142326c461,
+    0x0a615ca1185c8aec, 0xed43bef40a63a1fc, 0x6f8b2a85c906467c, 0x51f9a1c57a6b54b7,
+    0xa0cbd247269cb8f9, 0xc7b94a7734119a4b, 0xa4ab3cb48cc9fba5, 0xd37bd8ca22d68fae,
+    0x42a12225961b357d, 0x84a41036bd1b6e15, 0x5833518114da7b3c, 0x1ab0de7d1c886b1b,
+    0xbe9b3d27368ed5f8, 0xaf4e5c7a01e29c86, 0xbb32c6029bbc50b8, 0x5817ee7979ba49ec,
+    0x8f9cff0754d71b7c, 0x530c755fc6f1578d, 0x2ff22f0d33f9b0c1, 0x11a776f3d10556bd,
+    0x01f9dccee9a0002a, 0x240fe1da00852f78, 0x411456d4ddea3d80, 0x2e702a172d1b72b4,
+    0x2dadc1becdc44db4, 0xb66c2e801be53750, 0xcfb05eda0e1039b3, 0x7fd802496267be16,
+    0xaa22636fc555b116, 0xcd64bd84338bf722, 0x159601517674a0f8, 0x887d5504961de170,
+    0x37f9a23cbd49eb33, 0xbbcf1c3244b1f9fb, 0x9537b3d0bd95fd29, 0x36ad5ffe27c28e0c,
+    0xa86267b1f473e4eb, 0xdc2c214af17edd87, 0xd738efbd130a8125, 0xc92ee545ba1d74d1,
+    0xd1972483858f209e, 0x0b7cf49c2d802766, 0x5137d6c8652c2127, 0x194ef10c59f4ac42,
+    0xfe10163d09f44755, 0x5b890cb512d9f543, 0xd0ffa227d94ae3e0, 0x6fae15548c51ad5b,
+    0x5dfca0d4947a2ae8, 0xfc9316d93fb8d610, 0xea52a4de68477401, 0xbc63c84efd72f1ef,
+    0x7016c0b8e4349289, 0xa37ca4cc5212fd52, 0xd63549898283c388, 0x65e27ebe4b097ffc,
+    0x0f3fd9d18580ee69, 0x8b124530522c9c49, 0x778b3cdbbaa9838a, 0xeb7680b4e703235c,
+    0x1a363537aa32d067, 0x7fc9dc59d3f11e90, 0xaec81f5cb0fb004c, 0xffa1b602ef58c61f,
+    0xdf87d1e16de78fa9, 0xb177be5a667c9fb1, 0x4810b7fba4f7fcc7, 0x274b2e0e83084272,
+    0x8785159689e57a14, 0x1eca087d74a07da0, 0x577cbfa1504c8d7b, 0xb38fb278581f193a,
+    0x1ccab186481edace, 0x6b1f6706abe36058, 0xee39d7a80d876162, 0xce1b8e0a3612fc2e,
+    0xde043a7ed5780b3a, 0xdd2dc8f25105d08b, 0xdee707deb401f4e2, 0xd93b18352a6d7ae6,
+    0xe08707f3aa87b79f, 0x565c24a291d4248c, 0x7112ce83f4130924, 0x704356f42ed8ebf2,
+    0xac2ddb0af0bcce28, 0x2988c4ea92d705bb, 0x2c0b6b5f2563a679, 0xd4c33cb7caed2512,
+    0x1561ad2e4e6ed329, 0x5c428c6bbd14e1b0, 0x3bac5e66ef24d09a, 0xadd9235031b422ee,
+    0x759e9907a16ff171, 0xd080c8a05aa62761, 0x134ed0b0466516fb, 0xb48fad0d033664b2,
+    0x9dd95525f9a4d630, 0x00c21ad767b9b14f, 0x7029be4712854cf7, 0xe7e21e5f9c3b0514,
+    0x24a490dfea118ee0, 0xeb719d0de363cc82, 0x1bbec921f3d93822, 0x12b627bb7e06a6a9,
+    0x715346b431ca93ab, 0xfc58af8b465e4bc8, 0xa82ba86cdb33bbb1, 0xd2063cd67b1811a8,
+    0x673ed09322599626, 0x6363946eb73afa89, 0xd494cf14a6eb2984, 0x91fa7ba4ab72bcad,
+    0xa6a4a4553f095618, 0x59590ed4cdf11808, 0xf25da990c9b7831c, 0xf4f602a651038afc,
+    0xc56d4e9c6a20ae62, 0x84287e8eac22b8c3, 0xd2be6a897f78e447, 0xefcf562abbeb354f,
+    0x19f5454640685a68, 0x271ff7785e8b485f, 0x1d8373d0f62db213, 0x1667e6acd8e651c4,
+    0xa765a85d3768aa96, 0x1b0bdccc591ca192, 0x9a8121961543e497, 0x4336e00931f0509e,
+    0x03c20d5708f34312, 0xf3ff9b6e40bc45e2, 0x2f74b4bdeff5c2e6, 0x5485019133664d34,
+    0x57696d54b4aff055, 0x8925122546517046, 0x5583bf4bd5590d23, 0x641dc0d4bcbec17b,
+    0x6ca600cdc567be6b, 0x3041149b43825374, 0xd2c6fd334b8c798a, 0xfef8a75dc68c0449,
+    0x50e043e372957f45, 0x5456b82a21493fa4, 0xfffa6d105d4e06cb, 0x4c20c5407c63465e,
+    0x03539e0b9feeb77b, 0xddec53dc86cd87c0, 0x45baee5b601fa47e, 0x08f00758890a8909,
+    0x26fc90ed8f6d81bb, 0x6e77c417f7430c38, 0x7ed5d21cf5316a80, 0x7028f6de60eba479,
+    0xcb3b91df6d97169e, 0x8f8b432ad3429100, 0xd7e25bef06e6e85f, 0x24750e8d26acd501,
+    0x4702f0f12c74e9b3, 0xe24ce2f4253bd101, 0x5fb99f0e2fa7b467, 0x25f18eff4616865d,
+    0x114efbf870693aea, 0x6134da1bac9d8ca2, 0xaae363de7bdb3c07, 0xbc61af387e811a2f,
+    0xa65ec188fdbdafdd, 0x6bf707ac64fa704d, 0x05f030d9c3d76dce, 0xb375ed4c2a728611,
+    0x1024adef92456d4a, 0x2f6d22fbe7eb699a, 0x6a04c2cad5eb4fea, 0xc3ddf235f5f26b51,
+    0x14d9971f14369ba1, 0x32d57c68b1b5a352, 0x93dd26a1e7ca30a9, 0x49a6c28dd245da68,
+    0x91846b9e3240ec13, 0x1ce5a8e6cd8ad5db, 0xb18801aec2642887, 0x6a408e7107956c64,
+    0x1397ae3820ae7abc, 0xb40125568e58b934, 0xfdb3b6547bc4b363, 0x1387627a0f869918,
+    0x829a6e21ddd13000, 0x6043466899652080, 0xbcfe3ab80259f307, 0x6ce8e8209e58dbdd,
+    0x4407cfe8c49a03d8, 0x611564a1c149d76c, 0x2cf6bdbc7adf09ce, 0x26bd1dd5a603e701,
+    0xb388e825ad0964de, 0x2912e7ea0ee7d432, 0x64c5e886b1dac573, 0xbd6d64802c8c3097,
+    0x88fed9e0c6bcfa44, 0x14bc988374daad61, 0x44f5243f6c4e6e38, 0xb1da5e28f92a0f8f,
+    0xd878828d622d23d7, 0xbef29386f8134948, 0x0ff4af056a167da5, 0xfb16fe631c4d038c,
+    0xfe93b214f40034df, 0x96d7f535407867bf, 0x1f151a4f5c3ae074, 0xdcbdbc584d6331d9,
+    0x75b006759d53b1c0, 0xadaa26e04ad378a8, 0x4bc15c6062b74d55, 0xb6977cf25bb0fc00,
+    0x42b9b35dec4621ff, 0x5018ad4dd978ed71, 0x057ea694a1404c00, 0x0c68f5f73100be44,
+    0x4a10ddd4b28f781d, 0xa4da6d3f5e13c2a3, 0xbbfa964915681ea1, 0x24a7ff1e59a1df68,
+    0x6df7856dc5a03a6e, 0x2f286922eacf4e12, 0x2dd0c88dacccbe59, 0x84d9c0c8ec4c6319,
+    0x3d8d4a8e6575671e, 0x95765503740ff2f6, 0xe81c0b79415ab67c, 0x2065d421e7667681,
+    0x85de4817ee6c6393, 0xf6fa231388d340fd, 0xdcf4e3eede8b7e83, 0x4ee31c044bf62784,
+    0xbfff7bb5690d58a0, 0x787b99c860961eb9, 0x8a008a4c4f507530, 0x1601b28985884552,
+    0xd087eeba94172e83, 0x2f10e95c0e2759c5, 0x4c5b20150ce68635, 0x8e776857b29c3113,
+    0x7b16b542f1958274, 0x3ab2533ec02bc119, 0x338b422afa748f4b, 0xfd84089f04048981,
+    0x719df63db138ab45, 0x14b887e8a7acd74a, 0x90d9507df7e9dc9d, 0x3836db25c3c486fb,
+    0xe2624998052a6301, 0xc4a2e546cd5cf5cc, 0xee6bd5edc8f4146c, 0xcb3a2d32a5bb1e4e,
+    0x0cee4c76bc56de08, 0x1b801ad13e3d6ec1, 0xb769cd2b06ec5034, 0x8603078146a35857,
+    0x5116fb5d93c29422, 0xcbaf433b4f5cc01b, 0xe9121651f6e7ba8e, 0xc6c319a755b30f30,
+    0x5dc2f39c48638ad2, 0xf00b8a099700ba8b, 0x2e8f94bc40f58582, 0x650e26801c34ed7b,
+    0xdd5f7394ec7ffe99, 0xe72608207b0da329, 0xb354eee3763b7c3b, 0xef3383aa1b4ad980,
+    0x2d4b04fb62f5ad08, 0x046a84009ea7db9d, 0x69c29d10c9f26be9, 0x1324700f6ab9d8ab,
+    0x1a3918de497fe591, 0xad893712b5fc8200, 0x01ee3f4d1aaa4181, 0x0d8fc46063ba1174,
+    0x7f091386f03a08ba, 0xddc189c96ff46397, 0x78a05bb983541e50, 0x773db6f01d3f6a56,
+    0xe7b2e581982365e5, 0x834c05bc0bebf74f, 0x2b782cff4265dd7e, 0x94f81bed6d7e429d,
+    0x262f824fba723935, 0xd7928b76d4fea7f0, 0x4e6d5764ea56d439, 0x3ebd85cb8f8227aa,
+    0xa024d69f0a074313, 0xa1d67ed7f344cc2e, 0x027f92bb735cf5ce, 0xb16624573c129fb1,
+    0xd6fcfdd2ca653884, 0x6e1dd11caae43fb6, 0x9ede08c9f4982861, 0xf3ad9e5acd65ff0e,
+    0xba29dcf7a7bd910d, 0x9b33b7edda3727e7, 0x6d02d53b89ec7076, 0x236e55e2dd9732b0,
+    0x876cfdc966930f18, 0x4c1dd5a4d8936379, 0xdd2a570d7e374926, 0xf5dcfcfe88f4bdbe,
+    0xca217a722c9d8e28, 0x4c6f4661cef1a910, 0x1ba1267ff0f8ae8f, 0xf341d5461c9df931,
+    0x8e3ecb8875c26299, 0x18856e6984f88043, 0x1bab2657a3b3e437, 0xa40f41f2b8704459,
+    0x9e23d5ed226d292f, 0x94110895d36cc7fd, 0x0621ea63e2d03477, 0x0a54db1e0e311d73,
+    0xc37b7755761977e5, 0xb1d3d19edf6995b5, 0x8d57b9a5c673e213, 0xe29cb8b2a7ca9bc9,
+    0x987354feb101e614, 0xbd67ba7725a12c57, 0x7494311d4969bf45, 0x570f11ca252b5ba7,
+    0xc312537fda720fab, 0x4d07fcbcecfd6f93, 0x2a45a89f42bf5b6d, 0xc38c9d48ee4be6c9,
+    0xcff94e1c3380db8f, 0x14a1c501be766e15, 0x5eae91a3d7ed2268, 0x63efaf986790b35f,
+    0xe898a3dae0b9d0d3, 0xde457fbdf7932f64, 0x8a4b647f4ab1e306, 0xc800c5cc7c9fafc1,
+    0xc974afff4e483297, 0x5d5b72410d12b5df, 0xaa8daaba0589b9a1, 0x5ee3d8f5ec608d93,
+    0x1f7bb97ff1c23ced, 0x77279947052216af, 0xd58e520b22fb4068, 0xce6c800b8619a3b5,
+    0x31c2fb46244eb695, 0xdc7fb8b3eebdfde1, 0x287f34994c9302bc, 0x45057f89a0dc454d,
+    0x366b725f764deedc, 0xe300d3da35040d56, 0x07e6409ba94fc722, 0x8fbbad092bbe0a00,
+    0x92976f13f4c7fc7a, 0xb123cad202ab4c29, 0xab3e3f16a59c7804, 0x9905674fbd66ff00,
+    0x2a27084f45a98309, 0x32e28c80e35b7612, 0xd699d3f3479372f0, 0x7d19ea5a7fe96715,
+    0x68cffa3c6d6e8e6d, 0x1917ffca75795b47, 0x257b32b24a53e63d, 0x9f7ffff5696f9114,
+    0x95f09e779439dd29, 0xe24fa7f87e259ed5, 0x7706ab7c176ef939, 0xdeefc48098112689,
+    0xb344feab2eb35ec6, 0x1da43ee724d37268, 0x1bcd85f8acdbb20a, 0x72c4d6cd0fcc0b88,
+    0x6a6e51beaaf8b6c7, 0xc4b8ec48f10ee6fe, 0xbf279c67bea88806, 0x5dd451d6185476f2,
+    0x57320a229f66daea, 0x694b138ad6ab558f, 0x6ffb82454f0a9895, 0xdd00093ca96fc267,
+    0x1064518be3f55ffd, 0x47f9a21ff7cc1bab, 0x98b47d1e697ac7a4, 0x6baa3e62e9a5d831,
+    0x2a0067d5749dd269, 0xa2e89209022e2c66, 0x5ce879cb0c03854c, 0x3d6fa65b6c925184,
+    0xb41ce74a2da43e7a, 0x6c1a9b35fe4c359b, 0x99443acb38ae2dba, 0xa571dbab0e24b2a8,
+    0x5d4e14fecda5c2f6, 0x5c40c2fb51ddc450, 0x67191428c1671ccb, 0xce9f1ba86fff1e28,
+    0xc818d46a4df6f621, 0x526df82172130231, 0x189dd65ca35bf593, 0xffd98363cead518e,
+    0xa5fd9a43222d9825, 0x3e8bdae7e5462d57, 0x616d7af798fa4b45, 0x9d272fc32aab90e9,
+    0x430030c9ca587aa3, 0x79b6831ed43a094b, 0xe1ea8dd8732f3a0f, 0xa3f06e2ff0cda15e,
+    0x5e528c00082b8499, 0xd82c849e00bcdac0, 0x98d1619ae0bd6247, 0x2cd91df4d1cae2ea,
+    0xe256f9f6bb5b17f5, 0x9f8a54e3adfd3053, 0x8c3bf428e46bd8fe, 0x4c6b9f29593f9faa,
+    0x5ff9e7c731d7058f, 0x906f02793fcb50cc, 0x5b8c577ee0c2ad9e, 0x96101d0db10e71b7,
+    0x4e07161f2f89d9cb, 0x1bb758173706a9a9, 0xfdf5cb82ce105625, 0x1d5eb378ab26f282,
+    0xb0f0ea30e05d866c, 0xb5cddac95cb73fc1, 0xe4aca4f2751ff81b, 0xc4d4bd0d257cb40b,
+    0xc861fede390375ab, 0xdcfb92bc5ea232be, 0xc0db3bfec8da34d1, 0x7c0c25d1cc0ffe18,
+    0x8577c9e3a72955d6, 0x93ccf326e8b9b01d, 0x835d89583dd82e0d, 0xbc07a6c1a243b0ea,
+    0x859c2b82157dfd62, 0x24aae32d07c95e85, 0xb00b1d6d43408002, 0xa0e41839f0f015ac,
+    0xa333be9830744648, 0x1875c1a469f6f9b1, 0x92b39d4654261cf3, 0xc04dee4022cadda4,
+    0x69d0e829ec8c8641, 0x545f5b93f40ac205, 0x4397db0bc9514b89, 0xf1df91e9f10658b4,
+    0x1bf387394d527151, 0x2feb6fd31c230e1b, 0xb67dbd4e214cbc49, 0xee5c17667b46a385,
+    0x16ba225a0a1463a2, 0x5d5fc344f06c8061, 0x392809073fb325fa, 0x215e734d02ae209f,
+    0x36abf2de89f6f168, 0x674410c389941006, 0xeedf41d0dbf1a5fa, 0xa10700bff44c3b72,
+    0xa26cbecfa9d1cd6d, 0x3c6eefe67264d4a9, 0x7d998d9e793045bd, 0x4cd98b2f4f09f6e6,
+    0x9a4c9eabc9ff1a65, 0x52b78e2948dd7f36, 0x3c3d973bb55a7d9a, 0x1020b3f9b2927eeb,
+    0x92a8d9eaa2a9aee5, 0xa38cef17fd1e21db, 0x9112f743ef07a883, 0x6461c709c7d03878,
+    0xcd45c9dc9d813dd0, 0x993c8858ecfb9fb2, 0x19d182d2e76159b6, 0x4ba9e1c1e90c7470,
+    0xd8711b1736a554b9, 0x7aa6af232cd719cd, 0xd7576e449a188a62, 0xd5f1a95409c10b4c,
+    0xe2f82b24798d4c15, 0x2c5f3ed46533ec8e, 0x1fa3b32e95266a85, 0x66fe2891ccc48e4c,
+    0xd11d6f1ec60ac0d1, 0x3e6c8f1cc81030af, 0x52c079e6122f8164, 0xc72fb81f5eabf2b0,
+    0x8d6189ce444fe077, 0x00ac583c8f6bf234, 0x014ea78cc3608b14, 0xc96975872596cb62,
+    0xe4abbc62ea2f4fef, 0xa3d3172561e09e6c, 0xbc34a09e29744120, 0xdbeecbf7fd85fe5f,
+    0xc43e934a2c13268f, 0xde1ff48133385087, 0x5e77e7cab48bfb0b, 0xd41ddaacd79f8d29,
+    0x5e7af27cd167e581, 0xe7d4dd42cc2ecece, 0x5973bd299b7d7471, 0x372331f07b924377,
+    0xc61cff9afa980746, 0xb0eb2a872f9cc18b, 0x5c219a3ba0001f25, 0x0a101b213dad3982,
+    0x6dc028b458237677, 0x3a70173d52c65ec5, 0x845adf51d9555f87, 0x11a7a383fe1e4f88,
+    0x6f9c20e79967aded, 0x5f7c7e1884bc5934, 0xd804b7166f73f9ef, 0xbf51478ff77e2c19,
+    0x2fc3928a43e819b1, 0x17e8f5e22da55ba4, 0x45eb255ff4fd7f8e, 0xca352250f557a23d,
+    0xda13208722259108, 0xc3ddd02cedd75120,
+]
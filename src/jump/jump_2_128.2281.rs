@@ -0,0 +1,11 @@
+[
+    0xfdab4e783107dd07, 0x4b5177a76b4ada1f, 0xc15db4c4a36d57ac, 0x562993f743c6453f,
+    0xdda074ba15e63493, 0x501aaf09737c8c2b, 0x910a1722097e954d, 0x15cf21fbb3391d76,
+    0xae7f45a24c41afb1, 0x304a5dc88a481c2f, 0xb513b176e8ba6244, 0x85b5159d59ff0f0b,
+    0x859802300ebc19c6, 0x70e964a024f2b4d4, 0x3de8bb02661ad167, 0x903c5130db24460b,
+    0x15d6e2a1a20b3d11, 0xa3c10fa2bcda21dd, 0x8e8c57fef2d313ce, 0xc3c239d8e03f8067,
+    0xe157eab1a5a3dc2c, 0x1e0b67552d20d2f2, 0xf5b876fcf6b0b270, 0x0e892a402a8fac6c,
+    0x608e6127aefb5857, 0x5e4b76096a7fe487, 0x6549c5579155e520, 0xeffea5f23142e339,
+    0xf10bbc3eadbb0bf6, 0x0830bef301b87f05, 0xc26a0b0aa5219dc9, 0x55918349f860f7ef,
+    0xf2edde323e2b5dc5, 0x0209ede17232eea2, 0xbd0de51a02814d80, 0xbc00b06a3c05029d,
+]
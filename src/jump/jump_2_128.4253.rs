@@ -0,0 +1,19 @@
+[
+    0xce851d7d5f08da81, 0x2233fe1cde0297d4, 0xe5e0844a0752d9f9, 0x2e44d3b706dbec28,
+    0x02eecab1af9cd579, 0x86efcfa50b4a6d31, 0x12d5b425c046ad5b, 0x2f5b26a82f0f53bd,
+    0xcad09b152173553d, 0x38d486e944443b23, 0xd7475debbbbf2537, 0xd1fa9b679c139ea3,
+    0xa8eacd4d08b5acd5, 0xdbc9eb8a24d5ac8e, 0xc310633607c18157, 0x65e64219f00d7fef,
+    0xea42af56f06bb25f, 0xc4f004b6a78ba33b, 0x8449f9e5c4b7dcc5, 0xb6329aead9f3778c,
+    0x1e4456685ef55c8e, 0x963df4e169c274c0, 0x32aeb4655603e54d, 0xbc67d60bfbfd324e,
+    0xf3ca286c990a5e18, 0x7e75dbff7826d7d4, 0x9dd9473e179fc40d, 0xce1d12ebe7300d21,
+    0x2a7b32ccc992605f, 0x01bcae61c9a7422d, 0xd8eeb942050a041e, 0xc54e284bd5d92033,
+    0xed2f268a6883736c, 0x698f6658109d911c, 0xdfac892b39582dfd, 0x28a2f26f5a7c4799,
+    0xc44a2d72375410e9, 0x9a8c3ada66242410, 0xa26f38af7832c5a3, 0x7b411a3de935eabd,
+    0x8e54825f710ae2d8, 0x9f39df6955acd791, 0x6b83e0b948006462, 0x4b5715a14c3a8dd7,
+    0x7a2b87c81efe814f, 0x6f8ac8a2f0bd152b, 0x7e26bc62edb21ccd, 0xd2eed712edec7529,
+    0x6a56224fad94f92e, 0x8eda4c7b97dcb039, 0x33682a3c3a4b3c01, 0xa541ec9e18d43ac4,
+    0x199b522819161b7c, 0xde6ca3deec8232ef, 0x74f81c2100910573, 0xc3739fdf8965966c,
+    0x8106249a9434e4c7, 0xdb12bd5e2d04ef2b, 0x59e547ceae6f091c, 0x21728a00cb84bced,
+    0xfd55d40e539ba734, 0x01da669cf003eba6, 0x8349ba55367eeb2d, 0xf5d3545cf67ee59b,
+    0x981a4fad462e02dd, 0x50f55b1318ef913a, 0xd59b3a912d9fcb8f, 0x42a24e6f45d14f47,
+]
@@ -0,0 +1,176 @@
+[
+    0x7f5b1f9f751c1cb2, 0x1079d665b334e0fd, 0x115c2a8dd5aa26e2, 0x3a108d96791c1599,
+    0x1f47dd198ebf6bcb, 0x470e9272565bfc13, 0x9166c1fcd463d101, 0x14aaf230480925b9,
+    0xf0c07c79323fad42, 0xe1a9c93f9a216e23, 0xc84b59ebb941e9b1, 0x926ef3deaeddeac4,
+    0x344968d649843517, 0x087cad7200281ad1, 0x5d80e45c7e2e38eb, 0x8ef7e3b3b991d07f,
+    0x7ad09bdca588f925, 0xc697979326214fb9, 0xa5cdd377838f3a03, 0x71a8c98760fbfe98,
+    0x8d1fb86de9495467, 0x1b3e55cbf3678edc, 0xddb82d31cc711cb3, 0xe1c26bed5037ef1e,
+    0x7d46d0e9aa55f31d, 0xe4c24ed53102c276, 0xab3cb8606934a66b, 0x0b38f45c3658c6e7,
+    0x3b339740a9733063, 0xf38d9d6258340504, 0x2e39e9a13f5cac41, 0x6bd11cdc47ff7755,
+    0xfe6a8f3724feb024, 0x6b01d7271b16bb04, 0x0d45dfae5ef8eba2, 0xa80e7fddaa6c1f0d,
+    0xc744ed92d1f75a8d, 0xfea5a80d6e579919, 0xc69461fab2a1eef0, 0xe81f42634bd04ee4,
+    0xbdc914b49aacfb26, 0x1133ae82e44d17f6, 0xe41bdb9630506cd1, 0x57b94d84d74669d9,
+    0x8d6a23ddb94b9f5d, 0x89b076791962c286, 0x115e209a5df6fefc, 0xd0d4c9e17c726b55,
+    0xef6b7f93320e3e93, 0x1460d37978c297bc, 0x70fcc1d937ae1181, 0xa5575d786cb1ef43,
+    0x0e427f1ec2bd61d2, 0xacb9ecbdf4a7899f, 0x4b93b07c9d5b25ab, 0x0ecba2edeffbf7d8,
+    0xb0f6faed2cbb7f67, 0x7adf92fa28734992, 0x901e356b44ff8a16, 0x76083adea7f15b62,
+    0x0a9a31745992eb01, 0xdb973bce085fc7b0, 0xae2da7dad6bd5471, 0xdb4e213b20d97ae6,
+    0xac432d76efed80b2, 0x7949be1366f6ffbd, 0x258e488a34d77592, 0xdf996864152b502e,
+    0xf9ba0b73a0c9270b, 0x778e73a9b1cb1239, 0xe345d2a4404915d8, 0x930e4e8224272492,
+    0xd6d9719bf6c1cb20, 0x8ddf424d5b30d5b8, 0x44014e8df70c4689, 0xbb0e36b17e22f7b1,
+    0x0f78764036b7354d, 0xb4497fad757ee8eb, 0x31479694b016da57, 0x6c1ba21d46989741,
+    0x6e537ec8905226fa, 0x68cf4571ab1d687a, 0x5e7a41d2e6795db7, 0x30b8d7aa31e4514c,
+    0xd537b643cca3ab0f, 0xceae51ad7b0d0207, 0x4c5316c538735481, 0xb7312f09ecf280e8,
+    0x73b11600ff710cf3, 0x2803c12c3755bcf6, 0x6f9c9ba63a89b982, 0xa7682bc8fa3d84b4,
+    0x8f61fbdd035f2d77, 0x490f4a5f483e06ae, 0xd33de0fb8ec54844, 0x026e2b59cb12fbb1,
+    0x4815dd0ba20c3125, 0xc16464ca25f161ae, 0x979e09eb2a8e4d73, 0x4d8778094abd5cc6,
+    0x10ec9152e42afe0b, 0x4dd5ed83d62363dd, 0xa0bdf9f1365bd1f4, 0x506398e1dfa25d50,
+    0x18b9d5773ccf98d8, 0x11e11eecd3254765, 0xad5d74713fe621cc, 0xb7e0bf3b5c518030,
+    0xc9a0a53cdd9ecb58, 0x3a352c28ba2dd084, 0x35f89c865c8f0cdb, 0x1e46897a80f1f39a,
+    0x7357e6ae6c57932d, 0xc374f11dce9ebc50, 0x2f57a82d0a7ab066, 0xf2771e9c477931be,
+    0xb7cd167763729afb, 0x909d3bbdb7e26a48, 0xf8212d2c29e0b74a, 0x832795f6f286f2fc,
+    0x63f6d6781ac90f01, 0x779a549a70a1286e, 0x3c4bbe09739fc694, 0x1b7bca348830f40c,
+    0xa4914658b6c60619, 0x248a106199df32dc, 0x1325c9ba27cf7dbf, 0x2952adfb6cd581a7,
+    0xd32bee46ce4434a0, 0x7f1411b340c0fbc5, 0x45a7e85d1da4eed4, 0x554db7c285105ae5,
+    0xf5384a0dba98a897, 0xdffec0daf3df3176, 0x8910a135f604480d, 0x41e15d43db3bc23a,
+    0xbd9ba1251bb3664d, 0x95f178a82701d70c, 0x59070f8db3aece24, 0x849fbcae16752474,
+    0xd31ffade30c9c0fb, 0x263f7bc1904f9a3b, 0x0ece3dd9812cdce6, 0xb618f5322c6f2013,
+    0xba2d4b9a2ba66592, 0xa883a29e50975f2a, 0x30db92e8e6865af5, 0xe0603a63f157cf8e,
+    0x02c865ce526bb7c8, 0xd30b6fca6520dd71, 0xd0bf1e2a64eefbb8, 0x62190184dc872c1d,
+    0xa5fb58290da2e28d, 0x6252f9df67518968, 0x6746ac8a352ecbdd, 0xa649ffdf75e71a18,
+    0xccf21e1a26c89372, 0x2db90eff56e3d3b9, 0xe1b06e598cf4cf2d, 0x9da78287521e3dbe,
+    0x21d79d56af0a8a2f, 0xc60898bbc679c73e, 0x5ed701b394c02c18, 0x4dd702503bb4c1f5,
+    0xf0eb354e8b2f8a3d, 0x438c9561bed2034a, 0x45820365a45c1cea, 0x5dca5feb83a1a761,
+    0x5b82cd1f4e5fd25e, 0x63649ca83209203d, 0x36ed160d3168cb41, 0xc422e2aa57edfc32,
+    0x02cfc462caa2803d, 0xcd86ef6f43cd6119, 0xfce308a0a1ad5c2e, 0x79ad07de61c1d776,
+    0xec98d04ccf8450b3, 0xfbaad1551e89aa8f, 0x1722a27695c6b89f, 0x89546f837a28845d,
+    0x662639afb558916b, 0xf6bab4154c083c9e, 0x85969f4be603c4c3, 0xd1a658eaf65de201,
+    0xd72e86af482c2b70, 0x99ff0c52cf03f7c4, 0xcd96e12f4976bc1a, 0xd9bee5b58b395f4d,
+    0x81c87004c178a3a8, 0x3e56b5985575f531, 0x7538c6788d9d0795, 0x8bc5723819c58ece,
+    0xef2df09d00f35b56, 0x3f959b33d6773e25, 0x4b82f926c658d2d6, 0x4cb2f4709d15f8bb,
+    0xb3b208b817297965, 0x580538196ab12106, 0x5bfc3559e52a394d, 0xecb4d648596a6258,
+    0x760879dc76dc5287, 0x5873945647a7ba73, 0x3c2ce0c3d6aa2a50, 0xbc1428ca27840152,
+    0xe9f8295f2bc9bc94, 0x618a43fb5678cc5a, 0x4eacb87ee925b2c5, 0x69f4915a1e52ac39,
+    0xdc779d8394614364, 0x2387dd75d19cd5ca, 0xda3cf402fb0d947a, 0x932ecf55d41514f3,
+    0xc8f4c2aeeb1501df, 0xc09e83b21bac759f, 0x6d109a7832dfabcb, 0xb18c873cfbaaee06,
+    0x4b458c41f5938c37, 0xe58fc24cad35def6, 0xba2b92b566cf5d03, 0xf113c5695d762c23,
+    0xb4490979dae3c827, 0xd76eb5bb81c2e810, 0x4fb7c3a282abf0e9, 0xf8011ec54ed2038c,
+    0xaaf681138b8eb586, 0x8896080799a29f7c, 0x61291061f46b7f48, 0x8801e695e1659e4a,
+    0x45dbdf580e888072, 0x973d912fa8dadc6f, 0x9f3531f8c208a6da, 0x43817d3d772f7898,
+    0xb46854cd9a4d093f, 0x33e6a363660adf0a, 0x05b4a9aff772cb0e, 0x5487526a636255bc,
+    0x6f8533e69adccba6, 0xb1a3bf198d85e36d, 0x52c54470772dd7a1, 0xcca989e1908d543d,
+    0x129eff4b077db8e3, 0xde5e75f158bba4ef, 0x40d75cca72ba45b1, 0x70de04d892a6eb48,
+    0x63ed19906f3fa8f4, 0xd238dd648b3844c7, 0x91f8d851016c703f, 0x3de87709c5e1949b,
+    0x636e99489355dfd6, 0x4acfba24ac4610ed, 0x5b0c90f11efdd8cb, 0x74666c024a140e57,
+    0x14e8f69799b86e3e, 0x8323469159076f20, 0xb46205d41c68f339, 0x15777fde5614d3fc,
+    0x5f9bb03dd0aaec54, 0x07b363dea3ef7ef0, 0xcfde9e0a4715952d, 0x034ff5e30085ae89,
+    0xdb29987215276e1b, 0x86eb49324d5c64e8, 0x0957268486847d89, 0x52c3b60636dd7198,
+    0x29f2214ec96f10fd, 0x87b69ea3c750c272, 0x298c4f8548b56200, 0x53191f8f5f3d0bc7,
+    0x42bfa14aaedc43ea, 0x3b09d9c96f1f681d, 0xf72aa1836a7987c9, 0xb42d7b1c863cedd7,
+    0xebab6e9fea87b838, 0x639464daa5cb34b2, 0x4face4625ef8e222, 0xcbd526f6fdaeff5a,
+    0x731b2af5c7a1bd57, 0x87a522fab08fe350, 0x082272280df9501b, 0x36e8ee389394e0b1,
+    0xb50aab88225be653, 0x8a794bfd0c1b9dbb, 0x3bc0b3fa60b4f2db, 0x616c4b815842e5bd,
+    0xb7680c8a8d4b1050, 0xb6ec5c23872c3922, 0xd68229de48a6f0e2, 0x204ccd70c4735081,
+    0x4540442a6787e931, 0x7eb85cc00cc45785, 0xc73c1a62978072e1, 0xbe4547d771fad7e4,
+    0x40fe976e2d21c51d, 0x6207e0cc2eda16e0, 0x86ad06f9bc1cef22, 0x04553a8506c179c2,
+    0xeb2984c494216093, 0x4d3bbe2811dc2156, 0x8e30e71026d72bd1, 0xdbc17dd728339ab0,
+    0x32e8295d5b48c905, 0x128ca4bcf2774497, 0xd65ffe93693efdbf, 0x677cf12b4bca91d3,
+    0x55e0fa35dba1a0b1, 0xaa3840f3515dbcd6, 0x09ee993cfdaabc15, 0xeb758e296754f526,
+    0xaea3039b6eb41553, 0xf86b1de50322fc12, 0x248d64138ab675d0, 0x781534154d18f940,
+    0x1687eedf1cad505c, 0x7305cec8933638e7, 0x889762a37cbbb01f, 0xc8b0c23b3e327723,
+    0x08cd13cdcd8273cc, 0x71a32b6f02e088f3, 0x307dabc8b8ae2f27, 0xcd5f2048f0e0aaf5,
+    0x11ce2762fbcd5b49, 0xfe02fdd6cb54dca6, 0x8e9701dbb17f7934, 0x83ddd1c8d427dee6,
+    0xbf6b26a930b6156c, 0xcbdf2f333bdd7a8c, 0x754bb7ccc4335ff7, 0xe9bf12825aef6bb6,
+    0x584d4a8a88a8f7b2, 0x447a04c893f5a8f2, 0xf3a6daa57210f9d0, 0xf4972b56c7c86b7b,
+    0xbfa3d50215a60594, 0x6b8d3aa3277b9eef, 0x1b64b72eef09f727, 0xe4ae031de57d7844,
+    0xa20e182e1cfa1279, 0xa6ef88f0805d6d31, 0x1fa93dab0d8aa339, 0xdc8c04ad09a2b66c,
+    0x8ff6895c432e7fc7, 0xe9a8a3ea82259f38, 0x7490cab6b73edc12, 0xa798257583830734,
+    0x094f17d88c2cf693, 0x23d974768d461262, 0xd446797edf9c1352, 0xea6921d923758ef2,
+    0xf6594c472ce804ef, 0xc2699dada3ac0397, 0xce8352dd4fcb1a16, 0xf70ff53e9cb95445,
+    0x6158cedc1657d723, 0x5797c1e75f80d88d, 0x250661626629c80c, 0x72972f5d72bc4787,
+    0xde74af13aa2e190f, 0x26e03bbe31f009c5, 0x800d8d72eb46a5de, 0x827d52e01240ff01,
+    0xbf015b06de542710, 0x3604d9eee86f4c13, 0x1e2aaeee177e76fd, 0x3032e0486789a38f,
+    0xa320d846c1b7b9cb, 0x59a8ff171f7a0952, 0xb00440a0178222fe, 0xff0d1d908016906f,
+    0x3df5eed72cbf0541, 0x36ea6c8e3bce7087, 0x27bd60de49dec48d, 0x9760c1fc20a7aadc,
+    0x026539c8dd41ae7b, 0xf57a0866bfa7763c, 0x645458c5e1bad6f8, 0x3f41d89590c5c131,
+    0x74af747a62edeb0e, 0xa69d6a0b37c50b7a, 0xa49cfd542f626939, 0x7d1c872462fd96f1,
+    0x587252309b7b0a33, 0xb66bb0b5394150ef, 0x3ed9881141abb232, 0xaaf8253eb69e6fa6,
+    0x95277e33bbd246dd, 0x6ee7a817596278b7, 0xfef63ac324d800fb, 0xfc87376e9e19493b,
+    0xfd24d3243c432660, 0xd3e7d881798ad1d7, 0x30e6a079e453d44d, 0x49d48162181dd060,
+    0x79574ba580f33b42, 0x163bbbc292805287, 0xfaf808da63f26b68, 0x8c2e242f7c84a274,
+    0x7497f7e996047040, 0x4235b1dd32ba535f, 0xd9d507d7b860f2fa, 0x01368cd76b99401a,
+    0x583f67418cbad684, 0x02ce4aa39328d2f1, 0xbe5e1f29a636c03b, 0x7d746114696d099e,
+    0xa4f5923a3759d0ce, 0x77526a5984d1ed10, 0x1ebb2ab0e25b3583, 0x9f976036509a31f7,
+    0x0b5f3a5f5d4f5793, 0x765115cde8866489, 0x143e05f52595f17a, 0x05cdb64a97f586be,
+    0x10ed959d773265c9, 0x2eb1dd764cd59476, 0xab6da5bfd3c78c17, 0x2a292f53ac7bd74f,
+    0x527d7bd85382d508, 0x595204dad10dc357, 0x800a7ea291a01792, 0x6e6c576c1b885c3a,
+    0x16530e5036330eff, 0xbfda3677ff21d4b0, 0x4fe1557686581669, 0x694dba028dd4ceb0,
+    0xe3009aa7074991d3, 0x2ebd1ecc1418b891, 0x5462d94fb28ade9f, 0x21d6fe260e86c82f,
+    0xff233f2f256164cb, 0xac7bfe0eda046fa3, 0xef51044e24a91f5b, 0x06e3407fb49193a8,
+    0x2a0bd867c0160d1e, 0x3539718077587fc9, 0x8cc27dbb8b53e5cd, 0xde80cd088f39eb2f,
+    0xb3093c2e2c282776, 0x84e90e5384226ce9, 0x8b337191615f3771, 0x5d7a1a0531d114da,
+    0x35263767e8377d04, 0x7ff77217d3d2b924, 0x567c5111b2cf1bec, 0xb7e43966f886993c,
+    0x72ca191551dbbfaf, 0xe599d9caeee43144, 0x702779fc21d50ee2, 0xc2ce94d354711819,
+    0x41bb170fd2708990, 0x24674c2825dfa8bb, 0xad8bec00e546448d, 0x39149647d17b06f8,
+    0xfaa3b06f2e0b8055, 0x25fb1ebfdcc182ab, 0x12dbaef4e85e6f4d, 0x495c933578a1acf7,
+    0x31a337167c98e5b1, 0x5d558bd1c85ef5b0, 0x845d6d9c3e3f3f74, 0x39ab2250b73b9f83,
+    0x1288cb95336d7a39, 0x4d3f98658b247292, 0x0629d9f81df15886, 0x68a196c2c4988382,
+    0xcc873d9d932dfccf, 0x1bbddc561e0cf08e, 0x22a3629d48ab1a22, 0x4c6335e0bdfe2fd0,
+    0xa01d88d2723cc029, 0x95f4c6ae82e2ccc5, 0x6a244e75c7492c50, 0x7c4d031b6ef3163f,
+    0xe2db75d5f7463f7f, 0xe22d307f73f185a7, 0x31e9291753835deb, 0x04802548e4c04bd2,
+    0xc4e8d53b378275bc, 0x1ec1a42183016519, 0x612c96080d974e8a, 0x4f042275a8e21318,
+    0x8962808fbbbf7d21, 0xd14f1da45259016b, 0x4568ca22cdaff6fc, 0x08597eebd097f7a3,
+    0xaf4134d146da998a, 0x00c513e92d052a93, 0x8d94e735d1fac665, 0x2aa01b903ead36b2,
+    0xea1ab898f56a2ac4, 0x508ac36a5c0a3cfa, 0xff170ef01a85e5e4, 0x0166f4422e6b3847,
+    0xc5a8bd1343e18576, 0x2fcfea1a7752ae6d, 0xb9e03319f33ddc05, 0x25aeae617fed2ecb,
+    0xee39744a6da769f5, 0x57e2ab206e5f8a61, 0x29b6101afdaf59ea, 0x9b7905a04e71f5a7,
+    0x76b5f77102f7de51, 0x84ffe6edd954b1cd, 0xdcc918dd75f76f4f, 0xad45079238296174,
+    0x3ec0410b1388b24e, 0x3c5c75492724c510, 0x5c4f39b9258042f9, 0x7222d72f94c62bab,
+    0x9785c6fe0a458421, 0xaf6476ea074277ee, 0x3e71937ecdf198f7, 0xb23ecbe1aea9d4f0,
+    0xdb48296789102cce, 0x9d2d313b68a5e904, 0x54cbbe61c30b2679, 0x49e8c6d02b26295f,
+    0xac938cdf2f8da369, 0x20503a7065b9b03c, 0x1d87d9d6fcf2893d, 0x5ca432ea40f3f15a,
+    0x6056be0979125d02, 0x452909db841a12bc, 0x4414f6085e269a4b, 0x70febf040114adff,
+    0x1e16b36b7b9e760a, 0xc002bc7ad9abc095, 0x72a17678d1d0d9a1, 0xe0c0b348a6164144,
+    0x301ef1c8e27c8a93, 0xab15f93525ba6831, 0x78dcb8f37c17ec2e, 0x88493e6197aa4bb4,
+    0xa541014305063a73, 0xe122c2b3ce81f584, 0x03351be513522c58, 0x82eca135c340e496,
+    0x6d148ebe9929b368, 0x7becacfd2cf7b408, 0xcf22bf7ce6793adf, 0x85beaa1e92670b7a,
+    0x16e20344d8d3d191, 0xc59b9428d4e032a3, 0x4ec56ce87434b100, 0xaa427adefc3afffc,
+    0x026d159a06bdd19c, 0x896bd0514866239c, 0xdbadbadf7df02df0, 0xb7b19f6d7f2c44cb,
+    0x8c23c1d789c1089a, 0xe42c01746b0e9fb5, 0xb8b4d526741dcb34, 0x6ab91ed9cd3d1bc4,
+    0xeedf67b3b1415076, 0xe7a1cba1d5bad9d3, 0xac588e7ef733bcd2, 0xa26d9db160c8c2de,
+    0x6379a72ee534c989, 0x8958233667f44543, 0xceb2158ebfd15c65, 0xba4e88d1f0af8606,
+    0xef2d437de858ca33, 0xc97d6f9d147c4bbd, 0x4a2b34413bc45bdb, 0x091a1bda2cb6d463,
+    0xa85bb6f7a62665ea, 0x88431a964deadb62, 0x649848b947f61452, 0x58b6835c9c7cc501,
+    0xc27b642332d4ecf6, 0x087ee643a106beab, 0x196ee62691efaa7d, 0x4c7d88d5bffceae1,
+    0xf8d33d4560cab583, 0x55645bd07111bc1f, 0xebb79aef0377c48d, 0x3adaa8629f5657a8,
+    0xe0e578cf43a20838, 0x867a1aa3d7b20310, 0xe951a016ba5d61af, 0xa04712361137f5b3,
+    0xfc782970923df11d, 0xebafe3ba5cc0dc31, 0xde886c20d7a07fc5, 0x287156ad29fa1c9a,
+    0xe69ae1a13bff96b4, 0xb6869076f31a21ca, 0x087ed5af34c4de69, 0x7414de610a169adc,
+    0xa02790467be23a56, 0x4f579abc313f5cca, 0x1d856645d85b2f1d, 0x388d1142fdb1ac54,
+    0xbaf0480003729916, 0xd2bd5bcb22b235fe, 0xd38f743166042902, 0xd7004935e31ce1ef,
+    0x72da55c9e1bb56a0, 0x054bf26ccb891fec, 0x5e5ac5a693cc7f5d, 0x7bb7868532138385,
+    0x36d7ceb82b2b8a84, 0xc603ce2d95bddd2e, 0x033356d7c59f5935, 0x91a48751601552c6,
+    0x9280fcae45da9378, 0xadb6c4b6d6c848f5, 0x6c04a9903a531454, 0x85145ce135c033ac,
+    0x151ed82a81864ed4, 0xb0c11bae49e12bd7, 0x29bc9e36464ed8b7, 0x7f07a15c73a9e89d,
+    0xec736f769e2e296e, 0xf5f74607f08c8c80, 0xa9af6753a0ae5cd2, 0x46d8cd1a89c4037c,
+    0x9abf182d659a750a, 0xa045a58744b647af, 0x61c2e80386e3217f, 0xa6364e5726e2b595,
+    0xeb25c70d665b4a27, 0xa61cb6368fa58ee8, 0x9f230e5a734c6f85, 0xabe644849a591e55,
+    0xcebe16992bd8c818, 0x5ca2baa58f5b6585, 0xa9ebbd231f8f9f20, 0x39315eb601b7e47f,
+    0x724f4d49ba0e243a, 0xed963b4a73cb74dd, 0x1876e128f9bb567b, 0x82a6af4beed77859,
+    0x4d2eb43b94d0d84c, 0x952c5c94223ae39e, 0x512b4a7697b9acdc, 0xc0fba6b2afa84ca3,
+    0x9a5ec2803e562b79, 0x6e220e52a69476ab, 0x5d34e88a2e2a5121, 0x71a438a51707798a,
+    0x8137ac5c5c843713, 0xddcfbd7a878feebf, 0xe1aa84c92292a09d, 0x9a9b003596ac3e52,
+    0xc38301ee6468088d, 0x5f88c55d29a5924e, 0x73afedd826188ffe, 0xaa8cea9d7cef29ac,
+    0xb48e3a54f66f5757, 0x95beb84e62b738bd, 0xf4471f771c9753ec, 0x17f045916ddc92b9,
+    0xfdac3244d196c28f, 0xfd67fca334032677, 0x776232d20e49522f, 0x9c3737b1f7c4625f,
+    0x0c52350de56f6bac, 0x1f50dbfc515f31cb, 0x1a70e171c16d1497, 0x7225eef848f9d798,
+    0x63b3c8e2cfeed87e, 0x600e2c72805d690a, 0x9c5b09899fa45d69, 0x406d2022a620a649,
+    0xd3c876dd6b4108ca, 0x0ecc8510ff9b03ef, 0x7ad26947c14d5f23, 0x73af671d9780ae42,
+    0xd205291018bb89ae, 0x8503abf9bb0ba7e0, 0x04de6d01d59643f0, 0x7b9445d714116dd6,
+    0xaa743d039ebde1c8, 0xb1173258dece142e, 0x61ec1ac858d1b1f1, 0xef3bb97bdcc38a15,
+    0x6441a6a49f7463a0, 0x60e1012a41ecdace, 0x21a55ad3eb8e61a1, 0x611e4b69c48f658d,
+    0x2e96069eb393ad1f, 0xe865b03a7abaf3fb, 0xe02200e2ad73411e, 0x86a26ec0eb04ab94,
+    0xf6162d720e0cd507, 0x6770a3339beaec1e, 0x766860f2db175042, 0xe4aba0df6e9ba0fd,
+    0xc53442c440aba0e3, 0x6a9fbfdb4344e166, 0x271195edb7b68ed2, 0xa8be9f85d6504f0a,
+    0x7b4bca5236e76491, 0x344b38a1ffa95d49, 0xd8380c5aa6db1d46, 0x769ea98b63c5ca96,
+]
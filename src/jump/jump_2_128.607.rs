@@ -0,0 +1,5 @@
+[
+    0x0f7549e5fe2d4548, 0xdc04b1329021a4f9, 0x8a281c04d68165b4, 0x7f19f9e1d5690d42,
+    0xb5ef4343b6f50785, 0x9990587ad117b1a6, 0x96e1742b82471336, 0x6f8144493e26fc31,
+    0xcc95a22b1e8a6902, 0x05384e3d9c8c8fa8,
+]
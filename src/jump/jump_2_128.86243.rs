@@ -0,0 +1,339 @@
+[
+    0x0fde27b5f17c64df, 0x5bea714b7181d171, 0x46adef44c5f06a83, 0x7b76a33865bc1745,
+    0x0d9ae25714d04f33, 0x7504c867de9b15e0, 0x8b4a53eb04031aae, 0x89ebe05a3218e4ef,
+    0x1655a0b233af77df, 0xb6c5515f3a9bdd8f, 0xeb78a538f1882600, 0x1832a22fbe8cd902,
+    0xfd1a7bb0ad37f6ca, 0xc81a0459986b178c, 0x7d3b39ba0960ad1d, 0xc3b55d86d4eb7d86,
+    0x7f392bd138f1d08d, 0x2373befbc1646bd8, 0x0bf9dad4b2501d80, 0x42348e605e358359,
+    0xb5949347bdd08ff6, 0xc83063ef41a07797, 0xc487dd8d57c1189a, 0xc0e46192da04cbc8,
+    0xe6ac3bd044dc46d3, 0xaa3775529d330390, 0x43a1b82cf8b5b5ec, 0x7f94296603fb9d73,
+    0x4cefb64dac2be4e9, 0xf0f5899955abfb86, 0x06d0d0f265f4f027, 0x8de5e7bce73cc00d,
+    0xad4144b85d5acc17, 0x3ec8632f737d3a84, 0x390637bd5b3a3287, 0xc7635c7345649a64,
+    0x2c930037d10a4f37, 0xede9fc570b6656ea, 0x60ca10ef5069f7b3, 0x6aa5b642335ec9d9,
+    0x91d86b93a0d59b7d, 0x25ceb3fbb05b350e, 0x87fb3f660da64dea, 0xc31d295d6a460760,
+    0xe394a8f8ac23c6bf, 0xc393256d030370f6, 0x656e1a82f67f6fe1, 0xd5edb28100e240e3,
+    0xe4e252228aa6c429, 0x090fd1058909df42, 0x7e49e1e5e15354a4, 0x24106f9e874acda5,
+    0x3d043883ba8b8e29, 0xb1d1fe0d44373614, 0xe1d3248d0f9f877a, 0x9bd5160ec4ef3dfb,
+    0xfd3a17224ce9fa3c, 0xf25af64037da81da, 0x41ddd40392b98ea3, 0xb20f8224fcbed6c3,
+    0xd1caa337c626d151, 0x9aa16753a8867e71, 0x463333400bb75098, 0x9047235b50afb59c,
+    0xc20c33c7672da5ac, 0xafa8943f122cc68e, 0x375eb2cc7a40b0e5, 0xae59b1475cb3cbff,
+    0x79cc7e173cf554bd, 0x88a9a420ecd34146, 0xb265acc3fb9833e7, 0xc450df4214348a95,
+    0xd264d4055c119ca9, 0x475dc0481beb1be8, 0x3c4828b045ea70b9, 0x719315e279727785,
+    0xea8fa232e1356787, 0x7ceb12d4f267e05f, 0x584999f241ea6f62, 0x6340dcc89cec25af,
+    0x5625aa6d9785f488, 0x1e4fe9dd99837f09, 0x55168daf5a97e494, 0xacf8028896986cf9,
+    0x957199e8d4fd8317, 0xd1a453d9663fb2bd, 0x081aa96fb270602c, 0x98ad02d1a797d81c,
+    0x3f6d0af8f6462570, 0xf1b1f544d31fe36a, 0x7a8811bcc848b4da, 0xf0c79608080482d1,
+    0x29e2b260d8ea80e4, 0x733937fdff090568, 0x0650d4c56decc146, 0xc1e88e7e1f906be1,
+    0xcfebfe2d12a450d6, 0x16d5924d292a154b, 0x8c07b09932fe9566, 0x43cf82ff7e9e6ce9,
+    0x5ed5e0afa6046ec9, 0xb7a95a6691811159, 0x34c31cb91726809c, 0x0360c7c677691fd7,
+    0x321263bb26169579, 0x6764ca8f8f24b8be, 0xee1405a6da2876ed, 0x2600247e7cbab33a,
+    0x3ce9e59e6a970d3a, 0xe01794be6f80d439, 0xbe75712df85ade8a, 0x5e7c286cce630b73,
+    0x6cb218a053abeecf, 0xfddd6823f7d223ab, 0xe5fdc8488f4638cd, 0x90073ac09c1535a8,
+    0xa0c31d0509c33669, 0xf77415d51efdcd9a, 0x59914c9f813634e0, 0xc1c4738e79f43329,
+    0x2362991872522d8f, 0x0da83a9e6eadb3ba, 0x8ed6d461a2140f91, 0xfa9aa71d02a1e8c7,
+    0x1f0016819457509f, 0xb53201f2f796fa34, 0xae84d6802f1f037d, 0x75f3f54bd4ff92d7,
+    0x972b9069a620f495, 0x35b39a2d3b1efc19, 0x3f2dd63ad71ca355, 0x7d86dfa40f4c75c8,
+    0x2b6b2cfc7fc6b361, 0xa3a7a065f738a56e, 0x53d70a973dd5d655, 0xbf48106f18fafe8d,
+    0x9c01354b9cf9259f, 0xa341f84e99c31fea, 0x0d126235e91a195a, 0xc19db0e0da441407,
+    0x6524651afe1ba802, 0xbf29d560a3c148fb, 0xc0170c072f142e50, 0xe5ae719b9170ac3d,
+    0x4bf80ef289691d2f, 0x05b470990c02fe01, 0x13444a9d7264d02b, 0x6237290d690333dd,
+    0x0a3ff53ddcbc5d9b, 0x87d6a47fdfb42196, 0x5361c2de4321db13, 0x503fe042fd02cd84,
+    0xfa91f2a2e53bda21, 0xed0a29e627e404d1, 0x901c0eb9bf90c700, 0xb685e1a166ba8dae,
+    0x3e121378976d02c8, 0x3eec386f23001721, 0x25ae588a43b5d562, 0x9e9728f50d0c1fbc,
+    0x0e4a2f6e5284590f, 0xc9345239fddb2615, 0xf66ebf8b86ff4561, 0x2441c6e5e2ac5be4,
+    0x2f43cb14eb10cc22, 0x1c7f44c72a9b0966, 0xd6b5030862791af4, 0xec74acc3656b9794,
+    0xd8b26b665a2a8636, 0xc1824ac3770bb710, 0xbd416300a47a9f24, 0x2c64d43c25f95013,
+    0xf1f5098b0115485b, 0x325f3c00ca05217a, 0x18f0ecf9b8b8644b, 0xa8a79a1aadfc0fca,
+    0x71ae3e88b679f6e4, 0x9af75d1d94745c52, 0xc03ed5179f3415f1, 0xc86d75df50cceab0,
+    0xbca4013e47723534, 0x73d62d3ed8e5ba34, 0x58fd3992e1538760, 0xbcca498a64f59a8b,
+    0x45e8ff9511418a91, 0x4979316a74707682, 0x910ab6365734ae64, 0xb6cfca5142c5e7d3,
+    0x284b04b4ac4128fb, 0x863f3d7205dccb38, 0xe74c22b202faaaa1, 0xd801b7103857f2a5,
+    0x3da2234f1964bc9a, 0x2643eec1a7e5b291, 0xf634833d6cbc21a3, 0x3d8a26067a907044,
+    0x5509cdd343e5db81, 0xff13c6b7fa0c98f4, 0x17ac5173f743f441, 0xeac89cd8f0df491d,
+    0x8621e41cacb79e49, 0x9520cfb1c1fb43c6, 0x4e5d9f46ac5091e3, 0x6e3d5e61e78b4e7a,
+    0xcb6e74192d39726d, 0x4a6ed07131aacbc7, 0x44ff70e9ffe0eb1c, 0xa597fb23205f757a,
+    0x42e5ccd3365a1980, 0x90de2345e446a044, 0xbb2b9ce05ef2d5d0, 0xb4d64b9fc40868d5,
+    0xe9f28fd7fb396e63, 0x2eba6bccb21bb45e, 0x6045a0ad8550ecf7, 0xfdd9e3dee7e137e4,
+    0x83f36cdfde8bafe9, 0xbdf17b3403350a8f, 0xcef57fa8171b1bdd, 0x3631c1543495aaaa,
+    0x2ad2fd47a74921b5, 0x09b18940321b8d42, 0x8485ca22eab14cd4, 0xff2928cd0354ad1f,
+    0x5771e40df9ede5dd, 0xa652ed1470530319, 0x3e028030da070b6e, 0x09527d6883e4ecfa,
+    0x3b668273a2294de2, 0x2a71c85a50dabb3d, 0xc42a6fcac1e0d40e, 0x78bef8d7cdba1a60,
+    0x9621202ac6c2293e, 0x9251c12d9aad39ad, 0x51c801053f2859ee, 0xee7577d0772f0a6c,
+    0xab8f1754fa352ddc, 0x4f00719102fbe569, 0xfacc5fc4fddc532f, 0x4068af09a7c407de,
+    0xf81ef293474368e0, 0x42fe23afae8fc663, 0xc4a9013fb4cddab2, 0x21a7cded9d328be1,
+    0x7ab1c92e1e86dc3a, 0x856b102c28c1c25c, 0x724a315add1d9bd8, 0xec7a4b68db9c9f13,
+    0x07ae813cdf473181, 0x3b3b75a8fd2dfd26, 0x58f3fded91bf7634, 0x45cdafcbac2b45d2,
+    0x595dbfcdac2f9c83, 0x65f906d3a8a8833b, 0xc97f6e264ef37d89, 0xe54ebf778325d9ea,
+    0x5e61054930db3118, 0x2983ac944270276b, 0x5a22fd6fdb8c1578, 0x8d6369f0bd045245,
+    0xd6f1b6f077177b3e, 0xeefd9dd793e9c991, 0x6a165376ee1eacb4, 0x65041bdd648cccf1,
+    0x2e0cb4cd1c4af60d, 0x043b0d01cfa1044e, 0xfa9795596497fae4, 0x9f29222038769de6,
+    0xaea76eafb9464cb7, 0x471e20e8029f122c, 0x0d4d7ef0d6b30962, 0x65fc83d95276fae8,
+    0x96e6ebecdb51adaa, 0x8f03818c5738dd4e, 0x1701a9e6db1e604e, 0x1c794ad975906e85,
+    0x72753a636d7628b6, 0x0a49d23ce817a24c, 0x3d5f11c9431c2529, 0x11ebb4d0622770dc,
+    0x05180105a3ceb1e0, 0xd971868fd63ac2cb, 0x7cb346d72cfc3af5, 0xf1c023a18030c2fc,
+    0x214f74a07689ba19, 0xc6fbf5ff7f917941, 0x2ff73cb0fb1f819b, 0x3d468301904e2004,
+    0x77ceb90aab0ea246, 0x2d053c61c6154493, 0xe7cc792f1b55624e, 0xd8f58368f0548d81,
+    0x932ed408fe6cc22b, 0x4a7744821821f04c, 0x963deb225ec14cb1, 0xb0f2ce230f17ac34,
+    0x34724ff71cddc11e, 0x4187e93df53c1e68, 0xa4a3c0bc57f1d5ce, 0x959c8b3391aeb7fb,
+    0x188ccf7e003bda7d, 0xe0f7645a39ef87ec, 0xd45ead1c6aad0e32, 0xd48ce04734d16c34,
+    0xe061cea11df25596, 0x8561a7f6d0389999, 0x446787c6346eb376, 0xc7f8156cc9c1ce5f,
+    0xcc0adbd2cdb99e68, 0xbd5ba09be7f8c504, 0x7986e92de039aac6, 0x77ce6f150322fc5d,
+    0xda859c59ecae1f36, 0xdf606a53f6eaea95, 0x5de110a470738cde, 0x7ed09fe80d40c237,
+    0xf84260d9847f8a5f, 0x28626251a84a2f45, 0x5ae5460201c9f3da, 0xd5a52608b59b79eb,
+    0x609a84f01506f69f, 0xa0eeb852abacf54e, 0x6b7f5dffc5b8d0fb, 0x60cfafa7423944fe,
+    0x839dd032c386896b, 0x8d3e9e5a0be215f5, 0xb5f369ac58118ee2, 0x80b245d8a1ddc2da,
+    0x1a51a39edf16664a, 0x1fa64750ae222ec3, 0xae70e88a6d4dbe72, 0xb44e123ce943e5e6,
+    0x4f7c58c935e44a01, 0xa65cc8454a66d3e0, 0x7a7ea68ae05ab136, 0xcf6cd3b740fee2bd,
+    0xeb6c024f979f2cb3, 0x8e96a0e9bda3d6ee, 0xe28bae34b81fb374, 0x7637af1fc529639e,
+    0xdbb52fab31f4130f, 0x3a82b89680b2645c, 0xbbf9abb912035323, 0x718e9d34cb4984bc,
+    0x24798f4f8c48c39b, 0xdb3c2b8dbf7db4de, 0x676a20ccf504da93, 0xf48435deca527356,
+    0x8a5d36f3da2d460e, 0xb15aa664fd6cb5c5, 0xd139ae009d5de8d2, 0xee33d71380415978,
+    0x3d62c67f72bb440e, 0x19fe926101a3c5e8, 0x6166e3d6f736aa65, 0xeae1da021b7adb33,
+    0xac0dbf1536c6f710, 0x04516252e08354af, 0x6189839c2e032872, 0x8d73c326a5b35410,
+    0x6b0ef9e4cdc35ac0, 0x76d40e911f9b71ad, 0xf24221458506bebb, 0xbfdf04d9a8f3706e,
+    0xfe572c8230501c39, 0xfb0edfcb15d6d321, 0x820ca29d805466c2, 0x63470526d0a66182,
+    0x3b05babf75d17a67, 0x15f14e872217d1fb, 0xccc6fe903810d68d, 0x20b5c77025d746de,
+    0x4a5238f0bce00355, 0xbfa9b0767dc1b693, 0xbfd531a198e2be76, 0x6f7241ff31127d38,
+    0xcac922fc59c50040, 0xd5ecceb0cfad275d, 0x4cf715e9f44d65d0, 0xec180131027ce501,
+    0xf48ac7f5f9743b92, 0x89f20ca73e4a09d3, 0x8d3cdae8802291d4, 0x8741475635b4ba35,
+    0x8dffa0c894c5dac5, 0x895bffdb5bda219e, 0xa645c38d4f783084, 0xccc76935d8fe457b,
+    0xef5f0f8b7099d5e5, 0x1408dcca57878242, 0x9962640a9ee77581, 0x508a3bf7fc17c829,
+    0x6ffc40ed423ccba5, 0xefa73d8b6ae5e77b, 0xc9137cc9e7e87f43, 0x1195defcebcb124c,
+    0x687455041108818f, 0xac5257a42d375718, 0xc37b68ecab015132, 0x534b79fcbf7bf1f4,
+    0x5e4b50ae2553a1fe, 0xd99a878c4ff4a4f1, 0x01fae61cb748397f, 0x54b472c67ee8c979,
+    0x76e83f80d529d41a, 0x067c835bad76feb1, 0x44f1d9d07a314816, 0xc34d8c8dffd6110a,
+    0xa189ddca40b8b7a6, 0x19a46b97b90ab10e, 0x9cf7ebcd0d5bc5cb, 0x3441991cbf4d91e7,
+    0x75b7b03f31a775d5, 0x6703ff507af91ef1, 0x7a5300ad0c9dc634, 0xac0336cf74701faf,
+    0x374bce3f1c513eb6, 0x16bd5d64906a6b08, 0x8eae1f463e1c1d74, 0x3db7125328231b10,
+    0x935ef4108c99749e, 0xfe71e4fe17393eae, 0xbe4cf585bf2e1e8e, 0xbff0bcdfd816b54b,
+    0x8cc8df9a2ad4a483, 0xae1f60b0aa5ed727, 0x127312b2e1db9950, 0xed57dec2296bc9c2,
+    0xa6fa95f32180bc22, 0x473c8e16fb3f534c, 0x682b8f884b51d1d4, 0x578b758883639672,
+    0x6095778795cf3844, 0x7406dba12b39ee35, 0xcba36f4de752e732, 0x4b4e1d3af0d4dc39,
+    0x6a5e34ddac2a41fd, 0x795be9e7476e4c17, 0xafa7801fa795409f, 0x350d2b373cda4f4b,
+    0x93cce71f079d8ade, 0x1f58aa9ab52a81b5, 0x97d17fe9c3a53d30, 0x7ba0ce34d159c879,
+    0x1ad36071c92ef96c, 0xceaea59ac70452e9, 0xa27c2c5259b78157, 0x33e3ca3d89720c7c,
+    0xebd27978443ebfa7, 0xede7b6356040c5c4, 0x6773d7a8794366a9, 0x9a393fd92a677154,
+    0xa2bad849b49722f5, 0x872351acda03da76, 0x7127fc0f7465acf7, 0x956389c38ea6d998,
+    0x267449e714d794f4, 0x1ca4156f67e36707, 0x257ddf99ab6d900a, 0xcd07525c00b0d717,
+    0x9753cf01e6ff0a82, 0x37bad521bb061a04, 0x53643aed6a854bf2, 0x50379bf04cb4a8a3,
+    0x771ce684456406d9, 0x64f95c6e5dd8de75, 0x24261594f92f732d, 0x1efd375ebe38d7aa,
+    0x56bfa0f76e9bc5b5, 0x35c13a6bd0aea962, 0x72a9499db28c33c4, 0xed40ac985087fb92,
+    0x02f1e4b2a427d56e, 0xee78694e26a75aa6, 0xc1fdbd6cfe695a41, 0x9f93029c8fe73b5d,
+    0x5123a81ac616703c, 0xb48a2fc03e8d1064, 0x32aa93bf9be0e001, 0x462179340486ef35,
+    0xa8a48c24db7ad2c0, 0xd1eb35b796e46800, 0xfbcd371df47b224a, 0xf873cf2eeebd6774,
+    0xc20eb4bb2882dce9, 0x7e3e2088b9106506, 0xd6980092718cb587, 0x07616051d406c541,
+    0x3d2eee954b99104f, 0x9317f0f5e2ccc974, 0x73928a4b6da8a61a, 0x39feaff12d1f1504,
+    0x73581c1a37ca4f89, 0x84bd92c103a125c4, 0x483dbec4c6e42203, 0x91867530b5dea2f8,
+    0xef1eec7cb9cdebc6, 0x3a61df77fe6ea202, 0x986abe581926c389, 0x0b25cad3054fc2f4,
+    0x15dfc2f391efb12d, 0xec34f0ea3fd00a73, 0x5e356b01da8c6f13, 0x5fd127df8fa3b8eb,
+    0x9d9e26e3de8965dd, 0x8ceb210ae2ef98d1, 0xafc8dde0f6875e61, 0x0ab010925766cb97,
+    0x2bb700016a545f17, 0xcd96174d8bdd4518, 0xcb0fcd262da444c4, 0xe108c2f57b273741,
+    0xc2e5e7933d095301, 0x0f8a4ba606dfcf3e, 0x3dab1626af1705e9, 0xd49e2c45261a72e2,
+    0x741ebccded49d7d4, 0x48497449fd613660, 0x0385521564074bd9, 0xfd1abc3b0a90a0c8,
+    0xf8af92ff0bb14b9f, 0x6c0ecc5d65be47e8, 0x3fb61de0ac7ee1c1, 0x6349b7aaa3302b06,
+    0xf5268190b0cb5448, 0x0134b4240176793a, 0x23f6d0847aed6c71, 0x0aeb2e7963a26ae9,
+    0xaa7eb261f8cee6d6, 0xa22a94e1c1d34536, 0xa17422ac6191edf0, 0xe2d25fb007de5859,
+    0x7059c5f77a5a78f2, 0xfd63f97852b5328d, 0xfe4f2a7c7933e4dc, 0xcc02503489a52394,
+    0x81de2838b543188b, 0x003c024b037bee38, 0x58024e880f3457fa, 0x11010057563bb8c5,
+    0x52816b647ead93b2, 0x7f9792baae6b4815, 0x13042f45b60fe3d3, 0xf932dd1a3436d02d,
+    0xb4f0bc5cc7cb8252, 0x009b5f3b2f15ba94, 0x03793a022563c453, 0x35e082a9b6f7325d,
+    0x008e00e5873aee76, 0xbc4120892943043b, 0xe230c97761d9ef9f, 0x69f67d842c376dce,
+    0x637fc3fec4687ff8, 0x1c33e5c9bd82a8b6, 0xfde0ac1dfbea2ea4, 0x512f3b910c27f105,
+    0x3b07dd86d6cfbbba, 0x69210b16ffdf1660, 0xff1b4446e5040d08, 0xf8dfc80492c8b446,
+    0xb3e6249f7fc3ac08, 0xe8090cf504f200b4, 0x6cf259cabf7e5ee0, 0x1a7b355716b58a0f,
+    0xc0fd95a2d993b62a, 0xa1fde9bc69c201a7, 0x22fdba7ddf7814ec, 0xfa745ffd10f090c2,
+    0x349968dc86b7c038, 0x3c01513902672428, 0x6348c13719b199a5, 0xb514a86771f2fd28,
+    0xa28ca6f989cc6f15, 0x5f12d20b6c0a8586, 0x5d9b6f380d3f3421, 0x3ce0b7cde8e726be,
+    0xcb8690b10c12146d, 0x856c7f897bffbf7d, 0x108632c95e32eab2, 0x04fae6f8333add16,
+    0x9706b5e716451aec, 0xee9772a5a62e3579, 0x43c21041b1fade43, 0x4e9adbd2c478daf1,
+    0x95a49b69a48bcca6, 0xf08670b8aa13b005, 0xb6e612e06c77d817, 0x3f3d339b47ada73f,
+    0xf07a5c9c75a3616b, 0xfbc0d0e2f52a9e78, 0x87b0d4805e4afa0e, 0x49a17fcc8ff4c003,
+    0xf8ceccf0ea39278a, 0xacb81fc553048b3b, 0x1a5066f82a32521a, 0x57d823f2c45d60ad,
+    0x765259ab8bce619f, 0x41ab02cd79200e01, 0x93223995f13f84d2, 0xcde1cc5c03a509d6,
+    0xe137601d402a4592, 0x0e02d518e199d07c, 0x0b07164ca005783c, 0xdbd2fd6425cde2a4,
+    0x71f0a64ca2e57ce5, 0x046a209224c4cb97, 0x7c7163eb871e1919, 0x8285ffdadb323bda,
+    0xc9adb053baf08325, 0x47068efe61a786a7, 0xee3abe3acd81d4bb, 0x04e84aaa498e7e13,
+    0x86828f28abd0918a, 0x4a05df610b199684, 0x5ad4d4e8840ea174, 0x858532cdb8a4134b,
+    0xda8f804aa979edd9, 0x7cb94651e874fe54, 0x701d2c038cd3549c, 0x3b4a04192adc98b9,
+    0x9c1f022fa273ae87, 0xb2ab49b797436f9c, 0xde14adac5ee83b5a, 0x9aa4f50da98629dd,
+    0xf431f6baa72eeddc, 0x36f8b2306fa82522, 0x1aa7e98bba11de74, 0x1331bcbe159770cd,
+    0xb603f73a636a324c, 0x8aa91435e6082b85, 0xf6d9cc215f30e105, 0x10ea9d46b8ccfba9,
+    0x205f19ecf954257e, 0x41ecd358c75789a8, 0x02f6fa5692a6a183, 0x6f2a09eafaff8f80,
+    0xe513da718bad6166, 0xed0fa1b7a40b8871, 0xe3e5aa9ed91309c3, 0x48b6545a4fe791e3,
+    0x640195dba420c078, 0x928cf489958c24cf, 0x7578c79aac08e1b4, 0x231637e5b87bd3c9,
+    0x7a5c1614e79df31d, 0x0a48b5b935593bdd, 0x59aeec2501d2a784, 0x3810de43e4d5d2ce,
+    0x921939d5451dae45, 0xdfca3e03aeae6823, 0x0d6e91cc7785a86e, 0x0ffc6dac318585fb,
+    0x0798e2650741b51d, 0x328d8ab1c4147a63, 0x12fba5990a37988d, 0x71e48d34f9859770,
+    0x4530e6b65e76aff9, 0xc1f02109a57a90f7, 0xbff83e353726eb3f, 0xcd6ca7baa260dc99,
+    0x66ab24b5eb079f14, 0xaad57decba825d35, 0x26a69d533c7b25e3, 0x90e25430f2f211e1,
+    0x34aa97b760d62d7e, 0xa3a6972ca07542a4, 0xc2e1813333ea2b40, 0x1e179791fe1e036d,
+    0xc6da0a2bb5661a1e, 0xbf3b24e6eef43394, 0x11827b95fb8d1a98, 0x0b13430111b90cc2,
+    0xe191c808a594d8d8, 0x4723c1a8218e5c49, 0xb0bbad60434d74ce, 0x30884a58af182527,
+    0xea706e5cb2ed4492, 0x5eb961bd1c18168a, 0xb1b795f154c45708, 0x11d897e4498557fd,
+    0xa801a2123419fd7c, 0x56a4d3866cf22ace, 0xfa17ef57927f23c8, 0xdd48084b56a8a05a,
+    0x7fe89bf131254041, 0xfe65aa7c8863120c, 0x68577f22aa487a29, 0xe8ef17adac8a1b51,
+    0x2272ad6113f810f3, 0xf206bca628beedf6, 0x768b7d258ae79e52, 0xc10199360a51d7f3,
+    0x1c537049d3e8d6f3, 0x93544fd64d26ef01, 0x5429994500bcc6cf, 0x10bf65f3a380358e,
+    0x4ee9cad779794073, 0xd410ed0b8f7af058, 0xc581fc9e472ff7fa, 0x57619858464342a2,
+    0x269d96da0de49c52, 0x74f74ef87ecefeaf, 0xf3a2234ed8143dec, 0x14cec7e1ca93e3f4,
+    0xb8f797d998a2ae03, 0x30a663efe9d4e82a, 0x9dad348bb98e73f1, 0xc2e777db58c6cfc7,
+    0xaed022b4596dbc69, 0xee680a6906072f92, 0xc00de69c32f23643, 0x1ca9008b063c18c5,
+    0x252c67bd64f73460, 0xac506f2947fbbea6, 0xa38bc7cdd94eec83, 0x5a4ddf3344141374,
+    0xd44a01801f146a7e, 0x5855561e3b1caf63, 0xffc010310026a2c9, 0xecf020135ee510b9,
+    0x4ce4b0c99fe6daaf, 0xcbcdbe37bfb1004d, 0xfbb96541369faab2, 0x479b5c02c6f4891a,
+    0xba6dee93b105ec2f, 0xfbee360c042ed3e0, 0xd8fa6d5e2bca9b64, 0xb6ad0cef4c66c942,
+    0xdbdd6dd03b39c827, 0x4de438201f622577, 0xba48ea81b2265ba8, 0xee566920e723f22c,
+    0x899cc8db117d7f26, 0xfabbc53f711b497d, 0xbf6b665e91ddcf75, 0xeb9f2e19e1f44d2f,
+    0x4273f8904aac81b4, 0x6ba05c2ca45ce096, 0x4de321cfa9b685f0, 0x60f90b7b0e0c6525,
+    0xd3ed4cc02d5845f8, 0xe9fe5877aa15754e, 0xbd12599902182d72, 0xf4d7c6ebd3adbcb7,
+    0xcbbc03f7c276ecd1, 0x0be774670e04981a, 0x0a3b62e52f6e64b8, 0xe23ab6f5c594cf2e,
+    0x74bb7535938f356e, 0x3373cb5cd988d3dc, 0xf3c5c2e4c6e33852, 0x3f60ef9ddd64feaf,
+    0xa80d40a72df444a7, 0xca22c93051eeca91, 0xd9b5e2724306e373, 0x46a466cc4e4ca9b0,
+    0x02f44b0d6212e318, 0x74b1a8dd46e2e4e6, 0xbf139f4ba9d32820, 0x490a31ab39a24b93,
+    0x504dbbe2b8dfc806, 0x6fdef015fdfa3352, 0x0fe5d359b70fd612, 0xeeacbe0f914ec37c,
+    0x614244811cbb9b5b, 0xa4b18907219afbbd, 0xe4ffd888c3edc2a4, 0x2d52d69f08e6a6d6,
+    0xb335133ab3c6edad, 0x8b817d443c3dd552, 0x25d937e4b8da5c38, 0x4dfb0aa5373ce263,
+    0xede83ee1b652bf08, 0x3c05b3fe8730cd9d, 0xe1b0777e78d00c1f, 0x1ae3ebc357c25018,
+    0x482635bba2fa3f17, 0xe766d5dc08b2bc4a, 0xc9ee269481757572, 0xc8df9417bdb74e0a,
+    0xcf82aa651fe26fc1, 0xb13fe8260f1b9c54, 0x8426b0d63e157fba, 0x5abb37ebc4ea6b29,
+    0x78a055ef7cb7bf35, 0xcd87465a2c246133, 0xde6f3d92d2bbbc8b, 0xd88d785beaa131ca,
+    0xde2a6388583d1388, 0x8b369c7a14f39cc0, 0x68664d29b94ebcb6, 0xfb3dd0e0a3650308,
+    0x1f5f236abc486bbf, 0x5ebed71f154e4a47, 0xf0d718c7e31984ba, 0x7523e9a3cff0ce1e,
+    0xe3d4761d3d0de482, 0xd22ff51cc5d75c4a, 0xfaafe89903134c32, 0xfb1927e88bf42d98,
+    0x1178266def74aba7, 0x51bfb8c0262e5c85, 0x9c6b5214099b5a7c, 0x5dc36bca970617d0,
+    0x7e0988aec9bb63ed, 0xec4debb0fadab842, 0xc4e4c4d59298f53d, 0x74332d59aa625013,
+    0x251e0305d7e1846a, 0xd8dc3d6af3b26a41, 0xe69c094dcc0731f9, 0xac7df295afdcf35b,
+    0x85d7c5d8d740574a, 0xc8df54d965f75085, 0x9ed6bf9ea6c1c164, 0x9f41e27da0c6a7f0,
+    0xf6f6d91ca7f04bbc, 0x05b1c125fa878f7f, 0xb04a4ce7befc155e, 0x6794c6a6adad3790,
+    0x9a0405bfb31c2bbb, 0x2d0599f27db0a0e3, 0x2926673a8256eff9, 0x4803c669f5673501,
+    0x1a3084ca131b0a64, 0xeee75e88ddc73770, 0x88a3ec72216f87b7, 0x444184a58b492467,
+    0x68b50cad3727b18f, 0x96caf074ea1c5cb6, 0x7660832512723d23, 0x7112f5bd6b777bdf,
+    0xa5ac63f2a41be3e5, 0xf04d609f496031f0, 0xf1542762dad33c13, 0x595a5da7d99c1661,
+    0xa36c9f1dd15ea9ad, 0x26326eac9ce4397b, 0x8a162f634211a723, 0xc332b324acb47714,
+    0xbcb6d056b2e1f671, 0x792664d12aef7d80, 0x55720b135501853a, 0x92f0088d35a4f479,
+    0x5e241bc05078d90e, 0xce41cadc27aee5b2, 0x70e336d8bfd9f39e, 0x235ef0fa67f8ba74,
+    0x45b02a092124e930, 0x3abb31d5da835846, 0xdfa8778839b62f74, 0xdfee0bc0d4e567f9,
+    0x213d42ad6e9d345a, 0xe5f10f29aea7cac7, 0x3c43bbb9276db087, 0xb43da16d7a5565f3,
+    0x56c08eafa77cee29, 0x8ad624dbd75ac0ab, 0x61db08da734f1458, 0xa27dd71590bacd0c,
+    0x75af7617de140c8a, 0xda8e0be1c5e6af02, 0x03a5ad2dbdc06645, 0xc3eb02ce4824dd27,
+    0xe1dc67d7daed3b73, 0x8b77db4292792dca, 0xbb85de908b4d7f0a, 0xac4d3554c7e34550,
+    0xf4a8e20a656e6185, 0xb4ddc50706f37714, 0x1184762662ef8641, 0x99b6f819a9b00abb,
+    0x5726e7fa3c14cbde, 0x32805ec78e7a16cf, 0xa5a17944d15673c9, 0x30368bb670906280,
+    0x3d25bc8a76e1f65c, 0x2ba46f9efc82fb1e, 0xc83be287f95c0930, 0xe4ecbf6ea2131564,
+    0x029ee8a9d58a9d56, 0xe4768793edcc9d4f, 0xabf713c48309a7c0, 0x3f41b3e6ae8b9bcb,
+    0x8dc6f14be843e01d, 0x47161f6cd857a753, 0x2a3aef5988b381f6, 0x31697a14b7b7469a,
+    0x6882dd9af176251b, 0x928a207f48f4423b, 0xa46316828ff346b1, 0x5e5826556fb95a0e,
+    0x536ceba9d72d876b, 0xc52a7881dd0a3ce9, 0x053c6f4fb1f9bea2, 0x85d6678cad01d18b,
+    0xc4f05079e1c0280c, 0x69892706886de710, 0xe62759657571b998, 0x003fb78fd3e8a2b0,
+    0xffd0461143f0c7ca, 0x0c8455970f270327, 0x4528d728da8e3816, 0xa48284f4de697d09,
+    0x4de44bcac2d23103, 0x855215879c185031, 0xd611ff048899c20f, 0x4940b2b6bc951a48,
+    0x8aca0507702ce167, 0xa33436d3302ec817, 0xf3b75412d17ab729, 0x2eb9131661a474b5,
+    0xec95257f97c1891a, 0x9fab940d4992dec6, 0x8e976469350f7663, 0xb158b582d85f6f68,
+    0x794a3d5722f29f53, 0x176a2bad8875f0e3, 0x679c89fae0b558f4, 0xf87c41bd71d4847c,
+    0x5c7479cc245f7ab3, 0x7ab195db0b7391bf, 0x710aa04acc37ef45, 0x6acf2a3ffc1d134f,
+    0xf8cf5cbb7d1b6665, 0xfe3862eccd8b0de7, 0xb6cf087bc2773324, 0x02ad54f88286e7fe,
+    0xa63ac086cf84cbbb, 0x78a51f7229b02a8b, 0x6759ae948d57fdd6, 0xb2693a9dccb27b1f,
+    0x5f894ad276d4fc4f, 0x8f9369bf2a45eb3a, 0xd38d487a4a49614f, 0x09b2fef0b2a394bf,
+    0x9364f464b130db39, 0x4251e699da6965b9, 0x8f50f5b255d34a98, 0xe44a86f49755ae6b,
+    0x04348f4dc0cc8fca, 0xe5b8c9582c7d7ca6, 0x3ec7e97bee90f503, 0xa2277a58d33e2c59,
+    0x1bac11e1f568d3f4, 0x6c8547d9c5b8964f, 0xe1c2530314a4d729, 0x31bc00d9187a6b2c,
+    0x0f621b26ca0a0f0a, 0xcb0da862a69de03c, 0x1d8701a45ef9282d, 0x3b9211a6fc5270aa,
+    0x4286c268b44502d8, 0x4ec7060ac02ea161, 0x6c684797cce41792, 0x76e8a0a7d9d1e066,
+    0xc59c644154561a2d, 0xda7194dc027c141c, 0xdaa5d24277e43a16, 0x1e492600a57bbbac,
+    0x980ba55e2ddac71c, 0x9dfd2427c3ba20bb, 0x7bb2c0f105560dde, 0xf0d4297faf5ea925,
+    0x069e377f7920afce, 0xc1bc9d772757cbd0, 0x980b1dde184c233c, 0x216c5a00b2b37ad2,
+    0x39ef4d4a4932dc05, 0x5395edfc93d5c6c8, 0x83cdfcf380c88dde, 0x24406c35f77adead,
+    0x44fc9b939fcf5a84, 0x0d51929dd20e6804, 0xa7241400690c8431, 0xebed57beec187747,
+    0x6b34df22856ad6a2, 0x2b6109bcc78f636b, 0x51caf498e89c0923, 0xdd965db754ebfcd9,
+    0xe7f22fd8b7e75317, 0x990c54dc673fe01b, 0x306c781dc856867b, 0x4067912b3c3f9d65,
+    0xebf587aafe86423a, 0xcc527caff39045b4, 0xfba61cd7b1268ee4, 0xcebf5ebf3dcfa202,
+    0xd9c16f3554859145, 0xb36a657d92a0f398, 0xbfbce764073049cc, 0xb810b681a3a8a22b,
+    0xc42b3b214b7678a4, 0xf69e7aeaf7e7fb92, 0xd3a4d43c1609ade4, 0x6b0a68a3eebe56b5,
+    0xbc8a2034dee9e35a, 0x83482cbf72ec8795, 0x936e38388cc4f999, 0x78eae24441948f1e,
+    0x454991e5f163d033, 0x06d97a30e02ea60f, 0x944852f5f6383293, 0xc9e8a51aac2656ab,
+    0x49ddff492a8c27ae, 0x09e58a0fdf289491, 0xa1e99f0263bd480f, 0x131cccb473466975,
+    0x7965cf6188b57911, 0x9d25c64a2b6300ba, 0x6366bb0ad9cb3caa, 0x29894b640f3af7bc,
+    0x8ba6f71b5c11b0e0, 0x572e3c9d0d5e1f17, 0xa948fcf21d149b08, 0xabe74383d41839cb,
+    0xb58b36ba778df09a, 0x58054d6ccf8acee8, 0xfd1a4417b169f16c, 0xb05ee35b9798f97e,
+    0x61a082abb8002eb5, 0x776fcb4bc92e94fc, 0x903a0642122285bf, 0x281ea0faea46f6f9,
+    0x7ff2af938a1b2ffd, 0xff1eefe206eda6a2, 0x9aebfec615480d66, 0x8ec2d591c2e2c6e1,
+    0xf487d8b26729caf4, 0x51a4fcdc492a0c24, 0x4a8578ee80b2bba7, 0x337f81b190231a92,
+    0x258e594ccd6c9714, 0x074ad94d630757b3, 0xb58bd93f4728ca87, 0x7ab97c76d61068e5,
+    0x0baed7f2287c47fe, 0x03937dc3fa93a6b7, 0x75c936c8ca582dc3, 0xd604685aca5ffc5a,
+    0xeb0b060308c841c3, 0x26bd85a0589c635a, 0xc08ea9742d28d86b, 0xb8847e376065baad,
+    0xe93297f7c9476339, 0x53204b5aa14d56ae, 0xd7dff35edeb93900, 0x8a0b70830bf3eea8,
+    0xc842b9c7fbf8c18c, 0xf845d9c76856d9e4, 0x1cd3eb94db62d847, 0x90e5a03f8d0cc354,
+    0x787ae87cc7c80009, 0x5f1a1efc15cc8e88, 0xb563d5f904983097, 0x9565aed89e0b15b8,
+    0x7513cfbdfd382b33, 0x8b6b258e08a648c6, 0xe767b9a0ad8aab59, 0x518ab440b3aa07e1,
+    0x8a2e3ca7e0be1af9, 0x7acfce0d72f9f173, 0xd3a9bb551e3764c3, 0xf22a2eb630f01f39,
+    0xad4f8f4e197a8134, 0x48e7db57ce623702, 0xeec530cea59897b2, 0xe62a50e86bbe3f99,
+    0xec16fb27065d0785, 0x2ffa7fa59dc773e8, 0x31ac00cf8e7b0d0c, 0x1cf951d4c637b04a,
+    0xc1c770c613d28d82, 0x65af9db344962757, 0xa5ce1fd1d3f14254, 0x3ef75e5aaa218143,
+    0x61436fc42a04eddb, 0x44a336058ebf4606, 0xb3037c2b202cba43, 0xff5386976eee02f4,
+    0x6dc9a77117264490, 0x82e203883ceb2bb1, 0x505fc170b26c3396, 0x9250611c06f1ffda,
+    0x3c9fbefd79ce2514, 0x635f178a3b299dfd, 0x2961cfd5a7e28618, 0xa2bbc68d4b941389,
+    0x9d7575989852735a, 0xa4cfcef1c28dd3e2, 0x589452d49234c50c, 0x2e53bcb42a6c0c0b,
+    0xdeb47bf7802f7c60, 0x2b1e8c7af708dae7, 0x4625a449b4ae1652, 0x519a57be6af4b37c,
+    0xec196f6522df16fc, 0xaaaf012165eb07f3, 0xc38fc8e64afe8376, 0x125f18fa295d9821,
+    0x83635d87cb5906f6, 0x0538d945f70a012f, 0x337f6bd4153e895e, 0xa11be80f6b265a82,
+    0xf13e4268dbb19839, 0x9564869c947cd8eb, 0x6091da008e2e71ab, 0x1a89b49491c0444a,
+    0x43c9dc0ee8bd1047, 0xc9b5b8f169a8a952, 0x3243da66744af88e, 0x369260841943b11c,
+    0x80037c6cf78eb95e, 0x320bb6a4c77ac282, 0x7de8628dc2d1ac69, 0x05801d81aad4cf50,
+    0x351e36cca0255d39, 0x4ea6aa592b45df7d, 0xbcd721cdf57fedf0, 0x01a4383e60b37600,
+    0x6c54ced54e3c5734, 0xe22acebf9288c97a, 0xbf642c2c8d4eccc6, 0xb2de60d22deb7422,
+    0xb27589b544bad2b2, 0x7f3d08f491722bb8, 0x1f2bbc1f24430748, 0xb3a42007a68fbb01,
+    0x6083be14eb8ada0f, 0x38c1d34d9099c31c, 0x13152331c3c54a23, 0x15944b29423dc391,
+    0x2b83599d3b06d189, 0x17a3ffb36e917857, 0xaeef3c0f2961ddb7, 0x64873e69567a866a,
+    0xe6ce6a37d434e6fa, 0xd63f5705a0c53e45, 0x6b58e8a07da3847c, 0xe42aa59549b76e76,
+    0xee708b287b62cc00, 0xcd54f0fc7b9d9691, 0x884b5049a7801adb, 0xc72f48d3b94f5b4e,
+    0x209ef2f94be89c62, 0xe32f05b4ba7121c2, 0xe3e7a5a75c414eba, 0x1c10c45505b1cc70,
+    0xd6feccab06fb30b1, 0xc45ec14573ae649f, 0x9e84bddbc01fd00d, 0x8bf76332573993a7,
+    0xaecb49a18d3669fd, 0xd714df8097be3b84, 0xa7356cc00dfb7423, 0x3bc13c558911eec8,
+    0xabde81d5c804b924, 0x372a0d0ea1960dfd, 0x0b04f13fe8ac9168, 0x1b143b29a6942f2b,
+    0xe4d0d361ebffa43e, 0x303065552f1570f7, 0xc7d2cfc1ab9fe3f2, 0xffb81c04be081abb,
+    0x064e23a1eb560f3a, 0xc1b86ba810cfd3d0, 0xe1fbc71308676d49, 0x130ceb10136251ee,
+    0xac24b6c75b09314c, 0x9d2d3f185a860201, 0xf6096ce9897d2d34, 0x6300fafd49a303fc,
+    0xa46332b7969f5ddf, 0x2a86c41fb3fe15c3, 0x30e501facab641b5, 0xa453fd7554359402,
+    0x430000e214b925f1, 0x3aafb8133c8db71c, 0xa517d59271a72bec, 0x168aa3855a276fc2,
+    0xa61ef6cd589b4ec6, 0x8c8fb3252d3642c5, 0x564d93e8db1f275a, 0xe042c1c0d8635162,
+    0x1002c1360e08c0ab, 0x7a602e4abad94240, 0xb88d2ee85aab9c46, 0x979f43f3be84419f,
+    0xed87e514fc702aee, 0x48248341d7236f57, 0xbe7f5b65e96067aa, 0x074f70f956c85913,
+    0xed7c9ef2afcc0b50, 0xb7b23844c704806b, 0xf36b18315d60490e, 0x4d14d2ff1526a56a,
+    0x31e779acd4faf385, 0x21bc284ef7261d86, 0x9c1f2e2356a12f37, 0x38e87cee67ab8898,
+    0x2d80fb56f3a05454, 0xbd1c1b7f28fdab88, 0x4decd6de7b15c98d, 0xd582e1891d4373a0,
+    0x5f679afa459bc5e6, 0xdbac4e87a0aa9c4e, 0xeefbbdd3059b6807, 0x47a22472b1208dec,
+    0x0e067bb5986efe28, 0x4860e617f81c49df, 0xbe7c1564a9805884, 0x140aefbd150b22bb,
+    0x79c2d5edd0876e1d, 0x1675ca856884dad5, 0x2b89ddbfad335c11, 0x94f5d1ad82786544,
+    0x3a9f3c617cea38fd, 0xde0be4b2ec288b02, 0x8dacf442440f3c77, 0xebc66b21fbe5384f,
+    0x8c56fc3a04a84f1b, 0xfe345aa3da1c69f2, 0x98512b0813061c1f, 0xc52efe0656755462,
+    0x60cfbc2e50f60748, 0x6a1db2228bc0a8d3, 0x427ed0a49a8e2c76, 0x7955f9e645cd703f,
+    0xdc9e61dcb999e3fe, 0xd31a57cb6c909d98, 0xf2ba384e3155f70b, 0xd2e17e9085b76084,
+    0xae36eddec31fce0e, 0x4e0eba0fc2f72925, 0xad09f5cc1723bc9d, 0x2b0802f125a4fadb,
+    0xfca1bb666c099ac5, 0x129fc038cdd89be3, 0xc606a13de5349237, 0xfbfe6340a9a26fab,
+    0xb14f478be7dff255, 0x6d8fc1a2aedfeff2, 0xf645701844ce2404, 0xcdd2ac7d1b89cba9,
+    0x5d17c0f572f05d8e, 0x44ca4b50fbea5b27, 0xf4546008b6058baf, 0x06001da9a117987a,
+    0xda6a24ea87cbac42, 0x2fcc0dd39da61842, 0x45377c7500da2bd5, 0x20b20b636fb8d6d7,
+    0x6341080c21e26bd8, 0x92962c3c8d3aeb48, 0x316d61657a27e5fd, 0xb8401117162a6922,
+    0xec0560d33f3dda7c, 0x1df9d46cd3581580, 0x29034e94d2398200, 0xb9cd6991aa805f40,
+    0xaa55751b7c0ccc5a, 0x9eb0e97916fcb723, 0x1b0d486cbc5e2efc, 0xe1c47f7b1c8ba955,
+    0xa51a84382e2fd54a, 0xc62e23fcf68a8e25, 0x1ad57ac4c3892a34, 0xcb4a4261aed99149,
+    0xc7f3b74367c42201, 0x4ae95b1225b2bbc7, 0x5c3f576fd4b66372, 0xd18a8dc7d825364d,
+    0xf0bea1c376886c0c, 0xa6a47c750932d7a8, 0x8e74f92ce83dae91, 0x295d0b056b1a843d,
+    0xbcaebbdb8faa6963, 0x066df7259bc0bbf3, 0x490219d2eb9b4255, 0xb98bad9385c17858,
+    0xa52b02fd7cf2932a, 0xc4ab09fb341d6af1, 0x12701ed26b64235d, 0x259576baa6a38799,
+    0xfd1fa3807c1f45cc, 0xd3b7e83379a0c2cd, 0xb8a57fb577e53015, 0x3b515706a18ddca8,
+    0xb3c960df2565e879, 0xa8fa309bdff11655, 0x23860441a44d0195, 0x8d42cdc5ae0b2ac8,
+    0x33b608955fe10275, 0x3d92975b43fd60b2, 0x2c89674027c228e2, 0xefe3357b0d0fb88e,
+    0x9ade3abec816101c, 0xb859fb7de3dedbba, 0xfef7b6f8183e5ca9, 0x4bd9df571a96d71c,
+    0x2228c6e3791d2168, 0x5799535a2929f4cb, 0x637f15726eb7c6fc, 0x36e533e826c5029e,
+    0x1a1d2ca286ba66cd, 0x89c34c45cfe025a7, 0xe32ef683716e66a4, 0xfe6aa4672e488b53,
+    0xee78d20ca96140cc, 0x526c34f5996d2d42, 0x2c4b42ea9fbff046, 0x5dcc3768ace17f1e,
+    0x1b4daea21c553b64, 0x7051156b452a62b1, 0xbf535680c2e74426, 0xa111226d79084737,
+    0x605f86cc0901ef73, 0xb8a5dff6c48cd857, 0x127f7b3134f760d8, 0x23dd2368711cc30d,
+    0xf0fdec3d7f26e01e, 0x504f2aa89d476b12, 0xb215d967f1a2629d, 0x12fee25b72b54ae0,
+    0x06a2e5da0c946b5c, 0x394013c020e14cfb, 0xc6806fc472e74fe0, 0x8fbef317e7d76652,
+    0x35ae55c3b68e7e01, 0xea38b8d7b26a430f, 0xecae637e2a671690, 0x6706f7fb7db6b08f,
+    0x1514a01a32e433b0, 0x06d7c38b56736627, 0x0ce9a89f8c681fb4, 0x6ffd04a40a8c348d,
+    0xa22ff9f00449a3d3, 0xa4f1537e4fd73815, 0xc07c211387f7e318, 0x322ae5cab4bbf6b2,
+    0x0e0e67d3afd613ea, 0x865096e972819de8, 0x6cfdb59e32e372b9, 0x444922309bd1df91,
+    0x36f9049c21fe6208, 0x6f010d5f577bce37, 0xe065f58f161d9b63, 0x1450ea57366689d8,
+]
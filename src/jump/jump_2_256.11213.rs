@@ -0,0 +1,46 @@
+[
+    0x0963a69957289b74, 0xdcbfce266a190332, 0x4d0dba8fecab6f14, 0xf2e5038d2e60e4e4,
+    0x95ed14b85e2f1878, 0xb2145b5c2781db93, 0xbc4edfeeaf43d7ea, 0x2a136edb469a4ba4,
+    0x4874142730786d85, 0xde534c155d37acec, 0x4099af6abb53765f, 0x92b07f55c3790344,
+    0x6823cc6c6b3f6886, 0x3b0a6d8f86e359b2, 0xf870d440e7cf3bc5, 0xe793ce5dfe32ec31,
+    0x83d0f2327500e8c9, 0x5d75e47e29ca441d, 0x9c38d6cf8443a612, 0x237543fca4866f78,
+    0xfc0ac059e2dca3b5, 0x6ef6beddaef768d2, 0x688474bb561fbb37, 0x86426dfba2c01d7f,
+    0x286fe7a38969204f, 0xac3435f0dfa3848b, 0x23ab0ff0080a7974, 0x2884b669b082d99a,
+    0x7967e7a744d732e9, 0xe5714358b48f2e2a, 0x9fd3a64017440524, 0xf04d8a3a4a6fa0c4,
+    0x91268c270280c6f6, 0xbd499b3c7f4d4b1a, 0xe1832762e1a138e6, 0xa08f381ed4e133ac,
+    0xa830944551916b8c, 0x7981db2f3154978d, 0xcfbff01b47a62d15, 0xb555cdd13d5f9308,
+    0x871dd6705bb17ca9, 0x5ab8a33ac5f3c2c4, 0xf8def6ed0dacd6e8, 0x0ff06a5eca483061,
+    0x5c1fce980f6dbec2, 0x9375811610137648, 0x5e7a31848cba2e2d, 0x3e3a4a7db3d0516b,
+    0x915fce5665406885, 0x533690d0a4f78957, 0x0c175e2b28ba9e01, 0x80fbf88ffce19e4d,
+    0x381f53e4055595da, 0x5ae9dda212cf6bec, 0x5e2c75ae8f0e5c53, 0x91d02ccf3cacff39,
+    0xca2e41b049e6eac8, 0xa9a234d0772bbe5b, 0xcb83c432acebbadc, 0xb33223c761e998ca,
+    0xded9733f34818542, 0x88142783d7c7c425, 0x63cd38c523ed585f, 0xc175116c4600b4cb,
+    0x88c1e3377798d7b1, 0xfd428eb28d521dbd, 0xdb4fd70cebd70b01, 0x61e917ce0012bd54,
+    0x9db6a6957a90ec66, 0xd7bc059b42e3c9fa, 0xcfc8e58e64475984, 0xf5eab30b423b7e67,
+    0x63622627b2a0d641, 0xe61a00e5d3b2fb2c, 0x7b5a0d6ed9e0880f, 0x6f688adf6b7444c8,
+    0xb9d2aef2f0434dbf, 0xacdd6bb8f01961db, 0x511e10b4fe89e9f3, 0x1ba98d1993988afa,
+    0x164be91ac7278754, 0xf5d4a81efa6ba409, 0xe2a2982fefb4bb0b, 0x4bd918010a7deb38,
+    0x79f494559077af4a, 0xfc28498c62ef7d65, 0x2dcbe259ab307450, 0xf86d850108eec24e,
+    0x5f1eb6f94b35ff09, 0xe2134117d43e0fae, 0x1b2cdfd4b537930d, 0x99e436a5317034ec,
+    0x7d5735127d07dc39, 0xec954a7089aa5482, 0x5d430f31f588ee6e, 0x44b38213dc048b4a,
+    0x9f3d394c9493e27e, 0x0ab6c43d01406e50, 0x6fa1cc78ef6d821d, 0xc675d1514daa67a6,
+    0x97bd081b91072498, 0x353b3367ef5c069e, 0x4eb28cdf2885b39d, 0xa8924ca369ed171e,
+    0x9c5e91ca6be842f8, 0xff237e9fe7554491, 0x353504d66c7e5b2e, 0xa172974075a80f0f,
+    0x81557876a78763ad, 0x8c408f1975b945df, 0x15bc4aec4addc79f, 0x779678a9b568da5e,
+    0x18e74a14fa4e5a4b, 0x5f1df0d40564a218, 0x69adb1e90bb9fb0c, 0xb77138c161c0317e,
+    0xbb7476edb0aa568f, 0x7f389ade9571db83, 0xc8749d64a5bfe2da, 0xe81457a5ffb1d3e6,
+    0xf0365b8d8a82c8b0, 0xf7845dbec9cb8914, 0x79559ae287827618, 0x5b54911de96e1ac8,
+    0xf108e4a28ddf3f8b, 0x22f2aa92bbe679b2, 0x5e4dd673f357aed7, 0xed75f8b5363f4485,
+    0xcd3d779372ef8132, 0x86f8b67086208c2f, 0x47830f8b5d017f41, 0x2175ac0357dab5d9,
+    0x0d2b5eed50e47457, 0x544ec546e293623b, 0x47174ad34d9f480f, 0x5f6b47211aed9405,
+    0xff1387e72e7c85d8, 0xbec834cb23b0615a, 0x37cad4a53deb96d9, 0x51cf557a40b3ce71,
+    0x03a55031a2987579, 0xd4047d98eb737a9b, 0xc945fc99f631445c, 0x434f524d55c731e6,
+    0x1e5ddb9392d250cc, 0xa23a66768b470c8c, 0x9679a4387b9b4827, 0xb0f48f06a387d763,
+    0x541d101feceb3543, 0x7d2b5b8f7ded0eeb, 0xa15465d833309324, 0xd1af2a5c9d34e586,
+    0xbfadda5761914e0f, 0x7621e14e7e71d5cd, 0x617c24484e1aad9d, 0x677a81fd6eb63959,
+    0x39263da5ea30e36c, 0xb2a761e77f9e8708, 0x194ed6ebcdc49e5e, 0xf22563f2be7e8b8c,
+    0xaa738d397304518e, 0x9832900c698176d2, 0xfe55d6f039d463bb, 0x2aac715a4762e24a,
+    0x5cadb4ae51ec8f58, 0x18e012ed71882fac, 0x6723e9f23504b1cb, 0xd5375640c34cd2f9,
+    0xf0e30d00e2446590, 0xf2572b65373dee80, 0xd3afcbf9d402f48c, 0x8cdbb81af9cd79f8,
+    0x8b838c2797e34df3, 0x84e369b5be06c63f, 0x43001c8f9da55bf0, 0x84bd8eaa0221ea75,
+]
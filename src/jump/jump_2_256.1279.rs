@@ -0,0 +1,7 @@
+[
+    0x5fda4e7d20790508, 0xdb6a24bc960f46a2, 0x6b3b47eea144a226, 0x813e3a28db42ef05,
+    0x57b71128884a9325, 0x23d56b4bd1d18f90, 0x7793c7506f0ef219, 0x7f7026a040dd45cf,
+    0x7f7ee846a68e96de, 0xfbbd17db337de73f, 0xfc459f0ff21d1397, 0xe0774655b561c9f7,
+    0x323ef14794b675c5, 0x5c59c20b7ce8c2b8, 0x49551a5840568882, 0x3ae2ad5db5576dc4,
+    0xdf47c7d6c90d4f15, 0x3a72c1c2f5b61d88, 0x1d3147c657c6014e, 0x58de4717b3f33ffb,
+]
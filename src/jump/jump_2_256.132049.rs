@@ -0,0 +1,518 @@
+[
+    0x1b9fbf36ca4bd693, 0xe9b5ec9c9a557247, 0x10fa2ebdebdc6932, 0x9dad440616d03566,
+    0x89fdc7bc36fbf66e, 0x5fed64fc634b2650, 0xf2f37498172b4e1d, 0x867027541bff33c0,
+    0x217294f57da5192e, 0x3901b98a5eff02ee, 0xbbc734b579e0d4bd, 0xb6ee08bc5460b0c0,
+    0x88c134e0ca9259dd, 0x258eb18061401ab8, 0xc975336f168409ba, 0xa662e0951efcb4e8,
+    0x64b4ebf86ba54532, 0x36d47e58370c8847, 0x27a6c1c29bd48cfc, 0xad7ac6dff0bc9159,
+    0xbc05fdfd53e5d5d9, 0xe9fee31c053964d7, 0xe75c8d612d6f8013, 0xbba35ad2dcbceb24,
+    0xb5b7663e36729314, 0x4ceb67d14d039f08, 0xeff5f121c08841c0, 0xfa9ce09cde3ec0e6,
+    0x19c8a37c81998aff, 0xd2504a40c0decaac, 0xae2b630b080581ae, 0x46e41af9b1a96cb6,
+    0xbe03070f6510df2b, 0xd557206d6c7f0339, 0x1c7fe013a8be9295, 0x12c4b2fa9fffb988,
+    0xca676e99cf60b9b8, 0x076ab30e6d688438, 0xa627fbd78fc7f8ef, 0x40245cc5b7052ef8,
+    0xcfb73c708d38d2bd, 0x0f9d3a4b08245feb, 0xecfd52d5e38431d4, 0xb6a245ed80880047,
+    0xa0d85ea221a37545, 0x03f8c5a5c48c894a, 0x6e359da0245f3db4, 0xa70d8ed30c66e7dc,
+    0xe5cbfd25fb4a1684, 0x342b772e89c88890, 0xfd305884a732faee, 0x0ec9bdb8bdc4f607,
+    0xfb207b648a86bb41, 0x829eb679b1d062d0, 0x8fb4953711246f0e, 0x4d5d95d79cad2caa,
+    0xa0ace9bb11fc821b, 0x88fbafb06b1a4d7c, 0x7e5d6a2f0a63d034, 0xe6f8f525880880c4,
+    0x7721980702f207e5, 0x43d3c47cfed74d08, 0xfb4dcc10097ae288, 0x27d439d6163934fc,
+    0xcbe36f371cbd4078, 0x1c0c1c076b24e542, 0x907ac2b70b878b6d, 0x8b0a371a3b237329,
+    0xd9688760093ede91, 0x27ee56c10d06d561, 0x96767d687fbd7021, 0xc2f3b0cd50d2d7e9,
+    0xd8872626b0c321d1, 0xae31129f6a18e36b, 0x9f428fa75091d601, 0xb87ac9899461c13a,
+    0x0752aec8989bbaa3, 0x6e4f7ff3e025e0ef, 0x585d1e99a90aec9d, 0x27825cd3d8b8c1a9,
+    0xf9b2c30c5b9902b9, 0xb2e2ce672d1c38ce, 0x8bf4d21014ed6d7b, 0xf904843b7a693fd4,
+    0xea01ecbdb839ca99, 0xef47c377c934f2da, 0x55691de4ddaeb20b, 0xf8bdbabe108fbd5f,
+    0x9ccb794bc93d1fa5, 0x9d8ce5e4262c4d53, 0x34aacf36c87acf1b, 0x53b567cb394c3ff3,
+    0x5f67ae57689f8369, 0x07f51b9c97ab7931, 0x6c37a5e2cb9b15c6, 0xd29364de3515f7a6,
+    0x2022ce479fb256a5, 0x6f8fe87e5a7d0f11, 0xe6e4f661e1cb7ff4, 0x6989756c2fb656e8,
+    0xb43937691bc9b7d6, 0x7eea471aeb2f6447, 0x9e73ccde0085731c, 0x6893730442a8b398,
+    0x082cf82154d084a0, 0xbd58590c7a0815aa, 0xd82b5a62545fc021, 0x23a9055540ea595f,
+    0xd1e114dd87e4102b, 0x58aeb23e49b77325, 0x6580a37736ad1515, 0x276a26199fde1290,
+    0xb40dd088494174f9, 0x4344aad3f75c9144, 0x2d44905d6b59392e, 0x4ef872bde9162bad,
+    0xcd2fbc1890027a06, 0x6ca4b5cdc3d116fa, 0xfb0959ba8232c9ae, 0x5476bef9befe767f,
+    0xfc5e2e0ce2cddff4, 0xc96f3612da1588aa, 0x52bde07d6922b567, 0xeb3342b238a9f756,
+    0xa4322debd9930ff9, 0x21f48c0a7c0e6c3c, 0xd4e2dc387e52867d, 0xd3f4bc98129b3f47,
+    0x1300879189b976f3, 0xab45fd05761b696c, 0xd093312eb9f4ac58, 0x5d122e6177eb31b4,
+    0x8832dac7ec1f1082, 0x073beef9d3b874c3, 0x6eacb38653813748, 0x6d4ad2db01a81c5e,
+    0xfbdad009f04ce251, 0x50d4ee0a5ae21f8e, 0x8fae32c0b60a788b, 0xd6fd4a205abe6e8c,
+    0x9c2af491130dd968, 0xf2521a45c03b5d6c, 0x9cbc0571a20a83b4, 0x34be509c4ec2e19c,
+    0x03df04dfc0e623a7, 0x07cf870419a487a3, 0x3d2f6325ffe4861f, 0xd65c834ce3ca4c06,
+    0xdd501af5c499b9a1, 0xf6931d34ba1e54c2, 0xd1c9259561b1a3f7, 0x935204a839b9d5f2,
+    0x02cb2f8161726c9f, 0x27ed7189aec5f14b, 0x26af8cf38cee4ad7, 0xa0212184c309ff1b,
+    0x0485dd6a60a2f44b, 0xe4d754e86631edb2, 0x694aba9821f0c2ae, 0x893898a496359620,
+    0x1c5a00efb425a84b, 0x249fc809cbfcf195, 0xa502993e67a20d0a, 0x887a83c8e40e99d9,
+    0x4ef81461cf5c9905, 0x9ad80706fe4a6b3c, 0x80ef575d811545e8, 0xf95960d273b3c545,
+    0xe1123b1ae372b48c, 0x12465c8ec8e6a366, 0x0b14c29b6f5945df, 0xd8dce2d4f777374b,
+    0xb7ae9d2b84226610, 0xff4490a6c00d20d5, 0xf2c2927fbcdc1375, 0xda86dd8cc8db039b,
+    0x67bd8da81ee0b86e, 0x294088d48d2c8d20, 0x60611e9050140936, 0x7926896fdfc89918,
+    0x67be1be4be0a3be6, 0x8e9d41b80cde5f6c, 0x31f726f14bafaa95, 0x97fc8f43d4dae718,
+    0x0e9ae275b344a0b1, 0xe8306c3207d35bc0, 0xc35707f611df1eb4, 0x4cd5db29d5b123b9,
+    0xc6134afed82677cf, 0xb00d35b447c967a6, 0x881e49f56debd563, 0x7f2fa09c3643c7e5,
+    0x5f748c38a12bd7a6, 0xe560d0fc2b5d8523, 0xce1b028b85a42df5, 0x302bbd365ccfda87,
+    0xf76ac0b7d18e69f1, 0x6791ba6821cbb115, 0x8e15faa2f0e461eb, 0x73d5ede494bd9276,
+    0x351f98c228cb1877, 0x8addf82707653a14, 0xa10e10157517d651, 0x03c3a2ee7bb24b86,
+    0x7bd349f9b710632b, 0x171c91e69f1e8dd6, 0x33f46d06af876b86, 0x8d23a4c42fd19734,
+    0x7b9af0bda3235aa3, 0xeeaff460b4bae6e4, 0x51c4697e1df3806e, 0x9e3d08caef4d1793,
+    0xfd0dd795162496f6, 0x28e851dfded64bd6, 0x88a11f7696ce446a, 0x62a2485c19a4c62d,
+    0x0f65fc5cf1741a5d, 0x3384f23e16838a74, 0x667aa0f8e6c1cda8, 0x5f77d09b659fea76,
+    0xfeb42fb38f3959cc, 0xf9f7913d7f5fd124, 0x5572e403f35f6107, 0xe6cf4f49ffd34981,
+    0x6f27c91352b814e3, 0x43f495cd89f907bf, 0x6ff673c5a5eca248, 0xccfb2ea3a65a1651,
+    0x91fade6aefc6ae19, 0x0392f31927f175a4, 0x7c7549181539889b, 0xbf6c9fce19b7205a,
+    0x583216cc81e06f63, 0xc18507386e1b76b0, 0x398dbd40557f34e6, 0xa2edf519b0f9d0ee,
+    0x6276b696cdcf94b9, 0x75c4422399f0cbff, 0x106b76f5947ec4aa, 0x3fe7c705eafa2b23,
+    0xd41ae14b890cdf68, 0x28e5d1fed308ab9b, 0xdbd85a2c0892fe18, 0x80183528ee118364,
+    0x9a12de329777e4b3, 0xd9abcef6fcf60a1d, 0x41e4820bf75ff0f1, 0x63a7afade37d36f8,
+    0x51f6cc1783577bb5, 0x0a702e6538a66a2b, 0x31762b0544cd6206, 0xf5bd19fed19bb0ff,
+    0xb03524665f798673, 0x1d67fc4e5a14c9a1, 0xcaba01e496871519, 0x5975fb9862fa0546,
+    0xe90c6c26896e8427, 0x0ecd3db0aa6d0cd5, 0x3d29823421b69770, 0x097189ca1e19dd0e,
+    0x701eef49305b0ae6, 0x196dd4ab24c4cf1a, 0xec8248dd486b61f3, 0x3429b40938882dc0,
+    0xa83ff73ad7f1c3e5, 0x1515c6abd11a9fbc, 0x1518015615f35f55, 0xa488b70efefcf4a2,
+    0xbd8bfa8ad65a8d5e, 0x09d908eaa5d61c41, 0x4ff5bb0c90166363, 0x97ff0445088e0212,
+    0xad9803d25377c35e, 0x8b8b51a28bcd663b, 0x0b6ddb641efcc26f, 0x2009e5b05495732d,
+    0x1b66a0ecf8b2af9f, 0xe71f97a102bf2659, 0x1aff4cbed783fc3a, 0xf4bc684c9eb17600,
+    0x4a38145ffd6e8013, 0xd83466a16816fa5b, 0x953a99e090c61202, 0xcd1c19533336ed17,
+    0x72fa8f092834e766, 0x32bb4f31e2eeee19, 0x1acfaa62419e56e4, 0xda38b41a717b16c9,
+    0x28c471491d39644b, 0xb83ca91490c85c82, 0x0ea0167e65bcc479, 0x5db729f431343433,
+    0x1ca6165aa39dc004, 0x58639e65d5ea7fab, 0xdd7d81f172d75d42, 0xcf5293c1e3847df7,
+    0xfeae35c766daaa2c, 0xeda6c7a3f8ba0884, 0x57fda323ff9c141f, 0x58b82bd504ae9929,
+    0xdfeed4a0b4ce3c3b, 0x3983122e9b59cfa1, 0xa1c88c793d0a0295, 0x943c8a59f26a5cad,
+    0x0ca05046efccd2f1, 0x706e02da76230c9d, 0x38c4a3442f2e5707, 0x3f423da74a4cafda,
+    0x22c5287091374ace, 0xde54696ae4179a2d, 0xf2fa2a46a7c16f05, 0xb229d9cb3d96ac70,
+    0x116d3a3cd01660e1, 0xbea0ce269ff5a084, 0xa255e1fdcbaf424e, 0x090f3baf80a0137b,
+    0x59907f95b4537fd1, 0xbaf824dd1665720a, 0xe0d539d69a9ede8e, 0x4a139a26b56e393e,
+    0x406dea57125ab3f6, 0x32dedb1c19f0dd37, 0x44fa2bec6257d3fe, 0xb176d7adf6b9c33e,
+    0x5ad4acc4336542df, 0x3a2117c760d857d7, 0x5622fd484ba25733, 0x54eb1416cd25760b,
+    0x3bfabd836c99812b, 0xc58eedf696fbf1fe, 0x469dcbab0b558ac3, 0x4306689fa211d3b3,
+    0x072745c0ab3aecaf, 0xece00e2799547915, 0x5de79d0c215e4347, 0x6d67066c8466e44c,
+    0x5b87b39f150f070f, 0xb540e91145ef3918, 0xcb417aee5bc3a3a3, 0xe673a7823f118762,
+    0xeaa11e50416170d9, 0xb49d3e697d4a6fce, 0x2e00b31c399963ae, 0x62ff030eae626fb1,
+    0x5e9f4b34888c8d4c, 0xeb0c42a1b82d7256, 0x74ad289e6340fd9f, 0xe7523af987557269,
+    0xa61577c266543aa5, 0x2ef0d02a37ad7f8e, 0xbd035bc29915e47b, 0x9401188c33f1af69,
+    0x9c41eaf1d48e8bf6, 0x6e8f67bfd7f05a70, 0x261a35f3ddf747ef, 0x0bdacfc6e673da5c,
+    0xc75875e16aebcdde, 0xdab797b9ef60b524, 0x77bbe886c0286259, 0x7bbf33279c91b790,
+    0x11112bfe4d513815, 0xa85f19ed58f71182, 0x640a3a3eb53add4a, 0x9ae65f1d0d0dbe0a,
+    0xf7607792ff7c8853, 0x9899f5c13e3345b2, 0x906508993759d137, 0xdc584582df80101f,
+    0xc8cf80721dea2b9e, 0xc552fd122fcb556a, 0xc7706c7a8a9f8cd8, 0xbc2eaf08cddc98f3,
+    0xd194ba74b52525c4, 0x9d8f05a1d327fdc2, 0xb4e7836c61a58d8e, 0xbce230a476893c26,
+    0x1b89cccfb33c7d1f, 0x38f51d46c6d3b927, 0x50e6b66e4f17a19f, 0x14c7d4d9abe3cab1,
+    0xa7beb6f3895bc4bf, 0x79b6f52e3a220c69, 0x09849d1247b0508f, 0xfe39f8f2c3eace76,
+    0xbffa30368ee16282, 0xc09b8b2eb1af8d93, 0x0360ddff8bf2528f, 0xe4b5dcbec9bb254d,
+    0x48a792ef4ece08bd, 0x8a8057c2f9b25cd9, 0xc9537484e08de69e, 0x679e2d7696b0780e,
+    0x34acb81599445add, 0xfef34ec6491967bc, 0x084354448116b851, 0x65860df248b3a9ee,
+    0x022c5e5ae0ccf0cb, 0xc0ad59adcfe06523, 0x029dcb33604fb70c, 0x42b4d74b4e6ecec0,
+    0x3b3d52094954f3cf, 0xb092e2989c6a09bb, 0x9d40049c0e281ed4, 0xb5569c186fd45a2a,
+    0x297cf2623f8060f1, 0x65c128b8502da86b, 0x127090e84e81104d, 0x934a523efa1b0425,
+    0x30bc012860baa259, 0x3a7de51a17e154da, 0x756ee4db2f0731e8, 0x981a32037a554cee,
+    0x44b6bf71425e6e66, 0xcfb5a88c4c1c2083, 0x97c2bd618562125e, 0xc253bdbd21a2bee2,
+    0x62791ce899db29e1, 0x69fec8092f9ae25f, 0x222a8ef34ba324e2, 0x48b71718baafdfdb,
+    0x73736677f641bfaa, 0x7509826e520a5202, 0x07a0cc9cd22c9475, 0xebd1c32ae31efdfe,
+    0xb70230fea64a8426, 0xde0c4173b67abf92, 0x4cdc937633a208c2, 0xe954720c88be0582,
+    0xe40de750ce786e9b, 0x64430270e543b589, 0xb42cadebbed4c5c2, 0xde4c1e13155b02ac,
+    0x39d480d5f9cffc6e, 0xd174ec8c7c0beddd, 0x410456c8cedcf789, 0x05fdcbf31bd2a83a,
+    0x05cdb178b72c0646, 0xb6d9a455af21e741, 0x3a35cbdcf8413666, 0x45de45a6368aa7a8,
+    0x61cd83caec1e0625, 0x8fcab628803a5336, 0xdd1b913311111f00, 0xcbcc446852989b8b,
+    0xac6f2cd466656921, 0xa75280eeb9579c64, 0xa0684ada81ac8fb5, 0x9546f0da27af31d2,
+    0x13e2841276872859, 0x47ba6aa3b5aaf1f5, 0xa2ae5c0f07bc980c, 0x4f8bef5abb046fe0,
+    0x44031152292173da, 0x69a9afd96ec64299, 0x3d028154dba9fa29, 0xd9ff19bcec5d0d57,
+    0x8ad0fce9bd4f80d1, 0xb339a58924a7bfcf, 0xa67689ec68489bee, 0x76505eb647608cf2,
+    0x408baa4d924a479f, 0x5e2fc55bf07b5ea3, 0x62890df025eb6c74, 0x33ea542fee27bc1f,
+    0x39c0e74d991eac44, 0xa8376950653ced78, 0xaf60fb72925a70fd, 0x99dc526028f5a50f,
+    0x863dfe568650a36d, 0x5ad5c6ba6df02b33, 0xa8ed6c6896d17113, 0xd7b4ddb0a44ef24d,
+    0xd774de4fde53e80e, 0x718b83da3a0a6a96, 0x9a6ccc1b6be26763, 0x73433cc78a87843c,
+    0xb329f7f699769ff8, 0x6b5bebf52bcd1e6d, 0x1cd474df59610391, 0x87f32e88b8426af1,
+    0xb2386971fa9f54e1, 0xefdad803e88b7a4d, 0xc7c87bbb4988eae4, 0xe2ecba2e4f4e3238,
+    0xcda536e4d1f1ec7f, 0x376c1719bd00bfa4, 0x889b5ac031d8dbbc, 0xda08fb4b9ee77df9,
+    0x4e2279032d79d34d, 0xbf2f7db98b30a91b, 0x0dfef371f6c38e84, 0x17db6766a9062f04,
+    0x3a1f00061ae2fde1, 0xb2cd9237bcbd7034, 0xc04b511ddea9c517, 0x37e2693bd4764058,
+    0xb19faa8f0aa741c1, 0x45bd1057fc57b104, 0x19daef575c05130a, 0x6c31fcc6634d778e,
+    0xc62ec081f1e742af, 0x2d877361e3f0468c, 0x4ff6b51e9a973a67, 0xf3fd3efb55e08093,
+    0x89c5abfeda1addfe, 0x944ab3d7e3920a0d, 0xd142f77b72989f81, 0x5adc138ce3b2cb48,
+    0xbe3921361b2aaf31, 0x54f24645a7972fe6, 0x1a6728bd7433c8c4, 0x935e8c4730766783,
+    0x109c129b6b5e0928, 0x3b21e5c740871d02, 0xe3c59e05c310aceb, 0xb680b5a7874128ae,
+    0x2b4bb064e4c90d52, 0x9e12c3871eb7787a, 0x42fc997c48b677c1, 0xde9f4f2f7bcc0486,
+    0x68609416fc1762fb, 0x0b11725e6bc30cf8, 0x38e69ffd84b01f4c, 0x868171e4717f38d5,
+    0x0f4ef56d32be5efa, 0x7547f58c7e25640a, 0x9bbc024651148272, 0xa06a90bb6031a88c,
+    0xb3fc96682dbcbf0f, 0xd8a0787ba183b9e5, 0x87298dae1c52fc34, 0x0285f2bdee9fbd46,
+    0x8ac1fa24994de65c, 0x8c48d51a7af54a9e, 0x7bbd8fedacf77f16, 0xc749eb7fc5e23338,
+    0xd2b1862525c40b8c, 0x3e72f8c42ec093f4, 0x806d049257d3116c, 0x508e11a7f8cefa3c,
+    0x748ac1f002069e33, 0x1233f1d124633fbb, 0x199cfc74ba0332f2, 0x8f3cb416ff772ad2,
+    0xf9aacd37086f06c3, 0xd8690116fdfcb852, 0x2dbd42be5342bc9b, 0xb1eb74289cc8b223,
+    0xdd60541397453f73, 0xf1568f6ce2f8c46c, 0xc52ff0b37669037d, 0x537241976c8beb3f,
+    0x906646fbf0afa8a5, 0xafa111eb8196b53f, 0xc09110214a054494, 0xd13e10f670d73190,
+    0x13c1d5946b5b4c88, 0xb9cef17ac8d37f4c, 0x3597fea2898f436b, 0xef39ceb87b12556a,
+    0x35ed283f2d29d68c, 0x73481e254d8bb748, 0x667f2eeb65af59a2, 0xa8f52295f53fae6e,
+    0x6888d9fa11c40df5, 0xa108bafcf95577f7, 0x75a30ed11ddeba29, 0xd46e718ba618c93a,
+    0x58dc43d1c3b2eda4, 0xe7e1ff8fcd7a6b8e, 0xacf6216fca09c2bf, 0x605576ffc4de87c8,
+    0x1a22b10f1e5f1d98, 0xc2ccba4ce014d67f, 0xb7e0dad5627e793b, 0x3d18d9bd215ab01d,
+    0x2490e44db914552c, 0xeed47878c2547aba, 0x531ac545c51069fc, 0x3e9c6a3da798fb70,
+    0x792e6fe6003216f5, 0x532acba62f6d59a0, 0xd60481ef93813949, 0x71a2204ba6880a31,
+    0x24e7ba7aa5bc87cf, 0x9209a2f9c457c6b6, 0x21958ab1c0bb5856, 0x2c9c277b13c04ee1,
+    0x078ab2f556384b1e, 0x474756082f694876, 0x514756c267a72fb4, 0x0213afec1af706c8,
+    0xfd552867d8febcd4, 0xa8cb6781146e412a, 0x424ee0eeb36e16e2, 0xc305886ad6017b8e,
+    0xe50e4012c757d172, 0x7e4da93ce9b2dd34, 0xa948a7341eec28c6, 0x431029f13a6e9f8e,
+    0xfd2639057d11ae3b, 0xf45d434bafc446c0, 0xe6fd1448a0b39228, 0x19c183dd9ec11c3d,
+    0x4fc50881238da250, 0x5bd5fe392596ee2c, 0xe746347c37cfaa66, 0x3298a07b82775329,
+    0xb1f9e1ad9f5751e5, 0x96c03961bba541a8, 0x5c30ab67c14bd385, 0x90fec91a59088c32,
+    0x399e9b1215540aaa, 0x74e4c793482daac9, 0x7837e1d333d56066, 0x91b437cd257d06a8,
+    0xfbcf8107a923122f, 0xcfc0dce473b65b4a, 0x54ccdfda7dc7159a, 0x17a6b5b58b94342a,
+    0xb2f95b39c5eeab8e, 0xd6dd365b389bb3b7, 0x5a30d39e24075e79, 0xfbbee1a32d90e2c4,
+    0xc99d1b6fd29f05c5, 0x7e3f94d760d67de0, 0x30e6922ed8d981b9, 0x606314998e57b903,
+    0xf1359d67fa9d4990, 0x6f993d73975fe3c2, 0x2d8a80b93e0c632b, 0xcff7409d8d769201,
+    0xc91d7f00cf1fd33f, 0x1cf826374b37f302, 0x8cb52f243b52db31, 0xee2af8b5097867ef,
+    0x3c1919d88351a714, 0xb33e9ab64591df63, 0xead121a28d6158fd, 0xc191da88cf6441fc,
+    0x4f449e39c46b60d8, 0x9f91d6552d818f33, 0xd43e41e0e046db07, 0xa766966d745c2595,
+    0xde2336633b581a92, 0x375cf98028c8c585, 0x22a026d68747e426, 0x199e117f973b00cc,
+    0x09146aa8b37dba5e, 0xebc2343f68f0638f, 0xaf023fcad7947703, 0xdde966ba40647ba8,
+    0x008002daf554b3e5, 0x0778870d1f1410e2, 0x3e140c62219476cf, 0x1699f0c41a9a8136,
+    0xfa3fd75eb4609117, 0x7fa5df642ad394f3, 0xa5337d0b7d7047f8, 0xf0aed71718635b1d,
+    0xb3425c8347a2599e, 0x286acbc406438fb2, 0x740261ca3a5a00cb, 0x8ca0cca2c894e6d8,
+    0x73b33699ca5a0c29, 0xa6b1c39086ad1252, 0xe6775358a68063f9, 0xdab80b1d00166496,
+    0xc798bbca59deb6bc, 0x4eafc3282cb70bbb, 0x08aa643ca793bbf6, 0xc75a9a4e6062657d,
+    0x35d0a52d608e458c, 0xbcd22b1b906c88da, 0x388aefaae277f958, 0xde06417422c76a05,
+    0xdb369bc5ce5abe99, 0x543ffe278130d662, 0x48daacc3b0aaad2d, 0x17501bae38d08983,
+    0x287a4dd80b5ef88d, 0x5be7a808e1c6d358, 0x28f90568293e454a, 0xeb32548d5ae70136,
+    0x24edac6c77e8fe01, 0xb5059a06f5275024, 0xecdfbb590b848e17, 0xdc2fbf0ef3edc9db,
+    0xe6557b9e64ea62de, 0xd4f8678997df4709, 0x1eff4843e99a6c13, 0x9fa457c32198470a,
+    0x3111022b5dc66219, 0x1304a5ddf4bb0b0f, 0xfbbdcadbed3fd07a, 0xbcafc44ccdb4968f,
+    0x57dca7d1d1a6ad82, 0xce4df5458d066831, 0x224b00a46e7557dd, 0x2a19a37a86b179a5,
+    0x7335e1b1003fe159, 0x2ecbd0154a66864d, 0x9030f32a1ba85cb1, 0x9e6401b9f760cd2c,
+    0x9dc58d1b149abc8a, 0xd993c12230d167e0, 0xfddee74b2dd342b3, 0xcda01a1382c01f14,
+    0xf3f0d5e57528bcdc, 0xe31ad54caaf92fb2, 0x30569e719700b42c, 0x94501a1f0032efb8,
+    0xf603e138e5a06519, 0x43653efa9bb39e6a, 0x6336af6729f2d6d6, 0xc3dec208b769ddea,
+    0xeedf6f0099c2605f, 0x33b68e3af2754e86, 0x60afacabf387693c, 0xfc8558b290228d97,
+    0xc163f6c56ed0d09b, 0x0c5e465aa20ec085, 0x12f721b79b590a62, 0xbdb4cb280e8da852,
+    0xb8dfb7ea08ce4d11, 0x13341d3446455660, 0x6c56a61b188d036e, 0xda15279509388197,
+    0x0406cb704b3fc11b, 0x5908d598831bf37c, 0xd1b1fa6e69793e81, 0x78348f468371ebde,
+    0x2b63ca6696620a50, 0x139e3c70c5ba8cdf, 0x4a0f401a3a3160df, 0xb70a2573c033c371,
+    0x366500a33eb5df32, 0xf7bd180475e8b519, 0xaaeef5aaa6029e97, 0x6c86a45ca3105a4c,
+    0x9fd8eab48e573908, 0x708373d07be13b07, 0xb4744b0e4fdec0df, 0x0a4ed171504b5f23,
+    0xb37fb98b66774e1f, 0x3f13d0de278bec3b, 0x6b774f8a2598bfc6, 0x6809b3440744bbe3,
+    0x98cef61070aa499a, 0xb96fd2214abbcf18, 0xf5756832546857b2, 0xd6c7157c8ef955bf,
+    0x080cd5fdd7b38045, 0xfe5f8f5803725540, 0xc1342039be6fa0d0, 0xe8a3088b792d8235,
+    0x11ecd6cc58e04fc2, 0xe0515aa0bc22951a, 0x7914ee5c7b5fa585, 0x2dcbc9527530f1f0,
+    0x10b0ee3d3a663ed2, 0x4de0eccec07d85e9, 0x658799c0c011af22, 0xbe37c061a3a0d7d9,
+    0x3d379e0c493dd8e9, 0x5832e322cb58289c, 0x60acccb0577300bd, 0x074842598ca14d56,
+    0xd4858f8c309bfa5e, 0x9328f112e9965b04, 0x628acf8c0a8f8663, 0xece33bb86a128bac,
+    0x2e0542f47f6bde27, 0x062d1e24e5abf410, 0x0ef1639d51edb5a9, 0x2aa3d18ceaa0533a,
+    0x1a2c4dc4278031fd, 0x9d4f5896362b4516, 0xa082ba6783c09972, 0x9b9db0114f99dbc2,
+    0xdcb3ad33c12ebc1b, 0x4ef7e0ba8caef6b4, 0x1956199940c9cf08, 0x00d91c2402658cfd,
+    0x590b075bae9a36e4, 0x07aa8944f8f7ac4a, 0xf09ee00254209e19, 0xf6b0edf164153d5b,
+    0xda1824c6e591a467, 0xe742ae7575da411b, 0x1bf39f814262b785, 0x5c403f9f6726443d,
+    0x884d75017cef30d6, 0x488000080b5eaa96, 0x1188f77799885d4c, 0x87d740b4cd6790d6,
+    0x4578404ff0fcf207, 0x37b3c386504c7666, 0xe30e2bd217ed7f8f, 0xe0905422de6e319f,
+    0x990da0a44cb0827d, 0x2a687d4474c10603, 0x685aabfdfcdace2a, 0xee704e2b072dd567,
+    0xab61d3521a332490, 0x82b3c63a3f8cc605, 0x9e07a87e1fcfe7b4, 0xfcd5af3af9db58de,
+    0x685b9870798a1d91, 0x9467499187bddc15, 0x3a32c960910f6c90, 0x4a8065387f574ed6,
+    0x57e835b416f7cdec, 0x3451ca6934f7ff70, 0x0076e529b6c55e5b, 0x5c1eebb0c192679a,
+    0x0253f3cdac632a59, 0xb12aeeeb67e318ff, 0xf265098e48f48c8f, 0x20f429a376b31f8f,
+    0xa0740274212b1348, 0x7899ad97fd3b0958, 0x63edefdf89f36c0f, 0x3b5cea8969d1e5c0,
+    0xee4609d10636186c, 0xd1caaf764560d0d0, 0x7625c74ab488d591, 0x826acf067e4404ae,
+    0x06bfe2e37949ca47, 0x11381ecf6eb6accf, 0xdfbdbc9005ace960, 0x06c641958a84f96c,
+    0x3b56020e82fdec11, 0x93919e1dd81a67d8, 0xbfde4181e24ae2ce, 0x7d11b9cbb5a4769b,
+    0xff6d9bb8baf3d345, 0x2bfe8bf50c1ec52a, 0xb45f118f28333a12, 0xf6c3f309914d7e17,
+    0xdec3450cd9883aca, 0x9aa1d1d7f67067df, 0x42885227172f5473, 0xed1a03188d198141,
+    0x37cc12c254fbb16b, 0x0f5cad5f57227139, 0x0f01c55dc882cda1, 0x0ffccc7e6d1d557d,
+    0xebc384457b867662, 0x60045311e81fdeaa, 0xac8f9d7eceafc46d, 0x4ba2564b15391211,
+    0xd3510b123250f737, 0x8a27b29a41a657c6, 0xe46bab1d2995e78c, 0xb12644af8b29f75a,
+    0xf8719dfbdcba3396, 0x3ec00353ffd85dc8, 0x480e1464f794f22d, 0x71e16a21d018b4c1,
+    0xf4936374ce81ea7b, 0x2d788a394e67cc3e, 0x700d06faf259675a, 0xca55608d45099753,
+    0x405ddc016f3554a3, 0x442395d2c5c010bb, 0x7f127c02609f2560, 0x12d05b7f916067b4,
+    0x5dd5418de9a47735, 0x706343a240eeeb9b, 0x00dcfe25d939346a, 0x743d90a3553602e7,
+    0x7896a6ad70a438d6, 0x43efd4a1093104c4, 0xbd7b3a4c10e39f0e, 0xd25d66130505faa0,
+    0x69d3743f044a77b4, 0xc96b4c2032b1ff26, 0x4ec31a0e804f9ec3, 0x5587f7a74ca6db19,
+    0x269f1b79ca69e7e5, 0xdffba8ccfdde80eb, 0xcc932fe2162b1cc9, 0x5a838b6f7df876ca,
+    0x2a2adddb918ca827, 0x91a9cfed4486f92b, 0xd88bc4b33f26c343, 0x9943504f91d07d78,
+    0x4531be325ed6cb4e, 0xdb99d3d14258462a, 0x8e8f84be3d64da52, 0x2de5e22233fb6a7e,
+    0x0d3f8fbb5a540fc7, 0x198c6b283a9f6d78, 0x3054f0f893f7c8be, 0xfc4984fd675f1d1b,
+    0xa82c78bae3239ca3, 0x43eb1d2fb1babbb5, 0x9dee846c7977f65a, 0x4b875134369827f0,
+    0x47fc8a7c901c8943, 0xab0f1ce43608cf67, 0x59be7caaf3d5d57d, 0xe3e3ab350929b4ef,
+    0xea1143cc5ff0afd3, 0xe84484d87332b908, 0x3fa95e41400eab63, 0xdd8d8d3075d2e751,
+    0x1b347980c246a324, 0x65c3554f4ab62e41, 0xff6448639c1db595, 0x007ba5d6a06e5035,
+    0x4657d46bee073255, 0xd56e19309def1054, 0xa9a7b0cfe567b403, 0xfeaf10d863a914be,
+    0x8c854bc1d086f8ae, 0x0fbc28d59604508f, 0xf16f5f5f2ab16975, 0x269f4adf963c3a8f,
+    0xd381d733a9104ff8, 0x6cadda23524c27d8, 0x855a82b4d7fccbfa, 0x230b286ce8509eb1,
+    0x3e45d2580da05ad3, 0x791d6933673d0dc8, 0x170f464d444ac44a, 0x3ed1687b4a96e325,
+    0x786c9d438565e147, 0x91dae144a803bc60, 0x063a1785b98db2f7, 0x56d083493688ac50,
+    0x32a0f3c9003dcb60, 0x00803b3899900de1, 0x75a59c2361df307f, 0x4f8157edeb400bb0,
+    0x26e0edf0beb11820, 0xba8fa13ad1f962fe, 0x534fec8f51141a33, 0xbd3caf418c91e1ec,
+    0x3a01e0639d8a5813, 0xec61a25e9d5267ed, 0xefa5d3edfa7913e7, 0x89ccab84b2aafedd,
+    0x7afa5db8c78af2d5, 0xa452cc19ac78d870, 0x61799ea34b36d512, 0x31e6a380a7f294c7,
+    0xfd4d82b2c07c019e, 0x0d1b59023f96b5b5, 0x5f895963c3cc2c7c, 0x25b8355e6001b75b,
+    0xea00b3151bdc2056, 0x53442e66b5769cad, 0x5f297f42238f7e0e, 0x501d19c4259ac3e4,
+    0x94c3d5216e3995e8, 0x41f5fb244830edf1, 0x9b1abe1cb61c1a3d, 0x346b5c3d37f0189b,
+    0x84704810c0c70467, 0xd111c72e5cea61b1, 0xb5d3ac3123331917, 0x603919209fb80575,
+    0x944758136ed3f87e, 0xb787fb93596ec32e, 0xbbdb486ff35b8fde, 0x8460f432a9143976,
+    0xd2668ec3088a466e, 0x7afce41bdf96ef2f, 0xf9e5d05503f7e23a, 0x1c305ec3f792fedd,
+    0xf391330deacb7ed1, 0x36cedaa8c55a10a7, 0x6df0c6df783a9847, 0xb9f6344bf807276f,
+    0x8261ed9395bd8ac7, 0xe543eb596225f713, 0x6d8fafc708d05b7d, 0xcf75a528e7040aac,
+    0x951667476b42f7ec, 0x7d1131d6ed5f6467, 0x25a7319392fdaf04, 0xcee2a57aa3234bdd,
+    0x732094dffb08414f, 0x447b7947ad0e0706, 0x86e17834cb2268cc, 0xbd58cc46fbc22a6f,
+    0x2113fc860913028e, 0x1ed6ec47a010e7a1, 0x60f45b84ffaf1e26, 0x27dc33b9a386f10d,
+    0x3d3dc8e8c4b349fd, 0x09874c7af1c0f9e9, 0xfb4f4ee2838423db, 0x980ac2240b8bf4c3,
+    0xa253b1ef31356420, 0xe46fbe66e8122ccc, 0xa21c46351e93d0e0, 0x223996954e5e6164,
+    0x59eab4db72847937, 0x337a5d07eb48b697, 0x2724ee5b09331d97, 0x35c20307720b4701,
+    0x3b9a51bf3d2763cb, 0x776b002fb4c52ca6, 0x15da60eea8b174bf, 0xdb189566a45c37ac,
+    0xfd8dcea18af42411, 0xf5563d78b53a1dbc, 0x67ca8b944f7b83e0, 0xca479e0b4b784c65,
+    0xff376ad0ee27435e, 0x7416b6439e4312ed, 0xc5ae2f26fb436370, 0xb399a807eda78aa5,
+    0x8b875a0d5fa93646, 0xfffecc038d57221c, 0x4cfdaf227cd9d620, 0xd68edd0bc0ced710,
+    0x53d06cf9f2e02db7, 0x6369c2eabe4cba29, 0x4957f0872fe2aa44, 0x2f65cdf7fc6914d3,
+    0xcebda12362608735, 0x136dc0a3edf8fc12, 0x5c9c26c1f31cfab6, 0x392892e5d72e8871,
+    0x1be67dd98926421e, 0x7fef57ad78e599fb, 0xa1e27f5915fa9924, 0xeac1489b200c5b59,
+    0xf7edb09479bd6746, 0x5abba59bbaa52835, 0xd882b919ecd460c7, 0x95ab2009c254db02,
+    0xa0ebbe881b07f199, 0x86b238165901947c, 0x2353c082ad0bcb42, 0x755c12c6efa3bd85,
+    0x0ba029465a057f4e, 0x575523ba9adb9d22, 0x8c63f92e61d0c233, 0x4e200ab457c7cb30,
+    0xf2cdabc8a304cd3c, 0xc16b0c6c02afa311, 0x89d2765c637a69a6, 0xa7f9b0a3ffc498f4,
+    0x8d52b68bfbb2470c, 0x1a51cbcdfbde16ac, 0x162ca454776f1978, 0xbd6b41a76b3f78d9,
+    0xd6f9b421471ee3b0, 0xe31d11c4eab24839, 0xaa5db37af131fce0, 0x1e70d38afd34c703,
+    0xc95bea370fc5e69d, 0x659c148c718bd54f, 0xa1a402756d08fae2, 0xf0f4f9838dc35223,
+    0x88d282dbf613ca41, 0xa3128f5e83fa6e8a, 0x8533ad0b889ac68d, 0x9b143f2227d9de0b,
+    0x73bbfd006c1b519f, 0xba1f9df0222cbb21, 0x6136fdda9011214d, 0x62ffbb73133cafd7,
+    0x6e0d0fc7b4e6f056, 0xddd4362ad91e7fc5, 0x2cad877fa819fc88, 0xf42f7aa49c739ffc,
+    0xae24b43378050d46, 0x0a7ffac9a3e7f422, 0xf242bdc469f5af5f, 0x30072004b26af4ae,
+    0x97fc2aaebf52d4c0, 0x20bb3028567b0b97, 0xb25c3cb4468ed915, 0xb0d27f0da7911867,
+    0x6594eb69cc0d4339, 0xa8427c10cb2ff0ad, 0x2425b4c0f8042f21, 0x88ac3b70075ceb6a,
+    0xf0cf767dd20cda6e, 0xa05d5e4cabcf7a33, 0x75d01aaa94a9c19f, 0xd6722c2da1d9d93c,
+    0xaa70d5634f1e1868, 0xb90bb731b7947ae8, 0x6c35e7a5b4ddff39, 0x8fcbd4521d4d4a4a,
+    0x4b0246a792cfd098, 0xa973dd67ef84ed1f, 0x326139df748672f3, 0xa55fe7da836b05f5,
+    0xa23b360f9faafc4f, 0x5375d25af26aeea1, 0xcc281ebb6a9afc75, 0x50483c135ab432e1,
+    0x33a3854ee0786358, 0xbbe4cfe926ea7ac7, 0x5602732030fc841a, 0xd1eec6ca84ae8b58,
+    0xfe1833f1d882ce0a, 0x8f652423961f8e34, 0x61febf7e032136b2, 0xd8bdddce22e955a0,
+    0x9b1e24aebcab9e71, 0x92ee29806978e6b3, 0x5898fbe06bf46abd, 0xc7938369eee3c595,
+    0x2cddb30090a25e21, 0x4ea267b29e1686fc, 0xb810c58bd535703e, 0x8d78d11d95095738,
+    0x17c981ab8f4bcac7, 0xe9fba9ccf3816d64, 0xd6354d9a9af3667b, 0xa503186e6ddae09b,
+    0x9cf09d45e511a7eb, 0xfd489e28746157c1, 0x5f85b713f889821b, 0x1c9253f6a8f306c0,
+    0x181469cafc961057, 0x6f587d7a7b78efb7, 0xde45c968dad1c2a1, 0xdfeb68c4960d3d92,
+    0xaa857000cd837ec5, 0x6bc0a30be53a0686, 0x7debfb83edc077dd, 0xb10f67827fcf3fa1,
+    0x346fe4bf0ff53209, 0x6b7cd3880d43efc7, 0xe1b7a24fc7fea09b, 0x1dfacca715d8e58d,
+    0xaccc62b348ad3c57, 0x292b6101a3e86fb3, 0x08cb1941a4c8e2fe, 0x3a4f6e9b34a6a9f0,
+    0xe82ccfd9411af94b, 0xf6b99e3f0cd36d88, 0xfdc58f88277c21e8, 0x7432f3d9f542bf64,
+    0x87e3695b596a4812, 0x99937154ad3f6905, 0xb2a6640da71a017c, 0xefc09fc557d1b146,
+    0x8be86d4b1bbdc3f4, 0xbdc4d1d2c310fcb6, 0x9b2ef7ea078abf63, 0x437b19a17b61add5,
+    0xff27b16e63d7a1fe, 0x255e21570147e5e6, 0xc8fa9130f71ec8cb, 0x2b0afad8700bf24c,
+    0x8f695b89ad26a7b9, 0xb3b8f9b4f776b051, 0x722cb5d765f516a0, 0x07a6561d8b0c0dec,
+    0xbf22f28fd723f22b, 0xb501ae7cb2b53a70, 0x9b5c1a0804b1ebe5, 0xcb7c1514b308525a,
+    0x5e58f798bb95707b, 0x6f4a1eb851905fc8, 0xc90f19e49b4cc570, 0x79a39bb95146e7e7,
+    0x72a7544f9c604d14, 0x9a5f7899582e97bd, 0x6e3bebab99fda083, 0xaa681ee6114ec348,
+    0xfd917b5dc3c41def, 0xb8801a0d04031a98, 0xef68c19dd5d09542, 0x1ebb5568a3b3524c,
+    0xef853570864eca51, 0x1c42227c1ad3905e, 0xf400ea13da88a22c, 0x736ee256ac20502b,
+    0xd04e765daa91dbe0, 0xc9210ebabf853689, 0xc3318c84f4a8c1e7, 0x63460e6f4073febe,
+    0x59fcfdf91c130384, 0x9686e60d0a804129, 0x9e6643fadc3d1d32, 0x488e6ce2fbbcb069,
+    0x81d2a313b13020bb, 0xc910e92e4107970c, 0x1a6adc0f2a6be090, 0x3e18b82d2bc5ba7d,
+    0x25f107fd7185e37f, 0x9ed853f15336a86b, 0x766ab075c2a4c313, 0x0a5b67377c8ce505,
+    0x0dc88b307e39cb30, 0x3cf72777920c5593, 0x1e5bb623a0d5a149, 0x7753f28d5f296704,
+    0x869cff5df8b5aac0, 0x141d7284b925b493, 0x98f51a59c3a87c32, 0x83f6f1671e0852c8,
+    0x254e67787541a71f, 0x7d2fa8c32971e742, 0x0c2b0fe1825c22b3, 0x035240a7d6f60c2d,
+    0x601cd7cc73c152cd, 0x8503abe55fd99d64, 0xa61eea06391dc940, 0x8bf961034a021427,
+    0x977dcb2d329e05de, 0xfdc33c34a03e044b, 0xd488abbdd6129c6e, 0xfbeb411c1e6f2149,
+    0x69c6a0272ab9b672, 0x55d6a994a74653ea, 0x35fcb8d931d7b029, 0x7304ceffa0ae3eef,
+    0x8f70e1503728b794, 0x8ab68fa99f99d39b, 0xba20c6233e5494ee, 0x66f1daa715276ffb,
+    0x4bcfd3d98cff204b, 0x1eccbcf8fd5ca0fd, 0x324e4dda2cb90510, 0x9dd8b2d78b6a7635,
+    0x0c9f60c10433751a, 0xd61166f5992948c4, 0x13dde7093520bd9d, 0x2cd6668a36e29148,
+    0xbf7735fa8f534eef, 0x4cfef1a05fc1dc6a, 0xa64a7f9ab1221977, 0x0027668b029196eb,
+    0xc204a33e242d8ee9, 0x4024f3e739c576cd, 0x93ce42af467a90a3, 0x62e7c912dab0c79f,
+    0x6337f33ebddbd89f, 0x05c987c7934d3cbe, 0xda71256cd868994d, 0x14b69070727c9bdf,
+    0xdf7820d99642640a, 0x78161b6030c6db67, 0x8561234e67d0f5d7, 0x8a23abb7637173cd,
+    0x38cd4d29c5afc9ca, 0xe6c155a62a0383ab, 0xfd20d4a8a6596896, 0x8cc13cb73862701a,
+    0x3e069c40c173fe84, 0x64ea94cf6f00a213, 0xf2e777d8b9891c21, 0x630aa1dab6ca94ad,
+    0xe1eafec4c7744a9e, 0x6f88d1546a9fd8c9, 0x3e63b14a2915da39, 0x63646cebe33b640b,
+    0x35b4f5fb4270d283, 0x14f3e02c02b76571, 0xf4459330cc67d14f, 0x79f77c4a7d7fd756,
+    0xb88f4f29e4f1ff36, 0x79aabd44803eaa7c, 0xfa003dbec28139ea, 0x9afd1ee001682d11,
+    0x5eeec2d4889c17e9, 0x7b2b9aba8b04c97a, 0xe45e100bea751aef, 0xbd00d5d97887c10e,
+    0x27d5056ee482e1d7, 0x3c24d2f3e802fd0d, 0xf8441dfff15b12ef, 0x2b07fac5d7a988a7,
+    0x4d93ed9bc204afc8, 0x22a8dd22a46beac6, 0x3e0d25a0e92c645a, 0x37ea51d620650dfa,
+    0xf2c91ce281cd51f9, 0x439cd06a594cc33b, 0x80e0f9ff1273a9e2, 0x7628cc5967b7dbcd,
+    0x6d35f57223d1ec71, 0x84e70a736b77f84b, 0x6666718d9c5935c8, 0x80d86f66b308a392,
+    0x0772f65b13103c0e, 0x719941ba5426d83d, 0xe996cde739869958, 0xc9919da7100b31cb,
+    0x2ba1ce60599f3ad7, 0xd2e82a0e8aa06c71, 0xa38535b75dc06b1c, 0x53d5e69994ed8854,
+    0x86e88a2286eea912, 0x593a5dea16d2ec81, 0x4112e06b48c1f2f5, 0x4f58958d359f0e1e,
+    0x0a0394cd33a23f48, 0x042c05725064e7cc, 0xf8165de4aadac090, 0x483e95827ecee2f8,
+    0x60bf3706da59d877, 0x080474caaae5cc1b, 0x542b98e8647414f1, 0x35ed323d5f9ed051,
+    0xf20f3583aede305a, 0xfd830595bad502c3, 0xe4a98c8bf7aaa978, 0x6de4b27ef2567071,
+    0x435278a49cd06968, 0x5e6789dfd4bb6d80, 0xf623557560140b08, 0x02851535f0b5affa,
+    0x68b1cdc6c93372eb, 0xa02664127af6f827, 0x7799aa36f02f4a66, 0x4028839c7a3e0bae,
+    0x813bddf40de05893, 0x66c282f7d33348cc, 0x5ca4daf2664c59e4, 0x2b854c45f4fa171a,
+    0x99e666098d8eb7e0, 0x209d1cc3b8ae3078, 0x867bf63c82cd4cf3, 0x7396ac9fec195326,
+    0x286d2fce2e3df269, 0x17e64ff6f3b6a61b, 0xeb1b55a3ef886765, 0xb29136810640415f,
+    0x37b57a22d5757df5, 0x2590e227ce055c24, 0xc468fbb464aaebab, 0xfa5ebdfcae2160a0,
+    0x8d348b2e160d2eb3, 0x3e1e9a8636a22bc4, 0x391688f8f6d42a87, 0x9d6951a07f8fbbc2,
+    0x8b082aa84fc6c9ba, 0xe17e0d330bc832df, 0x6967b7caf5a2f545, 0x42ac4e73214a233f,
+    0x4999a1d2739c17d4, 0x7f15e0c3a272ca92, 0x4df452f36ec74fe6, 0x5cbafbf104912da8,
+    0x2fe6d4a651925061, 0xd4d4c14f1d8978fc, 0x53d7d46c898c08d0, 0x2d11554406760a08,
+    0x0b90cc895d7f642d, 0x1e5fcb68c0cd274a, 0x8a4cc3cd0c2108c1, 0x5a00db342473ad63,
+    0x3c54edf5fcf354bf, 0x110afa16aa1b955a, 0x8398fde893a1c29b, 0xeff29df3f18420c5,
+    0x98059bb0ce419e56, 0x0dd7113cef467a8b, 0xb43cb1162d401510, 0xfe10fbf0327cb155,
+    0xfe233f5d90643116, 0xe0f649a985d6a0f5, 0xa54bf7080aad395a, 0x33ca57ef4122e9c4,
+    0x0df6bac080e49219, 0xceddb2e931754981, 0xff47bda50d358f86, 0x32e693db5a41bb5b,
+    0xbe017f7aa068ffe2, 0x61b000a8c74bfc6b, 0xbc627a0dce80694b, 0x50939e464ae64b6f,
+    0x8540a053573cc62e, 0x42d37ca8752ae85f, 0x850361c5e5687c3f, 0x80f4d81ee7a4341e,
+    0xa438899bbcf1dd69, 0x43307b456ff13349, 0x53e24565dfa1ccb1, 0x976ad73345a5bb73,
+    0x5aeacd97488a1100, 0xabafa77caa5c0117, 0x6da5897414850ca0, 0x34bcdc302956fd14,
+    0x7241c221c64034cb, 0xee0eb6d5de782770, 0x1d25e9f2e86e03cb, 0x6fbd89b3ab1e6cb2,
+    0x7f180b341b237848, 0x95a04d1db2ef1267, 0x6a905634d339451a, 0xfaffec596e3b6e0e,
+    0xc1cf1b51fbedb1f0, 0xd7c3de493f2f8e1c, 0xefb788e5c0293983, 0x055c2be7639a5e34,
+    0xa4b18be6a5e0c629, 0xc9f4381fdecda475, 0xf76d157b7d26d211, 0x983589fde52b642b,
+    0xf162d588e59eb240, 0xc6638199e1132423, 0x5c684a36f4576262, 0x676173ee860ad5a8,
+    0xcca8626205ba1782, 0xc5c4d7acd21f53b1, 0x3bd5ed05c690f50a, 0xeb33a82982b91ee5,
+    0xfb2ae882c9433d41, 0xb913769292dc2022, 0xd6e3fca19e4ef7d2, 0x16cbf28706cc8be7,
+    0x878af9772105d75e, 0x29bac235a9410263, 0xcee93dd063c21637, 0xbe7745fbb95951c4,
+    0x604e19f7c3998636, 0x72603311b7b9708d, 0xa522fa60dac1c5b3, 0x96984c8445867df2,
+    0x7b599a96c0193f61, 0xa663d43357f93221, 0x8dfc854aa22c799a, 0xc0b662ca2d00b1ad,
+    0x35de36a06eafaa34, 0x1f2064511446fb6f, 0xa87f130838a4048d, 0xb7dec10c2d1f1da5,
+    0x0c3a1bbe5ab65e33, 0x297aaf14325e4856, 0x14fb35bdba4b6ee8, 0x0f1abd3ff920c4df,
+    0xbeeb3ed7c51b92e9, 0x1886dbda3302811b, 0xecad29a9e09f7cdf, 0x3693a52cea361bcd,
+    0x0b9e4f181468844d, 0xf144889ae1d31b64, 0x912b98486038cf73, 0x1d30adddec98109f,
+    0xaf9698b089f55d15, 0xf60b78efa420cf73, 0x74e4778f514088f6, 0x28b1fc809c5419b6,
+    0x84e3c6145b4201a0, 0x5ef39f3ce66a6d7f, 0xcd5c24715e80e9aa, 0x7095bcb60a22f55e,
+    0x6716d01e324d3dda, 0xbece63fc334df60a, 0x94c9646591f034f9, 0xefe5338d73e481bc,
+    0x522993d77b412bb9, 0x686d60cfac67fd90, 0xb153d735d4f61715, 0x8a1f9d3c1eb43731,
+    0x91718b307f228311, 0x58caff5222d50261, 0xc5d852c44cb4e711, 0xeff7421be611b513,
+    0x85e94cc4e31bb89f, 0xff2cf54bc93c2a80, 0x432a514db6b0cfb4, 0x6f72063a56dca48a,
+    0x5badb8f0772d0f5b, 0xe5c115d862d761db, 0xf588876bf9ebb08d, 0xb64850ec1002e3ff,
+    0x80ed392e78957999, 0xf1e9aa532501f10a, 0x121a3d64aec0c549, 0x15dceee17867e568,
+    0x44a8fee9c4f802ba, 0xf7c4fc7fe4f7636c, 0xa3711177468c8e4f, 0xcc84dc1fa7ced101,
+    0xea1d15d0287d31c6, 0x1e763fa3a9be9dbe, 0x8afae795bf93e936, 0x546811aef364124d,
+    0x1b9716487d352c92, 0x475aa222d2a569d5, 0x51e4481e90c79ec1, 0xef71d36f415e1925,
+    0x0d7efdb64b791bb1, 0x38c084deb215dadb, 0x24c777a4f8fc7ec8, 0x0e77e91c59a963e4,
+    0xcc26d87ac7309abb, 0x21fb7b6c9ca77dc3, 0x2c7ae2cfcef547a8, 0xbe272b3e467a2b4b,
+    0x59c9e2a5835f4f60, 0xc9670f8a52c67f26, 0xa19bf7630286f519, 0xe2c9bbe9a363fdd6,
+    0xb8e35ca2e0d68433, 0x296a64c16c13100a, 0x71e3eb0be2c4d73d, 0x44251119e7910a24,
+    0xe833481c2d10bd24, 0x8753462cf36ecbf1, 0x704fad0f8c72dc17, 0x87eff1cbb486d2e0,
+    0xa8c8b54bc05296a2, 0xc225bac3e23eca4a, 0x0016409517b89ed7, 0xee7e4e62da7165c2,
+    0xb75f6f282d67c6c5, 0x83cc007485913744, 0xb04c864b89be6558, 0xa4dccd80413c41ae,
+    0x47632aeac5bf5cb4, 0x4d53b87774260b32, 0x5054ac08adcf2a86, 0x9f73788b297c101b,
+    0xfdb46fe9fbeacdb8, 0xeae2f7f9f176c66e, 0x0a4e0c4d7fa7092a, 0xf09e281525f04736,
+    0xf88fbee8edc63af4, 0x0a6d1e51913575a9, 0x29b989be03bf8c04, 0x0973ea3ba1975740,
+    0xe4937ba0a9e7047b, 0x6f78ce9df2a25792, 0x2047cf7bf10ed640, 0xa1b76fdeedd3e270,
+    0x3261a648931dcd9f, 0x7ce84e612d2d2ce0, 0xb749fbe068c1e0b1, 0x799ab83ff710c1a8,
+    0xdc238562d4aaa87d, 0x2cb337ac24c13362, 0x9217e70a6798291f, 0x562d7dca4c0ef733,
+    0xa5ccb8f06965a306, 0xcd6170ffe53e331a, 0xf28542fd8cc24679, 0xdc49e3dcad308ddc,
+    0x53d69b9a31dbfc92, 0x08324161e13eb87b, 0xef9634140bf72fc7, 0x0f6d4aaec110a0f1,
+    0xd4656dd94008b1af, 0x4a339bf96a7801eb, 0x559b6d44c5449cc1, 0x500aa570ccd7255f,
+    0x63dec33a11e61ebd, 0xeaea7f12cc509e9a, 0xbddf1dc383738d70, 0xb324cbc05c59c54a,
+    0x0cbf387ffa9ba1b4, 0xd468be44d61d35de, 0xfb16b1efa06c1b65, 0xe7649cd2d58c97ee,
+    0xd81c57642527e504, 0x10fbd1e73b2bd078, 0x48d45f4ee85f6728, 0xf1990437f7afdf82,
+    0xba94f248eb2250f9, 0xb9e3ef425775b4dc, 0x70ae941cf224d8c3, 0xac9f3da9e5dd7bc4,
+    0x8ff689dceb7f8057, 0x054674b4e93a3ef5, 0x356d305c7a5eb035, 0x033dabeb12aeabb1,
+    0xc8434d91a26e8b68, 0xfbfe1d7d7d178a80, 0x58314fc0b5136cb3, 0xc21227d86c5da284,
+    0x150fdf3bdd61438e, 0x083b5e0b5dc1e55c, 0xdde0c0495508f5ae, 0x8d83ccb811d107df,
+    0x883e4dd326b757b2, 0xba7277155ca56b19, 0x7ecea0a7789a100c, 0xcc514da68b6158f2,
+    0x16002c4058c1f8c2, 0x93afd45fd4bde016, 0xa43a9332fbf80f54, 0xbeaf38ac2b038671,
+    0x9575b88802393cbc, 0x38821f2c566dd48a, 0x6bb040b0c32a8921, 0xafd0eb4bd3915a4c,
+    0x873c444c5be3a11c, 0x4ce71194ee6ebacd, 0xb26fc89e4afa9742, 0x9a1edc88bcf1daf7,
+    0x416fa5a782d704eb, 0x3ce89a054b608fc7, 0x65b0f8ea8016aaa5, 0xb4844da9625b81c0,
+    0x9719e5505a4770f7, 0x51dcb849994452dd, 0x48d1685d2b283818, 0xa9df69881816e580,
+    0x41b210c77cc3c3f0, 0x4718027c65120874, 0xa9f95b9fbe2d97f1, 0x34556f3ad91c38d8,
+    0xd393c67212920338, 0x2bf2584dd3e51b61, 0x7e6a1c95b74abfcf, 0x248e4bd4f4247b54,
+    0x00a553e0058c887e, 0x928990623005aafa, 0x8babe45bca2b5b69, 0x389ad3ec335df3c8,
+    0xa9c9b08d597e3d0f, 0x17f14f495c6a8991, 0xb2ffb6832c19a5e0, 0x83170682fa630d56,
+    0x731edbe1673440e4, 0x5b27f5a2e0f18127, 0xf23c4f64fac2a46c, 0xc6bc2c610fce5861,
+    0xafc26442a7935605, 0x704a57626312143b, 0x341b048e28c2f9ac, 0x101b4124f4f1b341,
+    0xb1107f1e9796b653, 0xfe67726c46d61c76, 0xcd742a967e4269ff, 0x982c8d1cb3d78a7d,
+    0xf5447f9d0aa80cfd, 0x91f3f1b501fc5a50, 0x5fed5ab6ec581350, 0xa58cef1807d6fa91,
+    0x84d7b093695a0226, 0x4aad3409f4506d8d, 0xb1a94e62f6705cd6, 0x361168082e2428d9,
+    0xc870064a2a27269e, 0x63740f5b58381646, 0x6c6fb0596d300e57, 0x446372deeaf50c54,
+    0xa796afae9b352c08, 0x785a4051be2b4c80, 0xc1c413c7f764d13f, 0x075d42a1c7451ef5,
+    0xa33a47c632a0d723, 0xeae2cc109ccb8163, 0x84cc1595bf1886dd, 0xd96c324fca61b5a8,
+    0x6c68f69d8730dcc4, 0x1b181d0c1f90f852, 0xb2729d7737307320, 0xd8cab30091d24c32,
+    0x26b34e316bebb130, 0x32efa5820aec9bb8, 0x7bdfb34a2e5f653a, 0xc1aebbee69b78e15,
+    0xf55d35852d90d45e, 0x001bcb8006b2ebe3, 0x34d9a79cc83b251e, 0x970ac111f2323784,
+    0xb6317d3991736079, 0x4db08d46420d83a7, 0x42f5c9a035205fdf, 0x7241b5292f3e629f,
+    0xc6e57d1095f2157c, 0x3943153549ecf106, 0xcdd9ab8f3ae87dcd, 0x984683d206ceb719,
+    0x37a8c9ddfeb10d1c, 0x0d4f95ecc20d5fe8, 0x812907c5c7738e9e, 0x0b00f473964fb319,
+    0x9701ce7029e11b45, 0x9cf88bc27f19d38a, 0xb1cd400a2c242aef, 0x01c489eede989d96,
+    0x4f5591206e66bb35, 0x7182c908e1d7a93b, 0x93eec98c98ad09fe, 0x955af03efdbc450f,
+    0x05f0d592ce2c0c8c, 0x1fc5c5db1c3603a2, 0x2551a15879e26e52, 0x656c39474633f9dc,
+    0xbb339d731fc5f46f, 0x3d51e38161d72759, 0x292b1100ea78b4ae, 0x9ba8ecae75ae0165,
+    0x503e5495c0b8721c, 0x5a14e1d4b96bd988, 0x056db919e145725b, 0x7d28884052847ab4,
+    0xcfc42bd8d5cc81bb, 0x02bfb82723317ea0, 0xba6348f2df78ff98, 0xfa2f6593f50e1f82,
+    0xe0ae6aafc2620462, 0x625c589a4f3376cf, 0x329291b8498646d1, 0x9377700facbd9571,
+    0x3fc12e12e3fbb4a3, 0x03b1cf4cf08490a9, 0x05a0a01c07b64c0c, 0x931bc35831271ca3,
+    0x12df297b7a4db717, 0x70f02df96e6969b1, 0xdb1ca8b77e76b033, 0x4069596bc310a44c,
+    0xf4e4d643e1eca142, 0x8f5596086fdf4a97, 0xe3e9f41627e5645c, 0x9b66754b52d0e109,
+    0xaf8a003aaaf3b5a5, 0x7540894b2efbed20, 0xcf042df5a9fa698c, 0x0caef1001430d4d3,
+    0x855485ce2693d708, 0x04694cdb2c675687, 0x9121904d92ec7957, 0xd97ab3cbf0349529,
+    0x5b104af56b7c10e5, 0xfc5b7ce38e896c8c, 0xa35fc748e880ca4c, 0x16ee88ceb8eb4f22,
+    0xfe3236ddd7a3488e, 0xb30682ea4a9bc2a2, 0xe4a4b813497ebd9c, 0xd9f125ffef394552,
+    0x43c8eff2dd88efd2, 0x4a2358a1ab9a2c84, 0x8fd34e9e5b7e9c56, 0x926ff47b0b97692f,
+    0xb944a17e35269715, 0x5247002cec168953, 0x4304686d62d793e0, 0xad36e9879113f99b,
+    0xd0ff8222a96934ef, 0x36e499509d8599c4, 0x3f59febf6e801e0c, 0x5808d147c8c161b5,
+    0xdcaa25dd2032ec6f, 0x0c201fadab1a86b5, 0x1ab40e6349d8fc31, 0xb742cfb201541da3,
+    0x1a3d0d1d02c60746, 0xf1cb8ba7380ac5cc, 0x4584e1ee53394edf, 0xe2dc70943a4ee16f,
+    0x739914527ed3aa39, 0xa0a14a21379c9871, 0x247920e7bcdb2f7c, 0x8fe6e7fad4478c9b,
+    0xab7247a93e0266dc, 0x9ddedae68c529b55, 0x7ad56d58171dfae9, 0xd3f0adc1e8e4fa6c,
+    0x6cd331776156af96, 0x40a9bd9fd4bac423, 0x83bf3d36377492e4, 0x9f0d8439655dd1db,
+    0x3b55d7a80b3db289, 0x1f69ebc9432eec45, 0x8942e886e947282c, 0x33344b0ecc75f4fa,
+    0xe6e8f8aa737b436c, 0x278c76c8327c34ff, 0x233a62a62e3b5c2d, 0xc31c6a95925aaced,
+    0xc0251fafb808beac, 0x751ccf2b85d2e3f9, 0xd22b61098f9ffb4d, 0x1ff4de286ba11c00,
+    0x00c9fc2c4a336f9e, 0x0b6f5a3b05462488, 0xd5932e889150edbc, 0xf19f73b6fe3f741e,
+    0x393c4d1df0e5cde8, 0x7c2acd4b3b786627, 0xcf7e4f396368aa31, 0xac1d83ed50da163c,
+    0x96c22ee1545bc6aa, 0x34dc7da78c0305bc, 0xb3945e151a8b2e38, 0x4351ec52c4a521b8,
+    0xc3f7f6bbd72f4a57, 0x15f7814b3dc14417, 0x1b9d12079bbae77e, 0x0f74cff86360112d,
+    0xc5963f0f5dc1570e, 0xb3a7e6ec5a136965, 0x545ffdf6b7085896, 0x8fc3b52d1a67e22b,
+    0x0bd75e34c3501a1b, 0xce1418153d8fd4c2, 0xac1ecae8109b5145, 0x0e9401cb4dc39c8e,
+    0xc434143028e9da5d, 0x75f10a7bd20af276, 0x42c19ab919aa43d3, 0x55205fd564115d5a,
+    0x2f2402de4cc8ce97, 0x53aedb3e95867d38, 0x9beda5a18723cbaf, 0xe4ae28a7205b0267,
+    0x295cf7990dd2fb91, 0x5d4d4deda0704240, 0xd7ed4f795ac83d86, 0xfcf4b9b22a9cab07,
+    0xbf367f37a1a70f0b, 0xa9a67e2cb0f810a9, 0x1b8ac788064549e0, 0xe1f3cc9836d3a0dc,
+    0x8c9de77152fb7968, 0x3596c1ffd5a00e7b, 0xbe01ffe64112bdd6, 0x0db8c0d74cf7949f,
+    0x7e9d5c0db576a1e7, 0x182686f50d5e13d7, 0x189388f24b828c50, 0x2e651a38da93022c,
+    0x5a3d14450555a3cb, 0xa2b90d8ecb82e6bd, 0x52397841ed858a10, 0xd5dce9f2a5856850,
+    0xdb79059b3ce1b99c, 0x61cf4ed58c0a07ee, 0x305d303079018127, 0xd4eace517b9c5db5,
+    0x6b5647a38d3cc98d, 0x22fcd864cac59830, 0x7bc405b2943c5401, 0xe40a8b5d3207e53f,
+    0x60bacba76e2dd982, 0x55435f48037ebe06, 0x580efa8522f1daa8, 0xa7bc778ed475440f,
+    0x55cf74d7bb85ddec, 0x6b2d969f5bb3c9fd, 0x76004d12be42f3d0, 0xa6d9aae0c66bb2a2,
+    0xfbb4e41ad99e19e4, 0xe91161c79bec5e53, 0x590e41d2252b334e, 0x3b42f9aa1f3fd9ce,
+    0x7b660ecb577c7f8d, 0x7199dec2e8ddd78c, 0x27f81b92aca67d56, 0xa0f658cf7ae3d467,
+    0x0676959dffdea784, 0x321ea838c8adf87d, 0xa8952c352d870094, 0xa1ccb1e8dfb60bc1,
+    0x37923b27702ab4e6, 0xbb2bdb04d69e99e2, 0x949a4a75d9a817c7, 0x3817f32765f975bb,
+    0xe9b4811240a16c7e, 0x9eea93913b0435de, 0x06f83d50bed468d7, 0x87b6f5b11df4c6fe,
+    0x114aac5b2ec98a27, 0xbd8071d5d6b9b721, 0x80e37c5f3f547688, 0x33c7113c78291552,
+    0x81ce2958a5db69fa, 0x346772d627d16000, 0x614b2958374275d7, 0x5c68f1d6605a757f,
+    0x0698592302ccc047, 0x513235d843df0143, 0x81100761dd369bfb, 0x1f80e18eaa1da23d,
+    0x5bf26a1dae30ab04, 0x5ef21e1b4d02a3c3, 0x2797ec5d1ccf75ba, 0x3c487a7e1b638ec8,
+    0x436f8ae6a6d1178f, 0x8e1f1cc49e9d63ed, 0x5f05db131d03365b, 0x528c9396555810a9,
+    0x246a76f67e8d6e50, 0x95c46276db06f02c, 0x3d78c2aae54fbb0a, 0xb34fa9223bde7caf,
+    0x80f5f14782513623, 0x8e70045759cb4e21, 0x221caf5e8e20e9c3, 0x14a66bce9cb73596,
+    0x8142cb611971999f, 0x88e806cd99377c1b, 0xe92cc91aa13101a8, 0x0ccf1b9b2eea9f18,
+    0xf6386c3e275ac119, 0xe352ed1060012f2d, 0x22e5bc1ea661d4a2, 0xc2d632ce540559dd,
+    0x224141e81df74f37, 0xe730b88d5c42e482, 0xc89355cc30082704, 0xe204c870cd9089e1,
+    0x820b261accbe2128, 0xf675d90042344d27, 0x0e708b6ce60faa10, 0xb66722c10c17e6b9,
+    0x99bf5063f6921071, 0x573e6ef7377edd90, 0xf4f42d23788f4c1d, 0x3a22adcb730b3210,
+    0x8b4e971b5b1d8bf9, 0xaadc303c32b1b01f, 0xe7cffa76f3e29562, 0x0841c485b8dcffc4,
+    0x9aa180a5ed5bb8d0, 0xe27e0c5fdecf6e21, 0x43021684af3c3350, 0xd3b99b518639b421,
+    0x7b57eeef5c5d84d8, 0xc3b7cffe547e64dc, 0xa93e35be3dca4c69, 0xefa196d45d67e846,
+    0x24a4e8e22f292991, 0x31c187b6b6a73c18, 0xebd004736dcaf208, 0xb0bb85ad2837b168,
+    0xdd3cf5b3b14b9d49, 0xbf6287f47dfb913c, 0xbf5790a75f9a07fd, 0x9377805cfb1863c8,
+    0x23bdce9dbefb9a3a, 0xc24ebcd0799d6fd7, 0x002da033d60eae6f, 0x96b45c54f319be87,
+    0x80aa6ee803789bfd, 0xb115c38ffd793150, 0x4cadb5b4564be120, 0x37c847c6d2886dde,
+    0x1ad7dcf85787f051, 0x0812f186287aae47, 0x86178acdaa3b2c75, 0xb7f7c78f34af378a,
+    0x700570c2d3371288, 0x9caae6bd0e0c1d70, 0x71a1d59df6a276ab, 0x0271b8a20f4154e3,
+    0x4a21ff28865ea17c, 0xbcaff8ffd786ab04, 0x1d926c85071644a8, 0x58bf9e6e524a6d5e,
+    0x9fde0171805134c4, 0xe45bbab14409db30, 0x03504b57a8998fcc, 0xd1b54669c463da8d,
+    0x9d3e832bceaee19e, 0x9e5b4d1690d6ccdd, 0x964bb9d0f6eedf73, 0xab88cee1f0ddb9f5,
+    0x42625bb1db3286b5, 0xf3350436a5278bd0, 0xec3b9e5903201679, 0x63f02893ec4f4437,
+    0x765daf0e0f8dcf0b, 0xf8b17baed1265f66, 0x700625ea50f641f5, 0x945b56fb432a1d45,
+    0xa010e1c55271f120, 0xdd57ff25c20547a6, 0x3bb97bc38c062355, 0xa08b6ce09f58eff3,
+    0xa0730b691580d4f3, 0x382969ed964f043e, 0xa6c3f2c22106780e, 0xcb241a2835e64f27,
+    0xbec2257f46cc3640, 0x93e738d92cba4dc9, 0x95171cc9e1d3bc3b, 0x05fda0b21ceabd23,
+    0x0afa4a1b2919c58b, 0xa9a1d590988f0463, 0x86ac262740ff95e8, 0x976c3464fb321a05,
+    0x6bdef4fa15d3f534, 0xae0f336e4cc4521f, 0x5932b5699edcfec6, 0x06882061a03386f4,
+    0xcbc7aef9ed31ab60, 0x1aa404b55ecf4fef, 0x05cc8752eb4eed85, 0x70181f70363c3b73,
+    0xdb79496a93014e6e, 0xdc9eb8eba66adbb4, 0xc3a42642d172f5ee, 0x510ec75934020c36,
+    0x64da1c6caa2ba272, 0xf8abbc706a1c54dd, 0x00615df8955aa345, 0xb1e8bcd5a9e94778,
+    0x02378408a9104bd5, 0x0c0722312dcc5b5f, 0x4a3d2417dd088226, 0xa3f174c231bcd793,
+    0x6decefc09d728329, 0x8ffddfaf5f231454, 0xd7dbdceb2acfdbec, 0x036fe7f0e5cb6984,
+    0x1294e3e0293ba204, 0x0d6db814c7670ce5, 0x39cd6e8c7acc8095, 0x7d9169a18fd68394,
+    0x0cc4262205687e05, 0x869948e4df2ef0e6, 0x30bf584c935df741, 0x549025301a2b181a,
+    0x403fdb2d3e8a7588, 0x9d6e218f018809bb, 0x288d3806beda3443, 0xa8d2e40f2ed8788f,
+    0xefd79cd324a2c5a3, 0x6befb3c7a5d9c654, 0x8bf315f8916872b0, 0xf6a4ad5e92971820,
+    0x9e1e3865be9d0144, 0x372b2783d5f7e214, 0xa5b7f067088c0fbe, 0x11f04f6d5140cd28,
+    0xc3009e4a431c2a09, 0x949a6a1d3fab90ce, 0x5d642f10ef74529c, 0x9f567cebf0870a41,
+    0x8280bb0c40894940, 0xb67b2a4146503bca, 0x8d11a8a787858155, 0x7c29ec55f26d3c6d,
+    0xbd396b2629f53e1c, 0x839538276ea468e5, 0x14b2348995f273ff, 0x91684c338469c769,
+    0x25aebbdf7e41908f, 0xea9345335125ac65, 0xd6f2a7eb97a821c2, 0xa06606f6c0ea3709,
+    0xe0e8affa775c1ede, 0xd84fe7531c5c70f3, 0x0cf99fb309724f1f, 0x686776ee33a6aa38,
+    0x06ce6072b77c9133, 0xe9a4713ec82dca13, 0x52705fb65d5af8cb, 0xe48bf065dd80535d,
+    0x81b996e2b593c705, 0x439d6c5dd984004c, 0xc348c4adb5aae434, 0x5c84088fed48b69f,
+    0x7204450da97b6609, 0x66d2427be2b38c7b, 0x87726fe37b897a0c, 0x8353f2186fea93f5,
+    0x80edee7a67716fc7, 0x919dc001f11a87d6, 0x813ba6676bcb9499, 0xe5da0a593a4b1e4b,
+]
@@ -0,0 +1,80 @@
+[
+    0x61c47d654d553c61, 0x594c93922afb88fb, 0xb760224d6c61e5de, 0x6ca8d530df94a599,
+    0xe48411069b24abf4, 0x8a11b83f63c2b6e8, 0xbdad1c4169b1a51c, 0xd56a941e46e25819,
+    0x897e7f492bd57245, 0x99ff18283e2e9add, 0x79c774f5a1f98cab, 0xc93119225f83eace,
+    0xad66688a82178fb9, 0xb4f0a57fd03753b9, 0x920e0d2ede247b47, 0xf87d85454e46534e,
+    0x7608c3bb63f5f04e, 0xafc2a883929806f6, 0x5b9d4f2173115cb2, 0x192f3473cbbeda14,
+    0x8b8218c612f1cb6c, 0xc01e44afb9c836e9, 0x3311ccd4e63b6b3f, 0xb4ee4bf36be39ade,
+    0x83752dbbeeed0505, 0x4f1e218d53489f01, 0x516b4e05db977401, 0xf03aa4cfb4ecd1ea,
+    0xdd00522ee984d36e, 0xfc2f61ea993b6b22, 0x1e1ba4511ed6bdb7, 0x37fbf5bd279c71ea,
+    0x30dcac8b01ebb22f, 0x9c42cdb605409432, 0x3d20f96308a33cf5, 0xdcc4874fa03b32e2,
+    0x01b93b7392606a07, 0x95186ff924d8db83, 0xfae64e259a3aea3d, 0x44a682f5f552a8b0,
+    0xd88922b824561bdb, 0xfa6a6f324ec6bd7b, 0xab4c863c19442d9b, 0x245a5676ea0d1e0e,
+    0xb7553c5f84c785f4, 0x4426c2c4f85904ac, 0xe14f4d5738743433, 0x7ffdec9d014cf00a,
+    0xc12646b178ba9f34, 0x93407d50d734577d, 0x303ad3d1651b30c3, 0x0f10afceb7ab8b61,
+    0x944a11896ddee847, 0xdaeef8d1aac17707, 0x34e3ecd92b0ac6f3, 0x6a8bc12486b01b83,
+    0x1cd79f466a435051, 0xf223f9a0b7a19632, 0x2eb3971029a95e5c, 0xbf483775da22fda0,
+    0x2ef1a83ded7c613c, 0x08bb81806e9936c0, 0xce78e5e5ddac9ba9, 0x2b32fc0470df71af,
+    0xf0be742712312c06, 0x9a476f84c7147ce1, 0xcd9689b176bfa069, 0xd60303b3b2f4d894,
+    0xb8de4b26f243586a, 0xa97a9db1178cd572, 0x42a511bea76867be, 0xdd3b48cc78d01bf0,
+    0x2b853016f50a77d1, 0x5bbdda90b749d6fc, 0xe98c8c951b016b3b, 0x8101b5b5195e50c0,
+    0xfe63f59fc615ca53, 0xe62a133c4fd4eb25, 0x4750ec069e3110f9, 0xb88ac01421e23c67,
+    0xbf1e6f4c5651d45b, 0x3ee37f5a018e09b3, 0x9312d13bfcf93c86, 0x1e62c8c260e5c91c,
+    0x301d236929d7ca27, 0xd954ba6a8291b74b, 0x0c36d9ec06f7c8fb, 0x5d7abef9ddc7e419,
+    0x914e2a389ae7ae2f, 0xf49ee0d74157d49c, 0x8704b7a45bf5a92f, 0x1ac52a2be639f3de,
+    0xd3e72e396bb18404, 0xbc53be2cffcc0474, 0xd63b17d4fef778a5, 0xeaa054c3d8188b33,
+    0x92484c68f2b7b966, 0xc8f1c2bd564d75ca, 0x1e4420d3694a3ea8, 0xfb26d265c25327f2,
+    0xc330a98fbc43b375, 0xcb862233409387e7, 0x2a15823e5def6598, 0xc09fd940cece1630,
+    0x973d47769c29e8d9, 0xeb9fca1fc334c2cc, 0x3e36c6bf4836981c, 0xc9ef10863774fb64,
+    0xf659471aa1c418ee, 0xa283553b323c6796, 0x4c7d05fc9ea49321, 0xd46f22b18e286af1,
+    0x05e2338419172197, 0xbd45d4e26ae29cd7, 0xfa7cd442f4344acc, 0xec5fb98db6f069c7,
+    0xefa2827bd8607e02, 0x79d0b6da4ebd85aa, 0x6d2f41fcbe8f3ca5, 0xf9e16afc69839b2d,
+    0x03115d51489e1d32, 0x156ac3474d429252, 0x0b0476e46d8a5efd, 0x567442b2702a342c,
+    0x6129575f763ec19e, 0x7cb3b7feafe6043e, 0x244813cbcdda6d01, 0xe9d22819c06fa582,
+    0xa0f9bc3b224afe9f, 0xa3a5a2102299511f, 0xb25fbcbff1ea0eb5, 0x429d7746af9f3f5a,
+    0x66d4ec37e47d705b, 0xf24edf60d0196b9b, 0x538774588670e89f, 0x4b2ae6c094e9d9f4,
+    0x529fc48b51ebd5b9, 0x972e2bcd744e3c67, 0xb4fe6ed840addc73, 0x4bcfecd8352cfb90,
+    0xd71f0122226084f0, 0xcfc553a04844971b, 0x369285a7df394558, 0x093432bc079d5071,
+    0x4d53cdc4f4a823b9, 0x8fd7ce35d17c0de3, 0xfa0b6ff7fbbb37be, 0x9f74f2baa225bbc4,
+    0x7481d3597f513eb4, 0x4d21047927ca8915, 0x34d2df795ebe68d8, 0xa0f9096d21b329cf,
+    0xb9112b517dad75c3, 0x0eecb602b6c4f348, 0xe1738bf17ea06cb5, 0xba367563759b00c9,
+    0x329e1a553ad3a862, 0x91491ed164d5bbb3, 0x205d6ce9a40f3496, 0x2fd4d375c0fe1d32,
+    0x3416aac09ca0b216, 0xcefd230f125f43eb, 0xbd03f185f68628c9, 0x654e44e091a53fe1,
+    0xaef8235853254334, 0x1d67ff59b279ed14, 0x809bc798a43b0c0c, 0xb1393be15c25e58f,
+    0xfa00837ce09981f3, 0x2c51a3c551a9a681, 0x51c69025607d7f42, 0x316073f936725de2,
+    0x54f98f2f416c596b, 0x709859176cef3347, 0xa35fd758e36d6b6b, 0x5711cb188b68482d,
+    0x94e7e6d709a45342, 0xfecebc86932b8ebf, 0xbbc727bf9165090a, 0xee6f715ca4b0854d,
+    0xc831d91539fe347d, 0x45cc3be86816a72e, 0x4ce91e76883494d0, 0x502a898818d05039,
+    0xd21eb9a4d1ce329e, 0xd27f8512d5355576, 0x5ee987dfd0fdd304, 0xa9c200358c2764e9,
+    0x5ad0e3811eda5713, 0x0d7a5b10696fc476, 0xaa0662a23f298730, 0x3dd41f65116cea00,
+    0xd7d0763f629d10a4, 0x61ea79d8b4f32c73, 0xf079202cafeec11e, 0xdf4a0163ccec7613,
+    0x25f117071b5cf02b, 0xf92056b01ac004c7, 0x73262c1bd8a5c9b3, 0xbcc785a782b94fda,
+    0x730577ef3bad8311, 0x4a581dac9c5b7adb, 0x3a7e3a499e744291, 0x4056a9c31eb545b9,
+    0x90e7b1e4c08d6c29, 0x5e9048a9794cc17a, 0xa1a909a88aaac8f6, 0xe63d9192b0daa038,
+    0x69fdab066355dde1, 0x9ed5035ab38f23eb, 0x4dcaea0760e7f1ba, 0x58469db4d209902b,
+    0x1020e47c7a90219c, 0xef193e2dfe19cd3b, 0xbd4705bf92f5ceb1, 0xfe17eadecd8fabd1,
+    0xa1d25665dc6379f6, 0x0542d1b41372e279, 0x23dd53cb5a7587b8, 0xe7095f683306852d,
+    0x669dcc53bc8ec7c0, 0x8d4c629dea86dd94, 0x2ba17faef04d7cd1, 0x9286abe4e1b247fa,
+    0xfb051847369d35ec, 0x21f72848cc3ac77f, 0x56b72cf11948d512, 0xf2f839885b4c4fd5,
+    0xa31e45bfb095d7a6, 0xabf79bfa3c996b9f, 0x7651e3842ae98ad6, 0xfe89040777bd453f,
+    0xa922d70b24b8a229, 0xb57dc9e8cc02ec4a, 0x07a5a1e52ed76e52, 0xd25b85b066e81544,
+    0x6d2f253e7ebd1054, 0xf8019f213bc6c444, 0x5961b398e5a5b19d, 0x714041e2a5256cf5,
+    0x2e8909f42289e693, 0x1c54b5f77684ac29, 0xa273b058d4c56550, 0x2fe5b81926228818,
+    0x79bfe74cd7707473, 0xd604abae22ac310d, 0xf17ae0c472d22bdb, 0x0dff0ecdcd206a63,
+    0xd931a36e24977ba8, 0xfdab1145a53cfa68, 0x7a129809adfe0cbc, 0xa615a505dc9a5d9e,
+    0x8fd47e4945ea0ebf, 0x76a2a3f1e1298910, 0x0e955c1dc2e18711, 0x66a993bf93814c79,
+    0x8bb64f6156a6ba25, 0xd71471014a095481, 0x15f9a58eca797049, 0x30292ee783cf8e70,
+    0xa4b3eca181f043ab, 0xc25c76bc4cd25086, 0x7794241bcd00e7f4, 0x31dee3171dacc03d,
+    0x92c3b8c7b98910c3, 0x0e2f151fabe967c9, 0x5b46e9a6bbd06625, 0x2d9f49c95396e9f5,
+    0x7f7486d66725f958, 0x5ce0632bce4ccdea, 0xc15e621fd7a1fb67, 0xea225044c2c10592,
+    0xde1dddf9289f13b9, 0xddcb7c062a89db95, 0x2c7e773cf37a1086, 0x90d4c75b2136a797,
+    0x4408e36fdd830483, 0xbf3e81dbe21ca2b2, 0x42bdf31ed224c1af, 0x90dd396af76e26d1,
+    0x272a330802006d88, 0xbcccde1e845a0459, 0xc69a6b70c0bb3ae9, 0x4825e0dcf3f75e70,
+    0x3db2b338ebb78971, 0x7b3258473caa6428, 0x49ae8de7ad6d32b2, 0x821edb5a5bd7bb77,
+    0xd7bd09a5881318f8, 0x1e645ffcb1882170, 0x9ac7f1e0fc6f506b, 0xbab1b4be0b204786,
+    0x931217ddedc78842, 0xca7f76867c02bc30, 0xc45dfc28c556da31, 0x8fcb759b076050fe,
+    0x10864f895fb8e10e, 0x203386f07d36de3a, 0x34376f9cc8f79817, 0x94c41aaa7f01d43c,
+    0x17558a6651da7437, 0x8cafd7a8c2d1cdb3, 0x429c0722237dbf7f, 0xb1f00e6ed14374b5,
+    0x6567650c242a4505, 0xfe83fa8960fab3ff, 0xd61c86e8ba5e3a3a, 0x4b5c80f29391dbfb,
+    0x5b94f516eea35cf4, 0xae01eb5fb51c6d1b, 0xeb4173a7ee4547bc, 0x420d548ef464b02d,
+]
@@ -0,0 +1,847 @@
+[
+    0xaff7936ee51be770, 0x719fe1a6de966eb5, 0x719a1d04980117a3, 0x9823aa335ba25cf1,
+    0x84d51f9c4e3a516d, 0xc8f4a5b190726c0a, 0x547fdd19b8365b86, 0xfee716af0d42c2fc,
+    0x0916177c77c95df1, 0x0d74fe15924b28b7, 0x1908c2bd3b5f3b00, 0xc561473e0e9c62ae,
+    0x21bb03b4ec3d4f42, 0x17c192b4583234cd, 0x5758d028dc2e2cc5, 0x1cb6d3655e7fa7b0,
+    0xf86fa8f01690e0af, 0xc15d2507d5e05ad1, 0xe6a1b57d174efdda, 0xde816cfbff11a8eb,
+    0xc3041b51308aa651, 0x7706fd5074816186, 0xd85e6367131ee701, 0xceeaeb212d0215cd,
+    0xd2484e0727e54b7c, 0x73030d1bab77005b, 0xcbc4b3053976008d, 0x7dac859827ef5ff5,
+    0xe94f13a52ad12015, 0x5a4d79545a1d56a9, 0x2b889ae3179eb934, 0x7b7a4a6cee754ca3,
+    0xa3f09c2fa6674dc9, 0x4796a2182e6dd821, 0xf979821bb2ded53f, 0x5e5cb5f1025420b6,
+    0x76cf507228c3a645, 0xe453637220eb849e, 0xb45db85675858a5d, 0x6ead69e726465ffb,
+    0xabdd0c0c75f6d3a5, 0x29462894e14213a3, 0xa3b6d7fabea7fd61, 0x456bcfef0b2f1f45,
+    0xb1f2a8d3752dbd82, 0x139412c9f9582b86, 0x320f2026cbe407d2, 0x95dda6d216f184f3,
+    0xf2b8e2e86d49dbfc, 0x504c3fb76ff65d65, 0xffccac47483d058e, 0x720aa17670eee909,
+    0xdd83b6087858a951, 0x87e5a966f9a0e5f2, 0xb24f17fed749c48b, 0xe15bfdfa2fccd761,
+    0xd52ecc28cbbf9c18, 0x647f0a4874c66a65, 0x13ff721fd94210ee, 0xd13c64b7ff6bb3af,
+    0xbff619e233c4ef21, 0x46dbf16da0bb4d5b, 0x62a3e1de32a4b995, 0x2acbf125f523bfe4,
+    0x28dd80f3245012df, 0xb74bfa13dda736cf, 0xe197cfb2ee0dcfbb, 0xc2eac64dab3e3f22,
+    0xfc4d1aec47d64fa9, 0x42652938d7a3f987, 0x1b00045ac6162e5a, 0x99777220960b33d3,
+    0xe417c7d3b6fe71f0, 0x1e226872b2d2da91, 0xf0f5f3cbad75e5df, 0xb48a85a4404292db,
+    0xa8b62d3801d33d29, 0x306f5672eb855819, 0x63743cead87a8167, 0x1933f55966ab6be8,
+    0x414f768254c863db, 0x24e13a708ffc9095, 0x4c0df37ccf7d297d, 0x47003efaaca41c66,
+    0x54d9d5dac53f4233, 0x988eef09d60809e1, 0x6c011a6bf1fe1cfc, 0x9144cff10598dc36,
+    0xb2877e73fc6fe51c, 0x381c9dfc1a5fce58, 0x96bef38bb5ed1564, 0xe08be032b12c0559,
+    0x26277d9129f98b61, 0xb8b8dec77406f934, 0x6cb97e0cb8cfa59a, 0x6867e6773e95ac79,
+    0x7d192f7aca0a3b2f, 0xc009541f5e8cd3c3, 0x4af675236092d859, 0x8f1bd5238c4dd965,
+    0xba0b05b7102899ad, 0xc81a27c906602c2a, 0xf5b73b695e353455, 0xc744a510e78d7b6a,
+    0xceb43d413296c15e, 0x1683be15cd96e6f0, 0x8c12cc65d8ca37cc, 0x13e91a6d28e07692,
+    0xf9f074fd4b4a34e7, 0x081874a8c926aa81, 0xbae7b27e84b77411, 0x0b1025508aa73348,
+    0xd3f14aad57d6dcea, 0x959ef95bf3e5cb39, 0xf2b194dbc0253f2f, 0x86bb681deda7d46a,
+    0x8dd3e0a937b4bfff, 0x7671e3ae18cf5d64, 0x62900bc3ec44a6f7, 0x7ca96946902afdb4,
+    0x05210cc2975222e8, 0xce3e09cef25c66c1, 0x231c9cda724bda45, 0x16e36f1d5cc3c130,
+    0xd070fb4f47726d26, 0xd735b8757f035f20, 0x29d1657b0839f789, 0x6d19e561264309b9,
+    0x9d92135931867251, 0x338b111c6dad1e1e, 0x8bfdfdaf8d98863b, 0x0efb5133f7e3b84e,
+    0x06174e3ea7048180, 0xb06af55083436dca, 0xe0588d29c272e725, 0x17f82cd995246509,
+    0xa87b24934ecf4a83, 0x3a930e4d9bc2914b, 0x2cfb73725b5dd469, 0x89e506002b763532,
+    0x831e7e06fa5e29ac, 0x03479220e235ab6f, 0xc77440e4cf1bbc4f, 0x91a67edff1fac018,
+    0xe8cf7e075eac4a68, 0xb6b9bada06121458, 0xf77839add08e288c, 0xc5a5873644000cb5,
+    0xc6226141de242524, 0x17214c7f705ab33d, 0x0734e5f1cdc1e729, 0x45483288cd9b1d9c,
+    0x705996089d720684, 0xabea056183abd869, 0xe2242a81017ffa05, 0x7cde95ad3e6b88c2,
+    0x1f9cfd9f92143619, 0x2aab699e1dc4ed1e, 0x372c80ab5060cda9, 0xd296b7c5014374ff,
+    0xdea06937546631c0, 0xdc65d8356b7ff6fb, 0x716e4af0f4c4e147, 0x7dafa5c42c3b80ab,
+    0x945ee876fed49239, 0x579df82ce6ae9073, 0x2ad4155605e99ff0, 0xb61b603d409c174c,
+    0x7de6468eb8296792, 0x6ce1cf49608d6a0c, 0x97f6245775e2a14c, 0xc0bb26e621d57b68,
+    0x727cdcf47ea65878, 0xd771ed689e334fe9, 0xd1e93b654fedf458, 0x66cbf1cc4a0e53c3,
+    0x844605b4c8b74e0e, 0x2356f7a7c42f0af1, 0xe61e9ace7580acfb, 0x12e01a6e91370b79,
+    0x7ebe631af8ec27b2, 0x764741e2b00c2332, 0x0fb7c4bfc6b5ba08, 0x7790bde5be6c0807,
+    0x0e601007d99df799, 0x86d567ecfa5d312a, 0x0693270a2a75c66d, 0xdad320a4571a36ea,
+    0x15d6f27cde15f711, 0xcf0879db5967d8a2, 0xd9368e6ceaf637bc, 0xc9f24f90c5d28a77,
+    0x991886bc44f1453d, 0x7503d63474000e13, 0x0c68ece55a23b09e, 0xe727cd94dc2d1a6e,
+    0xcec8e8fb2e9ad838, 0x8f7be57ec0d91bb9, 0xc3d2211ba08543e4, 0xd959a916c1404806,
+    0x071215e9aed84cc2, 0xfdc247bb59f6a44c, 0xe1accef7ebc724b8, 0xed9d6bf16060a265,
+    0x3d653d696bc43453, 0xbc2197003630bacd, 0x0f53bdf216359d28, 0x2a5909602e89ac6a,
+    0x0c6ca4c8e9fd3fb7, 0x863cbec63d29c01d, 0x3753bbaa09f7d75b, 0x2a88d44b6fcc0885,
+    0xa6e0f075ac3dc459, 0x7fb042d9f0015389, 0xfcd24a3b28e35b3f, 0x00707c81e46ec229,
+    0x90410298932113a6, 0x4434f1bf6afc2b15, 0xcbf7dd31bd482279, 0xacc90810cbda967c,
+    0x4e4734bb79c79e41, 0x690260752c16412c, 0x7894f815bf664a1b, 0xf78b2b1ac03c5324,
+    0x5a8168aa10e9e2ad, 0x767f31a7f2a51ce0, 0xf2a9e4253329b4a9, 0x7e11268b1bb4142d,
+    0x9222fd21f8454c8f, 0x8b959d736d9bce30, 0x96d9c11d3918dd33, 0x831fdf0c102a7f1b,
+    0xf09a253e872b5b6b, 0x518d6fcaac2e545c, 0xd296cf35add3265c, 0x3c5fd662f87e6891,
+    0x91f814eb9cff5d38, 0x38a6ad5edfadc374, 0x4067625f53ed35a3, 0x1772b57f88c9fa1f,
+    0x4f8989cbc82ca169, 0x901d0f8941e1892f, 0xd3e28ce8ace90344, 0x483aa4392aeb0f9b,
+    0x8433923c04f9f6c8, 0xb1116df2604fe528, 0x055c441e60379f71, 0x66619f5c244657d7,
+    0xbc8bb69ab79359c8, 0x1271245855355308, 0xcf94ab6c075af7cf, 0xcfed04a6ed2e3ac4,
+    0x8c982bca84c6b722, 0xf8ea0e27ffd19687, 0x91614b0bae9b3a85, 0x457059d5808d80e8,
+    0xb23e6591d6bf8a83, 0x426de98ea7654db3, 0xa409b27a6b2f42b6, 0xa1ea9319cfa036b7,
+    0x338ec23d7f060973, 0x4a311b337a53906d, 0x9ef1b643bf347c6e, 0x4351bf583634d373,
+    0x717ccacd7e1b5420, 0xc7625997cf487ddd, 0x8c94bc15bbc52d1e, 0xa5b71baaaec38f49,
+    0x78d5acbf629e3969, 0xb550d298b096cbe7, 0xa03418d58fae4274, 0xfaddb501155913f4,
+    0xbd29eaca0d654b5b, 0x47c8797145ea8bc0, 0x43f0b80432597eb5, 0x9c4156caa16ee29e,
+    0xddea05f56103dfce, 0x61629a4960e033e0, 0x38bc51068ea2340d, 0xd8834082da634706,
+    0x04a736199fdef877, 0x9bb7381ba01be302, 0xa336a993b84c94f2, 0x631403b99fe5b449,
+    0x0cba31d6a0eb027e, 0x58b2704626170a4d, 0x4ccd175b9013713f, 0xee7173a0758a1685,
+    0x490d107798d94daf, 0x9a627a1b9d812cc5, 0x2a747db5edf8a9a2, 0x7445a22e68064ba6,
+    0x5d9d8a6df08e2dab, 0xf82155c6d54b8e1c, 0xf035f6c4d8119f3b, 0xc67941c8f1333b10,
+    0xfe78490fb1d6fe72, 0x5fb4375685b45844, 0xe01191bdce068c7c, 0xedc782d9fbf9ba17,
+    0xc847162f274dbddd, 0xe969755e4176b58b, 0x658e35a95683ac40, 0x416dd736eebceb2d,
+    0x172b978fa3b8662e, 0x382996cd83bb0661, 0xc92a76c9a6123a17, 0x525ea1be652df722,
+    0xf349790b24cacb5d, 0x21c84543bd47f401, 0xc6a110ecdef11de2, 0x07b58a94b3449721,
+    0x7e86260a59977475, 0xa53759163c47be4f, 0xb03069bea51f3453, 0xde23dbd5d50aed03,
+    0x027b3eec562598bc, 0x756d3ab5412a6d01, 0x1338e798e4f1c7ef, 0x5eb824435b952f1e,
+    0x161944fa42bf02de, 0xfbf666f0d4a1026f, 0xbad2cbfee783241a, 0xbcba59c03e315d55,
+    0xe134a66be0ac1a3f, 0x411213bdf57dc279, 0x9f323c833cff1eb6, 0x31dcfeafd055d744,
+    0x45a947c2a6db6204, 0x2a2527ca5806ad20, 0xa18a39e442805064, 0x4a50ba264b842edc,
+    0xb30aad92d0a73e7e, 0x2843a4c9b37fe105, 0xe4028b7fc68f6bfd, 0x9802d3040685b794,
+    0xaeb89a14cc83d2c5, 0xd35431cc5a73bf3d, 0xcebad8d2fdb1fb3a, 0xdee0453aeafce158,
+    0xc6fe44cb3b8b4f8b, 0xa0243232a5e13bdb, 0xa5aa085b7dd0c092, 0x31577ce677446d7e,
+    0x3a4efdfe88b68f75, 0x6564c89a2d63e00e, 0x74171fbad0f7c8c2, 0xa21796ac578b7eeb,
+    0xb9ba35bc12279520, 0x222c1d63d0cd15d0, 0x7d151edacf36db4a, 0x595c33aaf99250ba,
+    0xa7bdd57e2e00422d, 0x3b93febf9d98e1f8, 0x73a26d80df267d60, 0xde2d0b868464615d,
+    0xb66cc8b64ea2030e, 0x61842a367e58e2a2, 0x3e1147f8b77d7a99, 0x931c86397919c3b2,
+    0x8e95e726770a4b89, 0x2d6be1c642667060, 0x212b758570a3049f, 0x5678ae39146040b8,
+    0xde19ca3abffcb300, 0xc54339123e879334, 0xaf8183c6c76f2f6a, 0xf22b7a818c39f70b,
+    0x5a202ce5cb6cd079, 0x0387230329c98ad7, 0x3adbb2a38d95aebb, 0xc79cfb9d887e5ecb,
+    0x75dbec50987f3e66, 0x96337f0dfdedee66, 0xc31b41a99f9a5b09, 0x6dbb25eabab1df97,
+    0x0c36ad36118a1f18, 0xce4601ee0fb7513c, 0xa3db6f488bff15f2, 0xda709f413dbbcb19,
+    0x8f9a337772cb0fe2, 0x0a3601ea6f33b0ab, 0xc8ba48013edb7b03, 0x265949bc5d95884c,
+    0xda67b5e74a820c1e, 0x75c3010f0eecbfd4, 0x6db8d8563dd854c2, 0x2298fd4d4d313ba2,
+    0x24bdb9b0ae2acd12, 0xbabda00d7bc19e07, 0x6f206c798c6bafc5, 0x395910d55859d151,
+    0x9cc92bb9d91430bf, 0xd673b0e54d136d1f, 0x7159cf56b60b8674, 0xe5fde29623b34c12,
+    0xb023a47ffdcf0238, 0xba4a5d6c82a93200, 0xac9af075184eb082, 0xdd5481f92d0a0d23,
+    0x1d0d4bb1ad17c7aa, 0x840d406caeb11a7e, 0x5d678fd7e0c033cb, 0xdfb5d199e12fc05f,
+    0xc5f5dbd93ef618cb, 0x241307537b3a9278, 0x2199c207158d21f9, 0x10d8bf20e4308d39,
+    0x4342495a42417e5e, 0x2f6b096e10cc739b, 0xfd4ae760e7750e27, 0x42517a9cc5bb1fd7,
+    0x488f8022c4866c7c, 0x4de1fea3f1e01199, 0xb5f4a81ba1b21563, 0x33cee94a077d63bb,
+    0xe455b938f8d094e1, 0x40ac8ed1713d8f91, 0x42820ebc9409fba9, 0x3b80fb65bead3c0a,
+    0xc7c40611f6febd9b, 0xe7ee6dc1a8f417f9, 0x58fee367918c2789, 0xdb263a8c0bba4ecc,
+    0xe50716bd4a9e4fab, 0x75386c80c9e3fe41, 0x035a38e86fb9fdd6, 0xe75953cd40975c29,
+    0xf4e08b7a6d39d1d8, 0xbce5fcbe5ed08275, 0x522f33fcefe72934, 0x1883906fff86121d,
+    0x07895f06775af60e, 0x5a42754cd6b99188, 0x898add62728697bf, 0xb89be634738c77a8,
+    0xee4665423fbedbd3, 0x502e986f4974dc3e, 0xb405545b35a33a2e, 0x02dd9bcf9023d564,
+    0xcce5d580b2b3c81d, 0xd6cb64a709e3789b, 0xe977462db59b3135, 0x0bde7b2a01b7d60c,
+    0x18f8a5e40ba0c6d1, 0x5295e4d478f431aa, 0x7f31f4e836c9f552, 0xcf697915cd09e8a2,
+    0xe08e2e864988d175, 0xfe42be16996da4fc, 0x17b5753c3a142f12, 0x34ede2dd3ced3a4f,
+    0x29ba4201fcd9f007, 0xa3e17c90c4dcf9ed, 0xd26ba53f59039e71, 0x1bc9b850a82f2714,
+    0xc2d64471f74add93, 0x3f2a6abdd6dd6853, 0xc08560de9d67ca36, 0xe2f645ae0e3af6d7,
+    0xb96da1cece2c1b94, 0x07e69b4d37436e01, 0xb673517cca65fdc6, 0xbad9550c2d505897,
+    0xd13090ca13bbdd43, 0x388e902b67ecf5a4, 0x3a70e37e2399aebc, 0xfb8c2762808a6f94,
+    0x953242ac19f16531, 0xcd101498e4b8d4a3, 0xc52ff40c331fdaa2, 0xcc0d7fdb874cfa0c,
+    0xdc50f3e685053d9c, 0xd8891f4ded41d087, 0x54f2e35fcda4fc3d, 0x3bb649bb2b5bdca4,
+    0x9795edd56eb57c0e, 0xa72b184ad90a9a87, 0x9badbd76f38bd450, 0x338b7a9f858f7251,
+    0x4141cfbec772032e, 0x2ff128589996fdee, 0x7071453afa856902, 0x7b2924aa302b8f77,
+    0xa278d3cccab3b428, 0x8d8ded466ae0cc24, 0x98276a1cdfbb1b27, 0x2be50983c81e986e,
+    0x4d6416bbdb393ef7, 0xcd605d9af4200f78, 0x404bba848a0aae47, 0x203543ec5aa48e69,
+    0x3e37670676257264, 0xc1e15ca1e86c4f0c, 0xba801bf78d65ba80, 0x271677c17e624180,
+    0x4baae99c726fcb3d, 0xd3209cb4fa985eb8, 0xe59edbd98d3c2b91, 0xce0107e3e0d96750,
+    0x8fcc2b1e52e56adf, 0xd6b9137ba6fc3c04, 0x773e2f22ab650a03, 0x20e000219f62d184,
+    0x9db1d035e91b98b5, 0xf111b96f3660d1d0, 0xce115579cdddd265, 0x6c3e86b5891d3f42,
+    0x17b82cd7678d52f8, 0xc6ca237f2f4c30c1, 0x31665f59cc1898a4, 0xc4f01944d4fd0844,
+    0x47fae426ca7ab516, 0xed8eedde92312e09, 0xa6f210f751674ebd, 0xd804fb54c65043cb,
+    0x9ecf1a11ec79fe00, 0x89ac76e63b843b07, 0xc4acdd4f96dfd71e, 0x99b36d1e240e6d3b,
+    0x9cc2362a8f206cfa, 0xa03d908582590c9e, 0x08db58503ac2206a, 0x39ff227a932bfd29,
+    0x9293554f4484af93, 0x09ed4a1c7c2807fe, 0xedfe3933690930a1, 0x2f896cba7150ffb7,
+    0xa245ecbb8c30fcaf, 0x02c4f3aa638c9e67, 0xad91424e9ced828e, 0xc7b0738ad223bbbf,
+    0x5441cd7d5165136c, 0x92395033f6961550, 0x8a898bf8c9ce9ba9, 0x67be5b2a3aa4491c,
+    0xc8f3e8aa9518722e, 0xcc5ef1ba1444039b, 0x5b0f29de8a5a065b, 0xe43185fc0c9074a5,
+    0x515cdc463bae3b9b, 0x6e235efaaf54f9e9, 0x469db21e0921076a, 0x25ccd7e6f2c7737d,
+    0xb2a490ae6ffc0367, 0xb2b82edbabada19d, 0x758084c9778cdc6b, 0xee6b01f91f5e1169,
+    0x3d463d22858e7d99, 0x7b1b302b933bcaa1, 0x396ee8ba43aae375, 0xf1b2e516709cab27,
+    0x21fd5d0a326e501f, 0x3193a0bc7c6d6b5f, 0xd6d6e093697512d6, 0xaaeeea88608529c6,
+    0x57057f0a9771c02b, 0x067e04cdd6d66af9, 0x840cf64681bed59e, 0x09d57502e1315a53,
+    0x614d02d5ebfdc972, 0x20702e68464e0aaf, 0xd640a45578842867, 0x1df660a0953d10f5,
+    0x52dafe5c09cbdf0c, 0xf0defbe8b9e93116, 0x914b6e76e55cfc84, 0x843d0be564e89309,
+    0x0baeff1d63e7f8a5, 0xfce1725e5f117f5b, 0x9d430d89b390ba42, 0xc1979f0b8b1411b4,
+    0xe5eb390b43649da2, 0xbcd6a5c114652744, 0x423308bb7d348c1f, 0x6b408345576966a6,
+    0x2980d08980946a14, 0xeb8111eaad9c48aa, 0xd311f7f850e22bc6, 0x6bca402419814eb5,
+    0x94a98ca84791a2a9, 0x3e2f1d60661d8a2d, 0x40b0eaecbfc51aaa, 0xa2d407dd5b0d39b9,
+    0x94c1f15d54064055, 0xa0b55b9d159cceed, 0x6fb889506db0f4f9, 0xf1a44c7f6f30faa3,
+    0x571ee13cb67360b1, 0x54792bbc0c34c299, 0x6324faef1465597c, 0xecdfda2c0473bfd4,
+    0x48dd78b99de84d2b, 0x273c382a83426f93, 0x9740558698ba17dd, 0x0f30904a9497eeb3,
+    0x24399e87a2a4a104, 0x6098d4c61d8a5fb8, 0xeb20c7a14840d104, 0x363a9b7afa606570,
+    0xe130dc8fd913fc36, 0xc53ae7bf9a82beff, 0x02bc9916e71144b6, 0x88a3bc72439ecdd7,
+    0x6b72a9ec7bd5a17c, 0xd9b6ac9c20f60f75, 0x2329fbaa70b55ed0, 0xa37a2f9c14cc822c,
+    0x1f4d6052b40e3d14, 0xe958c49cf55864c5, 0x9d0070975b0baf0e, 0x4dda2ce36a888286,
+    0x2c396291f1d64b1c, 0x01f11f0a793a38cc, 0x4347ccfe10b42c33, 0x94eddbabd5374cc5,
+    0x27120110f281d575, 0xaea74b2b0efa317b, 0x2d44814eb933c75b, 0x3e1159f7b92f7e1a,
+    0x447ba0ff45f7e048, 0x731f70d8f2176a41, 0xd153bb0b388a6b56, 0x4cdb01348ec3bc40,
+    0x5103c0521c3ddb74, 0xe0e07ad090e0ce44, 0x72993c26ceb203ce, 0xb90221d517a716e7,
+    0x271ffc25da5dd333, 0x2470df069f1195df, 0x6405050d6511e211, 0x437619862377f35f,
+    0x669f67f8a62aa7cf, 0xeca66d5f73e5dde9, 0x18f0abee078f3dbd, 0x512898c339ae2103,
+    0xf3da2e6b4bd5fb64, 0x4fd47f5a0a54d498, 0x33a74b14d144a360, 0x531fbd0a2284afe3,
+    0x27ea9b4c322f64b4, 0xb6f59bfcdc4ed634, 0x1d49232f1d74b6fa, 0xd0db786cd468fe99,
+    0xa6a45b1868d717c2, 0x28194b463d304e36, 0xee61cbe139dad3cc, 0x81af7fa605e56bfb,
+    0x5be686b527dc0704, 0xcb00c91ce5cece89, 0x5ca36203336579ee, 0xd6b1c6a28108b487,
+    0x322e83643cc2adeb, 0xe7376dc2ce151689, 0xcf035a6c0a19bea6, 0xbdeeb8be7fc9655b,
+    0x1403690b59467f49, 0x2bcb7b37d0e69742, 0xfa9296ddf7e83ce7, 0x09568a37fe0d8df7,
+    0x8ea52096461cffa3, 0xb1afcd83385dc3d6, 0xc67a4e4c558de735, 0x1700d661750e0e9e,
+    0x047f93900bc75476, 0x47e94bad397258bd, 0x8b954395d37648c0, 0x3d0fa812bd042d38,
+    0xa8ac59ddeb19869f, 0x876d6f3667a25cfd, 0x6adedd4ee82553f5, 0x7b910879a6fda515,
+    0x7e9106cf690d7c40, 0xeb2569ee1bbce904, 0xfa8e37e2b6f44ea0, 0x3c75a9431099be11,
+    0xd548982bc709fae3, 0x81c4898e3ac2e8c9, 0x560d0a1e2928f2f6, 0xf0850348637faa64,
+    0x513d5737591ad853, 0xcd220c59310017ad, 0x4612826d55111048, 0x64a0d46cba53bd6d,
+    0x885ec4ca1aab865a, 0xff2ac475093261a7, 0x3e6a4f2b441fa04a, 0x71e646e3d8f8f1cf,
+    0x1af0b58078b27e1b, 0xe324b18d302dd612, 0x599b60b6a0515d85, 0x04d696b6a34e3ebb,
+    0xe0eafd495f4f84a3, 0x07ab7394dcea81ee, 0xcef4c2d473a654f2, 0x7f8b24eba7dfc3df,
+    0xdb87b9f612804c0b, 0xcf42044aec6b6f97, 0xcf33c8ffbff0ff47, 0x06995d2cdd62af20,
+    0x30f54740dcb07c90, 0xd4636ab3d62f67f7, 0xd2facf88761d6826, 0x770929c2d83b26f6,
+    0x7d46a00281ac829f, 0x125d1df235974411, 0x3ffefe371a779699, 0xef7d018635df8b1a,
+    0x2ca90d1e6b97e272, 0xd7cab6f929ac1e7a, 0xd78a8ccda52b995c, 0x95915e12fe14c79a,
+    0xb08a6a8984237d7f, 0x55dd3e625fbe8348, 0xf27a8c0ca6d9735b, 0x29788d4db777c95f,
+    0x1138e4081828b64e, 0xabd3119cd3dd6321, 0x4661730610e72c8d, 0xf08827b3a345899d,
+    0x974d5a3b3a685f15, 0x1575368ec2b831d2, 0x04d8d733bbfa5e6f, 0x3df59e675a8a2458,
+    0xda509c5aa3150994, 0x7636e617a41a6520, 0xf568ce11ba6b9858, 0x6b8839abb8f8c977,
+    0xe6270ee0e5ecb09b, 0x086fbbbf0052b679, 0xf7a4ddc553bc4f85, 0xb403d75659bada85,
+    0x4d895ee3c24ab84a, 0x2322323a4f69fe37, 0xd9a3138a4c7e17af, 0x391ad16c8c567be1,
+    0x8c9c5c0c974358b4, 0x61d549838bdc438b, 0x6e2176d4e7df9eca, 0x543815dbab6d43e5,
+    0x00682c52b43abb84, 0x81611249b41f0937, 0x2cdbcbf65bbf5437, 0xe726ceed1ffae227,
+    0x0dae6b2821a33827, 0x1fe8a47b675dfd68, 0x6d5651f6f3def08e, 0xff5f3e5a7e88784b,
+    0x5c3b1b5162c7e4bc, 0x07a03b9d955a1823, 0x4d195099927f0d81, 0xa767694190776946,
+    0x5743a28c1cffd65c, 0x74732d1714383fc3, 0x89d2558bce108322, 0xe961cacc43bba26e,
+    0x8e7c74a96a91ab93, 0x75ab6b2201aa16a8, 0xe929b44715255a0a, 0xf9e0117169947fa9,
+    0x9113fc683a197a3f, 0x79c5e1c50f26dcf4, 0x7e6839be6df4d4b6, 0x7fba8d5132e35711,
+    0x3e95950628a66196, 0xc1611a8d0c59a40f, 0x7798fc3972949896, 0x9aa6feee4552c17d,
+    0xe6760ec991e03304, 0x3c49756c3de28144, 0xab0fbc4733bedd68, 0x2a435170f917f6c0,
+    0xa21578bf2a22f971, 0xe2ee041dddaa853d, 0x0c8b03c5ed780c52, 0x90371d0cdd8b201d,
+    0x8de4809864b5bc08, 0xfbf65b2dc8cd982a, 0x694d8a75c134cb56, 0xc0e2b5ca359edc20,
+    0xb86d040d280c649b, 0xcb8d5c848b2b95eb, 0xc70ebb5aa34b7a54, 0x1da64b09a4341ce9,
+    0x7aca1d1d97aa0e52, 0xfc1ca6879738f657, 0xc831d135991541ad, 0x9c9b3120f36b046a,
+    0xd90ec7e7338f6638, 0x078d289be602b9bd, 0x47168cc08a38acce, 0xdf34b6eb39d27af3,
+    0x12dde41fe0b0f145, 0xb4ca251a2e3da5a7, 0x5437e269dc407167, 0xee6963d78fac9709,
+    0xdb42545a285b1207, 0x766bfe65501a1d08, 0x024bb896acaad9d4, 0x0e3202f70f11c684,
+    0xbd8bda73ae72e61b, 0xe7cde412b8c73dfd, 0x686f446c8b3549d8, 0xd589136e5471514e,
+    0x746cf51793eb4d73, 0xcf20c3e5bdb0a4a4, 0x0803cd3217a2e8d7, 0x872e74fa951613f1,
+    0x0b6679e1faaf3fa7, 0xf24f0af9717ca5ae, 0x02de2473517751b0, 0xdeb0dcc97139ecd7,
+    0x4708c6d867d466c1, 0xe3f24226f1e58231, 0x8988d25ec85b5575, 0x591b4d8ddf0087b6,
+    0x64356555f2a3da7e, 0x9e82b2146ef86f2d, 0x82db1a52fe905ae9, 0xd07170ed1f544fd6,
+    0xfd9a88641294efe7, 0xcccd2e9934470e82, 0xee3fc53c6949443f, 0xeb5974377fb40ead,
+    0x37f6ceecd8be8df6, 0x7118edc6c6518400, 0x3afb8b1902eadbeb, 0x4c25bca9c554d2e7,
+    0xe0d28728b7c949c4, 0xd07053a060cd0acd, 0x7df5942cf7d56653, 0xb5015f009689f7f3,
+    0xdf043e5cd5890549, 0x4078ce9dc8e7fe41, 0xba665948b1defbd9, 0x25c222d1223f1e59,
+    0x0221d3efd7a9565c, 0xc45775609ed745bf, 0x3f2609a1512430c9, 0x908428b6e3d62e4c,
+    0x586ea0b35907d79a, 0xbf22c7b99e76fb6d, 0x26da31cd3a7cb1c5, 0xc84fb6789e4c4d45,
+    0xf639485bf8ca82c4, 0x47fd652e57e92fd6, 0x765875db249ae101, 0x9c61552f058c7aba,
+    0x53867342c0859760, 0xb9337ddbb66cde34, 0xbadbcb0f1d2c4eea, 0x144ead0b484c660a,
+    0x23b8142a3d9a9793, 0xe641b5ce8b70f7dd, 0xb10e53f7d7867b1c, 0x1cb0a98ea94e826a,
+    0xe861caa1b974db0e, 0xaff64d2fe5611d58, 0x01b52a825d94069d, 0x1d062cc647de79ed,
+    0x624a05e575da5fe6, 0xbe9a289abc9e93a7, 0xb62e5f5bd195255b, 0x1fc0a5f572e92380,
+    0xd6619398d6013dca, 0xa1f2bc2eeecbdfa5, 0xab5501980b9f6d12, 0xab1c74a752ba5b3d,
+    0xd10d82799fea8b9f, 0x1aa5e7b45ec388ba, 0xf82d8595bfbc6104, 0x4c7b8ba226c20838,
+    0x1cb04b0216f2c841, 0xc8182781830997e1, 0x99518a1dec0c5d65, 0x561fef3f49e1aef0,
+    0x4db20a182660f27a, 0x80f6ebd8c3f11bd5, 0xfddafeb1f643c2a7, 0x61c443a61e450959,
+    0x47d971eff19d551f, 0x9179d8e9d4bb3ac1, 0x267d0fded9d56d20, 0x25a11aa1680e3a2c,
+    0x82979c2d18a30559, 0xbce0e8bc8742b770, 0x184529c7b2be670f, 0xe511a2a876271f67,
+    0x2c33b881a08357f0, 0xc79a8588628e73d0, 0x4be0ea9c2298f447, 0xb08e1d94241b4842,
+    0x948f96165039ef6b, 0x7c1428a2400cb082, 0xf677049097874907, 0xd9ea129462363937,
+    0xcbd7d7985d17f901, 0x0c2b134286489fa3, 0x322d515bec3f562a, 0xc5160a1c8c8e7e84,
+    0xaa0fb9f9a58a6cc7, 0x42b54c8e793b53a8, 0x98da6cf8abd74705, 0xd7c1dc1095985eab,
+    0x07c467de66c94a6b, 0x929d6cec15703add, 0xf8eac2875cb56eae, 0x2cd40dcc8e9816e6,
+    0x1b81989c0b309145, 0xd6ba9067b8d4f635, 0xa91458c8a6679094, 0x43e45d4b524fc2c1,
+    0xf3b206205d945194, 0x513982934de11040, 0x937e00c5ea1014b9, 0x1964ed579c91d5e7,
+    0x7b34cb31e3436512, 0x44c7891862e2fa0f, 0xade1fbd05ee3082c, 0x7eac2677ea7055ff,
+    0xd6cfff090b6c30c4, 0xdf9d94e1af0a99d9, 0x788dd67caed26816, 0xc40ad9703ecbd334,
+    0xf2caa51553abbc6e, 0x247c23f226f6aed5, 0x2fd54cb5e892534b, 0x0d36eb2efdb850b6,
+    0xffcc1cee2da3a991, 0xa05c9053df24f9ed, 0x4cc69386eec9a334, 0xe7a26e0648d80bad,
+    0x5ccce8653fdef3c7, 0xccc6ecd81ef854df, 0x72047919ceb42177, 0x9e5bfc89db1073fd,
+    0x406b9225b1641dd0, 0xcf409c622845326a, 0x4ef64b0b801eed43, 0x5b2df5ce167f186f,
+    0x8242c4b107788cde, 0x553eb64761740445, 0xc5fe42ceb8708d20, 0xa4580fa6b8c75508,
+    0xe804fc732333f928, 0x452108eabd4544b6, 0x5a6c6d74444b8aaf, 0xce9bd1b0c0e1cf56,
+    0xc80c44905d3c0a18, 0xb1b7c718dd111881, 0xe12c798fec8e84de, 0xa5e430a213407b02,
+    0x402b22f3127fc6bd, 0x1f20cb3b8dde674b, 0x9c6cd993eb72926b, 0x1c92cf8f3ef01283,
+    0x9d5c7c4bed42211d, 0xe80f0cde6c2c1a12, 0x6185c0af0bf704f9, 0xdc34bd69e5d8981b,
+    0x83bb13fcc84dac7a, 0x29f7ca70f435db35, 0x172d96bcb323b18c, 0x0a0fc4bf3f65d5b2,
+    0xde953929582ec2da, 0x83ee199144fc6d38, 0xfce48448fe8f5fb4, 0xbad605639757616c,
+    0xd39d5e622d2cd9c5, 0x43122864535f3a20, 0x2d0de1e3fe86a181, 0xd69d38b4ed0224a3,
+    0x4bff87d42483485d, 0x62880ce2b668a735, 0x0951976bb1303224, 0xf9ce2e8928152938,
+    0xa8c0569f0f6cd96f, 0xae63c8092186270f, 0x5751be857df46daa, 0xdbc68206d9d74a87,
+    0x73e786f3938ea8d1, 0xd9334a9f296b7730, 0x835ee60593dc3000, 0xea3e3dfecb40a990,
+    0xb744a888484664cc, 0xf274d6c1d716c8a5, 0x1daa8ec55ea25af8, 0x2c5bae08f3645691,
+    0x181de715fddf8cfe, 0xc699192a6fca3da3, 0x4c500ba3e7e9ee8e, 0x3d9a0191eb4b609c,
+    0x1699f7cd358aeaa8, 0x375814e7b65449b6, 0x057d8eb930acd636, 0x07b3a3316f930469,
+    0x419e2cbd3111b517, 0xbd1d9897bf8fb1b9, 0x3447cb76a725cf9f, 0xb03adf75eb53b551,
+    0x4d5a1f354f7255fe, 0x47d3c81a022df6af, 0x07beae097deafd4f, 0x47e5d55a49f4d8a1,
+    0x1d8c29fc6d0029b0, 0xee8b9ec89ec86768, 0x477937f2c49ae525, 0xc5c8865fc69c0b16,
+    0x8fb59984e600355d, 0xe7df0d479756210d, 0x7d2a40306b4f80c9, 0x71276930738f247f,
+    0xf12ca1ba04cee45f, 0x440a3f027f342f99, 0x9579b9dae24a9f72, 0xd6198c519a5c9d80,
+    0xe452ab632b76116a, 0x54a109fc514de4e7, 0x0113593a206f9d28, 0x4eab179e9c1bc0b4,
+    0xe349d08fa6b48f93, 0x699de36b5730f8b4, 0x6a03af9c28c06153, 0x66cf08a797e08da6,
+    0x6c108420de5b025d, 0x3f467b26cb30cdf5, 0xc0f061f54fc7d715, 0xff11d463f95ce049,
+    0x0efde9d53453d78f, 0x525b95444d2f96ac, 0x998279b46dfe97e7, 0x8edd8f0c05b89779,
+    0x54315708b68d14a5, 0x06d661e50f61d247, 0xa80e42f7be357ba6, 0x449de281824e3466,
+    0xaf8f7db1241283b5, 0x36d9059ca939f12d, 0x75030f6762a44bb4, 0x6addc9c59f764af5,
+    0x605f9165d95c7f78, 0x4f99355b7383b550, 0x4916f73394b305d9, 0xf71d50b2b9caa705,
+    0xc9699bdc18d7c199, 0xae06813575b99c45, 0x09028dcc92090c32, 0xcf5a00b0bf30d57d,
+    0x921e0a71c339e4b7, 0x354f50432c844cd5, 0x519024e1c8c85e64, 0x4b2a414a2fc757a7,
+    0x16571b14e23d4e1b, 0xa3e682b99ffc42f4, 0x4926519df6cd0f3a, 0xf6bc015b11e08d91,
+    0xcfcc24ff0e899cd3, 0x204aa593ce5b9dac, 0xefd9b9ac2c64bd7d, 0x915639d390b2714f,
+    0x5fbe892484b1739d, 0xecf1caae75dbd97a, 0xfb08a68c57aea0da, 0x4a603b3bc820332f,
+    0x442635c31980fca6, 0x287b242122f5df02, 0x47e906babeb779fb, 0xfabff6c601ff7805,
+    0x73f09f0aa2d0ac98, 0x98650e13f95122e8, 0xb68ebea870d3a124, 0x2f3b1f08a7d91923,
+    0xf4ce9a8afe24ea06, 0x1b12cb82263354bf, 0xc39f3dfb0cbd21b4, 0xbb7c1bfcff352d43,
+    0x78778781ec9b774c, 0x8d51331d1bb55830, 0xe31084ac7dbd9b6f, 0x8974fffd6af4f32f,
+    0xf6b04d2d7bc69b8d, 0xd6bcadf1dbd90d09, 0xc635ad2241d6c23b, 0xcf7e4f452645345c,
+    0x823c56e398cb397e, 0xefa1adee6d34985f, 0xd963dee37cc0448d, 0xd6db4bd324777597,
+    0x6c1f43bb97d2bd3e, 0x6d3a0d01c4efc84e, 0xb6b3aedaad6cce50, 0x4d561a795ce83c53,
+    0xf5ea1f5705a3c4d6, 0xb36dcdd5b3f7d029, 0xc1af7c1cf14be1c8, 0xc1526d8e1c76471d,
+    0x3f3bf1e315f31e6d, 0xf6d8f2cf9cc703d1, 0xc12cf0211c864dcc, 0xd093f0c2b63960f5,
+    0xd4dc0b7413a6bcea, 0x624baf57d413c397, 0x494fd5a465d08357, 0x978f599f89801010,
+    0x24064bccd22b4ddc, 0x9a178a18e1a45cef, 0x609b19b3b7ffb52c, 0xd7bcdadc8e59f906,
+    0xfa6702d8db62562e, 0x48f067b224408cea, 0x76adc7a13f4182f9, 0x4d8de9e3bc3b8542,
+    0x9349d33f7058c748, 0x391acd2a29c38db4, 0x5e0e67b365034a12, 0xa9dd714a4993f1e6,
+    0xfa4aa871c120258c, 0x1e28f82c626fdd7a, 0x8711a2e488da4be9, 0xc297b1c98697df62,
+    0x6cac2e974dc4df8d, 0x2d0635ea5c9f591b, 0x247827fe182886ab, 0x7aa877c64934f78a,
+    0x4d28dded74b6db91, 0xb75984df8b93e385, 0xb91376ef962b7068, 0x55cc09238bd11d2d,
+    0x4d6eb1ec3ef68543, 0x63aa1b821832b833, 0x0783f3a2749d59ba, 0x3fc01ca39ada3bdb,
+    0x83005192ef7a54a5, 0x79b0a218a4399291, 0xbc87ab8502c02f45, 0xd2238f6ef30a14f4,
+    0x242710ff32cf1081, 0x6bfcfe82714ce1b6, 0x6bad33bf42ca14b1, 0xd4d2ba8e8f3e29b9,
+    0x9b4f577a0bc81674, 0x85916e7a96f16ac7, 0x87bf7e2cd8f46287, 0x3f657779fb9d35e8,
+    0x214ec5139ef6fb0a, 0x9053e9444176a90f, 0x2dc0bd8afbc1581a, 0x1d2e49611ea0786a,
+    0x64dab26207dece39, 0xe6e7cbd69ac09c45, 0xcb09f052afea5e86, 0xfdb2571b2c10cfaf,
+    0xd6f9f56b9ca68173, 0x5ba298c6730c4912, 0x00864ed979fa3b09, 0x425535dfdffb5cd9,
+    0x4e3382127b5431e2, 0x790553e44b6aa1e4, 0xa570754fd872c985, 0x3acfc649da4786b2,
+    0x78c804392360b8b4, 0x506bd9469f5fe832, 0x81dd3125d549e5ef, 0x1c0415af7108452c,
+    0x39da6083da91ccb1, 0xb0084a74210d912e, 0x423361e9daaad64e, 0xe7bd5418625d6a8f,
+    0x3b7a86c827e88eec, 0xbc4f49650722d74c, 0x65fa3a31d81813d0, 0x93f655235fc4924d,
+    0x81ad4f38bd169469, 0x9d622ab0082f7d93, 0x025a8d091375a199, 0x47d4018e637b7f1f,
+    0x0720be8458fbea84, 0x94ba531e3102262a, 0x825a88ca4abf6969, 0x36cb1a25632c30c9,
+    0x2c6fecce81381100, 0xe5beaa1220ace443, 0x214217cd68e64cea, 0x1e77a588e20a94ba,
+    0xbe17d826811b7fa9, 0x3d57b5604806aece, 0x43187c1273747058, 0xfa0b1edcdfb8fd53,
+    0x634975b34c3a67cc, 0xb5d7fbd0e42c4721, 0x73e5ae65af23ae42, 0xa86c73c230f90b0d,
+    0x8dff8e0c38731f05, 0xdd1342ddcd5865f8, 0x9f57acd47cfabe3e, 0x145b369927eec18b,
+    0x4a7f9034d43983df, 0xeece885e1e29ddd6, 0x8774318fc3ed7a82, 0xd72708eb5ed6d268,
+    0x17c71ca3ed20a586, 0xe6333f98059bffb7, 0x801fd52c60531dc6, 0x74793d255a5107ca,
+    0x63816337a97c277e, 0x21c0915f589f7ae5, 0xde1c0d6ce21055e5, 0x7ce341fff78268e5,
+    0x317e0b0694f4eb2c, 0x326cadec4c788d39, 0x386f05f0eb079b6c, 0x532a66a41a4850c1,
+    0x83de5afd0fb1fff0, 0xc1283b71f9210ea5, 0x16cf601fa5c31f03, 0x287e4ab55a4ddc69,
+    0x309d44d8cb7fd0d5, 0x13d937897ddea366, 0x60b2f53ca606c184, 0x197da7738b3b6734,
+    0xa430dbb8b69fcba9, 0x25cd65d28c716c13, 0x148679b731817920, 0x78ba7212393edc0d,
+    0xc2770e23bea53f6b, 0xd7ab6f0a8e2109de, 0xb8cdd92a882faf46, 0x1271a7b8b3376dc2,
+    0x93e15e5fc2dd08e0, 0x78e7102b8eaafa47, 0x27a1ab21ba99b1c9, 0xe4802dce3c59aa71,
+    0x218dfea265c185a6, 0x971da58a95d07141, 0xcae38886bf2a0170, 0xc6b2326f180955f2,
+    0x4f9d43b26576e742, 0x41b5cf8a578c745c, 0x0f5cc638f19c49f0, 0xe2ee9d80ff68aae8,
+    0x9cb6aef98bb0bc5d, 0xb39627e0dca6842c, 0xc89478c466ba74b1, 0x2380e46145466577,
+    0xf619d0809bd2f739, 0xb9493f37d8f48b75, 0x8b7de9db338bcf22, 0x72c900b25c67a523,
+    0x6a9a30d77af18b68, 0x6b876a32526f587c, 0xbc1e9335ad7ce1fc, 0x18e8b2bf6020fd1b,
+    0xc095460243bc64e9, 0xd5a65418710ba6ea, 0x95eae28a6d9a8b83, 0xe11ddc2e41947eb5,
+    0xfa9976724d4981dd, 0xf0c95988750317ad, 0xe33035e83ae6f23d, 0x0a06d8f11601b5fb,
+    0xbb2843ce081340d3, 0xef6c8c4215c1c6a6, 0xc819439bdf312242, 0x28438164298be3c3,
+    0xa66835d7ed17f21a, 0x3d09aa0f7e49b240, 0x59b4e336498821cb, 0x063e6caceddd8644,
+    0xaef6d4938f403b0d, 0x2152398dfa89bc00, 0x46f8bcefc8b0a6b0, 0x0e1b180a32a5b89f,
+    0x68b6b633c51b5171, 0x1a92ee585b4db763, 0x4fbdf7cd673179b3, 0x505ab01e8326f011,
+    0xa23c4d83aa42c25b, 0x8a3085303262b090, 0x75c2525705470fdd, 0x47cb786bb0d7236a,
+    0x97e84c15f9a0ba8f, 0xdbb1dbcb412a8ec0, 0x0aa643b5033c0526, 0x09e73a4dd78739b0,
+    0x546975b26d5860ab, 0x45d213ccaff6a7e7, 0x56eb8a6b653bf887, 0x09b1d1bce392bc01,
+    0x8ba080b6e659c199, 0xa2da150f965bb9f5, 0xcd45bcb732f9e2c8, 0xeaac2cf827aae6ed,
+    0xde89422f3f2110e2, 0x716fec2f867898dc, 0x515625fe30826032, 0x9830d76d342581b0,
+    0x0668b1548d0cc6ac, 0xffd7d1c76ff5a50d, 0xe32783df1ddf8405, 0xee8e298533d27ede,
+    0xebd9c3d42cd1ba31, 0x5b31528f5f38dc41, 0xf5c4bf8f7d48f14c, 0x8b3ae2bbd2824b07,
+    0x0727e8e1f5141c32, 0x459f0f4191cf1fd9, 0xed3c26ce939ed6c2, 0xd412982a9d5f5e6d,
+    0xaa3ff9eef96ba176, 0x3942b88a57bf7958, 0xdf778c3f3cf5e086, 0xb7972981372ebd72,
+    0x1f2ca9425094b8c4, 0xc734c7b562a30b32, 0x9d640d8ab97401d9, 0x679144628dc58d00,
+    0x11188345729033d7, 0x7aa2c27f23f45c65, 0xc00bb5e4a36e76e5, 0x44566cd33f6e8c13,
+    0x60d44e425f5076ed, 0x9d12cfe5e402472c, 0xe2718d1bde72d653, 0x58c2eb2f0ad5915d,
+    0xac92908c0e8ce880, 0x473dc4d65bd84db6, 0xa326310a209b0c5a, 0xc225bbdcef88fbd3,
+    0xc7d208c4f10caa1f, 0xee15f5f5ec6e0c17, 0x1c9ed2af735a07dc, 0x42085bb881094565,
+    0x789b50f437bc0966, 0x2cac273b39a2c523, 0x7920b0f019be2ae0, 0x29a260a491805020,
+    0x2d9eb1203906e0be, 0x028697ac8176fb6b, 0xeada7c572e3af1b9, 0x8abdebbea0e700e1,
+    0xf99cc86449da9d4d, 0xdd38bee94d73db90, 0x47ffc9bd57f4def4, 0x298cebdaf38365a1,
+    0xba1ab3912a2c0622, 0x12a850028a4c8a8e, 0x478aa445b8385cf9, 0xd5f08edd6e475f61,
+    0xc159a521cb64c315, 0x3b12a956f0dfe5f6, 0x5a0f826c0900d6b3, 0x9c05026de659bb4e,
+    0x96eec7c53cb31e74, 0x0b319d213d21584d, 0xbea5c6bc573d989c, 0xa872c9e55c60d0e2,
+    0x1410f31d313d9f10, 0x789143541af291ce, 0x16890bdb2fd04a92, 0x867d828f9de5ee72,
+    0x67f41ae0aca740ee, 0x8b8ef58265189ad1, 0x6654ee1713dd0198, 0x958d75d56b34d602,
+    0x252015a0c34f3ca1, 0x8d7e1a6fff67c77f, 0x52037404fc5f8427, 0xff490550415a7ad1,
+    0xb4ce0b05d31d3acb, 0xb323d92d82fe38d7, 0x385ec1733f48d13c, 0xabed89f09f3b1aef,
+    0x065e92e5a9917707, 0x8d589c6bcfc4e3f0, 0x8a3233e387bc24ef, 0xfd607ad6cc2e1c12,
+    0x8cb87f89517b3506, 0x213eab7e4d6eb312, 0xff5fb10b18465ecd, 0x3a03bba8790c77d9,
+    0x161f3e472a87001c, 0x8aa8123498f3f3a6, 0x5c59de3871f3be0b, 0x2b0e4e4f92d9a3db,
+    0xfe83bdf54a4d7ab9, 0x53375261f9bdabbb, 0x37dc5c0beb217135, 0x05077cfcb2881120,
+    0xda5107fc8dffb4f3, 0xddab72dd4f033c6d, 0xbbf61610477eb66e, 0xab3235c23fbeb16c,
+    0xa513cdd92bc140f8, 0xdb3db999d912a287, 0x54887e1fa5364407, 0xc41a3de733e280cf,
+    0xd2ba7a589e690a6d, 0x8ade7f86f51726a6, 0xf850eb2914387a1a, 0x833b489b72a02df2,
+    0xa084ea4719dadb90, 0x75ed29dfcc628aad, 0xdb5eb21abc25cc0e, 0xb1f5bf40c65505d1,
+    0x59ee827ac882990f, 0x0fc685b6004e17ff, 0x5bf2056f13751e24, 0xf3b2896718cc05f9,
+    0x984c7c7bcaf1de3e, 0x8bd4da854d81c5be, 0x47b1a8aae75d2b5c, 0xac8b566658a5ea88,
+    0x393834fa6ac361e3, 0x1eed69f88d583583, 0xe434688d16024d7a, 0x2c572b52732b9544,
+    0x32517f0051bcfa69, 0x69f2df9df5ddeed8, 0xddf9610f6d48a179, 0x02dd3bffe9f23515,
+    0xd54f5ceca10d0c2b, 0x0ce2346dc64c616c, 0x35d452e7c01cfa19, 0xf2cfcb5576670b3b,
+    0x007b6f05cdc216f8, 0x7dd110da4c726670, 0xe0fedf6df16b8540, 0xa48819ff8e91e67f,
+    0xcbcee38380a0df6d, 0x503947ab6bd515d6, 0x6d023e739f8914b9, 0xe168a625eaa08d0f,
+    0xbf9ce3210259e684, 0x28beb050ceb17cae, 0x599b793d5dc741cf, 0x72b4e5b400de80e6,
+    0x39c9ac8a4472578c, 0x16e061af77efd328, 0x1943962cd7ea83ea, 0xd42be3eeae5e12c5,
+    0x70b4eb0ebd60fb93, 0x3ebcddcde1479bd8, 0x77375449eccaab1c, 0x76f24df6b44c4a40,
+    0x078a6176eeb64f24, 0xc9f6440ef54233a2, 0x1951b85456e7bf43, 0xe10598e79b9bd4ce,
+    0xa7c2c2d7ec95416f, 0xd7e5dc9d88ec61f3, 0x30f24e0edc7735d2, 0x7b4f2f73a83fd2f3,
+    0x1ac2d5d48d8f2d44, 0x525f6b2626a3905d, 0x8d0a69b5d901d62d, 0xbc29d90ca04d3a4f,
+    0xb184424cfee2a5a9, 0x476c1bc24a018e9a, 0x03292dedf4cf2f6c, 0x4d204514a0be001e,
+    0x16d800d34b14a044, 0x3313020f97a9a7c2, 0xc9e20ba0a70745c2, 0xccb3cf597ddefe3a,
+    0x9ceffbe97e67f250, 0x33315dfb48949934, 0xa1d6cff2ab31f261, 0x649d688ccab1775c,
+    0x55bd34dd95a9ac4f, 0xd58a58f283d2a9d5, 0xf74d1d1b6a58e463, 0x7d9574b719b21249,
+    0x930daa0668f11e02, 0xf884a90f7115d193, 0xb8e01f9f44f1ae10, 0x1c7046b1fd5bcf79,
+    0x284b13b8aa8ad2b9, 0x962c065d07813253, 0x90d4d0fc9c53fb83, 0x19c6e5e34b9274f5,
+    0x6761bb89e1b0cff8, 0x06d1606be3798dff, 0xf29ecf123592e787, 0x633fee788345494e,
+    0xc9383738c975df53, 0x655f0984c1ccd516, 0x5bfbbd1186c2f301, 0x024e36d7a4d2e47b,
+    0x1e72e4c51cd75355, 0x3823f69f79b153a7, 0xcbd2c90bedbe87a3, 0xce8234ee4aa67bff,
+    0x5ee96d18ecee1f4f, 0x492412a63e3965fc, 0x20f039fc5ab79298, 0x806f2f348d8ff481,
+    0x3bb2833cb0cdceb0, 0x51b9efaa10d789aa, 0x0b34f3316eda39b3, 0x9303705d9372b515,
+    0x91011471c4e23d27, 0x3c621722e5e0f9cb, 0x2c1e8392d44f397d, 0x09e0257eb8a998ef,
+    0xe46cbcafb5047886, 0x3c7fdee68d756da9, 0xafcd2bfd22cd2caa, 0x0e52f2dafa282e4c,
+    0xecc37b217f33ebbd, 0xd71fa8f616ded0e6, 0x89967d1280e1a051, 0x0b700dc5569a2541,
+    0x780cda7caa19ec5a, 0xf9741411742b5398, 0xf7b28c4618bd9191, 0x043d0887bb1ec956,
+    0x269c1fea3d3463ce, 0x1291e424619011d1, 0xca6908811ea60b6c, 0x32855a81ff69cc2d,
+    0xff6cb30c12f3b0e9, 0x06b0255acb57a7ed, 0x8021347d685b87f5, 0xf0673c1b5bda90e0,
+    0x598cc562c37dad29, 0x20f12545b5670cde, 0xe42fd09356837717, 0xea4c57a5135e94db,
+    0x894acbd60391c8cb, 0x97d1c738c7e3b35c, 0x64b73732d6bd6fe3, 0x943d6eb07b10268b,
+    0xa88ee12dbec77585, 0x3ee50f13e1f73545, 0x6901531a69182a39, 0x431d9938e2efae6b,
+    0x8c009359dc31e092, 0x85d70ddd40251c20, 0x3845706aabb00562, 0x23f6218ad66c33a6,
+    0xef9c741c455d87e8, 0x95091e630fadc607, 0x9c4a671d45ac8709, 0x6961ed031669caa3,
+    0x81129f2713ed1ed0, 0xdfcc966e95be6209, 0xd92b57ebcec5a1e7, 0xbfcaf649c67d6889,
+    0xd04ab1117fbe4082, 0xcdc2fa7bcd3e186b, 0xe2f693d15587925a, 0x9d34cbd6727fbfa5,
+    0x414ea6d1daaae49d, 0x2ab89cd5d1d5c4c1, 0x4e24bd593a325d8a, 0xa95d4221199a1277,
+    0x331febf3c517b5e8, 0xbceaa9615f92470f, 0xd0437bbf399668ca, 0xfc46559e33c901d5,
+    0xe45d0d946f0ea080, 0xc8da01758ed490f8, 0xb5a487a434d71904, 0xdc5d8c62b90a1b98,
+    0x8f73f86c16a655b8, 0x73f0876d7f67dd2a, 0x3abbe1931824d513, 0x48d25325a7e5598d,
+    0x040ee58df724dd9b, 0x459210ac7479949d, 0xc80783ef553cde90, 0xa5a374ff06ec3e71,
+    0xaca38fcb8c3dde6a, 0x2282d84b01a54310, 0x9c107640046ff2a4, 0x4000dbd953f49fe1,
+    0x28c32ede35823d18, 0x8a33b5f5864b5dfd, 0x3fe8adf2ec343c31, 0x8c61e1de487a7e31,
+    0x4db8b36636aa3a97, 0x0da18e52e13e93a9, 0x6babc0025ffb34d9, 0xe854653b9a696014,
+    0x8d6bacfa2542d42c, 0xa5f3d1f44c97dc7c, 0x43bca1609d95396f, 0x7f14c69c7965a024,
+    0x8b9922a965b1b217, 0xcdeb5e5a3d815376, 0x29aa08b1a55cabbb, 0xa6b46c74795d24a3,
+    0x8b82897c5ec52671, 0x7057d49549e3b57d, 0x4d41f59b07bc9e33, 0x0e1555def558c52c,
+    0x1fb4d11cf72fac3e, 0x36f609ece5919849, 0xbbbd121b472c3d45, 0xb6373c25b9f6a229,
+    0x873cd182b296e3c2, 0x37661767e54142ef, 0x9fecbf49920958ac, 0xf09e1b80d701add9,
+    0x65be68df7d1e5aa3, 0xaf9a8d72789e3695, 0x69cdc7ed49fe5dcb, 0xc3eeaa04d6ece6c5,
+    0xee2a4f533dd20547, 0x2105d61945f03953, 0x7cfa864736fede29, 0x991a3490c2512398,
+    0x1cc5c84b114a2e33, 0x25308a8d6ac5da5b, 0x54df4fc77cf672a0, 0x905939f7f4d9a139,
+    0x67fe3c55b2e214fa, 0xe9a8ef8ff50b26ee, 0x446e490a93bca38a, 0xf7738617115b8a23,
+    0x00546f20f44bb647, 0xc2ae068a7e986340, 0xd08ffde41bd28f68, 0x57015d490e872b57,
+    0x43c5118151dc1743, 0x9788216cb1154509, 0x84722440a4c89c0c, 0x0c6466aa7b46f1ce,
+    0x7869fae10fdbd3ed, 0x9290ef6c5ac45d90, 0x6c5b17364f19cc89, 0x6e83a8e548207ee8,
+    0x2c7ab8b6e5ae4b4e, 0xd55101f34cae0df5, 0x8f55b960532e61bd, 0xc9f8decb3fd5e602,
+    0x79f4bc1e4430e0c3, 0x5b8d2fbda485d92d, 0x16f5df97a5aec8b6, 0xd56b14662c1c559b,
+    0xc4b88666714d48b0, 0x17091e392be843ab, 0x07a82af17911b7de, 0xf63561cdc4b79e3e,
+    0x7bb147d435d02270, 0x57175195431f18c1, 0xa8cea2b90da9e930, 0xd2c2bcc1934cfb58,
+    0x1dd4d4e0d0ee3f74, 0x061deac6ae09c11b, 0x0e8c2ab532adaa3b, 0xf1a3be15edc27175,
+    0x29108c36367c9619, 0xe0c71015596ebe82, 0xf9625a84303ded1b, 0xcc4456f00d867267,
+    0x259b76d0b3942b7d, 0x4f560a1dae957a6b, 0x899bbb532a2b0761, 0x24afc9a7968334c0,
+    0xcb8972da4b9a1c21, 0xf03f50431ae5ba6f, 0x6d3067afb52f29f5, 0x68fd37b10e4513ef,
+    0x0064e9c2fa17ac57, 0x1f0fbf1bd66e616d, 0x5b11759d5ff55bc0, 0xcae82f7b3c548f2d,
+    0x1ef9c3993c82063f, 0x011cc537da58a2ee, 0x5c7f40ed099bfed4, 0xef5a6e474acc37ac,
+    0x2a346c6c5ad3eb52, 0xc96200bb2ff74e56, 0x6ff1ccbd04858ffc, 0x65a5f66f2f32402d,
+    0xba42f8a00f7b0016, 0xfe3afa949b12fc75, 0x8cb1fe973d9932e4, 0xd1d6dccbd5a61a7d,
+    0x2fd051636e7632d8, 0x03eca1e0376e0d47, 0x380ead6daf03cfc8, 0xd7cb9516d962418a,
+    0x575a88f75a70001d, 0xa48bc64314efe1c8, 0xa3467cfba6a52817, 0xa4a7f866e93a65e7,
+    0x438fae9984a20a0f, 0xa221ca88f8a163f1, 0x3fc0d54b8d030c2f, 0xff60dd78204cc80b,
+    0x3b3ca4d966b16a04, 0x14c66ce220bb3c3d, 0xebda6c34092cc877, 0x06db023c681300f2,
+    0x6eeb246af01125f3, 0x87d2edeb831e90a2, 0xe8141c40006d64c0, 0x49a30652f30404a0,
+    0xdc5597dea7af33e4, 0x86bb5785f3460dab, 0xc6f12be424d5dc34, 0x7024ea03de7ae0fd,
+    0xdf425dcd3df73def, 0x86c51ae6d2cc5b8a, 0xd7765fa243a9eee7, 0x35f52bc63ec520a9,
+    0x64d4b9933b4938a5, 0x39561ca9450d5907, 0x59b0f2b017408e53, 0x74e3fef73336f9ac,
+    0x0a1f816f0d61f7fa, 0x950775aa7a3daa39, 0xaeb5bbd0eb2e048c, 0x24f646199ce85808,
+    0x3a5cdd096ed018b7, 0x0f970ae6eace5f0a, 0x3f8ffd9b01fed9dd, 0x236d8165a6c74e2e,
+    0xf32aecff24f257a0, 0xc6ec6b24221675f7, 0xec411ea5bca80371, 0xce3f85ace2902cac,
+    0xd08315329ae28297, 0x5ea82467e811762e, 0xbd5793ee3e49272c, 0x9b0e45e174c625bf,
+    0xf24ce30d5891dcbb, 0xe541734cd095ddc1, 0x0fc2cd8b546abbd2, 0x030db1e606a9d408,
+    0xbbb2b2ed0745be65, 0xc308c2a8d2a50193, 0x28fe274a03a6777e, 0x4bef12b1068cd1ad,
+    0xff4e7756356e047d, 0x2e87c6bed36cd893, 0x9f57d73fee25acef, 0xd9c66d051a31842b,
+    0x460d6fa8e0d09470, 0x2518b4286d198205, 0xb5152bef27aff83b, 0x825f6b2174ad090f,
+    0x7356a2f402cf34a1, 0x4b81280a94500541, 0x3744bab02e40b62d, 0xa42473bc11bf920c,
+    0xe4dcf89775f82647, 0xb8944d785467292b, 0x271b007029c156ef, 0xb2c20cbaf0de9a62,
+    0xd192e6ad5d4c5eee, 0x41041c2ced80c795, 0x1bb803e0ca1419c3, 0x0a85c01145792f9c,
+    0xbdb201884b57415c, 0x63f1320938e1ebaa, 0x9327b70a97c33219, 0x0867c07e7889da43,
+    0xc77b01d0fc33512a, 0xa369aaa325d67feb, 0xc2dc3d43d198a8a0, 0x55b7acc0f2b7ae78,
+    0x89f3a7c829abe250, 0x68d8500a32f0eeef, 0x2b3ff9aa0236fc4c, 0x188444febc6014e8,
+    0x4a1c04f1c9b0e85f, 0x4fdc3404fe9495f4, 0xf051395867c3f681, 0x486e155114b3fe98,
+    0x8628ececb83babd4, 0x9b11816ebdf0a6d1, 0x661789b437973114, 0x6cc5f4d3ae1f87c8,
+    0xeaa55d3f8b25ca74, 0x1bf2c7540aba103a, 0x2eb4389670e562d2, 0x4ec0f6a14fc6a0fc,
+    0x6cc1622217ac9589, 0x8ab64692cb665419, 0x9a500b60eed6c4fd, 0x56a89cea34669c4a,
+    0xcda002e962c80fd4, 0x843eb588be7f6bde, 0x5559616ed852a2cb, 0xb2b71da5e9b3cba2,
+    0x0deaa4504344b174, 0xf58174ec41b2d64d, 0x87c125134400c6d2, 0xfc8fbba875929c43,
+    0x64903624818b7f19, 0x18639373cffb80ab, 0x24f99467fc174efb, 0xb08db850460325ff,
+    0x129720279263de8a, 0x90ee0f367e1294a0, 0x58c4b3253e3ce35d, 0x2025227612a30af7,
+    0xa54d76e6eddae300, 0x0ea8e81383ab0d18, 0x9cf1b71c3d61e9bf, 0xcac831405a04dc38,
+    0xe9575803976bf602, 0x3eec73e1a7657e6c, 0x301c594380205bf4, 0x30ecd8df3b84df1f,
+    0xffb8f9993396511f, 0x749a60993a9fbaf2, 0x4bd8352f8d1b3036, 0xa181c9fbb0e85898,
+    0x5c93a71f98f609e1, 0x21cddd372257aa1d, 0xcb4457402af772f5, 0xb0f9db3c3e1f059e,
+    0xa4ab83b19e2e6405, 0x7394bbcfa2614454, 0x4485fff4268ad3c5, 0xcf617162832f5b12,
+    0xa50a1bb8ff5d061d, 0xeb1d19af8df79719, 0x6bfc15c9e0de2e6d, 0x2708a4c7fb36ab1b,
+    0xac2aae9074e72f54, 0xe873d90e3f441fbf, 0xe5d853bddc7d0b99, 0x993b390713f923b1,
+    0xb3a625aaa858b343, 0xf654c08368433515, 0x0b51dc67376c6b45, 0x8910b579e0448299,
+    0xb87374f934aab896, 0x3f2bb817a704f4b5, 0x7b2a08a2bd95ce3b, 0xf8807cf04653f60f,
+    0x6889ddf559c905fe, 0x376846702589ca27, 0x6e6ea5399686e0a9, 0xc00b24f6885dd961,
+    0x0cde8d4abdbb4ec1, 0x45f4cb9896f2a98e, 0x7f8ffc7481d899cf, 0xb0cc5ed4dd66fb9c,
+    0xb78e7b667139e667, 0x3133d9e130199600, 0x6f56e83f297af8e7, 0xd355e696cb4080a2,
+    0xb0765029f54b6344, 0xa85a25b068524315, 0x228ed77b131682dd, 0xe909b9133eccb8ff,
+    0x782d663f3afafee1, 0xb878ac81212fbab5, 0x84dcfbc0f4c4b718, 0x94da52d2d8dc63ec,
+    0x67e218750cf1ec81, 0x19bc9e9247f3e567, 0x4f01f12bbc9a232d, 0xf99eb4e3f65b7a6c,
+    0xf57cb9a1bc459565, 0xe0d893698f10d59a, 0xa6bf4c7c0898f9c1, 0x4f3f13ddf4bd7b66,
+    0x4b49a36310edb4ca, 0x4abffc7db38b139a, 0x90dfcaf6a504b81e, 0x711c3efd3ee97490,
+    0xdcf03616429b3b1f, 0x9730e7fe3428a844, 0xcaebf491ee8ae03b, 0xa047bbfb65adbac2,
+    0x44cd4bcec5c72ba2, 0x213e5dcdbcb03613, 0x40e12c040d90bfab, 0xa3334824a69b1504,
+    0x983b0e5380e1622d, 0xba7e95d5d929b1b3, 0x92788f63ba3007ad, 0xea5b4ff197e25b0f,
+    0x6743cb35d8c6ca32, 0x429d31786b982701, 0x951be11fa85b766a, 0x630ba00aa761b3e2,
+    0xcfd72fdfbe1f9759, 0x689b065f1570d5c2, 0x0eb7bb5596aa2948, 0x0d32a00aea651f21,
+    0x6e0ffbf7751192e9, 0x0876c067a0cf73a8, 0xed3883458129af0e, 0x6085c53e17936dbf,
+    0x5ccd2a5a4b7ad722, 0x3614140e5cec457d, 0x1163d242ad98826b, 0xdad8e767baf31a09,
+    0x781fae9152627dc4, 0x4d3cd60c46dd821d, 0x3ee74e93b7f2a2d8, 0xc47780e3dc5bbcfe,
+    0x16633c18043add31, 0x9e4230a46ed444d2, 0xb9ebf1fc93fe7ce2, 0xf4f51a118c57487e,
+    0x3070f85c11474b06, 0x2c292bedfbb69dcf, 0xf8091c5f71d96fd3, 0x4a296858aed0875d,
+    0x5f4a694e6452d2ff, 0xd99976661f0df278, 0x5c8f5d32a5b3ea68, 0x5eafb40dfa291183,
+    0x2a0e881b259696e1, 0x3646fc4991080501, 0xc3e64153df83d061, 0x1f5bce3784519bc0,
+    0x586a79f0571da56b, 0x1bbb155e1e837121, 0x0e09ae2eb9988e38, 0xd7f4cc62b29a7f78,
+    0x074171ab50ffeee5, 0xe28cc29a1e88374d, 0xb39ae2c47978fe52, 0x9759e395b3c72bdc,
+    0xae352154e4abd251, 0x09295ea99412b929, 0xd8013bcba7277b50, 0xdf741af7dcda1272,
+    0x90df4af60586bfe7, 0xa6d695489d0f5f09, 0x713b04bc36e7d236, 0x7bf00855839f2176,
+    0xab2dcc2202453d22, 0x14fb4ee5e580e4d3, 0xd518b1338ea94d05, 0xf6bc505ec0b91279,
+    0xba504f15aab1dada, 0x51b607cec462ba76, 0xc2a95f92ebd2d910, 0x9871913a7837f30e,
+    0x60f9e9005cc02c52, 0xc8fb32d9ca8caa9a, 0x5256ccda9f211dc9, 0x697ecfdb6323f8e0,
+    0x71381461597af415, 0xc65842d89a495fad, 0x59b86c00215b27f9, 0xb9054155f504dbe2,
+    0xa47323970c9c73b1, 0x601ebef04470831e, 0xf5a4b578214c8db4, 0xffe3d36aa9b7fbc2,
+    0xba5f9d3b15443d9d, 0x0d3c7e1a8f022c0a, 0x750aa4b70ba792b7, 0x429de61780377ee6,
+    0x42a05f96e09292c5, 0x16f840863b2ac9fc, 0x20a6a7bd80e93e99, 0x26539e0cd9ff883c,
+    0x0a3c77a6d89bfaa9, 0x2a25eddcc82d73c5, 0x6f28699ed1295df1, 0xb70cbe5451cfe50c,
+    0xb9038181158d2c99, 0x4331e9d5bb9269ac, 0xfb9de1d81dbece43, 0xb6eb17081a921e1e,
+    0x256c6730edaa9482, 0x2c553c791e1267ee, 0x79131a9fc7e6aca7, 0xbe883c759082567a,
+    0xe79bd5a39e03652c, 0xf03b6362b689e7f9, 0x936a2f534d527db4, 0xbdcb1a2c9b3547b4,
+    0x1ec87d52ed185df7, 0x1d6aba07451873bc, 0x43b80287d6d12589, 0x6f6ff782f762d963,
+    0x8005f485496c579d, 0xa2a51d3fd99ef37a, 0x38f0cdd91d96eff6, 0xaea697990c1875cd,
+    0x7caf4c63a6e71b96, 0x502d264a8594dc49, 0x66141c2948b4c73c, 0xa36e541df96a9574,
+    0xec00108f574cf5ee, 0x3852cf6108cefbe1, 0xb6a908be7ebde3f4, 0x732e75f57c68584b,
+    0x16f42193abaafc8d, 0x6c8b1dbab9502c3d, 0x71479c58fc550b79, 0x950cda6b662131d5,
+    0x0e0f2837829a35d7, 0x6bad34fd59296824, 0xe623406df020850d, 0xc5ff9d4b23a52d53,
+    0x33238e6731cd3899, 0x6f9b22c3337e4ee9, 0x8829ee237dc20e70, 0xcc7bc295353617ba,
+    0x994b7444e614424d, 0x0196fa43694bfcce, 0xe7ccbc5a82b9b804, 0x648af636187480d4,
+    0x25d1e5f1883501ce, 0x93fdff2049431f8d, 0xc9ccce315636a09a, 0x1ee0b0138192fc5b,
+    0xed1b4445f2581075, 0xeb92a5a0ce64b693, 0xaeb6ec7e733570f1, 0x30fd2a83b33fd483,
+    0xad79a1ebfd673919, 0xf0d2198cb97e27cf, 0xbc5ddfb42988c023, 0xb46e88cea4f0dda2,
+    0x6d0ab018df8fd159, 0xd73a0d121d63e146, 0x0305819ad7c64eb4, 0x0ffa61ddd8b338c7,
+    0x006385bd5eeca582, 0x5c5df49957347abe, 0xdcc8cb775446c8bd, 0x71ffd81203da64d1,
+    0x50c077ef26775b19, 0x7cc8804d81ab7fbe, 0xc2ce9a24789269e4, 0x06cdd5a0b6ae21cc,
+    0x09c8187003947ec4, 0x41df2c2125b8196d, 0x9cbf2f6c44a08d2b, 0x399d86194dfd8ed1,
+    0x3840cdbfd2924a81, 0xcb357f07dc1c8e3f, 0xbb65173b67a9cb30, 0xe0d208cbeec86ac9,
+    0x9551418f75aa67da, 0xac7cb0657ff7d9c4, 0xe52fafc74da07e8b, 0xf8f374f6a36cdabb,
+    0x82e89e624901667f, 0x0252bcabfc543f19, 0xe32147feb9bb2446, 0xcd5b3ce56a5178b0,
+    0x0ec8d0a9781582af, 0x48fd18b0ac611145, 0xd4a20e005b9bba39, 0xea2dd0e421ddd20f,
+    0x83a781bfd710e76c, 0x04067d38f7bd0e3d, 0xba6447d2809aa230, 0x9037659faa05cb4a,
+    0x159d0143de081cce, 0x9f805328192635b2, 0xd131496233b34e3b, 0x87249286b444ea93,
+    0x76709b729eb6b028, 0xd81737c35fdb86a9, 0xf6a25a144dd62e3c, 0x35546a38ab8896c2,
+    0x036a5d9fbdf6e6e6, 0x0be0f7ef65e4dbc1, 0x95b7f83b1e9e99d8, 0x1d7578a2bbda31b8,
+    0x24bc8b6e6b27c962, 0x2e9441ecd1f8dca2, 0x5b88379b013577da, 0x2af865f4d9d68396,
+    0x37b5086e2170fbef, 0x5c822d109ffdd3cd, 0xd8815b0cedefc1b2, 0xcff9922f24277724,
+    0xb4abe3412368ac14, 0x1b5fedcadb9ae9b5, 0x55b15fae95107069, 0x5c9d1aa2b7e7e07d,
+    0x0f68b592f0b41820, 0x542417573bbb0fef, 0x649b2267c5d4d9af, 0xfcd0b31d5b3dc695,
+    0xb6ed74e7ffc00634, 0x7d7bb6878a54cb89, 0x89de35265ecdd449, 0xfd845e5a777e3508,
+    0x7192f396532d9bec, 0xf933c29821fce7df, 0xf6d7553de7c6f2aa, 0xff75a44e8bc5111f,
+    0xed0af50c1e568d21, 0xa49a5fa5b7a9937e, 0x863486cd7d765311, 0xdeed50f944340eed,
+    0xe48dd4a4acce0daa, 0xc611abd45a1f3f92, 0x5d2036308978e749, 0x7bc467c8f44dae49,
+    0x8a353768a04f6a80, 0x3ec6c23bba15a7fe, 0xa78f029500ccaeec, 0x171cb60718eb4d41,
+    0xfb83454eb6dea4cf, 0x1065ac28185434fb, 0x6a58cb8bf1e3d6e7, 0xe74e20692c3d95e0,
+    0xb5e582e5c79e71ce, 0xc1192f71a621db60, 0x75e160978103555b, 0xa108c97817e0ea15,
+    0x6688f389e5906198, 0xc57b9f074ccaca2d, 0x052f125364a2b0e7, 0xc10e04a73d2d667d,
+    0xcc400a0ecef803ba, 0x17bd36cef86c5bc9, 0x8860f8a9fb43766d, 0xe6ab3aca96be0561,
+    0xe00e26d9d0a096fd, 0x0d9bc246db4c7ece, 0xe19f9b539013781f, 0xde1d10ad9292901a,
+    0xa949e8c8c777d09e, 0xa5c9c5740bc4b60c, 0x8d94e9d92c40c575, 0x761cb3e00c172d6d,
+    0xd846a75945da6b23, 0x3cd9d1c7523cf2de, 0x91d6131a5bccca48, 0x3380516fddb32758,
+    0x093abdc40c768764, 0xe3af719347cdaf90, 0x38d2a57add104617, 0x88ff81430c0d8bee,
+    0xed9342b66cc211aa, 0xa07673d79bb2a2b2, 0xde9813d14809816a, 0xff42868dccb55a26,
+    0x2569934b6b089931, 0x186a8338c288b325, 0x5641b67d1bacbe48, 0x97602b1657eaf95b,
+    0x70f552adc615ef4c, 0xdbb090e566abb8ae, 0x0fcea7c85d1d3743, 0x38337df2f80531a7,
+    0x489f39dccaa52a05, 0x4a2c71d13dd5b767, 0x3cfe92cb77b1d2d0, 0xeba8879e0a14b68b,
+    0x6d427cd7cf6fe7d7, 0xddb83e4f92eb5c47, 0x1a938ecf8ae85060, 0x2f7454b74fb9e54a,
+    0x5aaa2fc671d524b6, 0xb10371a20613c905, 0x749e4cda05ecae6c, 0x4cbff4cddc6eecd1,
+    0x81926b9daa7f46b3, 0x5c0e40cdf10b8d7c, 0x9ceae68fa85deaf4, 0xbf73c12c57352297,
+    0x984c7ee0ad1d7fd2, 0x004772ca9eb4b226, 0xf4b5931b03537739, 0xf5b2f20bc7a59c19,
+    0xbd237c5fc3f5a8c2, 0x3b21c3fdb041da86, 0xcf4eb44122ddeede, 0xd16e1ed575a5ca99,
+    0xc5709e7f6eaa3d93, 0xb4e5dcb2249ae737, 0x853b96c57fc43750, 0x1798ab98c9aeeed3,
+    0xb54ba0a020dc91f6, 0x9b9009da065f3bbc, 0x7ff0faa137cab675, 0x733e66e3607b3e59,
+    0x9a4d8f9b5466f924, 0xdd8bf662d1d69f95, 0x205658d9174f4161, 0x9aaa527e0e88018b,
+    0x99d5f72ee93a8bc6, 0x35e79ac6d0152e49, 0x0969a0f504fa5530, 0x3af31a9bd63847b8,
+    0xa14964260dc2018b, 0x5fdd4e939ab6e8b0, 0x91552618de431e6e, 0xba483cc297c6114d,
+    0xf13401679060af2c, 0x571ca388b9c367f6, 0xd7007bd254d9e014, 0x87200e84b112ddaa,
+    0xf605fca7af223d1d, 0x39fd217349aae51d, 0xc81a7ff9f73c7baa, 0xa219801d7d566143,
+    0x4477e09f6b8181d2, 0xdc63db4f212dce3b, 0x750611c18b2f89ec, 0x3c685771072633d9,
+    0xa53a57be6ee90b90, 0x63e89060aae2927a, 0xa72c9eca78b571e5, 0x218522a5782deb8f,
+    0xc8fa031571de36c8, 0x6fffae6912a9466c, 0xc33ee2f09cda69b8, 0x9f1ee36180576714,
+    0x0a7a1407f05fb540, 0xa6ea9fec52e8f2e8, 0xe6059e782c1293e8, 0xc0842be04b113e5e,
+    0x4973d3a8de4846ac, 0x64347f2d51ea546b, 0x712c12d42502ff40, 0x7a929617c903acf3,
+    0x27034beaebf1bbfb, 0x7e6feb988ac83c75, 0x4cbc5a8a93b16d00, 0x51cbec2d9f602037,
+    0x3932ff5c991a9dbf, 0x888d1f9f171393d8, 0xd0d9f79de6c6e466, 0xda3b092eec36008b,
+    0x5987385535bcb391, 0x0c34c3e4f9bb8ae9, 0xf8314329cd470bf2, 0x3e0d52df14682f47,
+    0x2d10e6f540ce22bd, 0x3550710d287c30d6, 0x62e89b755a7b8542, 0x843e3be67ea6561c,
+    0x0fd2142f1fe0a927, 0x11ce588a215ebfe8, 0x3a4c94f8ef61d52f, 0x8391f6cd70484407,
+    0x2b56c17ebb0aa97b, 0xc920fc76c0aa8807, 0xc53594894be79744, 0xeb3e2336f2cec830,
+    0x7eb52c9cbc53dd0e, 0xeaa72309dd779610, 0x70aa62b85d287e8a, 0x441d1ae1810c9d83,
+    0x856e5d5f5bea9cb5, 0x399f80f1fe550a76, 0x244f3835c8777c61, 0xb542e4232379b876,
+    0xeaea1b84d13d4d62, 0x1b8aee8d5c420ed9, 0x26749236eeacaa83, 0x5eb9ab5e3a2c5313,
+    0xa8916df3f8c12e74, 0xbeff612a98cbafe0, 0x36db100de779636e, 0x1985ebf79643549a,
+    0x4ea70ef2ca8a36f7, 0x3e2620bf8b386f83, 0xddef77bca0d0c512, 0xa21f1716d3e2e894,
+    0x088d583a000998ab, 0x18ac548f609a9053, 0x5f73ee0f0752baca, 0x86f1b0c75a095229,
+    0x38aad99a9a4b84a5, 0x6220f6b58c49c661, 0xd77fc8083b7c7f15, 0x5aa69d9ffbf5eded,
+    0xf01fa262febd4425, 0x65c90b3589c43e4f, 0x78dbb0b0533e39f2, 0x3b39eb01d2753ae1,
+    0x5a1cf233e6f924ff, 0xfaef7032458ae0d3, 0x3850820c142bdf78, 0xd311a7e2dcbd40d9,
+    0x11a6d2795987bf67, 0x9988e0a40d2a7a01, 0x560ec62ef078c1b5, 0xfc34abf0b527fcc4,
+    0xbd2ec39465c5a2db, 0x1e573d80af8f8ab9, 0x1f0c0960c37cdded, 0xe3efc9fce420ec3f,
+    0xfd772e65fe121471, 0xd2b2ea61ebc8360c, 0x9a4ce7b14c8870ac, 0xb4013947f2df4e6f,
+    0x0ba1a7b43f62b0f5, 0x24c2e8e816e051ce, 0x4e352270610ae6f1, 0x75f4aa811b54b47b,
+    0xd1db5526b40cdc5f, 0xebfb2fa223b809d0, 0x0d68d0a0678ffc36, 0x29fd26b9a9e337cc,
+    0x1e1b8cc430501e54, 0x0d8ea98faab4ffc1, 0x04d0028cccc9365d, 0xdd09d9b723cf7e30,
+    0xbaeda01f60ea65f0, 0x721a7db1c9d5b847, 0x6e9837cf550c8d28, 0x5b1e5d8ffdafdf38,
+    0x2d54ab77a53bf96b, 0x69d6bfa59edd3c49, 0x8cc7bf9b4e82e28b, 0x8912be863dfd58a2,
+    0xa9e1ac807ef9eace, 0xb06feb6c01e8b2c7, 0x6f23a86128680cab, 0xbf6d738f3284d52a,
+    0x77b0882e68d1909a, 0xa15226b68dd28430, 0xebd2a5f47806dbd5, 0x6ec8d0d8992f5fdb,
+    0x1ce61055bc205a7b, 0x0a4933edf5651d61, 0x204226a43fc5fd67, 0xb537b508b9532049,
+    0x09bc01da81516b86, 0x9cc875582be7a0dc, 0xc7918794817c1c32, 0xe8bffa83da69a98b,
+    0x39fa63863cdd0320, 0x3960663e66d8f1ba, 0xa4e3a43c031735f6, 0xfb51dbb8c8d2eead,
+    0x3c41c48f482e259c, 0xf3a855d25607d609, 0x661cfd26b3081b90, 0x4131cf5e72644064,
+    0xa4d0d9da0643441b, 0xa885d53d187fc865, 0x94002e3645f155a6, 0xd5c263fb67ea334a,
+    0x2eab70f1a027b7ec, 0x3039de37eba9bdb8, 0x7d0e0758a73faceb, 0x41a57abbdfd5b2b4,
+    0xe5cc16e096494975, 0x1ffc8840ae908900, 0x9b848b5d4a80fbc9, 0xecdc4bc19ca05bf7,
+    0xf610655e0bf31090, 0x41664ef97f095d68, 0xf4aa3a15c3734175, 0x995ddbb178226dd2,
+    0xd0ed397e0979fd54, 0x7430bacae80f2eb8, 0x1e40e00d2dc63a2b, 0x4f2124216e68e7af,
+    0x9d86fa0984697e3d, 0xa661321fc87416fd, 0xf630352915eba282, 0x41d3524535e49c02,
+    0x49df678274c75f4e, 0x3730a094de68c5d5, 0x2c6759bda41008d8, 0x340ca7e7f066eded,
+    0x22983e1125636146, 0x2950d26a96f93a37, 0xbeb88b9ae88a1d72, 0x6684dd06f25fcc36,
+    0xe0bec6997896c3bc, 0x6049836e7e67074b, 0x53fef02e2b5e08e1, 0x15475c95a166507f,
+    0xc4221ba3fd55ff0f, 0xdd4ebe813d12ca25, 0x72c9074234002270, 0x1dd782e33494e84b,
+    0x14e65831fe7af8a7, 0x11ae173a0e394627, 0x18e41011ddacea95, 0x3c4b257c8350e932,
+    0x344bd151481178ac, 0xc113a72541853ba0, 0x3dc494a11223c6ae, 0x460c946cab07f8ba,
+    0x8f5d9a3d42568902, 0x9028e098b2aab639, 0x380d9d994bc7d259, 0x508d04a54860b00a,
+    0x50210bd94a7290c3, 0xdcba78c3ddbb9c3f, 0x6dbb383d2764eb8d, 0x541ec009c9cc32ae,
+    0x08f2749f05b5a3e0, 0x6912f1e28079e09a, 0x6268e20c8d577e7b, 0x9b649a36652d8b57,
+    0x98a19eac05d6beff, 0x4b624acfdcec2a20, 0x9fd59fa70c31807b, 0x241f9ffb50e6642e,
+    0x53ed4b3c22142877, 0x6b57ea5555408f6e, 0xbf9244b1f1e82305, 0x0c574f4e11ab2a7d,
+    0xebd739a4722f028e, 0x269d6922dadac055, 0xcaac42ebff920538, 0x3a786e05d162a4b0,
+    0xf729975436bddda1, 0xf2920f88be19c4fa, 0x8b69058d00c6b076, 0xab64a4ecaa3073c8,
+    0x2c167b0f0b852879, 0x7a3d1896525fc3da, 0x99045da275410eee, 0xc357c0dce16b5039,
+    0x0e1df837dbc0dbf2, 0xe95d328fad47e07d, 0x993373d0900e7f75, 0x4b4ab02ca8d96648,
+    0x4f63ccb0c340c9ce, 0x028f9564bc73344d, 0xa47a0c7aad86f61c, 0x0e506816e15a974a,
+    0xa9a3e0b408723a17, 0x04be5ee2434f1394, 0x3b35ad31613ac718, 0x594bcfbcb01f40d1,
+    0x01685cb76b9c64e5, 0x4d38b30887293769, 0x67d76f1e1e2931ee, 0x9b1bf3759c04c5c6,
+    0xd61fe7fb7110b781, 0x816cf0c447d1b6a3, 0x3e0a2d02e214f194, 0x56163f1c99d90475,
+    0x8cf528c93686ebf9, 0xc325bc888e4f724f, 0x9fa119dea3a4c886, 0xc4342bda7b4b8906,
+    0xd12b1cb0c4a6e804, 0x16572eb5f1b8510b, 0x940dfa973e4ecb4b, 0x8481cd15da75cdaf,
+    0x5da348e2f08862f0, 0xc450adee3250004b, 0xd7967a83a7302bb5, 0x1f0386871cd52843,
+    0x3f4277b5efdeec45, 0x043517c26aa95547, 0xf27870cebef52195, 0x36f0fafe03465f59,
+    0x528e98874676183f, 0xff5f084ab2aae69f, 0x744ebcdb00bd79f4, 0x4d8e8c0115eb0e9c,
+    0x9ff38be93d7a820a, 0x9cad1ea530f28d5b, 0x74964f412575a458, 0xbd7f54de6dfff556,
+    0x764d4090a5434833, 0xc4bc2b9a2bc24fa5, 0xc73c13dfef5b4bf8, 0x5d99ef96abd2905d,
+    0x229db87e50be5a82, 0x006e1a5503baf493, 0x875f8471bbc6a57c, 0x9ee8bdb7344bbc79,
+    0xa2a5a47021bcf735, 0x793e91428ff37b05, 0xf2dbece4cd62d7be, 0xa7b4273c9682eb2f,
+    0x6f87f1e08ecd437d, 0xc33b3f6f8e634a6e, 0x423eb80468368a9e, 0x1812df38a803a604,
+    0xacb3941114444f80, 0x730b355d8e756960, 0x98ec1a2f8a75310f, 0x9755de07f2053493,
+    0x1bc4d437bf6a2072, 0x2c6a6161d442bb40, 0x36c87fed06637d56, 0xeb8a3f2a3d710c90,
+    0x7e6d99c387cebc6a, 0x8b60a65ddae65f2d, 0x780fd6ee6a82774c, 0xcc15f663f2ef56ab,
+    0x19244620bdd5ba52, 0xe3e0212735404825, 0xcfee53016dad2f78, 0x713c85fda88dc445,
+    0x6ad29987b8a82055, 0xe7ebd94522fa59eb, 0xc1013b26a3cbb837, 0x569247f2344290ab,
+    0xc940d3a41316afc0, 0x67eca903e5c33bcd, 0x0a241d480cc2efd8, 0x247745c02c01d27d,
+    0x698cc9ed8a25bdc4, 0x693f62d32bc8b9e7, 0xe942574227f0cf7f, 0x424c99aba6586201,
+    0xc654ae6c78ba8309, 0x89f476f371cd9b57, 0x48ac76b581567874, 0x51496c4877c3f3bb,
+    0x2500672cd4fdb0c5, 0xcb6f3e76a23daf20, 0xf8dfa7c901cf3d79, 0xaf01fb05af56bd48,
+    0xf068eae2252f91d8, 0x8152eeaabb8e3465, 0x80e69b1315dedce7, 0x4575c03b561bb01c,
+    0x7872445949a36ead, 0x94c8aae02c0d2314, 0xe4f95f0c6067769b, 0x7c174269ce5bcf6e,
+    0x6fbe172e339f35c0, 0xc4076ef6c6df9336, 0x01879a5f86d0be3b, 0xa54162d740fd1072,
+    0x04b7495b95af4047, 0x810261c77c0a98bd, 0xc23cdab84db07b80, 0x0b75d3958524cf55,
+    0xa272b3ef4a28eb5d, 0xcdeb41de0412976f, 0x9f7e136f749b3f5c, 0x7f791c998ae87dbb,
+    0xf7cc803f9625ccc1, 0x21524927ff027a78, 0xd2ede9e29c2d9189, 0xf324c899037d0dee,
+    0x76f7c7a5ec0d0aeb, 0x815bfff7e559cca7, 0x62a0736ae5804fab, 0x6e9e28005c865f60,
+    0x16f917300b91804b, 0x62ff16fb3908734c, 0xc8ecaf3b924abcd3, 0x9be2e26316a91b25,
+    0x32f3c16100a401c1, 0x4eee45badc29ae01, 0xa2a97aa019c58e6c, 0x4bd122bcc4c380a5,
+    0xc2e08e8a39d04946, 0x9aa07c7b954189b0, 0xfbf319dc88572908, 0x3edec428751686ae,
+    0xc06ac223b4efd47f, 0x277e429778e07d87, 0x669f737dca7a5595, 0xd1db5ff6eb1c82e2,
+    0x9c9f3dc47005db46, 0x116677ded8abd934, 0x98f316f655dafc4f, 0x45881ba63940ffe8,
+    0x54899899f6599f4d, 0x511c6cefe745b679, 0x02580322fe9be4b1, 0xfb1b57a9385d5964,
+    0x1a3fe0663fdf2089, 0xcda6aed07c37a1df, 0x1386d5d1e8675996, 0xe3a8f9347de7513a,
+    0x580e65f11147bea5, 0xc59de055e29a3090, 0xd66b7ef35f929ece, 0x3b1512dc9e3862a5,
+    0x2b4070cd04cc0dff, 0x303e02b508c05d30, 0x450494b5ca14c9d9, 0x79785c63bd7e79a0,
+    0x757ac78010161a30, 0x6e1b6728c840d0f8, 0x5072d5014f39d683, 0x1d88f09ee7b4e0f8,
+    0x5d6846dcfa610bac, 0xda76be45f92141a1, 0x0e28c8789c5c9d5b, 0xbe85e06599843c20,
+    0x03c2cd014c3f30d5, 0x25eed211efceaa9f, 0x79eb4d59d8a8c006, 0x6aadce1903cd5e6b,
+    0xca14eefb6205ddeb, 0xbb324938da3aa84d, 0xeabf787cfefd1933, 0xed76b963b64c4689,
+    0x25012571c0a549ae, 0x23dcec7474d92120, 0xb4546103e9337be6, 0x2289a98093e44035,
+    0x61c43562b38f7639, 0x74063dbcd0fe5066, 0x5b969d24e67962c7, 0x516181725b4be49f,
+    0xc1f478043222bbbe, 0x2ded4b4511472714, 0x074b0aea6f3d69f6, 0xc076b3e55d57638d,
+    0xb506d466d7a800dc, 0x4c66d45d75a6892e, 0x22222616609cf5a8, 0xe50e9c11a898590b,
+    0x66d76b24c126fcc4, 0xe805c1fb732133f8, 0xf48a227b2719f979, 0xd80ee3c9c76ee779,
+    0x7ac8faae83e957dc, 0xd909b7f32fb30913, 0x0d9d837b58b5b465, 0xe82bb6c96c3bff4b,
+    0xadd9903354067ad7, 0x6edf8da00932a15f, 0xba8b1b0f443e645d, 0xccce0b94e81d564b,
+    0x2993396aeeb65e51, 0x81965611f0f8ee98, 0x23d114044b1c190c, 0xc043363eff08a6d6,
+    0x1abecceea3cb9f01, 0x22161084c03377cc, 0x01936b4b80d65fb3, 0xa9079e9b630e52bd,
+    0xc0463932a9d91d50, 0x238f55b40bbd2f52, 0x49e79e628c3b40ed, 0x5cd7fdd690f16544,
+    0x594ca9f90b9ee791, 0xf71ed818f1d77545, 0x439883cbbf822f43, 0x1f5c56e25addbe9f,
+    0x5faaf4b027fc23d8, 0x67c24b4d936b53a1, 0xde85d98046dd1927, 0x1edacde1ba6f5ed8,
+    0x413f5f1003a24935, 0xc2711b7d5ac8bf80, 0x98b420e2e1a8959f, 0x035ad3b5149948d5,
+    0x9fef6f4c732a69ef, 0xd43116bf80f35237, 0x63c08ff3176592fd, 0xa171b4877e6b9b82,
+    0x92cdc3739d4f80a6, 0x0d8a4058e0ff3983, 0x6f37b26810c71899, 0x3c649f23d04e4e04,
+    0x85cfb525673001c3, 0x57a69dbd595df8f6, 0xf4a5d6e4a5c46de2, 0xa25f5ae51ad0feec,
+    0xf8025cf63e24b741, 0x172290f1fa95b1e0, 0x40f0fc378f87622c, 0x2fbda868e36516e9,
+    0xc02c1fc490fb8795, 0x84c92c3d0609a049, 0x1c807c8dfde190a4, 0xbd4af895212675cf,
+    0x65c2bfbd0e8bea9b, 0x33e53dfb04130441, 0x7a61c6b837a142ea, 0xe329020df1463732,
+    0x5cead7021d5d04ca, 0x5385bec36180e1f1, 0x0523b792f3392d9f, 0x9fcb90edd7b36184,
+    0x2543f45b8b7404fb, 0x0ef649cbe0931ce1, 0xbe1c985c46d9fa0c, 0x10ba7d80df1d73d6,
+    0x16c60811dbb3ad34, 0x94905d194e68f19b, 0x083ef19ec9110058, 0x2bfa38805188af6b,
+    0xf16cde093eaadf8b, 0x7abdb5258d925b3a, 0xe2fa1f896f1cfa18, 0xbc496a6fa1a76500,
+    0xd91f21d51c67a0ce, 0x0c5a0e79591fb346, 0x10dba35569d10e39, 0xdfc7b4b789297314,
+    0x8fac82a07ec132cb, 0xd3c98dbbf644ac74, 0x5e315779f3d77e02, 0x54d7e7e18a8fd2a6,
+    0x9990bac74762275e, 0x0c8054d8582897a4, 0xdd4368e428b656b8, 0xbd052c63c63727d7,
+    0x49bdd96245df11e9, 0x59f33645750660d9, 0x3a913c43f14d30db, 0x02c442b3e3132d14,
+    0x26c607e009d2d616, 0xad45b9c4514c17ce, 0x15ae3cfdb436596c, 0x8bf5b689f8234c56,
+    0x2102ce0f48b4efbd, 0x3aaf8dbf18318892, 0xe7d8939b054aa523, 0xc293fcb06e0a8d69,
+    0x1d39a7787586a205, 0x2f2dddc31bb5b282, 0x060fe03ceb13e634, 0x2ab9da5824312302,
+    0x9006812693241977, 0x9aa2d98b59b72c15, 0x3416bf3f42b968bc, 0x30a856308a71c797,
+    0xa7086f99732668ce, 0xb8a25ff6090ca954, 0x516417b65a36ad78, 0x4e6727e0f9b186e3,
+    0x66debdddf4a64f68, 0x3c3af24cdedffd72, 0x7bad541f3a16adb7, 0x39266bafbc849151,
+    0x5813ee9ced8d7166, 0xba097a24cfab4b91, 0x4a2ea6b9f6ecaf69, 0x83ef0b2587eda8a2,
+    0xbc827cd4413de6d4, 0xf03035da31327cf8, 0x0cfbd6e0388a27e4, 0x5f780d4fb9825d22,
+    0xb7479115d11388bc, 0x35ca87f45b52b4a2, 0x6a535b9d16439e5b, 0x4e1e9f9231a1eb29,
+    0xbc6e9a783e0af0e9, 0x40f0e477761400d8, 0xaac376a4bd085515, 0x617c2252ac9eb09e,
+    0x0495b1242d88e120, 0x215c6c147216e3f9, 0x488f53bf201a9fea, 0xe2ac2678ac13a769,
+    0x13a5ef751cf7e591, 0x5b16f2f9ce3b1b7b, 0xc1d6b73c607dfbc8, 0x0f6d7ec9168e15cf,
+    0x4513803ddc72f7ee, 0x9a212506e7729d0c, 0x7950354c4e5d755c, 0x317a1c8a52aba1b7,
+    0x52bede4dc03a61d4, 0x4d3ae31566aaf6fe, 0xcafa9e50d5aaca38, 0xf34d88f0883dab93,
+    0x68cd8f437864ab2c, 0x5fc2e1f30951afca, 0x16c6ad4f6a8096fa, 0xae390e2b4883c18b,
+    0xada04faeb29b46c3, 0x229062155db9350b, 0xdd96efe75ffb4323, 0x83280c9d49468e2e,
+    0x35e893285250ff66, 0xe7e9bff801ce1336, 0xe3eebf868d80c5fa, 0x8377c8f178a99ac5,
+    0xb70a9926d5e0ab09, 0x4ddd6d4ad52bb328, 0x563417ee48a89ccf, 0xeec1ace59e0fbb0b,
+    0x804c6b6f05a425de, 0x53714dae800743c0, 0xb86b39caf6e91515, 0xc0560f1e71926a6a,
+    0x7891c1756ced56bf, 0xfbdeca83479a707c, 0xa3825cb8a105b15b, 0xfa4f2d8b75a0ef99,
+    0xf0ec7e35506f9f65, 0x5ef9d23738cdc811, 0x0d83fc6d0d880f50, 0xb141f679dabd1dc9,
+    0x7b1027df94691cf7, 0xc228c80f97e9ffc9, 0xd161892642b93c0b, 0xdd78862611ad208a,
+    0xb3dec3618092d0b5, 0x110cc324f8df5710, 0x2f22be1ff6a82b74, 0x8968a4dd22e13aa3,
+    0x61281d8baaa35aeb, 0xd9afd812b3216a77, 0x2b4a01c19501667d, 0xb4369ecb84097e55,
+    0x6f0e1096ac06ffdb, 0x7c12f723ec0f1675, 0xe5729fd13cef764e, 0x62de0af9176dd6ea,
+    0xd6a4500d13f8029c, 0xc683b729e58a730a, 0xec2285b9870fc9cc, 0x0c39dcc94d85ba59,
+    0x09c851cf5479229e, 0xfbc5a00118c109e3, 0x7ffe5be31874b441, 0x5a4d9eec0c784927,
+    0xeb51bbd465dafd84, 0xc658e7abb10849a9, 0x9a7b4fd04b0c60a2, 0xcd46f516a8d1d9cc,
+    0x88ab7917c971c30e, 0xac951c357e7f0456, 0xa2858a0deec6cbe8, 0x8be80c29f36567c6,
+    0x930165df74f3d7ab, 0xea1cfa303e49b4b1, 0x5f5b5d99f1af445d, 0xf9fce836aa5bff4c,
+    0xbfc1a18302f7b552, 0x3bd71f0ec43d23b9, 0x99cd48b20a33c668, 0x89938c5d05e66d86,
+    0x91224b2189a46152, 0x193281439ccdc95c, 0x74d25a23e09c2f21, 0x0a61b57c1d641944,
+    0x4375da6fcfd9d4fa, 0x2673020fb270ac96, 0xe95655951e598ceb, 0xb41cf0407aa8fb76,
+    0x114c95a2591c1329, 0xf7f84c5bbec3eeee, 0x549bf51fec532ebb, 0xca1edaa15ce306c3,
+    0xe54e7151ffc9acfa, 0x69c4c2b2b540d9f7, 0xd4853ac7f409ee1a, 0x5b5ff7007142b25e,
+    0x854fe0e85757b805, 0x986d6755bf963d67, 0x460b7e6f8f55066b, 0x946b293960df61f1,
+    0x358880ffe8772f00, 0xa50421728a2ba946, 0xddd036b4367d222c, 0xb2215d88a69c096e,
+    0xf996a9c5ab08492e, 0x2271716829ede3f7, 0xef58688e1dd804de, 0x12d190a59e537d33,
+    0xf17071dae43876c0, 0x2b281f071941322d, 0xa512b26fb518c44c, 0x6f16577c2680f2a5,
+    0x11078476cbd7bb79, 0x41aa286245364896, 0x0eabb3a8bbef5164, 0x261c8233a5f599a7,
+    0xd7b2a8f2854a1808, 0x9e06586563500db6, 0xe5981e35b95286c8, 0x463ca9d3173930d8,
+    0xed48c86d15c39c9c, 0xca09ebee302def9a, 0x21b7a97845dd89c3, 0x02d27212af172bc5,
+    0x9967e56b3483c10d, 0x82f3896f7923bd9e, 0xe68e79959f540a61, 0x9238baf96650555a,
+    0x8f9517b4c908c379, 0x3ab47d48ba8207f9, 0x42469b337dfb9db4, 0x96fb2f062fac3408,
+    0x75a0cbcdac6d5217, 0xba6bdd4c77710f9e, 0xa5132c359179ae2d, 0xe2e1b0db4e13319e,
+    0xc5e626aba3a12127, 0x98d79bbcf84e371a, 0xb556092cb11a6127, 0x997f12cad06089cb,
+    0x669fa5681b11b73f, 0x4780568ba587fcd6, 0xe7876bb97663cf7b, 0x683ee2116c188db9,
+    0x1996b38b9b79e6bb, 0x151cca40c19610dc, 0xb766fda82d901452, 0x63ad85b370bb04f8,
+    0x1546b91aa0e25b4d, 0x59d295683f80e1c6, 0xcb6766132c044e13, 0x323cbed2834bc701,
+    0xdde30d1d6e334b46, 0xaa29a1bc6e472a96, 0xf3637cf28c598e72, 0x40a946dc68cb17bb,
+    0xe36742d9032350ee, 0x00fa74dc63db3381, 0xb70fd0905afc2186, 0x5a39d648b9bc692a,
+    0xd1e7a79268446809, 0x6e0120682c600268, 0xb0c4e228bb5fd68d, 0x1f2377e35b7a9305,
+    0x97fa39a76fe4d538, 0xe254d6e9fe4ab777, 0xc3afe8c0eca980b7, 0xd0097e15633250bd,
+    0x87be714c52d75309, 0x7bf691115694681a, 0x3acdbf604a5f09a8, 0xe91af2fe0b620e53,
+    0x6856edeceb045f84, 0xc62dcf3a7f7f6a95, 0xae244115eeb07c2b, 0x15c4437f16e8d3fc,
+    0xc9b07514f7d7f1cd, 0xad43681418061a13, 0x4c1a52f7dba8a9b0, 0xb686c6b78c717765,
+    0x40f8e7cea7802713, 0x3f4b05d7437c2dcc, 0x1ea15c080cfc127c, 0x7b7a6bc5d877f39d,
+    0xbdf3810db82c70b0, 0x9c9c73cdfd2bd8e1, 0x2b66215ddfbaf683, 0xf80b3891869ded10,
+    0x005bed3cf148ff99, 0x582307599e568f14, 0x51b0ae0a127874ed, 0x0601b06036d07d7a,
+    0x84eceecce5562fa1, 0xd1f804b222a6faad, 0x037d9d1a4a15ec1c, 0xc2e64a66836c9132,
+    0x253d1df121043bb6, 0x83aa705e51b27fed, 0xe851e1ccd6a1ee6c, 0x585206602092f3f7,
+    0x0a49ab012836487a, 0x331086c7e0213e0e, 0x61cc8e18cd889fc5, 0x353911d363fe41e8,
+    0x3413ba06354c73e7, 0xcfb65cbc3781f9b4, 0x31b418daaf074b7e, 0xa093e38406733d7b,
+    0x52aaef9dcfa785e5, 0x098a0609a8bd9f79, 0xabe09fb9e5e4196e, 0xf21fd3ebac62dda0,
+    0xdf1c567ce0db2569, 0x91fdab1e82293b89, 0xcd63406568169d2b, 0x5f7419f6f0b398ca,
+    0xba7356a1d8db9c3b, 0x4f5264b9d6a4e4c6, 0x72e9fc8bb2a2462b, 0xbf5e8df953644258,
+    0x94a9d3d1b145e9ae, 0x38607d7215b8ee4f, 0x3c9d9df522d321ef, 0xb43d9cf9a47466b7,
+    0x383cfad29752864b, 0x1f4e4aed04b10e61, 0x7ce6e49b55275bf2, 0xe72bfe4a389028dd,
+    0xfef99a1ed7ca3b5e, 0x6229a86e3ac74e40, 0xbe5b9ca91be10869, 0xbee72fbcdafb24b9,
+    0x1adb05bed3fe154e, 0xee74b209243beb57, 0x998ff528ce887667, 0x4bf2c5aca25b7d49,
+    0xe416b9a301bc6234, 0xbcd8bd5d67555ef2, 0x3cb445d7112f07da, 0x72d553994aee26fa,
+    0x36e03857a3112eba, 0x2104c40c375aa830, 0x0d66129c1fd8b370, 0xa2cc971ac00a8d73,
+    0x3bc72906aa909a8a, 0xa8b2f0760019df5e, 0xbc0b29a7b8bfa57c, 0xfa465f5871f1943e,
+    0xe3c25c536863b8de, 0xc6ad48f809e622c0, 0x34e2f62e4898dbe6, 0x9f6abc9193369740,
+    0xe12fcbe76d2f67d6, 0x1347b32f5a6e224d, 0x1c9f2bee4e88f8f1, 0xf6038c1aef5ef6b2,
+    0x6a7f2ec7dafcafef, 0x7133942566c0a786, 0xd4f2ac5ea97f7b9d, 0xff8537bd732adad3,
+    0xb0d96f14a2d8358a, 0x6c07c4ee54e82388, 0xd0ce2f6e08792cc5, 0xa553893f46bd6fba,
+    0x60201d5d26ec14ad, 0xf8b7ddf5e26ad1c5, 0x6f5242afd92ff2d7, 0x80cde229bf9f698a,
+    0x93a89866407721db, 0x0d027c13208ea2fe, 0xb53f4a0cd7abd00c, 0xceb7753121474c60,
+    0x1ca0d40921845f52, 0x6414685db8b9f444, 0x2940dca882de14d1, 0x2a2071194046b739,
+    0xf74a760b5baf351e, 0x52e5f9c7ec4005f2, 0xd7296cb5e27e387e, 0xde53e87972a302ed,
+    0x722f94f80bf19a4c, 0x9aa961ded82b4b7b, 0x2cca15fbf0f17064, 0x890f09efc986e53b,
+    0xe339cb029b30492f, 0x4842f2af684893f3, 0x9f11b709b1b03a52, 0xcff1d31f78819a10,
+    0x264c28082989de82, 0x145f1a875050b7bb, 0xcf8c501cc293dea1, 0x21ddc794eac620d1,
+    0x750ce8be7d15cb53, 0x4c661a1b550d6d5c, 0xa22704e2ca2e7be0, 0xaef6294c2421435d,
+    0xd313313a7e134366, 0xe28c0b7029309e57, 0xc18c2efec556e1cd, 0xe847e2df7477a1d6,
+    0x4d9f3db38e3b1d47, 0x46397586bc198ec9, 0x306978f1c9c789a3, 0xdadd4a9cee971f54,
+    0x1fd029c34ddec0b6, 0x671714fd48ac6fd4, 0xcb9b3ca8c7a8dd3b, 0x03049a06cc7ec76a,
+    0x60bfa956535a7aab, 0xc7d849a21a6bc98e, 0xe30321be216f85d3, 0xc3c9e21b177bee84,
+    0x837e7db623d74bca, 0x3d56213902a29452, 0x05315952ee6d0432, 0xdecc90dfe5a621eb,
+    0x5ca43b2547eb2d57, 0xe3e316a580650d0a, 0xff9ebace0d9a8432, 0x05ed1c926332ac28,
+    0xd604af121580655f, 0xe6b0e9c40847c826, 0x3d8d56b84b2e6093, 0xd311a9dd58b2d523,
+    0xd1c534958cee50b1, 0xdb3a372a6769fff6, 0x31e25cf7cea4cdb0, 0xee3aef1d277f1a82,
+    0x26bfe9612602d2c6, 0xaf9ea51e42ea40f2, 0x2c5b35d708359042, 0xa01438ad2db4f06e,
+    0x7496d586d56a7ead, 0xcd26620fac02e403, 0x891f265ac7500d8a, 0x863c2b6993c38639,
+    0x6a37dde79d262fd3, 0xe24459184b167bc2, 0x269367b172a53f4a, 0x93c5e7cfa2ee9d1b,
+    0xb5bff99bcec516ac, 0x3102b086f1a9a47c, 0x8c8c0e86fcebd42a, 0x8cb608153be821c0,
+    0xbc3496d22b70cd55, 0x995ea87a38435ba4, 0xe5922121f758cb9d, 0xa684e5734ef1c87c,
+    0xf729b28d3de9646c, 0x8809653f8c54f80d, 0xe0f7d669cf5842d9, 0xa4a218fb7d5e9607,
+    0xb5ff639e6a395fdc, 0x176b5a812f80fd5d, 0x08d4e7baea3c206c, 0xce0bc7fd25ebb646,
+    0x87eb9cbf9aa87dc8, 0x5ffefe3d242ee51e, 0x079a2261a3543501, 0x8453868495cf08e2,
+    0x8b0cedcdf2dbf53d, 0x7c51716c03f46f0f, 0xc58760979d18b70c, 0xc1963a18a7128adf,
+    0x2e0b83f9e60e1d8f, 0x65ca53db362bba23, 0xb9967d65efb1d3d8, 0x29ad48124f955251,
+    0x6445f6b6f29d2f1d, 0x8660e129e7d9f7f3, 0x266bd5e6a56b924b, 0xfd1e73516875263b,
+    0x7e7b2d3ca18bc754, 0x01dc498ab99f46b8, 0x286d2a585b9cec52, 0xedf55ce73d77484a,
+    0x312e418831cd7dd3, 0x7663b41d4876cd89, 0xb26fd81de0340c63, 0xa907d38f48fd70b4,
+    0xc85af2d422d11cd8, 0x76a5a160168e6b36, 0x8fd52b2ddd91b5d0, 0xe19e85a5e5d338c0,
+    0x28e3e123dc8f25e2, 0x50f23c142fdf8a1f, 0xd158c14e7545e710, 0x71bcf1c739f1945b,
+    0xf4415737dd6c4b57, 0xf83542ddd07a80a2, 0x67025a59f7390431, 0xe4a1ce15b92938ec,
+    0xe804f07a57882057, 0x6750e23bca4756b5, 0xb74a340ad465efd9, 0xe8c41e2d864d541a,
+    0xd0f3439e76ef2d4f, 0x7556e97996cb3d61, 0xcb7024e3a6d3a806, 0xf0f071c0fe3cf53b,
+    0xfa48783c1ece3791, 0x39503b005903d93e, 0xaffa0f2e3838ca31, 0x19a872372b939aae,
+    0x2ea410c860c3c1b6, 0x00ef1317f5b4e076, 0x026c6fc940f97ba5, 0xb9fbd87fb99de8f0,
+    0x493b5075a6de96f9, 0x977d9c72b5388e9c, 0xa52025dee13ee0da, 0x9f3b615a64f81e87,
+    0xb35856007571267f, 0x77a37d3448484aef, 0x798e974229d8d770, 0x3cf1790b40b81b7e,
+    0xedc1c58b1c872103, 0x3068f2ce6d09e531, 0xf84361ff8ca3a8cf, 0x010f4a6706daf521,
+    0x48f49584206dc701, 0x362c87efff2c302b, 0xe5e4d5482fbf0bf8, 0xd86884b7d6cfcad4,
+    0x70d68c3d5ef0e4c0, 0x2567891252bf4691, 0x8275fbbedd168419, 0x977eb1f66e3725b5,
+    0x3cc96ff32afa7f67, 0xa7d61bbfb7b6b74f, 0xb72f948571062e09, 0x8c2a719604c1007f,
+    0xa2cf3e6e4e27a577, 0x34316de1e99f63a9, 0xd02836fb91b2a574, 0xbb28b166c9734d66,
+    0x2a9eb1f2afa319f7, 0xc6f2c9541db492f5, 0x620d70b60a0a4a81, 0x276ef7542846aeb0,
+    0xacdcfc9de16c2562, 0x51aa7940aeac2a66, 0x27fbe5fd74988007, 0xffdbb66d9720206d,
+    0x926412e094e08535, 0x9d2262d719c99aec, 0x8be7c563f6cd906f, 0xf21c65f5845250c8,
+    0x44956b7b852803b7, 0xbcbc728265d16d5b, 0xae1a04befad9d397, 0x60326b1cfabc9874,
+    0x1a88f9c48c9a335c, 0x4522579340070f37, 0x916f5241be163bda, 0x5a8ad69bd5eeaec4,
+    0x86f9f9bf60e12b3e, 0x5e2e3a52c5ba5d8e, 0x33e84956650e6964, 0x102008aed929aef9,
+    0x5601629f65327b4e, 0x7ff73c1fd275824b, 0x084e35a65c116e6a, 0xb4249bc3aba2d4a5,
+    0xd1fba17a26e49ed7, 0x50258940c36fdf8e, 0xc174af925b39e41d, 0xce023d1d3675beca,
+    0xf4bc56766c2c880e, 0x2f0945da6073a29a, 0x0f58b06e98a140e9, 0x8b305065ee3caf79,
+    0x36dfa3e39431e953, 0x8669686c6243d877, 0x49e6379d63cc7b25, 0xb6086a2ee26d5297,
+    0x28ca78296b308b64, 0x58b125dffc55a210, 0x408f4e58110b5e0c, 0x4f252b54ac6389b9,
+    0x7ae1791ba99751b2, 0x0981dd713c85207b, 0x99d02e5e6f5756f3, 0x5d88b88667523bc4,
+    0xcebd0bbc98800ccf, 0x29a036c121169a38, 0xc87c7b600e9e5d2f, 0x40eb693e3c92b187,
+    0x32bfd4735d45f3b6, 0x1da79f47ac41284d, 0x4458542cb24e5504, 0x84320646cbcf19dd,
+    0x07246bae3d007dba, 0xd4c9928196f45fd3, 0x0a325d7ec3aae331, 0xa6a6b2c80658555b,
+    0xe6919c4d36834f6f, 0x680d39496bed3562, 0x817396e09d5d12bd, 0x697770fd651581f2,
+    0xcb0cd938b7630449, 0x0c3bb015bb350033, 0x430be1639346c1c0, 0x03dc722d714b17b6,
+    0xcba5a2680539d0e5, 0xd7b5b67bfd6c90b0, 0x39d57e341fd18dc4, 0x12ca76f2d8d57eb2,
+    0xb682f3225fbc02c9, 0x85a88ecd75ca2ef6, 0xc00606db0f48c64d, 0x502b6bd6b9ac690a,
+    0x51ed5a16d57489e2, 0xac0b3d604f3798fe, 0x1bd7107c8fd9c812, 0x4c229ec918871d68,
+    0xe78ff428a9722819, 0x3f8f24d5aa3f1ee2, 0x5947333ab212b1ec, 0x83341c4e3f4e07c3,
+    0x108984b4ed182dbb, 0x3f60a64f8d397e28, 0x82c0bd7e977e1fd8, 0xae4904e2dcd9c112,
+    0xa75c25aaf60aa382, 0x4cb83f1b5b9578f1, 0x55ddd760950b3b3b, 0xa74d9ad68172b6d1,
+    0xfe322d342986ae19, 0x116174ba4cc3595c, 0xf723da09edb534f3, 0xa02e3dffca62fb86,
+    0xa2109d2c9407d196, 0xa41def5b65544537, 0xd8f7cfb3ca829cef, 0x942750c239441acd,
+    0x37bdcd274122b2fa, 0x562dba807c8acb25, 0xdf18af24f535cc32, 0x30e230cb75bc6ff4,
+    0x11f71aedfead8b69, 0x952ee7a38c363c95, 0x27ad4a320d8745a8, 0xc69028a98930e095,
+    0x716bde947d7737c9, 0x631249fefa48c54e, 0xc3bfcaf5f701ab6e, 0xdd5b0304f3e2c063,
+    0x511c101f72de33ad, 0xde71d8da3969717a, 0x62f1af099dff59e7, 0xd07c96b875d4a1b9,
+    0x484617523e6c26a0, 0x1fc00e393794eb87, 0xa3971576d64750ac, 0xb1ec5e920f1f6f54,
+    0xb24ee91f6e7c1aef, 0x17fa87b07b9b684f, 0x0db46483e2350522, 0xa46046b745455015,
+    0xe70706859b38ada0, 0x982d493665eab400, 0xc0af2a032525a084, 0x039f0b01184932fe,
+    0x3da3a9b2d84cfeb0, 0xd4c1f02a471c7009, 0x7f75693924e75811, 0xcdd4e00ecddaf0a1,
+    0xfcd2e2d35c251c21, 0x96857d99732b60b2, 0xaf79e5779b00a6a9, 0x002ecb5025fb2186,
+    0xc235df7af5702e99, 0x8b72efb5d47ea852, 0x5c0b170c09ced4d6, 0x1955f615a1f89628,
+    0x8f63f61d1dcb95c9, 0x12f8db9ba89ee9e1, 0xc90e0af75337aeb9, 0x9baf2dde8677b774,
+    0x438b67d742137d4f, 0x1b20b56b45f60393, 0xc706984d00325eea, 0x96f364943fadb6a0,
+    0x809b73e380f12099, 0x7e8905f8cdc3b536, 0xae35f8bad17d37ad, 0x9940338020c19c3d,
+    0x0b3faf05666cae6a, 0xe1c9582695abf918, 0x692584fc4a2ddd77, 0xd9d6147a83b408a2,
+    0xd1a07b16ad438bc8, 0x2dd32cdf492142a9, 0x7bb7c27397c0f5c3, 0xd3bdd9d1317502ab,
+    0x16f5a322f98584e6, 0x2d04ae9c05151a06, 0x3c2d451f860a5c42, 0xc19857d7397089e9,
+    0x8d9f687a8ef8a2da, 0x92e18b3c0a1dc0cb, 0xf1aa1aa4aeb778f2, 0x7cabbb89169c0874,
+    0x7e7a02221ed28d83, 0x50bf7404b2f9ec97, 0xad71232d7dbc2ed1, 0x739e9b88f054ae3d,
+    0x4d06055962848f3b, 0xd395333a9661bbcd, 0xa3ecebd76e329300, 0x7235d511099526e3,
+    0xafb994710a31683f, 0xeb061a5e947834ae, 0x020c58f9ab5a8b7e, 0xe55d49ab0abe5adb,
+    0xba9619fc8752b249, 0x4e8e567ad890aa64, 0xf7cf6f9cb226788d, 0x2d943ebf2e365360,
+    0x5dcd5ec8d157edbe, 0x5e9098ae70d6d250, 0x48617214568af430, 0x2f6db2ac1adf4158,
+    0xdc98c3321aca50b6, 0xc1bba169c10e8305, 0xb8bd4b53f4297aad, 0x4ea08faa6115368c,
+    0x385615c782190b2c, 0x05306786464ee7f4, 0x7c3bb882d4ada04b, 0xdd187725a7267f91,
+    0x07d41f82de923d99, 0xa6c5ac8e76279af4,
+]
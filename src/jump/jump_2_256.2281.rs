@@ -0,0 +1,11 @@
+[
+    0x510de4d053913be7, 0x8d90d5d5bb0765a5, 0x845d2de5cf16ff11, 0xd262170f32b9a290,
+    0x1fab0c0291fdde96, 0x98da154a0cc4c38b, 0x478e3c7c9e57c560, 0xf5543ed20cb3274f,
+    0x2e30747bf2c765dc, 0x18129adfc8f78563, 0xc408bc2518cba55c, 0xaacd97d4ae23858a,
+    0xf6884f997a8ce514, 0x9f27a95bb4412b26, 0x0b3071947a3c82ed, 0x5f6caf2c9a67afa2,
+    0x101b2261522ef3f6, 0xf0661b9ac743ad9d, 0x4d2f75137ee751fd, 0xfa78bc566351823b,
+    0xcc5c5650f84267f1, 0xdfb58b39fa235f42, 0x3842711ffdfbad3e, 0xe1a13e7164a55a5a,
+    0xfd0136bb5f44dc2e, 0x024df068bfd3f209, 0x43cc316f156cabb1, 0xfcdf0fb088fc0a08,
+    0x4276fa0ebbbc477e, 0x3b3866dc3fdf474e, 0xaf1ddd001b02f515, 0x0ce4b0789c3a15e6,
+    0x568711f540d3db1b, 0x40712143958fc04a, 0x4eba0142354168f0, 0x331819a9337e9870,
+]
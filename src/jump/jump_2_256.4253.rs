@@ -0,0 +1,19 @@
+[
+    0x208265eb5bc7f0e0, 0x43685c921aa8642b, 0x7ea27e48a0b60e57, 0x29104ecb477195ad,
+    0xb9d74515685fb170, 0x1c82c847a54525f8, 0x1cbc7248b60016aa, 0xa29e2a4500d19eb3,
+    0x48bd641a7a35c142, 0x1556c5615f13cd75, 0x14ab62219fc6f526, 0x08c622f7b1d70f4b,
+    0x387a9edbfbfc17a9, 0x2744e39faa465035, 0x8de7ff4a630e6b28, 0xebc553e29e9b3c93,
+    0x507a9871eff97b95, 0xa307440322a371b6, 0x2f5f2c812027f453, 0xe64530f3d2c4b0ed,
+    0x2c551f4e9f7efe72, 0x4f2e9cc61553a1b7, 0x836a86ab568e1417, 0xd537916f184ca4ac,
+    0x6b81d5ca57ff27ed, 0xff16d1a9fed8c7dd, 0x2572005d26ec1b44, 0xf837b0ca91715e76,
+    0x0eabb22250326b3a, 0x20d5bf0d9818f117, 0xed962160357654b6, 0x1f1333907fba647a,
+    0x3e7bac96e450b949, 0x2a6b8e482a540931, 0x722faa86bb40bb5e, 0x34a19cee21c10549,
+    0x73b9d13cf13e269b, 0x38532cbaacaff8b8, 0x17a9d2256ba6b3dd, 0x9f6e71550a82d819,
+    0xf6e3daedfafed3b2, 0x7ee2088652e3d3f9, 0x642d15c72179f52f, 0x5e50f95c3a7f77e9,
+    0x52a902b3c4bad29f, 0x66a8246a319a2d10, 0xdb2594741ceddd76, 0x750fbb845e7b860d,
+    0x242ac23a5c02d4c0, 0xfa89ef6c34e8ad58, 0xeb0bafc0cab9f1d4, 0xf2dbc86199fae712,
+    0x2de6688c1123c8e8, 0xb244369bf9328d1d, 0x964f8f77dc198863, 0x3e7b7421f4bc3c74,
+    0x79ba802de49c0d8b, 0x9982c1f5cac84b58, 0x8717513653379af6, 0x451599942acbcde9,
+    0x79eeba433132a51a, 0xfc16d053b1409a13, 0x9c55a3b80efd21b3, 0xfea1dc3a6630573d,
+    0x5225370710acbce8, 0x4b1e5a08be2b5c36, 0xcabf4e134a89957e, 0xb78b41bdb05206d3,
+]
@@ -0,0 +1,176 @@
+[
+    0xae52f6f84a9d7b9f, 0x7c5af72620b569d1, 0xf5314ff8d74661a7, 0x8ffd614d78b33906,
+    0x793363ea25cee079, 0x6d2ae2ac37822dae, 0x89b4018f590a0ba2, 0x4c35d9976698f8fb,
+    0x6335c4a419df5c1e, 0xbdd4d875d3dcc353, 0x75efe9215cd0d175, 0x8e02ea39211989b7,
+    0xcabb845771247e17, 0x34ad128872786eeb, 0x53308ce1f657f060, 0x2d68cf5caaab7ce9,
+    0x5176560239f1c7f2, 0xc43525c16313be1b, 0xc665c0741c5d3644, 0x2b31aa7cee3dec09,
+    0xe08868387f5abce1, 0x6ac6eccbdb59a159, 0xace6040340a22a9b, 0x26648a87c900288d,
+    0x3fc445f8e5a84374, 0x8d70fa8c612170fd, 0xae348d2dd0ddde06, 0xd479bbb451656668,
+    0x4fb210c2fdb4974b, 0x619cc71723f69f52, 0xf61be250b2d44d76, 0x8a0c2cb3893865b6,
+    0xa2f155ecb84dd9bd, 0x4255ceee9b39caba, 0x1d20f0577428077d, 0x9c84fbb0137dfbe0,
+    0x8fd48c532f586af6, 0xb09ae4e8e369796d, 0xda7995bf777ddc00, 0xee8fca9cc1ea02e9,
+    0xcb0bdebe5898195d, 0x43dd5cc97f2c13df, 0xc39a0240b6af7351, 0x4185b8eea79e9399,
+    0x8932f7faec0cac16, 0x0e14009fcca03f2f, 0x041c99fc525f1831, 0x8694e56eb245a6e2,
+    0x993feddc55bd8e4d, 0x355b0b44a623db5e, 0x29fcd015c62a7c69, 0xc00a1ff771eec7c5,
+    0xefbafccc818cc67a, 0x382777fcf60444cf, 0x74674f7795fcec84, 0x16b9853b30550c8e,
+    0xc7bd8a754f402007, 0x942f9a0ff90748f7, 0xecaf05661325e371, 0x7a06b24b5e01bfbc,
+    0xa5d201fda2f68ad6, 0x52d464141d145f36, 0x01f71d20d996062f, 0xef45e433efa3e56a,
+    0x924151920831f6ee, 0xc0b87acbb4b95769, 0xd0b8515cb1f1410c, 0xadca04b229d3641e,
+    0x7d5cd396cda44bc0, 0x4be9f2360a11f0d0, 0x3a2443831af00789, 0x49a1aeadd226796a,
+    0xae7af3850f7f5fff, 0xdc617e0d796c36c5, 0xbbaf35db0bfd5bef, 0x0515a949de8352e3,
+    0xe1e4e07d7678ab14, 0x683d3d61bf23db7f, 0x91d908e75f9e7cc1, 0x821e2248838d604e,
+    0x3b2d58609c83c3ed, 0xa8d97bae7b256dc4, 0x853a7753d8b398a7, 0xfdd53f61efcf6d00,
+    0x05e59236100cd80e, 0x4b3b1732e694a1d8, 0x4416757739165da6, 0xc9aca9d93d82b0bd,
+    0x76f5df6b2f05b734, 0xf2b79eac598e4028, 0x66fe38c7655ef954, 0xed13f6b6505a8401,
+    0x8bf6cc1db8356dfa, 0x79c0833f1594ffb6, 0x6d3db7bfcc015127, 0x8f5f965f83869c95,
+    0xf7f7d19f242df229, 0x9610a57d7a351589, 0xfcbaa0c3e9343336, 0x0799b5292b786278,
+    0x99c6058df7cb8eba, 0x48e314c4e6d2007f, 0x103e1dde8495cc06, 0x9adbb1d94d9522f1,
+    0xeac738d2f9b7fe1f, 0x6269e07e1281734d, 0x7a8b0656368958d1, 0x9c891c7c8fc94ebe,
+    0xc4fcda8d5f03d96c, 0xb175397f43b4a651, 0xa02e2c1cf1616aaf, 0xd734c509ec2ba189,
+    0x90aa9369f238e236, 0x020f39e0c36fc3f0, 0xb00de64495e380d5, 0x091610285f7eda8e,
+    0x7151d10fdc46bc67, 0x6c64021fabb06440, 0x57639c4fc8c7061a, 0x63fdc439634cab8a,
+    0xf641a490f4a15b11, 0x7cf11870ad9641bd, 0x9a14aef892a8ed8f, 0xae9655f171275538,
+    0xbc053c6af1c65b7a, 0xb1dd1a6fff917f67, 0xeb2015e30bf69fe7, 0x8538a673d0f2176c,
+    0xd5475bf843d8e8ba, 0x78b7fb0d7ccc2c93, 0x056fcf2f614bf53b, 0xe463c4fd1665be6c,
+    0xd163da822b74eb69, 0x9f144edbbb6089f3, 0xa2ab14d34ce0c341, 0xb3a2af9d684ecf45,
+    0x1b55fdc691acef79, 0x2df336ffeac8a8a0, 0xeae4a4b52ee6007a, 0x56baf63eacb1a41c,
+    0x391380a093f98458, 0x605d41863ade29bf, 0x880585188409e1fd, 0xea73d4593a090e4c,
+    0xcd00d8849b2d89da, 0x354388b5c151f03f, 0x3b1cf8d2aa410b47, 0xeb93be59506be679,
+    0xd19757bcd4f0abc2, 0x5eb86aad937c5860, 0xe3eddeb9cbe7c45b, 0x170b17020455ca00,
+    0x47af2c1e1fccdf85, 0x2153428ada649c94, 0x4c3da493ea6d7016, 0xce74c8f2d5388a66,
+    0x5880d9e07b636bc8, 0x034738a3da55b20c, 0xf0e1f0e9401c53a9, 0x031e8780d3f5da78,
+    0xc518db0d23a99e45, 0x284e302e4553b74b, 0x3110bf52f6254103, 0x687f65c8189d3b27,
+    0x3eab95bb76827c8f, 0x53c35360ae742f67, 0xb416291364c02f1a, 0x9cf077d4195d3cfa,
+    0xa1813cf3d68ff8fb, 0xb1b25ba1bc22ba62, 0x3037e121ba7ad964, 0x5acb5918069a5440,
+    0x187cd46161075bb2, 0x28c28ed912eb4239, 0xaa3587e3a2d397be, 0x06a40b70808bc312,
+    0x110a103d9347bcc7, 0x5f45910c6295f60c, 0x314448f4375b2725, 0xdcb7a8759cb858ab,
+    0xe39731091d484420, 0x4a853452d284dd47, 0x217399c78ddd1211, 0x96ea675030fe9fee,
+    0xd3f2111ea0115ec3, 0xd87489c7f797c7d6, 0x119dcf82db396b89, 0x5a6572f081fe653d,
+    0x33f831786bf15caa, 0x7840117b01fa476d, 0x2df998d24e437c45, 0x56186cd6a3d7d91d,
+    0x626b548d60e51747, 0x9ab89e775b8216e4, 0x0f03931c66a056c8, 0x6e00d31c83ae3c2b,
+    0x240d2766160d3efb, 0x8e7108a407a90f89, 0xdc4ca41312c33738, 0x49e744808926168c,
+    0xf85d213a0ed79e55, 0x9dfc06e01012b42a, 0x4f61d665cf228db2, 0xaf815a9ff6f4669c,
+    0x963716ad9fa43db5, 0x5fbbd349d9fd4177, 0x4196ece9794a63c6, 0x3285b9d17847b265,
+    0xb35e64fbb7afe83c, 0x91da4750bebb28f9, 0x5bb528b415ee9c8c, 0xdc9bc4731b7ac369,
+    0xbc44191475840acd, 0xf19fe4e9e3616660, 0x001077e2bfa2d92a, 0x6d86fb108532904e,
+    0x1c6e88c05b9c834a, 0x8c774a1ce8968312, 0xac0a85874d07309a, 0xf1d8bf5be661edf8,
+    0x3d2a266875f92e9d, 0x11ec7365a3457ae0, 0x8b304b3ca50779be, 0x1b0a8577df560e92,
+    0x65b87c2676df298c, 0xfb08cbdc2624d889, 0x97f8d6a05d06950a, 0xc16ba9516bf5aa7a,
+    0x299fd574ac2a38aa, 0xb2de7c5567adac72, 0xffaf8ca71b8c9b45, 0x389a3cdacf89a583,
+    0x7e57dca29c75addf, 0x53ad352aa482878a, 0x3b1782cfb38aa1ef, 0x25e59a5ccb410423,
+    0x4ff9f5f3e69ca9aa, 0x350f903885ccb3e7, 0x20d77f81f3b04144, 0x13a5b2e650e7fb99,
+    0x24da027b3432083d, 0x7cc8b8f5a3bc5829, 0x03253b2736488b67, 0x694feea082ed1b33,
+    0x1b0c1b97bd578bec, 0x001ebbca499b9a3d, 0xf00e1699501a4297, 0xdf8057abd6c775eb,
+    0xfb6b23b215135b9d, 0xd0bcd3adbfb08a0b, 0xc38b08af76b1c0b5, 0x35579526a03b4037,
+    0xcd4ecd402d241668, 0x5152eed3d19638d1, 0x8cdc37f12c724d99, 0x55b7e26538ce57d2,
+    0xb15df1a35f77f3fd, 0xd7b959b320102e34, 0x514f88d5ba880a1e, 0x592e457d7195879e,
+    0xe139c52ee816afd6, 0xd6b3973cb36ab6ea, 0xd30a3352641f8940, 0x002a4ac1c0f89ece,
+    0x96de8b41b05519fd, 0x8823d65637eb2225, 0x9e2948b0bd26c3c9, 0x977b268cad9a6655,
+    0xfe3c43d7fc36080e, 0xb5b6f3f944df2259, 0xe77b3fe5dd1dc92e, 0x5f1ffece7e308ad8,
+    0x153cc34a8647e865, 0x7357e1f159b00792, 0xae68e76d8503669f, 0xebd9f96c2f4db771,
+    0x7ff975a91b9d3226, 0xf34457acad095fd0, 0x2e71d41a836c74a8, 0x4c81a16112882e2c,
+    0xf381fc6dcd57fb4e, 0x18da1bb9e26596e6, 0xaf70e255b1dd2b8f, 0xd57d274941bc7a17,
+    0xbeb2e9e45a8d2cff, 0x60ebf74897d682bb, 0xe2bc454c7d00b382, 0xb3151953e416bcd7,
+    0x5a5991eb99b6bd6b, 0xe468c2f62a569d10, 0xe314778fc1a7c77c, 0x45f6dc15487f61ca,
+    0x943563da98003269, 0x786f2e9724bf0913, 0xe4170fcab12db5b4, 0xc177fd906a68eadc,
+    0xce289e44e6ec1b0f, 0x754d81f33385f564, 0xd8d68e864a6b4a3c, 0x2c6349b23206916d,
+    0x8dec28b0b2032b7c, 0xe497d1ca7a056973, 0x1cadb251913be979, 0xb1315ed157a6540c,
+    0x089990893a522e20, 0x79757b8dae5a8057, 0x6a8f964c3791a5a7, 0x306cef0b570e1bbd,
+    0x7307f01b99d376c4, 0x2e96ba3e8993e680, 0x764a7c6f8247dc70, 0x16da5bdfe169b43a,
+    0x4c32f8a760cc5f63, 0xe4090d8475c08b74, 0xec83284da05dd365, 0x091ad38f9e6dcb00,
+    0x2b1ece058d0ebf0c, 0xbc044f2c08ed9649, 0xc206becb9c85f2e0, 0x52d14636a1b55ed2,
+    0x63d94e2d0341cecc, 0x755ae56dc25f4e0f, 0xd53d955e991cf23a, 0x03d5e0c85623c37b,
+    0x9995217b962b0bfa, 0xf862ca76e167ad2f, 0xdce15c6e0f23c948, 0xf59320e20b34ee67,
+    0x1e949bd0a79dfc00, 0x495a1636be32b0e1, 0xde6e3939e95a6577, 0x67deae1e617c4cc1,
+    0x44e71e3e1c902629, 0x74dd4b8ca169f91d, 0x83da5d3f58ec2e97, 0xb28c873758fd8519,
+    0x989acbe1ccfbc378, 0x3caa15ab674215e9, 0xbb45aaa05bc05c69, 0x0f24ca9ca0a1d73a,
+    0x9adaf498ab2eda19, 0x0073aff4ad5a177b, 0x0a91465276db4e17, 0x131ab0f5c142351a,
+    0x59203acf1ed2030d, 0x0e7c3f00433053a5, 0x2734dd1777e19546, 0x7ec6a2e69dadb3c0,
+    0xae09ab870363650c, 0xd369d5ef0daac47e, 0xcfb845279e97c3c8, 0xd4a515f15573cff5,
+    0x6cb1e421d4e5654d, 0x1ecefa0f965d80db, 0xf970f33844f4a5db, 0x779d7e6107b8e251,
+    0x29bfba2474551712, 0x85a698836d23c29d, 0xb210f612bc453d88, 0x273316f241bc67c7,
+    0xc60417a2b68679a8, 0xa61146019f0bbfe9, 0xf1489cbeab7690d1, 0x875f505bfc1a6093,
+    0xdc31ebb0564b8514, 0x7efe40bf92dd0f8c, 0x3669c26f1af7198a, 0x3a97330aac4a4082,
+    0x2f701c6e06fdaa75, 0x24802404726ceacc, 0x4c385890d6538bbf, 0x1ae5b2f6201ba179,
+    0x54ebcc8d61388502, 0x10bcf9521918ca9c, 0xe312e80b7cfbfcb9, 0x41c9176926da7f19,
+    0x8033e58664cf9e8a, 0xd1146824354f5317, 0xcd7e857a4b37aebe, 0x683048c64a695daf,
+    0x7beac5055b6c6496, 0x4dcfdfba077d3249, 0x35867231e1579c7f, 0x9df8475dc4ca0e47,
+    0xd9de5d9a83f89512, 0xf6803944b425aefb, 0xd7f4526ebb30c011, 0xc6ac4d6ee938295c,
+    0x50d671216a301df9, 0xfd1152e893fccad4, 0x25eaed7ef0e0d6ac, 0xc5b7b7828114566c,
+    0xd80a7d67096c0425, 0x712d12c9f5d2b7d1, 0x857ebff6854c68f0, 0x4d179293b701c5e8,
+    0x137348f6b08a4658, 0xea3470704604af3c, 0x0eded4c0df0821f7, 0x747f4e6aee728ebd,
+    0x9294190e1af5a5df, 0xb14662a8e2bb33da, 0xdc87740064600d96, 0x718d14e8d03e1c71,
+    0xb8524090786eff0c, 0x751c847c36bb7ce9, 0x43ef2cfb4bba505d, 0x55be005520628cda,
+    0x3ef2227f379bd590, 0x92ab8c67358423ad, 0x4bbcfe2797c7609f, 0xacb3a29a6eb6398e,
+    0x0bc7af10ba4fd8a5, 0x4c1576dc0c73fc85, 0xaab79dff2e123394, 0x8aafb501682e6609,
+    0x3a13a56f8b21841d, 0x24e6d5b9b211bb40, 0xd10943574a6d5eb5, 0xcc814c150c909665,
+    0x545ad9eaf1c6b515, 0xdc4eb1f1b7dae5b7, 0x254664e078297902, 0x149b0216bb827f47,
+    0x969f568d3999723b, 0xa3a1c1351c45a50e, 0x02f3c5fc0e8eb840, 0x8d41cb73fd82e7f3,
+    0xb3161a4233ddb883, 0xb490fcd1d169a54d, 0x1af137509800d582, 0x336156f72e118bc9,
+    0xf34f6668e92d1a60, 0xa32d7cb30a142999, 0x2e496a510d8c6389, 0xe1bd5d44a76df764,
+    0x98289eba64e98904, 0x9c297b339d935641, 0xc7995abc9b1460a8, 0xe039233947b749bd,
+    0x1c83cc63bed5ced4, 0x10864f4b1ee39720, 0xc0dbfa5390018ba1, 0xb9f6e630e1abed75,
+    0x7cb61e59475c20df, 0x6f20aec655265466, 0xa32409fbfd2b4cb1, 0xf8dd70e610cb9574,
+    0xcd56d63c7160f5de, 0x80d9b433317d330e, 0x26a24744ddb18e46, 0x1ccba9b96dde1be4,
+    0x8d8b187c0d1d9803, 0xd36f7f72b737d160, 0x0322e6866c2683e6, 0xbf910fc26a628224,
+    0x8befd26834053983, 0x7509b206290eb534, 0x639c29f96bf30022, 0xd6790ad8873369a5,
+    0xc7fd4bd9c52fc88f, 0x401c21551a6161c9, 0x20238e1417b2fe12, 0xa49b030844121d5e,
+    0xe7af2e07e8570dce, 0xa965a04c3f306f63, 0xbcaf6f1bbda845a0, 0x7024e830147ae080,
+    0xfd305d2a64bf6253, 0x2a12eaf63bfd21b2, 0xf26536aa9b92717e, 0x1267e6d16969c896,
+    0x6eb878e85632e564, 0x9a8e868d38aa8dcb, 0x2363e1293fc62338, 0xdada01b6479379af,
+    0xc072d38aaf8cea68, 0xa229f3a1b8eef786, 0xab936d38c7080a39, 0x89a6cfe60ab87f68,
+    0xe6a3ab329ccaac92, 0xab0c285bc1c5d8ba, 0x06a6f6a9e4baa3c7, 0x70b174c14c00544f,
+    0x41858febf5ef8122, 0x48d70b26fd7df187, 0x0f0fbd913c7456da, 0xdde133c32bd76d50,
+    0xb64a391f84e2edc1, 0x94e24eb8156fa4ff, 0x7e62cd92b19c871d, 0xd75b520c41529e07,
+    0x05b75a6c9fc91dc9, 0xa8a45d487bfad3a1, 0x9ad0fb82dbb93de5, 0x6226a6f22a957d42,
+    0xc1058359914eaee2, 0x7db477656dfcac01, 0x51c5b5f36b0943f8, 0x249110dc83036976,
+    0xca3efc2dc33030e7, 0xea1ec2ba6c4bfd3e, 0x1d7a10ac06409c1d, 0xdadc4822be3ced3b,
+    0xa8e68ceb3bbc19ed, 0x428dcd2e7b89d888, 0xd6fe172d055b143c, 0x0a0a86288fa75d8a,
+    0x44270b058a9e61bf, 0xc958b2e923d79d20, 0x77dac7ac8d0b2f15, 0x3111bb174af3d9df,
+    0xe8afee2cdd1ce528, 0xca72084891e0de66, 0x1f6bc9e83bb60f16, 0xe15dda1db5525bf5,
+    0x827d7d036555c1ea, 0xd124202581476202, 0xe5b0c1fabd13f42b, 0xf49f5666186dbaae,
+    0x46296ac1f0ea5c2e, 0xce065e25f3d29994, 0xeb7b0d4acac5a747, 0x90bfee6c1b08c545,
+    0xdfb4a6c09f5becd6, 0xc88741727061feeb, 0x0c99865db5197499, 0x85f1c7b0c90919df,
+    0x3919d63051307073, 0x999774c853f102f1, 0xd34c584989881d3c, 0x11c95ebfa78b357f,
+    0x42b1f09fe332a602, 0xa197b5f27d4aeef9, 0x2125bb050917df47, 0x1421a79ae0a7fd3c,
+    0x84e6268a0a5eb924, 0x8ebc3078fccf592f, 0x5202f392b24b11b0, 0x008f80f6406f6480,
+    0x7b1099b7a93dc0eb, 0x3babd20c0aaaf4a8, 0xe66073b94622b8c9, 0x4bdaa1811a36054f,
+    0x91038bca171907d1, 0x21de808624532fd4, 0x42cdb28632af6d4b, 0xade50c702fe6048f,
+    0x536935ab22b13fa1, 0x04069047a099bb8a, 0x8dc4441c0a4d92c7, 0xd8393c2bb4f68815,
+    0x0afbb48f7696e89b, 0x4a6ab84a156c5d17, 0xca19ced19450db62, 0x54f3ed08f1c9d212,
+    0x1b6d1036b73bc09d, 0x1a272ac3eb6caccb, 0x7f5d1fea9b79a9e7, 0xb5d22f1f881f8d9a,
+    0xa526b2006de217de, 0xa03e91eb56821f0d, 0x5f45f7b5f6c9dbec, 0x376bbb228a23995b,
+    0xe495cfc34af463ac, 0x76c22a3f59dffc97, 0x0aac46da90573f31, 0x1670451a9e394341,
+    0x4f0c69ab55b04dcf, 0x8d4d8d43762439b7, 0x49fadf384096ffb8, 0xcbb073993bd02b67,
+    0x015e64f3fcd30c93, 0x1b8f9fbcaa0eaa5d, 0xc455aa6dbcb6fbd3, 0x2c6f1e282586a320,
+    0x33af934959247245, 0xa5ce77d9cd8f115b, 0xe1444627009256cf, 0xa36176cb762ab401,
+    0x8ec395ba318f60a2, 0xa8ff31b8537c95e1, 0xb8a5291b6027ac6f, 0x7c8a3cc6ffbb35c1,
+    0xc2a2cb399c961f7b, 0xf97efd9ff0904800, 0xa2c904367ef58a93, 0xc814a6a262883f8e,
+    0x379845f0849f006d, 0xcb294166886edae9, 0x5dc4a01c696a6a45, 0xcd27f3eae3e56588,
+    0xa04e44e7a7e6220e, 0xcc95fd62a6660185, 0xbd7d7ce50305b075, 0xb72ad4ae7403cc73,
+    0x440f1e97d50ba39e, 0xbb503a3056cb95e2, 0x9e6794994facecb2, 0x7ba2d64723cd45ee,
+    0xc866aa552b664fac, 0x53cc0c3079da5d6a, 0x8124b8b2b45d29c4, 0x8e4d821c678c71cb,
+    0xf0810a4327e9e71d, 0xa4a762d482be670a, 0x34423d34e9a6d03b, 0x6eef95f13596b3fe,
+    0x7ab16a9be1df2fb7, 0xf88f1de4e96faa99, 0x873643f75614e519, 0x09248911180cc412,
+    0xd6f42e23cbd67182, 0x791f511294bdd460, 0x71fbbb4cf8bf4183, 0xad5ca9b3ba91c763,
+    0x010a7d703238114e, 0x7a7a62c57ca54a72, 0x37e4c3f91b863da8, 0xe410890285811844,
+    0xcceecb867053b578, 0x03bc7cc5cafcb18c, 0x4ccdd4de12d3bb51, 0x3f7d682fa70b5437,
+    0xc7231a72c4892efa, 0x2c066bd4c121ad08, 0xc5747ad315777a69, 0xd7dea94d44d46116,
+    0x975c8b9941734d5c, 0xce29ddd53bcf0924, 0x1776bb1d25e09584, 0xda01800736a45f66,
+    0xbbcaed1589e04f57, 0x73dbb0e941536b92, 0x48cae122a115418f, 0xca5e977b1f6e5181,
+    0xfb18b23f588fd7f8, 0x6f590ff0b0b95e52, 0xb73e0ad325fe4170, 0xa810c8e29e4ccb89,
+    0x0e598466a1bb1c54, 0x7138440920787337, 0x9447595257bf5b5c, 0x41daa91a142eeb29,
+    0x6738d13e59369f19, 0xee74df345db349f1, 0x3f5a837a6fef9909, 0x0ca4a846aeb2a0e4,
+    0x483ccaf04282cb2f, 0x90702730431c194d, 0x09eef6feed8dacad, 0xe5daaea556e9e0ab,
+    0xa598678b8933556a, 0x793c31a255ddd1c1, 0xc885e6d8820a0834, 0xe84c18bc3eb8191e,
+    0xfe2b2dbab96c9368, 0xdafe05dc57a1ff6e, 0xdce40ebae3ebb50f, 0x5b7207f6c829002f,
+    0xffc7282b815e4d6c, 0xe608f845c115f867, 0x6cb3007ac7e922fc, 0x96075bbf0cb48285,
+    0x81e83d8934275913, 0xd85836247d1b7e99, 0xc04a6215183ff5b3, 0xf44a2b8b12174d28,
+    0x26e5d3eabd2c3a88, 0x24961f60ab038904, 0xcae12c1893c207e3, 0xb109bdedb8115806,
+    0xff07a8136eec4085, 0xf01597bc17274152, 0xbee88b201b9a7c81, 0xc0343aca0178a3d3,
+    0xed21ac4b0b0dcc4a, 0xcadb739a2df0687f, 0xe6a7095797bef11c, 0xe7ba7b556adbaec4,
+    0xee821e80c009cd4e, 0xee061708d563113b, 0x1e5052cacccd453b, 0x3944ea9ba142135d,
+    0x9ca79408b43bd927, 0x901b707ed98a120f, 0xb626b9f119a7aa53, 0x368528a6039b34af,
+    0xef20e835334b781d, 0xe9f065774c434b3a, 0x83e1d21bcf05ad18, 0x7001de18044948c3,
+    0xd9d27d1a654ecef5, 0xad43a50850e28ef6, 0x5557702dfad519c9, 0xa17e204e6ba56502,
+]
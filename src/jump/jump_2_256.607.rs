@@ -0,0 +1,5 @@
+[
+    0xc4b884bc8218da15, 0x5cf400ef7a99de66, 0x1288212ebac67c7a, 0x24e2912a91547285,
+    0x7dec00836efaf82d, 0xa2c424f72d6ca5c7, 0x7b5468735d806ed7, 0xdd0bf43c38a72b04,
+    0x30b82eb8f044b9bc, 0x21c7a3327a170c4d,
+]
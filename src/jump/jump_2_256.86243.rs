@@ -0,0 +1,339 @@
+[
+    0xd5d21a336fe70bec, 0x2dc161fbd16c8f4e, 0xc36f084ab75a2a95, 0x782d25ad985d4284,
+    0xd79eef4f6d9e9970, 0x1349eeb1d5a51c81, 0xb7bb021e4cdbaa92, 0x0ddd96d4ab05e1ad,
+    0x9873c4e8ab20a9e5, 0x06880541c80ea129, 0x35a4111149f12bf3, 0xb981807cc4d83705,
+    0xf2ed3abacc9edc16, 0xbd87a93450b16410, 0x376e19c0e1fcf8d1, 0xacf3c9dff10383b6,
+    0xd52d6bf7df47e9fd, 0xf7316302acea3c0c, 0x08513cb624100106, 0x6cb6be488a68f55f,
+    0x37f997faf543addc, 0x85d0761f72e3f952, 0xa69f8c3adc2cd06e, 0x9c7aca2d060a54c2,
+    0x31145deab0d7e611, 0x2851b5c448e26059, 0xd734823413df24a2, 0xeb3e7dc9ec926974,
+    0xecc27d5a907bed19, 0xa37c3b04273c69aa, 0xf08353c8e3c06b1a, 0x0d36fc72e65ab7d1,
+    0xceb4de623588f0dc, 0xfbeba433c681f977, 0x90e3b407b7c4efea, 0x6e667936fb6a3620,
+    0xb243dd5d0a2886c0, 0x84bcfe0ea41b8c2f, 0x00fb8c7871971fda, 0x5efc0a1941079d5a,
+    0x60b8e6484e34ee12, 0x69e3f41750106e54, 0xf7e61ee99561762d, 0x49f0d506757b44c3,
+    0x0dd7212a48430db6, 0x39d814231891e2b5, 0x9f3cc4ce75cd60f2, 0x32298958d2ab2016,
+    0x6d0e09e3672efb26, 0x0694407a2f5bf998, 0x96a582ac23108bb7, 0xb4fc945b5013d4c1,
+    0xd530e379f96f6bcc, 0xf1a0d192c6e11a3e, 0x6225a8db6e8a2de6, 0x8783a85e34b1bdac,
+    0xe58003d320c7d608, 0x7febc87f17efc56e, 0xef15c1382f2d12da, 0x4cd9ad8cce9c911e,
+    0x24d2686c5b72f19d, 0x7f9ba71240628602, 0xecc7ccb58b0d9a7a, 0x5a5ca7d6b7654af7,
+    0x93fd9f98e0c2e79f, 0x1cadc750645c4e4e, 0x62f72f95448bc659, 0x5726314999396da5,
+    0xdd15d6a8534054d1, 0xdcfd31319c7a59db, 0xac16bf6d2c75a140, 0x0d23e7a6960915b9,
+    0x2046e482700d2074, 0x50965437b94e972b, 0xbf2278049ea5a613, 0xb79cd51848a95915,
+    0x42b67631a82549c7, 0x9c6063d55c58df5f, 0xa08bc2d0d6ef5625, 0x7eb3c7f54fc75000,
+    0x17fa7eedacbb2fae, 0x95317eec650f9726, 0xb6d41e661dd97e66, 0xc5f9f87c8235071b,
+    0x972e8dab16d115c3, 0x07b7ea0994252369, 0xe082a12aa4ca84e3, 0x9f141e647dcfb46f,
+    0x2872bfe90802fa61, 0x314fc6aacb4a704f, 0x08d99528c819d3b0, 0x2a048e791f7a2a67,
+    0x2e9f7d26e625fc68, 0xea3dc0b048b43909, 0xbebf963c3215b71f, 0x1a2a886a5f5c3f7f,
+    0x6a89360c9ecc8169, 0x47004655f4c414ac, 0xe2d80698e2d162ad, 0x2a1c3ded1cdbb8cd,
+    0x3110f8f918552fcd, 0xdabd17686b97cb46, 0xffa96f679f1ea758, 0xd267a525d7178e4a,
+    0x6d31a0bcafc4e8cc, 0xe0f3ea13b7918ffe, 0x8c00e9000fd20093, 0x14387e61ad1eec5f,
+    0x57001ffc57f7dd72, 0x56c84aeb4ad0a49c, 0x3f01755444d5d2fa, 0xf74852575f3538df,
+    0x6ae06ea6844be1b5, 0xbcc7848fc05d8332, 0xac9a72d54d9fe59f, 0x18541b9689eab8da,
+    0xc717dff0f0dc3e95, 0xe72d93b007834552, 0x27844c53bb8e8bf2, 0x117ab33cc3e0c46a,
+    0x9e6907969e4acb37, 0x1afc31a0cbef412d, 0xef830d0af6b3a951, 0x457cbced108585a0,
+    0x467d152d6407ea43, 0x8a7e9336b1611801, 0x28508f40c32123e5, 0x0e12a9c98c925c9d,
+    0x6e3d7a073daa45c6, 0x9c7d9f8807c5ef09, 0xc8b31d5ec2a15405, 0x352467f7508e796e,
+    0xbe98fe88615752e5, 0xf047537722bff168, 0x7781b719de9e0f3c, 0xbd8cf1dc46724ea5,
+    0x6d5b8e7bc71d5170, 0x695306021415af83, 0x0e2a6d2b732e14e4, 0x0966e547b6cea46a,
+    0xfd7b2366de43ad8d, 0xa43d4cad21658eed, 0x17798c7840c25073, 0xa7ceca73452bede4,
+    0xb6abd70828470525, 0x1cfe3f79cff032f6, 0x89372764fb030270, 0xc54f7eb4361fbb28,
+    0x7d82f841a4beac44, 0x9dd847c32e1e2b03, 0x9df253dda17e4895, 0x29a4981129e12daf,
+    0x5ec710a350268826, 0x29414a9dea10d4e1, 0xba3d18bac10699dd, 0xf936dbaba119fbc0,
+    0xb687ae01040644a8, 0x3b80e362c16f4854, 0x5e307cc05b98cfd7, 0xf603f0343beaaf7c,
+    0x0d7e03cdadc71dce, 0x223754bbdd997666, 0x3a349f9ce284b9a7, 0x84ea8fa049949a80,
+    0x5015261b3cae7bca, 0x8843366085dc9909, 0xabcfd90e805f9ae9, 0x75efa59c36dee761,
+    0x707fc02dde2d42a5, 0x02420d2e3788eba3, 0x4a31ad39ea752150, 0x7a3e8e6cf5c01336,
+    0x44480c1bc5ce0631, 0xe1f9da8181f8ad4c, 0x7afbc1181fce0e6b, 0x0d79b1ca13f4789f,
+    0x07d99834e3d085d3, 0x3bda9fb3e99f44b3, 0x81e6b600ae77b094, 0xd79aac63282f5497,
+    0x914aa0f9991d0e5c, 0xa09b4a718ef89dd7, 0x38d384cd1df43e02, 0x77178d4698a12b87,
+    0x6b8465742f427471, 0xe19203199d214c06, 0x6476659a9e92bc9b, 0xb2e570e7d051ad15,
+    0x034512e71ce12328, 0xa39986e13226a444, 0x4efb0d82c35d2a3c, 0xf81b499bf510d88f,
+    0xeb74c180692f94a6, 0x6a0202e90db9f2d3, 0x3edf673eb86fd893, 0x9f384693fddb37ae,
+    0xbd604cbe3cdf3527, 0x0bb8def57a33c996, 0xa2ea2d6f7064e54a, 0x81a054aa224fbb84,
+    0x06f536e5fb9b4773, 0xba9c512281300022, 0xe550916dbac120bb, 0x3e8f5165f3b71fe1,
+    0xca5a15c5ce3023fd, 0x94a8e26d56053e0e, 0xc19d92714328538e, 0x93ec071b7c680268,
+    0xdf7988dd6c3f30b0, 0xde1b71452dea3bb8, 0x2e8a669a742ad9fc, 0xa1e36c99171ddaac,
+    0x0b80ecfd6253dd67, 0x3170789d83a0f288, 0x087a5aa42c1d9dd1, 0x620c214cb4f34ecd,
+    0x0f56f3ae64d91144, 0x2e0fadc7df272797, 0x20d42f8181ec5c90, 0xcfd880889c6daf07,
+    0xab3f5bf9f37c4010, 0x16c672145a3a2e47, 0x129ab75e7e9221e5, 0x07aee9079433997e,
+    0x3cb60aea51b4ef84, 0x9598d331f16e53ed, 0x3106047f517a5ce3, 0xc9ba0b715217fe34,
+    0x612c8ccd26df0ee9, 0x6aac2966abdf3f1e, 0xe4cb1ee3727889b3, 0xb02977a5bc4e9212,
+    0x38dc55000e489a7e, 0x1756071e64c0512f, 0xa4d03ad7671bac21, 0x2fe5f98de557c83a,
+    0x499b477c2ddb50a9, 0xe698bbb53297f873, 0x667394360d8417d5, 0x7098535efd0b6c4c,
+    0x1f8a2f99bf5c6050, 0x415c99db0be868d9, 0x0c6e6614e7447d6a, 0x02f5a9f6afdfd6c4,
+    0x81a191cd5594234f, 0x2ce5df2d77fd94b5, 0xce0511efb20cba94, 0x517f9b48d9a75a74,
+    0x0340196c1024bc86, 0x0b3cdf05a416929e, 0xac520a8f04d57c5b, 0x31212590a35c426a,
+    0xc29d5957cf43aa8c, 0xd8af69a778c5965b, 0xe469f2f49a124da8, 0x841580436f7e0a03,
+    0x75a25b967f9f0330, 0xe45e6d33886a872e, 0x825ebc8abd9edc07, 0x8076c987d5cf57e5,
+    0x3b5acff2f1d058c3, 0x0cb4d8138f961326, 0xc1cc5762f969cb5e, 0x78418b04ab93ca04,
+    0xf70336b47aa941a3, 0x35b2413fa1050bc9, 0x5de8d005dd180990, 0xed03dc5ea6444eef,
+    0x1bca1e09b74f859d, 0x683e67d5901c3deb, 0xde0cfae6ea2f1197, 0x2cb6287c8ccded77,
+    0xb6adc126dee8fd89, 0x7dab0b50882a8213, 0x3fc55dad5ed3db0d, 0x8b4aa0860ea1c074,
+    0x4039d64d3df4ab9c, 0x508879285de17a24, 0x3425700c69e857eb, 0xf8cfa4e98ef7f972,
+    0x9340c1b419612029, 0xdcfd5e606581fdb8, 0x7a0642d22dcf563a, 0x2f56527554ae8edc,
+    0xde4b0403292ccc6e, 0xe42dbe64ee196c44, 0xfbca3a1969317c4e, 0x0fa1b35742096f5b,
+    0x6cb51f567fa0335e, 0x01ed774f3c0be19d, 0x1f5e86ee9e9a0212, 0x14169a8f8b2aa8d6,
+    0xe944528e2221c879, 0xd8da3e4b004bb870, 0x950f21b3af032f83, 0x96a9beea49436ccf,
+    0xcce2a05de83f0b32, 0x2ecd4dca19fc8608, 0x7e5d6e2e38f8df50, 0x783b0752146e0153,
+    0xad864db08cdf5e5c, 0x1ceb17b7abd2a398, 0x79394f286df1a734, 0xd882dae5146866fd,
+    0x160b775d8653b0fc, 0x4094ae4d0442c66e, 0x5c41024944deea41, 0xac9f673ba3904ecc,
+    0xa1061c6444eb929a, 0xce480eb7e551950d, 0x3cfab4d5689f556a, 0x5141d30732616d97,
+    0x6cd3e66003538884, 0x6089082f50f66bfd, 0x2bf900e9faf5d6a0, 0x0eee01fb371728ef,
+    0x36d536169335c1b7, 0x82acb2dc1002a930, 0x81172ffa620fd4eb, 0xc373fadfaa0ea3e9,
+    0x430de8e8f1e2f4a7, 0x6524d8b0f891bc5b, 0x8eb6983c4cd75d1e, 0x983638be2ee0959f,
+    0xfd98d6a6e03bd0f6, 0x3330f9038ccfd5f3, 0xe2ab29809ce15530, 0x83648a4714f380af,
+    0x7005e22d2c2773c1, 0xbf85a8b0b8b0ccfc, 0x57a9a30cdb5e31eb, 0x8920ad1ea25134d5,
+    0x043c2342f5bad336, 0xdcdb7ee30b1cb29f, 0xce5fa586ae5e21f9, 0xd9a2a60304fb8f78,
+    0xa98622c8427bd084, 0x0ef4bccd4de27e6a, 0x70ab6ef019cb76be, 0x03412b1a9b40ccf7,
+    0xc3ac21c6e3b89247, 0x2d64b73b7c4a6ee0, 0xe4584ebea73938aa, 0x178d85d26d764593,
+    0x12eda02787bf8fcf, 0x7820804c7677e763, 0x9c37061cd2922029, 0x3e722d2c7173711b,
+    0xacbf7f00fee0a9bd, 0xb1e339b2d0ef8c35, 0xdd6a344182a9379c, 0x0058984f1033609b,
+    0x23881261e3f58a71, 0x4a08c153d2c52741, 0xe29c7c820c6d24e4, 0x0e33a9be316287ef,
+    0xbc22b9655e410a9e, 0xb0dbf080a966828c, 0xfe6fbabc0e60c9a2, 0xdfd2c0a6b9c90be1,
+    0x3f0dbaf03d8dff06, 0x92a26f45d064fa0f, 0x3ea4287750451c81, 0x82d6136f2a2359b6,
+    0xf0e04478c37d0acd, 0x96b9afd8cdc86130, 0x9b7630d7ce609d62, 0x41cfe05f8ae1c62b,
+    0x0f70b047bb040bfc, 0xa9a4e7801fff4d60, 0x54356492a52b8c6a, 0x8788fde14f574752,
+    0xf9ac50a330fa3c8e, 0xb45fc742892de30e, 0x7bf0f0310014135e, 0x97a141641bb4913b,
+    0x16a0da503be2d6c5, 0xb94321dc82f010d0, 0xcb909f10abc121e0, 0x8317dcfe520e1968,
+    0x09eeb9b345a0295a, 0x76f79c68f1acbd69, 0xa5bc2b79082e566c, 0x95b62e402b211e7d,
+    0x309d038e0012aade, 0x395624dbbd0d37c4, 0x1ef5249b53b1d341, 0xfd0fafad88b41c27,
+    0x12346e4fd6b71338, 0xb9aecde840507b18, 0xebc495ec9b35ad50, 0x90b781e68cd4ee12,
+    0xf3e013cb495449c5, 0x52ff65273301a36a, 0x4c1585329e97e1e0, 0x58a93fd9b55327e6,
+    0xb0f3f23861189a52, 0xdb561d536352cabd, 0xf9e08065db90a57a, 0x30d84d88efba2559,
+    0xd0abe014d0c438de, 0xb214d4c29dca47aa, 0x8326153444d66440, 0xc2ab9c9552f3f1f4,
+    0xdf9fb9f56ad5d1ee, 0xb3bcdf46285e017e, 0xa44a0e31889cf523, 0x508e2a36d4ca8035,
+    0x13cb416c47c17e0b, 0x0b2aa5d1fc55c4ea, 0xaab50def3e5965cc, 0x895787181f942f71,
+    0x3c807f3d10837e1b, 0xfc2f07d8110dc135, 0x4a3d7027ec9ef8c4, 0x328bad79a0aeefd7,
+    0xfdf507bc2a3a79ff, 0x1003fb79f22a9663, 0x36b108b1e46460e6, 0xfeb225166f49b9e4,
+    0xab4c5b10ee3801a7, 0x7229bdc51b4bd1c9, 0xdedbebb98a1f4b1a, 0xfd9d101c51c5827c,
+    0x39cf1452ddc9826d, 0xf5c4cdea07bb0f09, 0x6fe38c5c4fc51cc6, 0xc1c1de106bbf5d1f,
+    0x992bf99692cd37a3, 0xdc1b5a19bfdf366e, 0xa8a5716239791ec6, 0x49fb9aeb7d72d21e,
+    0xd3f61376db25dc9b, 0x4dd02e7a2cc43bee, 0xbdfb9e588bd057a6, 0x365349af5795ac18,
+    0xc403ad39f839e4f4, 0xe559c4e36f2259ef, 0x20d4b6a80408634e, 0xb4ab9a49a857b277,
+    0xaa82d82c715511a4, 0x09f754974644ecaa, 0x8cdee5933ff09145, 0x71f2d886c88edd27,
+    0xf95b55328d6ff344, 0x552a7bfa13a311cc, 0xdb83b78666d39e47, 0x92656f26bdb081d7,
+    0x792a4be00147cb9f, 0x7d3d8ff9a58c6c3c, 0x37f7f245d6013be1, 0xf6bd7781f1ad7f92,
+    0x7719539ea47a72b6, 0xec693bd19f26c625, 0xc0940c3928079597, 0x344f8ddc58558072,
+    0x17b1ca6235b0865c, 0xf128949af40211b4, 0xe1d0b3ba3a200b81, 0xaecdbc921ecda154,
+    0xd628f52be4fc5404, 0xe8f6ba397b06c965, 0x5188bd75594aa3b2, 0x75394f3b025b35e0,
+    0x08d07c2509de8984, 0x62213409c0162047, 0xc38648546e97285a, 0x7efa64db797ab8ed,
+    0x47fc85f2bc33f736, 0x227f7aaf1977a6c4, 0x5cd34d083cb411e1, 0xaecd01ce4735d813,
+    0x904686784d4a16be, 0x8e20c28a8770d813, 0x675652662241d6ee, 0xe6cc9a1951667293,
+    0xe948f621c94388cd, 0x5f28488e6f9e18b0, 0xff9c698a67c9d1c1, 0x65510559ddad3ccf,
+    0x60c07040bf42f8e9, 0x2bd4d24c81fafa6e, 0x32dd78be515ccfea, 0x188f10ba8e962fbc,
+    0xb5a4fdcf5f99586b, 0x107b3bd7922a09e0, 0xf88bc7db3331aaa7, 0x7e2e17b253200b8b,
+    0xc5cb0211597a1f6d, 0xb5e023c5fd730912, 0x1fdb9a4ceec7914e, 0xc490196f118b11ba,
+    0x919696fa0354016b, 0xf5fdc7da0b44a608, 0x0875405c99abfcd5, 0xfcd941974a759798,
+    0x95b2ee3774685938, 0x80c178805d7bf6b2, 0xeafe238fc97d4c11, 0x14531a1156c561e6,
+    0xd0a0750818f5bfff, 0xf2d065d9e72aa8c7, 0x73628fdf80d33fc3, 0x9a86304935fb5559,
+    0x9ebd14dcad50c601, 0xd92f0f6575d8f019, 0x201d0d2ceae1f01d, 0x0595fe874cf8fb4c,
+    0x8b7d35442b84ea23, 0x3c2a6422b2496e74, 0x0c55436ec9af6811, 0xfdc844b52eed3700,
+    0x9b5ae517b29bd1c8, 0x175323b9b29eec50, 0x8aa1260fdb2dd059, 0x633cd77e5ea64654,
+    0x7a827e30988ac905, 0x8d7b3205d63fa638, 0x148754e96cb7778c, 0x1c8889688b8507b0,
+    0x2ea6a139919a9447, 0x78e220d706edff51, 0x61cd058235db67da, 0x47203e8b1c58c922,
+    0x59471479b56fe5cc, 0x26e9a7e21a8c6598, 0x6e491ded7a5dfef2, 0xca86b2d6544d8927,
+    0x4927c31705cbb824, 0xfcfdb7f5b5d23e6e, 0xf8ede366fbcb5353, 0x442cfbb6d1ad3d2e,
+    0x9791d047caf433ea, 0x8aa280dd0c557bee, 0xeb0958c3ff4313e1, 0x3208278ad3601389,
+    0x054b9bd48360a5b2, 0x996665cfb868b201, 0x15a52228f3092658, 0xdb1e4cdf4c3b84aa,
+    0x0145640a0bcab7c6, 0x4a4fdb76c43544b7, 0xa6b9629ed19def99, 0xbfe6d2ebaf4da384,
+    0x279bb85f878cee35, 0x88f520a072f4ee72, 0xe15bb3d375a0c1a2, 0x8fc03a15661a5c04,
+    0x945725017d91781f, 0x24b07405f7565af6, 0x210321b978a7d04b, 0xca79c1766382780e,
+    0x786b58324ab5417b, 0xfbf856a066939f2d, 0x93b64e03f05efbeb, 0xdd857d5a49f819ed,
+    0x952129335b3e8bcc, 0x6574e847fc90088e, 0xb19382baa1824e3b, 0xfabffa628fa27354,
+    0x03a4d22b6c8cf843, 0x12a4a7cb9c01b33e, 0x68d93c9bd86a33a8, 0xff621697d2ed36b7,
+    0xe30c1091caeada58, 0x635ed28e1c1eaa3d, 0x0c5b534fafe90f6d, 0xe9464a37c18733cb,
+    0x4dc833504f667ac6, 0x15b4a0d44dbd500c, 0xcd62f283e5f29bde, 0xe75cfaa3415f7a11,
+    0xfad9a719cdb7b316, 0xac25f7aa9ce7550e, 0x412cf0a7f843d419, 0xc79562f5ca267ba6,
+    0x9fa54aae6c33512e, 0x10f2b706a3ddf609, 0x2a62d5d122185109, 0xd95595f2ba7f0e46,
+    0xb0ecf9ec722d6c9e, 0xe63c559b7efb5b9b, 0xa5390336fd27a06d, 0xb0c1c040cd86cb74,
+    0xadad4fb15aeb8ff1, 0xbab42db3e160d8ac, 0x8365cb0e63a77fdf, 0x4e6aa86cc109bbc7,
+    0x8e66c28d0b6ad919, 0x15a760d01733902c, 0x3694bc43b4a3bd1c, 0x17433c175ba5bd90,
+    0x611954335e7c3d7f, 0xc1596ee7d8c73160, 0xb1a7a4917d692148, 0x0a1f54cd676a97df,
+    0x432df5adc1f77add, 0xebbb62c1ca36dc48, 0x615462672172b181, 0x559245ec2a756253,
+    0x22a515ed8fe852ce, 0xb1debbb558b9a288, 0xee82dbb15fefa1a5, 0xfda35492322009c7,
+    0x53a2499a5e4d7ec9, 0xe098a96bc595d360, 0x545557b86c0c8fd7, 0xd4cef83ab0c2debf,
+    0xa594462e397ad966, 0x8985eee688821d6e, 0xcf6544c37fa05f5b, 0xaa3e2773b990e871,
+    0x905449eef35d2ef5, 0x0310e4cb221c78b9, 0xdcc3dacebd09c0e0, 0x21d67604761f6de1,
+    0xaa37284c5ea84bf6, 0x1c2a653c72914de3, 0x9d1d48f95f7afadb, 0x854362bc0ef4e394,
+    0xd34a271245c7ef42, 0xbe03697f74d2763b, 0xa34060fbea403852, 0x95783ba6740cdd22,
+    0x402609ecfea6745e, 0x3079fac8283b22ec, 0xba62317f1b551b3d, 0xfb9db5857883aa47,
+    0xdf04d5cc46e6d3c2, 0xafe9737d234a8025, 0xeb2b7d01c42faaff, 0x1a6130ab3e9856a0,
+    0x898188136e59c599, 0x4ea85db1a178969c, 0x6c127ac46c368b34, 0xc0eb1c55ce6b4336,
+    0xb4f997101fcf91fb, 0xda43be29bb6dd1a6, 0xddd2bb477beb9e95, 0xf833e2a74c3e1f74,
+    0x2ff36c6e05d615f4, 0xf8b9f482ef096599, 0x04243e8dfd80ed99, 0xdb2ad59d0c2b3a84,
+    0x2da3023c04821ccd, 0xc358aa89bc2965e4, 0x43eb7589422f165e, 0x7b24547e249ec363,
+    0x234effd058fbfb44, 0x9c91220a7c34267d, 0xef2604f43bb41b19, 0x34b575f3b9d9a8cd,
+    0x3e688219be981d68, 0x6f5104734727e313, 0x724028803960dd36, 0xad50d4ebedcb3103,
+    0xb4e4b8ef788fda48, 0xb403025a0dacd2a5, 0xf2a534e8da8f1e5a, 0xe9b8b0889dc207e7,
+    0x04a2c2787459c4d6, 0xa8834bfa103c50b7, 0x52c91467cb5d056e, 0x9348062be04f2d7e,
+    0x967a5b1aa8853bdc, 0x572c65b96a3916c6, 0x82955380fe71c773, 0xcee97d0722b0735b,
+    0x472f2b83ccb785ce, 0x26da019d31278e2a, 0xb2402604aa0d7947, 0xebc38ba53a6387cc,
+    0x2674b333e2347902, 0x2607177ccda1a59d, 0x38a4e4a28e4377d3, 0x733f6494e7a6b05c,
+    0xecc39a74b1cf7be0, 0x8ff55f8329246584, 0x779518ef20d1557f, 0x80c8ab5967d38ee2,
+    0x24212989f77eef44, 0x9afef880c2bf5951, 0xa507b9e50d21d7eb, 0xba0235b0b613de2a,
+    0xd78030de0095f150, 0x8c7008a8b30dd5c9, 0xd7c5f9268df0c4e2, 0xfabce1bc9e37f63d,
+    0xa5150c4b68ad84bb, 0x6ad29f2662fea0d7, 0x54beb6d3c801f7ce, 0x92d698775b95fd2f,
+    0x6348d89723c3fd13, 0x4faaee7ebc984a0d, 0x23e0ad9f760339d1, 0xeb9fc72ad0f07446,
+    0xd31b0021efa3d628, 0xa46d7630205f304f, 0x6410ca5cb586c850, 0xa74a5d523eb97f99,
+    0x7992cabd01fc8bd4, 0xc9bda85f9ee4999b, 0xfd016b478ba9102c, 0xcf09604ef5b5618b,
+    0xe2e11fc3f1107dc3, 0x0576726b66850a5b, 0xadbe3cef7d4f45f1, 0x33de9f97c5bba11a,
+    0x6740775998bc920c, 0x3ce5d4c7f4c72e18, 0x7cbabf0156cb16aa, 0x7bf3581c87cb5ec2,
+    0xfdaaca502d3b6c52, 0xcba7c7a3291d0acb, 0xab54e4c34f76ce6a, 0xe83442be8ff2b1a6,
+    0x9a802c6e4094d51c, 0x56faeca7105dcf9e, 0x358ac090b681f83a, 0x703de61ac09e554f,
+    0xfaf6059a1a25719e, 0x5c2693d2a091c02d, 0xbb581b4cde21aa60, 0x640443b20d0b2d80,
+    0x161cc9a1e179e620, 0x9ae205dc8e85c67e, 0xd5826688f4c904ca, 0xa90c974be84c135d,
+    0xa8dc6c5150720dc4, 0x324278af8bfd2a87, 0x63104f01a11d6314, 0x1d9280ad06383258,
+    0x4a2e1a85cde37108, 0x57676ece5ee424ff, 0xc0ff21622db83613, 0x301212bdf67952c7,
+    0xb84ea2d6ed84af6e, 0xf52bd6cc4d88f175, 0x26ff9b4b4809fb13, 0x3116ad8371bf4aed,
+    0xeeb92fa66a26b923, 0x250211fe8914b615, 0x7837b797bdb5d071, 0x3f8b062cf4e663e4,
+    0xd35d5a949f864704, 0x21c624c238489e67, 0x7145703bbad29df2, 0x02081c60643254f6,
+    0x47d45575567ca474, 0x08254e71d9bb6afc, 0x5b7f4d9d96870ff0, 0x1ed8cbff09da812e,
+    0xc456dd6bfd258841, 0x45f07c9676296c0f, 0xc8bff43d582cba13, 0xc3e051da47da3c35,
+    0x477e3a47d49b48b4, 0x5c3108fc27a3b4e0, 0x290d1ebf204b3a26, 0xf9def44af3bc8248,
+    0x6bb718b7eca7a6a2, 0x6a422a25647e7902, 0x6a301988ff4a3ffd, 0xb776bd2f7dfc3def,
+    0x00c58a2dd1e82d1f, 0xe6810a118af4e5a1, 0xaaa48940173b9444, 0x9f1cccda387ef2ba,
+    0xb3f1f02f6375f877, 0xd4252f5f38482ee8, 0x09d7b5824bd4812e, 0x8e21d0d6d46a1b9c,
+    0x7efa325a7f650773, 0xc5061cf9bc9f959b, 0xe9b250121409b77a, 0x68f96520e5e502b6,
+    0xa372c7d0e9a1a056, 0x02111ce34eea29e8, 0x72084a4c94244f70, 0x950d71f81d813129,
+    0x29eb739aeaf24c79, 0x83707b03475057df, 0x14bd23f9efbe982f, 0x02e522df77e3fb93,
+    0xae39521d1a13f865, 0xcac99ddef99311e3, 0x6d16798c76fdee34, 0x5f47c6824dff4716,
+    0x0dfc1a3dcba2f0a7, 0xe66fcf471cfc5b6f, 0x49e1d35e32fb723c, 0xaba7e8aae6c0df80,
+    0x6e7a9f9221423410, 0xfe8ad3263ec98e41, 0x9f4a41fdeeeade6c, 0xb63899c74de2048b,
+    0xc6ff0375334c4c80, 0xb7b9968183f647a2, 0x6f89be179bcd1b43, 0x4c2341218eff2528,
+    0x6fe63f1c4ebe48d0, 0xd3cf06d32a235403, 0x39664b1208ca484d, 0xe08f990d7a45defa,
+    0x2801e18052d7c05b, 0x0ef1df5924a09b1f, 0x194b66ac336972cd, 0x75d53b57bc50e619,
+    0xa55ea3fcc62bd04d, 0xd6fe1008f8407d36, 0x6bd46c6cc15d0703, 0xca5462e33751ceb7,
+    0xc589921b07468de5, 0x183051ee5c8dd824, 0x528d1da02d36d9db, 0xb748fccbf3b7c6d1,
+    0x3d25a84ff630e057, 0x6f848449ea3f2d3f, 0x034a451ce7212ad2, 0x2482a338709a4990,
+    0xae992de5fe673916, 0xb6913e852bc95ec1, 0xf480b8217ea60d14, 0x8e93790f0fb8f090,
+    0x57c3ce4441c9d108, 0xcd04e0e1dc014121, 0xeb936ab90ea0cf54, 0xb8f1ccdcb4cccb3f,
+    0xf9b318afdb6417bf, 0x72233c4e80f15f96, 0xe659ec72a86b5c60, 0x1feb8e2a7c6ffb7e,
+    0x75e4f94eeccc35db, 0x886c375c0b9c49a0, 0x1d9c479771ef4dee, 0x0638b5cdb08c93a3,
+    0x229a1086a8fdeac8, 0x0245eb84c826bdf0, 0x117c2204f175dde8, 0xe7eb94e3b6f64b08,
+    0x2ca32e7495f88907, 0xf7ac42d86fa0f6fd, 0x77552cae61be814f, 0x9c680a3cd3fc0044,
+    0xecd4640dbb8f2345, 0xd09d738fe85c1ebc, 0x72f96e5c10365de5, 0xbba979e168052879,
+    0xccaca6e9e1346882, 0x2f1a7c64bf500329, 0xc7b51c3da7c310ab, 0x8af7bde4360d6038,
+    0xa7d908f5591a0902, 0xf016c2455a1f99ef, 0xc387af9162de120a, 0xfbfeb6c2c57ed08f,
+    0x390d575e13f2eaf7, 0x09f0ca6a2ca86984, 0x0082abfeb5a08b96, 0x7f9e94fdfb35c64d,
+    0xf0ea3a4206e13f5c, 0xbacab0868afbad60, 0x0c36df10e2c6475c, 0xfa179af5fff1127d,
+    0x91a6cd5b9eb212c3, 0x6c0bafcb0015625b, 0x3baf6f97b9807625, 0xc56c396965460c4d,
+    0xcbb2340ab56d5a8b, 0xe34299773738ad5c, 0xd450dd0feb039ea5, 0x9953cb45b8f664ff,
+    0x9920d66b6ae0c47e, 0xa5bc6e0eb6a3cae8, 0xd39b2ccf435eae02, 0xe303b8bd7b3dc039,
+    0x19709a7c6a5a6e18, 0xfcee56b4a579e926, 0xeec14c181a47acd3, 0x05122db26a068122,
+    0x65baf0d6fea5fee7, 0x1bcc615796744e73, 0xa9e8d31e67f62169, 0x078e7f9315733879,
+    0xd42b6d1cf2fa88e0, 0x114c3acc1f2d128b, 0x1e99a7f9e81323c4, 0x5f4a172c9ae278d3,
+    0x8c6f09d9637ed8e6, 0x723f001f698d4f85, 0x7a33a01288358252, 0xd56b5f8951d80538,
+    0x37189b6918eff030, 0x533b9c31704f4cc0, 0xc79fa211ed787a27, 0xfb5ab61ec9e8e414,
+    0x067ee477b8ee5ed8, 0x2b52fedccf96c199, 0x0e229a43d033f54d, 0x8da9d5652271c0c8,
+    0x863d39f97af23d77, 0x24f73c55b660a3bc, 0x5bfad4c322f62158, 0x63b74786ae696a82,
+    0xfb746ba2b9bd6a31, 0xbbb7de78442733f8, 0xc630f3b27685e789, 0xd4c5bb18d1856c35,
+    0x789df68964fba2f8, 0x90ef87d6808b1f21, 0x5a3cb59f21050311, 0xc83de0990108be28,
+    0xee9ae99f3f89636f, 0x11091012b7a1348c, 0x3cc694a37ded7067, 0x3d218860b969fbb4,
+    0xe6ba95b6841237e8, 0x890ef2dc4b3cb4b8, 0x2f9ba338a1468770, 0x08192d18b55ea872,
+    0x270ce711117c730b, 0x42477a6b3f138eff, 0x16630a7f685594ff, 0xa32844a1b9f199bb,
+    0xb88f47c75b9f6e68, 0x13d446c004d8054c, 0xaa793e40b29c0799, 0xc858209598528173,
+    0x3fc76d5127c9674c, 0xab2d22dbd7e1375f, 0xc8fc8781b97ab3b7, 0xc960b6175815d6e1,
+    0xb5eb930dc8fb8a42, 0xe7edd51f4f1ef8a0, 0x89a97d0f09c6c6a5, 0x38fa97e2eba54c75,
+    0xbb785e1141ac53aa, 0x1b954f060ee408bc, 0x7c77f8265f1bb35b, 0x8b2ab70851a024eb,
+    0x97ceca3beffab7c6, 0x8782c9b763b4bdf8, 0xd7b8c39b808fb50e, 0x1e6a395a336648d3,
+    0x3ad19e59d166aea3, 0x15398ba524b6ea46, 0xf3c0fb450b91dbb6, 0xd07b3669c159be31,
+    0x74d7486ec8cb1f89, 0x416f6d2ac17e7d57, 0x3f0a8a5a7e050f97, 0xa39cecd0a4bb696f,
+    0x710e66d93b913eb7, 0x3dad914746a57757, 0xeffcf94b2890b5b2, 0xfa2fba52fc1c9b55,
+    0xe9abc87d14de75c8, 0x9d068b7c5816454a, 0x216220b1ad3d1468, 0xac801c0721843345,
+    0x152d6872c9bd27d8, 0x5b53045fcb2f5888, 0xf5dd8eb50eb7f224, 0x91f8813cd93d473d,
+    0x039a51665b67b003, 0x5a41c4e877bad265, 0xfb40f49109cf8521, 0x6167e582635eb536,
+    0x37fa7fd77b287a9b, 0xc62ac00edc2c29f1, 0xb9ccf07dcec6ba91, 0x4fd09e0b4e31bce4,
+    0x43a9e5a20123d6dd, 0xeb11fbf93c0861f3, 0xb793bc1c14624a1a, 0x98d173dc7ec5fffd,
+    0x172d3f24a2724cd1, 0xdeb285a68d62ee64, 0xeb18e6ff1e835eb1, 0x99c8d60ef1fc7728,
+    0x7fe218a7d91f413e, 0xa00d5ef732e48bc6, 0x8ac6708ade2470ae, 0xf35e28f253abd6fc,
+    0xff3590f9aeb483f0, 0x6e0573da490b0445, 0x50b83adb417a9692, 0x1234b3004c9a021d,
+    0xa63cb1cbde67bb06, 0x641f619f79378310, 0x4642d7b95454bf7d, 0x9258d5032b2f1c19,
+    0x92fb365a8b82ba96, 0x778a5c890eae4fa2, 0xe9c5e6b9363e9a63, 0x12e29efb3c18d1f2,
+    0x3353dcc23fcc9b83, 0xc71c005cf74abe48, 0x0e79ab9a4b65a075, 0x4f012603398ed0e4,
+    0xd96c8bb5ef64b54a, 0x2bd0bb56c13ae1ab, 0xc0349797a513527c, 0xf398c6d39497dc03,
+    0xde4e9e4a62f9347e, 0x8a7c6486261fabd5, 0xc4a3e5002bcae2d5, 0x2b0a03cfed9859a3,
+    0x6a01d49736d7a960, 0x0efa0ed766a45f8c, 0x4636612c1a9b2e65, 0x4c134e09bed13b85,
+    0x7f4485cc6a20d052, 0x268ed2660541c4ec, 0xd402e04e65a0697e, 0xd69eddcde029d1f9,
+    0xde4ae902bfd6d962, 0x3e66bcbeb89a490f, 0x7f651d8f0aceea3c, 0x2eaffce5e227d917,
+    0x770c42134e5144d6, 0x124ce2cbb44b6ea6, 0x8cb80e11180a9f9b, 0xda658cd03a147227,
+    0x7f6861869125f122, 0x4c7cf5a59fc2e99d, 0xa8b36cccd50bd8bf, 0x07a1183aadf4f8af,
+    0x362651e572b800fe, 0xe8f4ca36a2d03d7a, 0x69e09d9370b0f86f, 0xb616b5ccfe735072,
+    0xf897c4d00c5feb96, 0x69b8710c0820a817, 0x496d803d086b4ed3, 0x7e5334081655a6a2,
+    0x63676379dc6e8c9e, 0x418ceb4b7a9ac2f8, 0x80ccdb463413254a, 0xdc3c4a0347a47b61,
+    0xbe098197986087aa, 0x7135d885f3102253, 0x53e5c06626062227, 0x519961d868742fe1,
+    0xc02f893d0d9ce714, 0x8b64bc5c890e6cfc, 0xf694a02f035e199c, 0xa46d6fb168476166,
+    0x7b08d2caa7646abc, 0xae3d991e6f877e39, 0xec1176aaac80e936, 0x73c65a0c1cfbf9ee,
+    0x396b3bfe097fbccb, 0x4acbe14d9af3e5e1, 0x0d770ca373dd1f95, 0x7944fc78ca84969b,
+    0x2117bc8356ae3502, 0x692947c71480289a, 0x3263d76b0b544570, 0xa492208f8dcc5fbe,
+    0x5b81707fdccd103d, 0x8cb9d23219920018, 0xa2a7c9cee178f631, 0x23b44ecbf5cd8c43,
+    0x19ac09b5b6e6ab50, 0x8228c6e58c4c4774, 0x02cf61c793a66ac0, 0x740261ff62c081d4,
+    0x6a904faae7c3b5d7, 0xdc68b39fd752ed33, 0x444a64aac77afc6b, 0xbb48dffe224e0206,
+    0xe55772ded729f76a, 0xb4cf404e478255fd, 0x94aa53c0bd714ac8, 0xebb2fb29619352e5,
+    0xb92a83db168a3875, 0x3073a169e769e7d9, 0x8701f21a94d2ca92, 0x5514dc4d01e24640,
+    0x51c08dc7171333e1, 0xb75a0e422816bc2c, 0xc012e1d100468235, 0x8d5e6e9a76022560,
+    0x15e3d03db877d24d, 0x5c7e19969a973afd, 0xee154d0e74736aa1, 0xcc3f8594fd480dac,
+    0xf3d0852897697b20, 0x6a939b73bca6fda4, 0xdd34411bd79c5dba, 0x30c21edea751903f,
+    0x8b23417204af7cf3, 0xc2d886e2a2a02131, 0x066a2a0ef6a34e7b, 0xc2fce36df50312d3,
+    0xe39327e09180bef0, 0x55ac1527b2f528cd, 0x6ae4826053f45b6a, 0x64be7b9ba05cc3d5,
+    0xee2a32bb79adcac4, 0x31ed5236cde123a7, 0x91a1d161cae8edf3, 0xbd05d2a745f2016f,
+    0x1dcb003ad4f12576, 0x5b1b60b05c87931b, 0xea6c0de19f5a4379, 0x987e0d00e599e816,
+    0x7e87a3e1b8df7cf1, 0xbf528fcc09c84581, 0x173bb82130cdbb68, 0x78fee618f2f0d24e,
+    0x087019ff5ccf5a27, 0x02bb9b6b77019f93, 0xd115481fd8213816, 0x52074ce6da413d3f,
+    0x271f913ee032e682, 0x87014368435def88, 0xf56e34b91531188c, 0xec473633940de764,
+    0x055afb0635a9da85, 0xae339d13fe45c3f8, 0x30f25740e1d48611, 0x03d0899886ef2190,
+    0x56bbd3977c377790, 0x07cb99739207c945, 0xc5643f1b82ef0303, 0x05f2929963a530ad,
+    0xb6f1c4a1c4efe2de, 0xe778701d274c6c0f, 0xa394882586f25554, 0x50b048853cd32aa6,
+    0x77d355f788c844a6, 0x29103a02bf7164b9, 0xfbaa05000b16933f, 0xe24783dfca619d1f,
+    0x4374dca92072f4d6, 0x9c12e9b0c23bbca0, 0x0e58e37bfeb60371, 0x7f5ce7bceb908f42,
+    0xda51765ef79fb894, 0xd084f0e577856136, 0xb8e427fe87ec25f8, 0x65dcbf84426d8dc8,
+    0x4515e00a5cb1d03d, 0x7c4d5dc1b58596a1, 0x468b128e8769a202, 0x203d2aa7879b9edf,
+    0x96f2485b599ef51a, 0xff693f59f19a21cf, 0xc7369bc0bb143032, 0x2f788f7f3693251f,
+    0xde82253f4108c462, 0x9985ba89570179f5, 0x710949f04d5a20da, 0xa7b7382c17b61b6e,
+    0x6d6de55efe7b1da4, 0x8eb2516958efd6f0, 0x832cad6cabb7bd6f, 0xbb81b75159c45f9f,
+    0x38bbec65a82e9876, 0x8ca35128d7e1a597, 0xc9047e962fcd798f, 0xd03464a9ee51bd51,
+    0xb00d7afb7c9a5e22, 0x1c2f87e402f04397, 0x33c9de579223a75a, 0x26d1148286e296cd,
+    0xd0906cbf890ba5b7, 0x2b734a8ebf7330f9, 0x8f59f8c77e16f744, 0xdc06e49f3f5bcd7d,
+    0x866341f6352b7843, 0xfba323b0c15e844c, 0xe359b1b19aae3081, 0x72963f1481330f7d,
+    0x42f45d837e25d75d, 0x30689f781848f233, 0xe0690441faf1f3f2, 0xaf71dc3a1db5498d,
+    0x62e5f185b8dc214e, 0x0bdabb3c47f4136b, 0x0ed880bc66a7b48d, 0x335efb087d4130a9,
+    0xa088bdd82f91bc1d, 0x8349f9e019408e79, 0xd821c379eb21f8b5, 0xd16e672f67447575,
+    0x2ff03bf576f44ca7, 0x5123c73cd67cc6b2, 0x32c9f37a95e8e315, 0x241fb2f38b52c982,
+    0x64bca04e120c020f, 0xb61bcbfcc3c4ba96, 0x4ea3f3dfa980776e, 0x5d2f8345f51d82b9,
+    0x6e37c64ae62f2b84, 0x063ce5844b9760dd, 0x07c34f1291d2437f, 0x9d77a923ef78cbb3,
+    0x242fc79c3a85825f, 0xb495c58de11ab9ac, 0x743ed576a7e8541a, 0x06138a539990fd7c,
+    0x9a0bde64f5cfa50b, 0xfc32c215ab05f8b4, 0x1a55aa47c5a2ebfc, 0xc3711173dcbc97d2,
+    0x176a531e926ff4f9, 0xc014f4a0a7a4f215, 0x53de1795601e29cd, 0x0c746796dc28bbca,
+    0xea6ee510e9c7ca59, 0x9e1641b3172169ac, 0x1bbfdf1a0933ccfc, 0xfe9580a45c23202f,
+    0xc5802b9eb7be5c0c, 0x88ab018b8920a352, 0x35b8662e15f9a9c9, 0x9af409b9f829decc,
+    0xc7c6d23d7d7ea2bc, 0x211dbdbe0fe564ca, 0xb3e154b95c00817c, 0xe91c1b161c68ff95,
+    0x76e4e43488e70904, 0xef32ee9abaaa21c9, 0x327dd2582b33a7db, 0x2dc9a649577009d5,
+    0xa97549ef7287c563, 0x0b638d2c5e02a4a3, 0x00c3c0a674687952, 0xa13ca78127559f81,
+    0x1c3e2f1c500f92db, 0x0ad10ae807e00397, 0x3e1ba6d571e3c2bd, 0x4c1233cb9d3ddea6,
+    0x1e45360a06fa0a52, 0x44a03a2727e86802, 0x81a0276bcf91066c, 0x066f0dbbf0c2d375,
+    0x0f88081015344bba, 0xb2a308c13040ad95, 0xebd4aacb820eece9, 0x96b81d33435b1100,
+    0x03d8b83c83413282, 0xebcd895c9fc6a758, 0x55e518fe198f650b, 0x6793e3f0dfea99aa,
+    0xfe7a19bd33b32857, 0xb50be78babc037cd, 0xa922160a96d6ebba, 0x57c46fa0d25a346b,
+    0xd9ec3ad8e74b3891, 0xbbf4da3ebea4b55f, 0x968fac60947ed616, 0x80af7db1ee468294,
+    0x0a9e4dea43937c40, 0x01f4b53146495591, 0x4e2b9a2b8a40a9e2, 0x1d87305448dc8cc0,
+    0xb4cd7f1c6b63e262, 0x375c08018605005d, 0x60339551d9db64eb, 0xf02934bce26837cf,
+    0x4b5eadd51854f44e, 0x91329a9c8a1cbf5c, 0x396927cdcd3412f7, 0x12afd396738bae49,
+    0xf9fe6c83724874e1, 0x150b3f0926c6a1bf, 0xe8d607e0ad754a0e, 0x8f0f92372bee02ac,
+    0x04c7c5cacf681ad6, 0x0b0fd62127bdf703, 0x6ff8fc158db9f06c, 0x848e5a6323f85a1d,
+    0x239b72b1ab295b1c, 0x13b9f4674a83502d, 0xd14f30a7c9a4b61c, 0x71bddc3948158b47,
+    0x8801926423a4bc59, 0x5bacb73f88f64f31, 0x6b7df2358340da00, 0x8195ec559cbedd31,
+    0xf754d57a74e185ac, 0x0eb2fbb8b5ac914c, 0x07b2ab994f4ab607, 0x9f63f3a724541671,
+    0xb29a9e9d1435b0c8, 0x0bf75f7dabc7fe03, 0xfff72f12619d9bfc, 0x626b8eaff3e96215,
+    0xafeed116fdbab1af, 0x6bfc3ada70702c0d, 0xfc3ffbe48b5e04ac, 0xf3ecd65d797b68cb,
+    0xca1cffed5046e7a4, 0x20b7cfd656054eb3, 0x25447f1be3cd4f21, 0x1c71f7a0ad11d1b1,
+    0x8f82dbd27a1b7599, 0xc3eeba7e054ec20d, 0x4d4184824d524304, 0x7f7d43b20b1836f7,
+    0x4e3f751d8f90fa7b, 0x343ef12b0751e46e, 0xd82e1a17e0b40180, 0x3493740aac015d68,
+    0x6935d33a2dc8606d, 0x8ad3f45e9997f382, 0x0b9ddde3ee4a13c5, 0x094b3a0fa98e3346,
+    0x429af72ef827325f, 0xf6bae1299db722ec, 0x009eb1a2e3611a40, 0xeede3ae4af83e5f7,
+    0xc5ed332e02ef4651, 0xc19f89ce532fefdd, 0xacbd95603c1eac45, 0xa32f826bb1992f6c,
+    0x3af982b6a40a7588, 0xab4f9515dd4461e6, 0xd7f97ebef2af0c5e, 0x44b023be5397bb10,
+    0x0cce365512a28e3b, 0xff0a5ac2c6363061, 0xbf48637b3340444a, 0x5cb9242e0e7bc370,
+    0xd49a11a93839f6f5, 0xf1faac4e30c9756c, 0x4ccc007870ea95b4, 0xfbf1910a012c816b,
+    0xad0f3e03d50b6268, 0xd1c7bd16d4283673, 0x95e00f213c5961af, 0xcd84529d004327da,
+    0xe13facdf68b086f4, 0xd39f5fda04108d90, 0x32f36d7b00abf4f1, 0xb4cee1f88c7c4fec,
+    0xa769fe11ce3cc88e, 0xfe275c1c94849351, 0xf0fbb6e6f62e2d67, 0xf1dbdc0af6960f7c,
+    0x24e44611ee8a2514, 0x2e94257256c15661, 0x42d7246435af236a, 0xcc75d5ad0df3b9da,
+]
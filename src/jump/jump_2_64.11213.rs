@@ -0,0 +1,46 @@
+[
+    0x4f20b8197cbc57f3, 0x54ad442b94c0ed6e, 0x8b183c73b3e1d635, 0xe47547916e69a6fb,
+    0x094452e3d0ed6deb, 0x002a53f318cc2c76, 0x66b3d7e8583c99eb, 0xebc375cf0a4a169c,
+    0xe2f6c0df8bceaa53, 0x51523b6fa09f76f1, 0x5b847da5f23daaf5, 0x46d4c44d63a02a02,
+    0xdb1704bad598ddad, 0x020a5a2e4b415a0a, 0x77db13ede0f0a1ed, 0x256ef533b9a41759,
+    0x4d5a9a8500c76ab4, 0xeb5878e2f577f790, 0x3d16073071a30927, 0xb36f71c8d3c604f8,
+    0x711bc48ed2f63c85, 0xb477c55e981d0642, 0x6b1af8ea2767f1d3, 0xd0ac1174d0978e50,
+    0x8dfefe2b94231582, 0x7307ce8829b55fed, 0x93258213e5e60ed3, 0x32cc523fadcf2d01,
+    0xaeaf04fc316766bf, 0xd16ce9e8fa620625, 0x3997982d521910ba, 0x95fc890b96e26af9,
+    0xe4293979c1e43a7a, 0x509528b65d077c14, 0x28ba41cbdfa60190, 0x16b5491db180edea,
+    0x81ffc6274425cb35, 0x8795e148dcfc79fb, 0xf77bc9a12ad3314e, 0x7ae33309c7c3c254,
+    0x858bfb889c97d227, 0xbf075e0d8d117809, 0xf7172f7e18d3a83d, 0xc0b87202fa4c3e22,
+    0x6b2212d53ee5ea14, 0xf99502627bd0c100, 0x9c16c7bb9ff041c1, 0xc985b4a94d09f1f1,
+    0x9637a8f642dd9558, 0xdd9e26576e2f6890, 0x83f32a0add3f318f, 0xb836db62ba7c6fd6,
+    0xfb92c8854f4b79f0, 0x80b0c1d110e61ac1, 0x3f178c4608dc0ea4, 0x00629adf28f8888b,
+    0x8654fd53d095d3b4, 0xa989580226399aa8, 0xa5efd697dad145a5, 0xcbe45b4ddc36a364,
+    0x4b038c4237e481e6, 0x893c669161ef181b, 0xc06ab8c6c63c1501, 0xbebd11ca6683d01c,
+    0x171938b39a61b5ea, 0xa15762e6eacda2dd, 0x95f12453c4667dfb, 0xd0d1941bf1e01d8c,
+    0xa2ff8d542a40d4ca, 0xe2592b9216326dbc, 0xcc2cda5241dc8255, 0x05c629656a1f1c96,
+    0xdb4ef5d56fc3aeeb, 0xfdd79de22e155ee0, 0xea34e702f5a2ed37, 0x3b4896ae43bda381,
+    0xce3596f509249e16, 0xc1036b822945af7e, 0x1851abe237eb7378, 0xb49838baa7280864,
+    0xc954bfe1f721f681, 0x895aa5f46bbcdb3c, 0xc83b3601d57565ca, 0x6dc2c01e51b1ad8b,
+    0x1e43dbc0d6ceb6ad, 0x832d1ac7235868c6, 0x7e4a19145235ed59, 0x9df71effb6a79779,
+    0x6c2a569665bf6ed9, 0x53b6549ca57cde85, 0xfb4ccbf92022eda7, 0x2482b72c895daee7,
+    0xb396ff2d22072650, 0x84ab2c078f5449f0, 0x1bcf7fd2aa3bf14a, 0x0e5179bf30c6db6f,
+    0x704414e8969eb2f0, 0x066d678dd85c0b8e, 0x8c99b7948f3f78fd, 0xf4562d71ba8605e3,
+    0x5bc64cb7951699f7, 0x3dd174d2ebe0a5ff, 0x68e47f05bb4dd213, 0xbbacb4ee764ad814,
+    0xbd360d4e81f7b5b5, 0x32df46cefebad285, 0xce12efdd2edb1c0e, 0x15eba598a4aa1eb9,
+    0x747b519bb89fc048, 0xdee0b8a5814bef9e, 0x9f38ffae0b93c3a8, 0xfdf22136e07db688,
+    0x1e6b5578adb5046d, 0x032e769bb2f1e3f8, 0x024f167e50d0c349, 0xe374df97c0f435ea,
+    0x925f606e0d44ce5c, 0xf2ea02be5a993dfe, 0x4e7bec80955d87c2, 0x4a192150c39ddd48,
+    0xa237cddaa9e3db0f, 0x464b96cf3c6b986b, 0x79d61bbb45325d44, 0x3acf74189dcd4b8d,
+    0x542232a4359424df, 0xf1721f8da4e7f959, 0x8514596ec60c8b65, 0xfe9ddf08a1f4804d,
+    0xef8cbff380bcfc92, 0x75c3dfeb74a5c232, 0x9c1cf30b66d9446d, 0x872e5dfcd5f664ae,
+    0x9762b909a30dda3a, 0x47e52293ec385270, 0x8d4882a0420cc229, 0xb319abf19351f23b,
+    0xb170008cf5815818, 0xc79828940409d112, 0x57dcbb6bb4275c03, 0x778cbd8f4057b840,
+    0xb224930def31ec1e, 0x6cfe32d6ce625d6e, 0xd79410b125e95a09, 0x6a628cc297d2c01b,
+    0xe07feb29677d0b04, 0x55da6e07ff83de66, 0x3e4e3bd25e2ac8a6, 0x9e1407bea599a9a3,
+    0xecbe9bbdbda1b49d, 0x3e46e1a63e7f323b, 0x5e5841a32de8086d, 0x40402da00ee771da,
+    0x721b6320747fca4b, 0xb5931f509113ed64, 0x89ac448683d26cb1, 0xced77592f71fa16b,
+    0x9260cd90b4b54e9c, 0xf245ccac2082eeb0, 0xdb984f0b37e47450, 0x349217a9d4154717,
+    0x83c25ce88fcf5f74, 0xe57f7af69ddfdb06, 0x32e6775b09c0977c, 0x5c0969854b77d1df,
+    0xdcce563976c96385, 0x6ad9abd6fae86c4d, 0x5ba9932a9af99c46, 0xc4fe7e4d3a391544,
+    0x363abde1353c7194, 0xb33fd8c5b019e68d, 0x77864a7b42718c37, 0xcfd9d4ac0bb5632e,
+    0x8f8e897dd7004da8, 0x1b28b6234f793bbe, 0xf322cf08d25646d4, 0x1dd12af3b2ee821a,
+]
@@ -0,0 +1,7 @@
+[
+    0x7bcc0fe86dcb7968, 0xae5b4906fc2041e8, 0x1e5eee6865d92ea9, 0x047d1c08c0b7a5b6,
+    0xf855af2a291e6447, 0xb1eecb1ed51fbd1b, 0x8b7c9bf1f5a13afd, 0xe1ee993e4fe87265,
+    0x24c787277d55105f, 0x80a0fd393c50d072, 0xed821faee795edd0, 0x0af7a1a77cf5f093,
+    0x2e7a332cc7f58c1f, 0x8408c17f8e7b5ad2, 0x6db2a7893cee26a3, 0xbb5b4b374fe08f58,
+    0xf2063a24eff367b7, 0x463dc97266e369da, 0x97ca09670250018d, 0x3fa5475cc1b7259b,
+]
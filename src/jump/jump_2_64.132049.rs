@@ -0,0 +1,518 @@
+[
+    0xc50afc503e36f717, 0x37439b5f189b3ecd, 0x0f2cdde59cad314e, 0xcc7ad7c75b2db991,
+    0x8eaaa76bb814a47c, 0x91fca31f7fc322da, 0x35111e29e24268ae, 0x12af15a29ab1aa3d,
+    0x8940c0cf1558a468, 0x7f27ab095dbe2eec, 0x770d9c5652339946, 0xd0c5ebaae4c42e12,
+    0xfe989fc75d7422f8, 0xe63a54dd37ba383d, 0xe563641cbeb60912, 0x24305edde0e91692,
+    0xa094faf331a458f5, 0x85de44ac4051a4e1, 0x29a6b5a53e02367f, 0x4dd15a4f7e95126f,
+    0x8511a19ca46d301b, 0x40b5ea0ca6882ee2, 0x7205237157b3ba08, 0xd3bba81d7609cc92,
+    0xc60cb1fe5b599d88, 0x30ce0e9c74a6037b, 0x99954f624822436d, 0x8007c527d6dc9da7,
+    0xc31c98e81c680bbf, 0x6988974976157434, 0x8882ffcbdcbbde3b, 0xdfd5adf5f1c5f76e,
+    0x92de5e1e9d9bc5f5, 0xa704712da7009a44, 0xee9dcab0cfdd26ab, 0x8ec3f32224ed9d80,
+    0x1c335fb6879f52ce, 0x94366d5f59be56f2, 0x37c62f5e31dfc124, 0xe49dfa5f136eff92,
+    0x1319a722ed8e17d2, 0x83eed7cea3c7fcfb, 0x1439d9ee93983e9e, 0xe858dddce9fc19ea,
+    0x0e13667c66369ad2, 0xf74462a8e4d44d1c, 0xfa3ebc614fce3399, 0x7a593961c8a09578,
+    0x40ddfd812871e75c, 0xb71e624d887f42af, 0x98d45fb949875edc, 0xf2a06bd02c5be704,
+    0x4d970411af33100c, 0xdfe8e98d64b43766, 0xb496eb26388edec1, 0x07caba465d501316,
+    0x7f274fb6ef6ea180, 0x8ba1c92a6b0c9b3a, 0xc4d880f0e372b2c3, 0xd9fdb0fdcef3a38c,
+    0x81db7f63e0713de7, 0xaa812ae74e1ec9e9, 0xa5b9a65e08efd98b, 0x8ee33275f698fc10,
+    0x71a0bfd2128b2e01, 0x1b559fb0af6c4e40, 0x1115c2b350d18821, 0x1631599a25acb914,
+    0x78953392c51a723b, 0x8e0fc7626564510f, 0xa4cd4965cdd99339, 0x1cd19e06f6fc9955,
+    0xd65288484f1522f7, 0x25ea52558b306163, 0x67c4fc9bdf60817f, 0x916e0e8298ab1c77,
+    0x93f25059edb6bef3, 0xde32264ccbd25b0d, 0xfab4bd4fa7dc7b1b, 0x96fbf4dbaa364bfd,
+    0x4a53c200ff214e5e, 0xebf75ff3ba4149ac, 0xe8f4eb1ebc591a76, 0x46306908cc3b0ea5,
+    0x5800670d64c54d64, 0x3ead918018f1c522, 0xf224dad4d739ed9b, 0x667067831651d113,
+    0x62c278bad9736bbb, 0xb5527c8d5501f9b5, 0xdb8e672844be4adb, 0x8190aac9f98bea7f,
+    0x7c76a4d02dc38bd0, 0xbdc7717eb15ddbd8, 0xd198a995d52c7405, 0x576ef1ed98dd6a5c,
+    0x1adc8a2974edecff, 0xda0b6c34af33c66a, 0x503d118e86deacda, 0x0204ceb78a8d6fdf,
+    0x12e82e139334ef90, 0x5bdecac74351d24f, 0x3f0850ae34f88e29, 0x069284e6eb888256,
+    0x1e81f89721c3bd1e, 0x5e0a8fdaec4276ca, 0x1aaa35e9ae7f0a1f, 0xb5d67bd1bc1a2bbd,
+    0x8fb1784dd9a55651, 0x794b63228121a60c, 0x58a9484ff839fc9c, 0x23ab6778517c53a3,
+    0x394b4bf87e12e88d, 0xba2749f7131757e9, 0xbb561fe97480afea, 0x84b3e3682d1510d0,
+    0xc5ef2e51ca36ba1d, 0xe4105a7c6d4918a6, 0x5e8b24923dab923c, 0x37f203f43273571f,
+    0xae5703571213d1bc, 0x412f1aefb9e7d692, 0xe79807a80e2a9418, 0x8116e4cdb02da7d9,
+    0x27e5028335f46b47, 0xdf3fbc691efc967d, 0xf9b085f0caabbb92, 0xec21f3c15e4ee9c7,
+    0xccfad7b5972c1cc7, 0xc0cb005b16f8ba5e, 0xb880e53621df8f72, 0xb708fdaf8a8e0d49,
+    0xd698a9cf6ffd96c3, 0x091a45b711ac574f, 0xedcfac6d254312db, 0x836e240ce138c133,
+    0xd67904ca72fd628c, 0xdf1059702404a840, 0xbef52dd41c35bc49, 0xa0bfaa403bd0829a,
+    0x49c8d3589ff1120c, 0x1e6f3e83d227f351, 0x07abdee5dbfaf1ca, 0xc19dad89f3c70265,
+    0x8041be29da6878f6, 0x33fff6b169f8527f, 0x584612270771c97f, 0x575080d6a287ce70,
+    0x50839d00e73d17a0, 0xca2d8160bf752633, 0x4cbb96eb485cb387, 0xba180cd4ea214633,
+    0x4c8cafdd747cfefe, 0x8cd7321829b27b9b, 0xb1d5ec5db7600da4, 0xd48b5b2313086bbe,
+    0xfbcd1db2e4ab4ed0, 0xf8238fe943b87158, 0x6372826f02b99684, 0x9b69bee43ed226c0,
+    0x40da2fc3aa4138af, 0xb29d75cf95b7b1f0, 0x8cb152bb0626be00, 0xc037eefc88ce930e,
+    0x6f0335159c56b53d, 0x328f656b917042c2, 0x9abfaa6cc40ae051, 0x2608aecb8c3c761d,
+    0x1754369bf1d8e34c, 0xdaf74dbb8d015f25, 0xd7e2ae597f4c0715, 0x5870b43b739df19e,
+    0xcbaea7b4282eea4e, 0xe231eb76ec82b580, 0x50df58881e52b1c8, 0x0388dd8bc7016d69,
+    0x7b17e30e4b9e17eb, 0xff50ae879093df9f, 0xe0c7bccc7dda0af9, 0xeed9ec9a501a3011,
+    0x0db6c2600f0c3c27, 0xb13dd75aa2bc0c6b, 0xcdd905f385077008, 0xd74d39b599a7148b,
+    0x25ad237afe8da8e0, 0x942a6096bdd39aed, 0x32b3c636395f3d1a, 0x94d543f5cfee0ae4,
+    0x70e8f237052454b5, 0x9d249cf0666ad6fe, 0x975665e00d1058e1, 0x6265592f44bdf137,
+    0x77b8d1b2db92151f, 0xaabfefd36e0995e3, 0x8653c2445f39cbb2, 0xb15995d487d2c389,
+    0x315a106065262187, 0xbd168a79c6f55b2e, 0x7471b0e304145675, 0x0a55bb1ca6f79357,
+    0xa8be942f5b1a9667, 0xbaccc961500947db, 0x2feccedcc16fcaa6, 0xde379fa80fb4566b,
+    0xedace5bc0371d49a, 0xfcda7afc35107e1d, 0x670c3273f3cfbc5b, 0x3acc0ca6b9565cd4,
+    0x88d28d18f0e4653f, 0x0ae687f2c5bb48ef, 0xf46d08966f698ec1, 0xfd7f961fe84feda8,
+    0xe2b8cf842a52e960, 0xfe62be1ec374f713, 0x322e9e8c9492ace6, 0xcd625ec3a513eb8c,
+    0xed99b8e8848ee3a0, 0x7e126b5b592996fe, 0x51cad9e66d952867, 0x0e516483013cebff,
+    0xce42a366c93a2ed8, 0x040fad6ff5a08f00, 0x49b06c191550508b, 0xd80e224c86e6fcaf,
+    0xab5053c45a8bbb37, 0xf34ac7e693cf4c91, 0xf2e1755425e1a89d, 0xc3c084de728b48eb,
+    0x6b562e17edb0ac72, 0xffff8f7b78ed9f86, 0x9469bc17f7371b21, 0x9d1776be7f986a4f,
+    0x599e0b4a58d8eaaa, 0xbf8c3389a16354aa, 0xf3651acd08671ddf, 0x7b0ede4173bed6d9,
+    0xd395ee48bcd9bba5, 0x30aab12e579fc062, 0x85c3bcf108f6ff25, 0x4673f029d7c6baad,
+    0xa184d775f876e011, 0x8ce317c6bd4f0d3e, 0x32f4161d3aff099e, 0x3d4561200305d56b,
+    0x648851e8983a1555, 0x427aa247a15ea399, 0xf899f2e803f35456, 0xb39d7168f66aba67,
+    0xb693a050b4a3f20a, 0x2af6a50611fe0e1c, 0x117b9f2bf5f0ccb6, 0xefbe49a121b8c8fe,
+    0x5a7513298b758de1, 0x10ee6a2b026cf17f, 0x66e39cd4ca2fbfa6, 0x4eb137549ec4a974,
+    0x39f81cd2e96246c4, 0x33212560bb00f63e, 0x3774cb6205eda938, 0x5d0cc51239b0ee80,
+    0x1595fa2c758a1786, 0x5fa5bd26c073128c, 0xa01544973856b14e, 0xab9a96d0ef734130,
+    0xa00f6000c616d1f7, 0xc4165ef75a3c1c8d, 0xb93909ca59ac621e, 0x0ffd1fc42fabe81f,
+    0xfb64530cbcf6fd04, 0xaf7046fe56b8f352, 0xbcf5f514c0c4475f, 0x38e33e6998d4b019,
+    0x32d8c6fa17be06a8, 0xe640977fa8929407, 0x2db72b4fc849d0f2, 0xf4af78bd52f29ff8,
+    0x921b4eccdb6fdf20, 0x71bfd4026c6b3b69, 0xa5572b649eb9efb7, 0xf8f7e315a83b0210,
+    0x5da60876dbdfa7a4, 0x3e67baffea06a627, 0x1e5ffd9a0dce21df, 0xd07b7469215db9f5,
+    0x2108e15ca83e21a9, 0x61a1e0cb0228a67b, 0xfeb7aeac5392fed5, 0x037cf83d73a3e960,
+    0xda1029efd3318d24, 0xc65b488a4e034520, 0x105db2429d4f448e, 0xffc849073eb01f24,
+    0x5142eb858348d698, 0x77d61d769d9ab963, 0x1f3f3567dbc9bdf8, 0xc60b5a979cbe72f4,
+    0x06e127cb01bb9a95, 0xe0d426f0870c4cad, 0xa7acf29f8eaf35f4, 0xd182075e68d22caf,
+    0x417956e507cb6fc8, 0x4fbd4ed212238995, 0x8bcef86c71dfc5b2, 0xefc7b899f40ccea3,
+    0xa5266698e3e1bf90, 0x1a80561e5294e625, 0xef50a8a7f6e962d0, 0x42d5c171b61bf0c4,
+    0xf878f6cca500c80d, 0xd3ae45d676725487, 0xe168b8c9f93035f9, 0x7653055daa448807,
+    0xac35ab7afc78aa88, 0x30a0f8b7fcd1aaa7, 0xdf2921ad8567a817, 0x32b628928727e7ac,
+    0xcee47646631c5208, 0x2a9df177c08a1bd0, 0x0a63013b99bfbbe8, 0x8b73de80d3f21e83,
+    0x105aac1eda34636b, 0xc7bded6a057bedcf, 0x04e65228a435056b, 0xd0e4255166f138fc,
+    0x5b575f5b6088784d, 0xc60616ac0b607a80, 0xeefee197a5e39d11, 0x9861a88369252e0b,
+    0xeabbe0b7500a4811, 0x568a965ff9f65525, 0x5642f76a1678cb95, 0x6edebf5350fb8f8c,
+    0x17c5330104c3c12d, 0x0070ec1adac46c71, 0x3ca588f8051dcd34, 0xb135ae727d9c7afe,
+    0x2fc3ac7fe30adc33, 0x5809621a8bc538dc, 0xfce27f6df5c56191, 0x219ff69e9a1fc7cf,
+    0x7333d00f157fb786, 0x5ed72cf92d3c04c7, 0xb74d5460aefc1807, 0xbc14d95a97c3f116,
+    0x46fe3eab652708f9, 0x09fedfac9c19edaf, 0xa5651d705bb46fc0, 0xb6f4d370b562bd8c,
+    0xa2def7b19770865b, 0xe2ffddbf8610cf4d, 0x7a443e37f13d47b1, 0x7f84a638428ba621,
+    0xf991337fc3b1d9bd, 0xaaf9aa214eeaa3ee, 0x4160c9c9be53e384, 0xd9e8edd59f9ca0db,
+    0xacf2cf999c17a47b, 0x1159d1f2a928a143, 0x5654229f394688ca, 0x38d99fc86982e46b,
+    0x318312964e8825d7, 0xcae3b8c3a95d096d, 0x5aa75e63204bd11f, 0x5eb37fe570b57858,
+    0x5d36486934ddf32f, 0x5568b0c4c6b1b3b5, 0x00a85fac022a8230, 0xfcfecd71c04818b3,
+    0xfc72daa7564c6197, 0xbf3ddf4fda89541d, 0x1d6b7508790239e0, 0xf30cdf97ae9868b8,
+    0x203e392c8dc2ba6f, 0x69ff8cd4391757cc, 0xcf5502904a987d8b, 0x7e5251955c62fd46,
+    0x7f3a0577f6389dbf, 0x56823cb0089940c6, 0x0c5684575cb3a691, 0x81b84916d6617a3e,
+    0xa51848fa64258ef4, 0x21c2e841cef8758a, 0x4414cbb222fa0856, 0xd3103a598baa0c3f,
+    0x8db9d10d0a986566, 0xcde950b3b0664921, 0xdf9d69829585af30, 0xd5dad420d4f49ed2,
+    0xfca7daf59c8b70a3, 0xdf162bfe22efc2d9, 0x2e1baac496f0f831, 0x671ce0bc1195184c,
+    0x25f2708333384bd2, 0x2db809150c2a1864, 0x4c418587c60d7f81, 0xb421eee882b96683,
+    0xb16b5fc94ea3f168, 0x7f6ae62372e7bc1f, 0xd7653c852d92342e, 0x8b719c0992d6ad46,
+    0xe7135146f9d0ebab, 0xcdd3b58cd80e70a3, 0x5e07d668c39a7f75, 0xade19f651bd6655f,
+    0x96fde0c5e7ddaf17, 0x8af334ca9fc8f087, 0xe45341a6229e5588, 0x2c832469e892c599,
+    0x44ea6eacb76623ce, 0x4cf7f9d799e71088, 0x9e5ce3cdcf54144c, 0xa80e70fa78a5e727,
+    0x017ea0778bdaac88, 0x88cf415e885a7115, 0xe0e443fbadc68f49, 0x4232574a21aef0af,
+    0xdaa18d18178aaff5, 0xced11df22e40d633, 0x8c179c61e16db61e, 0xf4a0d2728381d4c2,
+    0xe12c459080ba9e84, 0xd9ac8235a0c504ac, 0x6f40b9c9c3dad1e9, 0xfda1d4fccb038f7a,
+    0x9f45c75139e46915, 0x5abaeaf29410b94c, 0xfdbbda420f049dc2, 0x3165c65af71896e6,
+    0x8023c75487b077f4, 0x637429b6e61c721d, 0x1729de8106b04ba6, 0x0a41f620448bc3ab,
+    0xd3053e71f61dccec, 0x38ea2aa912a9eb68, 0xa03393baaa8bb251, 0xea5c9306f59eb7a3,
+    0x2dbf9e68304258e9, 0xdb22b8243d50092a, 0x575872bf3fe3e717, 0xdaae8c4054ffb3dc,
+    0xe188a03709ec238a, 0x8098d3a3b247db94, 0x13c291c08178e82c, 0x528bbfce743ea99b,
+    0x82395faed4498383, 0x0525f9b17a2c886e, 0xcdb7828ebd2f510a, 0x6493d9b468bfa0b9,
+    0x97499e3a1f5cb3ae, 0x8ccc272dff6b6a23, 0xd7f9fd41c09cc29e, 0x174c767bf4d60b43,
+    0xed7f97729c042547, 0xe0cbbdc70deee68a, 0xe4618f6797733963, 0x24051f750fa0f613,
+    0x0f09fc381b18fe5f, 0xad27e87738e733fa, 0x9d887677184b0ed8, 0xede10f1ec2bdfe14,
+    0x1cbfb25230295cfa, 0xb87be01b30f923f0, 0xf4f1387b8a224084, 0x8ae55fdfa36d8377,
+    0xb8e0aeb12927e945, 0xefebc72445f8df4d, 0xa753a76a723ab441, 0x6c8d1470a083aa4f,
+    0x120581400bfc7db1, 0xad586a8a7d20b930, 0xcdb7b5b6c0b27b73, 0xfe775722a473875f,
+    0x7e3864dec78e931e, 0xc02372f8331590d7, 0x1c08d036899b2466, 0x133db282c372497e,
+    0x794bd093b1b6b6d2, 0xcf561a46f902dbda, 0x8b61e85460210894, 0xef4f17244742aa3a,
+    0x23baf06e8f3ca5d3, 0xe4bcf14ddf69cae9, 0x741976de98234301, 0x30d0c5bdacb955da,
+    0x281208b1bdffd5b6, 0xff9abd745b52953a, 0x99895c76e7b7fec3, 0xc5d8a06294d38771,
+    0x3650d6fde5fc042e, 0x53da4dd7f5306d8e, 0xe8fa1600c781e902, 0xa2b034613882bb6a,
+    0x45802ecb7b19e6ac, 0x0682c1724f7bea84, 0xebbfe88d6ead9826, 0x2750220af82ac7e6,
+    0x515beabd56f66554, 0x6619962d5dd4c71f, 0x4cc2f27b680fee32, 0x1cbb5c3f8634ca02,
+    0x399c1ec179f8749e, 0x0def60b4376d6063, 0x163182494bed6132, 0xea471865e4d4a01b,
+    0x59501b57e441a442, 0x803ccb34cdaac0ca, 0x7483bebf5e284aa6, 0x1da9be8b644b620d,
+    0x20ec1bcd91fb1b7d, 0x7d58bbe111580283, 0x8c551a90a8c2c8f1, 0x282d74b34d237c11,
+    0x04bb4b511e9a35e4, 0xeff5fbadfb619b09, 0xaa8b3f7fb2644afc, 0x754deebeedc70630,
+    0xe88c3d4d4346ae43, 0xbfba5dff4658c58b, 0xcb3149b2b0eb9443, 0x4ab3b1e0f602e9f2,
+    0x7dde21184099137b, 0xe81340da6a2a1a9a, 0xe43031eb01df8f19, 0x60f33875bc8ba47f,
+    0x3d6a2479b8f235ab, 0x41d196ca03517b31, 0x418c650b363bd83b, 0x700a68559bda097d,
+    0xbbac6434d24f3c7d, 0x573bddd910c33cc5, 0x9ecae4439209d35e, 0x53bb2ff5009a2ca7,
+    0xe532667ffe15b2fc, 0x2e54126168021e9f, 0x92f786bde863c0bb, 0x1e7ca4b4bafa555f,
+    0x7106c9f07e129bda, 0xf95958d2b5ba5f61, 0x20f9db94153550dc, 0x831d55abee6129c7,
+    0x8489eedcbf393668, 0xff966a302f64645f, 0x85cf01dc19a29ef6, 0x27b8ba03a7b35c5b,
+    0x90f64087dc39fa9a, 0xc24ce5bdea7a8327, 0xdc619ee73264bad4, 0x47c9cc1c90e78dea,
+    0x5845a587de3622e9, 0x9c837889772d8e5d, 0xcaf3c84a8781dec0, 0xe24fb905612a2d57,
+    0x542e893a021d44fe, 0xccd9cc2ca8eb9a6d, 0xbfabc64c79b9b659, 0xb3c316e3bf67dc12,
+    0x0fc8f90c79b03113, 0xd6aa230c47e4f5b9, 0xf8f46a0f47771e3f, 0x7d9f4a38f4b3e84c,
+    0x962a40863b06a9d0, 0x926b60f9de520f31, 0x346525c44d496acf, 0x8d5518fa71f0d2ec,
+    0xf7db51259c181441, 0xd58917cb65cafec0, 0x9d13e18e78616868, 0x702b94a8db35e4aa,
+    0xe62a9b08ef2f7d58, 0x9421457baeccc0d9, 0x09d1db20d0533a68, 0x80b9e1cd21e69126,
+    0x14a3d61410174ecc, 0xbf8ce2029ddcfdc4, 0x1b21435da257e363, 0x06c004d1a404c0b1,
+    0x4aee11587c9c7562, 0x5f21eb69c4631ad0, 0x91ca7c58276f44a1, 0xa3b3e6b9005fe281,
+    0xe1dc1629d4aff35c, 0x2c79ff167f5b97d1, 0xe8148297b8a68e8e, 0x586d6e56a56d781d,
+    0x6fb7ede6da4d653e, 0xc03ca8d1ca160e74, 0x7571c9b4a645ab1a, 0xaf203c6916bbeb28,
+    0x1bb82e3cc66dad08, 0x789d44a6ab678792, 0x68eee5d63c39e954, 0x57b17149ea2ec0d6,
+    0x7e8b4601adc0e534, 0x204cb233c3d31866, 0xffc27db05794d323, 0xc5c4f55988c22003,
+    0xddfd3da742d68f94, 0x1ca57185abc259eb, 0xa79d96d73ff42693, 0x992eb90b5907bfb0,
+    0x29722ad88055c09b, 0x055938c028f881cc, 0x3934c16b21795b76, 0xdd91115db17a9816,
+    0x798fb5e6228acdf5, 0xbad0118c9017d408, 0x29f13903965b611f, 0xb557434788492ac8,
+    0x493f3c6460e7f8a8, 0x05278b423606e630, 0xbba020df2503900a, 0xae03fe329bfde1ef,
+    0xc183002b0c5385b3, 0x3d9850a997d84c13, 0x5a33091cb6548a8f, 0x40872c8fc4c3bd8a,
+    0xfbc23679c6ce7b9b, 0x1812c98fec300d0a, 0x7a9d423a3a3e2383, 0xd89c7e83f549a023,
+    0xab2619e07543755d, 0x6efaf70192f4eebc, 0x2331284e64a18cdb, 0x1559a8e4557d45d4,
+    0x638e9475efb9d3b5, 0x05ca05b53993d2c2, 0x911e3ee573778cba, 0x1c40c327dddbf1a9,
+    0xc324550de6a28898, 0xbb97d49202d88bcc, 0x418be56c8bf008e9, 0xe2dfdd9c06708903,
+    0xf2fd391bd4d80d99, 0x47877a683b0f3f9a, 0x274fb138c2fdf39c, 0x7b78a28692c8e2a6,
+    0x2dc094a23d72caaa, 0x339776a266264a13, 0x497a403e158348a2, 0x8403c4933cc2cea5,
+    0x1db3ac1537c8a12f, 0x80581e86ba10886a, 0x785cbf3f5d232b92, 0xcf4c628f6b32c8fc,
+    0xbdaa22d59cdbde3b, 0xde2c63b45e9300b5, 0xb64b3891294fcdd6, 0xa02a910561fbdaf7,
+    0x2a04c4747bc3419c, 0x4365c7b41b21db1a, 0x80fc2489b7f97ea6, 0xc4fbcd7eb10a580c,
+    0xc4d31b41761304a5, 0xd9b1093e2635474f, 0x7d98a1a515e86e2f, 0xf39eac474ddfe6c8,
+    0x05135d18573a6467, 0x4817f2396002dfea, 0x1c7bced454615ddf, 0x45b179f20b8e8417,
+    0xcffe2e934ee2b59e, 0x06fa54ee76f1669c, 0xc914ea24543dc0fb, 0x9c255425f3eb6da5,
+    0x097704beb0fe81de, 0x29cb824da8b8ae62, 0x4bb7bc064f115d8c, 0x181bdc707b850bc8,
+    0x61a7b70daed40934, 0xb39e9b8d56e9e6b9, 0xf1a7efec511a51e7, 0x047601b598d176b8,
+    0xdee73d6d3ee1c5b5, 0x9098d2eb8d5b81bb, 0x066c5f3f0e69e57f, 0x598a656b6f0e5b3f,
+    0x536e363e4ca043f4, 0xdfc9650dd3703a42, 0x9863df699f7bd148, 0x91b8817ae9e6d852,
+    0x703d25d6ba902c2f, 0x47ee094aa2edd9a4, 0x84a56104db5f4716, 0x2bfa5673beebdc94,
+    0x9bff711b473d457c, 0x6dace6a590e78756, 0x0ad6b028c01e7ec2, 0x12d785e030f50ec1,
+    0xc752da58c5817406, 0xfad5c99656744d72, 0x72a03f25515b5502, 0xd8688b1f0f84a158,
+    0x61fdcbe4a790e5d3, 0x4b1c85b8e831849d, 0x4de61bcffb9df251, 0x6ddd26963069354d,
+    0xdf3e39b876bed7d8, 0xe721c5324a7f9b3f, 0xaa1cdd1e75a0741c, 0x91e11b440263a942,
+    0xca7c21a90f9842f5, 0xd7d1e258bad118fd, 0x391fad4c54b77715, 0xc3271b1a480664f4,
+    0x0939efb8af47793c, 0xabee8a87fcdd125e, 0xc95e25526845cc33, 0x74322e3ee93bba09,
+    0x1e7ab9e822c62b0a, 0xdda66168eb3c5d18, 0x09c517286909944c, 0x797bd1ce942492b2,
+    0x2aad712656e832d2, 0xdf7330ec1cf4c5b0, 0xdc1f5fc4fb6f9ebb, 0x4b669ef97397113e,
+    0xd1b7dbab7dafbbd9, 0xb961416653043b88, 0xae77c16855a6a357, 0x23e73dd1386eeb1c,
+    0x01c715abf8d65929, 0x225ad7fda2c28fc2, 0x01535c6373f9964d, 0xe6b7bd440c7aefc5,
+    0x0d10793a6406e3f8, 0x88b1764c967e196d, 0x706a56e5f9305c29, 0xf79334e980a25eb1,
+    0xf2a447bceb7db079, 0xd6edc7f82178d353, 0x9cedd264fc184e1f, 0x77a3bcca00f56f70,
+    0xe977d259d30821d8, 0xf0f8a14ec9e70a41, 0x7ab9bd2d41cce538, 0x69ddf29b9e00e471,
+    0xcc3be9e2c7fee3aa, 0x69f25018a4e7d5d1, 0xd05e7a6d572c797c, 0x7ea18bc699d3935f,
+    0x607c277f8988e8bb, 0x26fb65da8d21671a, 0xcf9a27869d367ab0, 0xa3da47018be11981,
+    0x86a12becb640f07d, 0x41b3c5fb7c381e1d, 0x1e4c0c1e667601fc, 0xba403f6c6b7fbd03,
+    0x4217f822a1d7c654, 0xc3d75d08d37e881c, 0x6e94837e56b6d974, 0x1c29acf9a9b1d7f5,
+    0x2e05ad37b2cb5388, 0xdb39f470560efd2d, 0x275e923f8a41249c, 0x1e3e4b31db4f34a4,
+    0x4e6df40b4abf42ec, 0xf61e8f5d1a056689, 0x0e2a0cef2f14ec04, 0xc713b1fd2331c7c1,
+    0xc1123630a78d339f, 0x23cfe4072ce94b6a, 0xb4f3f19d545cb317, 0x97901ca58645a773,
+    0xf0edf08b46301374, 0xde013528579f356f, 0x3328307e6510dd78, 0xbe582517f01ee85c,
+    0xe7241bc46c4b4954, 0x50cc48ef60f34e5c, 0x16bc284f8d1b0571, 0x21aae1a9963e97fe,
+    0xd7a9e06b20d3a1af, 0xaa0db3092ff6f2d4, 0x9bb03de9fa8b1a1a, 0xfa243327e9545f8f,
+    0x7071758c1804da3c, 0x237dc57e2d2f50b5, 0x93a9415ba8ae2528, 0x7d6b6d30b2ddf4a8,
+    0x80e41a5ef2da3ec8, 0xfc7d645f505a52b9, 0x9d805b79057193ad, 0xbfef6f98bffa3ccd,
+    0xcc5bcb67492787c4, 0x977c833a434e6c8d, 0xa13b81f305f9cd2c, 0xf7a8ffa3d4e94566,
+    0xf780ac3afb88182b, 0x603cf424cea20a3a, 0x0fdc5f647cc4c147, 0x9750a82162e96490,
+    0x146afca695b7bc29, 0x174d942e5583ba69, 0x2a6509bc8492b5d2, 0xd4296504718f8148,
+    0xe9c7128dc9a9110e, 0x9a26c23d90f7738e, 0x302d36ad43f4047c, 0x09562b7a3de6e413,
+    0x967fa08df6f2efad, 0xf81d7898e3cc80e5, 0xab53fc531f7b940b, 0x063dbf5b30e4613d,
+    0x41ce117b9f77f5ac, 0x7fbdde5fdd57a6eb, 0x9c0d5c7c27734e27, 0xaec107f9f0f70df6,
+    0x99554515b316d352, 0x20e67e7537351626, 0xfecff877e267082e, 0xbab48a7946f655ed,
+    0x032ec0f07c45653b, 0xe31735261ad8e9af, 0xd1083f87fdf14453, 0xd85e639974c9094e,
+    0xf8aac47c02127376, 0xcdd76c7525cfbc78, 0xdc1a41e24690fe60, 0xe043fe43c781ee05,
+    0xb572907aaa8d8057, 0xee9e4c26da085f1c, 0xbea896806bf00203, 0x658b76d292fa0b0a,
+    0x795d78c49ea61ca2, 0x2400ab370211eb83, 0x1a23febb6343a527, 0x54fc714b393ab3c8,
+    0xd069e70d638d4115, 0x08957f0fc6841ef2, 0x89dbe6e3fefb00af, 0xc19bc6faa3388b98,
+    0xf839e0687815c8cf, 0x8b837cac1d0cdfcb, 0xedfd5400fdcbaf3a, 0x59b4fc77b3d1c49d,
+    0xff7e55b485c62f48, 0x3c7ffa45d50c6ee8, 0xecaa21351cca1b72, 0xa9855cd23f70d3aa,
+    0xf4543bd2d79f557e, 0xbecd212a61f3ba25, 0x08244e77e8db6c52, 0x246b062c34b469b4,
+    0xcebc4cd71d1dd5ae, 0xa67782b2a5dd0867, 0x859411c22d087f2c, 0x1cfd6b34c2d7c089,
+    0x56b324316d66a310, 0x8a4f1ad22ec7e494, 0xc449c21d0329f11a, 0x9003d352d26f6dc1,
+    0x33bdc4211792a17c, 0x466749a91b80f016, 0xabfa3b6228bbe001, 0x2abf8e71fa05c46b,
+    0x587cc089441a0aae, 0x854d0e2ecec0dd7c, 0x10dcd48cbe5977e9, 0xa9d18df069200fc9,
+    0xb84ea2da70e5950e, 0x9a357d219b819f2e, 0xe3f17c3d8905f0d9, 0xb2e91a13d429401f,
+    0x34ad766543812b4b, 0x405d565b124fe652, 0x0f788c5c209ad5b3, 0xd482d8e0e2942a09,
+    0x668f6507a6bcce9f, 0xb23198d759a36c30, 0xaffcfe67b1b87caf, 0x5c2f69f9b2492838,
+    0x31275f70f44cdb60, 0x0c77e71ca8c6886e, 0xf2556c2044ae669c, 0xd24ad4d8713114ce,
+    0xe3505f735ad30bc5, 0xe715411d60adfc8c, 0x8ebf74968edc9ebc, 0xfa55f2592cdb6326,
+    0xdd394fec63e5a19d, 0x0a50eaeb9ac54cd6, 0x6f5436f9526fc431, 0xe105a8a10b7162ae,
+    0x1c3d0334997c74c5, 0xb43a052187ccc8d5, 0xf43d23e434bfac62, 0x6386d653e80b5450,
+    0x8e6cbe8baa01ebee, 0xb7c2770621c6fe40, 0xc692863a4f7dc6ce, 0x82d05a90153a2337,
+    0x271d03acd8394bcc, 0xbf2d2cf617be6330, 0xc2f23ef856a02f38, 0x2955d4db322a58bd,
+    0x0f34ddab3628fe6d, 0xa756ad77ecc648bd, 0x4521af42193a2b38, 0x5c5475246c24997d,
+    0xaefaeccff7ab6b9a, 0x1cfddb3ca791bf0b, 0xd88307090bf5e1c0, 0x0ccd81254feda0ac,
+    0x5e0ffdb319dd82f3, 0xd24e8ad863f7e296, 0xf2162bfef33f071b, 0xa6a2f872b197c1f5,
+    0xc84eef2d3eba2465, 0x99711e0bd57cc829, 0x5c329a5fc146bd90, 0x0f3e908544636bd9,
+    0x43cc4eb0a2e48b34, 0x0980148d9d868094, 0x0951a2ca65bd8a1f, 0xb10a6d815a86ad23,
+    0x0051bdae56431b6f, 0xdead6fc5a5262825, 0x1caab90062afbd1c, 0x5639e6d111d819a9,
+    0xb40c0080c420e015, 0xd5bf5380341f35fc, 0x407746f3f9ed6b54, 0x04d9b1353bde2ef5,
+    0xcd250f32278f3997, 0xfed4f34d64f73688, 0x858beb9761599fbb, 0x587140d9aeb48ce9,
+    0xefeb39b6980b7e29, 0xd1007c7f0589e7fe, 0xd5266ef66c03bf46, 0x904be183e773c5c0,
+    0x4bf5cb7b0d01807d, 0xf0604522004e46a8, 0x9596b78b2aff09d8, 0x148aceafb0d3a228,
+    0x4e5f3b236d9cb710, 0x8a0f5d8474820ebb, 0x31ef3703d9d13897, 0x597a7cf22ce1184b,
+    0x49179a0c2cb3e3c2, 0xdbec0fcff2cbb892, 0xc59da51a62e0dbce, 0x223dec9182446abf,
+    0x924d9dd0e19e3b5d, 0x14ebf32d6698d949, 0x5ad315522481aa40, 0x16577fe40a595362,
+    0xfd514171d2acd1cb, 0x4079cff56c1006fd, 0xac6788f2a4579548, 0xbab2f19b6c55dbe9,
+    0xb61b226d6586ba34, 0x7088609b52d097f4, 0x74277c48f2ff8245, 0x9f4b808fe9e45786,
+    0x58af6d3b49d1c04d, 0x6ba2505c8e054338, 0x9fc5effe395617e5, 0xb2a00cd72b7e9058,
+    0xbb7b754e12c0b483, 0xd10aabb8a9d8f9df, 0x0edcf89f098041a3, 0x920f967b71f03cc7,
+    0x6e3a85f429466b47, 0x6ab1ccf33924e2b8, 0x9fbbace37634a1b2, 0xdb81b359b9602052,
+    0xa3b857681c6fd07d, 0xf95a3b88c48afc9b, 0x3977402def625dcc, 0x40c195a739a195de,
+    0xe0fea20d893cbe49, 0x860edfb26dacfc9b, 0x72d8c2ec6520b955, 0x4a72dbf412d2fffb,
+    0x738a3f9430cc4c01, 0xe1d3f90a6b86bd83, 0xf59ba4c6822d1fc3, 0x1918791a9717f707,
+    0x4c0772e56225ee60, 0x21fa0640ac521caf, 0x86ed422379cfd185, 0xb762ef6eb29fd4eb,
+    0x6805089c33064747, 0x52e29bf72afa37bb, 0x60cefc8f98735806, 0x40f9375a8ae6991e,
+    0x72de2cd211c5d971, 0x9be1bd36a464cb99, 0x1d9b07f3f28969c5, 0x019487db7a9a9fcf,
+    0xcd0491f540427a82, 0xd592d0a684d37568, 0x61693665ae990b09, 0xe6733e236fdac2d7,
+    0x807818da693e0b42, 0x1a944dd044aa37ef, 0xf248178986f515b1, 0xa2bbc2e7057efbdb,
+    0x5adad67173d7f760, 0x7a724e0b4aa4f7d1, 0x02f45b6e0c8ae42b, 0x50dc3c5ded280026,
+    0x9e699377802aa949, 0xec8e19b57edbc533, 0xa4fca3030da4a0e7, 0xd87f3e7cb0db657b,
+    0x284b87b08416fe90, 0x8d87667644ac598a, 0x6b9b8a4e75f20602, 0xb46b190e720de242,
+    0x89473ab191ef7d59, 0x6e2041eb64b0c4fc, 0xe1cf1534077d97e9, 0x96c11d567dcc2967,
+    0x24d8ea3faba51a0e, 0xf3106e1c7af3b575, 0xafce94b167526ed8, 0x0c39aaa594bc98ca,
+    0x228f2d52ec9a6d61, 0x6e5ff1ed1bdb2192, 0x470f79209642e36a, 0xbfde48603dbdefe7,
+    0xf5939fa5fb2f14d5, 0x7bf0a03f20ca9bf2, 0x4bcfc2fb81522232, 0x6b1770a2141dfb1f,
+    0xbdfeaab50ee8d322, 0x00439f2bdc6d3b8c, 0xa702e62a8872ad08, 0x4a85464716d67601,
+    0xe2bef0aa3135dc50, 0x0ec48056b2738b01, 0xfbf8a22686013554, 0x7e37d852b8b5698e,
+    0x726df75f738342e4, 0x2d06d60ff1796bc4, 0x0bc405c82e00c035, 0x58584e78253b46fa,
+    0x3cd554c9c88aa504, 0x3859e6a087a7447c, 0x455dc721f1a6aa1a, 0xa14e45d95187f2c6,
+    0xddb707761a04f655, 0x2dff5483c51dd897, 0x68e46a04541e7bef, 0xd4dcc4600309a876,
+    0xdd31134e5c6d3a1e, 0x55b9648a5bad3d38, 0x35890a04c61b6065, 0x76ec56366061fe63,
+    0x2ac661ba702ff1a7, 0x9ea99be6f82283f5, 0x65dc52f42da04afa, 0x226469f125b02062,
+    0xb86b85df048d3ef1, 0x063f20b2dab7c211, 0x210211a71b8786fc, 0x66252ae77dff1947,
+    0x833034098f574eca, 0xdc8206d0b694f679, 0x8f1f9775d108dc32, 0x1ac88954359613fc,
+    0xbe25f3bc8c0b4569, 0xc2aa4086568fded4, 0xe050ba4ee6d47529, 0x9a8f83b30cd2f279,
+    0xe3c7d16598a22b4a, 0xf70d0cf8d0c86a40, 0xa93997c237a809dc, 0xb5ec32ee1f91fb3c,
+    0x710da3ff44087e2d, 0xb1a4492181c658ff, 0xa9ef6f4b1be2c91a, 0x8bb1f657b1933bd4,
+    0x2b6319a5c562a275, 0x7ac5cfbfa4f778e4, 0x85b5314e6c321536, 0xfaf4524dc08178a4,
+    0x1ede633620576a31, 0x05c8574fa6c2c9ea, 0xf6a0b1de82d683ef, 0x557faf68d858fa09,
+    0xb75a5765c20c7295, 0xbc6fb14ac707d562, 0xf89d840232605378, 0xb663b5bfa3e84e4b,
+    0x5940c06365f20d33, 0xc99a924ff1d04ada, 0x4a48e36767273fc6, 0xc3dfa8d8b59ce014,
+    0x118e546e854c4dc5, 0x88e47e61ac9daaf9, 0x3e264981b5ca612f, 0x768c86156835eff7,
+    0x884396293fd80ab4, 0x982e9616dc099194, 0x24be20bcbfa0ca10, 0x6905846e0e43c562,
+    0x092942d4542ddc4e, 0xf5259d3f99af94b3, 0xdc06c0e5a5135be9, 0x92e6c58f07d1f8f4,
+    0x290023da8341653c, 0xca7556e682fda8b6, 0x11a5b589c3119795, 0x3d4de20b80555d4d,
+    0x226a48b8701b7bcd, 0x63c75d2d9a3de176, 0xe0f1928f26ef3b0d, 0xe40a0692b69919a2,
+    0x20195a581dfe6482, 0x35b67cc9c8b32225, 0xdd45c55390d97cee, 0x0756a0b734455b5a,
+    0xb0e05d2145b733f6, 0x8cf8e3f6597d6951, 0x2bca05fc0bd1ac24, 0xc108e4ffb36fecf9,
+    0x43bbdf1cd00328e6, 0xc3a5ae1f0b082450, 0xe36ba82e6755e46b, 0x16eb7d7206deeaf0,
+    0xb2be1f9f2e2d0475, 0x7957eaebe979b1ec, 0x3fd8135599c17ea7, 0xd77687a7af6a060a,
+    0xe4ace4b04e8d2673, 0xb301ed15bf234948, 0xe76b5b919ed52624, 0x9af1e2917f7b2457,
+    0x84a2dba5efa5423b, 0x0a3903d110f755d9, 0x8b081e343595749a, 0x5fc8f56594627033,
+    0xda531d907a82e833, 0xabe9520a389c0184, 0x3242397a9d91cbeb, 0x7c061ef4e5d22966,
+    0xeb3999a7a159d136, 0x7ccf90cbcd23507d, 0x5d5f755c3f7bd821, 0x4cf7b93b8836cc90,
+    0xbc0d5be9db304dbf, 0x82673ec25edef279, 0xaac21445db05b163, 0x962d52f42fbec2a8,
+    0x1cc024347e0d7995, 0x2ad1a3768379d7e9, 0xacf0c1ba0b0f6cc4, 0x9fc259a914d1ebbf,
+    0x1ae63e5f138556f6, 0x2b08055d115efdd1, 0x469ad810abe37007, 0xebce1d4e0b983c72,
+    0xec776117c6a34c6f, 0xae68471d5ff50482, 0x1dc6d842d6653925, 0x41d15e73c7554c1c,
+    0xa2fdca2f339842cc, 0x6606c81faa937952, 0x03ea4864ebe0f46e, 0x37e820baf9347e0c,
+    0x12af32d23e49966f, 0xcf4a3831dbbcc091, 0xb2d1dde185983cef, 0x03420697b2c4af2b,
+    0x6199eceea61a8ed5, 0xf5fa41bda98e81a9, 0xd5dbac2cf7cbaea4, 0xcedab0d805293b86,
+    0xccc37c7f84de3ae3, 0x601aeb204e0010cd, 0xf50b87b2a9d3fea0, 0xe037877a7d80e420,
+    0xac4d47749ac4ba82, 0x484983a1e3d16c25, 0xc78bffd2bb3942cd, 0x1308d18e512075ae,
+    0xd6189633540ffc8c, 0xb37fd0782cd75fd4, 0xafda575666e6c1e7, 0xf53b409735f79261,
+    0x46b46166f8605903, 0xbb9a8b998bcf7ba7, 0x189335873f69cfbd, 0x904c027804bde7b8,
+    0x23ff791e30fac3f9, 0x883ee2e4d874758c, 0x23221039757aee36, 0x158b300960b3a4ac,
+    0x35ba68a97366763b, 0x578c5ad33165c59f, 0x6728123fb6ec21c6, 0xd51713de90904491,
+    0xda44fd1cb916659a, 0x0a604141b58f792d, 0xa35041773c5df048, 0x8f1e54150659d19d,
+    0x85627a6512e915cf, 0xb3fdada927d54821, 0x168637b7530b91f5, 0x4ec170a8aab97d65,
+    0x58d83880f652710e, 0x30d369dfcb3807e7, 0x15507da7d9d29ef9, 0xb157854e37070be0,
+    0x61d402a97314636d, 0x5f4e73efa93c2378, 0x04dfa7c828e01f8d, 0x1f21e351697e2b8e,
+    0xd93c8c989c4cac42, 0xe22660caed0f9b75, 0xb93eb94accae3b3c, 0x0176bc2c877035a2,
+    0x655d2fc25b41bd40, 0x8de42b3cb4e1da52, 0x7e6c0f9c10d9b0d2, 0x59c4d5cd7e8f02b9,
+    0xa619a689fbdff965, 0x299e20802a80dcbf, 0xa8e30916178e218c, 0xba6e166d61acb511,
+    0xdced0ec4b276f506, 0x2b4f6f79159845f1, 0xe07ceda2be6320f1, 0x4c830e0818c8690b,
+    0xbb57cb1f9bd54387, 0x091de329a182e36b, 0xa694ef83b6f9b5c5, 0x60bc2549607a08f5,
+    0x450f1b96153f1dd9, 0x491b779a9c583e1c, 0xb41f49b08453560f, 0x179cff7f1c395ca2,
+    0x9202e840cdbbe43e, 0x79dd7523beac7ca5, 0x77a1c888c4333207, 0x5e8b5c075d0d64f1,
+    0xdfb5a35cd7189409, 0x7d0d4e55313c0130, 0x072ec9ac0479c078, 0x76c0eb91f66ac4c8,
+    0xa00b110d4f52d71d, 0xdfa2e345c8654468, 0x7bfb8efa281e1380, 0x6d399007809b7eda,
+    0xac50b1fd08c1d14f, 0x8c4980643b736f6a, 0x71cfd6678de7d26a, 0xc10751b76443e940,
+    0x7854e5066864a29c, 0xc65250612e64cfe7, 0xd9c99782a8fc9a6e, 0x3fd82b4b26dac3c7,
+    0x14d6a88af4998370, 0x5dc854a78be6ee53, 0x82c1a228559bdfe5, 0xfce0e9eca01f7126,
+    0xbcc2d0ecde60fae0, 0xe28b68b2caf55fac, 0xb3615a7487c89c6f, 0x25f3c60efb61fb2d,
+    0xb2b6eef7ae3da9fa, 0x47ef4f5afb8a537c, 0x933fe96c0c56e9a5, 0xb219178e4351d90b,
+    0xc1d3cf21d92ac856, 0xfe5643fcaf7dd2d2, 0x9e4af6c536eb5a50, 0xb9dbbb8a715cc8e5,
+    0xda142f89ae8d54a5, 0x25f72ff19b0190a5, 0xdbd17bab4443d8cb, 0x8f11e3c6302eac3e,
+    0xe0c1e062255450ad, 0x12c5d79c6b1946be, 0xaf501cae2a62976d, 0x633c8481612e46f8,
+    0x11c05d5a068e2e06, 0x50af0d99c7cdcc84, 0x73d41e345449822b, 0x1f915f8bb3a97845,
+    0x0f903b59e09f797c, 0xae8940133dd030a9, 0x2f67ad7bf8a348d1, 0x00cf97d3b60f4734,
+    0xb1d767c1de14b318, 0x19bfbfa05effa2a5, 0x900f0d433a6edf96, 0xb02500d5a2b3724d,
+    0x7d082b65ae59abc9, 0xfdf822fb7e3237e7, 0x885109e2a88f7957, 0x8c422083e9ecaf6f,
+    0x4a09edbbb76f8b4f, 0xd767b24bce3484b7, 0xf8bf3aa343b9593a, 0x69ae452759cd2943,
+    0x483f2746ca49daf8, 0xdcadac27fe2d5187, 0x21a819177580d1c2, 0x160be67b58624c06,
+    0x2cc7b63aee21d252, 0x919f8b8f7ed821eb, 0x309a96f30cb0f89c, 0x0a95a5cb5f3b8b91,
+    0xe3f9b626111681ed, 0xbdc17274104da4e3, 0x10ffd6aef53d44a2, 0x00160682e8639bf7,
+    0x17587c0751f21ec2, 0xfa7342ba4b9dbc0a, 0xb06c5733337f45a8, 0xf036d73766a3ea6b,
+    0x91098a45b64d9155, 0xffa1fcf2784f0d53, 0x38907db94ecced58, 0x07c66a79595323f0,
+    0x5d1e353ebcc43ebc, 0xa545df3149e15ce6, 0x21c60e6094f83634, 0x3fc8cbf5bb9a00c3,
+    0xb7f27ea758eabd5f, 0x8d1e05bf4df4b562, 0xc12a7e80ec511bce, 0x6692d0d6ff508f34,
+    0x5e1e3f4ed75ed70b, 0xf796ba9da4ed3f89, 0x4277706cbed7af17, 0x5fd78c5b55706792,
+    0xea7a437690fcf974, 0x521c98cfe4aae38e, 0x6752aa675edc95a7, 0xa39406a1c3befab8,
+    0xb6ed6ec1d8b6c3cf, 0x540d3f168343bc67, 0xd0c379a32aee4e27, 0x5fd2431d0e37874e,
+    0x301cc6127a4dd915, 0xfc02ca1ac2f739b9, 0x57d4ff4e2750e6d2, 0x662ce5665f0f4dc4,
+    0x091967940e40eb78, 0xc3be8a1af5c8e627, 0xda567f48bc69aa2a, 0x91cb058caf40b8fc,
+    0xb741fd8cde93b6e3, 0x4137f1f37adf3be4, 0xb548f04a0db3f214, 0x4cca56d6f1ba9084,
+    0x91178f258fea5a03, 0x2b6e0f3e9f83c37b, 0x2efcf4cec396408b, 0xb9c23500b650b926,
+    0xa8f4ea653720f9e5, 0xf71e579ab49ed074, 0x0764142a46b5baf5, 0x9bddf73491e6f33d,
+    0xf7aa65069412daed, 0x594702afffe92bf1, 0xac20ed07174ef3de, 0x6e6d9b8ba29180d2,
+    0x2277d219252c5cca, 0x56f0891054ffceb2, 0xa8d45426b9dfe1c5, 0x03ce18d7ca671b7d,
+    0x76f97864208e25ed, 0x8b638d8dbf11076e, 0xbc2242abc58e0665, 0x53e91e189629ea9b,
+    0xfcbbcdabd58a538e, 0xcd11eaf5ec3fedad, 0x6f078b1fc09286ba, 0xe8e8a541cb25cdfd,
+    0x29c0b0cfc2cb802c, 0x31719e1a5476cbf5, 0xbcf360ffc4d648f8, 0x00e0e22f8b3dd215,
+    0x7c1e645f5bd350cf, 0x824b34cd1ae8c202, 0x0a637af16aa2c681, 0x5544e1ce737f6da6,
+    0xcf6777fa58c6bf49, 0xcc45a65182e08a93, 0xb7ab9b3a22e5f7af, 0xc879ac48f15dbe56,
+    0x93f3f6c76bcf7dc2, 0xe1848f27aa1467f8, 0x0c1f57a9c17b125b, 0xa66d78e4fb247a42,
+    0x60ce8693f53defa0, 0x7e12e8af53c653d9, 0x48d9d8062b84fd2c, 0xa240f74e96590e8c,
+    0x5d042e9266427556, 0xf246cdf52cab5c4c, 0x323edb39dbf15e41, 0x303172c72633fe5a,
+    0xf272448cd65ef837, 0x12bdb1799f318794, 0xf1dd774d37de2d42, 0x369354deb1b2da25,
+    0x06c02af1c41a51a7, 0xc80a31707b7e063d, 0x37bc908928feb481, 0xe30ab42550222679,
+    0x013802cd4dbfc858, 0x9ef4e2512a91684e, 0x8257ded9cb74356e, 0x660f67160ec1a7e8,
+    0x31204db8c7e93f5c, 0x758acad5208cdb1c, 0xf9c56ea6ac69cc84, 0x8a4a302154acfece,
+    0xd5a9c9232d3a3aa5, 0x386c84c44512ff35, 0x242a44db5f76bd23, 0x42d85391f547829c,
+    0x0c9d7dc4ce169180, 0x2ae252a9f2077868, 0x7ac797c8805595f8, 0xe5bd46fd85a547ba,
+    0x8b785ce407280c4e, 0xf3f0753768503194, 0x535f4da46f1ed10f, 0x6e18ef10e166a941,
+    0x879aa8a223bd9dd2, 0xa7105fef0912e8eb, 0x762ba4d340e768f9, 0x5750266047e91180,
+    0x2c7a6248508f3011, 0x977a24eb2d77fde7, 0xe06cb0538e47e607, 0xe8b9ec3f98c814eb,
+    0x502a382b38a6b227, 0x40504fba050d3214, 0xbb299f9760b127ab, 0x16156009f5df722b,
+    0x8a6da56d2a9b62fd, 0x2a73024c9d11adca, 0xb4691acbc40a5a98, 0x931ab45e7b714340,
+    0x0d3c5aeb6feb2a07, 0xcc1e20c1ae482bc7, 0x94d2e6cc6ccb9750, 0x5eec1aa417359da8,
+    0xf003e70c12bca337, 0xe6d8522ff918fa45, 0xe3be3f443dbab92e, 0xbc21551a1c58f5bc,
+    0xce7a011742187541, 0xab5779e0d8dd311e, 0x03ebe57b9a8681fb, 0x3f535ada0b50e1d1,
+    0xf1ad3529c625c31d, 0xfb3f6b2962e4f82f, 0x6180aaeb7a85b477, 0x858acf0589e7a919,
+    0x4a4bb3ad8ca9b246, 0x91a6e08e18f50707, 0x843bc79baaf0edce, 0x37dd79e1ef7ef1eb,
+    0xb26d1ca3eb8797ac, 0x6b116361e9a48bee, 0xed065f217f6d5c31, 0xb1dd4ea2c5a8a97c,
+    0x2ed991ce34cf06de, 0x7617ee878cedb43b, 0xc371d02f42db7c58, 0x3ad0da825268cf39,
+    0xa2bf3c2dc2195e23, 0xd79d5e7dcfe2d1b4, 0x7bc0b02b6e300fda, 0x01cedfe96ac549d5,
+    0xc8d386527d846be1, 0x2575919e51761fa3, 0x9b28dcad14c7ac37, 0x0b6d7253c8d15643,
+    0x6f4c5668391f51de, 0x442d3a07e1d0367f, 0xefeada6d084711c2, 0xea216fd0935bdb84,
+    0x637090b7338de768, 0xea6a7d937025c9f8, 0x6743f3d85cbe178f, 0xb8a04dd0f3a24452,
+    0x66534b822b0cec7b, 0xaa5d99cea0afabae, 0xaee8a51a6b8597aa, 0x13d870f12d7d019c,
+    0xfb13590fa201defc, 0x6d556dd7be601ebd, 0xe1ad8497859e5ada, 0x35c841c1a22d57cb,
+    0xe025cb8c3ff82a38, 0xfd4d4847d480fa86, 0x17f655196b271629, 0xe4e3830940b7f84a,
+    0x7e0f19536f199335, 0xa2063e12b8fd3fc4, 0x60c351e36e763985, 0x44bcd560c6059389,
+    0xca8479964cc5b2e8, 0xfa52dcad82a0e9d3, 0xb1ec252cb5177173, 0x3b900e67f97da029,
+    0x7b3883dd4ac97ff6, 0xfc6ff5cc127329d5, 0x20e00b5a3e4ad95e, 0x15e5c5f63f6b337d,
+    0xa0b3cc100b3d5572, 0xa281e1d138d1d54a, 0x5f621acd8ea8c226, 0x51ef670d5f4b5a36,
+    0xd97d8cd544c1fd89, 0x282b8c2fedc66671, 0x2bb01b4c309a6065, 0x3efe5ec6fc0f2bbe,
+    0x3a4ecbe894ff6a5c, 0xb9e1aebde6dad0aa, 0x4e5a1328d4d5c018, 0x47c937a3d0d9b850,
+    0x9d94ecfac017a63c, 0x59d26070a4675529, 0x6123dffc2e778d9e, 0x57eb135b419703f7,
+    0x526427abc0449487, 0x1174567dfe018484, 0x4afdd5fb5674f98b, 0x675fbe39ceb6e420,
+    0xd1b242f2d8d82a7d, 0x101a2afb0a5c7e7f, 0x6e3904fe11289092, 0x1458919bc6059a1b,
+    0x7cd6d65b4b052c27, 0x4f99de9a37b1a7d5, 0x15c62abe25cdda67, 0x45d9ea0cd999757d,
+    0xf81521f1ba16df8b, 0x054264227e29da5a, 0x2aebbf4b9953a42a, 0x3ab9d4df7a31a59e,
+    0xd8f622983b573aef, 0x7cf7de6c728ebe59, 0xf153d74aee9b3139, 0xdfd26aca7a806663,
+    0x68072b2473914249, 0xd726dece4a8ce1cf, 0xf707617e8c4330ca, 0x0ee88f3cce193dd5,
+    0x6c325fd87f6260e4, 0xcb880c77c2de01e1, 0x855d1851d9cec402, 0x400dfe4738d188b7,
+    0x4cbc83b3fdd7b7d3, 0xa2da8f07ae9585b1, 0x42966cfed6f9c0ce, 0xacdcb0db2cc9d274,
+    0x75dc2b3a194f8830, 0x02d82bfb1e6891c6, 0x345a7d8aae0b4c89, 0x6ba8ed7dea900900,
+    0x8582462872a07757, 0x715d0350f52cdbec, 0x360a91ed10ca8a3a, 0x2a05ffd695bc1e4f,
+    0x610001f731b1b217, 0x81bb567acbbfd8fc, 0x13daacd3a50642b5, 0x04eecbcafb59108b,
+    0x74290bafa08be607, 0x72793291b3796576, 0x6ce303e4d82472eb, 0xd2cede924371686f,
+    0xaac9e9a5f8a824e8, 0x306a7456eff7f9af, 0x6578bd1e0e774f24, 0xad501021065eed4a,
+    0x8e621615a7851ff6, 0x529b449b331c0414, 0x0ebb255dad29ddf6, 0xe6376030ed6fdb3e,
+    0x1ee4d6a97b10303f, 0x90f8425bdbd72442, 0x1c71cfb3ecd929a1, 0x1d4907519fc0de71,
+    0x3b18464897617d05, 0x41373e77b5fda3eb, 0x7d76f5083d516a13, 0x91029f7a42858714,
+    0xb93557d7fce9ceb1, 0xdd4c3cb43d2f324c, 0x2bbfc799e9078eed, 0xf4922104c87a1e69,
+    0xcccc8632a4b39203, 0x2f8a9f931f0b6848, 0xc3dded57326797ce, 0xa5fb4eb90955dde1,
+    0x666ec3fdccc046c3, 0x37d232dfe34c315c, 0x1de20fdb2a085390, 0xead50ac0fb99712c,
+    0x94ed273210f30d5a, 0xb33d0adc0be756da, 0x98857bfbe7af2ce2, 0xac320a147f4b2a2a,
+    0x61c9fc0b24eb3d37, 0x24bd097c287811da, 0xce52f14f043322ff, 0xa848a65893036696,
+    0x0a6af8f07755135b, 0xd6e298cfb089bca5, 0x8e38310d4357013c, 0x244462f357a5d637,
+    0x0765439ff7f9f505, 0x52d553316086027c, 0xbdb1fab98dc0c61e, 0x74bfa09898450cc8,
+    0xdcecb5c30e520d67, 0x019367bb88092ff3, 0xff8a298e56b13c94, 0xd23cd911fa6e9c1f,
+    0xbf3a26f1cce023ca, 0x71730c331cb557d0, 0xc261a2bd75026999, 0xb74037ab1b42e84a,
+    0x320fe4b2b277a2af, 0x44fa051794d630c6, 0xe568ea9934e5220a, 0x0b016b66041f7f84,
+    0xb9c467df5cf33361, 0x9783dde0e278a694, 0x3ef623799205b2d7, 0x9b94ef0cc6a1c98e,
+    0x0fe2e823f428f2ea, 0xb098d91151d1703b, 0xd1d801f8e724fdf9, 0x7379e921093b20bd,
+    0x2c83cbb97140591a, 0x87a51c7997914543, 0x422e11522c8cea32, 0x7beb4a2eb5d9f24c,
+    0x6b3ef3f6cce741df, 0x19c807e99fe455bc, 0x7ef96e064a395a30, 0x3e8f308ec100169b,
+    0x6a08190d1fc91589, 0xbc571b905fb4a224, 0x5f4cb4a1bdf16863, 0x713e57387077fd21,
+    0xf5a0bf4d3bb754de, 0x65e2eb2716e57452, 0x0a04b915d883a3ba, 0xdcda474d89f7b8af,
+    0x4f721f5fff1ab23f, 0x018eb8febad39164, 0x0061e83f59fe2a12, 0x20dde63db1690c2c,
+    0x40e36683de076a52, 0xb05c229e5b0ef47d, 0xe15fae1f1f92d722, 0xd31950047fb3075a,
+    0x76a8712bb4ccde4b, 0xae054c9789a946b4, 0x0cc7fd42cda61285, 0x1fd7c412f8883a00,
+    0x9e1ede14036abf31, 0x36d40a578a31b56e, 0x9c7c11e4e3a42154, 0x130769bd0e114c33,
+    0xc1e389546fee38a4, 0x8f137f477c4646c0, 0x30f84afedfffe512, 0x89aa67fb592a9568,
+    0x1dd42abb51d4f9b2, 0x055012d1594e8b2b, 0xef8d846f16c92571, 0xb071a157509ec5b1,
+    0x90982c53d373bf9e, 0x478a50d9e06eedc8, 0xc32fe1cedc3b8d22, 0x88c7c1e1ec10d17b,
+    0x3864b8d503af7555, 0xc713420658b56c37, 0x46149ea9789817ed, 0xc8f9317f057c2eef,
+    0xdbc0d00e61cf864f, 0x9af9d2172c2b6e72, 0xf3f77dce543d90a6, 0xe3405000790d6ccd,
+    0x1fcfc5bc37115c41, 0x08a0553b5bb6c9e2, 0xf47e368b885fc1cc, 0x890202464ac5b8b3,
+    0xd567526c3f60bbbf, 0xb8e9502edaa22139, 0xeebe14b3774e51a7, 0x19c7b4619f77c73b,
+    0xc2bb69193fc5e93a, 0xc83ce592517222d4, 0x4a49d5a44807068e, 0x124b6c5c37bf5d6a,
+    0x0b0b0e4196db4b13, 0xec36f28b6f4654f5, 0x54ae8ba12bdf28a5, 0xcdd124dc6fd512f1,
+    0xff8760838bcb24cb, 0x1fd652dcdb3f902d, 0xbff37490f85ab2e2, 0x37a142400e862f6a,
+    0x3b5af0f1772312f0, 0x919af9361aaf7eb9, 0x4b8434049249ca1f, 0xbc68bbc3d63295e5,
+    0xa3ac0138e2cfced3, 0xaab882195d34cdad, 0x8b3fed9a73a94350, 0xe2de25aa615830cc,
+    0x9848f235eee363ce, 0x93926ae473dedecd, 0xf22fcc749850f249, 0xee81109666c0a395,
+    0xaaa7e9dd306f9f93, 0xd4b84aeda3451033, 0xe80296cd51c3b857, 0x6266170048238ba9,
+    0xdf5711cb3119f439, 0xb9932947ced4ecfc, 0xd116ea5002253b6e, 0x1fe61c0fc411cf47,
+    0x7d779b4640fcfbb3, 0x7e929b193977d147, 0x6c67421fc03c3ab7, 0x11d90396f82b3840,
+    0x36ae0c2e69240e89, 0x162f382f3ca2dbed, 0x7ec11cbd5d316676, 0x14f92482c09acfac,
+    0xb61cbb6827877b08, 0xa3d2c001f7c7369f, 0x26dac075047f4d8c, 0x69906841a2b1f55a,
+    0x2d1dbef11318d7eb, 0xfdafde5f0f3015ab, 0x9567569545b7980b, 0x92d70851543d202d,
+    0x555cd25969b31e11, 0xe66371e3e4ab7d32, 0xb9a7be695b7b8561, 0x873e696c6fc5ff48,
+    0x6cca54b368a72e1c, 0xde8619816eee5d92, 0xd94172faed002d19, 0xed4f0ed036efc5a0,
+    0x62ddf725649d2d5c, 0x75f12f84b9789cf0, 0xcd78c71ef85cf2a8, 0x3eaa49520ff644ce,
+    0xd52de8163e0d5516, 0x54b1c03d8816cb0b, 0x6d5a8b8085bcf551, 0x78dce19a10e594c0,
+    0xe60bc27ee83ec1b8, 0xa98393becc9755ec, 0xa02eb536582c421a, 0x1871fa9e7bed77e9,
+    0x9ef5b828d7b21a88, 0x116ba5b71f1faa59, 0x97bd0ead86875586, 0xe4c242fd8f059945,
+    0x2949765ecf6a60b0, 0xef213f3f91f2ebad, 0x2ae1245c2b95420e, 0x637bc681e0228ed9,
+    0x3328f217557cae57, 0xbb45908de7e1d0d1, 0x19c8e4c1910ad382, 0x1a2dd7c8fd50573b,
+    0xd5391be8312919a2, 0xa70b7f422363ddd2, 0xf1067fe5ae1fd965, 0x9f7a9d4c7d2e66ee,
+    0xec7b73f558bc504d, 0x1ccf3e770ea2aab2, 0xd73a2b425aa7c4e3, 0xe534f67946d0e3ea,
+    0x654d24e3027c01c8, 0x4315439a95dfb6b5, 0xd443f294933d3fbe, 0x06dde0f59e62d1d4,
+    0x556e50ddef73f72d, 0xd5cb41810c6968dc, 0x46e0f9026bacfd64, 0x5b0c83178f37e4c8,
+    0x69dde593f368974a, 0x3630ddf6e5548282, 0xc16c4b3ea8d562fe, 0x2a783fc5e55717bf,
+    0xfbd7ab1746fd9d04, 0x53a26e050c2ac6f7, 0xbd33dfb8c82126a8, 0xe88f64e0ddd9683a,
+    0x77bc8a74ef0e7f97, 0xeb010a9897c5323a, 0x1ae8afbc41da0fb0, 0x5bcf5e62af5f0fc1,
+    0xffa0604ef0895fad, 0x252fe537415e36e6, 0x32b395ff5f35f5a6, 0x17cdb9fa9ddf6e23,
+    0x066f9ab36dfdc986, 0x351061161ce5e22d, 0x22589586310b9287, 0xac526a9b4f8fd881,
+    0xe1310516dfb98960, 0xf59c6c6db47e3935, 0x5968d83019ca4590, 0x02ed2c41923d7c86,
+    0xee21612297d0652a, 0xa81830eb89fa713e, 0xc484cf0a5fb8b7d5, 0x531922537b8035d5,
+    0x71cf7df4b892141d, 0xd4e9b6ec370d7807, 0x9c8a5acc70ab1466, 0x3b90fa6d9d05cbcf,
+    0x76e56ef57ce952ef, 0x7adbd473f62cf99e, 0xe59164076ceeb18b, 0x695d9d21eeabf47a,
+    0xefe46fbe924ebd20, 0x471d80b4a830a5ff, 0x52d9c02ff0a84fd3, 0x59a346742fc87c54,
+    0x5c2429f0d598abb6, 0x8acf1666139e6faa, 0x5a6b6effb8731a1c, 0x87b3b8cc63b4dd4d,
+    0x6affc5421fdce76c, 0x142f03fae2dcb7ae, 0xbffb5d9443fb04ea, 0xd60b9072dba81cb4,
+    0x5af7ff568a2a0633, 0xe0553b7bab7252d0, 0xad8865a5dc9fb4f1, 0x1e73b70c460b15b9,
+    0x401664cb7f38284a, 0x8ef648fe849ca153, 0x7fb16e387979bc91, 0xbb86265dd4ad26f8,
+    0xe87be6b71d0ff405, 0x469ae226055c1813, 0x72589d6fa119d6d6, 0x761d1c4509c35a46,
+    0x3f3096e459b973e2, 0xb15d18b9f93c1a9b, 0x04f327c9de79fea1, 0xe2dfbb329fe1a28e,
+    0x456bfbfa6bb81cc3, 0x9be7b64c7f49ddc5, 0x3891c5a3c2435d53, 0x8a07355757976970,
+    0x3fb78e618c8ac6a8, 0xbcc608e424116a7f, 0x8335dfd8125f21cf, 0x902a28c08bc39341,
+    0x712b306a1d9c9527, 0x7d4c6b41653e4ae0, 0x1af5595c9384d6c0, 0x3bd130354277cda6,
+    0xc86b8e60c535f261, 0xd517c0ce7fed1b6a, 0x497ebedd2eb81ef3, 0xc33adf41484a86ad,
+    0x1aedf6288928d85e, 0x45a737b118853ad8, 0x2e5846489f4266d1, 0xfe78acce66bb9709,
+    0x51a18866bbbb0996, 0xcdff9f4c7b107f06, 0xc2672ee949752cac, 0x475fd1a3e0b35908,
+    0xcf70f418e9709008, 0x3541d6b23d82b0d6, 0xe80d729de9e69e0b, 0x62a8082eeb02206f,
+    0x45f8770ea63d30e8, 0x722b76a95d348106, 0xbe52b6f8cf4f3522, 0xa7a4aff805e3457d,
+    0x20e990db1cbe1d3d, 0xb5116ab8da2d28b9, 0x1398a43cd0b19496, 0x414706aa9197f292,
+    0x56791df71a43ceaa, 0xf2afcad8a93cab89, 0xeba8befd1ab52aa2, 0x5c7edb410bcd2069,
+    0x831fc9e506260fd2, 0xa52266a2bc3299ac, 0x3387bd12c7e28073, 0x99202fab361c5081,
+    0xb4234d1760bda25a, 0xb1d4be535f287742, 0x96616f979b26508f, 0x0cac4ae9742c8323,
+    0xcf5341554c9b0f77, 0x70ffd9fe436d6cbb, 0x53daa17ad6e0f826, 0x94c1fd456b40e4c8,
+    0x93411ee312857ea6, 0xc7482b144b98b917, 0xb376b4802e02df4c, 0xa99980444627ffd3,
+    0xd124cadb49c3b95b, 0x5b81da442c2fd06e, 0x7e9578798594b9fc, 0x78645fb5f2066cbb,
+    0xb515381e2a0db33c, 0xd619e1a68b713127, 0x7a0f5b783c517b0c, 0x21aea07a56ba3728,
+    0x9fb6a50e3af02d0a, 0x14bde5b3c2484109, 0xa1b24c2809e02309, 0x5ead4bc9a7cb99f7,
+    0x003e2c25c05e9d3a, 0xbaa1209a4ea41c1f, 0x84f6baaf48f09e29, 0xfc42013d2eb791d2,
+    0x43a44ec70f2cf9d8, 0x50735685acf18eeb, 0x76407a913a224616, 0x717227f2dae54b99,
+    0xdc1de9ebce0ecdbb, 0xa43d3ef64f951ed4, 0x5bfaa1706fb1adf3, 0x396920b08f1c22e5,
+    0x32401fad661d1ba6, 0x7ddd4ff2293ceaa2, 0x2a352d47467bb8de, 0xc6c8f626bfa816f9,
+    0x112c1680748e29d7, 0x3b076c535a74096e, 0xf6b820a0814e7f45, 0x85af41017df5e91f,
+    0x27b3f1883fc800fa, 0x2dfcc5daf9aeb572, 0xc64a9ab6ef57a03a, 0xc0f645f28f9541eb,
+    0xd60ceee14b544537, 0xd9973b612103b4dd, 0xe34e15a6a47229ce, 0x80cb04808a58fcf5,
+    0x79689bffae2916ef, 0x8e78ec0d35b19699, 0xd55c08d57b77386d, 0xbd0ff2ecafaaba6e,
+    0x611ddd6d5a0ed5b6, 0x835945cca1ea52b8, 0xf557bb3d60434138, 0x5088f1e1ab52379e,
+    0x79ec299ca1f849eb, 0x07b6ce1ef6464b96, 0xb31bcba2dbac5803, 0x5e0def5f175a3b97,
+    0x77af0f6f24d2de61, 0xdc267d3b349a8b53, 0xf9c7ee04a02975a6, 0xf39882e84bb75009,
+    0xd52a75069a6afd5c, 0x54bf520dba448e86, 0x34469a4b31167f30, 0x057382dc1d403c44,
+    0x62111dac2ba9b531, 0x6722f1e2367c3b83, 0x3e9648b6c4565ff1, 0xcbe3494157ed769f,
+    0x6a59c34160426452, 0x37a27e87b566d54c, 0xc47f4d2c2eaa7597, 0xbbf47e9f7833c415,
+    0x30ff165c595eed0d, 0x9f841e6ec98b604e, 0x4a487ee6e6c383da, 0x9aca487fa65da445,
+    0x970d448a0306d87a, 0x2c08cb5e7033c5d8, 0x9fd512476cc28dec, 0x1015c0b8b8c1cb18,
+    0x6ab929d7dbd2f46e, 0xf1cf637e8e2fc779, 0xe15c58099fa7958e, 0xd27a3891132f0383,
+    0xa57602405b276e6a, 0xe52cc305516ddf9f, 0xcebaee498f2667c5, 0x01a486bcacd31b4d,
+    0x9922c36d8b09dbeb, 0x76c2357f390a2aeb, 0xca26be5a1a40cb17, 0xa020b978d68342f2,
+    0xc659b51c8498438e, 0x651add5775374d82, 0x4515b8ffceb82b30, 0x1e04816d7c3a0cc3,
+    0x4a376b1f3c885eeb, 0x19490f8999b56093, 0x09e7e29f634bc534, 0xad0e475c22c7bae5,
+    0x4b343abaecea4c51, 0x6b4ed4ea406f91d5, 0x419b6292c6c5dc6f, 0x76842b21248ade80,
+    0x517ddc04077b2dac, 0xfbdeebe000cb8d44, 0x52e85e7dfed78234, 0xb4cdee964459b8a5,
+    0xa2e151a5ff0123b6, 0x1eb13a4243168162, 0x9c5eb7cace34e20c, 0x06ecee3d67f0acbe,
+    0xcba43cb8180eb6b9, 0x8beb3c85810255e7, 0xabadea1e8a13a31d, 0x81e274203763cccd,
+    0xe6f7f0cccc819827, 0x8aeec7ff17f23a01, 0xab8558ee034c4316, 0xe4daa788584eebb6,
+    0x123967f2dc701810, 0xedd97d910f120779, 0x7e2661309c5a5529, 0x08fea634ba765d4d,
+    0x16ab5a1d7f8daaa2, 0x74f02297cbbaf3e1, 0x90d2bbc0f38fcb7a, 0x94d66a91ece1272c,
+    0x428042b7b7127677, 0x641a6ee6982b4abb, 0x5db29efaaace3377, 0x5d51112a3abcca74,
+    0xf6b8cd08e7a8ada1, 0x1d3131df22e86235, 0xe4b535f5e61b277d, 0x8b71c708a997d2f7,
+    0xdc1b9de3196990d1, 0x58e1bf51fd7df890, 0x084854de7a308180, 0xda245285e2c2f038,
+    0x8e218e4beecd3607, 0xa7bd975c72b2a904, 0xc61a9c75a065535b, 0x60e208434a64724a,
+    0x13d4f8f6c3cf2a87, 0x0e5a15bfdaf81be3, 0xfcdda355699c103f, 0xb3e7d0db52a2d96e,
+    0xe46b709783c3d274, 0x54235f4bce527640, 0x81d04fbe064f936d, 0x53519dab67a17b6d,
+]
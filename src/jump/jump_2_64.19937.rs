@@ -0,0 +1,80 @@
+[
+    0xa443aabe18861db9, 0x0c23c9e53fd60eab, 0x7a51e15741a2e842, 0x7be8541fe456a99a,
+    0xac46c97ef26c06cc, 0xe3b6c1a35b8b2020, 0xd6a26ff04ff6a471, 0x818314f3049b8a34,
+    0x91b5ab194cb1e17a, 0x2a3a3ad440684c01, 0x854cfa16f58e775c, 0x36d2aee3552ac324,
+    0xac3305698b82fcc7, 0x06536113827e91fc, 0xa32103ea534a94f6, 0x079fc45b39618c69,
+    0x6dbf73daed3e0c88, 0x80e4abbf3d49008c, 0x5563fe6f6e42b145, 0x2d5d12bba2ac8465,
+    0xcfd27d4c1c03b5cd, 0x530d69eb5deaeb8f, 0x4d0739fbef388305, 0xe540cd5acc103071,
+    0xfad643a3dcf2faa4, 0x3af0687a6e1c73ee, 0x3834b07561429d1f, 0x8f2403614c11ed1c,
+    0x269c68787c87fff0, 0xfc31b8226decedbb, 0x12ef306faa1a386c, 0xb545e45d830a1dfc,
+    0x2b80fe9168a6e6fc, 0xf7b13c9a4011dfc7, 0x3d03561066cb19cf, 0xaf80b524b12cd6b8,
+    0x03e5ee8b680a35a4, 0xbe74abdd160a8cac, 0x756cd0cf015678e7, 0x4c45fc5ecbe9bba0,
+    0xdcf9c38c28045a55, 0xb682b8bdb9f68687, 0xbdc8f9ef9d217314, 0x74d1b7dfb6535c3c,
+    0x1a74dfe037d2816b, 0xb22dbeddf8fee23e, 0x3729199924ada84f, 0xdc218dc8ee6f4fe1,
+    0x56e995d27bcac836, 0x22f768c7998b1c8d, 0x316e437adb5f9960, 0x212e59ba2f633c4e,
+    0x6e7a460c322c70af, 0xf77eca19e71510f3, 0x89e28d882d591951, 0x9eac2feb682a45c6,
+    0x3e09175d3b004f32, 0xd652717e69236d30, 0x39a756b033aba3f8, 0xd5416856b70ceb0c,
+    0x47eebec888a37248, 0x755891e524e3283d, 0xd17e884b92f8d9a5, 0xca86130728d78fde,
+    0xedfe27201b11918d, 0x0fe235c59e7c930b, 0x1fc76d3cc75f426c, 0x7916bbe34075cca3,
+    0x3990bc26f6587f36, 0x930dadd653d34e11, 0x4d5e23fbb1bff585, 0xf9376f89bf1cdb7b,
+    0xd34a0a47c23b9930, 0x769fe4d33a398de7, 0x888803dbca362587, 0x544bb815b01afedd,
+    0xc686f115528828ed, 0xb2531fc6e490ed69, 0x6c2a5b2a917a8366, 0xe222edca3d9d5631,
+    0xebc20afaa145918c, 0x3d04701cadef699f, 0x94cacfe2bfac8ad8, 0x24870e63faab5991,
+    0x244621ea1554bf41, 0x5cdc05db574090c7, 0x25473a09483cf481, 0x2f0a158546d09b99,
+    0x68fbc9ece42d06d2, 0x304d93cbc81ec40d, 0x97e0885fed113d59, 0x1a397b445eecc154,
+    0xf4cd8bf991350bcc, 0x4b7852eb6526eb63, 0x5a1927f288b35c35, 0xa72231629a99bcda,
+    0xef6fd4d94735650a, 0x3e7a5736557bea5e, 0xa500694862bfcc57, 0x38a18fc7ee325e5a,
+    0x6ad29ab6f037978f, 0xdfe9d47a7edad5a5, 0x7ac7ec7ca758f1cb, 0x37d0faa9d038fb8a,
+    0x39c7a59c5a8a0891, 0xa930e48bfca2b797, 0x86f5af3b05f73266, 0x3f4b15c12050c3fb,
+    0xe241d92c2403bc86, 0x348e54a16de11985, 0x70c42ea6ad683269, 0xec018d832ace82d6,
+    0x6b81f6da66e66351, 0xf06be58b693b6cf8, 0x7e6f912b625fc764, 0xcd784c11327de49c,
+    0x243a35ba84fc5951, 0x6dc48e3bf882cc46, 0x79de31e75c8df0f1, 0x1cc3a8a388c0ceef,
+    0x1b0140f0ef6ce36f, 0x33d0768a39873210, 0x6851e6b59d73de3c, 0x2d2e1746af1c6adf,
+    0xcfd0f9bdada7dfef, 0x090734cf9f594626, 0x4798076936a698f5, 0x63b0c509173de9ed,
+    0x2219782b3904f2b3, 0x06ce260670af87c5, 0xef76f95f7d044c95, 0xce9fa2f46c17d0dc,
+    0xcda618840a45d02e, 0x1d6cd28daaf9579d, 0xbf16de08998b3504, 0xca6a95c3198bc580,
+    0xf48bc245a7f23181, 0x22972f00747d52a6, 0xe77c42847094c198, 0x4d0270104d8e997a,
+    0x555a37d70e380d59, 0x1b2595f2f97afe05, 0xf0b0e70c0f922b51, 0x2d5613a3589ff9b3,
+    0xb9ba1a0401f2871c, 0xdb7a3366d34bbbff, 0x8bea5d9d021fdb72, 0x1d3df5a7b5fddd99,
+    0xe07343a5677172b5, 0x3be89ebd5baa9d2f, 0x320316662148b2e9, 0xb8b792ddc120eb7c,
+    0xf9e360165a2eaf44, 0xb53eb0993847a465, 0xe32a0a9bc8655f0b, 0x7a3eb1ba27117246,
+    0x61c07352f1816b83, 0xc19a8d8a25ee2c5a, 0x6aa73d1b6545ef4b, 0x83abe464c3ce7076,
+    0x4e78769d34b25e22, 0x724b5b73a1ec4cc8, 0x75b72079f0793746, 0x6ca93505586d5401,
+    0xca6815f710879d39, 0xe5587c4ba37b8702, 0xcdfe924ad070fc01, 0xcf79ed3818683e69,
+    0xac428ac9aedaf41c, 0x982673ca64a31e0a, 0xbf8613385549d8e5, 0xf38bea747ba9aed9,
+    0x2b39f635ba6126db, 0xf5903a2e819a5903, 0x3c878340e1b3ce84, 0xdde4978315697203,
+    0xc16a16c41deba893, 0xac1075fcc01aa832, 0xc2d263b620b138ed, 0xc4b4a6eb5957bd3c,
+    0x8508f6b8e78e8c81, 0xb041d8835c4212ce, 0x283b46bc012b3b34, 0xfad246213438b20c,
+    0xeaecbc944cf2880d, 0x27ceb57b7d839f02, 0xa8a9166cf45901e2, 0x6a1853262ce56309,
+    0x6a3caf10550462d4, 0x903a6a98df6b557a, 0xbaf5bb9355d0e411, 0xc1786f007532454a,
+    0x581a2308b9ee9784, 0x5986f5917c3a364b, 0x9e1666f5eb61be70, 0x1b9452aa614128e8,
+    0x61f8e08b73d6f26a, 0x66dfd44a481add6e, 0x348a3caf765b1ef7, 0xeefabc066b910ab5,
+    0xfbedbcd27474b698, 0x2be199c23d3e3fb6, 0xff011d5bcfa1609d, 0x94ab8dde5b087b01,
+    0xd3a279b4db5afae1, 0xf86654eecf04d46f, 0xbabe5796d5837c17, 0xe7ea9dd2ee57ec05,
+    0x24eb6e1aa2827ac2, 0x938e4889d89c291e, 0x9613b911d96d1859, 0xf4221964890c79c7,
+    0x070ef1dfe4cd2c19, 0xf36023d184161b3c, 0x58c570add62b0757, 0xedf29b3fcf1fef98,
+    0x271e8ccbe2456814, 0x7facab8ab22e5deb, 0xb4c89142864c7d7f, 0x84eee38a620df872,
+    0x0d201b721897b3b3, 0x89a8c4437555c4ab, 0x4721ee31bdf32360, 0xf8239174f1f88fcd,
+    0x2608f4f958e02fa9, 0x36e0f5055ba96c4a, 0x07d0d6a997526101, 0xfd58ea1b9c2c9d2a,
+    0xabe4d7b5611c0c32, 0x4498268610aba7df, 0x99c92d211e01c521, 0x0171f40148f0bdf1,
+    0x57084e8e0c11bfb4, 0x4a80da765b2c30c7, 0x815d15f92e1d21fb, 0xd575e4c9ad5390e2,
+    0x11982e1e254d7811, 0x050641b1f7da8813, 0xd513999f9adb3833, 0x2c6225fe3074753c,
+    0xf57bd28931c3385c, 0x9e0a05fce1e1cf02, 0xcc538c6ce28e04db, 0x45b530bcec8fdd92,
+    0x7f3d33116d096214, 0x3d4340abdf7acc20, 0x284d49a6975e9e2c, 0x9bd689daa4b9007f,
+    0xa030e1ff03796938, 0x7649d05f1eba3062, 0xff011a18b3dc5551, 0x9d8bdf7c7374a69c,
+    0x894f8acd84c5211d, 0x5d597d518eb583bf, 0x21cc3303e161bed4, 0x1e6d39e594cac752,
+    0xbdea0a1e096209f5, 0xc418972036aaf516, 0x12216cb6dd5e4be7, 0x8a8baf921ed1e4c1,
+    0xefde731086834c1d, 0x7428e064a5126789, 0xe0bb7eb1e404ad3f, 0x132c4b94ccc74e1a,
+    0x32bf1f2251b13bde, 0x8f0e7b694e76248f, 0xae2be99f36c74c7c, 0x4e73cb3c6904b548,
+    0x1aa2afa65783e683, 0x37ad2cccf04d6cf7, 0xab065becf5e70e74, 0xb4f8202f104d9aaa,
+    0x3e395f14f8ae458c, 0x902b34669ee11d64, 0x2563b73c5979bee3, 0x9dc4c73288e745d0,
+    0xbdabe93d0ed305ec, 0x69b8eb72a098b63a, 0x8c73acac486129e2, 0x9cb48f458b503cf3,
+    0x50ec21b4fc87ba96, 0xc9a65714ff4a8f44, 0xb0fe0b8514a710ff, 0xd299e61dcd6d3d6b,
+    0x17af33209cef9c4a, 0xb6c7fe89cf50afba, 0x334f937277d8a993, 0x6b3c46b1d0a69cde,
+    0x8acccbe46f522d2f, 0x29681589f17d09b4, 0xfed40e65d31470cf, 0xc506fad451680374,
+    0xa76ea1060fd1d8b1, 0x8d18e0108c0dfb5f, 0x34df15b78e35d127, 0x4fe4d835121a6e8b,
+    0x8794cf7c450d4198, 0x4cacc0c0675e51ce, 0x215087362231c79d, 0x1ef1573e45beafe8,
+    0x0b40eaef5426e445, 0x7d6791d6cebb8557, 0x084cfdfcdc415aa3, 0xe35fa7e76dded62a,
+    0x18c8b0114702fff8, 0x4f5e1b67e5d00f69, 0x5b8cc7bbb6beebb3, 0xe9824483ef93a332,
+    0x58ba330a981d5ff7, 0xf0be5429a7045172, 0xf06a44e850917a44, 0x96c26bfa8d517e18,
+]
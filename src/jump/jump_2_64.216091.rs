@@ -0,0 +1,847 @@
+[
+    0x6464b791cfdca5e8, 0xf0ff32cb6ba1b708, 0x5f774d0fa569b840, 0xfc657e75bed5c010,
+    0xb280325a00779a19, 0x06ee326869ce965a, 0xa09e05a9afb9e929, 0x7c6940ad0a6b0c64,
+    0xc55b3e5e2de912a7, 0x8649394d6664a6a0, 0xe0fa5bd7942144bc, 0x65f8065f5e020981,
+    0x55e9242a9b508b33, 0xddeaa68652579798, 0x88f0acc1c047df1d, 0xa835e3fa51f77b4b,
+    0x5e0380ec19ace7b4, 0x42453f6646682d8d, 0x7f034f57014fd84e, 0x8eaa04ee09c96af9,
+    0xb0ebf95a5c9fb661, 0x3ed4c975f1fbae0f, 0xbfdb39c5b17e8205, 0xe0f14f89b2082950,
+    0xcf615b171f3f01c3, 0xb5db7f3ced5ff660, 0x2ee79767c4c32f35, 0x06fc0f136c6d5297,
+    0x96735fc66a5367f1, 0x8f174373951ad7a0, 0xa41db2fb728a07e3, 0x1a415ce7da1ef059,
+    0x3a076865d11566df, 0x43f1ad1bc27a64f7, 0xeb4ceab8bc45c2ad, 0x15e7c21c7075851d,
+    0x83baa9b351bc6532, 0x9d4777738963e067, 0x6adb61170687511a, 0x7936d53143f1f286,
+    0x252b00c7ba0abcc0, 0xf18470bdbb1c91d0, 0x28118b56da3ec31b, 0x5b6d5f7d8e3e7f1c,
+    0xb5f559bf253b2ec2, 0x1e36d66fe36e5d82, 0xa981f34e85621746, 0xe2e1fe3ad8e57108,
+    0x6d7001412b6ebc7d, 0x4fad7f01e7f283df, 0xcd65e45e9857525c, 0x73d9c2b163dee932,
+    0x12c40137315ed8ea, 0xd10582280e7803e2, 0x590d2a1cbbcd4573, 0xa28cdc6def3d039a,
+    0x26b6e9b54e67abfe, 0xa81c09d1b585f129, 0x883e41349999820e, 0x862a23441cf5cd6b,
+    0x04ecb673fe7bdbc9, 0x57d4dc867fc95481, 0x563d100fd7fe2e77, 0x0653c88dc93fc851,
+    0xbb8a9ef12f5ffb14, 0x523796a11719afc5, 0xae9824cd7e44156a, 0x100a3a14672408b0,
+    0x4c0e73ebc75faa3c, 0x9cf51e9047604c5c, 0x3b0dbaa55b51766f, 0xe989bdbc2530d9f5,
+    0x71ae2711be1b3f2a, 0xdd455db206a8903b, 0x2e5d464d5c831119, 0x56c27343308343c6,
+    0x6bdb014dead4822b, 0xb015a5fe83a9af99, 0x04ec5a932c72eed4, 0x5aa4ff6ef819c6a9,
+    0x59028be66876a669, 0x6edc946cd398c0d9, 0x872e06c7c91a80c5, 0xca324cb3ec246a36,
+    0x8a6c73038c81fdfd, 0xd4e2a20063894ecc, 0xf3110cd44f626aa6, 0xc759d61e84244ca4,
+    0x870d509064548d0d, 0x7c0d9d7efd824e33, 0x6573892b4d4d326d, 0xbdb41d7f2ba6507d,
+    0xeae25750d9410df9, 0x04c8107fd4d59c0c, 0x52ff924d891dcd2a, 0x0c8a3c7790a69e98,
+    0xc476af42060273c8, 0x5e1da30636e37484, 0x2fc78841570c5015, 0x86d96333d3c9e555,
+    0x9629cc5f192b3d6e, 0x3c97824c3324117a, 0xa74e21c41b90fdab, 0x9512784a034cd88d,
+    0x810301d360922749, 0x55d254ae512c3fac, 0x8672b4556e64fa55, 0x95a701b1e13ee1a6,
+    0x7a813b5008bccee6, 0x9506974c90d04630, 0x592d63a0cf1ce27f, 0x6b72e46dccdb45b8,
+    0x9e0452909999e7f4, 0x7525f74da1323be7, 0x60ede5d488017bf3, 0x803ef18160a61868,
+    0x3683c65db78bf40c, 0x3eb4b1f3ea8b034f, 0x3992ee507f88ed32, 0x89cd93207dee1bb2,
+    0x21a77664a5620dee, 0x9ab8d498c9974edd, 0x31b3525ef6ae90ec, 0x5cb40b94db364493,
+    0x254f3acdfceaa84c, 0x86e10cd2b94ee134, 0x748517722d89d24d, 0xeab6b893201acf94,
+    0x30b80fe74cd3361c, 0xc2527944cbff9169, 0xd8a884b90cc7d18e, 0x80a622ab5f90f698,
+    0x230ccad701d7ddc2, 0xea167a82cdf373a4, 0xb310f59debfa5860, 0x2e5b1ecd49c1c892,
+    0x3d6d894f320a84bd, 0x5e92cf5e567396b1, 0xb017aa5cea131eae, 0xef8f1378df53a3f5,
+    0xb410e9860812526b, 0x0b394d0746c5b841, 0x5e2c1ab3b20d1377, 0xae416635328bf0a6,
+    0xe66ff23ab08a2201, 0x78b0cf2976f26827, 0xf2d5079faa69e267, 0x9cff0df2d180cd03,
+    0xd9730307b9e118b9, 0x1328773744461abb, 0xc3f01d94bf85a7ad, 0x3ca15fa1a219f710,
+    0xf9ebc1ee045c02ad, 0x513f2a2e4b673860, 0xf46b86a2645b3ad8, 0x3d0044b738cb61a8,
+    0xddc1663d0451ccd2, 0xcaa03b7b9fdad0d6, 0x970f17b7087e048b, 0x5e57e7ed17b6759e,
+    0x5608e6dcd0a078f6, 0x84145a1ca908a71c, 0x9304dc2880c3fea2, 0x128a349a415c1ac7,
+    0xc7fa9cdfe5a97594, 0x8a4b53a386cb6f4d, 0x212988e5eef928c3, 0x9e7b39cb3634c0cc,
+    0x4d9c5bfd3d91d118, 0x8be330a05db9931a, 0x4bb816fbb6c01659, 0x455b3e747f94440b,
+    0xc3f51185ea448892, 0x389c3e92b2d7920c, 0xbf0567fb824bd462, 0x4c9e370449a94055,
+    0x90bc86d4da4748a2, 0x22b3a33131a54d2a, 0xbc13f370d774b0d2, 0xb77bafec92fad1f1,
+    0xecd0f888e701f8df, 0x7aab2981b8314bf2, 0x624528451e25d044, 0xcf5d6630af1f52ab,
+    0x4fa619c59a312a24, 0x8227d26694294fa0, 0x92938be87d56711e, 0xf37fbe102d23ab5d,
+    0x97f8545c5c4f34b8, 0x05abba2f970a80b7, 0xf878d3be10b04183, 0x35480d3c13991528,
+    0x48d9f3be5a3c7ea1, 0x7e3addd19e083d69, 0xc117e035a9b1ede9, 0x767c8e13f5732f05,
+    0xbc207c309ac3f0d9, 0xf9590cbcf28362ee, 0xa506f0e35c6301e1, 0xa718e00eb8e0e652,
+    0xa6ca5d32f8139f76, 0xcbe965cd9bda3a3a, 0x2cd61e92273266c1, 0xc5ba592d6173c12e,
+    0xc038657864867d4b, 0xaefc1b5496a53a26, 0x112a1ce8cf26673e, 0xd8aaed35de5f7430,
+    0x0d9e24806d11edbc, 0xdaf6dcb9e62d30c8, 0x46fdea2fa90ed33d, 0xfb0b9aa63471ec46,
+    0x7af82fe1bdf60b55, 0xca776c415ed56bd3, 0x5a37137f6f00b0de, 0x205e147cc0b6b265,
+    0x1d54d0f65d4c9608, 0x44896b5e10b88b23, 0xb89dce96856e6512, 0x9bf3319687a76e71,
+    0x17df4462d4f0e051, 0xe7d8fd25016cb5f1, 0xf5b55c40cf63f707, 0xc7330b723fbf6e13,
+    0x98f5ec29060c2617, 0xcfa609fba7d0496d, 0x66e8e6f2a8c0fb4a, 0x6985dfe9c3364917,
+    0x5a97ed6cbafdaf3a, 0x486a4683b2f3e3b4, 0x26c8f0b65b5c65bd, 0x1c76a2b04733b906,
+    0x12de60ffb5b318ed, 0x84e8ca9565fe66e2, 0x585d5ff519f21498, 0x35e318517565a9f4,
+    0x3a0c8484fed3941a, 0x502a5620183c0cca, 0x26bb0e79e4ad34e5, 0x688f5d1eb415c35c,
+    0xc51a088978a11e66, 0xae6cba51d468cc77, 0x5753af2f18558d8b, 0x0d7f106e4fc48316,
+    0xfa3742336e2f92e0, 0x08fda17fe96af13d, 0xb0c76debe49549c1, 0xc073efc88749223b,
+    0x782125c56fc177ee, 0x6527a790240a2945, 0x7b67544fe09eb56a, 0xa8970c6f8a7e6b3b,
+    0x17a7c866f9631794, 0x15d13f934e8c1e8d, 0xba60cf36527e9255, 0x8ae4ad207b747a0a,
+    0x0d1317cda91961ee, 0x8bbbeb4b1fd1746c, 0x98efbe8d3b179ef3, 0x2b14de1568483d74,
+    0x6f45700262e033a6, 0x8d31ae6855184bce, 0xf4d298da76c7b44b, 0x8c167f8b02f153ce,
+    0x46cc6b30aca77b80, 0xe8369b6786aa9770, 0xa7f99b80c76445c5, 0x006bbad19f53204c,
+    0x750f63fa9ba6d013, 0xd459231ea102cfbe, 0xfaedeff2258ede83, 0x1506bdc35912b59c,
+    0xd7bd865e4bc9d1ae, 0x59fd2fe510e21c1f, 0xe66dc2731d67ad9c, 0xa6d0cb2245250aab,
+    0x0680d6c2a910e483, 0x90dcc426dcf6994a, 0xf96ea7c614f042d8, 0xf4970073c15b92e3,
+    0x4d0ae37b8e519bac, 0x1f24942b1f79738e, 0xc7f452d5c49ec8ea, 0xcd17e89d485f67a1,
+    0x399969b7b2f75b49, 0x46665859a7bbae4b, 0x28e9636329e7389e, 0xf45544d6db26e952,
+    0x248b2d38d4994bb6, 0x750abde7731711dc, 0x02a7ba5b222124ef, 0xf526a82d11e2f57a,
+    0x56f9593d77a24a95, 0x465f4794741ece23, 0x2ac6b41a1f9130b9, 0xacd35eb8196d808a,
+    0x499dc7b800942bb8, 0x5e726c28276e77d9, 0xa2ab55b35a419f16, 0xdeca07b55b114552,
+    0x9db93d5441a2da19, 0xb6528a6f22af575e, 0x6a6b42af0039f5cf, 0xa59625aac6e458b4,
+    0xdda9c02b3de73227, 0xcd6c3de9790beba3, 0x73cbbe3af01e4a7d, 0x2d85a9abdff112e7,
+    0x0833563c11ecfe8c, 0x96fe9a1a69235e2f, 0xe8c36d0703cebee6, 0x0352d61c9aba2a1f,
+    0xff4df4a956bbaf14, 0xfb3f9bd1c910e9f6, 0xf1b1b01dd186db4f, 0x040e66e9a3bb0121,
+    0x16fe431e8a550d2b, 0xd1a076edf564b5ff, 0x4a9d8fa8bca41831, 0x2e44f467d5d6d87c,
+    0xe013e4d6575be9cf, 0x4b2e216b8417f2d8, 0x3b51db6fdc263632, 0xa5ab05aed42d0ad6,
+    0xe0d6c5bf8e6ea921, 0x70ce4232bdb89027, 0x714027e314431446, 0xb2740e006379bc51,
+    0x5ab9c8b4d5e8dd8a, 0x20abd63b44f3f9ae, 0x415bd62af6300d21, 0x631a9dc911d32537,
+    0xdfd5a4d8dd8b26e9, 0xa5c691d57a0cce5b, 0xbfd215bd74ea0e5a, 0x838da1add04a8ca1,
+    0x9e50d33ee811281f, 0x3a3336c7ad166ec9, 0xdd051eb00510f69b, 0x2a02196d503dadd0,
+    0x94e83ff4492690a1, 0xb65811a59c97cdaf, 0xb070c0fbbc30872b, 0x892aeb08360cefc0,
+    0xc2da3982fbd54aa3, 0x20e7d978d57a68f8, 0x13aae7673e7def73, 0x976570f84e164e31,
+    0x9bc25d536c8f0aeb, 0xcc1242cfbea1c736, 0xc31e77c07aa516af, 0x452e71040df17e44,
+    0xf063b7106df6383b, 0x57818e3532c93c43, 0xa3cd28d8d57edd97, 0xb5f9e3fd38e59661,
+    0xec67a20480c615dd, 0x7e7aef704aaaaa3d, 0xa5800b2da1a99526, 0x6ce4bc810a0e88e5,
+    0xd6f66fe1d3e77ee6, 0xcc825b8f9681dc6a, 0xe22c59a9aaadd20b, 0xa0c3bba70ba68055,
+    0xd3d55949ad204172, 0x38b01e22c0e57a00, 0x645ecc539250f080, 0x02297425097bd6aa,
+    0xb7e9144ec6633995, 0x36419bb36565deeb, 0xcffa59f9fb2ea612, 0x63092c441c41b0d9,
+    0x246b0362fecb8673, 0x27564c5ca3fc0181, 0xbe73299eb83f8926, 0x22fde475fde7fdd0,
+    0x00db97e92c4f6a29, 0x482e29139e898c20, 0x6470c425310b271e, 0xf3c1161ed7afb55e,
+    0x4d946c237827462c, 0x1516d257272a1251, 0xc9c15c0b18cbe193, 0xd6a32e851b9335d4,
+    0xc2395e242bdcdc15, 0xe16006372d39b316, 0x9b2337e5e8997503, 0x338b6361b476a7f2,
+    0xf8d1e9cb2df6746c, 0x42932c0177322753, 0x9753b00574ba9366, 0xfab1e4bf8b117845,
+    0x7715ca8a64614f98, 0xb7cb8e33b47fc9c9, 0x7332b119859b52a5, 0x43572b86ff5d0c16,
+    0x4b38e5ae1680ec4b, 0x501cf1c2a1f9e2cb, 0xc4d353b6a374cfb2, 0x9201130fd7783f14,
+    0xc7b5c71d22fc8548, 0xdb82d0b70a2a497b, 0xf60d0b1a84539d39, 0xe7aa76625c3d0aa1,
+    0x39c5be5fc76621a9, 0x7e2d63b65cca9847, 0x147a834e0fac114c, 0xf72961ed72d64103,
+    0xd7df7dfe34bbe1a0, 0xac1a8a00414c6050, 0xf44ec7e271a6ce39, 0xcf2b984ed863d3ec,
+    0xadd84b8098c4716f, 0xa49e1e9ad4013d73, 0xd4dc6759c4cb9327, 0x579ab7d40a05e1dc,
+    0xf32e0bb08690f37d, 0x5d7e6cabe7e97628, 0xf171d7a3eb242614, 0xc2fbc7f56ee8adfd,
+    0xc39bb4150b54ed51, 0xd944d744ef94b1a6, 0x3c23114a3c56a494, 0x8fd3a0da45dc12e2,
+    0x24aad95d38697d6f, 0x6bdb0bfe020805d5, 0x562f11ef3274a07b, 0x008bb37b7b95ae91,
+    0x5dcaf6a2e0d58e37, 0x6462fb05f072f8cc, 0x726b558a51276655, 0xee2942fb61fb0de9,
+    0x59e446683cc510d1, 0x325095637fc7ce39, 0x9a36a820fff86c17, 0x947ba434e60af256,
+    0x2c091425e3f3d16b, 0x3cdbef31324cce8d, 0x49267e22a8493257, 0x2eeef0ab7d2fa9a6,
+    0x77c730443f55efbf, 0x71c6510da0daf3e0, 0x1c06a740ac680f3e, 0x72a13631c9e4a61e,
+    0x97cb73cf1424fda2, 0xbf492220868e19c1, 0xe4fe0c6534c26758, 0xefbe38a310e73034,
+    0x7c95cd826e52e131, 0xfe01c0456d8043af, 0x2fef98b1b99a5df1, 0x63c880492c1b92a3,
+    0x126d40bbca333666, 0x7da2537380cb9a0c, 0x186a2bce2e662334, 0xb883b44a4f652933,
+    0x827f40a72388fec3, 0x11c4441059dae497, 0xfe32a6ca3190f430, 0x03a332d1c5c734a9,
+    0xc20d5eef848ef5e6, 0xccb2352910ec386e, 0x19e82dac646d17ab, 0xe72f650069d3b4e5,
+    0x060614e601f39dc3, 0x7e5288147d6f7fed, 0xb0d825b84ebbaae9, 0x670ecf38dc63a935,
+    0x08a5f50901d1b14f, 0xe1664ef132e7c61e, 0xaa31556c64ed4728, 0xa805eacd4f708143,
+    0xd95e9dcbfd1e1ba8, 0xf6da29738ad179cd, 0xe338ddf0f5cd99da, 0x1a0094f92a7a4be7,
+    0x9ef3162818c59318, 0xf3b8d4b3c091b564, 0xd7a2d75304c507a1, 0xa8865f95683164bb,
+    0xd549db1a0d881e04, 0x645a91427b53e976, 0xc842f669c2824364, 0x9032c818601dce6b,
+    0xde556d1e2aafb986, 0x86c2de3afaa1516c, 0xf2583deba5fabba5, 0x7316c905ff7eb6c1,
+    0xf3ce2f6eaff12189, 0x1facc1615de17329, 0x87f06b3aaeeb3d69, 0x065fa2f3bb38ca44,
+    0x9db6376fba6ca77e, 0x189caca94b739419, 0x3101fd300a7d6ae3, 0x196cfa4708122f19,
+    0x539fc8e1213ea4cd, 0x961b775bc6ece44a, 0x2b1ec241077f0a42, 0xd0c18d1129711b78,
+    0x097243baa62f8272, 0xcd49f17fef7f0f78, 0xe0aca6999c86f22d, 0xa7907ce436b1a07d,
+    0x3bda78ffea9b5967, 0x003f5c75b4878b29, 0x2306289bd987beb1, 0x74c8bb9dae9e3f71,
+    0x0bfca27ad8d9f337, 0xa3641344287e62fd, 0x4c05eb9947624d56, 0x71ba6aaa664db681,
+    0x1097fbd0f0eddb9b, 0x11846e7ce5cd97eb, 0xd0cd08488d764c04, 0x1b1efdc1d11c7032,
+    0xabed68f355795f04, 0x7447dec4196b8bab, 0xb46355e50873e1de, 0x3c69e47fe4b0289d,
+    0xd0d35c6ef3a9c017, 0x764867c6fd1ed7bc, 0xbbae39dbeddf873e, 0x3e8a8adac232d712,
+    0x9d5f4a3329c97117, 0x03b5c8e10f37c917, 0x753c3d29ed22d59d, 0xbc78e582da285edf,
+    0x0afac07002e5e9ec, 0x97e73286403592a8, 0x82bb257cad6825ec, 0x0a76f89aa565d28c,
+    0xd68c831258e83eba, 0xb58e3f35c912a4e0, 0xe45c3209a88b09ea, 0x3b5f301be0c3a861,
+    0xb245507c5ffa9ae1, 0x7fa51dd46da4e63f, 0x9e0aeca7d9286d63, 0x5ad77e1f979cf29a,
+    0x4e9fc25801e36449, 0x5f88fac6703fab01, 0x648b461d5d567743, 0xd8d302d8685e0cc3,
+    0xb37c920e7ffcf471, 0x2d561c6ddd501fb5, 0x01c64c6124aa85b8, 0x563c4f7065132298,
+    0xac4efde14d4865bc, 0x277026ad4f06e48f, 0xfe333f278c7d21ee, 0x1ec12fc118711b11,
+    0x153f4fba944e5a07, 0x7d0539759f2c7539, 0xf7775f3d76edb921, 0xd03d6da855c3db5e,
+    0x66077d42964fa9cd, 0x500e4f92c98f3c74, 0x9779ee05b285d639, 0x99d5667d06aa65e5,
+    0xf14ef60d0a90151b, 0xb8cc4ce338000889, 0x1adba3e4ce558972, 0xe3e71ba10b570c6a,
+    0xdfb0e8555b5d8e44, 0xe58fdecd939535e0, 0xebf1ee5023248be0, 0x832d9f9e104f5d37,
+    0xdaa7023a8e1eafc5, 0x3ced268e46e8fe40, 0xec539f7a193e141e, 0xafcf9dc245799186,
+    0x95d9a6c91fc016d0, 0x88ef20dd38743691, 0x22c26d0238d8a2bf, 0xc020172f006316c2,
+    0xd13c16e759ed58c7, 0xe4aa3cfcf5dbecd4, 0x6245a28dd923a56d, 0x29a85cf0341cb485,
+    0xe8d40e6b5392c09c, 0x6d395f9f508ba85d, 0x6aadc1a6d5e419c1, 0xee05afc041b3cb83,
+    0xe3194807f25bcfbd, 0xa3e14c49e1e0b7b8, 0xe5f520b3080e7847, 0xfede5e87b3d6b66a,
+    0x65012dee4503bbc2, 0xbd14456239c712c7, 0xcad27b9159b1db65, 0x8fa48eed25e736c2,
+    0x54645373e9646ec9, 0x31d423ac172ff91e, 0xdb36d5b0ad560f64, 0x970a601574abe6b4,
+    0x91f6ed6b5dd670ea, 0x18e0239157ed81bc, 0x7afc65a446b6cc1f, 0x509effd0166cb190,
+    0x1f1c90339c170101, 0x3c4929668bb1efd2, 0xb5e970486bcfffdc, 0x54a4c6d2887c868d,
+    0xcbb0772a0bc805b6, 0x9f53ee9ea192d5d0, 0xfef6c5b9c89aac39, 0x34822890e2a3b92e,
+    0x6c61d6899312c31c, 0x11f7305d88f6c268, 0x06f123866e1856d5, 0xff61053deacdc764,
+    0x36d06a1781c904d6, 0xd96487c050080296, 0xa14e246035c5fbec, 0xbdc6eccc82a05ae4,
+    0x6e80c83e7dfde7bc, 0xfcb21943c2d82c6a, 0xd473ff2c11c08c9b, 0xb7ab044d1c979375,
+    0x81887943e7f7f5ff, 0x19ba8c48ae459cb4, 0x8ce7debfcf674e99, 0xced2ebe09d746bfb,
+    0x07523fa31c245b21, 0x80b83e6337272da0, 0xfd0828369c2da0a9, 0x15f1c515628639d5,
+    0x6e8c14a9b9bf5bfc, 0x134d32e68018a186, 0x10dd59446999f10e, 0xd12187b6a6ca7de8,
+    0x9a8af7ddb4a32d63, 0xd0c851076576edd7, 0x092b46edb2d77361, 0x1e444794326ad223,
+    0xf2440fa17efa137e, 0xf4f0d0202ddc57d9, 0x460884c917005911, 0x0506b5e8127ce83a,
+    0x8d9877dbbb7001e0, 0x465ad1eb67d32637, 0xdce2f2ae43fbff65, 0xfe3390b73ed963e3,
+    0x7aaab42aa8ee94f5, 0xfcb0145b2e61fd15, 0x075cad3407660381, 0x1b62ce4235c1a501,
+    0xc259ced73586f45b, 0x043d81302ca60119, 0x03e2de3711de0ae5, 0x9a283634c18561cb,
+    0xb74690d47b7b5261, 0x712026e361909aac, 0xc505e6bceec6ae95, 0x3dca71219182b1d6,
+    0x2d3d80507ae5274c, 0x866b7ca9938d9473, 0x1a190720ec2ad993, 0xc349303ff5ccf671,
+    0x4f4ffec93bb70900, 0x9e03846e6245eb5e, 0x56e35019732b4c3c, 0x2fec8eb2da00415a,
+    0xa8282227a07d611b, 0xd2d677132516cd5f, 0xddf49a34a9195c7b, 0xe33beeca2e31ce8d,
+    0x55143e150e1d25cb, 0xe06be58a8758c80a, 0x1bbbc9a60b864fd7, 0x24f7592da05d7b51,
+    0x2eb85eb8b19d1034, 0xd21a2b4d1e418b0a, 0xd8aa61f5d4c14b7c, 0xd4ace261d8142a52,
+    0x3304ed0b838e8340, 0xd3fcddf8ca99b731, 0x8b84a155da84bc30, 0x4700872aa606fd98,
+    0x00ccc4945c0a57d6, 0xe4972aef6fda16bb, 0x242bb457066020ad, 0x8a0ae3c9e44a000c,
+    0x7299802c21ae5d79, 0x0d6614de884ed674, 0x9292e34b14e3985b, 0x915351b375517246,
+    0x829cea3b4e18da25, 0x488759ca12f554da, 0x2ca72fb9c98344c3, 0xf613508828c0c787,
+    0xec11ab13fb5b7fe5, 0xbc7a370912d272ed, 0xf738a3b42324e6f7, 0x3f93b90d8bc6a756,
+    0x5ece228019a821f5, 0xc7b272c437f985ae, 0x6cd3e62ea12dc345, 0xde16c98ec7fd7390,
+    0xa60d0afdb0b09514, 0xf6df0862d25d037d, 0xaab6cf6f15b64744, 0xbbeed3f83207ef49,
+    0xa209c04a7cbefebb, 0x9feb0924d9d97a3f, 0xbe14e0cf2fdec22f, 0x7256ee1e8d6cf526,
+    0x202d24a400b17f7c, 0x2054f496494d448a, 0x440dd7f683e8057d, 0x04e1acd99178b8ed,
+    0x5aa4d9dd62669c75, 0xaaf37e278af6935c, 0xf1692221b9bea169, 0xf40a849bf7b775c7,
+    0x63eaceca47dc2d88, 0x63c576b6f2607cdc, 0xfece8800af750b87, 0x81de17e6c9a57999,
+    0xa59e227c2ce29084, 0xdcb5172661daef46, 0x031725a25eaff8f4, 0x3fc9c8a8995ec973,
+    0xef2d7adab0817db7, 0x8977779321b12035, 0x49e4f9d9e2a2cd63, 0x64acaeb319edbe74,
+    0x0b11b5b4a160eea1, 0x4047c73824671cd1, 0x47ad446cfc386e0d, 0x7dcbebc9b3882204,
+    0x11137a0bb8d74a1d, 0xda678ae91b6f56a3, 0x9f76c372be569d11, 0x5fa0b804a966e77b,
+    0x7976a6e667bcfd98, 0x98b9fe082424b987, 0x9510720d3953bae1, 0x9e3785d49eda18cb,
+    0x9a100fa166dfa23c, 0xa79a87780a6063e5, 0xc40c2fcdebf084e1, 0xa4b132ab3fd19c77,
+    0xc6557ce13c324503, 0x5f0a5dd28060d33e, 0x782fd4ac0f5ba2bf, 0x5d94e99321c27a8b,
+    0x78560645174c6cae, 0x9a3a47bd4ff21659, 0xb4ff8d87aaffe80d, 0xbad56243291fc471,
+    0x41c8f6cad3e054ca, 0x293f5904f402fe0a, 0x344b2df5b99adfa8, 0x724d90038e3d7722,
+    0x3720f54091e47735, 0x4a97374da2ad86d6, 0xc163ec52ba03601f, 0x1a73d84e6c7de806,
+    0xbe4e1d9fde2b1d4c, 0x47de358fcad8a385, 0x3b71021b25e2ab1e, 0x24766c3273b33cb9,
+    0x5ac862e07489844d, 0xe8e16227750b9ca8, 0xe30100719a324f0c, 0x23a3fe7460ff406c,
+    0xdd3e9b10f1a25819, 0x2a568938a2e56279, 0x237f960f46667c57, 0xfac645ccc48df1cf,
+    0xbeac48b9c3ca1649, 0x9a32e3ec770816f4, 0x4429ba638e7d9d10, 0x20270f39fc3d89cb,
+    0xf49ae7c3d2ed01ff, 0x98542d676e92b983, 0x26a404394a482a52, 0xdf710c2ce912f3ee,
+    0x57083f7cdec64bf0, 0x854a1e79278ee5ea, 0x57c4cfcab8ccdd39, 0x17fbd5e8e3c7e367,
+    0xa9335d002eada8cd, 0x56e0628cea61ca7a, 0x99f61ac2e630d94a, 0x5cc58377e7d57107,
+    0xee77a0ffa7a2724f, 0xbe6ced15b2fefc03, 0x1d7bb483db997b78, 0x0bd73f6a9db82c3b,
+    0xb145fe5760378ed5, 0x0cbad47297d6416f, 0xa5c10d5e896c8d2b, 0x6198fffac7aa1948,
+    0xc069dc8c3e45a00a, 0x8eab94d6607a6cf5, 0xd8094efe3af08ffd, 0x1e80d6bd6c600e12,
+    0xae2c3689e5450da9, 0xd2b9176a8058c457, 0x6c73a8d75bf7365b, 0x1651a9fac1edae3a,
+    0x1f8bfda01e7c1a2a, 0xe06fd4f3fe892afe, 0x8ab100648266e562, 0x06bc80bbfa1f6e80,
+    0x5e30cb67aa5f4a40, 0xc772c22a845bc550, 0x5ad468f3aa81b011, 0xab77520e9ef967b4,
+    0xc18942716ff2df88, 0x019e3e46d80aee92, 0xade873660a66ff37, 0xf35307037742a0ba,
+    0x726072c67cdaeabe, 0x4a1f0aa9e2f7a803, 0x98f4e90b49650a0e, 0x699383bb48ce3e2b,
+    0xde97ec1daeb8bb5f, 0x02fded2e31c533f1, 0xa0b4a5e262d99866, 0x3c62d27ddb063314,
+    0x76f12d7ed9a6e469, 0x5176ff51e6d693de, 0x3befcfab42d15959, 0xde6c06407b6b9276,
+    0xc324b74caf304ee2, 0xa0edbcc5e9477010, 0xadeac7aa9f6a8c42, 0x2c837d5b4a711727,
+    0x1b723873e22898c6, 0x10d2bef51d78b8f7, 0xf97d15653078aaa5, 0xfdf37669bf25e948,
+    0xb6c6fcc1b59bc88e, 0x77b22c7f81b4c1a6, 0xb25b339fe4e2eb19, 0xc3ea3cd8e82f9b4d,
+    0x19de12d7a42fcbd5, 0x28359f4478c6d27f, 0xa84d03b189809373, 0xfc1af63a5932e99b,
+    0x630f15199225451a, 0x650a371d0eed2d58, 0x056f08db0f0cdfc8, 0x7abb0048651c4834,
+    0x108b362ae38cf5a1, 0x03c5c84404b99192, 0x1dcc3c3b633fe88c, 0x5fb96f935a28def0,
+    0x62ba9b9e36c5b9cd, 0x6ed0fb2dde475f46, 0x2a4972646832c3f2, 0xf853d0a8b412039b,
+    0x6b5b338940a0daab, 0x03d6b42d2a85013f, 0x5224262720604e5b, 0xd9d1083553a64ade,
+    0x39104f8775a60117, 0xca86ea7009a579dc, 0x8b527bca4285d706, 0x4dccf8d989219272,
+    0xf428037d4d1b6a4e, 0xd24ab1800499e63b, 0xc5d7242606367e97, 0x6c0f32b02283213b,
+    0x51cd602db596cfb0, 0x36d595588e4098ff, 0xe2d4c3f2c3669906, 0x419a87f87f6a468c,
+    0x12b3c31948eac95f, 0xccb6b92635ad360f, 0xf32a7a69fea97742, 0xf2478eb0840f54cd,
+    0xbef697b9667a7b77, 0x18482f4529df618e, 0xf5bffbac8e9f6740, 0x11374c2eab66ed84,
+    0x854ead1515b70d9e, 0xa46a3a24756a1e2f, 0x51a4bef93471eb3a, 0x76064e4180bfe9e8,
+    0xa3cecded9bc6b1ab, 0xae3e72d07e52f998, 0x1587007fb9607a86, 0xf22af292ecdbf183,
+    0xa789445f94fb2a0b, 0x7dacf7486f742915, 0xa074bd7f3c28bb61, 0x746161adde1fa38e,
+    0xcf8209f72a4cea1a, 0x2313bf677b1c788d, 0x880ef25dafb4df2d, 0xed61ad761473a9bb,
+    0x63ad92c18d081312, 0x77d4e92a031ee5eb, 0x200cf9f6434e1ed4, 0xcd1845b778d36fd3,
+    0xc99842939f7a492a, 0x40b102bc573af805, 0x7978bd8cc5b57a34, 0x2c376bfaa58e0c7a,
+    0x44bd34bdf2c39786, 0x6045127ea66830b5, 0xd1c92136e321299e, 0x5e1ba91356f2142b,
+    0x3d492aa0bc10ca31, 0x8557ea0ddf685d49, 0xd0db8e9968e7d18e, 0x5be10e5d411ae5b6,
+    0x904b7231582b4a3d, 0x5ec38c8b421128a4, 0x3eeacd463ee04737, 0x8312c5e33e3d3ad5,
+    0x8fa551dd68802cef, 0x5df38c5ddc939f2c, 0xb0d5971fe697c621, 0x0638038a928748ef,
+    0x6a5fa207903b6159, 0xbe6725521296b18e, 0x10162d2ba285cbb5, 0x41edef02a7b4dcef,
+    0x5a0d5283367af3ef, 0xfe750da940db148e, 0xf7631d05c5c94bbd, 0x4f62847b93d0dbe7,
+    0x7bc014e36fffd1b3, 0x075bd478b6d16e04, 0x04bd6359c930c62c, 0x736c75f96a013596,
+    0x5f2be9539ff1271b, 0xcdeb9f3e184732b6, 0xbce0c77b52c0811c, 0x43dcd94c82acce22,
+    0x2d6ecaadb9957b91, 0x1ee2d54559287d63, 0xfb2e3edde9520115, 0xc01da6d5899bccdc,
+    0x9bec3b48e0a7e3bc, 0xb8eb600918459ab6, 0xf762cd9a426aef7d, 0x7846ba84da715514,
+    0x7ef8723a3fdfd1d4, 0x40f5625993a844c2, 0x4fca878835c95d96, 0x48e0faf426ee097c,
+    0xda7dc6f2878d60e2, 0x76e108f900076f10, 0xd841bde2ea071da2, 0x420c6ebbc02c31e0,
+    0xa7adb9a8b22f29a0, 0x220e55f7e9bb7c26, 0x35741af29555c105, 0xb381ec2fe4c4753d,
+    0x42fa592e1ed2431d, 0x5d1b2c3558d98517, 0x4be17bce264b6f59, 0x37ad421fe62364cd,
+    0x3be8529ad35ecfb7, 0x6ebc760f176cc38a, 0xc10ca6a122cff879, 0x33ff6181d61278b0,
+    0x9e01e543b614f0e8, 0x4dcd5eba73350baa, 0x3c2fa298cf9ca9f0, 0xce6a87dc341695a0,
+    0xac25b2bb100242d5, 0x3894703b566e6c5c, 0x1cc6f5d50b114b97, 0x991c77616801df53,
+    0xa8fd946b529e61b6, 0x11d0c060ccb41d91, 0x49bb26b450ae0bee, 0x33ff17fba7bb5a14,
+    0x822dc6871d62d2b5, 0x447973dd6aebe035, 0x3fbcb54123a56813, 0x93b3b05cdea302a6,
+    0x03a92ecb4b68237c, 0xb05b24a6edf8a4cc, 0xbc06630375e052ef, 0x78f926aaaa85e291,
+    0x1e2a28d94d0885bf, 0xcb8bf86d26395182, 0x1bd1ca16b28c19a9, 0xcaf410c8f6e48b64,
+    0x73ae09c44f52b4f9, 0x4c0cce48ff0be6a4, 0xa71eb1dba5b886b9, 0xebcfaf54249441b4,
+    0xb88db825100906e4, 0x3db79fb7c3b63e37, 0xf5fb83696fa1ff4e, 0x11628dc5e310e2fe,
+    0xa61ebdadf219fd1b, 0xa4fb38bd052fe5e3, 0xfd47d3b16d1c57e8, 0x29601c04fe758d3a,
+    0xa4ff528f49d24b3d, 0x8c4718a0fa492a3d, 0xfb79725706cec8cc, 0x7bb7cfc798b744c7,
+    0x8b5b3795daa9aeb5, 0x023923efaa2b0dd1, 0xa9dd025ceffc9599, 0xbac6981b655c3e9b,
+    0xbc83a59bc0f8bc69, 0x3e0914d41d6595b3, 0xc787221c825ed537, 0xfa58706ea24032fe,
+    0xbb5b241d9cb980c3, 0x79bb547961c9090a, 0x1d39e85063c2d5ea, 0x901f7b27a61282b7,
+    0x89052a230098e9bd, 0x1597c4c9c86653aa, 0x5a87cd4bdfeb3fb4, 0x2347cd84f161caf6,
+    0xfbdc8ac45354cdb0, 0x0a3196660566a531, 0x22b27a890c54f2aa, 0xd5fbecd7ee39073f,
+    0x9240cbd02edb938a, 0x8831e6cc1bebb8fe, 0x1370a8149c713457, 0x64303f7d9af3d975,
+    0x46e781fce43d545b, 0x1cc9360cd94cd30b, 0x90786206ef9be407, 0x3f310b6915d98ebe,
+    0xba5d613910249d43, 0x24c5682c89abbdfa, 0x9fc30423bfedca14, 0x901db96833fa52a9,
+    0xbe9f1736d97eb958, 0xf446932746226f9c, 0x7da19761fcebf56c, 0x8845a814f8f77257,
+    0x5ab793a939547e45, 0x40641e7cf5cdd356, 0xb239914d4bf87358, 0x999fac1802f6b2b8,
+    0xd35cbf5f7618c0b7, 0xf7919bdf04b199cc, 0x9f5a3667c459ee5f, 0x00df2dd832384802,
+    0xed51acfc2f18a545, 0x9af8632c9b837142, 0x772d9b082b518f77, 0x4a4fe6da551e24fb,
+    0x94c4cc2ed561fb29, 0xdcc76c710cead1ae, 0x1fed8ab1939b0d67, 0xa303612095991ed3,
+    0xa536fcba0f0f76ea, 0x74264d50ac769206, 0x19da09fa962d66cb, 0x96d9c71dee453a4c,
+    0x51131546cfd7e316, 0x214ee7103ec52234, 0x3018d91b7263ab09, 0x4557472904e22d20,
+    0x0b57906b26b95fc8, 0xf996ddaa465e85d1, 0x319514fc073f9bf2, 0xb859bfa18d09f439,
+    0x7f2a6b9c9f4a28d6, 0x2c2f5670e1703154, 0xa107e4cf21ac4419, 0xb60040ffcb5f5aae,
+    0xfce6e2032d369581, 0x8658b85a5dff4c63, 0x2c07d38da7000fdb, 0xb720666b88ff4b07,
+    0x9600148197883f0c, 0xeb5949a357972723, 0x0b312b942877e285, 0xc4d4ecc78165662d,
+    0x763b022a5030cd57, 0x6e2aa340a9550491, 0x03b7b33629254aef, 0x33f462e77ba6beae,
+    0x0dbed34e54f3a7b7, 0xe5fdceeec3b13cc1, 0xa200c9a163916270, 0x3688e741279a1449,
+    0x59c70e8bea329bb3, 0xd10221c2ce0bd99e, 0xc726d9439f7d35a7, 0x009ffb0f623bf122,
+    0xb88c951cf25451f2, 0xda10a5bdcf694717, 0x8656be744bd34476, 0xe5c2dc19c5110b65,
+    0xbfe0be4b5a6bedcf, 0xc457fcb2a51d36a3, 0x359f50094fc82fd8, 0xaaf02f6095119ac4,
+    0x1e13af51e3404cdb, 0x266c2bdc84f6db3f, 0x9f82b0f2652a57fd, 0x8f3f0b6b8de293c9,
+    0x44aa9e92631a676b, 0x33ae67f3ec74530f, 0xb11864a670a7e242, 0xa8cde96206e1c480,
+    0x74048e163aa64cd4, 0x81dc970ec7313f19, 0x1d45c484dbda177e, 0x90216688d459e9af,
+    0x43e30383ab906e4e, 0x563a01f93d788c90, 0x99313c397b6101df, 0xc8c27fba77676836,
+    0xc15edb4b8e7c111c, 0xb9cac86185f336ba, 0x77f0baa96e0d557e, 0x47c0a97c285a6482,
+    0xb59ad6729d7d4172, 0x7b6d97fa16454b6d, 0x41f1eaf7e476b76a, 0x80af51d1e4e59fcb,
+    0xd33407969763ae74, 0xa02488e752d835cd, 0x667140f7990f4380, 0x7678890d8b356005,
+    0xedce6c98788b0b06, 0x14e60f834373c568, 0x898d6d353ccc28fb, 0x2d06a2ef5c49edb1,
+    0xac5febdf98d16f00, 0x9b824db0032eeda0, 0x240b54d85a56e33b, 0xc06e059ffde58510,
+    0xde778a7ea9b544bf, 0x19dc2028b7a612e1, 0xc5eb97d4743f1437, 0x02362c3aecc4d1a8,
+    0xac41a353f92b0211, 0xa31fc5987888f007, 0xafe3194f1e6f4a27, 0x8b91be9945b0c3f4,
+    0x59cdac37d459ebdd, 0x9370f450143574ae, 0x90eece8a94cb96e8, 0xf22039fbedd89d58,
+    0xc08298b7cdb1be19, 0x119277150a9a1f46, 0xaccabb14c1b38227, 0x93025c5572a40ee7,
+    0xda383c64f3418247, 0x669c648344887487, 0x86a37910f7af097e, 0xd8790030f99971c2,
+    0x46019d1b8e52e94b, 0x699a56f74ec93e8b, 0x5aaabd34d78c0ec5, 0xae5ba53eabace49f,
+    0x7805ee7e9aef316b, 0xd16425577a083ed2, 0x4a3747d2ee7f6b99, 0x7ead65882995e4c6,
+    0xf61b512ccffcad75, 0xf78d13b88b2c9da2, 0xa319d1aa98c35c0d, 0x157e0551be6aba34,
+    0xec0816147e7d02a6, 0x2f4f763a84066baf, 0xf4c7d933c4c2aa26, 0x017ece4215371dea,
+    0x8088091307b74f19, 0x26e6cfe98fb50c8a, 0x33a4bf39870d4434, 0x6d2d3f80c4dea739,
+    0x8cba04886c757f71, 0x39eadf79444f6896, 0xc2bed23b3243e10b, 0xa2590dafd63fc57e,
+    0xed613362e5da908d, 0x2580e094c2f1eb6f, 0x5cf88c7a22c02aba, 0x8c9c3cada6743c17,
+    0xcadb640ef1100138, 0x9f50528b757965f2, 0x0d52e40784596fc9, 0xfb2a7937a022c789,
+    0xdb81e89b6ac10cd4, 0xd1204df5fa76fc29, 0xea92a0d01a7d1b50, 0xf48ecf71531edad1,
+    0x30059391b2e681f7, 0x6391454261b4d87d, 0xed9068ef2a7645b7, 0x8806a55012be615d,
+    0x68b8a7b880c04f85, 0x05aa46a3d4e2264f, 0x08423385a515ce35, 0x41f84a2adf4c8b2f,
+    0x28a5187fbbc0e858, 0x9d309660ad3eed77, 0x1feb8ca13c41feb2, 0xaa2bf9f01b6bbc01,
+    0x5ba425ab77f04ff3, 0xedea3f527a14eb1e, 0xfe83e9b9ab8251f4, 0x79394f5a66449946,
+    0x5c6a7f6e79c9bb5e, 0x34341b3e1f763cbe, 0x7b5a757c98ca18ce, 0xc3615376d927f326,
+    0x505bfde5adfc1e88, 0xf0b98a2aaf02ae56, 0x7244c4d6ebaa8d4b, 0x2bb9e8ce1aefb5c1,
+    0x68aea0cd641e55c6, 0xd0f593e69815949f, 0x826a9059ba8d51ea, 0x55b27fb7ee9bcbf9,
+    0x79227896780fd368, 0x3a8efdf9022a236f, 0x1272d61b36e12d09, 0x9ce79d896345f826,
+    0x6716e6f440b36d38, 0x3e96170f7e621ecb, 0xda06d2f99143ef5f, 0x3dddab13edffa4a8,
+    0xed82494c84872ef9, 0xeb19211d839ee55a, 0x52f720891ef19998, 0xca6c1b12e92d1efc,
+    0xec2738353c7968d6, 0x5ff6a127728c2ed2, 0x2a0c885177779f55, 0x38c573f3282054e2,
+    0x9740c56e83178584, 0xba98a230236e6948, 0x4f4e95b6bb26b543, 0x9181ceb4983e12bb,
+    0xa35a7618e4a5ccc5, 0xfa4b0229657b1001, 0x752a479ea192b0b9, 0x6aae4010559417af,
+    0x11555d5da62c1478, 0x59b489f2d68bd4d7, 0xf3690ed0075adccd, 0xc2b078eec1df0e8f,
+    0x698995b2d4ca10ae, 0xa346516ebe05eb24, 0x12d5fa136b091a0e, 0x6007b597aa304cbc,
+    0xe7407db5c9ad7a9a, 0x4b2565b5e2295672, 0x4f8a20abfaa6f2a9, 0x121ecc74092bf8d0,
+    0xf6922528ab009b09, 0x2134e89e96428d91, 0xb9f8392b3691e21b, 0xd858f9bbe42448d7,
+    0x213aca1f570144fa, 0x8cf6156c0dd96b66, 0xd7fbf549528c8a7b, 0x01078fb6fbcdb248,
+    0xf58102a002da9c71, 0xc56489dc7b665d9a, 0x6d9c7fcfceea2391, 0xeeda09505d3e25e9,
+    0x27ad8e020b79b395, 0xca31c006fd917fd0, 0x20ed41e04140f927, 0x0b3e69bde9b5073e,
+    0x4eb215c40498b5fa, 0x2456d7a37f8138c3, 0x99e182cbfd597e87, 0x1f1ba5a69536fb17,
+    0xa2ff89df464900e9, 0x098e5ded43535dec, 0xeca1d9a4ce46e061, 0x494cf6257df05666,
+    0x735354c2df5be929, 0x6f33b6e80619d40a, 0x190f9dfd53e53a24, 0xdf437c23034314bb,
+    0x079461b4c409a437, 0xf973b77942a0330f, 0x974fd04bffb44c00, 0x167f6e279d1ecb8f,
+    0xf5d98adb6319076c, 0x8a75519e569dc9ec, 0xd5fd828f85dd9e83, 0x99ff67029d610d77,
+    0x545013bb0843d77c, 0xff459a28dae52429, 0x5b135a02fc602683, 0x923e37fa39a2c376,
+    0xf9f9b586d292c93c, 0x95fb61c219b712c4, 0x40d2ce541c6b22b8, 0xb10f6cbd43f7a367,
+    0xbadb548c9b1c38bf, 0x5e0a656757089b16, 0x2c600a452d8c33e3, 0x84c40349d25d8678,
+    0x3cc0073285fd957b, 0x88cc2ccad3d4aa54, 0xfbc296f7290f80c0, 0x8cf3d70854d6ddc4,
+    0x2cfa6f2e3345d540, 0xb19e8874a78ec981, 0x12f5c90cfe052463, 0x172104609944a983,
+    0x05f58fefa7f6b6de, 0xf05e15a5f2dbabd7, 0xef523472b72db55a, 0x3cf05c9d9fa66119,
+    0x4d79bc1962c486c6, 0x7ae3fcc54ee8455c, 0xd864a837893c8af4, 0x1cf0fa0e74ad210f,
+    0x0092ae0bdab53327, 0x1df13e481a863995, 0x19c941ed6eb800fd, 0x6bfb161c770b5db1,
+    0x248444244a73ee09, 0xfc439ec3fabc37d0, 0xda851993f7f0f821, 0x224f78a2c5bb87c9,
+    0x98aeabb44b90ac62, 0xd1b0d35d0549b47b, 0x10a667cb4f75b946, 0x6200bc6f9ed1b0cd,
+    0x6dfb3b2767ff694d, 0xa806c425c48adf68, 0x41212ee22cbf2f91, 0x62ac3c2fc58ea2be,
+    0xec0c5355ba52246b, 0x86918527c571128e, 0x61b72f35e7d71821, 0x65a96a5ded652f17,
+    0x0b56d61387e479e9, 0xd0e230d7a85c6c54, 0x5876b26fd39fb047, 0x9231904c8b089d05,
+    0x5fe19216d4ef187b, 0xa10a30dfcba962c8, 0x81ccbdf2510ca272, 0x64cb560235bc0be8,
+    0x6b4b1251bf7f5863, 0x6285744ce2020d99, 0xff88ee6768d4a42f, 0xc6496bc4c8a6c6cf,
+    0xf875310e2e80581f, 0xc3a3d45a6e1d7bc4, 0xab112dae2bff9949, 0x2a80e64779840132,
+    0x3a1777bec31c2fca, 0xc7010f859f0abf5d, 0x2d1ca6c063f86b8b, 0xd2b71b347f4ea919,
+    0xc657cd5b54785923, 0x4d378119fa0b1a69, 0x2cd12e4b29fa0e10, 0xb149abf8d1fc3a55,
+    0x3c84de586aef292e, 0x8e4a2a628b035518, 0xa6728e866cf90b35, 0xef31b01780215cf8,
+    0xeda49a5880d5f2b0, 0xb33d9b96ef56c574, 0x471372bc05abd5db, 0x05b7ef722726cc23,
+    0x981babcc77e40b79, 0x34730c9013276fd4, 0x87ba0e985fad2783, 0x58ac95fbbefe4cf8,
+    0x74e4d1aa1fdf551f, 0xfef6454015b34839, 0xcea144dec9c30fed, 0xe59aa69166f4e0d9,
+    0xfdd5c37e3185f733, 0xb6dfb8d3cacb48f9, 0x65f902de576d8d87, 0x3d988524c00d1eb5,
+    0xebd9934314333616, 0x67fcd3701b03e837, 0x6202a68d578cf463, 0xc56127dee7c7c769,
+    0xb6af8df7e44f5813, 0x44466e069f779290, 0xe23a71297fd6ea03, 0x488b429602215196,
+    0x521f9cef4aa9aa77, 0x2f47d6afe9f0ad30, 0xc1286dcf7f6171a7, 0x081e7f2ece812a0e,
+    0xbfc7bb0d39b3adeb, 0x650bce6cbd58588c, 0xa4e06317525f015d, 0x43846f758858d6de,
+    0x77f35e5acd2e56d6, 0x494bf43c7f17149d, 0x392af1359348f5fc, 0x4a7d6be8e60dc3f8,
+    0xf0d3f968b5efcd48, 0xbc4a4fae25cf8748, 0x1c6d8ee7165cd399, 0x9391d95b3446236f,
+    0x0c3b97d762b4f9ca, 0x74815f9797564729, 0xe41a60c5afd676ed, 0xe8b6f8e2d9891569,
+    0x60b731c56543be30, 0x9512b8f4ff02955f, 0x7ca587481c8bb6b1, 0xaf4f673ffea4393f,
+    0x18942d2e20b138c7, 0xa38aadbdd4c74307, 0xc6e35627a41ab446, 0x4034b149c4a7724f,
+    0xb7fd3ff08c28c73f, 0xf6809cbd28468b50, 0xfc17cc39a2c25797, 0x8bbb63db9fd2fa3a,
+    0x9d739b405c8e2024, 0x61b0449b14dfc732, 0x56ce2d9dcf748cf2, 0xefb2ac6c3994b528,
+    0xea4d787db9e59509, 0x4fa37fca81996899, 0x89485e41136f6a22, 0xd685ce0dd5a63a3e,
+    0xe402e86a88fb1ac6, 0x721b6160ae5ca70b, 0x7355b0c8a5fd88c8, 0x21d7bd40796afc8e,
+    0x1de5772458503b56, 0x598bc9712cf3ba13, 0xb553d8866f7d5c8e, 0x7d431ab8ba2fd487,
+    0x63afba54a3b1d555, 0xeb39296639789ccb, 0xf54222de777ed5f9, 0xabac55b10b2b569c,
+    0xa09238e047deb9f5, 0x8f74f366da17a8ca, 0xe3ef900c8c3629a8, 0x3577a9786cb904bf,
+    0x2e7f42a37e8e0fb1, 0xdfc15edb497e6086, 0x8785de48e923ac9d, 0x85e34ab2011738d0,
+    0xc950794a85e6a731, 0x0c7d109211480f48, 0x2ba4b7cf0d76bf19, 0x041f41dfde69f73c,
+    0xef59dd23bd8ef8d9, 0xd64b314001e5b111, 0xe80af5a1fdef1aa3, 0x4cf8ed2e8b71d022,
+    0x020fa66c449fcb42, 0xece6813c5819e0e1, 0xe6e57d0fe18f54ae, 0xbebb11bb9a6360fb,
+    0x35ccb891713139fd, 0x7ce54b4b8f89236f, 0x4b9e44c12c3e55a9, 0xbab24e1453b807c4,
+    0x6040af471b7ee1d7, 0x91708e7ed3caaef9, 0x489ffa5d7a60efc8, 0xd51e3fda7161b32b,
+    0xfe1f6b6c2e68362f, 0x67b5165987814caf, 0x5a3864ac8d43af1c, 0xb305e466c2a3c391,
+    0x132e681e375de325, 0xcd89d489beddeeb4, 0x1146be2048f6010e, 0xd53006e437ae3524,
+    0x8731bfd1ca2c3923, 0x48494a7002dd24ce, 0xf9533d3a7b23ac48, 0x72d087f6df705b5f,
+    0x8c7659388d2becfa, 0xef7a6f6c261a2448, 0xbfce2a63a0340782, 0xdbe042752e774b62,
+    0x29a0e5e051929b78, 0x5f1c5591f63a6b08, 0x6709ad3e9a4ece24, 0x97475fd2068961c3,
+    0xe56e440dab79d197, 0x227c7ef9bbbf5547, 0xbc743cd37aa8f2b0, 0xf8afac7821d71a61,
+    0x3d42088321af171b, 0x5bf6648dc272f131, 0x16dece791b8f4d9e, 0xff38ef9d0d52c93a,
+    0xae3557d782cbd5b7, 0xbb12f14dfabb5fd1, 0xb3ca67a4fa0b75ea, 0x544611df80ddd610,
+    0x975124f339361cb8, 0x09c72e2e57b0965a, 0x8c24ea863a8b1a14, 0x1069999247bfdb09,
+    0xace10b5ecbd767b8, 0x95a2d42bb1a7c496, 0x7da99714f2088462, 0x559c0cec0bcc2d7a,
+    0xae84909e8cd01028, 0x06bf259946b81d63, 0x03f342a99327fc76, 0xee959fc811920e9f,
+    0xc7b93b12aae3b94d, 0xadea2c0f1e760dc2, 0x311d6a3b54121a6b, 0x8cbb1ea13aa7ea81,
+    0x380b4dc70c0f8bd5, 0x14b1c4b03590666f, 0x763a87f67cbfa18b, 0x3e347ed2fd3dfcc7,
+    0x9fd42bbeb5c085eb, 0x75b456b008137a22, 0xd0351fb2fd917802, 0x7b5c0a00a8ad2334,
+    0xdabc5ea514f0f968, 0xbef94846a73303c9, 0x26b45cfd96c3ac34, 0x9bf8fb443c6631bf,
+    0x6f10bb548f2aed0d, 0xa62fc1632a60bb0b, 0x65562b0ed93be43d, 0x99e8f28e6df42b57,
+    0x14cf6a79160f3868, 0x88205d556f2bf11b, 0xd877f305c6ee4775, 0x278c8fed761126c9,
+    0x283503994b881394, 0x2fc629c7449c80bc, 0x47802de80be8e6ef, 0x3eb41769ee3041db,
+    0x1b84dc93c7cb7b37, 0x7c58e6977a0d5c1f, 0xd6790a55132c1b25, 0x5322dba3b21ca1e1,
+    0x2416487c999d8c1a, 0x5c9b4a8657dca5f1, 0x4d2f568bbfbc9317, 0x1d1c964cffccfb62,
+    0x4f7ac3a402c81e71, 0xa6da38e792c6a997, 0xc026ce4cec9165ab, 0xff6e488c0467ad22,
+    0xef5dcf6bb9e6411e, 0x0f15fedf69014315, 0x5c728d6cbcd971f7, 0xe320ce16215ae412,
+    0x39b59d437ada21a6, 0xc92a4faee7b451f6, 0xb39fb344b31fa7b6, 0x6071d637d24ad61d,
+    0x96a363b25a58e718, 0x9ed7cfc69c8a8608, 0x7976052ec7084378, 0xee726ca1421dc01e,
+    0x39d6250e60de1513, 0xade9bbc74e1cf21b, 0x7896a0ad9375e932, 0x03734749c8c79525,
+    0x287dabca4637f693, 0x1773bf64bcd61426, 0xc7cc10aef4ae98ea, 0x5dc2b513a345ccff,
+    0x3005dc4092f47b43, 0x116deb37f85ec19a, 0xf8ad128082bcd8c3, 0x2be8385dfad84471,
+    0x7c595be25dd11a97, 0xcd7fdcd378b99f6e, 0x278796f8218fc82c, 0xaea5fbdd7c03bd3a,
+    0x5a03dc5d5e0c5a10, 0x854e08e6ede81b1c, 0x642d53ce0e774e1b, 0xcf982a65ba398267,
+    0x381b302d00f79a32, 0x86285ebda094daca, 0x93e4945169f84dcf, 0x1fff48161d6e6785,
+    0x2ebf5200b02f89e4, 0x8e57fbf43ad0b575, 0x11f03e546093c5a4, 0xa9c43bfef6a12e53,
+    0x1cbdf3f0db03b0aa, 0x58fb58623b42d053, 0xeb4291fcc3f295da, 0x97730aa364c4bb17,
+    0xbfc76b5217202b4e, 0x7f6a18487009c138, 0x680365e6b9f4c8ea, 0x9339937f476b7274,
+    0xc78d4daf2f1af167, 0x3172bb83b5822cb9, 0x865b94b229cb5c9c, 0x1cbb0f3eddc56189,
+    0x097982485d884cac, 0x1810e173e689f918, 0x4a0955f9793481a0, 0xad260922a96845aa,
+    0xb49c884d74b91ac7, 0x369013a60b276929, 0x248a4ae5ec7f4908, 0x80834fb368fc3a0e,
+    0x197722c3bdd8b63d, 0xd82ac6d4c5bd6d89, 0x3a976797f06bc01d, 0x433e6535f3ef1972,
+    0x7fd2066f06f69b7a, 0x6490ab016fff509a, 0xf441454f800c2b78, 0x3f422807faeaecbd,
+    0x118bfabab88bb664, 0x32e6bed45a9b49ad, 0xec2eaa8c88fb667d, 0x7ece0eecab857703,
+    0xf51c674a8a11f9ac, 0x846e767c598f6401, 0x029c07000358894e, 0xb306bd7d31470e84,
+    0x6866c7e835b73b3c, 0xf5a8ca298b88ff27, 0xf951770abce2a8d4, 0x07e07bd93db054cf,
+    0xde5bb89025dec091, 0xb2af44fa36411188, 0x902439acdbe4f930, 0xc6b3fac44e40f420,
+    0x186876fac46832a6, 0x5258cea0244ab893, 0xea22f7a43bc92a87, 0xee3e7bf146e3824a,
+    0xf0ddcd312573a1da, 0xf620333e26b9e5a1, 0xd8853a4d853fc845, 0x713c88e7b944ecbf,
+    0xbd79db9cb8a78883, 0x1c46a16f48c42525, 0x1e60aceee20a63d1, 0x4d1b1b9c09d9f56c,
+    0xb4a5f6245a1995aa, 0x56b7380d3e9d765f, 0x7ecacbefa52c0ee9, 0x8fa6687b5d02316f,
+    0x76396ec915021cf7, 0x77ab0c354d51c4d1, 0x1b4fc2d74b593b57, 0xf1744c14688bd5de,
+    0xa0956a6aea17d831, 0x796d98fb14fc4bc3, 0x0e4e1e34ae4fc212, 0x166f9ab378c4bde4,
+    0xcdc6d3c86b1b861c, 0x7d6574ca714b2bfa, 0x3c63a57276368ba9, 0xf80dbc046bd6de94,
+    0x548385a3e8d59e83, 0x3333388e47045aeb, 0x56a9de1fa85485d6, 0x9f88bd7afee12e7a,
+    0xafd1333a45cbbbf3, 0x318cd4f8c49b7fcc, 0xfac196c77e9fff30, 0x83e1a60ad1e5af73,
+    0xb979b142790b7948, 0xbe88357e028f50d4, 0x7f5858f89e6e50d9, 0x6a563f66bf259555,
+    0x749cd8a22b7e0220, 0x81e3a9ce148315d2, 0x0fec9a40dae553e6, 0x8cce58ad20b7286f,
+    0x93bad9eda0d5c309, 0xec87dfd14411cb04, 0xe806b29320a9e78f, 0xe8ac02300cac85b5,
+    0xa79f86f98d0e8e06, 0xb221207e1d985fe2, 0xc3816101896eaad0, 0x407448ee59864754,
+    0x137052f472c6bff3, 0x29c35083b0b6d27f, 0x39e521453ebb592a, 0xdb5cdcded30856e9,
+    0xcce4976b116fa8f4, 0xa1af65fbafbfd988, 0x81db37287dbfe45a, 0x119bab417d4af1f6,
+    0xfb003bf9ee855adf, 0xe5f8854c250dd0b9, 0x46c1ca3d81951d5c, 0xc40770436d016ffc,
+    0x03c11b96d26b762a, 0x87bdc4bf37fec03c, 0x16d4a8717927b4b8, 0x021b16bb013ccc43,
+    0xecbfa03aedd2e1df, 0xb67aee43c87df0d4, 0xb847d33bcb69ac93, 0x475c76803e2eec8f,
+    0x66dfe9228fd3b1ce, 0x7b130d567d3af872, 0x3fa9ba8fde7fc7c4, 0xbb0d8dc8404ab6f8,
+    0xe63c7307cd2db0a2, 0xad57fb02ca938282, 0x553318c35185e657, 0x050147e18fe1ffc4,
+    0x52d7d0dac981a1fe, 0xe54d6b71ffabf5d0, 0x25fa82184a37a00b, 0x5d048321b562a58d,
+    0x4c1958dd52e81893, 0xbc668c0e11fac5c5, 0xaf37d9f16abb0f3e, 0x7887b8c3a28a8d89,
+    0xedec834291e00eed, 0x446a979b09f0fc29, 0xf0db8252066574a7, 0xb61958937c5e5127,
+    0x51b1c1cec0e6717b, 0x286052908046b663, 0x8dea94fa60fefe24, 0x354ba99e3930e757,
+    0xc8a49ca543875f77, 0x5ee3bad3778e2cb0, 0xb81da7abed9344ef, 0xa2131406e1a05327,
+    0x50df10d4eceb1323, 0x859026caca68eddf, 0x7ded0165756d0450, 0x5d3e41baad59b0f1,
+    0x8d46ab0515ee7a3b, 0x295d22ca970cc722, 0x1e6e06b0b4a3fbb5, 0x1dda86de567216f4,
+    0xc99488f9cd555816, 0xd5abcba23f172d15, 0x5fa08558eeb1ccf5, 0xfd8a30b318d69e78,
+    0xaffc46b9aebc7a45, 0xb396ba79366f84ae, 0x66eed81ab8ffe7c6, 0xc1da22470be493ea,
+    0x84ad6cc6514cc514, 0x880096a42d267c99, 0xda2be2bc9ad48290, 0x17088feee4215431,
+    0x916abcfb2746ef63, 0x475de55088fb4c88, 0x6ee8e0b3ce9eaab4, 0x57bc48d98c863ed1,
+    0x845bb4300b042a57, 0x3b16bc0c9bddf909, 0x319526896b3800de, 0x1a4dd5eddc1853ea,
+    0x8d7a1df250b85a57, 0x0b00574ed2975ab3, 0x0db42aeaa0189096, 0x20bd15572aed0d4d,
+    0x5fbdac12389693b3, 0x81bc96523ee3ca8c, 0xcb9ac85d56f3e146, 0x090238efcb6eda8d,
+    0x9785e3e6c686e737, 0x9a62793f33c6b9a6, 0x058fb4a54ad2b2f4, 0xf2f686c466200060,
+    0x3a6b7275d1686c88, 0x6b149bc06d7d86a7, 0xae073b140eb6acd7, 0x51274bbc99d6066f,
+    0xb158c9841bd199c4, 0xd7e16b60b26a7eb7, 0x1069d9f2de90a6cd, 0x90663da0622f1ab5,
+    0x4f57d13929595ab4, 0xc687c5df6d40d472, 0x1d6008d77182b065, 0x14c6ca6c71b2f11a,
+    0x2834fb2384891466, 0x40a90a68b5a86462, 0xdb35e106f58a84cd, 0x8c48bbabfb51e44f,
+    0x7dd094e54d15c3a9, 0xdb499c28a1911428, 0x308532040f8f4326, 0x9f6e22113c7348c0,
+    0x8a07cc6097c879a7, 0x6e602f87d6969b82, 0x6897479c6c45b17f, 0xf7ce451d40b22cbc,
+    0x1ed20031de7c1345, 0x0c366dfc04ea1611, 0xec3611dbc8ea5601, 0x9f4373a66a7e0e56,
+    0x56232bc3879a3456, 0x9cc5fd6e76be2fcb, 0x13eb8ece1cd0eaab, 0x4df1b25c0276d6d7,
+    0xf6323df1036e5375, 0xbc0200de39cfe33e, 0xbe3a04907c6cb38f, 0x55bb3d90fb66ed13,
+    0xb752d0b8282d2e71, 0x9396efbfc26eb326, 0x33e347a7cd782851, 0x3d77a27349052d7c,
+    0x8f90eae8dd8cd099, 0xf4d38287d27674d8, 0x398d4c7857e5a5fe, 0xb4ae0bc4e01eeedb,
+    0xc0a906e0f9062411, 0xdaa1135bc67b7949, 0x23f80659a292dd69, 0x2b394877fa0329c9,
+    0x163c645023f9fd12, 0xeb655d2a96d48ced, 0x680adc2b9a66973a, 0x91a8cd3211eb77fc,
+    0x491c574620d306c8, 0x31f110b5ed3f13fb, 0x52e86b95366bd6e9, 0xcc01c2f2be374309,
+    0x9dab55a7c75b6a57, 0x526190de757e5e86, 0xb9fcdf8e89fa6fae, 0x51b6e0c2b03a1c21,
+    0x3adce5a5a3eab5d6, 0x97e458d94659ee5f, 0xd150bf343ffb16ee, 0x009cd0de2fc1e72f,
+    0xff141cd5e5ad1b26, 0x54b0d1847420655b, 0xa3e5a191e1323d1b, 0xf429caae02104bbb,
+    0x0b9b17d3b8cccf19, 0x65da6c4da45b4c2c, 0x0b5412de65534506, 0xb1e89d1f209a5e43,
+    0x87990c5d5293e72b, 0x34f5cf16807ec975, 0xb2c51884ac456077, 0x821c8ce238c05fe4,
+    0xd17adfd75f40dd52, 0x5a61cb81d92868b0, 0xc486d7a731556bc1, 0x34dc6f4922f0e91e,
+    0x49ca37030cd265fc, 0x731e9af4e41a77fb, 0x7a8c710b5ef0f258, 0x0f27fd7d870b5b77,
+    0x7e2a2f1c243d6975, 0x2c9fe8c21aa15fd4, 0x4147f1e84be5f224, 0x47244f16fbb1c9da,
+    0x86e44e0dcde70809, 0xfb346a53bb5b91ff, 0x840997431e1db936, 0xf6c8fb327ae28812,
+    0x66d82966f517017b, 0xab024cd988e38abc, 0xf2c02665d1ae341a, 0xa46d18472c6ac0c6,
+    0xbcb3fc4f24b9d187, 0x40e6409677d10567, 0xc132b1f9bfbc404f, 0xefcee6ae59c7dae0,
+    0xee962e698c550346, 0x1ca44c67a8824027, 0x9f08842216d2b97e, 0xa14eb0a9c5ed8b25,
+    0x8159c8af5c8defad, 0x386997be55345ce2, 0x19bad24331bdc92d, 0xa227551fd7080984,
+    0x7cf27a661f45df46, 0x35103e7f14d29384, 0x493b201cc214bf4c, 0x77cd41649dba8940,
+    0x643a0d389d104682, 0x7427f5f93689e4c1, 0x6397a46d4ff2087d, 0xf4a2b73896daf39f,
+    0x21809b18b5eac3ea, 0x85a2259cf55b2438, 0xb7ccd40971b91f47, 0xa29de6af6ffa0621,
+    0x32c009dc7fce6950, 0xc10c694d96622c87, 0x886937b28996ada3, 0x17db2c07e8fe3588,
+    0xbaf902f04b2eba13, 0x3968efe51c670406, 0xfcd22b94d0354dd2, 0x40d5d2f6dd551edb,
+    0x59490b0dde63409e, 0x4c48234f8820b2f9, 0xb4509f5fff9bf30a, 0xb5d4ed123227f0ba,
+    0x620aa25051c1c32d, 0x7b690191a65a906e, 0x019ac9ed4eb5d353, 0xda3a0dc772f26513,
+    0xba5a09c4afc2e0e0, 0x689e61acbbadf576, 0xa353ce1a048f022c, 0xbd45919e76443ff8,
+    0x95897713bf3f7def, 0x0b70b8705dcca6c0, 0x65543890faf155b0, 0xed848447a2683e44,
+    0x832fc8c367908715, 0xde0ff170069a16ca, 0x084a2364f2635f70, 0x0113d7020a7220ec,
+    0x543082973bd65a07, 0xa4c63e4336d1a318, 0xd3dbcf9354c449c1, 0x225e775de9350249,
+    0x1cbb001a28fbc258, 0xce4c601d579b213d, 0x46681838f213d25a, 0x9d43855e3e7e03f0,
+    0x99782f4ec2b4e2fb, 0xb9d227d7eb3f8866, 0xf76c58253f25adee, 0x92794d043a80fd6a,
+    0xe8b687879dd2cac1, 0xf03b5834bf83afc9, 0x40c2a6058667686d, 0x75606330248ceb20,
+    0xf58f1abb2ccfbb8a, 0x83ab4d1b506f88b3, 0x7aec43854895380c, 0x6b1704eb1884b2e1,
+    0x85f5fdb369b2f763, 0xf4a67925c75b30fc, 0x0122254b411fbbaf, 0xf64b23f8cf626f07,
+    0xdf3f058d75320463, 0x1ac2a961efecbba8, 0x1a36d72aea85ecf8, 0xdb5cf835c37f7967,
+    0xc7e036882fe086f2, 0xc8d8bd4674a06663, 0xd47e78ff2bf094d7, 0x0c4281db440dda09,
+    0xa0bf049de5d6ab70, 0x85fc8c961f4985c5, 0xd8c25df222d6d775, 0x7799790cce91ca42,
+    0x7dc42e708d076826, 0xf0f6425f77699890, 0x9161a0a45582b39d, 0xd5aeb6a349384433,
+    0x671e8b8578dbfa7d, 0x706e283516e60e7e, 0xf632ffcf1178984c, 0x8409894968f7bea1,
+    0x7c9ee17d2cbed180, 0x14bb7cd8c3974214, 0xb290b4877c9b7315, 0x9c2d6b6927eb7ac3,
+    0x61a9f0e19d788fdc, 0xb7aea05fa5f555ae, 0xdec73c537f3db231, 0x6e63d644550eb53c,
+    0xa74d959f6da82838, 0xaa3081c40cf1e5b8, 0x0e70c4f8e4d83059, 0x608c93d1f2dec243,
+    0xfa807f7e093a82b1, 0x86bcce6f037e2f70, 0x8e3c60535d61ecf0, 0xe743d7065a21632f,
+    0x639b9955b7141aec, 0x1431044b1d4eaabc, 0x4703213e0b49bf54, 0xef8b535d4f3995f1,
+    0x27cebd117cd05ff5, 0x903b9ecd704f3fd4, 0x614d0ab7baf89831, 0x6b74ae9a438b0def,
+    0x35a46df64486fd29, 0x2fea2fc271116e28, 0x34995813fe6d45f9, 0x0af1611c025d65dd,
+    0x450d8f92ae1a6d49, 0xebb2d054d8a070ac, 0x6cd28e9a73c75b4e, 0xb56cc880bac076cc,
+    0xe689a96d9529bbac, 0x4d8bfee427a06fed, 0x4dc43e80db42d244, 0x253d2f157fcfc7cc,
+    0x1b36b8924964d51b, 0x16fe011e663e4fbc, 0x98d45c50eedb8c8a, 0x9d0256f64cae3055,
+    0x91e142cb75b136ae, 0x1ee43cb48e6e51cf, 0x3aab595cd43c9b13, 0xb79c409097ca4a46,
+    0x99cb6bf91cc32794, 0x0861160206543b6e, 0xb0b5ee266acf7ea9, 0x78c2420e4beaca69,
+    0xd2586f6808c86efa, 0x100797b198f9524f, 0x0ca721cb7e7458c6, 0x6e1c758901e96453,
+    0x8399cbf593682d57, 0x812e472be24f6394, 0x8e45d96ab0a0a554, 0xfbddd9460d5592f5,
+    0x955f658d488e5125, 0xdbd5dbbde0fe461b, 0xb202afd7b9faa7ba, 0x6322d147c7c08ec3,
+    0x95eb6ac3a39643b7, 0xcd97daba88a0141b, 0x8b2429e857941fe6, 0x28bf4a468dc67e3e,
+    0x576039cc72206f2f, 0xf283489a29bbf68d, 0x2214e6ae43bc8144, 0xb524f64ee7fe5e76,
+    0xb674632cc7682479, 0x6bea5aad8039504c, 0x03f7f346016d82a6, 0x3b8cd0abda013556,
+    0xb68811b0116c1427, 0xc12aaf339b83df78, 0x24f4f0e015ff7c26, 0x598b979210972481,
+    0x9d19307d0f5df58d, 0xf38bdfbf519e6260, 0x369d977cc33bd6b5, 0xedc765ae579c459a,
+    0x347f272b9a7d7a0d, 0x887422d2394f953c, 0x8fda4ec3e4d78c34, 0x4887b7e67949ced9,
+    0x98fd3a4b97234966, 0x4d601a46b1573177, 0xc632620905135ec9, 0x94435b22dcd9185f,
+    0xd0bb9a54b004f3a3, 0x26cc67324152b084, 0x16c8fe35ac59326f, 0xc1a2d87a1b150477,
+    0x41a744afcaca6cea, 0xb31edbc358aa776a, 0xebb2b59c6dd82907, 0x3cf5df8871c9c860,
+    0x7fc4d35f7108cd99, 0x135fe0fa74776336, 0x12af02f6aff3c2e7, 0xb71af290249ac88a,
+    0x64b4007b8352c260, 0x40b64070497bacb7, 0x2a8e0e0d955f67ef, 0xe7d37387fb35367f,
+    0x655cca1904f00c8f, 0xf50ecfa74584b18b, 0x0c49502a569d9130, 0x00bf4990f460ea5d,
+    0xe5d6006142ae82ff, 0x0a343a1c81546b41, 0x3244031fbb1e418e, 0xeae54ba7d9f0317d,
+    0x8dbd872b61c97b62, 0xefaa3abcf4f5dc50, 0x96211d420d21c308, 0x7374d9074acb8e58,
+    0x933cd0896ee2d486, 0x401023853b2b60f2, 0x4a482b7a5f1639f7, 0xa1aed65f96bd7227,
+    0x7e5bd5006302db1e, 0xfcb43e1d5eafd38e, 0xf34e7be1e207dcbd, 0x0a7745c6bb918b00,
+    0x60da91d0163d17a1, 0xf712c6728730a3d9, 0xfb72e5d721cf29eb, 0x439838a5a59d9df6,
+    0xae4cd5ef7b22af76, 0x186c41cfb6980f20, 0x26ee0401029377ea, 0xc3c2a5d00fac1f42,
+    0x33bb54520719badd, 0x9feee55aa715e1cf, 0x73f4dea6f2bf7199, 0xe99c89f49f04ed9f,
+    0xa8f7db84baa1e5d2, 0x618b8e6413dd2d2b, 0x83fd42038fcd86bb, 0xb3eceb4426fbee3a,
+    0x2afcfc8ba102109c, 0x31ac3241eda31e15, 0x785c6d6a193cf12e, 0xab91328deed317b3,
+    0x3fb6c70bae1e5690, 0x53a3271b1205389e, 0x7dc7fb7fbade0157, 0x8af04ee90e1df1ad,
+    0xe686e8eb2b885a50, 0x867646185f914728, 0x0182ce21d54f966a, 0xea30017861a8d5f4,
+    0x5d8381a903ef207a, 0x6123d749e4f54507, 0x6a9d9959248b0411, 0x025010124d403150,
+    0x3e7c9eb6b8f6e652, 0x9878ca01e43086d1, 0x6dde94619446e008, 0xc469fe4f41872ce3,
+    0x51e6956544513d58, 0xd1ddc41a13d9fc7f, 0x21afb1bf716121aa, 0xd53f897089367216,
+    0x65527bad6b3a2c27, 0x9848ffcf4e98351c, 0x56176f3a88cb2b42, 0x56300442ba185c38,
+    0xdc90831a66920a1e, 0x405daca592562c05, 0xe0a71936e38c60d5, 0x3267547ef30251fa,
+    0x56003ed442378b5b, 0x9315be632e62b87e, 0x192da7e0cbc411ce, 0xb46b71091bcef22c,
+    0x7bc40428200ee6d2, 0x4540baf5c8d5d2f5, 0x83abfe3434065296, 0xcec001f9037b9419,
+    0x3449e86b43898d69, 0x4be94bc6f5c1c5cd, 0x59dd29e86f776057, 0xcdcc698b6c06c3e2,
+    0x828f81baf9fb9ee4, 0x200b6e71ee5ec8e8, 0x738cb5efbd32fb62, 0xae6be968973a4d09,
+    0x16bcbf0bd0a81188, 0x4c7d58b994a44e9b, 0xabe8710e166ae230, 0x504ca5f098dde5f5,
+    0x3d3a79ec96161203, 0x6137e97df91d9e23, 0x57b9d078ecd4187e, 0x2d98715713f7c0f5,
+    0x8398979451ab8657, 0xac10523a5630fa8a, 0xc82d2dde64318fbd, 0xdd4f197ace5c50cc,
+    0x6043e1cd30c2e62b, 0x3e6ee98370989f5c, 0x9c41b28a4222682a, 0x527acb6525cec6df,
+    0x520f60927b8c02c4, 0x8fff209d6c124820, 0x93058a335cdecef9, 0x3dbedb79330bf51a,
+    0x15615c9bd9fb8703, 0x4a9bf8cb139f9509, 0xef04923feb60f7b4, 0x97af68c4f7f5da06,
+    0x2357083ecf66d30a, 0xee61008080aa9cd1, 0x1baf1e0f0ec01493, 0xa365a56b96e262cd,
+    0x20adeca7e178ba2d, 0x27fe45aa068833db, 0x7e26c1eb6f761404, 0xb5f79a9d28c8caa4,
+    0x7d114fb6b2cbed4d, 0x4921e49853a73052, 0x531a3502bdbbdbe9, 0xf916bffca0cb46d3,
+    0x033fe5f86d7f7eb9, 0xcabf4e471bc58f68, 0xbdf898cefb3a56d1, 0x5b5d0e88c549432c,
+    0x89b40b74aabf8a70, 0x3607a84ae4d909e3, 0x64c3628e19e0d4a2, 0x65e17438df7afc79,
+    0xb6bb00cb306086a1, 0xa0bf41e8ee9fd52d, 0x7cb8fd7b5aa796eb, 0xb0c01d983e2cf306,
+    0xa64218afd895b447, 0x662a30ad1b19de18, 0x5682b576c9f43071, 0xc9e4351037a759a8,
+    0x32dcf8b8b046e75f, 0x38cf32230f99c345, 0x1f61eee045bdb85e, 0x2e42228c48040193,
+    0x9593a9404a0309c4, 0xa2392e5f71509f61, 0x307855be0c7ef645, 0x730df524deba74cf,
+    0xa1d3837ba3c95652, 0x842d30565aa721ff, 0x9b88fbd4dafce804, 0xb0f0be69b887a685,
+    0xed053fa18effac03, 0xb851839fa70583a4, 0x7c106bea2bbd22a5, 0x1afe56bdb85fbc64,
+    0x75d0f4e3204a181c, 0x507102f12f7a3da3, 0xbfebe581d7e53f6b, 0x5e648421025ce076,
+    0x26ef0dc90345c1a8, 0xd28e3fe03a89095c, 0x8a24e87e3212eeff, 0xaa234445aebe296d,
+    0x5301cf5bc4af8082, 0x7a62b9dd6d53ae07, 0x9a9dbcde3709cc13, 0x1fbd5436ef6fc7f9,
+    0x3edc99c56999e2df, 0xf466fbe9ff70f911, 0xe03bee01b787e9c0, 0x7b47c872037af647,
+    0xd76f47b225c866dc, 0xb3113aed15079fa7, 0x4acf41c3b762e1a5, 0x0baa86333eddc8cb,
+    0xf581948ccdd28255, 0xf0f04a357ff9599c, 0xae171d2d0225fb42, 0x91903289ef7930d9,
+    0x00fb35b4f6a9bcf6, 0x4a786f87fb6e8fc5, 0xdafc05023fceb76e, 0x9874e588f0bec188,
+    0x12a769dd202efd0c, 0x85818d6f5ef8b8d1, 0x8bd2c4f4dbb768fe, 0x9ef3e8dec5958ff0,
+    0x4b8dece93ed1335b, 0x5da3ddf77a406946, 0x555de771452dd1d2, 0xd9478cf6928fe738,
+    0xf03111d93945f93b, 0x81ac5b38f15ffe63, 0x9ffe820ac205763e, 0x1b040d2956151cca,
+    0x764316f890e02925, 0x2abf0daca2966dc8, 0xf784a91aae813c17, 0x982948159ffb9c2f,
+    0x7b67871a009042d0, 0x73d8c1361657996e, 0xfab98180eb49da04, 0x20843507eb411389,
+    0x3ef7ebf9de982c7a, 0x2246ddad3a0e88f6, 0x666f1a266aa93181, 0xa7c19fc7e5d3cc5d,
+    0x7e87b055e5bd7d9f, 0x2ec184dc4cbf26cc, 0xd5f39ba0c8b0a088, 0x2eac412972035849,
+    0x498e7cf8363f10dc, 0x709b2dde5b044624, 0x3e8c0d259e815829, 0xf068c65157c4d7d3,
+    0xb9022fd96460313e, 0xdc7c2dfbaa54a595, 0x583282bb0d020607, 0xac585d61b125b090,
+    0x0155549cbd8fde1b, 0x1dcdebbe4c162b22, 0x45b2f35f08e509ba, 0xe808ed02dca60732,
+    0xfb718348209545bc, 0x285765e3de6ef74a, 0xc7429c6c587d7eda, 0x4ef2621222db5c36,
+    0xc0f0835f99acc3b1, 0xd3a8f0fea87ea2a6, 0xda0dbbe05a9ca860, 0xaa5e49b85e190580,
+    0xe17106bbf76505b6, 0xf8a0a9e7f286efee, 0xdd1d9a07f1840f31, 0x04a404b397e5e38e,
+    0x9a72ef2eaccbd34b, 0xb76be2fbc41c7e2a, 0x02f63e5d3cd4a0df, 0x0a75bbc8a57fed20,
+    0xa669195cb5ecd427, 0xe226159d87c8bb8c, 0x2e63752d8cc92f69, 0xa518339dcace6eb7,
+    0x9cdc7c58c53565fb, 0x7327ecd0099e1d50, 0x8128c2fe82b672fb, 0xa515dfd9232d16ce,
+    0x991f827c6561ceca, 0x17682d27710c882a, 0x0940582a4ea30f49, 0xd1fe404573cc723c,
+    0x7fb9dd591b19e5ea, 0xdb7759932c7da254, 0x652ebec852092dab, 0xf4b8ed3f0b2172f4,
+    0x6b4c26a4b4e6beec, 0xeb510fcbbc360df5, 0x5d1f0922d4116b90, 0x56c1177ea9f64412,
+    0x9d7df37010799b30, 0x775d3cc2990053d4, 0x68e73e69fe472025, 0xe23fc13c6d746f71,
+    0xecf9378f86db8a44, 0x80297b8b371d7d9e, 0xd3fa12e7433597c9, 0x1f7773e8837e16be,
+    0xdbcc6e5e11e60231, 0x5e8f530da76f87d1, 0xba6a0d4856139f8d, 0xe383eb90bb09db3c,
+    0xcbbadb96955ed295, 0x89c584491f3930e5, 0x41760c1f15a070ac, 0xe1aa9df3299b66a1,
+    0x4c271a3689f3ed87, 0x4618676670cb913a, 0x41a62391cc57387d, 0xacb03e3d9e4e7f82,
+    0x8356a373b82ba49e, 0x224ccd2a997794ba, 0x2c4e435a01b9ca3a, 0x22877d1d20b28c23,
+    0x6134a7deba440c96, 0x5ba4d3e7f825fd5f, 0xcb683c6d43c36819, 0x7f6005c11dc6a5b0,
+    0xd4fe6a9381de7d28, 0x302fcf21960d1b76, 0x9a065fa6c1ba98e6, 0xf4ed0846a5ad5d8b,
+    0x2ff614418b2ff64b, 0xb4673b0cc433dcad, 0x3d104de992497c73, 0x269ab4bbbe609cd4,
+    0x48d501b19c42ef87, 0x343eef2e11528f2e, 0x75a1d1459cc8a9bf, 0x503aad736e5eb5e0,
+    0xe13e93bcbf084407, 0x1bd1c8c46e78712c, 0xbd868363c64e9e68, 0xe41280e44fbabf2d,
+    0xaf41f4ddd6e7e6e3, 0xe5a14b72e8098356, 0x60dfe1d0b687d3a7, 0x5a7c17719284808f,
+    0x9dd04d7dd7e41251, 0x9f5b534965831d43, 0x395f4b106ef8f7b9, 0xcf35a7674e5a6bf0,
+    0xb003afe7b10ed65e, 0x17017999f75b6f41, 0x124f903231322de6, 0x546efc3be4411660,
+    0x2f232787c35b2303, 0x89f33ef8bd2e4b2c, 0xaa02ce8453c42972, 0x99f71f9accdd7e8b,
+    0x495e6d039f150a4e, 0x2c9305ae595f0887, 0x5791e2c112278caa, 0xa1a639fd2e6eb1a8,
+    0xcf490defb1e73dc9, 0x950805176ba4e4fa, 0x0943ded0cdbce39c, 0xabdd467ed49bf0dc,
+    0x70153fa51a375f16, 0x7872d21bb6d413eb, 0x47579509740b517f, 0xdd6dc607d26a81c4,
+    0x9efb3ac62aa0e9dd, 0x22bd8a0a6794978c, 0x333104aebe66f00e, 0x71fdecbc237f48e4,
+    0xe5094fa5897a738c, 0xb6413d03dc59bb4b, 0x9e9831ca0f5fab66, 0x72c952da710e7044,
+    0x74fdeb2b92061eb1, 0x7b6bf9595437d08f, 0x0d44d03f8583fd6e, 0x05d18b864d853e3d,
+    0x568b96f2b74542fb, 0xb78a094fdfc5fc81, 0x686fe923378d17ea, 0x70aa6935c3bc7a32,
+    0xb98ccbc60fea1d2c, 0xaa128f7804c12ead, 0xc8136e896532d849, 0x1ee882a0cda25a0b,
+    0x8a83aaa0a6142885, 0x7a072dbabfd45f71, 0x55e395107d126cb4, 0x2956db2f72ae0f19,
+    0xe9f14ee90bc51a8b, 0x9394aefb546b9982, 0x6fb6bfb4ef434607, 0x8dcc597412631606,
+    0x6bb4273ee371886b, 0x4b4ccb0af8057166, 0x6962af84c777da08, 0xe76d148e6dfcfb34,
+    0xe32ef2e998aea9a5, 0xfcc3c9279b3cace0, 0x93030c3024b09418, 0x29236a166a3e2ea8,
+    0xe04190cf2e9a7d4b, 0x859c38e45df94307, 0xe0474104d932a445, 0x1a2b33087c979ea4,
+    0x05907fdfa7d665a7, 0x2ef6a05e39a5602d, 0xb75f2f3fad29cc12, 0xe8429c87252219dc,
+    0x48f611be01dc5959, 0x8c68678d362791ae, 0xa6b18d45bef0c545, 0xbfb9327e913bf26d,
+    0xea18a36101d2828f, 0xe1f06e4c2fbc75e3, 0x9a226bd586cb8f07, 0x79e51068c415cbce,
+    0x106b81568ac64d39, 0x6d454e6e1169a071, 0x49be652169965631, 0xd6c4222e0e136620,
+    0x07fd12cd6c185db2, 0x810a8364ba91bfe9, 0x8c341ff9e40a94f5, 0x28ce211ee3a3defa,
+    0xce7c38bf31e68c2a, 0x857f51eefa4c1a7d, 0x491b2e129ca69d30, 0x26fe789ff0c24fec,
+    0xa9bf45b8e84f6696, 0xf00e3e9a4afeac81, 0x66294be6ad9eabcd, 0x62e77b1d1c70b22f,
+    0xb54f087be2dc12a2, 0x40ef97d8202ebf8e, 0x39dc4af9161f93bb, 0x5c320d60531aa8c4,
+    0x1404b888624226b7, 0x0f76788d2a36b4a8, 0x75b59be35d52b8b0, 0x4c4fc7ce3a203de1,
+    0x1b94640c593ee705, 0xfc35124f5f390575, 0x7c1453bf8c968cc7, 0xba98e2a268418c34,
+    0x052b68de46039892, 0xfa7056929530e64f, 0x98a462bbf030009b, 0x6f52ff64690a8a8b,
+    0x7fa359253fa4c048, 0x0b3e4bf75fc725bc, 0xf59b2f85474fa855, 0x6e7b46b3c918eaf5,
+    0xc39d277744a8147a, 0xc7efffadc3fd60fe, 0xf085d24efb2388bf, 0x04e2eb6fedbc178d,
+    0xeb91c64e886fdb6a, 0xdf41c8ab45d37326, 0xa6e38aa58b05276f, 0xd6f602a8d8febfdb,
+    0xc619a63bb628ec12, 0x414af2399bec946f, 0x0a8a61bbc13567c8, 0x398858ea955a65a4,
+    0x880a535405e95200, 0x5c46f36fb3ce9281, 0xe34ac8d8223a7d96, 0xa676e9aae4ac7f92,
+    0xa378b4585419be30, 0xaad740c9a9ef3108, 0xf5f5f19a7e5357e1, 0x60e91e4f8a2d98af,
+    0xd9bf20d32f207608, 0x597f2e23cadb957a, 0xf406428ef63fab2d, 0xf538b2cf437c9aec,
+    0x2855beaad4ff3a0a, 0x65fc52bf1f659cdb, 0x500b96b68a6d6446, 0x2315b537ff3a845b,
+    0x82314fd39e6891d4, 0xeff865bda83e807f, 0x8ac4f05387219681, 0x5fb91a3c76b68a16,
+    0x58c5e1d0869b2dec, 0x21ca4b1a3292d323, 0x407e99afd5a5bfa4, 0x76d1a22afe0c2184,
+    0x9baa77785a0c68be, 0xc4ee4c1c24cbb564, 0x891a88273bd56938, 0xe6800cb74d1dbbfc,
+    0xcb07339d491daf00, 0xd797d7a83e69cc8e, 0xf644ae0cbd4651f6, 0x9f29d47acc20572d,
+    0x9c190ffd7c407ea4, 0x9a9f1ed78182497c, 0x903ac9237cb1036c, 0x4ff3e7e021da64ef,
+    0x68ec7155b43e13e7, 0x7cf608bc05dfbd68, 0x3370c1f69694d6d9, 0x57a72cdcd7313c9b,
+    0x66da9aa83edda2c0, 0xa5c3c8188273c523, 0xc8b94f69ccf4fce6, 0xc546fd365180fd95,
+    0xb5338010773e7360, 0x28d22f1ca5442153, 0x983baef9d9b45ede, 0x1f793830033c63d7,
+    0x41bdb15d53ee237a, 0x000940a38375ccdf, 0xb4ac98dfe113be9b, 0x9115f7e5d0b35a85,
+    0xfb4ab0a13d2f4657, 0xd9f33edc94ea27d4, 0xe7e8f1bafd1dee0a, 0x38526756b9b3c997,
+    0x56fb6475f8142c87, 0x5aeb9980f38edba8, 0xef293225d71228b2, 0x14786c80cd990cbd,
+    0xf6cbfe70d1278607, 0xe18666e5819e555a, 0xb15d9544eb159453, 0x672e009945249471,
+    0x5e5bd4c354b5bfc7, 0x652b11a60eed9075, 0x867441f0ca138d11, 0x456a6a61edf1ce66,
+    0x7f521181e2549034, 0x3d76274a7c109c2c, 0xa24dbf378d13ad92, 0x02cbd6d03ca6014f,
+    0xcff903e223070ebc, 0x73aaf0837ee87441, 0x8c9e8c1497fc6692, 0xa13ab2e5465c2568,
+    0x13756e28e6af65ef, 0xa7618681406b2703, 0xdbf28e8d454f9d83, 0x1e0111b1387781b7,
+    0xf08dda13c406e988, 0x1a6c8e38f5fd26bb, 0x640908fb0a209082, 0x4c06048d38513316,
+    0xd3915aa199ceb5a1, 0x5bf71469e851e1a8, 0xe62bc539435038cd, 0x7513571ce13e9336,
+    0x26aff85f16f877c1, 0x61a90a185fcbf32f, 0x05f456e0c4feb905, 0x1c20e9f4a83a04f0,
+    0xbd47e8ab9a1337d0, 0x2c6a8ab11ec55ba9, 0xdcffc1d78f178113, 0x3f1856eb214ee7eb,
+    0xdaad941a83de7456, 0x52b3ed63b2ec7c35, 0x35dc2527fd8f2e67, 0xd42bb86eb2821a37,
+    0x99f9daa6428238d8, 0x91efc6f92518ebf8, 0x55d7f4bfcef20f69, 0xd6ddbdf526dbb2f8,
+    0x0408965f7e2238bc, 0xae1459feb03f3301, 0xcb58db567c7ef080, 0x20b49dff983afd92,
+    0x4cf03be88f2f262f, 0x2c8cd804cf3f9a3b, 0x47251d26d4ff9931, 0x936a2b93ae2cbee6,
+    0x133337bf7ed1280f, 0x607038c3fa41381f, 0xa9fcb3f0d1adcd6c, 0xadd7962480c8415e,
+    0x3e2cae0c37fb204d, 0x278d9ccd139c2c63, 0xe46bea844fcded9f, 0x42cdaca11ed42fb9,
+    0xc4211f3fc5af382d, 0xac6eaa74373f5293, 0xe7919132f059938a, 0xcab84a80a1112a2a,
+    0x4b26a0a87463b1e9, 0x4ad73d12b575b1f7, 0xced17cf2bc70f86c, 0xf300b28215c41aec,
+    0x9c49a6e229a157d0, 0xf9f3abb3684ed253, 0x49722b3e62721963, 0x938aa7e806b3a58d,
+    0x3457772912be1c53, 0x7a980811ba028502, 0xd89f6e1284b4fa7c, 0x0a56f45adb8d7d0c,
+    0x361911b2e165125e, 0x25bb47d27a055f77, 0xe94d678c4223143c, 0x62962e109e19eafc,
+    0xe2e1ed875bc670a7, 0x0a28afd8452ac4ce, 0x813faa4ff1e8f32c, 0x4af7ad71a8d298cc,
+    0xa5a1aa829cafd55c, 0x81997a1fac8ac391, 0x3e03adcf1f460af6, 0x5bf736f21d851a14,
+    0x5cbd38a4bb14db3e, 0x80e5c5a97848d414, 0x04c9f935873a8162, 0x3711106250b31c88,
+    0x72e053431909869d, 0x7650e9ab30f60f65, 0xabad549ec2d799da, 0x221c651a701e4213,
+    0x1f3e34b718a65c49, 0xad2398c0ec9ed51b, 0xdc2a6169126d1b22, 0x0a38a0243b47f48b,
+    0xc8247c02693b3b0a, 0x4581a27ca075964c, 0xd0c0c8bb201d2aa1, 0x5e4260e7a505e970,
+    0x66d3f9c79099c1a2, 0xe1dfa1afd2ab6469, 0xa26fb1a4ece0fb6b, 0x1fd0b2b43f7a8eaf,
+    0x267e0abd8e4c551d, 0x344eac491fd0960e, 0x8c87a54e9a2c6fca, 0x22144205ccfad491,
+    0x12a12e1be27a2499, 0x4099dc6a5a07b70c, 0x3ffadbddf1b07a4b, 0x7ad92acd0600ea24,
+    0xdb3044fa6cc21e72, 0xc7be3cf20e9d841c, 0x7c3195c8c33f3c43, 0xd0316a2351a19c49,
+    0x0699ed5fcd010eaa, 0x1d9db1e8a779d0fa, 0x26f1d07823ec65f7, 0xf09dd2c60286f82a,
+    0xa544124cb2b295d7, 0xd6be48896238e814, 0xff1e47ed0c268736, 0x42624fd095cc3fa2,
+    0x40abecd9ab3c2621, 0x98be1c83154a456b, 0xccbf5a998c8f3d94, 0x4ffbb43ad2826fa4,
+    0x19cf81f3756e6b52, 0xdac63142bff96757, 0xe283d02d00058aaa, 0x67ebd0ed4f59d0f2,
+    0x7e9ab756cae4a98f, 0x603a0647bd737027, 0xc7155838f4ca7c06, 0x320e51866a5515ff,
+    0xde1a6d6d37a87d60, 0x8565e8b1db5cd46f, 0xa9e1f18552d53659, 0xa8048ee2ab06f612,
+    0xd8cc76edb946c4a5, 0xd918c1369d36c370, 0x1d9f319bf80341ba, 0x0e72183e315f303d,
+    0xb53f6e6f346a479b, 0x19b27c5072b182db, 0x4ed2143f3bdded3a, 0xf1d7e18c58151187,
+    0x9eef4f5bd46887b3, 0xc1727fb701889932, 0x03b67765f697d09e, 0x1f8fe9d3cb98e6d1,
+    0x99abbc5fe26f2b9f, 0xfbc25659ee184ed0, 0x0391c62a1749a6ed, 0x7ce40f06ea317ed0,
+    0x5dbd76f0dfb516f8, 0x707009e95bd4f831, 0x259c47df886a0e8b, 0xba5e4d11370d3af4,
+    0xc38628e10aca216c, 0x4a324c69ba109aac, 0x63029c94a3a25406, 0x8dbee07637b20261,
+    0x1448862a5a0f35d3, 0x45c81094cd61b493, 0xdb37c458f35448a3, 0x3bc084f9bdcac5a6,
+    0x1e2486bdcb2ef312, 0xf4ffd94b7162ff38, 0x4fb7bbf6ac3e2665, 0x95094405e54b5ef5,
+    0x07e92e4419acc4d0, 0xe1b1e9a6d8ca6418, 0x8d54d02da0c11fa8, 0x794429c62c127fa2,
+    0x44a3b3baa79ea531, 0x7b786d0f8f3db2fb, 0xcab0112205d123d4, 0x05a1d6ae25406038,
+    0x1bc32ee8aad3b473, 0x3af1947e9430ba63, 0xb3c6502c7832e079, 0xa564938acefe496c,
+    0x4f4318b19a552477, 0xbbbff45681f1b723, 0x15a108736d9bb6a8, 0xa98478c4535e5f92,
+    0x6aebf9e644b8d225, 0x13ac35dd487e02d2, 0x5f69b9a49374413c, 0x600e04310f2ffb94,
+    0x9c9f252988d67147, 0xfa7a74dd41295bf2, 0x774cc552f0add9a1, 0xbaeb8b99049d4aff,
+    0x646fa10ab4480f5e, 0x53e13e70dc062bbd, 0x858beb410aee5d9c, 0x5b055bdede88fa24,
+    0xd7669672df60b4e4, 0xfeaec78bce89c063, 0x9f77332471774e2c, 0x06789c2531afa822,
+    0x338ed64a7d196eb5, 0x3e50e82fc7c276d8, 0x24f683d0060363aa, 0x5d944492061afef7,
+    0x9080ed18f99d7ad3, 0x863f6dc819dee466, 0xe2569ea0df04175b, 0x0e15c3709537f812,
+    0xd1a945ae32b419ba, 0xfd00d69bd4cf6d31, 0x1309dba38e489137, 0x8e51cea7556a4d60,
+    0xb2a5c13b7ec1d21f, 0x6218334e51734f45, 0xf51aefea52c2ccb8, 0x2ea311d81dbd243a,
+    0x11d71f144fb51833, 0x3f2bb4ab9684fd76, 0x8bd56ff2c3f98d99, 0xd9cde4e6f820d51c,
+    0xb6f309807145319e, 0xbfc279f960cd54cd, 0xbcde15a0aefa4d88, 0x4f65f88fd9d55364,
+    0x2573c1bd7bfed100, 0x2b9389342e6403c0, 0xb5ab8c8dcf12a0b0, 0x2d788e32950dd7e4,
+    0x7e79163fc337667a, 0x4da874925ec932e2, 0xd85641e69347f3fe, 0x2cca46df180fdf99,
+    0xf4ee0a5323ca3dd4, 0xd28dd03ae167d6be, 0xfb2d58c18e599fa9, 0xc982cad2270f7ff0,
+    0xf490cdc9f2114b36, 0x9bf65ac05f588380, 0x88ec098fd34a894b, 0x9e232a317a5e2083,
+    0x67b45523ada95845, 0x97f88dfb93efa7bc, 0xbde03928f3c47c4d, 0xbf9539ac4be2f6e3,
+    0x515915bbce849085, 0x03b4dfb8fdbd7092, 0x820f779038aa08cd, 0x1c91bea12286840e,
+    0xf9789641c62c6da6, 0x99cbcbd66e089fed, 0xacc5baf56ad36e6f, 0x724d8e08174b149c,
+    0x7d400a06b5f525bf, 0xee8ce17d909d4f15, 0xeacae5107a5d7a84, 0x036d427717accca7,
+    0xe00bb632e609f26d, 0x000a9ffca5710d9d, 0x6076788b1ebdeed1, 0x1f78f83577e784e4,
+    0xbacde238585fc0b1, 0x073f93d2200285b3, 0xba92585dc3e4679c, 0xb579b661c5b0de66,
+    0x1f2d2861bc0aeb44, 0x94326f4bdfc6511b, 0xdb5d352902b46994, 0xc0200faf0d5d6c4e,
+    0x925d50fb3af00258, 0x9846a3846bc65620, 0x8a07498b9715b0e9, 0xf1a478a926442890,
+    0x21c1ba711a2d2c43, 0x6af045d9f38947b1, 0x26bf6690014e8219, 0xefabba3f611b9902,
+    0xc23a5089220a8c37, 0x138cc7045fff9666, 0x124e99943b8700ff, 0x9b9c773c7bb134f6,
+    0xc2715ec019461d46, 0xf5485523dbb75361, 0xe85ff95457482772, 0x234836329b5536bc,
+    0x2b7626af4b9a5bf2, 0x56567cb0c458d730, 0x5c2e81d68ebacdc3, 0x7e3eeff9c9ff62f6,
+    0x6ce093afb9024bdd, 0x20f6d6c54277ce95, 0x751773e946e3be0d, 0xfa277aa3940b4e24,
+    0xcb5249bef0bb3f1a, 0xb6ce38b45dd633a9, 0x51bcf5872af90d3c, 0x32953c7682c7d363,
+    0x62180f05c4f8e2bd, 0xe99ac07318a8fc9d, 0x7a19e9133de9ec4c, 0xb49c2c4b71adbb0b,
+    0x9d775cb7784c4bd2, 0x89f624a95ac814ba, 0xa725652fdf5bb01d, 0x9e95dbe3dfe24aa6,
+    0x1a8b8f327b1ccdf2, 0x703dbeb9ec9ae12d, 0x0e11fe55c96169be, 0xbfddc56aba8db7e8,
+    0xf9d0f3fe32aba5e3, 0xf9961574c9c5fb63, 0xa1791fb0899f22b1, 0x7b557b2fc9e13d17,
+    0x5a8c5e214bf0c892, 0x501d38cb6cb634a6, 0x3a54dcac9fd5709d, 0x4e705071d7e3c554,
+    0xf3b3bd460a2d2486, 0x0c845c5424473b14, 0xc3d567ecc817f668, 0xad35819a0553711e,
+    0x22c23db67d8081c6, 0xa0c65ad9fb8b4a76, 0x74c4c3ef77a3ab62, 0xa27cd50035adc7ee,
+    0x9139bc33983d3e96, 0x0ccda600f99f4550, 0x59add2fc1a9caadd, 0xc14779bdecf0b954,
+    0x6de2c48eba027652, 0xdd3cc6972cc869a2, 0xf0eb33e1a11b2801, 0xef0f2a9e092e9356,
+    0xeeb2b2b820c6354b, 0xc7d70d5d73fcd0e7, 0x096d0be0af78e31a, 0x1f30943bea8c9f84,
+    0x004bdbcf67cfcc0e, 0xa8a7020c60878fb6, 0x273d1559bd9a4e3f, 0x98da772c996a77e6,
+    0xa2bde90f8fa8d765, 0xc27bf381389c6370, 0xfdec46dc717d1eed, 0x70f982af24198f82,
+    0xb4e7c38b3ad0f9dc, 0x57e81e248bc45861, 0xd471b0b7b0beb1d9, 0xeed889c89e9c3546,
+    0xd0841da0d0b8167e, 0xcc57e2a1627f8018, 0x2b2fb5b7b4a4640a, 0x2dadaca76d1bfc46,
+    0x9efd21c3398f7070, 0x81b5dbde6aed97f6, 0xa15670f42a44f49b, 0x44295f9b9a7b7a9d,
+    0x62c0201b4ddb0b9a, 0xd98b55e467ca1995, 0x6bbaf0b4fd026f57, 0x3391806920623491,
+    0xfb17e5e5862fa000, 0xb9b0ed7fd1f52718, 0x8decfc6f0d7ef205, 0x2659323da2e6e075,
+    0x594ac03cc1424e57, 0xa59505830b190ee9, 0xf6fb172a3a798e47, 0x54d06f43d48bbfc5,
+    0xf44e4c685aa2b6f6, 0x9109caef4ffcc3c7, 0xf69871b9bb8b84e6, 0xdad127f43c45d582,
+    0x0d1c9ac8ee3967fb, 0x3ad8da9651a3cb34, 0xee6eeaecb2d23131, 0x5b8a4a22d7f2b53f,
+    0x34e8d4ab8631e4b2, 0x9c17b39e25f27589, 0xf3ff8c47718fb90c, 0x1bc9f0839bfa3708,
+    0x7c690b78b7c533bb, 0xb05571a7186805e9, 0x39ba5b2099f9c086, 0x8c921d651b186703,
+    0x6a9c8b220fb6b5fa, 0x5e43b119d6e63328, 0x689d49ab451b64ad, 0x9104479af3961eae,
+    0x546420bfb5b7de54, 0x40bea2c86bfed365, 0xaf03b868292f3fd3, 0x5554afd166aba51a,
+    0x07150fef55676799, 0x7f852ebb390a21fc, 0x4e809b5a31310632, 0xcf8753f7f6a7f759,
+    0xca83ea89beaec268, 0x68f1a873c6a85e8f, 0xbab11d75aed934ec, 0xb15db7c8bd5a8d3c,
+    0x104bbd2cfe62d6e2, 0xfb33a3bf3692a4d8, 0xfca2d41fde92cc42, 0x37d91be89ba0343e,
+    0x465867303cf113e5, 0x2faf56404956a0a7, 0x991ea4eb280daa3c, 0x26997c4cbb069e47,
+    0x6c63612a2695a3e0, 0x89cc8782e78de690, 0x80b6d61b8055aa59, 0xc8317bb398748897,
+    0x097888378fd48d39, 0x29a16d0b440fbe47, 0xe340ab1fad606fa9, 0x7fa3107bd7f2b7e1,
+    0x351277cf0d2e1f09, 0xe364dd83db72c929, 0x43c9f80618649edb, 0xb2268719c8f36659,
+    0x2931be05e5c594bf, 0xd41bf0b94dd0d522, 0x36ef622a32010cc7, 0x63196e82086282d5,
+    0x384ef6f7abc387f4, 0xc97a7598e5d59979, 0x7328644a7cb2834c, 0x8ffeb2ed812c7e7d,
+    0x1d2737e5bf26a12f, 0x1eea790791d35dd7, 0x3ee7332c3ba26427, 0x5e273ce340c38bf8,
+    0xde0c86033e43eb4a, 0xb3e5a86f009f5e2d, 0xb18f77c6af244a6d, 0x24d7712b1885b61d,
+    0xf058a4d8e002f101, 0x8419a11d3be4c5df, 0xd8a456341efcf286, 0xe43ede2804c68da8,
+    0x714eba7861a0eca5, 0x98a982956cb988fe, 0xbd91b61dfc6f5acb, 0xa656f372769f8627,
+    0xabc2ad9b63599b1a, 0x03576c4892055be1, 0xb1cd97f31cc2337f, 0xab2f9bd85efb2fcd,
+    0x30d7e29b3c98df10, 0x1f0b94f7db0e4ec0, 0xed6c0501a1a0aaa6, 0xe833f4cc6d62e1dd,
+    0x7f1cf4653797dd26, 0x833f02b5b997e9cc, 0x8e66d35d18f10cfe, 0xeb6b029ab978fcc1,
+    0x8ea38bbd56a6ef0a, 0xe3acf78e9fb7a682, 0x8fe62734bf140c83, 0xb4f711bd238da9cd,
+    0x61175ed40fe01d69, 0x99ca304b168e70ed, 0xca562402cb2ee68b, 0x495c6d04dc577726,
+    0x17340bb92146ceef, 0x228265c7c8d515d2, 0x554f3f1e3851d743, 0x9792f8de0882eb3c,
+    0x797cecc5baa5c074, 0x10d7c7ba9e6dc27a, 0xc6814e2efbf1b354, 0xae9a299e4e9e882d,
+    0x03bf419b55d96e1b, 0xf5653bc48d357fe2, 0x051642bb990a6dfc, 0xcfe5d68c6d5af74f,
+    0x4956e72ff569300a, 0x90f50b5ea6a209d6, 0xf926993417b7aaef, 0x03570e5ca6ed3164,
+    0x96926f072a6aa755, 0xd06ae7607317dbca, 0x9e4bca62dc910893, 0x91a4fcad0c214ae0,
+    0x325e11454c45f8b8, 0x5162d9d2232550c5, 0x60c51e120d9301d9, 0xa1e29c953f10777c,
+    0xd9167d4513192ec1, 0x3e9eef6ed71eb84f, 0x500d8cdc656fb645, 0x7a0560d01c0b2cc2,
+    0xf20e5be0aed329b9, 0x38f21786be53973d, 0xe3e1dd5fd7f0d26f, 0x5c3a7e2e26f7e398,
+    0xc83541449f7f24ec, 0x721272783e0c9be8, 0x26db16b7833d7e43, 0x02fb5ec29b4c1b7c,
+    0x2fa1a081db4f3eb9, 0x13259f935059f021, 0x9522c20c3219b0a1, 0xac87de6e5393d98c,
+    0xad4d1246b39996b9, 0x05e929d971a58a34, 0xfac34bdaf35406a3, 0x2394f6103e347644,
+    0x07695c23bad73f48, 0x58a8ece04f63259b, 0xf9259073153a0755, 0x5e4b295f828120ec,
+    0xf397c7eedac5eda2, 0x969fb07f9fb8014c, 0x76aa08abb7de1909, 0x393ef6a7d0616d23,
+    0x82914a8ef6cd140a, 0x482405e769f845f8, 0x8b93ea7d3351fd89, 0xfc86a8ff06438534,
+    0x0c1e81fb4e1e1d5a, 0x3ebd634c03fad592, 0x291cb06f541e4f20, 0xbb7795ddabb6e075,
+    0xb486d568152da8cc, 0xddbaa39ed641f07f, 0x39f8d1b83ecb84f5, 0x4bd0f62eb4cfdeef,
+    0x6fa52cf208b575c7, 0x0e998fb5b208cfcf, 0x92e955a31fa5ce5b, 0x16f922808e98a7dc,
+    0x93640e19d3538a36, 0x0e82cc57d82f8dfb, 0x329ce420ad773acd, 0x76b881d8717a4924,
+    0x36a3fe17f488d4fc, 0xe752e97c91b45942, 0x6439bf7610f48e85, 0x56b89858eb6cc366,
+    0x33b22e0c60411e8a, 0x7ed0ba482325fedd, 0x032d06a478a91ea7, 0x01c4c4a37c58826f,
+    0x353ebec4f29fbf36, 0x8e55c999f6020ada, 0xf631dedd801f7bc4, 0x1fef8970b9ed4e2c,
+    0xe45c5f5ccb9eeda5, 0x60bbe87b7dae0193, 0x337434522eea1f18, 0x5c6a38db02ddb0e5,
+    0xb86aef9aeda8f8b8, 0xd4863ef66e4d85e0, 0x48adc11930d6eb0c, 0x18332f5c4f3f58aa,
+    0x1f77e8e138a40c75, 0xd2ac1754a5bc3f05, 0x146ba9f43a4b956d, 0x23f788992623f3be,
+    0x2fdd79b1d3a57ab0, 0xf33bce5166af3e1b, 0x53d61371f04add74, 0xe9204a8ff93d8b26,
+    0x8280fef65337f253, 0x699df6da9df41385, 0x86fe81bfc11e5306, 0x6c58fffeaf47e1af,
+    0xe347ba502572d996, 0x90ce2f5b9405bd8c, 0xce5282622dc20bb3, 0x74b0330d15d74b78,
+    0x7a0bc0ca7ab249d9, 0x914c600c35aad61e, 0x1be1824fad73ee0a, 0xd872aa4401ff963a,
+    0x7d0b10b4bf8d4a6d, 0x997fddecac68f4dc, 0x2e9610aadf17b026, 0xbda518ef4567fcce,
+    0x2a595706c0052d66, 0x59e6989cc2b63cd3, 0xe6c1a028bc37b0fc, 0xff66c654bd6063ef,
+    0xd50bdd641d5c57b5, 0x07dfd11b382f0289, 0x7af48a1b55d664ab, 0xa670d802163ad7be,
+    0x96739c94bfa8a9b4, 0xb52780385663e5fd, 0x38f1ff6b99f2a45f, 0x88caeb3c853bc71f,
+    0x2d3af1a56db4fdbd, 0xc0d8efd5442c85f6, 0x7558bc4d19e595fe, 0x51c334711ba1295a,
+    0x9195663fe6630aef, 0xc7d291010725e979, 0x2fa29b356ed3ffd7, 0x774ea24a53abadef,
+    0x3b9a23916fc8932c, 0xd294dda8f02aaf7a, 0x881c4cb543573156, 0x12c516c5b4ae06a1,
+    0x429cf4771acb608c, 0x2eb27bbea317160b, 0xa558584ec6c79c85, 0xd18e1f689f5ea7a7,
+    0xac1a14becc92d5a1, 0xacb4e235988fcb0f, 0xaea256f577b17aeb, 0x15d68518a8e62e1a,
+    0x5c87d4a6725d9f04, 0xcacfdde365e51155, 0x46b51e66775d0f04, 0x88d2846f7562e220,
+    0xfacbb0fc9da84fc4, 0x54aadd27b0c8bf4a, 0x81933062c9ef9182, 0xfb5e5031840e8b39,
+    0x989ae9c65cc4e7ee, 0x333836c00004c6a0, 0xb8a439dd22df639b, 0x2ea9e4acf0dc3744,
+    0x3754a92db4cb9602, 0xfd625e8e9103e813, 0x4da11bdc527e5519, 0x7c0d5fc7fd15022a,
+    0xf29150cb8b4dd245, 0xedce7024f5fabaf0, 0x1192f0ba98285041, 0x0f09da6202f24d39,
+    0x444c027fa5b9f2e9, 0xfc058d3e0fb3df73, 0xe2c8bd8a3e21a0b7, 0x6a2d775be65e9d39,
+    0x24cf499f16bd2c8c, 0x7b5973bca16641da, 0xee4133a2633f5bb3, 0x91e27f8bb4cc7831,
+    0xfeca4c02caf24e06, 0xf2c9dbb7b64f552f, 0x1e85a6fa68ba4dcc, 0x487dc0bfdebe4020,
+    0x638b05192efced4e, 0x0ea080a8d526e9ee, 0x3b557d470cfa6f8c, 0x53cc0a36bfee2355,
+    0xf84f6166a4eca097, 0xac65d1344d7f2a17, 0xde46b84fb40f27a4, 0x0652c044b64b4fa6,
+    0xda5c283b202c9e65, 0x12d891ad5bb9c1f7, 0xb8ed4ca95707bbd4, 0x7a39262458c74f4e,
+    0xb6fc8668ce088cc3, 0x02e2170f2dd4be65, 0xde608a3b20eb52a4, 0xdefe5be52b7b707b,
+    0xad5a9dcda84143ca, 0x23202206b994a44f, 0x8026e8537c714e59, 0xe751ef31118e1a40,
+    0x86d6aa4bad6000a2, 0x1ddebc59b42953dc, 0xbe3326aeb81dd957, 0x42503de75c28a15b,
+    0x502b4bbfdb1f0cc8, 0xadba15b50ba8d41f, 0x88b907726d915873, 0xbae001daea690fc4,
+    0xd40ef982d3c98b7a, 0xc7923d76699c03ea, 0x4d0c8c04c107a243, 0xf55b4d0f3ff7abd1,
+    0xef7165eb31ddc752, 0xfc3b2255b5add1d6, 0xd7c3678ba814ebc8, 0xba64dbe113e6f4d8,
+    0x070535264ddf71ab, 0x65ae6dfa360a3edf, 0x036cb06ee1c0fae8, 0xa89543a91532a899,
+    0x7fb02f724f829c65, 0xcd5f4220dbbf274e, 0x03a58db1d4ff312a, 0x706fe9035967aca0,
+    0xf2090927733bcd2c, 0xebd5acf7a8b33dd9, 0xf789493d8dd35182, 0x5308d39aa105bec6,
+    0xd481048379419fb7, 0x44d22e2d68bb0bdc, 0x00ce0b77d11e152b, 0x6f9a270ffd02c8b6,
+    0xc23af4d409af96c6, 0x5e4df665d14ab502, 0x993fa96d08dc38b4, 0x39cbaf8afea86235,
+    0xf7d42b4dbaf08027, 0x3bd81341a31ceb63, 0x2bdca32b7a98b1ed, 0xa61b4c2bc9fc2d91,
+    0x3f70ebd48898803d, 0xeb006796812c0338, 0x3b1c721d5ecea5d6, 0xa453a9240a623a6c,
+    0x7a69812f7e9f3dba, 0xb67bdd9f2bcf07b7, 0x2b3eab137f5a6270, 0x55ea55e6f9bdf46b,
+    0x311751c78dfb908d, 0x796882f4d7052cc2, 0x8da87a51cef075b9, 0xab8cdd1b0496e756,
+    0x5cacdbe34109a840, 0x624a7a0d6221f3e2, 0x25cab2cc9747f75c, 0xdaa9053fe8ff4fe4,
+    0xcf613bcfd2771040, 0xd9b7d2e013ff5218, 0xef7c04d593d1cc81, 0xf69e60f4375d916f,
+    0x92e9ab995bc72358, 0x9e54073945f9fc36, 0x70ab51f68131626d, 0xc69ab02c458139a9,
+    0x224440e8308fa215, 0xb6f485258aeee339, 0x254552e922ab1393, 0x3f67694ffdf38a0a,
+    0x35b5dc00fbdf5403, 0x0a2749d45d8cd4f3, 0xc96c81058e81ac6f, 0x50aa77c1fd8a70d9,
+    0x43913975af34ed44, 0x15b2c21145feee6a, 0x209ef087f7ddb5a6, 0x6e3c48d2d9655042,
+    0x689fa38d340575ca, 0xe9259617e405b656, 0x0cb388d041327682, 0x5704b94694b38d1d,
+    0xa6902b7e3f7dc849, 0x02c0f7b84e8896ed, 0x28bb933778b2a9d8, 0x89bf8eb6bd66f334,
+    0xe0cbe22e5fd75e84, 0x01508d98fabbeabe, 0xda3d97a817fdcd87, 0x1ec15bb9f54a727d,
+    0xccd20072818c9de0, 0x32911f7395b21fd5, 0xd1c91bfb5f77904c, 0x15e464c188910863,
+    0x27391e9eae8b0835, 0x34f31a0d85dc84d4, 0x29c0eb3781fe45c4, 0x79e12e8bc77c83bb,
+    0xe78d8f05ed187233, 0x2ca92ab66f9f1e3a, 0xadbde9dc3ecc7584, 0x55f386ea2ca2e264,
+    0xbae4ff6bed3eed19, 0x3a6899636d98068c, 0xf2647c97e592d893, 0x3a9f477fc4db8597,
+    0xe1d0579354c983fd, 0x068aeb2b7c7fde47, 0xd6166649a0a6cbd6, 0x44c3ec7a110e92d0,
+    0x2501d8118030aa16, 0xc71fd6a74604f466, 0x4461f47374607ceb, 0xe05346d448cbaed1,
+    0x928781983c79484d, 0x025818dc33b287ef, 0x9455f4d17e4bfad1, 0xf631db459617eaab,
+    0x1b6e1ab3c17d6894, 0x399cd712b8c420d0, 0xba9e5d12093c8182, 0x10d67330150532d3,
+    0x02fbd14959a5f9ba, 0x7c5cb1b854ba447d, 0x8be5d2bfe74f086d, 0xdd1c3c7d07700015,
+    0x319dc2d99e76fd2c, 0x91f19a95480b8bb2, 0x45510672fb112fd4, 0x23d9ca10f2ac9fe1,
+    0xc400c8db3fb1b5b7, 0x17a3b24a50f497a8, 0x08498087c3fae724, 0x94dd6c3f2542d3f6,
+    0x7d55181028d2330a, 0x896d700a570f75a1, 0x9c8beba0dbdbefb3, 0x2bcf107ab5ef0e7b,
+    0xbc14bfe53446c26a, 0x70cf109eafa2a063, 0x28d546477ab5483d, 0x38bbd228ffff9c58,
+    0x40ec92290f85fb7e, 0x355a75b8c42e5fdf,
+]
@@ -0,0 +1,11 @@
+[
+    0x15205193d5a37345, 0xaf2a4e61fb0eac1d, 0x3298c7c4cbbdff0f, 0x0a7503ca6bb1a631,
+    0x836c837157c7625c, 0x474c8b0fa4ba294e, 0x8c919c35dc04fad2, 0xf7a72656ee95e32d,
+    0x0e4a34c7a77f6765, 0xa48aef77c960095e, 0xa770e39678251468, 0x2842ef7391cba95d,
+    0x0b0b914d4ebe095c, 0xbd6655144cee4a06, 0x901922b4bd780385, 0x01cf38dbc53a69ae,
+    0x1164b973bd096b7d, 0xcb484db18713f859, 0x49ee0063491b1760, 0xcb867aa0cefd17a5,
+    0xc3903dc060ce22d4, 0xb6c0811a4b660e09, 0x8361bf47874023f2, 0x009473e95f6c9c7b,
+    0x4119b2c4fd266cfa, 0xea1f1c261e752c1b, 0xa98f97b6aa9bd53f, 0xf6b98d91bcd0057f,
+    0xe1c1f11281999662, 0x81f6af575f18f0e3, 0x729c348f4a44ac92, 0xda0fb4287e4de5f5,
+    0x47219f0e276a9df0, 0x29aeae7df835f1a3, 0x79e2a1277235d35e, 0xeafeed7774cd9305,
+]
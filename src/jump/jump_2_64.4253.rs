@@ -0,0 +1,19 @@
+[
+    0x3d5bcb68b9893a5e, 0xcfdaf7e0739ca920, 0x62c8a3826d7fcf30, 0x4bd29a448288b9ea,
+    0x8c2af96d4e257d34, 0xe7b6f3c35fd780b1, 0x7b404e5095ae878c, 0x39e7959fb3023e2a,
+    0x419d987860e635a2, 0xcb629e9f15a949c7, 0x9a85f9eb69ddec40, 0x4b9ac5bd2e6e42f1,
+    0x870d6539ebee2103, 0x73a894ce4579a1a8, 0xf32a379cb5f00fb9, 0xfa3986d718e31b84,
+    0xf4380917049db628, 0x70d78c5da8a0aa2e, 0xddb4ac136dc91974, 0x79313d7b0241e022,
+    0x7726c484708c2513, 0x4a64fc1904de01d5, 0xebd971dab5d2399c, 0x26781d9e2c8b9006,
+    0x52440d5addc7dda6, 0x2c6521f93c07adff, 0x50fcd5e5b007a8c4, 0xbc62054ff8bdad39,
+    0x7597db9b859dc2df, 0x3b07ef2abc7d7ede, 0x870406dce9a8f307, 0xd70d5151f47aeafd,
+    0xdb26be4cf64fe2f5, 0x235027ce60360527, 0xd06652ae62c29e67, 0xcaf1c1bb08e6ff6e,
+    0x2b0faa1328a2b60a, 0xa2d329d7bc8b1c21, 0x9665fc57b7fe54f3, 0x7e3593a5f136566c,
+    0xad929485d43edc7f, 0x72588ec4efb14fda, 0x5397b37378f7cba3, 0xa66c8f9c4e66cb85,
+    0x65fd75c35e502ef6, 0xa41606d60dd77f86, 0x78d3b0ad964929bc, 0x3ef3d2aff0cd8e18,
+    0x81411b4cfcb51d8a, 0x589beff186240bf1, 0x77e1bfc0fcb91adf, 0xa42bfd7991ce149b,
+    0x9d7516b0b1ebac81, 0x4488ccbdf4730821, 0x97fe1e54998f0db6, 0x4dd470a3708af589,
+    0xdc5858e29467f87b, 0x03173023bd89a232, 0x2a48f4c56d81fcd1, 0x6b09b387e338be03,
+    0xbf99eee80c31c2e2, 0x172f5ae023f3799f, 0x9884a1cdf9e0bf6b, 0x7da72335e81ef113,
+    0xcffbab53e870da5e, 0x213581beb9a23e30, 0x9a4fb8ad5af462cc, 0xfadca96fb6eee272,
+]
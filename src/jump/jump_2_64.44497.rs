@@ -0,0 +1,176 @@
+[
+    0x7f24c934950be7be, 0xb4bf1505ad8b72da, 0x49a7219d36a52de5, 0x19930aab6d959128,
+    0x34319b092236c276, 0xc044fa59b5e1a075, 0xa38bd449e765dac9, 0x37debef08dc7e031,
+    0x0e005fff6b6b8e35, 0x52432a4c2f5779b3, 0x02e34f0deff6ddd0, 0x9c8d884853344fce,
+    0x54fc6c6eb78399df, 0x3c70149c59abaf5f, 0xd432a8e47dd6d57c, 0xbcdbb919cabc85af,
+    0x23b1b2addd86859c, 0xbce73349a3030a5d, 0x1b932f829f0b80cc, 0x085e0ec2f730f747,
+    0x0624bf61ebc069ea, 0x9fa869f612982c93, 0x74fa0056d451bba5, 0x2be41d569cda1c80,
+    0xb09d700da6fadef7, 0x4f3a7b5bebc3b999, 0x9cc608180af222fd, 0xf5cc65ebe4a82e20,
+    0x5ca9f00cc9195d16, 0xf4c2d04deb3dbb2e, 0xeb9b992bdcab2300, 0x07f3f7e981944c91,
+    0xf728dc18ffc09593, 0xcdb3d087710bb55b, 0x655577367646a5d8, 0xdd1ff160580929fe,
+    0xe3835f5cdd6c8b91, 0xc9fa1cc0d9d9e56d, 0x8f4b1e7d7d4be141, 0x7b522ffd83eca7fd,
+    0x3fdb33df1adf59b4, 0xf1d50125196c5ffe, 0x3293378e66e3aa97, 0x1f70f6f4de9d28a9,
+    0xeab4bfc97c2e5cef, 0x212bda69da8b4bb1, 0x3bf1f5eeb6f1bea8, 0xcc9c5b9447321b1b,
+    0xe6e6cfd472cca971, 0x1f763d867af6cb4e, 0x95083784f4c41a36, 0x9a88f8ea4155911f,
+    0x871d21fd54fcef4c, 0xd4fab6745078734a, 0x50c2b706c17c4a5b, 0xcb81e8afbeeb4a1c,
+    0x89694c3de75b9a3d, 0x6430e525f2d0f78b, 0xde70621f5137fe15, 0x6f147a56e0eb0541,
+    0x6efb3dc63fe3a777, 0x28bca032a7500295, 0x7327de87b894206e, 0xe26d796af30ed48c,
+    0x2c256d2593debe24, 0xda0d41a0b03ab550, 0x142b797315c222cc, 0xd06420f79b7d1962,
+    0x95dcb66a95af9862, 0xbe0b4a8778ef65a3, 0xad52d132d916b2c5, 0xda38980979f876d6,
+    0xec3d5d5fc9c4e9fe, 0xef2daafacc155128, 0x4f6d162a6c44e611, 0xbbfd1b91074432d9,
+    0x2d58eb08b94b77c6, 0xeef09de4b7acea8b, 0x13aab5d7768d43fb, 0xed262e53faa49a9b,
+    0x6edd8f88575eba25, 0x694717004536785b, 0x155866a54c4f59de, 0x715bcc11589ccf8f,
+    0x23fb959fc12098e7, 0x0d18cfe33b643415, 0x255b9e45d2604217, 0xa6f35dee0b819533,
+    0x1c45fea624205bf1, 0x18eceffe830764cb, 0xddf0f9d8b9c07807, 0x5e31262e959590e7,
+    0xd562ce25d2278cf1, 0xd8e4b40776d889d2, 0xf59a55ded60dbc99, 0x5294df3b017e7ccb,
+    0x368eb5f8bc16b475, 0xf6a1904458f3699d, 0xefacb67a16f6f263, 0x487ef00b33cde4f6,
+    0xdd693fa42c8d80a1, 0x9be63ecaa91a49f3, 0x8f54d62b5502882f, 0x60f1b86f1cff3f79,
+    0x4a016d27c468b1d8, 0xd1bd1fb249a20466, 0x23386f68c7604b82, 0xc6f8bd59476f2604,
+    0xd677e93f28659409, 0xbfb4021764d0d77d, 0x5ee3c0c8ea63c47d, 0x16fcc7d0ac9e4049,
+    0xd07e6ab6a6597938, 0xc91912f5accf5c6b, 0x88490c3309963d3e, 0x1541e19fe0705d4d,
+    0x8848c6af13367483, 0xe4c604decd4739e8, 0x5b1cd34a8dd60815, 0x821faec80f1203cb,
+    0xf1836f36dc8c571f, 0xa1f0ee81abc38b44, 0x5ccf6b0b391ddcbf, 0xa81090f708fb5a6b,
+    0xc1aaab5080bfe55f, 0x4dd7e3797702c058, 0x5ee1b50aa8d63426, 0xc91520a5b22feb39,
+    0xb3fd500de9e4d472, 0xfa0ddc70691b061f, 0x8b2d04958d8bdf99, 0x56de4e5029a33130,
+    0xb7e8370025520c91, 0xddc68c8d60da0376, 0x5ce0c8ea36affa05, 0x1184ed22dc31b0f7,
+    0xd8bf67250c203fb1, 0x4b71a258030c5ee9, 0x944ecad00e5c6f45, 0xa03b34903f46f863,
+    0xc58d31adf7ea3e52, 0xbf93c1bf824c53e8, 0x36c7b32a2583fe0c, 0x70e6ada57b1462b9,
+    0x91ba9e4c9c076dff, 0x81cef7a875df1a50, 0xec535f9b7adecd9c, 0xd044d73f373dbab2,
+    0x1b3a461650ebc4f2, 0xf0a5fc558fd936dc, 0x3c29b348b3e7836d, 0x385dcd02522dc088,
+    0xd3b98a95916ae088, 0xd3dbf2e5f8257376, 0x20ec5dabbfe8b73a, 0xdee184c6972e1c54,
+    0x2884fa75dac8ec7e, 0xd3d87383575987a6, 0xbe59de4cf1293ce0, 0x45f21b8c1f7fc8df,
+    0x3573513356e890b5, 0x3ac1f40ea37069be, 0x904dc35a07ca247c, 0x0bdf682eff6ec421,
+    0xef467a02c207f6bb, 0x43d69f6aa6bdc6eb, 0xebeac79aa3326174, 0x2377fbc253e2eac2,
+    0xb2ca6f1e433a53de, 0xeb398826dc002c6f, 0x42883786fad7bd45, 0xcca24b794bedb12c,
+    0x64e811117d9469ef, 0xf0733234a4d26b99, 0x447904a7654fe967, 0x81f539f95dececa1,
+    0xc24806856846ad43, 0xa821c761db560ab1, 0x5727298aca53e9ee, 0xaed4d01e48ac2ec6,
+    0x0df045d3b4b5a39b, 0xf8e4c23c301c327f, 0x1be2b7dd2c9d2b03, 0x54559829189cebe0,
+    0x40bf243c30b89e2f, 0xd207a2af0b943ed7, 0x6c5a16156d1a15c8, 0x491f153edeb4b108,
+    0x723e1cc3b252a15e, 0xb43c75da15fc56eb, 0x9e7d4b0abeacf2cb, 0x215c244e097d97b8,
+    0x0dc7149a64068394, 0xbbc90b0fc347352f, 0x031def83607b064b, 0x75626b387e484bd0,
+    0x30da3d768f62d938, 0xed7093942cdeac7d, 0x0e9fa16951386fd6, 0x423bd198588201be,
+    0x74eb11be96dfd16e, 0xb405bd4adcfe09a7, 0x5e8ff04abfee3dd4, 0xd73f2cb75d7ae893,
+    0xc677beeadae0ea73, 0x42a41e75f5b61215, 0xe5455e5532258724, 0x95ff1c0d87d4b268,
+    0x21feb5a0d15400f2, 0xf03f5c6e0cb071bc, 0xc7d54ef3225577a6, 0xf0fe4196fc56dbc6,
+    0x63a39a570bf513c8, 0x29f1d7beda2f0572, 0x172e55c4c0dced73, 0x424d99d478b67836,
+    0xd55644180c02da85, 0x6ec9a7c54e78f020, 0x263b1c6e22b8f00a, 0x8c7dd56c69f58aa6,
+    0xb44f268c6d3de7bb, 0xf9a04d05e00640b9, 0x2fe670d821e18a74, 0x4fd56eabdbf8fc31,
+    0xc05b968af40136b1, 0xc446671f4ffb1166, 0xbfcd2c150636ccf9, 0xccd4b2a60a02176c,
+    0x3022a01f0b87ed9b, 0xe83a5f2e28940c5e, 0xcd36fa43a8e2233e, 0xc5205da04c376ae6,
+    0xab0e9f691743e2fe, 0x04f6079f64792630, 0x2a83c09f4342cf1f, 0x73355caf9f5d7b0c,
+    0x3a887e8c72f40872, 0x7bbc7a3985430b09, 0x484b395560faf032, 0xe3d5b9177c8acb2a,
+    0x23b2a03234307f4a, 0xfae9f38e1e883a2d, 0x7ee8fdcd64cb6936, 0x92544467a358a76e,
+    0xf5ae4d52e61e35d4, 0x3cae4dc3d3896e18, 0xea21e8ac7f5ff95d, 0x91111e25512ec686,
+    0xbbdfb174463861f3, 0x7397cd8ff2212596, 0xd98d648325449e03, 0x34c1d900eabe03af,
+    0x95cd8b5f0787c148, 0xe63f500770b7d146, 0x026c36f4e6e2cd0f, 0xe93769f7088fa702,
+    0x966b10fd5e5ad5c4, 0xacaf260ee1c2412d, 0x9d6107d2a846104c, 0x12fc6e087f32dfe0,
+    0xfe90c6d95b5b60a4, 0xc8aabbacab389eed, 0xa5cf4c58fc792e8d, 0xa5a553ce977e708e,
+    0x427d31c58a130774, 0x9c3dcd8212974708, 0xce7de60716312331, 0x70e35d1e3d2c7864,
+    0x0c07f2b18428edff, 0xb114724a52773963, 0x0918f05cd819d299, 0x1aa587391d351647,
+    0xd8947ad72cbf118b, 0x4aba7db940a75184, 0x057ab6e99c3ecd5d, 0x05665ae9857be939,
+    0xa0b173fbb45f3750, 0x5843b0c444e4185b, 0x122c5ef32d6e4e0e, 0x6a821b1fc88ab371,
+    0x8d61f386c7ba952e, 0x1a0551d0e98557a7, 0x792d071013a4b177, 0xcacdb42f62d3aa46,
+    0x246eaa2e6eb86fe7, 0x181ad5ef0304f9a0, 0x66285f8aa127439d, 0xa557da55abf041a0,
+    0xc9a6fba70ff55c5d, 0x23f265455ea7c696, 0xb4d495851da2d5c7, 0xcfcdf0ea3c3fe327,
+    0x0f7de03411cf9d2b, 0x43cce9e843df1ac6, 0xdd9cf5895c9b9fb3, 0xb54e87cbf3b72d83,
+    0xf27de941a6aba5d0, 0x6ae263c8b71948b2, 0x39ac6325add79231, 0x938ea2a4d555f33a,
+    0xc20bde1dacfc3bea, 0x93768ec698acf63b, 0x3da83f69707d7ca2, 0xceb5afe1acdcd37c,
+    0xe4a1dd3529d62749, 0xbccc6844ff21cd91, 0x62cf1ceb39118f97, 0x2441bd8aa4c9f992,
+    0xf1ee20336c3344eb, 0x31aea69355a72b75, 0xd7e82e7194d2addd, 0x8d33a5e0d171a605,
+    0x9e3393ed2663fad0, 0x8346a88abde8d2a8, 0x86f151a51c0195c8, 0x92d98d4278000941,
+    0xddf0b26c02771856, 0x9ff579a08d094514, 0xe9cd6c5ce1667fdb, 0x3f02c861755cd018,
+    0x681ed7e65a97f75b, 0xdaa92949e0ca25ed, 0x74921576f9b57e5c, 0xd37ca3e9bfbafe23,
+    0x150c12b45eb9073d, 0x1a1a18cb91d4a15b, 0x6f9e689ffc77060d, 0xe4bc395d01405fcc,
+    0x2cbb936cc8a86f48, 0xcb635158db02e788, 0x8c8ee6b882ef54ac, 0xb2a08862bafa854c,
+    0x6f6e8deb835e690a, 0xa7dbcd30480de330, 0xaeacfcfe96c18564, 0x872c5d3dda1aee55,
+    0x9dfa7c9c8d29fff0, 0xd228623c012ca79b, 0x980e00e9cbbf58da, 0xbc8f37801f931c54,
+    0x800d406633deacb7, 0x8b039697ac16af5f, 0x705a17c13e8b6fc3, 0xb7678a60c88276d5,
+    0x36e5b1b57cfb2647, 0xe4a64de9d7bda8cf, 0x6ec1dd47c89d0368, 0x15ddb31b78ecd500,
+    0x5b68f6b0937fd6b3, 0xb9526e5a0313c4fd, 0x4c6d807d5bb3c4f2, 0x51fa8b6d2a10db6f,
+    0x8faee0fcc8050c0d, 0x306a91afa44517aa, 0x4bd8c6d4cb42253c, 0x0f052ae3307dc03f,
+    0x4ced66d0cd0ffba0, 0x25fcf0c21ab5cb7f, 0xfd0e64fcecfd30c1, 0x08006d4cf61ae7b7,
+    0xd2135d6106c1a7f0, 0xd4caf622420e4eee, 0xe9c70c62e6a55eb8, 0x86ec08261c230dcd,
+    0xe132cf5289e5cf01, 0x82580efbc792aecf, 0x49334fe85b4f8f35, 0x7942393687abd588,
+    0x3a11676c9f8525a0, 0x85b3deb74a44b0a6, 0xc8e06f3d3bf2dfc3, 0x056e7133b812b479,
+    0x934007f34397ba2d, 0x1c18c3ec49dd124d, 0x6af700b65c15705e, 0x64d769c5a54778bf,
+    0xc1fff7b9ed7775d0, 0x254a0f9ebe9f2ef0, 0x55fbba17e1d38efe, 0xaf43cedb6f795d7b,
+    0x1b7faeecb719185a, 0xf7b8d5831f9b5775, 0x9bd41eecd7de5795, 0xb169d77220e5bfb8,
+    0xfa476e1ceee9c52b, 0xe252c4c10ce3ea45, 0xcff77510e2bfa218, 0x4afa367d2cf7ed36,
+    0x946b8007951cecb2, 0x8333f11792fd8f5d, 0x3b3191dd61811996, 0xd1fc5fabd51a5a85,
+    0xd6144e36f849cdc1, 0x4cc85f014f5fc53e, 0xec96d560df753e21, 0x92b0549eb0f8848f,
+    0x94575316f2d3a5e3, 0x421cde963ef33580, 0x96bb600b02189490, 0xb1129830a83d273d,
+    0x00dfb522cecbebfc, 0x08e079dfe24f92bd, 0xd9ef83f01628eb46, 0x9fdb3b95c8238b9a,
+    0x839f5ca311a0b9e7, 0x87098fe25717d8cf, 0x4f7f3c5bbcfbd3e9, 0x391bbf8992a81bc0,
+    0xfed2e7898ee996aa, 0x530c7eb368ee6c0e, 0xb50dd99f15e6095e, 0x7e783b8a054123bb,
+    0x2ca73f734513c03c, 0xd87b7dcb13156cfa, 0x9ee4512c4f0d43a8, 0x892ac6f59ead7b7b,
+    0x6af7ac591023cfaf, 0x644956b3443e428e, 0x7b3eca593cf7168d, 0xe6854506e45bda59,
+    0x9559f1f9df87d681, 0x304d171bef4fe204, 0x715160a1dcc6cf22, 0x7fc1be311f183e84,
+    0x58dda42478cfb0d1, 0xed27982dc2b15148, 0xd4a4e7b8daf71cf4, 0x5ee9f38389f400db,
+    0x352ba6137fbf966c, 0xf9bf92ced9d386ba, 0x44130e22e2d0a3f8, 0xc6ecc7b9c22b30fc,
+    0x0113276334855c1e, 0x243b2d962941ff88, 0x1038a02464a022d4, 0x47e5577bf25d4c69,
+    0xdbba386d32eee186, 0x5077f0bbfa6ec840, 0xb7c517324d1bfef8, 0x1bbd91f74455cda1,
+    0x097418aca82ee1a4, 0xc8fb63937298fa78, 0xebbf19fdb5cb790b, 0xd6cfceef87ab8578,
+    0x3776d43d02506dd6, 0xa7d265afbacb8b3e, 0xa9f4737df0bf100b, 0x4f642840e39ac922,
+    0xf0f936ee8598f3cf, 0x06e84686c7dc7cda, 0xdaa2b24da44abce9, 0x770f4fea9b12b00f,
+    0x2d1f247edb20c990, 0x95a0b64589ba85a1, 0x2059462102ec3127, 0x3c89c7d4792f1801,
+    0xa717dc5603f965d5, 0xee47c036e8e188f3, 0x88d2b58f7947f190, 0x38dc1c645d37d212,
+    0x0305b1de70bf456e, 0x59da4f97bb70fd6a, 0xda62183ab724283c, 0xb135f087abc59352,
+    0x8769133ac94fb71d, 0x1fd2070a0f1c1051, 0xbdf5daeb08c17f71, 0x04db18070e84e3bf,
+    0xa905a1158fbabe85, 0xab17cb091e1bc122, 0xbbc1650bd488a609, 0xe19750665b5ababb,
+    0x71e969db7d0e80e7, 0xcd6b45a3bd6b2fa7, 0x2dec7f39601e45d0, 0x511cfd8cc91d1c92,
+    0x5cbd59e30596fca8, 0xdcd56df07b250b5d, 0xd8690d3ff19b9e96, 0x9472234a81c7e638,
+    0xcc8e4671074b1749, 0x3734a8147847e688, 0x28b89f3d36800d24, 0x36561c6878a47f2d,
+    0x17ada0b3fb842da5, 0xb4b32650a63fb8ee, 0x4fb4168373343c61, 0xe7285b4c4f75e815,
+    0x768e66a90b08ebad, 0x5f0d59244947a8a8, 0xaebf276004e4c725, 0xb16b8b6306a5563e,
+    0x76eda06ed58d37ea, 0xc30295b009f59e47, 0x364714f8b02292b4, 0x030c2fc32a32bad6,
+    0x9fd396ef21b81364, 0xb19680cbafcbfd71, 0x20610fc5bdbfeab9, 0x48b3550fafac6d4d,
+    0x28e69469414a1f85, 0xc0315613272859bf, 0xf7d8d6be27db32a2, 0x37219b7de979322e,
+    0x2c60e0a4b60f1906, 0x9676187f255d228c, 0x6748476fd4ba3630, 0xa80404ffdc786bd2,
+    0x98d5f63300fe81d0, 0x2a67550fee25fad9, 0x9c19502fefd9605d, 0x6701a470ae6ba03f,
+    0xd55d1f2d19131beb, 0x0a436a471e8f6242, 0xf7e8cc44d7586c51, 0xec1f49ccda42892d,
+    0x949c71fe2e1cbbf9, 0x23be976a29879132, 0xc52585a1005d963c, 0x0f1bff638e8247ed,
+    0x10d01a1585c0f9cd, 0x08f85b104a273a02, 0xf207a565d5e4bc20, 0x0cbf77d3c79edb48,
+    0x642b96d5b6f1463e, 0x4b222ceef9252379, 0x3ee687e3509dbadf, 0xa303dca39f43b9fa,
+    0x8bb01fe6f7b58d92, 0x8371fc8801d7d3a0, 0x66bddcc803e7a3a8, 0x0865954e13e27233,
+    0x5b4eb7e5b89b17e9, 0xde1de15bf1dc7fb2, 0x3a39e432a5a2bcf6, 0xcb2ed19c5e85833b,
+    0xefada6584a6f7e4d, 0x57c92f14ce8e204f, 0x8677e2e97eb521c5, 0xad544722e5a5e4f9,
+    0xc5b7877798e3a8ba, 0x59b0c0e6beb7680a, 0x128d8a1f40997835, 0x6c8dd600c22507aa,
+    0xbcc90b17ea24d9a4, 0xdaa2fe9a06ae6fcf, 0x3dd450aa16ffd98a, 0xa37526cc084d942b,
+    0x45052b7dcedaad19, 0xed0239a1a64ab0cf, 0x36276c0915b7889f, 0x85877b54d2d6d067,
+    0x6b6719deef15299a, 0xdb25949bcb49262f, 0xcf193f5a7edbbd67, 0xd731d9119a5fadbb,
+    0x01b3618e89ad3e2f, 0x03b5fa6411d7d06b, 0x6564761472d0fd31, 0xb2a2fc2679269405,
+    0xf2a19cc1bef5bd69, 0x29330bfe14dd3a19, 0x9cf81f62ef69ce53, 0xf2263457ff9ae1a2,
+    0xc93b0d2313f32fc4, 0xe823fe881ea1ecb1, 0xec1875215e590f81, 0xc31b5b1bcda67e29,
+    0x0e7af757a07ad5b5, 0x2e98ddc2a5901822, 0xd04f9d4a78f3c92d, 0xf73857b688e59d12,
+    0x4b54d7eb758df409, 0x27ae7daacbc9e938, 0x2c04dec4d6db8102, 0x471e43be8c9f4bdd,
+    0xd8fdaf28f87da2d8, 0xb0808ed507a7a128, 0x0e1d132a053fabda, 0xfc005bf39dcce206,
+    0xd1c06c10c0dcab22, 0x1154405896213196, 0xcbbb5962dbeb7446, 0xf791292118a9a162,
+    0x13a397c0327d354b, 0xf90d09e002fd1b37, 0x9c092653fcc5c321, 0xf89878e5593f1c20,
+    0x1413e764a0ad4b02, 0x07cd38281203bc43, 0xa8d2574789aa0f6c, 0x358b1c860bc8cff6,
+    0xcab8d089b08c246f, 0x0ed376b87edafcf3, 0xd6ebe7a4946ead0b, 0xa629af92ff00ec4e,
+    0xc582ac555a29275c, 0xd0b91ab682a71f1e, 0xbd9e48ba5fe84dd3, 0xde18bfd6acc0cd39,
+    0x9ff8e0068c99ebcd, 0x33ec2674e514781a, 0x80662b9c46139151, 0x8c4c96c29a583e17,
+    0x78e24dc825bb2e31, 0xf9a95cab41e06239, 0x3a5cc21b9d8c3bd1, 0xa86ad2f018790ea6,
+    0xb37826445a44dbf1, 0x50f492439f947bdd, 0xdde52d43dcb8951c, 0xa668edad78a54190,
+    0x022265839b18ef30, 0xc591b590b90d2f87, 0xadb8be5f10e6e7ef, 0xe24c55bdccfc55fe,
+    0x8f32645659dce835, 0xd1a647b311f5e1d4, 0xaf130aaec1f11ec2, 0xd84e964318d832eb,
+    0xc3fb88231c4942cf, 0x9f8ce6eeeaec16b4, 0xcbb6e40f255c63ca, 0xe13df7627fa0fef3,
+    0xac24a801add235fd, 0x65a104befcc4a4ee, 0x5d68ce605a8542d2, 0x56f6adf17b34949c,
+    0x240b16d0655231ae, 0xc56f00eb081e41bc, 0xbb371fcab477e5f3, 0x96f117b3f805d532,
+    0x34e5f3809ddd3d6b, 0x5703a04882c8b79c, 0xdbce77e43aa01dd2, 0x901aa44caeaa827b,
+    0x2132286502aeaa8d, 0xc17cccc60c7778e9, 0x6fa7fdcfbb76089d, 0xed9dd342e0606e92,
+    0xa8a4bf3239cce973, 0xad7bfd1007ad29dc, 0xe9c3ad827282ea5e, 0x89f08ea73cf496a5,
+    0x02317fdb5aa14cf3, 0x220708db4903485c, 0x4ebd092b5641d1da, 0xa6fac655e44f1082,
+    0x997743531094e2ab, 0x5b32f365bf6f98c8, 0x77009ccfa0b23b94, 0x442d2717e1a27885,
+    0x0e6ef3b14d5d0769, 0xa6e29493453a43d3, 0x7850ba0fde9658a1, 0x8b23ff757043f017,
+    0x115ef037878495d5, 0x91027ed8e3a572fe, 0x0dcda6545e04d833, 0x4ac70137926b613d,
+    0x884cf576021f261d, 0x5f2526d5a1204a18, 0xa01f216c537e0d39, 0xa70105750bf04240,
+    0x5bf5b75fb3607afd, 0x19c3557f848bfc56, 0xf08636ed054bece9, 0x24b1fab3d4055073,
+    0x6662afb852f97e08, 0x8081666af1f0960d, 0xa3b99c8150620217, 0xba57d5917ad902d0,
+    0x2df1d19406d16d55, 0x9f6e40d28bd3a1ab, 0xfe7e2a0df71e19ec, 0x83bebf5b0b1402c1,
+    0xb5a339b6830c0c3f, 0xf6a749fca880f7dd, 0x1957a33532d9f272, 0xf8bcd9bba1c6e9c3,
+    0xd23eac8a632fe203, 0xd5b2da390897991e, 0xd343e52beb183095, 0x05d528d5a1695aa5,
+    0x70eb7ab6f56946d3, 0x4c9b537ed06d5be1, 0xb8f071d9f06b9688, 0xf0338bcfdc9a2812,
+    0x48c77f5ab511c2ab, 0x6a9cdc1be4b1c634, 0xf12febd6acd77556, 0xc076ac1edcac8318,
+    0x611288c72f945fdd, 0x638fb2874882d8b4, 0xddb3bdd0fcc7b287, 0xb0f1784a3d082cc7,
+]
@@ -0,0 +1,5 @@
+[
+    0xa9744de24ede2974, 0xbf1b04914d86ed58, 0xec686719b2609c50, 0x2c0f449bcd7ef79e,
+    0x1b71c6d5a7e53de0, 0xf24e402c8fe0a72d, 0x6607e2d6be5e1a83, 0x928a151d3dedba73,
+    0x653a460cdac1be7c, 0x0c5b11eba262ad82,
+]
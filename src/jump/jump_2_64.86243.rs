@@ -0,0 +1,339 @@
+[
+    0x9055cc443e541980, 0x729838085e4a90b5, 0x196ec00d9556b99d, 0x902b93829e365960,
+    0x1b8dd9b461f32c07, 0x5eed37299a3b3a11, 0xfc9180fd5dce854f, 0xfbec3efeaaa3e543,
+    0x7bd2de6baa63f68f, 0x7aad39dd2d51340f, 0xccf6c8149b3e360e, 0x6718116082f5ed8f,
+    0xf62d3f5dd05084c5, 0xe726bea9a7f2590f, 0x0d73af3173902fc9, 0xdf11c8e10bcc957d,
+    0xe458ecf1d9a3b121, 0x0307600cffe52b57, 0xc39656cc69e9fbe8, 0xa5e87b972a4edf7f,
+    0x30451bbf954aacf3, 0x64919bff7ce9be1b, 0xc8d54654e796fdde, 0xbacb6a467833ebc7,
+    0xfebbe5ad37409a84, 0x97fc31270d3a5177, 0x91be2533395bbc23, 0x9e250372c979b5ed,
+    0x09aca84f500652a2, 0x764fbb652a54f229, 0x7dbb0d70266b8c32, 0x5a42817c49b4f94c,
+    0xc042420c6ffd57c6, 0xfd6ef814aaba21ac, 0x37b83c79bc7534bf, 0x56d9747c5fd93787,
+    0xa3af8ae58897596b, 0xac57d81e56aa7559, 0x08d338740acd35a4, 0xd8c4d04ff00105a9,
+    0xd576c22243ee11f9, 0xc0ebfc290c7b1b41, 0x7d3fe53fd88e50f2, 0x48f156c258d30152,
+    0xb4f31c7b3e3787d8, 0x4da4166285c7b8f9, 0x096a6cd0ec6aeee2, 0xbe3ffdf72bba5290,
+    0x07d41376874a64a8, 0xefecd7bbbe971dd4, 0x2cca1059e79e6806, 0xc1c35f1504ce5b06,
+    0xf4a9ef35d240d311, 0x951e87147b2a6979, 0x21c9c2a1be880f9e, 0xdedfede76fa6576f,
+    0x803e044e709865c6, 0xc878a9922174bff3, 0xc627456b8b91262b, 0xd6b4ce87763013d7,
+    0xe9dbab43f4f1f824, 0x6c05c0d2147dee8e, 0x9f765d8c6e12986f, 0xfce59de74ad9f2d0,
+    0x1f57d8e955c41d91, 0x02d2a0baab587c7c, 0x044092e2fd4ff6b9, 0x0773e4de21afeb95,
+    0x44c7d876bf195707, 0xc9aea60ee5c5ad5a, 0x13c94ae535ec4c4e, 0x2a4128e65535cfda,
+    0x957c8b61d91e355f, 0x91dbb5b4de1bae2e, 0x3d3a9e3dcc925b20, 0x2ccbcf6388163216,
+    0xf3ea68d14f12872b, 0xf48b72185e078b7b, 0xe8a0f248caf2d0a2, 0x3c4c8197e2a0a3e0,
+    0x6b79484f8a3da006, 0x3a5a95f47297027e, 0x4e4e5b8fcfb95ea1, 0x3b8055ca8e359323,
+    0x7015dd36e9e85df8, 0x69754d807fdae1f5, 0x7ac5a3cdcd25f953, 0xe2e9432e4dd0a4d0,
+    0xbc4f5e023ac4d337, 0xd9659cc4b8a0227e, 0x3ffa9ba99971140b, 0xdd8b7eac1ed2a42b,
+    0xbd4553d98a069a44, 0x4c3ee2c89e7da88b, 0x14f570bacfbd0f73, 0x606f466b3ea55de5,
+    0x15ba973a4ea65b11, 0xb3e6c6745691971d, 0xe346f2d173a8f717, 0x1ab0adff261f8e36,
+    0x19cf43248d5dfffb, 0xed414f3a3f2ef8b0, 0x1cc8f8b55c931678, 0x19735b607dabcef7,
+    0xdf79b98c7c6d1f72, 0xed9001bcdaa23407, 0x37af32ea59b9fb17, 0x1c5c9c53764c8c4f,
+    0x349a215e57e0f36d, 0xc4bc412335b3d300, 0xd56cfa4cafc921fe, 0x16234b88a54f27dc,
+    0xd34f7f3e25b1b5ff, 0xb02537d6c7cbe20f, 0x1772143096eb935a, 0xab6aaad4e8ba9915,
+    0x997341d8b26ebb91, 0x66f040bdc9f315a1, 0x35e8cc7189a653aa, 0xe78c4109bb6cacdc,
+    0x1d47099d28dc73fa, 0xef51a222dddf5b0c, 0x342986152de193cf, 0xca4f000549275604,
+    0x96818b242d71028c, 0x4de588b3508a7cd1, 0xeda785fe1e7617bf, 0xbfd7fd5e611c924c,
+    0xa5a7a0efed67cfa9, 0x7b9d752bbb50168f, 0xfe578dbcbf055fee, 0xd341cc5e5cf8113d,
+    0xd7a8c13173670870, 0x0408ef4a1fe79f98, 0x5c46eb04c11a3ce3, 0xa3848ae3c2273d63,
+    0x85b3e7c5f0d66a27, 0x6a83abb49ebded7a, 0x4742ec02ee497a59, 0xfc52ef0c05ee700d,
+    0x69547175ad636cec, 0x3e7363e9bd4f4905, 0xf8c933ac7d5e0c72, 0x8d41a4823a3e9450,
+    0xb85a7db57843dd0d, 0xa66122f5d44c83d8, 0x695bc14e453856da, 0x80b86a8fd4298ec1,
+    0x84b34723776f8aa6, 0x8c02ba05fd5785f0, 0x6d22e69f4b9d9b25, 0x37aa7eb747073825,
+    0x4f5883e1fd58ddf8, 0x2a895a37e7704648, 0xb833decf764c6203, 0x6ebe40f375d351e3,
+    0x0992c834eac04cee, 0x5657b74daafba468, 0x77e1bc02be0a2c14, 0xb86b83ebc5cded76,
+    0x6adb456442d148d2, 0x7c6cfc89574cdf47, 0x81e88f29039ee097, 0x156f22fa044f2300,
+    0xf32c0a388d672920, 0xaba055bf60d4d4db, 0x6ad439b07091cac1, 0xe85b3adba5fc609a,
+    0x7cac6847741ef5ce, 0x0dcfc3fa1253f551, 0xf147ddb6bebba893, 0xa05d1139a6c821b0,
+    0x1183c3fa02750bf3, 0x32ab7bf495e614cc, 0x22122d0c9360bf7f, 0x70e23312f63b3888,
+    0x43f7870807e85f11, 0xcfd7f0e326c3fa1b, 0x6a75510c7cc70cdc, 0x2c3c1731d527ecdd,
+    0x14022d7e2b499057, 0x80f765ea8c4e4c55, 0x32a0b3fbf117a535, 0x6224ec51ff0baa01,
+    0xe0c77f35ba8a9d45, 0x296373ac173b8564, 0xfbe9a0e9b65d5ae1, 0x4c95797a051e7d48,
+    0x36408a565f489991, 0xb905654f22686da3, 0xb32a467fb6eb164b, 0x778c73489c55c4cc,
+    0xa745ee7ebce75d0d, 0xc7ab7c32c05b9c34, 0xd9365dbdec071ede, 0xf0e0181dd7d9a085,
+    0x8756001326b25b0f, 0x03fb73327cc25300, 0xac5b9af0415d691d, 0x4aada967b0af1612,
+    0x4ef1c967e848e50b, 0x65d99676030577ad, 0x7bbb379b420d37a1, 0xbf023334002e1319,
+    0xfe1c5001b2a7575a, 0x7680a0c71dc8a61a, 0x581b877cd60c8f29, 0x4aca5ae20e751626,
+    0xfcd225ddec41eb2e, 0xeacc4689cc97e457, 0x0286fb027d99673c, 0xa259b72e9695a826,
+    0x674d9e7b96211cbe, 0xb6ccb7da76ffa896, 0xcf3a475bbe057900, 0x1e46a954ac9b6d12,
+    0x559c7325274e1f5f, 0x4d8d61b4a345fce4, 0x0a17abe9d04c29aa, 0xd45d8407760f6b01,
+    0xe2a741ce4133d28b, 0xe9fcda413053e0b3, 0xcda066870dce0147, 0x427921a41575c97c,
+    0x93d9eb9132c40530, 0x6e3eb10ca5dceccb, 0x4dfc0de102169e8c, 0xc6e937020a391534,
+    0x5a45110738d678b8, 0x94f9689b0989da60, 0xa9a56978b9a7a47a, 0xf0a600e5591aba51,
+    0x8de2bf3ace1cd6a7, 0x5686ddc400cd6655, 0xa0eb2cb4d73b1807, 0xa7d5a72725cbd183,
+    0x3247d875c9725ca2, 0xd6c8cd74e3a23d2f, 0x2f8e23a32d4d1a5a, 0x686e86d72eb3dd35,
+    0xa57e9690f3a6d143, 0x0a4ce05c3ffcd85f, 0x353319b13cf66d6b, 0xfec916962eb52e5b,
+    0xf62e4301985949b0, 0xe952ad28ad304f68, 0xa8dd97702c1dbe23, 0xc7a8421c644ab993,
+    0x0959e6d5326cda7a, 0x7fd9df87a8c16675, 0x0a2167bbdf3aeb14, 0x8b58ec2fcf056d75,
+    0x886026da32f44cff, 0xd67c6dbb58f52db3, 0x5ef30c020fa33bf2, 0x55845f5682235c35,
+    0x6cca08b9a43c9411, 0x708ed75d0a4822de, 0x56d5b77e3d3a630a, 0xbccb672524d609cc,
+    0xcec090b08dd54e37, 0xfc3470f37afc2861, 0xb2bada3f88fd9019, 0x379a808043a825b0,
+    0xcc570578287ae322, 0x58085f29d0d8661c, 0x772caf3292888b4c, 0x3d16f97808c4b7e1,
+    0xab0bdac4cf19ba5f, 0x5d1601215c005659, 0x54769c8875a97418, 0x5ebe0861361fd182,
+    0x93872d9fbe41332d, 0x9ca8dbfdb0525924, 0xcff45a33a680cee3, 0x0e7a267e7936ab85,
+    0xc6d1bd04be29ddc0, 0x1a60acfe136f9e7a, 0x10b7d0b6e6b3f807, 0x0cac8a369e342a69,
+    0xcf4a2dbd69a2f494, 0xd177b74ee92d1335, 0x314b64db7a2e34b8, 0x7f96c03c57ce2f37,
+    0xca25a23aaf983d25, 0xb70cca9c5067cf00, 0x0b0bb8b3cabe5b23, 0x7d3ede0daaf08201,
+    0x038341f75f331946, 0x3316026e12b0727f, 0xddab76d596136eb4, 0x8cb72f15d8774f15,
+    0xbda6a3587ae1a9d4, 0xf371966171394c7e, 0x7e62b2915ed3ea9b, 0x281dbe82ffbeb7fe,
+    0xfd29692f0a4ef235, 0x5fcff5e5e9df8b64, 0x31c76f7716e55b90, 0xdc0bcf9961304270,
+    0x7ae40282be8be68f, 0xf0e6f861b82ce9e5, 0x7184de92c9893942, 0x16635f508e55c570,
+    0x0e7c0b47770db5b2, 0x7f6d90f7a0fa4d2b, 0xb72a6063bd6ad831, 0x0071005fd174822e,
+    0x8c123d80a336f9ac, 0x0cf796c608f7f0d1, 0xa48ba44d416d0299, 0x17c0ec55fe583d21,
+    0x1f3afaf3a80b5b58, 0x7b7f4db777d4cd4f, 0xf9cb4c5fb6c5d35e, 0x0687ed36e1b5a269,
+    0x7dd7d5193ce6d7f6, 0xa2b525ebf2a19952, 0x88ea964b8a72befc, 0xd80ce92594036e9a,
+    0x943e59338512ba93, 0x4ef3d08af01dc392, 0x1d7fd078a80adba5, 0x98d8c343e9a4beac,
+    0x22379a303f6bf4dc, 0xf0c6ee5684f342ed, 0x8d054b5a264e7905, 0x2690c172adca459c,
+    0xc0fd065c1cd2f80c, 0x0c6045dd937c4837, 0x88f8fb54bd19534b, 0xd9578621426908c6,
+    0x6f44bec07b2420aa, 0x9a5bf99e9adf66bf, 0x54e596c8519dbd07, 0x67c4147ebea8ca3b,
+    0xf5d6f9886bc00f3d, 0x28808faf0935c017, 0xe3389fed8099957f, 0x739d376176ef29b3,
+    0x1297b5158093ff24, 0x8df823fc703a1028, 0x2193520e03e5d8c0, 0xe10e59168a37eca2,
+    0xf7fcd8fa331aa7e7, 0xa2505a9bb2c7335f, 0x00696ea5e8f375d1, 0x0804deb7f999ea40,
+    0x67fc566b58446777, 0x5cdd0da6206034f2, 0x566fec28105236f2, 0xa64412244e8a8dc0,
+    0x21580b653f17e3ce, 0x388c3af2789f3f90, 0x483647181ae98ac3, 0xaac7fa219d209163,
+    0xd20bf8b54900454a, 0x91f802d732b8dfb7, 0xe041829d8e6f5471, 0xe3ad8977e00339a5,
+    0xf6a7974770d743ac, 0x841477a209f13c5c, 0x36cea04772467f3a, 0xc1442586525a1e5b,
+    0xd6b7088839a6f8d9, 0x85a50b0cdb8e333c, 0x877c03bb4ded1077, 0xef873c79e8591bd2,
+    0x7a8e8c967d14cf08, 0x69861411cf016bea, 0xbc060e4c69c43779, 0x2bae089b277909fd,
+    0x71bf535dec5d3974, 0x97ac7a2143187f5f, 0x0fcb973d60fa5656, 0x7fc6bc85dc2f5abd,
+    0x73d2c4918249e710, 0xe305531f74dd744f, 0x8b593472ee54e318, 0x621651a50b010747,
+    0x158657c4c8c70f19, 0xda518fbf428dbeaf, 0x7ef43e0356a5f68d, 0xdf1d67ff3dc219be,
+    0x2980f2864b18518e, 0x564eec7dc701794b, 0x246f5fdebc5e8dc4, 0x33e71034ad5014f5,
+    0x3809d4aad7e52ac3, 0xfd532d3cbbde956c, 0xe44a5e65c73f7e39, 0x1af234e5d0d30509,
+    0xb44cb7573af2c584, 0x9f15a6afc12c1aed, 0x1bb1d30ca97ae672, 0x60a9262347464ab7,
+    0xd660ccd0228b7dc2, 0xf35c1ce425908208, 0x45fa250353ed25be, 0xfee6086e199c9216,
+    0x077b106f15fb99c1, 0x46f9a75d5616ea3f, 0x91cc096ee0ee3f9e, 0xc63110ae207ba527,
+    0xd5e99f9c79160e58, 0xed320bb3ec5493c8, 0x7407ab8c42e78c28, 0x607366e01919571f,
+    0x84a0ae57d727efaf, 0x4a7e7ba081b71720, 0x46f011ef0f8cef22, 0x09ed9d742f04bd44,
+    0x71a5ff5887be5ca8, 0xd76d8c1f0224ebd4, 0x406380b7760d5ac9, 0x561fa7f9128ae628,
+    0x38c6d49029cde605, 0xe531476025eef619, 0xae30f5e11539b675, 0xfa9af215ec54d608,
+    0xada57ca6d7ae3d8f, 0x3261b2e28bb41c9d, 0x34bd0a52aa987c47, 0x2d3779ee066ff0e1,
+    0x15d8622a27a49a29, 0x00186209c96f4c32, 0x86d9f05c3b458b56, 0x5024ec9b10c7d20e,
+    0xf42764a60eb9e7e9, 0xf06cc40cc516c247, 0xd7500c821d74e947, 0x855be82c0cede3f7,
+    0xab872f338ee4460c, 0xbffa57d7a119d761, 0x9cd0f55353b4a41e, 0x81e7e94b02b30827,
+    0x86255f169a5151ab, 0xb6719e550cb8e8e2, 0xb46c34c2f6c67dee, 0xe333e127ba3446e0,
+    0x8774c1637e4e0476, 0xda58c2e93575fe57, 0x4b878882a20b939b, 0xa323b1a7d8e87de0,
+    0x9d3e530fe0bb5d76, 0x68e8ac03f0e7deb2, 0x2649d89645e9eb54, 0x35b7ab3543dee42c,
+    0x9aa26fa336c18ef4, 0xf3304f39dc12fe80, 0xa665b43132c1376c, 0x889f9b3d3799d800,
+    0xb8b5f7b93a59a64f, 0x79934018b2ae262f, 0x2febee7a291577b8, 0x8715e39da0e41e09,
+    0x0313aa98101bf2f7, 0x673e71abbacd5196, 0x1a8063f09788e39c, 0xe4bf5f180992ef9b,
+    0xec936e03a9bc38c0, 0xd1019c78c4640e30, 0x57933365f0f87ae9, 0x07771de75adc0834,
+    0x9a91a3e7ed6f3c8c, 0xb411fd591e5d7331, 0xa429081b830928e4, 0x3f5ff9a124ec4f14,
+    0xa16126c08fc99e04, 0xc31b2ef16daf5787, 0x03bb382710a2f018, 0xc9ecfb91dc195052,
+    0xb5b2c91ee8c1ba69, 0xc97e6ce3c69cdce8, 0xcf91499145fbf778, 0x46a59d595c62c3ff,
+    0x906b008a70656bd4, 0x3a9ea17af5089438, 0xb4cb71f4fb155b76, 0xef2ca48690b7cfc5,
+    0x0f86d51058017ccf, 0x696d1ce6d510b9c0, 0xe6787bddffa65338, 0xb924ae7d531224a3,
+    0x53cd1fb4ef0e1b8f, 0x37b4ffcc59d8c653, 0x4831e04fd2038bc5, 0xd4e26633a863089e,
+    0xa67265b71b92b3e6, 0xb2b06e8d33772a1c, 0x2a26e2f0656742d6, 0x16596da0f3d914dd,
+    0xf4686f56be1f035c, 0xa940c0431af35d65, 0x679dafd624a461f2, 0x07ed0e368fb01725,
+    0xc79c5bcc1a84bece, 0xd14b1dc97dd668f7, 0x318d8600411b589d, 0x63e7e63450491b7d,
+    0x9b90cc383b731988, 0x80b5ad7a29432443, 0x54dcba616b6ad5bc, 0xd610007713c5b84d,
+    0x465134d72fafd630, 0xacc18e2e651ca30c, 0x84b524ac5afba087, 0xeb56a62e9decef49,
+    0xf12bde2a6caa31f3, 0xa81f1b734041e251, 0xbebfd180a17367cb, 0xbc56c10d8a5a4e7d,
+    0x1cdb425ad6e6d119, 0x94e25113473eb4b5, 0x636fea0f8f3ee2ae, 0x6d5e14a7e4b572b3,
+    0xa9bfa94fd39214f0, 0x1d0bd37545770abc, 0x0f3b1fd71f33f54f, 0x02367a964c31d29c,
+    0x11f784ed320dd4d2, 0x40dfed9ecad5961b, 0x4cc683cc15bc45f9, 0xc45fbf2b1872122d,
+    0x93577aff39f3fa8b, 0x1509cd60434196ca, 0x666a88c63c4f5b4d, 0x03f287786adfdd11,
+    0x82246cc187320f20, 0xcc894914807effc7, 0x694e56e4d6e14fb1, 0x3e37e7942eea3af8,
+    0x9bed9fe759c1e2fa, 0x02b3da5b4eb37160, 0x57b5ed12fe6fc2c6, 0x0864a1428d638634,
+    0xcd8972d7ac1cfe19, 0x40f754bc33df870f, 0xb9f53a7884483a99, 0x91369d8d3186d146,
+    0xbe5d983f8a22ccd1, 0xccb263c5b5370073, 0x1c3868044a042004, 0xcb343e13a28065bf,
+    0xb407f26b890a94cf, 0xce02313dc0960602, 0xccd8caf13a1f186a, 0x8ca78d3710e43ee9,
+    0xc84cd697ef27623d, 0xfe1da42c13101dd1, 0x059df88a9fb7a915, 0x4f4b844d958afd32,
+    0x6cb90b8c55b8e63d, 0x3f180947f09812e6, 0xd0953cc3d260065a, 0xb5b5d6d0e372d5f9,
+    0x369c2baf2517e52a, 0x5b311cd04da081ca, 0xa941b0b083da9395, 0xa4a9712de06cced7,
+    0xd8b2b8f3621dd5dc, 0x407c5ac3c09bf43c, 0xb3b1a5cb7beaf71f, 0x1ac914da16ea02f6,
+    0xd4ca5b456c83d7c1, 0x725b015d25210bbd, 0xca90a8fe1a889739, 0xe5cf692da0c31bc3,
+    0x5fd93cb97e0e7cd1, 0xe2186ec6cc6164c1, 0xde8d3965f692aacd, 0x0dbb7fadfdf9abbb,
+    0xc10cbd6fa5f6c783, 0xcb974c7d26947231, 0xc5635986948975c7, 0xcb6d6f6d416fa811,
+    0x2c49ddbc0d0c5191, 0xad0b92c4059049ad, 0xf2fb224305aead13, 0x4284f8fa2a09289f,
+    0x174c2c5db370c6b6, 0xdbb33beef3823d1d, 0x9b8a6d22b6e9a8f4, 0x50338e71e7ccc5e0,
+    0xa1991b9cdb6455e0, 0x9ddd77c93743db7e, 0x13146930f5b67b46, 0x846240949e8c13db,
+    0xb23ca9f0653ffa16, 0x3858cd5315c346e0, 0x4f3720f9265ffe34, 0x633dd644924843a7,
+    0xec0b249850f5b3f8, 0x3c6e2fd9c9404564, 0x48d1703b5eb197f5, 0x09cfe587c8e7c58d,
+    0xeb1cab9abbf2bc5a, 0x4be4e4f10f879f48, 0xab598865b39f5ac3, 0x17262cdb17416850,
+    0xeefe5147d8d8acea, 0xbcfe53dd9d19107b, 0x51ff9f5beb3cfc8c, 0x052cef33c98712ac,
+    0xb0bdc5ce3c477e6b, 0xe1491b34fd525f0f, 0x3fcc0503e5f64101, 0xee0bb86298a36091,
+    0xc1a53336c9d94c2e, 0x2e82fbac6137a254, 0xf30f7dd5f1d362fd, 0x204ffd39be04b886,
+    0x7fbf9952a3516c5d, 0x2826e788b658e335, 0x8c5cf3ba4b89d067, 0x29bca953fa0db626,
+    0xdb9d3cd4329446a4, 0x087a993acb0db1a1, 0xf7369fe4dc1b6546, 0xb91332240fab5d5c,
+    0xe98624af13b842e8, 0x03be8d0a89653822, 0xb92f528dedb5095f, 0xce467c6132aa70da,
+    0x4bec52f4bd682b6b, 0x6704010c3643a844, 0x1c993c2b883fbd79, 0x73b8edc387b67f4c,
+    0x613bcd49bbe1e982, 0x719008fa5d61f0c5, 0xaa25396059efb9a1, 0x137b931767a99b02,
+    0x1a58061f34d30c35, 0x96e600ce0c7a196f, 0xf2c2654fc8e4f8c8, 0x8968c794912d43e8,
+    0x1f7d686c88070e8c, 0xaa83161d05655b08, 0x6da96c6ed4a1a88c, 0xa8b4e8873dc34cbd,
+    0x15d190d2053cda63, 0x584694c65696fef4, 0x4ed0ce8d620b90a5, 0x4c37ba57c72929dd,
+    0x04d796c38ffd1b76, 0x5aa1afea6d495cbb, 0x532ce2e1ff375dbf, 0x5d20e9cf857583cf,
+    0x446afd6b0b83ebb8, 0xaa5dbe133374e549, 0x1f47ff0ad3fa0696, 0xee984e23d16213a1,
+    0x1306000b9aafb20b, 0xd8443e2625674ba3, 0xeb47134325ec5ca9, 0x3647987db2aaed70,
+    0x9c7bf37de83b76bf, 0x71861f8ba194bf07, 0xeee9ca4f16822f0f, 0x1b0372211e7a1279,
+    0x46f25f5692f41ca7, 0x8033dc379346f2b0, 0x1ffc19da1eff9fca, 0x0dd446425dcaa971,
+    0x3a71b4a95d7452fe, 0x82f30115375dbc33, 0xe2a2378e8feaf570, 0xafdd81039dedf323,
+    0x32164c840886e6e8, 0xc47c7bc1a248c839, 0x672acf588efb2099, 0x2a5d5885674d0c13,
+    0xc5b82871362043eb, 0x7e71c3bfeadd9ce6, 0xf002b3d842655348, 0x6b4a1d0475130b99,
+    0x0709b4289335dbc6, 0xe9fecbdca46af523, 0x04b5e8ca939b7833, 0xc26e9dde3e3d79b5,
+    0x1aa5499049ac9066, 0x622176ea3a799205, 0x4553abe7310edaa2, 0x5b7c0c019a14ed7f,
+    0x5921c6d0c72e5dfa, 0xb8fb40618fcdfd59, 0x7d8496b4ca0d46ae, 0xccdabe52d61ad73a,
+    0x6a5fa3517c780753, 0xbd51b6bcb60f2547, 0x30d519ba015781a6, 0x6b7f57eae608eb83,
+    0xf486e25670de55f6, 0xc31e17961a1e3b7b, 0x4d6353ade173d041, 0xadc7feaa0bd020b9,
+    0x0a47fb2b0dbb1ef9, 0xc6e9811f929466e0, 0xc1e8cfbb402f74d2, 0xed78283cabd9aded,
+    0x3d3b619748342c38, 0xedc2a2900877f9dd, 0x2e3ff67e5ded769a, 0xfdac2dc8ec08e7f0,
+    0xef734b6447157158, 0xc10ec06c0a81ca1d, 0x15fc06704c3cf4a6, 0x59ec63e7f7628f92,
+    0x81c9a0c745c0618d, 0x577d6e0b82cacea1, 0xd0cd1b893a4dc954, 0xcd4b4a7d39e06304,
+    0xf04a78b42afd9c18, 0x5bef55cd27c502df, 0xf6686743a1d49b95, 0x3f5619c1c69adca0,
+    0xac25ad73d31b3f29, 0x419f7a42cc937373, 0xd075d345916a51b2, 0xb92a921d562b1cb1,
+    0x5507aba74591aea6, 0x9d520b41b836a600, 0x522014cd0a7264f1, 0x6ab0caefa37ba2ce,
+    0xe83de919d25f3820, 0x4f4a1d64e1cbd66e, 0x1f20f1cad2289aa1, 0x7e28892c8c24e802,
+    0x2d14a2ae06c416fc, 0xab63197229e06813, 0x5da706ca05fed85e, 0x270f28debfea733e,
+    0x6b8fe986a93622c5, 0x54d8390d79d8c61e, 0xbacbc4475fa4b94e, 0x94d4453c3f46a820,
+    0x53df38256bfc091b, 0xda4e86535d94cbbf, 0x9b5e68c1e9f934f2, 0x16ab0e18c82591af,
+    0x9653eb9cd767188b, 0x3df817f26fb9b7ce, 0x32c038f69993fe68, 0xcea21b1361da2175,
+    0xd7504cfb1378000a, 0x2406aa8e26a4f8c2, 0x609eb80a08f2519a, 0x30c0b142459dc323,
+    0x1cee712f98fe02ff, 0x5edd435fdef820b4, 0x74bda69cb937baa1, 0xf0f03ac928104acc,
+    0x657b4aa4f62047fe, 0xb17c08f9d396bd43, 0x7782cb19501b9607, 0x120301977d9d5ca8,
+    0x6d63cd6f08706681, 0x635352f9d3f2887b, 0xbb840b5888b82d20, 0x1bd879968c9d8338,
+    0x5c6c57eab3867046, 0x263103d3e8104566, 0x2c9841f6f5446143, 0x2e653bbeaa9b65c8,
+    0x9660857718532dcd, 0xba62163de79960aa, 0x83117b026e457629, 0xe56a86a5423436a4,
+    0x36f838e6e22dafa0, 0xb8176844a4933fd1, 0x889de41c009a03e0, 0x7b62fed10c30074c,
+    0xbea67c6f14411b82, 0x58ae6b194c4b90d8, 0x878ee1fda05944f5, 0x967a9925787fb520,
+    0xaa737aa10e8d57b5, 0x0f05ecb1153390dc, 0xe5a9e0965641977e, 0x2b51c7a105d2381f,
+    0xc80d9b1bc7d043e5, 0xe9eea6ef26add114, 0x77728b64092b5fa3, 0xca669700306b00a1,
+    0x9905892af1f07f4a, 0x5b4e4434ad01cfdb, 0xd66b5e6fd4f79f15, 0xd8c109cc17339279,
+    0x3c24c30021641de0, 0x9d53836139728131, 0x14501e6d634508f4, 0x706cb50231b06c8d,
+    0x02fe073a335e065a, 0x72d7b7fb089b6ce1, 0xc6c01c8216211310, 0xb4a46e4c96d35447,
+    0x2a991f5e112e303d, 0x56e7c84005ae81a8, 0xbd72ac68070c5a9c, 0x01e4b61bcd593057,
+    0x31fc6278d81a4b57, 0x9adc855d909757c1, 0xeb3e4d4d3e7b6a1a, 0x3e1a90ed9d7bcd09,
+    0xb72d8f21ded0701d, 0x5063fe6c00511318, 0x5b67333078b93186, 0xc06df83ad071d7b4,
+    0xd0881572312b7746, 0x1de41b210011f7da, 0x9f06ea039da5fb2e, 0x95c616690226aee7,
+    0x9d3f13d6853e0aea, 0x21cc3f0eafe90a75, 0x3862ee92bf50ebee, 0xaa9dd0cf618a8a71,
+    0x1e59fe4f966d69cf, 0x4895e2b7776280fe, 0xadd8dd421048bdb9, 0xa0905f98415cabe5,
+    0x1e0a3c603d42ac80, 0x224b4d3dd4bacaf1, 0xc67abce5201f5439, 0x85b859e7260289b6,
+    0x81643f6d26fb35ad, 0x8b320677fca505b9, 0x7b9240b12467d45e, 0xe45d355cdb7f187c,
+    0xb2bb8f9dae9ed4d9, 0xa5f52f40fb373ccc, 0x1b2dc55539b43567, 0x5ae26e9641aadcc7,
+    0xbcbcd03e09519a02, 0x483a5fef9de701c0, 0x3f5cbdf4d0122d8a, 0xee59f9e5ee2f9287,
+    0x3f7e4587f19f56b2, 0x7715dfb05d53eda1, 0xc97b38759925c3d2, 0xb81d7d617a900822,
+    0xec87991dd308c042, 0x94afa4c2c0ade19e, 0x3771f24449d1a909, 0xb7808cb334b06a36,
+    0x451d5715e6b246c5, 0x5079873137501b64, 0xf874205cb809a6ad, 0x72ab3a2224d35818,
+    0xf0d4690685fca8d6, 0xf25213958f716817, 0x44a4c9ccae6dc81d, 0x5342a8d249a62172,
+    0x0aa09a8c21ff9f9e, 0x609e5555ee1e29b8, 0x689ba29cc4f446aa, 0xac1fa247d63c955e,
+    0xc4d288fe4af7af5d, 0xe6f63f7d03352710, 0x310f18d62b10a9d4, 0x227e801d57010835,
+    0x9eedd20667995e10, 0x996e6228b5364370, 0x3ac6b72719113590, 0x47c4df78add387b5,
+    0x1e5b2cb15b9bea1b, 0x390289cba3a9ad7c, 0x1fc653058cc754f3, 0x44eb07ebed7bb7b7,
+    0xa6365301a4fb914a, 0xaedee2c2dea8836d, 0xa2b51d469350f3d0, 0x3876ffcebb670dc2,
+    0x39ecbfa4c1b5e803, 0xdd5d40d4f87f33fc, 0x6bce9938c0232214, 0xba10d7a783d73949,
+    0xa0585a14e8adfc69, 0x80082aa6218f69be, 0x204d3d305cc1398c, 0xe02cbb640b034832,
+    0xe369c847dfda41be, 0xca9a517da73999c4, 0x7542dc065d022150, 0x8812e1f50e9d6588,
+    0x042aae91221e3960, 0x5979d3f73d75d839, 0x511bbd0fe6d1f052, 0x57a34d064e5e66c3,
+    0x1149e2398ccc3ee9, 0xf76db543d63f3bfc, 0x5067d67c24eeaf15, 0x11078d3416f125a5,
+    0x687aeb41bb07f13a, 0x813e23fcf01f7385, 0xa172d8dfe159ea7f, 0x56e61b3a56538297,
+    0x60d5b01f09c64f7d, 0x3ee15f2acf5f176c, 0x88804f255b472d35, 0xfa37297c681bb0de,
+    0xa68c2106af72548f, 0x38dc1c50729e6ce6, 0x8aba8f9adc25adb8, 0x873369566ac250a4,
+    0xd7e1769623b99f47, 0x43c49aab9b4a956a, 0x8a1b6aebf35e4abf, 0xf0ed2691c8924e1b,
+    0x59fa33b97c67f232, 0x50b2f0844e1a9223, 0x947ef902f111ab7e, 0xc551f08207461e4b,
+    0x41047cb935ea3257, 0x4881a78bd582e5a9, 0x5bdc6c659effc9b5, 0x87ac8e2cb64b2012,
+    0xa51db84ffce2d40d, 0x50bb4a3d3a863a22, 0x2f7e9a28934b91b3, 0x89095af8eae31459,
+    0x556634e1c2693320, 0x7f275a27a099a63e, 0x66a30eb316c067e0, 0xa708af5d7d4206a8,
+    0x0a32886448950261, 0x5e85fc1f1fb1748a, 0x2cb9bf430ed15b0d, 0x6389b4bc85a6fb82,
+    0xb08b0840aee7e41f, 0x6cb968ac5ff6d17e, 0xe2a00ac7b6395a87, 0x3af04256f9f62379,
+    0x21f6b432c8a96079, 0x6fced56534ed3821, 0x26223abd27bd2850, 0xcbe40d8e8aa9d46f,
+    0xed21cada8a3da4a1, 0x50fa7d4f50b8ca2e, 0x9488b5c122393f78, 0x6aef90f091c4beff,
+    0x8b3fab91391fc4de, 0x173c026d02805d47, 0xa3417726b6e8a268, 0x45c2b32914a519db,
+    0x5d6503836c8e4550, 0xcccb2523cfd126c8, 0xc0d626ee1b2092f2, 0xcb32c28537a861b5,
+    0x22f6c0ac41b5661f, 0x71e9865945cbdfaa, 0x7596d6f9a63d6279, 0xe22f231d676d265d,
+    0xc011bec526f61ee6, 0xa06750920d0b9a46, 0x2d948361fd73563b, 0x931c74db6827ab5d,
+    0xd6de576ab35d0a95, 0xdce92bf127f48e72, 0x658a7cd508e92bcf, 0x68b1b0aa3a08acc6,
+    0x93628dfc47e6f9fc, 0xbe04fe7e3793c055, 0xf1f74e3fa2bb4f8a, 0x08e14355102e88ea,
+    0x15e57a01806db22e, 0x0c2e373d2e45c72f, 0x493613bf79bcbe28, 0x86197ef47952ad7b,
+    0x087119a63e06266b, 0x4406d14f30123bbc, 0x4c157016588f03c7, 0x9c513ef856b8de33,
+    0x23834da865f6a874, 0x4b8a3acf3bfadb49, 0x40bb09dbf96a17e2, 0x4572f59b347a514c,
+    0x187f34b7f214930e, 0x0e86b22c332b4284, 0x3b39f8cd782f14ce, 0x80fd427378dd501f,
+    0x13d89ad8aacf6271, 0xa6e21e2e95a904fe, 0x5462cf1644ca69ca, 0x9c97ff5e31028f38,
+    0x466d4b62a83a611a, 0x99008132d2220bd4, 0x4158d74bf8da7be3, 0x6c06694f13e62b27,
+    0xdf6e2251d7516a37, 0xf3b6abc55220a5cb, 0x44fc409c8bf3626b, 0xfc07018c43a8e368,
+    0xfb8a1d982626ac5b, 0xdc6c2d3a4cc0e855, 0x0a2d5c1a0d2c1e6f, 0x4836c4339dcebfea,
+    0xbd5c5fbba728e3ea, 0x2dd79034e4b661cf, 0x1deb0ee8b1c22f5a, 0xde9077f2e1931f6d,
+    0xf7f0eecd8bc25d36, 0x7c4231f4c3097947, 0x0ff2c55291f85797, 0x64400df5cc4cc3be,
+    0xf51891d1e59339d0, 0xbe3aa03d30d54859, 0x595e21913e688252, 0x075cdf964b208163,
+    0xd3b1639f1c1fa823, 0x3f11a994bef5aded, 0x51b3e8f940e675d6, 0x6883c5513a250cec,
+    0xe98b8cb0b56bf351, 0x7d4ada1be6fe847c, 0x933ab45087de64cc, 0xc8c53170a3ad4969,
+    0x6fe19b9515e6b537, 0x72e3f6d5b41b2f88, 0x09537775a3540caf, 0xf66b06791ae3c100,
+    0xf8a14a72cd3c0727, 0x58cd3929bd93b7da, 0xf7a80955e75f38f2, 0x014f180d7a3bfa17,
+    0xc2a6d207e47c4776, 0x173b953a92639799, 0x83726445d38aeef0, 0x22ccaf8602d236f7,
+    0x211dc7a3340ddbf8, 0x6b591fbb040ad832, 0xff237d66125fe5c7, 0xde5dd095e27a655c,
+    0x6582234ac9d64b92, 0x3d16509cc4dabd1a, 0x8110b9714db55dae, 0x25b45df0699d2033,
+    0xbd04f870def9a0d0, 0xdd4a3286cf9fad73, 0x650aea16ba4b0d2a, 0x9afbfbf31b6764af,
+    0x26fedf8544206580, 0xe86160cb9f4c0470, 0x21c4f1e552498ac1, 0x115eef02773f153b,
+    0xa58303a749c20eb8, 0xba443a7c2a0fc03d, 0xd1c5b04c07210849, 0x6dfa2ba15f1fcf01,
+    0x603b396fbd7ed92a, 0x41d13e42c17e34bc, 0x36eec07d8dc1a62f, 0xf56168621c67a287,
+    0xdfb04af38e81e11b, 0xbada6a9cff6cfa72, 0x793b523f3ebfecce, 0x1f120888ccfccf73,
+    0xcbe6e066fc430a2c, 0xaa0c25d8a87819a6, 0xb02196b7c90f4252, 0x5864f8a58eaf1e62,
+    0xae61a4c0482f04a5, 0x6d0bbabd8042b5a2, 0x013a757e182a409b, 0x720c1b68c598a0d1,
+    0xaafff31e25dc9158, 0x14598942fd798f44, 0x3af3eced18b88290, 0xc1ea54e66901315c,
+    0xa513797d2f68b6cd, 0x409abffce2eff848, 0x6a7b0a1eebf50a41, 0x6b679632d41595f2,
+    0x2bcc5a97e2f8db6b, 0x4040782a394fa96e, 0x408934a38a36467c, 0x072f1fc16c6bd6fd,
+    0x696e4a5fb0535a87, 0x147d56c94b05fce6, 0x3d4475ef80ffa888, 0xec8ba07a6c1ac4c2,
+    0xd0b9acf8055e8ffb, 0x6430b1616aace300, 0x5c24b3e9ae09b2f9, 0x400a99bc7c73ee4f,
+    0xe620ffce55baacf9, 0x2f4c1d6d6c344a06, 0xff89d76844ec9c2d, 0x1a0ef49f144773eb,
+    0x567a82b1078343de, 0x756f532af14a2ac2, 0xf33556c45306ba3e, 0xc8fe15e64b702dc9,
+    0x7bc555f0998d2899, 0x6ac890f9d057dc43, 0xb890a576b7a498eb, 0x7b23f2a7b240c217,
+    0x2c9ed90042e80a4f, 0x172a465910916ce0, 0xf53b2901467a29b3, 0x48c427204053f248,
+    0xd56fee20cb48932c, 0x01ade5bbb5c42ed7, 0xd4817852e31ffd83, 0x8080a9f6adb1f022,
+    0xd972320d46021bff, 0xe966dcb007e4df0b, 0xa2e57aee6fad10f1, 0x6ae6208dfed44db3,
+    0x504c0e2de97c44a3, 0xdf99588588a3117a, 0x1bce1ddeef2f9033, 0xc2d53d9ce3fb968b,
+    0x583f38891c10caa9, 0x5cfefcb3d41a92d6, 0xd7e2f81c22571a5c, 0x0b247f3b29ead942,
+    0xf7eac2c7dae73d3d, 0x80a7c2785253cb54, 0x0703fbed4450e2e3, 0xb95fd66ebb0d0a1e,
+    0x17d32da7b1c764f3, 0xbd72a46555a465f6, 0x7242f5f551704e52, 0x879eebe527f178dd,
+    0x5462e476b7b5d52d, 0x103f868fd0920cc5, 0x41ad2fdc865b1789, 0x1d1c8c402257f04d,
+    0x91184b62e2d2685a, 0x66e64b5958a93253, 0x1a6c356f9f24ed29, 0xc0b6973c384324fc,
+    0xa8147ba3f0f6e8ca, 0x684d06510f5e023d, 0x84d400bc1aaf1511, 0x900a488d42fb5814,
+    0x9b36d271a0af0c44, 0x7ba405aab0ac39a2, 0xb9e303360414ec82, 0xafd55b6355f20f21,
+    0xb24f3ace7056ae69, 0xa785b03367393bf0, 0xf0baa0cc6039a873, 0x6c60b51aad790528,
+    0x330990b741348e1a, 0x649a4389806ff849, 0xdf19426116616e0a, 0xfeed43405357bd14,
+    0xd6867e032e229781, 0x5a6ae2a63b7a6dd9, 0x474ebd0a3ccc9c02, 0x4f1865acc7f64424,
+    0xe4d80b8293a48863, 0x9f3bf170216eb625, 0xd54e6e1ded9a96b8, 0x7a06ba6378a4971d,
+    0x58df2d2891668daf, 0xe3d641179b94ff61, 0xebf6cad8c8c8b3e3, 0x59922abecbd70c58,
+    0xe009f5f01ed61c38, 0x73640e4bc2cc0245, 0x5eb5548cfa6d8a24, 0xfe32ddca815f9b2c,
+    0xd66e194aca365700, 0xac44887f7a7c40c6, 0x65ee2d83bfcd0711, 0x1b1fca9d9a3f5ad1,
+    0xd55f71b9030a5677, 0x71ed33f11163e377, 0x7b0b1006d9c41aad, 0xbd5ae763cc0c774d,
+    0xb7ceaa0fe33a7b9d, 0x133c061cfc04b80c, 0xd1a389772ab84984, 0xbcbeaf63754a725c,
+    0xdd5518c09eab7a3e, 0xf45b5c3b6651abae, 0x8639df6729ec0739, 0x712ddcd2dffc4039,
+    0xa1ec4333454ffe01, 0x62ac5409d5157915, 0x2626fed7d6e6eb8a, 0x7ff6c3e3d73c4cc1,
+    0x3207f642021f0033, 0xe72fb688834b1f8b, 0xf0d2bb79040b8631, 0x37d7316c8174be78,
+    0x33f36d602958fad7, 0xd089a0cd68ed6cd7, 0x86e6cc1e8ed30897, 0x1f2b4fd543a94e33,
+    0x9de968848c73a4a5, 0xb9c4d8c74b93cdf7, 0x7ab51c8b7dd94c85, 0xb04c3bedc32107d5,
+    0x9d1c99249267570e, 0x4fa58cc56f245cc7, 0xceb4d5d0b07310f8, 0x50590b4c657646de,
+    0xbec9a1ce421b04e3, 0xf6325fceebcd0cb3, 0xb4a0562a3972e1d6, 0x2f940d33f1d90380,
+    0x262498fb37f9c004, 0xe62220cf3ebdfea4, 0xdce0ee8a9ba9694e, 0x007909652165e7ef,
+    0x5cc7080882a3049f, 0xf45455de54cf9db5, 0xc2ad79d9796726e5, 0xd1b2f7087dcf0701,
+    0x32cb6197ca59b977, 0x9a9ae60895d7a58f, 0x9105523d99f95c7b, 0xf80561c7c7b0e510,
+    0x875054dc903eb91f, 0x0fe4f22f35c17265, 0x805f315b3053516e, 0x51feef3d8d603e12,
+    0x81c15ca833fd71da, 0xfd8bbc08223a6604, 0xabb4e915230a60f9, 0x6751644a4767fc4d,
+    0xfd62a05c3c4dbc95, 0xbdda1fd3cc287de8, 0xc804936090ebfc03, 0x82f525dc92cb8d4a,
+    0x8579e330dc8879aa, 0x4dda4a2830680a21, 0x52d75093765015cf, 0x0b5c04070e0547c1,
+    0x1839b711193e64c1, 0x35905b92ba2e875c, 0x49a0eee14106d502, 0xc2e089115f13eb5a,
+    0xdcb17e5379b234bf, 0x6fdbbfe69362b453, 0x02a4e058cc756e15, 0xa581c0a5e922b3a6,
+    0x9d53caaebb6b1c1f, 0x44f8e481b82ef27c, 0xbb004357f03e0f13, 0x6e37df57c20940af,
+    0x877f0717d0243308, 0xac6ee5a9668864ec, 0x27fe6f1c82f03726, 0xe5aaa97a9cf53ec4,
+    0x27c11757ee4b6c07, 0x03ab82f5be653615, 0x1ee91ae3f839ec6f, 0x4f1dedfb5497e09b,
+    0xc630038a1dc64806, 0x1f0bd029f28ab6bb, 0xdc21a72cfb4e9a54, 0x4660ec5e27b5d906,
+    0x306e0980cc1581b8, 0xa543068d83f48753, 0x8ac36374427d47d7, 0xa8354b66e5080749,
+    0xcf8b8d873dd6581f, 0x1bb6e158d8cf49ee, 0x28fe52171cef54a1, 0xbbcf21754cdb3eff,
+    0xbc459b0968091f8e, 0x2e8f18da78841676, 0xb23112da0c189acb, 0x42b560cfaa120130,
+    0xaf0f8bab74fd7116, 0xd83d2c1cfee74aa7, 0xdca9c49c60f80abe, 0x710f0babd4f89024,
+    0x4d65b3629679e78c, 0xf78452a6d2b3f05c, 0x1ce9c0f0fe09ba54, 0xa5c3657a0750e521,
+    0x1bfb95e10a609d92, 0xcbb0256c1a500174, 0xb1cd0403f4031e8f, 0x1f7e1c0be492c528,
+    0x64a9b7dca955ca78, 0x779298611f7d8302, 0x941515149133ccd7, 0xd3167ff46d6121e5,
+    0x978e06ac1b7fd280, 0x6bcc435083b1d177, 0xbc177bb15aa47f38, 0xffcab470c14de3b8,
+    0x93d2c1c5c408a7a7, 0xbe22fa988298c47e, 0xc8b7eaf4e6a99567, 0xabf0511be6bc0a2b,
+    0x50591d87055b00a8, 0x47c328c207ae66a4, 0x8d7b45450b55ecde, 0x59915c8e850d77ac,
+    0x6dd5c6b8ffc1b055, 0x06f3537a7aea9d44, 0x8928b4a645fe9f3f, 0x86f7505907cbc439,
+    0x52fe7d8afc039993, 0x9bee100309db6a42, 0x7599ba7b4a92df92, 0x93bede17cc2e6f8b,
+    0xeb4ab53b9c74474a, 0x99308000ac5f50dc, 0x4854dc8acb959d5f, 0x8a105bf28394608d,
+    0x4eaee8d2e92f267c, 0xe441cbfc465defca, 0xb8305189224cc5e2, 0xf28abe601ee54e9b,
+    0x7df75ed42ffe4f12, 0xde5c63b4af33d772, 0x0e3f19db25575b79, 0x3752b78502f8a0e0,
+    0x795894698559f96e, 0x2cc2aae2ef88f859, 0x094d7ae9a76d9261, 0xcd94993d675c36a1,
+    0x53b02269fb53bab3, 0xaea8a1d56f03fa62, 0x6e0158dc1ced056a, 0xb140d593a54f0be7,
+    0xcc2953ef4eda6828, 0x81c30c921e996ae5, 0x9915703cbc42d935, 0xa83451c2e78aa14b,
+    0xed3a96f9d38acb15, 0x197992f70b69de3e, 0x88544b15c43931de, 0x7f62635a4a9f808f,
+    0x0561054bd43d1113, 0x98ceec14ab12598a, 0xf78b00cd9dd0ea9d, 0xd014f367c88f7a96,
+    0xa80ea6db003d48b2, 0x715c21b89d62b7e3, 0xba0c8e2993210220, 0x444f51b48cd197be,
+    0xed47aff3a0a25d20, 0xf27f4d9500a30e7c, 0x0b92d72c39801632, 0xf6a58cc7bf7cd064,
+    0x2b06b4612286b70f, 0x11be5f31ef0b3c89, 0xe0b598a061612e97, 0x76820ff32dc4e67e,
+]
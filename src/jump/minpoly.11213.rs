@@ -0,0 +1,47 @@
+[
+    0x0000000000000001, 0x0000000000000000, 0x0000000000000000, 0x0000000080000000,
+    0x00a0000000000000, 0x0000000000000000, 0x4000000000002000, 0x0000002200000000,
+    0xa200000000000000, 0x0000400000000000, 0x0001000020822000, 0x0020022800000001,
+    0x2a00000000000000, 0x1000100000002202, 0x0000002202228000, 0x222202a000040004,
+    0x2000010101000000, 0x0004400008222a02, 0x000a202a02220004, 0xa282a20010000010,
+    0x04000004440002a0, 0x010000028082a282, 0x02000a2a02080000, 0x2a020e4040040400,
+    0x9000000012020208, 0x0406028280228202, 0x828022a206c44000, 0x2302080001000202,
+    0x400004060a02102a, 0x000a02142a22460c, 0x8422a21280100010, 0x6684600404840282,
+    0x0101b003a2856282, 0x0a6a1c7202220029, 0x744066246a44443c, 0x0022b0002b0a381a,
+    0x40b782e582e50832, 0xa082c566662642c8, 0x2022010302209283, 0x620a64170a2c1a54,
+    0x0019281850242646, 0x81d02ab29082a285, 0xa2c2cc82edd480a5, 0x8222f080a0a18607,
+    0x016c3851032b8828, 0x0224282a244a6ff0, 0x3822a08307102f3c, 0x639b00e7e5d090a1,
+    0x86a187a6810828e0, 0x51813328a1a24030, 0x8022638ba04f7408, 0x21b508764e120058,
+    0x674854880920a2b0, 0xe262a2a26ce19d04, 0xa2aac08e31470503, 0x85f0564085b8990b,
+    0x17227c3a88666655, 0x2880a4139a945712, 0x230a649e175f1cb7, 0x43e500138508088a,
+    0x39866a93574a5dc5, 0x70526500248fb6de, 0x1019252d4c384430, 0x7952ba1fc4e3e9a6,
+    0x4ba0fd01cbbc2f28, 0x342c92a2a3871196, 0xbfe593eb175a35d5, 0x0f341334587a0fe4,
+    0x0a8690c5ae202573, 0xe180820fdd82445b, 0xd3e3cef0651cbe0d, 0x27a70f548e00fbb2,
+    0xff6bc6c9781f08ae, 0xddbeea9b47556061, 0x9c7132e6212ffd78, 0xed768f2f116b2646,
+    0xc42a4b58dc492d3d, 0x1d7425b9de60007f, 0x2d8b3633aa1bd85a, 0x4db1f5fb143c9c58,
+    0xe4cb1c16cb165034, 0x0607761d2831a365, 0x9f23e4b8ada2e9b0, 0x33fd32b978314440,
+    0xd8b933352038a56b, 0x767671575f383cf8, 0xb2792d30e37132cc, 0xc5d656c6a56b54c5,
+    0x0c8033ff4d2126aa, 0x37fd344d1dd99008, 0x78a70fb862985870, 0x80bf59af54f3212f,
+    0xd164a82a998d07bf, 0xda48ffbe1546071d, 0x6338e3f1aaac1ab0, 0x9c41c24691e719b0,
+    0xa05de11e802fc41a, 0xcc2803378306d864, 0x5ff72750b4b86b19, 0xca62942dd1f0fa60,
+    0x4b8c6edfeec28d48, 0x50e32faa2a058a9e, 0x06d05dc425bff3e1, 0xafbb1482a1289e8b,
+    0x77600eae8ce0c9c5, 0x3d020045c0128dd2, 0xe15a9756dacc98d4, 0xa470141db5731d3b,
+    0xf436921745457448, 0xd6044e5955654f97, 0xc399ff63e6568e3a, 0x32bc0cc04339c195,
+    0x0f36bb0b689dce1f, 0x3ffcc06d6235e1c3, 0x93d92c3cfba15371, 0x10d8cd9421eedef8,
+    0x0542740605546f09, 0x25d5819315fc673b, 0xbd01acd18819d306, 0x5c4dc0bcfb8f2e44,
+    0x383ea65605bdf172, 0xaccc7f261f087280, 0xe0b523c0508c64a9, 0xb1e3db6e97e76ba3,
+    0xca82113a670c1a5b, 0xc8bc538e736a5dd1, 0x68ac57ab9c5799b7, 0x1ec87a44e9b599cf,
+    0xc2e38f553cb7ccca, 0xdcbe43012a772970, 0x33c9fb53ecea7ab1, 0xfa094177d59a0425,
+    0x2207b4056fd1dd6e, 0x998542f1b2e50bb4, 0x30a5cf56576dc1f8, 0x5921e29400bf3515,
+    0xdaf6f994d510493d, 0xce271182295eb0d5, 0xcb64cc247ce6eb7c, 0xe3039ba9012b9f4a,
+    0x2399a9387cd7dec6, 0x3841fa1f8375d484, 0xa84c555df07c6318, 0x5ac5e831ff68815a,
+    0x45ecd298d021f1de, 0x80b001a51509763f, 0xbec353841c3a05d2, 0x1824d8fc8a93ca51,
+    0xa9e967d795ea8f41, 0xea3ba42757cd66df, 0x22bc3900ec0c33a7, 0x55061ee3eb2b5636,
+    0xedef9361b87fca95, 0x6091ecc8d0f3d18c, 0xa08d37c369691077, 0xf13ed537683f4edf,
+    0x552d700dbe2a21ed, 0x86b9466f74bc8a45, 0x1c1ef49e4d1734c2, 0x625c4f4a3be22f78,
+    0x7142607b5a2920eb, 0xb1193f666ca60f2d, 0x9cae3a00438282b6, 0x780b9bce207d9f84,
+    0xc361698190e7e686, 0x2b2248ebc1b0e1f2, 0x2c664423d388a02d, 0x04101e212f08200d,
+    0x21e302aa063c0810, 0xa222026244645020, 0x010001012001d302, 0x400005130a222200,
+    0x1008202002020404, 0xa000080010000004, 0x0000400000100020, 0x0000000000800000,
+    0x0000000000000001,
+]
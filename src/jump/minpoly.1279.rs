@@ -0,0 +1,8 @@
+[
+    0x0800000000000001, 0x488431882308c421, 0x2c2f488951440612, 0xc9946ea5e4133b8c,
+    0xaf1f05e78676cf9d, 0xb5a91c1719cf55c3, 0x5770203f6d5a3238, 0x26f6c94479ffdfb2,
+    0x86409ac8d1d964d5, 0x17dd7ca5993c1e77, 0x406a900b9fbf0309, 0x7db8c43cb0dcf6a0,
+    0x8aa07486bf1ded65, 0xd9b97a474cd7bb4c, 0x75c2cb8d701a0a01, 0x7b25ee68e18b54da,
+    0x3fa6ef12dca7fc36, 0x393b719c61a30d5e, 0xb3e75488053e8326, 0x0000000200c5b1ac,
+    0x0000000000000001,
+]
@@ -0,0 +1,519 @@
+[
+    0x0000000000000001, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000200, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000400000000, 0x0000000000000000,
+    0x0000000000000000, 0x1000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0800000000000000, 0x0000000000000000, 0x0000040000000000, 0x0000000000000000,
+    0x0000000000200000, 0x0000000000000000, 0x0000000000000014, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000001000000, 0x0000000000000002, 0x0000400000000000, 0x0000000000000000,
+    0x0000000008000000, 0x0000000000000000, 0x0000000000001500, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000800, 0x0050000000000000, 0x0000000000000000,
+    0x0000000200000000, 0x0000000000000000, 0x0000000000114000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0080000000000000, 0x0000000000000000,
+    0x0000011000000000, 0x0000000000000000, 0x1000000000000000, 0x0000000000000004,
+    0x0000088000000000, 0x0000000000000000, 0x0000000011400000, 0x0000000000000000,
+    0x0000000000000000, 0x0040000000000000, 0x0000000000000000, 0x0000000800000000,
+    0x0000000000000000, 0x0000000000000000, 0x8000000000000000, 0x0000004000000000,
+    0x0000040000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000410,
+    0x0020000000000000, 0x0000000000000000, 0x0000001504000000, 0x0000000000000000,
+    0x0001000000000000, 0x1000000000000000, 0x0000000000000000, 0x00000a0000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000500000000008,
+    0x0110000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000001040,
+    0x0080000000000000, 0x8000000000000000, 0x0000140400000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0002800000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000020, 0x0014000000000800,
+    0x0040000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000105000,
+    0x0200000001000000, 0x0000000000000200, 0x0010000000000000, 0x0000000000000000,
+    0x0000000008000000, 0x0000000080000000, 0x0000000000001100, 0x02a0000000000000,
+    0x0000000000000000, 0x0000000400000000, 0x0000000000000000, 0x1400000000008000,
+    0x0000000000000000, 0x0000002000000100, 0x0002000000000000, 0x0000000410100000,
+    0x8000000140000000, 0x0000000000000080, 0x1010100000000800, 0x0100000000000000,
+    0x0000000a00000000, 0x0000002000000000, 0x0000000000404000, 0x2800000000000000,
+    0x000000000000000a, 0x0000010000000000, 0x0000000000020000, 0x0000000000a00000,
+    0x0000000000000010, 0x0000000000000004, 0x0080000000004000, 0x0000041010000000,
+    0x0000004000000000, 0x0000000000000000, 0x0414000000000000, 0x4000000000000014,
+    0x0000028000000001, 0x0000080000000000, 0x0000000001100000, 0x0000000000000000,
+    0x0000000000000828, 0x0000000000000000, 0x0000000000000000, 0x0000000828000000,
+    0x0000000000001010, 0x0000000001000100, 0x0000000000000000, 0x0000501040000002,
+    0x0000400000000000, 0x0000000000808800, 0x1400000080000000, 0x0000000000001514,
+    0x0002800000000050, 0x0020000000000000, 0x0000000004000000, 0x0000000000000000,
+    0x0000000000002000, 0x0100000000000000, 0x0000002000000000, 0x0000082a00000000,
+    0x0000000000141000, 0x0000000000000000, 0x0000000040000000, 0x0040104000000008,
+    0x0041000000000000, 0x0000000002020000, 0x4000000000000000, 0x0000000000114050,
+    0x0a00000000005400, 0x8080000000000000, 0x0000011000000000, 0x0000000000000000,
+    0x0000000000200000, 0x0000000000000000, 0x0000000800000000, 0x0000a20000000040,
+    0x0008000014100000, 0x2000011051000000, 0x0000000100000020, 0x0010000000020200,
+    0x4100000000000004, 0x0000008208800000, 0x0000000000000000, 0x0000000011400040,
+    0x0200000000440000, 0x2000000000000008, 0x0000404000000000, 0x0004000000000000,
+    0x0000000828000000, 0x0000000000000000, 0x0000000000000101, 0x0022000000000000,
+    0x0a00001010000000, 0x0000045000000000, 0x0000004000000000, 0x1001000000808000,
+    0x0000000000000400, 0x000002a020000040, 0x0000000000000000, 0x0000001500140000,
+    0x8000000044400000, 0x0000000100000002, 0x0100000000000008, 0x0000000000000000,
+    0x00000a2a00000000, 0x0000000000000000, 0x0008800000004040, 0x2208000000440000,
+    0x8000101010000008, 0x011040010000000a, 0x0000100000000200, 0x0100000202200000,
+    0x0000000000000010, 0x0082a08000004000, 0x8000000000000000, 0x0000140005010000,
+    0x0000005450000000, 0x00000000000002a0, 0x0000000000022200, 0x4000000000000000,
+    0x0002a00080000004, 0x0000000000000000, 0x0020000001510000, 0x0800000000000000,
+    0x0014101000000828, 0x0041404000000880, 0x0040000000000000, 0x0000000000000000,
+    0x0000000000001001, 0x0220000000400000, 0x0020000000000000, 0x0010005041000000,
+    0x0000505000000002, 0x0000000000022000, 0x0000000082000000, 0x0000000000000100,
+    0x0282008000000010, 0x0002000000004000, 0x8000000054000000, 0x8000000400000000,
+    0x1410110000002080, 0x5151000000008000, 0x0100000020200100, 0x0000022200000001,
+    0x0000000000100100, 0x2000800040000000, 0x0800000004000080, 0x1010111110000808,
+    0x0040400000000000, 0x0000000000220000, 0x4000002082200000, 0x0000000000004040,
+    0x0008000000044400, 0x020000000050000a, 0x0000014501000000, 0x0000001000000000,
+    0x1011400000802080, 0x5004000000820010, 0x4800000000000050, 0x0000808000000000,
+    0x0008000010000100, 0xa000004010000000, 0x0000000500000020, 0x0014011000000822,
+    0x4040000000002014, 0x0400000022800000, 0x0000088000000000, 0x0000000001100000,
+    0x0200000000000000, 0x2000000014000802, 0x0000440000000000, 0x0004040000008000,
+    0x1150000800080800, 0x0100000082801000, 0x0000000000004140, 0x0202000000001012,
+    0x0200001040410000, 0x8800400400000000, 0x0000014000802088, 0x1510110080080000,
+    0x4010000000081510, 0x00000022a0000050, 0x0020822200000005, 0x0000000004000400,
+    0x0080000404400000, 0x0000001500000000, 0x0155511000000020, 0x0001000000200000,
+    0x100008002a080000, 0x400000a080140011, 0x0000008000004140, 0x0000000000144c00,
+    0x0000104001000000, 0x0041010100000008, 0x0001500002200800, 0x0011000008220000,
+    0x1400000202114010, 0x0000222800005454, 0x8080808000000100, 0x0000011000010000,
+    0x8000000000000000, 0x0000110000020220, 0x5050040000000200, 0x0400000880000000,
+    0x0080808080800044, 0x0000808814001011, 0x2020000001510110, 0x0000000114010000,
+    0x0010000104000202, 0x4104014000000000, 0x01100082a0808880, 0x1100000028080000,
+    0x0000000811401140, 0x0220280000444105, 0x8282000000011000, 0x8000400000400000,
+    0x0000044000000000, 0x0011000800000008, 0x4110000000020000, 0x0000000000000140,
+    0x80000200a0000010, 0x00808a5000101100, 0x0088000050000000, 0x8000005104400000,
+    0x1000010400008000, 0x0010400000080000, 0x1000028020202040, 0x0000000a00000001,
+    0x0000003504140015, 0xa020000044044540, 0x8001000001100002, 0x0100004110000080,
+    0x0000010000000000, 0x15040a0002220a00, 0x4040000000000000, 0x0008000000004040,
+    0x0808002000401000, 0x808a400010111000, 0x0000014001110000, 0x0000111010000200,
+    0x0040004002228020, 0x0440000002000010, 0x00820088a0884004, 0x0000080080000150,
+    0x00081c0415001000, 0x2020005400145000, 0x0000000111000280, 0x0000440400028280,
+    0x040040000000a800, 0x0102800080028000, 0x1000002808000014, 0x0000002001505140,
+    0x0a08220000140020, 0x8800001011140000, 0x0000404000400080, 0x0041104000000088,
+    0x0110500000880800, 0x4100000800801000, 0x0200082003400101, 0x000a202000014010,
+    0x0210001040104040, 0x2800500450500002, 0x0000015100020028, 0x0044100082008280,
+    0x1010000000280100, 0x42a0028222800000, 0x0002000800005000, 0x0000080454504004,
+    0x0022000015000000, 0x0000111015008000, 0x0150015110008088, 0x0114010020002000,
+    0x0016022088200001, 0x4000000000100000, 0x8080208900040401, 0x2820080001001080,
+    0x1880011010005000, 0x0040010040000008, 0x0001110000002828, 0x0500002002822020,
+    0x40000000a0004000, 0x2800000a00000410, 0x020888800040015a, 0x0008004441411000,
+    0x0220000100020000, 0x0011501140000280, 0x5040500000828200, 0x1540000000a00000,
+    0x1580808800004001, 0x0000008810000014, 0x2020002010005050, 0x0220000100110000,
+    0x0400001000140020, 0x4004000000002014, 0x0105040000202080, 0x4010080088280800,
+    0x0000002001100045, 0x02022a0000001000, 0x0008a00000041828, 0x0200440041004000,
+    0x2000050004000000, 0x114011500008002a, 0x0040100082080080, 0x4010000020000141,
+    0x4200888000001101, 0x0000001040010053, 0x20a8000004045000, 0x8000014051808888,
+    0x0110110015000002, 0x1010401000001d8c, 0x1105000020208050, 0x042002a00a220001,
+    0x0000802804004444, 0x0000008004510040, 0x80a8000000152000, 0x2054015050000008,
+    0x0000002000000022, 0x001514000a000222, 0x0140408000008011, 0x040000a000404100,
+    0x0080880840150504, 0x0088904000001148, 0x2800000041410000, 0x2001501110020820,
+    0x4011405100202000, 0x1010140200134000, 0x0100002820085400, 0x8080880a00800101,
+    0x0000211000404410, 0x0220a00001000000, 0x8a00000010600222, 0x5000445004000002,
+    0x0500044000000228, 0x1414800080202240, 0x0008800808801000, 0x2000202071500151,
+    0x8088020014010400, 0xa010000100130000, 0x8000050441000000, 0x01100194880020a0,
+    0x1100011028200008, 0x410400001b488100, 0x1200282a0a440000, 0x2280080200010111,
+    0x0028c04000444000, 0x0820044104440000, 0x8000001928000008, 0x015041010008000a,
+    0x0010000000022040, 0x1000020022222001, 0x0a80020082100010, 0x00a8285050005404,
+    0x0880801005010400, 0x9001010015810000, 0x0000005010000020, 0x10101400000000a0,
+    0x0011140800002201, 0x0440001720000011, 0x80202002c4400440, 0x8820000801150113,
+    0x2100004044401008, 0x2000010400000000, 0x00041a3c02200822, 0x4041000020008880,
+    0x10400000022a4000, 0x08080828226c0500, 0x0008880290001010, 0x202842410051040a,
+    0x8200000051000200, 0x01000052014000a8, 0x0404541400002812, 0x0190c00800a00808,
+    0x0414080080000150, 0x50001e2889001000, 0x200202d450155004, 0x00000201001013a0,
+    0x8800444044002082, 0x0401000400002000, 0x0516a40000028020, 0x5051000008208000,
+    0x0100000282700140, 0x0a002a2228000015, 0x0080809000141400, 0x20c1000004040840,
+    0x08000545000000a8, 0x0110111140008880, 0x0141050000289001, 0x025008002a0a0001,
+    0x1008002002214210, 0x0012220240104050, 0x000a585011141050, 0x8280011001532028,
+    0x0045404490808a00, 0x1100001000280108, 0x5290420000820020, 0x5402000a80800001,
+    0x0000022274004050, 0x0028202485001500, 0x0808990015108180, 0xd121015014401000,
+    0x0000010420202020, 0x0414010400a08202, 0x4405400480b00100, 0x140000a802088444,
+    0x380008080d000184, 0x0aaa891010001018, 0x0a4a400114014000, 0x2201101001222802,
+    0x4500442000800002, 0x000000002802c040, 0x4010000a00202c11, 0x030008000000014a,
+    0x000a026c01414045, 0x2822209100011000, 0x8099401155012280, 0x0844540044040000,
+    0x0d41440000a02878, 0x1410040000800080, 0x1458048038000110, 0x0000000210001050,
+    0x00200a2400310014, 0x0280001000141c36, 0x4240011140404002, 0x0104010522a00000,
+    0x0054081088008820, 0x0401002001108044, 0x12000a0202281000, 0x2008008020050844,
+    0x08206cc041000440, 0x282c010001100400, 0x9950114501080822, 0x0040014411008888,
+    0x4110000020286903, 0x4000008082a23003, 0x5600403840000011, 0x00a80a0c84405414,
+    0x8008800049805048, 0x09101100151d1500, 0x5001514040500a82, 0x1010002280020272,
+    0x4420008000220001, 0x0000000a84804000, 0x00000020a4d00044, 0x0000002004045010,
+    0x2254515000555120, 0x2803000011010000, 0x101504052c08220a, 0x450044400080a099,
+    0x04040020202008c0, 0x00008880a0140d01, 0x00d0b2c081001040, 0x28080b000040014d,
+    0x0a80200310405800, 0x4211401158134000, 0x0141005454020202, 0x0041220008085e50,
+    0x8110880000828100, 0x1000011000400050, 0x02222200a0000010, 0x0082020001551220,
+    0x70d0440050500202, 0x0380055044400028, 0x1404808500a00864, 0x0054010008289019,
+    0x2520200001012050, 0x0088828235000411, 0xb092802000150004, 0x8006054045040504,
+    0x88000910041000a0, 0x1100114101580008, 0x011140450a020900, 0x52200000086c4001,
+    0x40c0200002810001, 0x00484000c0004001, 0x08202c6800401404, 0x0082000045500008,
+    0x4350004040030080, 0xa000005010000242, 0x05014100822a0003, 0x0400004022901910,
+    0x0020002820885400, 0x0800001105000441, 0x1200810015000480, 0x02b06050005c1438,
+    0x2021140111200080, 0x001544131400008a, 0x5404050222a00015, 0xa4000002c4808141,
+    0x880100a089140157, 0x1100804000400040, 0x2208290000150100, 0x2024010052320820,
+    0x0040040160002020, 0x01401040002a6040, 0x0949180020480b28, 0x0100582a10190005,
+    0x20002b2202410140, 0x02a2a13150111310, 0x02020010000080e8, 0xa048540412143850,
+    0x0110005920a00006, 0x1444051500080a00, 0x10540a2209001000, 0x000000d420815154,
+    0x0000008901005280, 0x0080cc0044004486, 0xa4a0c00010444000, 0x2d04005401028020,
+    0x105051610080a0a8, 0x01140120a252d120, 0x5d1c020028032a21, 0x0014823019000400,
+    0x002022c844000400, 0xa8a9194404011020, 0x811011100098c800, 0x4341004905b04282,
+    0x000159202a000b22, 0x4532110002882201, 0x1006202240100050, 0x000a500801151014,
+    0x0200895108124040, 0x0045004480448a00, 0x2090000050101008, 0x4005161360802200,
+    0x5012440200a0020b, 0x1500480254d00804, 0x9500002001a20500, 0x1c82b91000048554,
+    0x0122005010400118, 0x8b35013031102000, 0x2414000488a40280, 0x84054001900282a0,
+    0x000120a8000ca2c9, 0x1911100800200104, 0x0eb0099290004045, 0x0a40220114011141,
+    0x02a9210b00500000, 0xcf40442044a06020, 0x4004001440400802, 0x4544414a00200420,
+    0x4344000022880bd0, 0x0008026441021044, 0x4220002322031151, 0x02b9500005000191,
+    0x02e05c0040041210, 0x9480440110600028, 0x141004988400408b, 0x0150101002022910,
+    0x102020201222e020, 0x111000a200030005, 0x0020029000004030, 0x4080819000150046,
+    0x8129078454000000, 0x40540854c0280800, 0x0401100000000046, 0x06432c0022002b00,
+    0x05404002a02db050, 0x4202448088404000, 0x2808a12a0d100504, 0x3940000504095150,
+    0x2342004401091812, 0x2010111070002908, 0x4000884042a0b980, 0x5414540000280251,
+    0x608000082a488401, 0x1008880041000549, 0x091a190000401115, 0x0201d01011101e98,
+    0x0b06814100000270, 0x442040c00a200083, 0x001540480002c264, 0x540500a084e00000,
+    0x4010822024bd5404, 0x027c811020540145, 0x2881282b10110522, 0x2020040108411608,
+    0x4d00000401543219, 0x00050430002028a2, 0x00801048e0b58c04, 0x01c40282a3809040,
+    0x000008214a010145, 0x0280003312045830, 0x4219000040145010, 0x0540140154400062,
+    0x8a00014048085400, 0x80d0c10b0000810b, 0x51401000004a6c50, 0x0140822022002000,
+    0x008028029a000776, 0x7820240054000046, 0x83a0075805400222, 0x0004000440150860,
+    0x004c011844b29028, 0x1411302001080260, 0x80040081948b2120, 0xa4028082a0110004,
+    0x0002218284814440, 0x8020291284052480, 0x9180004014411000, 0x0105014508081902,
+    0x0100501208640204, 0x60c1680222811988, 0x00488000406c0000, 0x4000046808200001,
+    0x822a001b00044419, 0xab38004001414490, 0x2033001010022248, 0x05004155136820a3,
+    0x4e041644b2902a00, 0x00302828088a700c, 0x950180508e408000, 0x0202032094010400,
+    0x008002202155443c, 0x001a16a504102020, 0xa000441500140288, 0x0441040000000291,
+    0x00540402c4400000, 0xc021108989398004, 0x00000042e4001048, 0x02202a0020010101,
+    0x8204981206341b00, 0x2200c440400420c2, 0x1b48854002202a0a, 0x000d1519606ca920,
+    0x8108543200220085, 0x60282b090b60044a, 0x41a010a890001011, 0x0000225001400020,
+    0x0002848151042c04, 0x0992655c4088000a, 0x0045150110008200, 0x41150818900a1820,
+    0x441300d452802004, 0x0404008b10820001, 0x88004440000066c2, 0x842080a080414100,
+    0x053c000445078000, 0x0050014044d08282, 0x0030000320080b20, 0x08101d062881203c,
+    0x0450100002200400, 0x24a008a2608cccc4, 0xb0408ccc08000420, 0x0330900140105901,
+    0x2a01024000a08680, 0x80745c4012000a00, 0x4530001042a8003a, 0x5104044000122000,
+    0x400a585000001843, 0x1080891882004104, 0x0047480090c4c205, 0x22a0008051410018,
+    0xd212060443804200, 0xd842440254804223, 0xc50003402a882208, 0x911e052401281400,
+    0x50800002288c0500, 0xa1290af084009110, 0x01a3880010112124, 0xa4b4010000700595,
+    0x0400c14584a68002, 0x3484548800008088, 0x1901000884002980, 0x0d98000010a08044,
+    0x084a420023085040, 0x4089008000500453, 0x4f00002046a04422, 0x2024081101068000,
+    0x804c050a50400428, 0x0344404402d22948, 0x001b500809282044, 0x5a0360312a000101,
+    0x8400022a07014194, 0x00ecf0088c861440, 0xcc88c00001602800, 0x35000010c0009500,
+    0x807941003e000310, 0x9550310002002000, 0x04000aa700138034, 0x0440001280a04036,
+    0x4240804000502141, 0x8508800450044000, 0x8090084480150800, 0x060910000500804e,
+    0x0445185006002a00, 0x650004c2962f2032, 0x0914288aa2004840, 0x017ca52a0c008104,
+    0x0002220d85095c40, 0x2be10a0092815184, 0x883010006138010b, 0x4000004540f15092,
+    0xdf01002a02400033, 0x40c04008080804ac, 0x1008094049007004, 0x1008190028411400,
+    0x5210123070091980, 0x0281014004570052, 0x042042a204220486, 0x0050010b808048a0,
+    0x10414504846000a0, 0x0044021f262c8804, 0x15088b9280f47164, 0x3c80280a01210411,
+    0x220204250c491207, 0xa302cc408514a400, 0x2004002420000068, 0x00005500a154c084,
+    0x0184a1008380b340, 0x411008010a01294d, 0x0a01502192401500, 0x0011002800134010,
+    0x1440147203411040, 0x814000044d485e50, 0x804080810181800a, 0x500011100040a018,
+    0x0145026022202210, 0x50823b0023120440, 0x28280488f0584202, 0x85a00ec801000151,
+    0x0204800544840471, 0x8220a0981434002a, 0x1501351121292942, 0x0054409010888013,
+    0xa0804222a0b72000, 0x0402014204a48c41, 0x09102116800400d4, 0x1180a00100580050,
+    0x022c620809158000, 0x410004045a4c4205, 0x4282440160c02280, 0x8149140008880200,
+    0x0450546828281404, 0x82b302a8c8844058, 0xa318a860404200d0, 0x8028a0210011510a,
+    0x0d010050a30231e3, 0xac089e5432042802, 0x01754000080a5082, 0xc54084d48a0002c1,
+    0x0010822016280000, 0x00a06200005d512c, 0x300b948214007510, 0xa0a0001314005607,
+    0x0422812115600011, 0x04040056c4008540, 0x800100819802804c, 0x0015800a88008040,
+    0x46302b002a1f0101, 0xf502218004101e04, 0x00c860c9204020c2, 0x04e8090001012c80,
+    0x814808244069f700, 0x828a45a28420028f, 0x645113080151882a, 0x00f0b0f80011a110,
+    0x4042203028000020, 0x204204a4bb152c02, 0x01120204408d400a, 0x0801041590440950,
+    0x12a9010140181020, 0x040547d402145214, 0x0002068e02834200, 0x11800a0822204e80,
+    0x94a0c0289084e901, 0x0684088c41528155, 0x18f0d12108d09264, 0x81210011110e8122,
+    0x5d08300439940000, 0x8c0082a4000226a0, 0x4400082002c86688, 0xb0e8dc04a8a11024,
+    0x02a0122000100940, 0x4b012b6105200100, 0x0000345016500822, 0x0032113006210039,
+    0x2305052002102062, 0x414f500010501038, 0x0004984283880044, 0x0000082088448285,
+    0x62b02a8220390019, 0x0000040043840210, 0xf472004a54c20001, 0xc100014128800a24,
+    0x8022452411000480, 0x10022400028e2954, 0x30082153102200b8, 0x8eb7001283100454,
+    0x8034202000244495, 0xc4a4614584004002, 0x2424508c400022c9, 0x1811085411103900,
+    0x0400008290a05081, 0x1c40020042021128, 0x05e83280a0000444, 0x0a0808004e626000,
+    0x60240804e1050d00, 0x880c814b40411470, 0x630208c482810200, 0x00014110010a30e4,
+    0x202170714002a111, 0x0224000227284080, 0x0820500826269600, 0x11c0c08310050440,
+    0x170828108540d48f, 0x0071748810401200, 0x174034750022e070, 0x1520523304120207,
+    0x0560429080160034, 0x440080000231c140, 0x802e802c04044044, 0x88180844c02c0015,
+    0x260923210411010a, 0x04610c5412143d00, 0x0088e482a1432080, 0x011524800a682620,
+    0x6168b55008a0010c, 0xa1000207a0080022, 0x23401a2aa2090093, 0x6020812005045018,
+    0x6028004040b0b901, 0x7538040202060033, 0x408545082240d4a8, 0x8040085058800586,
+    0x4052190035001514, 0x0210422001411000, 0x1a80230204400455, 0x0c2042600e024684,
+    0x0a840120010148a8, 0x9405518594f40060, 0x00fc827856200804, 0x041001b2a8d60101,
+    0x2896142a80120401, 0x402226a108002227, 0x034224240540b784, 0x20a5200404440822,
+    0x20001108f1a51100, 0x2a88800102801340, 0x15041a214040286c, 0x2200303000242510,
+    0x1619003b00115000, 0x1410080b29420564, 0x00a88084004d5100, 0xa8c0c10a0081840a,
+    0x120120111140a010, 0x0000a3303060020a, 0xda823e5420000d44, 0x608004a054280882,
+    0x92140a0083000150, 0x0206000000206660, 0x820aa03858370000, 0x8400050475182363,
+    0x00044295c4032500, 0xa4004346e0170000, 0x0412a100c5206462, 0x1910308024b00484,
+    0x91001b1091400058, 0x4320290102019017, 0x81a004001e400205, 0x6241ec0060847810,
+    0x012005050c800a22, 0x4135146860440808, 0x02e247a0088c8408, 0x1310a8412109024a,
+    0x5078003020015050, 0x2d200200222200b1, 0x0e2c125031044002, 0x8005440408027086,
+    0x9441145111c10200, 0x8041422016210800, 0x0380207030556220, 0x101f000510103525,
+    0x001d141500007413, 0x0029608225401391, 0x28840446c0408501, 0xc02110809c740004,
+    0x2111110e800892c8, 0x3220380002010803, 0xc667200004a41f00, 0x02a0022800404842,
+    0x5400092801006684, 0x000a00206254e408, 0x0208482101010205, 0x0444170909708c08,
+    0x43b0412141200020, 0x0102601209400060, 0x4008d43c51222c00, 0x10800780808d4503,
+    0x1c14140080440951, 0x1be0223150531000, 0x040007d052142210, 0x8404048c4411002a,
+    0x01010a000a00c442, 0x94258004000aa180, 0x7e88088425168530, 0x2053010088701252,
+    0x0131080090501002, 0x2a00202604c01e35, 0x80c4810440422420, 0x40041c6a220cccac,
+    0xa85009152400a004, 0x0620922140100841, 0x02412d412b288001, 0x8024b01006441840,
+    0x1230002004010339, 0x280223040514001a, 0x440f5d1010000813, 0x1204894500c0a084,
+    0x115808009064c200, 0x70a002100a2b2101, 0x0200042542812230, 0x542208ca5442567e,
+    0xc188002022c04028, 0x88226514d50f1100, 0xfc8900400208010a, 0x343d2bf284420030,
+    0x5027010600030440, 0x80b6210420244382, 0x4425016584200146, 0x2020848c4008c008,
+    0x8800185c0d103084, 0x3023901114001a12, 0x0c4e4200210b300b, 0x258c1551a0808460,
+    0x04000822c600c400, 0x20100800210e0105, 0xc804e5ca05203c69, 0x22090a4682c36108,
+    0x0800100040002845, 0x200340e418411101, 0x0100044620212288, 0x046cf000c0a01070,
+    0x0c50400001050004, 0x37080c18844000c0, 0x340967a0b8015210, 0x3110254001412050,
+    0x0030580604208002, 0x83c00110003c14a2, 0x4204804002308a56, 0x8050502000044404,
+    0x00980a5600010024, 0x160a022a25011011, 0x04d5880022282804, 0x208a4402e7044070,
+    0x002220d402208660, 0x4154e451011001a4, 0x00050300010a0822, 0x6ae1004420236889,
+    0x413020203410140f, 0x6000200002a27006, 0x0b294c2a03021031, 0x40850009c140543c,
+    0x8048881148800020, 0x5011110035158800, 0x5300100010088523, 0x0241200206450552,
+    0x0c0040a084020284, 0x0a00894301000000, 0x50080120b4e10055, 0x02d4827a0008d40c,
+    0x10005113a0f75020, 0x14c7140110030602, 0x404620012e283207, 0xe100c82081a0a100,
+    0x20002010000404a8, 0x00008000a0440d10, 0x29f2a3810291b140, 0x04001a1140402909,
+    0x48806022c0009100, 0x0511041112880000, 0x0040080428db4b04, 0x40e00006054d5e14,
+    0xa050200100830142, 0x020aa11110190818, 0x0800221080201318, 0xd0007c0646450c42,
+    0x408044a874780280, 0xd6040110430082a2, 0x5280000f08a44451, 0x0044201228090040,
+    0x00030301352d4950, 0x2004428265031011, 0xb482c342b1d52004, 0x0002450045244167,
+    0x8000204404800084, 0x110411039a081000, 0x0009002199611011, 0xc00086045e4c4210,
+    0x42c0440080045201, 0x01e9150406040800, 0x01303c5120591c00, 0x0260008105440008,
+    0x1351a060610302c0, 0x4521005100009040, 0xaa002122830211f1, 0xc420106c03000016,
+    0x010004040c831008, 0x8c400050014582c0, 0x2211423154080408, 0x03a000502029653a,
+    0x0038c00310022500, 0x0035000e0010008c, 0x0004c09fa7604111, 0x00860452c4400400,
+    0x800100a010564048, 0x9914101080080042, 0x220805001b160201, 0x84052e444402aa00,
+    0x46e822e960c06082, 0x5110150088a04084, 0x800918840051e805, 0x20027c098001120a,
+    0x0101071c02000040, 0x42a2e0114451b020, 0x4306251420002060, 0xa50054246b2b3442,
+    0x08c10080028d4003, 0x9c01190800008200, 0x10209c2570011000, 0x060742d502800000,
+    0x0400860046114202, 0x9000420048004406, 0xb490401810c08189, 0x0182844001028531,
+    0x38f2f06000d0a288, 0x0514020210401067, 0x2d3e120415831051, 0x80b4212000174aa0,
+    0x084418a804084420, 0xa8f91501f0a02000, 0x86b0150000104840, 0x514129202ab87383,
+    0x4341a00207600872, 0x0228080040882328, 0x6892212401140072, 0x478a500004000014,
+    0x0204000748084086, 0x1008001800448201, 0x40301a027201b8a0, 0x02e5400160812210,
+    0xf45244c254280603, 0x1080088400804d24, 0x9d08051480904011, 0x3cb1a0004342ad0c,
+    0x250801150040202a, 0xdb16106492020212, 0x8117600420004280, 0x042421649e9283e2,
+    0xc020028d04082281, 0x83080000882020d4, 0x9032208114014883, 0x894002100003110a,
+    0xc520470a00508646, 0x00000c0046002400, 0x6004001009e5e006, 0x6d0001ca05211458,
+    0x424602442888010e, 0x2000b118100630e4, 0x420150a340425110, 0x35240043068920b1,
+    0x28a15d0048240230, 0x9010048200000800, 0x54c80418a04001cb, 0x2021463aa2236a10,
+    0x200225250162c000, 0x0c00008200004447, 0x85b44110006200a8, 0x000080c00215ca16,
+    0x0056028850064201, 0x0409005640280014, 0x0602310987901000, 0x0001ae2032041700,
+    0x4542c420a4010d3c, 0x08880c840e6c6400, 0x0140a01051510080, 0xa101030f8d081150,
+    0x22470046202838a4, 0x0064f01020002420, 0x6000204842a1b980, 0x29045cba41280512,
+    0x0285450962084428, 0x0000880140c1cc20, 0x5459110198008109, 0x0200900031481536,
+    0x5002864104420000, 0x0c2040e00e02c206, 0x00d1490084820004, 0x000d09a594d40000,
+    0x42d4202001013008, 0x911891378cd42044, 0x0081147154170000, 0x60434bab28151605,
+    0x4d00402480340204, 0x74e00400080400a2, 0x00009848a0b59814, 0x03e09ae1a0111048,
+    0x04451a315a012828, 0x4080001046079002, 0x5511002002800800, 0x0040002928114978,
+    0x0b0901c402888000, 0x2050610b00800440, 0x41490190b0000409, 0x2962833012600202,
+    0x502a2c0105640900, 0x882041a410084044, 0x8285415d02000a08, 0x534bb20c141d0400,
+    0x0046000834302028, 0xf013011076000375, 0x2008608094881472, 0xa49a014001562204,
+    0x4512e54244202140, 0x802010c64c140284, 0x1100110201090000, 0x01010b2113618a54,
+    0x0640d4028aa00210, 0x42c1ac0020042048, 0x0100e0e400000c02, 0x4111145068000811,
+    0x2a2a00082c00c089, 0xab54ac00004144c0, 0x4511445120001139, 0x09362010134000e3,
+    0x4424063430046a13, 0x00400e5400ab3504, 0x996080109a844240, 0xba80400155c98c08,
+    0x0281020020014638, 0x001146a324022567, 0x0000000008040088, 0x000bc195815ed011,
+    0x80500202a0000400, 0xc020008104081002, 0x03809200041912c2, 0x3230160002000500,
+    0x86a00f2a00b0af22, 0x42a4800840042022, 0x155d80280808cc4a, 0x370410114000f80c,
+    0x000834b231221209, 0x0112471022240442, 0x02a0008884201251, 0x8004011727602068,
+    0x440a4428010420b2, 0x00c2e484c28d078a, 0x9100010900008200, 0x394136014de91800,
+    0x400202a102802010, 0x80020402004006e5, 0x800240044942e442, 0xd414008000504000,
+    0xa803e000e1068d1c, 0x0410204044802282, 0x4534080211490b42, 0x2014170200d51a14,
+    0x04b4109402720939, 0x0a4d54a820840424, 0xa000809820404400, 0x0481140140100142,
+    0x0801280002aa1202, 0x4720045206068810, 0x0600080000880111, 0x01140150e1120000,
+    0x028a284004000031, 0x0005000800036180, 0x901804085264c205, 0x52202200514001a0,
+    0x07c200a060a16000, 0xdc0044020028462e, 0xc50808085c08002c, 0x151c0000c51b1050,
+    0x841211026a012498, 0x7e74013084022000, 0x0082980034770100, 0x0154232200084480,
+    0x44a001458ab200c0, 0x2004528c02084000, 0x81091008880018c0, 0x351111910000180a,
+    0x888a021023021001, 0x050000000476c002, 0x4e00084644008000, 0x200400100802e06c,
+    0x0008e10a0544d040, 0x00444200a8d2230a, 0x6810314d28002c44, 0x520200e448404105,
+    0x10114a2801801011, 0xdce170080c200410, 0x8888404045000a54, 0x55089890a1408000,
+    0x800143283aa34111, 0xa442250218000000, 0x0c10088200024426, 0x0154810200000880,
+    0x0a44804002108195, 0x0008000072800200, 0x841947c480040004, 0x2400100d0190921b,
+    0x02d13a0024100200, 0x45028020b2066d42, 0x08004c0a00244c40, 0x0000f40108408460,
+    0x1152200904101140, 0x62610a0020001180, 0x88105444300264e7, 0x6602000002e00082,
+    0x01010838c3491111, 0x8ac5428cc0084404, 0x000808004841210d, 0x55b8280090098908,
+    0x1300122000211401, 0x02010466e2020002, 0x0d25800084020002, 0x005108c284088ca4,
+    0x10c0010890000080, 0x0280a03f02214008, 0x115dd80004f45044, 0x00d3000a44224012,
+    0x3240090056350202, 0x6102040004142011, 0x004044940a046d62, 0x10000142b0008094,
+    0x03a2b0e109101140, 0x54438b900a010100, 0x4800200006640c42, 0xa02800000eaa0800,
+    0x0040080009105510, 0x014122aa02880a40, 0x248000010180000a, 0x4009a1b01018ac18,
+    0xa000227002200010, 0x00283d0661060a30, 0x5878c02cb0504000, 0xc2010acc82410800,
+    0x7081918d9c080000, 0x022020001420103b, 0x7467070024546250, 0x0000408400889411,
+    0xa090834914356202, 0x4306c40244044141, 0x08001802a0048a50, 0x0800110333081050,
+    0x02200021105491c0, 0x4664aa028a080300, 0x8480040120002240, 0x80a8e00c86cc0804,
+    0x0110400028081411, 0x2ab1032984005040, 0xe2402c2041410080, 0x4048e40041500148,
+    0x81152732020200b1, 0x2c20045422103910, 0x408d4a04f4a2500a, 0x014090808a048000,
+    0x3081490414089000, 0x02b102000001432a, 0x001204c224624042, 0x0000000408105402,
+    0x000081141434800c, 0xa0220602884cc000, 0x0001008000021042, 0x8880125b1c080584,
+    0x2620020008140400, 0x91020bc002627000, 0x842420c1204020e2, 0x00ec004058004840,
+    0x1c0108800010b411, 0x200045a290312101, 0x044206d4a040082a, 0x02e0a08804714604,
+    0x8144087222228000, 0xe04a440c41012810, 0x0883008de300428e, 0x1101100000440801,
+    0x3001150415a82800, 0x2220028100000200, 0x00020202024146c0, 0x008804882807c880,
+    0x8080000810108888, 0x0703c0008100011d, 0x0430f00800809214, 0xc100406601585225,
+    0x2762020004940020, 0x040002b011456110, 0x460c5068000026a0, 0xa0a88010d040008c,
+    0x051a400010980142, 0x1801010102a23081, 0x06200c72014289b3, 0x0222200044000100,
+    0x01150030e0284000, 0x2c4a8004c0000018, 0x00000002008300ab, 0x304c3c2006400005,
+    0x122008000000a810, 0x43a0028242006000, 0x7470004200425403, 0x404045045c504d2c,
+    0x88000010911000c0, 0x101231105449810c, 0x2c50280000228000, 0x8a82104664062746,
+    0x0aa0022088204480, 0x440401408010c140, 0x84847201020c6680, 0xaa20005418000880,
+    0x1401011000011003, 0x1800010002023101, 0x40200200a400ab68, 0x0784000448600000,
+    0x2004080080a04484, 0xa800414001418018, 0x6009820002c0035b, 0x4045204460472c64,
+    0x220000e04002e110, 0x1631581065012624, 0x50e0000826040400, 0x0000044001a50644,
+    0x80809098a940818a, 0x0001422210c1401c, 0x2462200218229000, 0x0200501200020605,
+    0x0460800800600088, 0x000c800002114114, 0x00020020002360c0, 0xce00054048040010,
+    0x040a00088100104f, 0x0253442020041100, 0x00028002e40739e8, 0x540844004d60e420,
+    0x0000a10008e00040, 0xb140105401000008, 0xa3000a2a00000095, 0x0044240404064441,
+    0x020088c802a1e800, 0x09000810c30100ea, 0x2328028866040400, 0x0040090008018d8d,
+    0x11b0002128008002, 0x1200022021411505, 0x0000200021248100, 0x0005044004024200,
+    0x0a008808040044e0, 0x1045090980910000, 0x8280022806306000, 0x2054006570d42008,
+    0x0082002ac4114072, 0x0054742162006000, 0x0002240404003311, 0x540000a416004122,
+    0x10809142a0f00014, 0x022010c000008808, 0x28028a3111010001, 0x4001200004461c62,
+    0xe008484a02a02000, 0x040008290111402c, 0x01a080a924400000, 0x0404490100810000,
+    0x000801000048e000, 0x2342222001200008, 0x00026a0230640332, 0x9080057850200802,
+    0x82000a4400414800, 0x455c800880000000, 0x0202200000213000, 0x2402252210502230,
+    0x80806080d0031452, 0x0010c201008e4000, 0x8202e04404000140, 0x180008008c102220,
+    0x00001a0228200050, 0x02200b0011409b80, 0x82a0a96a10000205, 0x0041240020000040,
+    0x0008c44002c84004, 0x4911001124000008, 0x02a202386c001001, 0x0204494020010000,
+    0x0008441141000020, 0x30202204000000a0, 0x0404000421100001, 0x80ad6e5050a2240a,
+    0x90e0804100044000, 0x10c1010089801000, 0x0281000000014200, 0x0002044100422080,
+    0x080c000800005412, 0x000b4015000a4008, 0xa821644000088140, 0x4800008010001003,
+    0x0010004a42410500, 0x0220050400000a00, 0x82261a6c02702700, 0x0041002020c00042,
+    0x0044004008008080, 0x080008080010f008, 0x200084a330000010, 0x246a4140a1210802,
+    0xc0a0410104000600, 0x810008c2040000c0, 0x4004000001080010, 0x088184890228080a,
+    0x3800080000440800, 0x3960140019100000, 0x2102000000017200, 0x0402000400400288,
+    0x888044cc45454440, 0x80100000000a0000, 0x2f13e40041040910, 0x5000200080001202,
+    0x4114400220300000, 0x02000a0004d40a00, 0x8080019040443420, 0x62c9006824800404,
+    0xa0500014000080ac, 0x019a8110101088c0, 0x0100000101001081, 0x0600a802280a1811,
+    0x0028000044000200, 0x281000044400484a, 0x0400800081140031, 0x8001010000828029,
+    0x2045644444444801, 0x1120000008000000, 0x5380420160222200, 0x0020084000420229,
+    0x45c0080854c04058, 0x800a0010c50a0040, 0x00031140551c8080, 0xf800282090020020,
+    0x5006100224212566, 0x0e00000000a8c080, 0x0000014500108100, 0x0020022002004444,
+    0x2000105408000080, 0x0000110000000a08, 0x020000013502100a, 0x00044000a0a22942,
+    0x4d84444044000420, 0x200008000000c8c8, 0x0050014001000010, 0x200900000282214b,
+    0x2000204450416800, 0x082000e508004105, 0x0111441170202204, 0x80e024000c000000,
+    0x0d00000001a52a78, 0x01100010a0c00040, 0x0001450010800118, 0x2002200210001000,
+    0x0010500200200404, 0x0000000008280800, 0x0808815142100a80, 0x0000002022a14000,
+    0x0445404408008000, 0x000a000101100244, 0x1201200220001004, 0x00000002a2050944,
+    0x0800441041208020, 0x2800f41004400480, 0x1154114000000000, 0xa221010000228001,
+    0x00002400342e68a0, 0x04000080c0004007, 0x09014010c2080000, 0x02280a0404400000,
+    0x00400801088101a8, 0x1100000020080000, 0x0201415000288000, 0x4200202281060220,
+    0x4100400004000004, 0x0a0000488408c804, 0x0000010000110000, 0x0000022a04014410,
+    0x2074104020a02000, 0x00d2140144000202, 0x5015200460004008, 0x2400440080008111,
+    0x0000003032202022, 0x000088c000500c00, 0x015010c009000100, 0x2808110100000001,
+    0x0000200204448802, 0x4400480048000000, 0x0101140020880004, 0x00a022010c000000,
+    0x0140080100010102, 0x0001011000400440, 0x2222000000200008, 0x0002280001041222,
+    0x1050402824200000, 0xc205004400008808, 0x1408808000000800, 0x0010000200011051,
+    0x2425200220002200, 0x0088c00050020002, 0x1010820800004000, 0x8004040000000145,
+    0x0800180000000220, 0x0000004000001008, 0x0114000088001110, 0x8220014002200201,
+    0x0000240000040200, 0x0048400840044001, 0x0910001120080000, 0x82a0010104100080,
+    0x4040082020010000, 0x0541440000000002, 0x20004404002820a0, 0x4000004c01101110,
+    0x0020022880220400, 0x8840004100008000, 0x1080091001000080, 0x0010000000014510,
+    0x0020044000202080, 0x0800440000100202, 0x5000008800004004, 0xa001400008808141,
+    0x0801000010020002, 0x1100024004400000, 0x0000040008000000, 0x8005014412200220,
+    0x000024e0004000a2, 0x54440000800802c0, 0x080000002020a014, 0x0002540110110000,
+    0x200228a220010100, 0x4000410000200604, 0x8000040000008088, 0x0000000801011850,
+    0x0001008820280004, 0x2045000000088801, 0x0020880011000000, 0x0100020022015140,
+    0x0400000002000280, 0x0088cc0444000000, 0x0010000800000000, 0x0401441000000111,
+    0x0020800000002200, 0x400002202012d000, 0x0040000000801070, 0x8094011011000000,
+    0x0228226004000000, 0x0050000080000020, 0x0101011000888040, 0x00000101001a4080,
+    0x0400a82028000300, 0x4500000000200200, 0x2880000440080800, 0x0000800801150000,
+    0x0001000208020000, 0x2045044400000801, 0x0020080000001000, 0x4500520222000000,
+    0x2440004000200000, 0x0080080056500008, 0x0000000000004140, 0x1411111000008000,
+    0x2822000010000022, 0x5002002024042002, 0x0440000088804000, 0x000101441a808000,
+    0x0020202000048000, 0x0000000880000010, 0x8011111000000044, 0x020020011402000a,
+    0x4000020a00000000, 0xcd40440000200400, 0x2000000000400080, 0x0040000001010000,
+    0x00010000a0000054, 0x2000004440002820, 0x0020200000430000, 0x1511500000004400,
+    0x2240040008000010, 0x0000000001200228, 0x0110008800400140, 0x01014418a0800101,
+    0x2020000012000000, 0x0000020000000000, 0x0040000808004004, 0x0080015002000a00,
+    0x0002028000000000, 0x4044000000000010, 0x0000000002000044, 0x0202200000040000,
+    0x4000008000041004, 0x080044008020a400, 0x2820001050000080, 0x1140000000000000,
+    0x0101000200201004, 0x0000040420022820, 0x0000880040010000, 0x010050a880000440,
+    0x2000000008000001, 0x00080001000000a8, 0x0110002000400500, 0x0001400020280011,
+    0x0002800000000220, 0x4000000004004000, 0x004000080000c044, 0x0000010100000000,
+    0x0000000005110400, 0x005400a020800000, 0x0000147000000020, 0x0000000040040008,
+    0x0400000080140011, 0x0000002002202000, 0x0080004000000000, 0x0040888001100100,
+    0x0000020000000100, 0x0000000002008820, 0x4008000040050000, 0x0500002820000040,
+    0x0a00000008000000, 0x0000000100000000, 0x0000002000404440, 0x0000000000200000,
+    0x0000000111400222, 0x5000202804004000, 0x0005400800000000, 0x0000000000000800,
+    0x0002200014101000, 0x0400202020000010, 0x8000400000020020, 0x0088000000040000,
+    0x0000808000010004, 0x0000100280002000, 0x0000004004000000, 0x00000a0000001100,
+    0x0000000202200004, 0x0000040000000008, 0x0008800040440000, 0x0111000020000000,
+    0x0000001140000010, 0x0020280000000000, 0x0500000000000040, 0x0000400000280040,
+    0x0000001410100000, 0x0020282800000000, 0x0040000000000000, 0xa800010000000000,
+    0x0200200001000400, 0x000002a000000000, 0x0000440000000000, 0x000a000000000008,
+    0x0000000000000000, 0x0000000100000000, 0x0200004044000000, 0x0000000000000000,
+    0x0004110202200400, 0x2020044000000000, 0x0008800000000000, 0x0000000820005014,
+    0x2000141000000000, 0x2028280001000002, 0x4000000000004400, 0x0000004000000040,
+    0x00000001000008a0, 0x0002200800000000, 0x0045000000000001, 0x2800000010000000,
+    0x0002020000000000, 0x0400000000000000, 0x8800444000000000, 0x0000000000000000,
+    0x0510000000110110, 0x0000000000000000, 0x0020000000000020, 0x00000a0000541000,
+    0x0014100000000000, 0x20a0004000000000, 0x0000000000000020, 0x0110000000005040,
+    0x20000000000a8000, 0x0020080000000200, 0x4500000000000000, 0x0000004000000000,
+    0x0000000000000020, 0x0000000000000000, 0x0045400000000001, 0x0000000000000000,
+    0x5002020000000000, 0x0440000000004001, 0x8000000000002000, 0x000a000045000000,
+    0x1410000000000000, 0x8000000000020000, 0x0000000040002020, 0x0400000000544000,
+    0x000000000a000000, 0x2080000000008080, 0x0000000000000000, 0x0010000000000044,
+    0x0000000000020000, 0x0000000000000002, 0x4500000000000400, 0x0000000000008800,
+    0x0008000111000000, 0x0000000000500140, 0x0000000000200000, 0x0800004500000000,
+    0x1000000000004000, 0x0040000008000010, 0x0800000000202800, 0x0000000045400000,
+    0x0000000800000110, 0x8000000002000000, 0x0000000000000020, 0x0000000000004000,
+    0x0000000002000000, 0x0000000000000000, 0x0000000000100000, 0x0000000000000044,
+    0x0000000000000000, 0x0000000014050002, 0x00000000a0000400, 0x0000540000008000,
+    0x0000000000000000, 0x0000000000001000, 0x0000000020280000, 0x0000004500000002,
+    0x0000000000400000, 0x0000000008000000, 0x0000000000002000, 0x0000000000400000,
+    0x0000000000000010, 0x0000000000020000, 0x0000000000000000, 0x0000000880004000,
+    0x0001100000000000, 0x0000001504000000, 0x0000008000000000, 0x0050000000200000,
+    0x0000000400000000, 0x0000000000140000, 0x0000002020000000, 0x0000540000000800,
+    0x0000000001000000, 0x0000020000000000, 0x0000000002200000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000008000000, 0x0000000000000000, 0x0000000000400000,
+    0x0000000000000000, 0x0000110000020000, 0x0000000000000000, 0x4000000880000000,
+    0x0000000000000000, 0x0000000014000000, 0x0000200000000000, 0x0050000000000000,
+    0x0000000000000000, 0x0000800000000000, 0x00000002a0000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x8008000000000000,
+    0x0000000000000000, 0x0011000000000011, 0x0000000000000000, 0x0000000000000002,
+    0x0000000000000000, 0x0000005000000000, 0x0020000000000000, 0x4000000000000000,
+    0x0000000000000000, 0x0200000000000000, 0x0000028000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0001000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x1500000000000400, 0x0000000000000000, 0x0008000000000280,
+    0x0000000000000000, 0x0000400000000000, 0x2000000000000000, 0x0000000000000000,
+    0x0040000000000000, 0x0000000000000000, 0x0002000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000008800, 0x0000000000100000, 0x0000000000000014, 0x0000000000028000,
+    0x0000000000000020, 0x0000000000000000, 0x0000000000000000, 0x0000000000000020,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000005000, 0x0000000002000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000088000000, 0x0000000000000000, 0x0000000000400000, 0x0000000000000000,
+    0x0000000000020000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000800000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000002000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000001,
+]
@@ -0,0 +1,81 @@
+[
+    0x0000000000000001, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000020000, 0x0000000000000000, 0x0000280000000000, 0x0000100000010000,
+    0x0000000000000000, 0x00000000000000a0, 0x0000000000000140, 0x0000000a00000000,
+    0x1100001400000000, 0x8200000000000000, 0x0000000000200000, 0x0000000000540001,
+    0x0000800008280000, 0x0011400000000000, 0x00a0800000000000, 0x0000400000000000,
+    0x0000000400000040, 0x00000088000a0800, 0x1000004400000500, 0x000080a000000200,
+    0x4400001400000020, 0x0000002004400010, 0x5008800108000808, 0x0010000105500001,
+    0x000200a0a2200002, 0x0441000011008200, 0x0802804020810400, 0x011100880008000a,
+    0x000a040001054100, 0x2020000082a20805, 0x400040500015140a, 0x8820000810002804,
+    0x0009415808808808, 0xa00102a400010500, 0xc2800000a0a0202a, 0x000404440002d011,
+    0x0a8200020ad444a2, 0x0111040115028080, 0x028c170826050105, 0x1512280000828020,
+    0x4220144044141282, 0x8008200044880c20, 0x04d0501029419208, 0x2260a9a201342400,
+    0x42b011808200a0a0, 0x6043668044470047, 0x02800a0280028a42, 0x101448840d038108,
+    0x8a0249858605200f, 0x0614be1144282080, 0x982846067854480e, 0x005aa00480a040d4,
+    0x24915400e1456171, 0x41a8002841a1a172, 0x8236063b70e58020, 0x045a0e4302c2c14e,
+    0x1e1281a00c8a020e, 0x363f214518ba8948, 0x0271812261458186, 0x1cc00e4401222930,
+    0x2854d800a7263278, 0x68b8aa40a02c9855, 0x0373450904111bdc, 0x2600f3a0602350a0,
+    0x644f4c31b0bb80a5, 0xd20a1d4608ef2560, 0x8a0b4b9211312406, 0x82ae7517009f9982,
+    0x601756539a200074, 0x640a70122436867c, 0x8020484dc862a715, 0xe2e2e81b48d8b424,
+    0x72b122c30548ac38, 0xd2a3870790381012, 0xcb56e0eece1c70b7, 0x3502990347470682,
+    0xa8601f8b5c7411e1, 0x3775a12a833a30b5, 0x052143a0016f9a44, 0xce3b6a1212780c22,
+    0xc05c5070c11b954e, 0xa6b0b13223bc8d00, 0x26110291d7d998c0, 0x2097e7a161246d50,
+    0x8d4d25c4574d475c, 0x152e14187c8b1e6b, 0xda950b3fcb88e537, 0x835944751836d521,
+    0x2636a40253002240, 0xffef9c51964912a5, 0x7d4964adc523308c, 0x69f98f32aa726ab9,
+    0x47130b37425091dd, 0x401ab0ff24e21061, 0x9453c512e050cd4b, 0x1ac684510d88fa5f,
+    0xa16ca218b2933017, 0x5424cd6cea03afba, 0x3df8a93b3b286b75, 0x32873ba3471bc681,
+    0x5b798315ecd48145, 0xb45a9468ba2e3b9e, 0xd571d4457ecae4b2, 0xc9d63e3bd3bbfa43,
+    0x192bea7fa9441ce2, 0x79b6d1bcc6cfa705, 0xd63fc57efa82ca0b, 0xc839574ca64d7f35,
+    0xecf5868d70ee9058, 0x29f4a75568cf95db, 0x67a6382493eac127, 0xd196437f9f4a71cb,
+    0x1b3022c27d461c7f, 0xa6a567ce4085d0bc, 0xae311af7b7278a1e, 0xa48c600294a94bfc,
+    0xd624ca7a2f95b256, 0x560241615d847f18, 0xc6371879a520d42c, 0xd08d5f07d17e3abd,
+    0x3df9d3be7ad73124, 0xc33686612cb4cbfa, 0x2dbe79740e8090c0, 0x30a4a80f6d4c79ec,
+    0x5519d7912ce7f435, 0xc764fa909d0b2688, 0x27c655cfecc233f7, 0xe85987a8af20a5f9,
+    0xd411bc7314c8d5dc, 0x93899b016b45a3f0, 0x61f5d113c20b0df0, 0xb25da61e4a096903,
+    0x0dbe028d6d3567af, 0x9fa2ffe90c694a8b, 0xddbc8fc13fbb001b, 0xd4f0394b007675b1,
+    0x82a77db81439b4c5, 0xe3926b17cba15b02, 0x8c9459c774f90065, 0xc96951bd97a7280d,
+    0xd05abe912bca7f94, 0x60711d1a815f1c57, 0x042d25ce0d6cfd66, 0xe26807fc63178c4f,
+    0x7ce8a197b575c993, 0x40b7cd97348c4e6e, 0x4121abca0b44faf6, 0xe52018057e436e7c,
+    0xeee29d71348ff820, 0x5897af73be049411, 0x0a6fdc8a2abfe601, 0x9927489f06e9acb9,
+    0x212a9e204d2b3555, 0x726f34b152c7e23b, 0xba18032b9081e787, 0x1e6fd7621f8d4fce,
+    0xddc1ca0a680b74f2, 0x0b73fbbb3926fb78, 0x99f11bf5fbcb7c8c, 0xfa95b50d32e55b88,
+    0x898481c3f32feb9f, 0x0c5530801a0da142, 0xe8d7a917f97df770, 0x4875f816a8423596,
+    0xdbb428b030a50aa9, 0x0e3950a4612c5231, 0xe3e8182323c04d1d, 0x391f65dd70a31feb,
+    0xd0037d2ea87036c2, 0x585cb2a68d024115, 0x3ca80652b82e08da, 0x1222a69b8994a108,
+    0x4de6d9cdceae67bc, 0xddca8edabd55bf58, 0xf6a0757e4667e48e, 0x9b32d9f9b71a27e7,
+    0x40f2769f8f20f8f8, 0x45043e807c88737f, 0xb8ee0dd038f6f4af, 0x1484c5e77d62c435,
+    0x8dd2569dfa4d9131, 0x5f523ec999db3861, 0x3418fa6737e8b00d, 0x269f5801674ff9a5,
+    0x0cd977b54925f868, 0x0efe2aca2f5aac13, 0x56317da6a2f6b8c4, 0xe534d38250fa24dd,
+    0xdfa8dc9afeb39524, 0xf68b95bdbfe9f66f, 0xcd69cc6772132bd7, 0xb5b4dfded98e8544,
+    0x0387409dcb87d8d7, 0x8f0023832ffcb147, 0x2765011aafc4140f, 0x83081b652eca2bdd,
+    0x4d14a10e4b5b0ac3, 0x7c88af6e819ec2c9, 0x0e191e6f25748090, 0xd6495ebd110a22f4,
+    0xdbf1f3cefb3cbcdf, 0x9448bef759c292ca, 0xa5634a3ae4d4acfb, 0x7164a8c8c26ad6a4,
+    0x965e5a7cfb55c640, 0xdcf519a0992e424e, 0x8f610efdff342da1, 0xf9242248af2415d8,
+    0x10c4b695164603b8, 0x1e87d6082fa1757b, 0x7a57a7a99015387c, 0x286a730fd18197c4,
+    0x337303598db3d5d7, 0xfec20b20ffa6cb03, 0x420ebf29112f2932, 0x854a5d8b53939260,
+    0xcb1a14d9f27695a2, 0x70d1a3a726ac668e, 0xf1b6da4284c007a7, 0x72a04fdc5cb3134e,
+    0x2a3d847fe51d6b08, 0x3b3b804a91cea167, 0xc59263aa363cac3b, 0x034e799408af0885,
+    0x006262ed52a6fa26, 0xe0acc024778a11e8, 0xcd4d4ab18447afca, 0x576f160423a6c70c,
+    0x10631e8624500040, 0x02221f668cc007fe, 0x4b061c0105120745, 0x2b15ed7d4b520260,
+    0x20410d99d63883d1, 0xe3375e48c3b54b20, 0xcc86a05034ecdea6, 0xced1542ae91014a1,
+    0x622980024f61246e, 0x08b013659c68f806, 0xf5909002f128b242, 0x67d3234a7a8458be,
+    0x201ac293eeaa9176, 0x0cb848026d5fa140, 0x5c02883711114816, 0x1c518a7c4631ec3a,
+    0x164ab085407e6130, 0x00609822b1288189, 0x420e03588aad0882, 0xa0558040a144a900,
+    0x0054b1a8b0022848, 0x0a974810486c5464, 0x20406990422a4880, 0x04201d5a0c864f08,
+    0x00a14580208b518b, 0x2020d0b080740015, 0xc000b3323000a400, 0x13011049400a9948,
+    0x8348220c6a884c49, 0x91500a5781080941, 0x16a001b492002140, 0x00a480923051a804,
+    0x1b11001460854081, 0x010442001c20810a, 0x001a4d8101a30803, 0x4552001182b32021,
+    0x900000c8b61000a0, 0x4831008010402074, 0xa9d1000a00180808, 0x2040020c42038108,
+    0x80400040a0a03122, 0x448808048a111020, 0x0e8a1110001440a0, 0x0889100200080804,
+    0x2201120805400101, 0x2000000040888030, 0x0450880048841500, 0x0408801100800028,
+    0x00a8414002010808, 0x2220010280560201, 0x000000020000a804, 0x20050080000a0050,
+    0x01000a0000000000, 0x1100800400000008, 0x0022000000004020, 0x0000000000100080,
+    0x0000000000000004, 0x0800000000000000, 0x0010000040000000, 0x0000200000000002,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000001,
+]
@@ -0,0 +1,847 @@
+[
+    0x0000000000000001, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000010000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000002,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000020000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000004000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000800, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000200000000004,
+    0x0000000000000000, 0x0000000000000000, 0x0008000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000008000000, 0x0000000000001040, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x2000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000400000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0010000000000000,
+    0x0000002000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000080000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000010000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0004000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0100000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000800000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000800000000000,
+    0x0000000000000000, 0x0000000000000800, 0x0000000000000000, 0x0000000100000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000020000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000004, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000100, 0x0000000000000000,
+    0x0000000000000000, 0x0200000000000000, 0x0000001000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000040000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000200000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x2000000000040000, 0x0000000000000008, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0010000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0002000000000000,
+    0x0000000000400000, 0x0000000000000000, 0x0000082000000000, 0x0000000010000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000080000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000080000, 0x0000800000000400, 0x0000000000000000,
+    0x4000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000800,
+    0x0000000000000000, 0x0000004000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0020000000000000, 0x0000100000000000,
+    0x0000000800000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000800000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000100, 0x0000000000000000,
+    0x0000000000800000, 0x0000000000000000, 0x0000000000000020, 0x0000000000000000,
+    0x0000000000000100, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000004000, 0x1000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0001000000000000, 0x0000008000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000200000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000001000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x2000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000008000000000,
+    0x0000000000000000, 0x0400000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000200, 0x0000000000000001, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000001000000, 0x0000000000000200, 0x0080000080000000, 0x0000000000000000,
+    0x0000000000000200, 0x0000000000000000, 0x0000000000010000, 0x0400000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000080000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0080000000000001, 0x0000000000000000,
+    0x0000000000080000, 0x0000000000000000, 0x0000000400000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000010000000, 0x0000000002000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000400, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x4000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000002000000, 0x0000000000010000, 0x0100000000000000, 0x0000000000000000,
+    0x0800800000000000, 0x0000100000000000, 0x0000000000000000, 0x0020000000000000,
+    0x0004000100000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000020000020000,
+    0x0800000000000000, 0x0000000000000000, 0x0000000000020000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000020000000, 0x0800000000000000, 0x0004000000000000, 0x0000000000000000,
+    0x0000000000000004, 0x0000100000020000, 0x0000000000000000, 0x0008000000100100,
+    0x0000000000000820, 0x0000000020000000, 0x0200000000004000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000004000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000001000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x8000000000000000, 0x0001000000000000,
+    0x0000000200000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x8000000000100000, 0x0000000000000800, 0x0000000208000000, 0x0000000040000040,
+    0x0000000000000020, 0x0000200200000000, 0x0000041000001000, 0x0040000000000000,
+    0x0000008200000000, 0x0000000000000000, 0x0000000000000000, 0x0000008000000000,
+    0x0000000000040000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000010000000008, 0x0000200000000000, 0x0000000000040000, 0x0010000000000008,
+    0x0000000000000000, 0x0000000040000000, 0x0000000000000008, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000001001000000,
+    0x0010000008000000, 0x2080000080000000, 0x0000000040000000, 0x0400000000000000,
+    0x0000000000000040, 0x0000000000000008, 0x0400000000000000, 0x0000000000000001,
+    0x0000000000000000, 0x0000000000000000, 0x0000080000000001, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000400000, 0x0000000000008000,
+    0x0000000000000000, 0x0000000000400000, 0x0000000000080000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000080, 0x0002000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x2002000000000001, 0x0010000400000000, 0x0000000000002080,
+    0x0080000010400000, 0x0000000000000000, 0x0100000080080000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000002080000, 0x0000000000000010, 0x0000000000000000,
+    0x0000000002000000, 0x0000000000000000, 0x0000000400000000, 0x0000000000000000,
+    0x0020000000000000, 0x0000000000000000, 0x0000000080000000, 0x0000000000010000,
+    0x0000000000000000, 0x0000000000000000, 0x0020000000000000, 0x4000000000000000,
+    0x0000000000000000, 0x0020000000000000, 0x0000000000000000, 0x0000000002000000,
+    0x0000000000400400, 0x0000000100000000, 0x0020000000000800, 0x0020000000010000,
+    0x0100100000000000, 0x0000000000820000, 0x0000000000000000, 0x0004104000000002,
+    0x0000000020000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000100, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0200000000000000, 0x0000000000000000, 0x0000800800000000, 0x8200000000100100,
+    0x0004041000000020, 0x0000020000000000, 0x0000000000004000, 0x0001000000020020,
+    0x0000000000000000, 0x8000000004000000, 0x0000040000000800, 0x0000000000000000,
+    0x0000000000100000, 0x0000000000000000, 0x0000000000000000, 0x0000000100000000,
+    0x0000000000000020, 0x0000008000000000, 0x0000000000100000, 0x0040000000020000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000008000000000,
+    0x0000000000001000, 0x0000000000000000, 0x0008008000000000, 0x0000000000000000,
+    0x1000000000000000, 0x8000000200000100, 0x0000000001041000, 0x0040000000000808,
+    0x0000000000000004, 0x0000040041000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000001000000, 0x0000000000000008, 0x0000200000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000040000000, 0x0000400000000200,
+    0x0000000000000000, 0x0000000000000000, 0x0400000000008000, 0x0000000080000000,
+    0x0000000000000000, 0x0000000000000200, 0x0000080000000040, 0x0000000000000000,
+    0x0400000000200000, 0x0000000000000000, 0x0000001000000000, 0x0000000200200200,
+    0x0002082001000000, 0x0000000000010000, 0x0000000008008000, 0x0000000000000000,
+    0x0000000000000008, 0x0000000000000000, 0x0002000000000000, 0x0000010000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0080080000000000, 0x0000000400000000, 0x0000000002000000, 0x0000000000000000,
+    0x0000000010000008, 0x0000000002000000, 0x0000000000000040, 0x0000000000000000,
+    0x0000000002002000, 0x0080000000000000, 0x0000000000000000, 0x0000002000000000,
+    0x2000000000000000, 0x0400400400000000, 0x4000000000000000, 0x0080020000000000,
+    0x0000010000000000, 0x0000000002000000, 0x0800000010000000, 0x0000010000000000,
+    0x0000002000000000, 0x0000000000000400, 0x0000000000000002, 0x0000000002000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000010000,
+    0x0000100100000000, 0x0000000002000000, 0x0000000000000000, 0x0000100000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0100000000000000, 0x0000000000020000,
+    0x0800000000000000, 0x0000100000000000, 0x0000000000000000, 0x0800000000000400,
+    0x0000000000080000, 0x0000020000800000, 0x0000000000014000, 0x0000000000000002,
+    0x0020000000000000, 0x0000000000100000, 0x4000000000000002, 0x0000000800000000,
+    0x0000000000004000, 0x0104000000000000, 0x0000000020000000, 0x0000000004000000,
+    0x0000100000000000, 0x0000000020000000, 0x0000000000004000, 0x0100000000000800,
+    0x0020000020000000, 0x0000000000000000, 0x0000000000000800, 0x0000000000000000,
+    0x8000000000000000, 0x0000000000000000, 0x0000020000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000800000000, 0x0000100000104000, 0x0001000800000000,
+    0x0000008000200000, 0x8000000004000000, 0x1000000000000800, 0x0000000000004000,
+    0x0000000004000000, 0x1000000000000000, 0x0000008000000000, 0x0000000000000000,
+    0x0040000000020000, 0x0008000200000000, 0x0000000000000000, 0x0001000000000000,
+    0x0000008000000000, 0x0000000040000000, 0x0000000000000000, 0x0000000200000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000040000,
+    0x0000000000008000, 0x0200000000000000, 0x0000000040000000, 0x0000000000000000,
+    0x0000208000000100, 0x1000000000000000, 0x1010400000008200, 0x0008000000000000,
+    0x0000000041000000, 0x0000008000000008, 0x0008200000000001, 0x0000000000000000,
+    0x0010000000200000, 0x0000000000001000, 0x0000000040000000, 0x0400000000008000,
+    0x0000000000000000, 0x0000000000000000, 0x0000008000008000, 0x2000000000000000,
+    0x0000000000000000, 0x0000008000008000, 0x0000000001000000, 0x0000000000000000,
+    0x0000000000008000, 0x0002000000000000, 0x0000000000000000, 0x0000000008000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000200008000, 0x0000000000000041,
+    0x0000400400000000, 0x0000000008282000, 0x2000000000001000, 0x0000000410000000,
+    0x0000000082000081, 0x0002000000000040, 0x0000400000000200, 0x0000002000002000,
+    0x0080000000000000, 0x0000010400000000, 0x0000000000000000, 0x0002000000000000,
+    0x0000010400000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000080000,
+    0x0020000000000010, 0x0000000000000000, 0x0000000080000000, 0x0000000000010010,
+    0x0000010000000002, 0x0000000082080000, 0x0820000000010000, 0x0414100004002000,
+    0x0000002080000000, 0x0020000000410000, 0x0100000102000000, 0x0000000080000000,
+    0x0020000400010400, 0x0000000000000080, 0x0000000000000000, 0x0800000000000000,
+    0x0000004000000002, 0x0000000000000000, 0x0020000000000000, 0x0004000002002002,
+    0x0000800000000000, 0x0000000010000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0004000000000002, 0x0000000800000400, 0x0000000000100000,
+    0x0000000000002000, 0x0800000000000000, 0x0000000000000100, 0x0100100000000080,
+    0x0000020000800000, 0x0008000000100080, 0x4000000000080800, 0x0000000800000000,
+    0x0004000000004000, 0x0104000000000000, 0x0000000000000000, 0x0000100100004000,
+    0x0000000000000000, 0x0000000000000000, 0x8000000004100000, 0x0000000000000020,
+    0x0000000000000000, 0x0000004004000000, 0x0000000000000800, 0x0000000800000000,
+    0x0000000004000000, 0x0000040000000020, 0x0000008000000000, 0x0000000000100000,
+    0x0000000000020800, 0x0008000000004000, 0x0000000000000000, 0x0040000020000020,
+    0x0000000000000004, 0x0000100100000000, 0x0041000000020020, 0x0000200108000000,
+    0x0000000000000000, 0x0000000000800120, 0x8000000000000004, 0x0000000000001800,
+    0x0000000000020800, 0x0200008000000004, 0x0001000001000020, 0x0000000000000000,
+    0x0008208000000004, 0x0000000040000000, 0x0000000000020000, 0x8000208000000000,
+    0x0000000000000000, 0x0000000200000000, 0x0000000000000000, 0x0000001000000000,
+    0x0000400200000000, 0x0000000000004000, 0x0000000000000000, 0x0000000000200000,
+    0x8000000000000000, 0x0000000000000000, 0x0000200000000000, 0x0000000000000000,
+    0x0000040041000020, 0x0010000000000000, 0x0000000080040040, 0x0001001240000000,
+    0x0000000200200000, 0x0000083001000041, 0x0001041000000008, 0x0400000000000000,
+    0x0002000040040001, 0x0000000000000200, 0x0000000008000000, 0x0000000040001001,
+    0x0000040000000000, 0x0000000000000000, 0x0000000001000000, 0x0010000000000000,
+    0x0000000200000000, 0x0000000000001000, 0x0000000010000000, 0x0000000000000000,
+    0x0000000000000040, 0x0000000010008000, 0x0000000000000001, 0x0000000000000040,
+    0x0010010000000000, 0x0000000000000000, 0x0002000000040000, 0x0000010010000008,
+    0x4102080082002000, 0x0080000000400240, 0x0010010000000208, 0x2002000002000000,
+    0x2480000010000010, 0x0000400000000208, 0x0082080002080000, 0x0000000000000000,
+    0x0000010000000000, 0x0080000002000000, 0x0000000400000000, 0x0010400000000008,
+    0x0000000082000000, 0x0000000000400000, 0x0000090000080000, 0x0000000000000001,
+    0x0000000400000000, 0x0000000000080000, 0x0000000000000000, 0x0000400000400000,
+    0x0000000000080080, 0x0002000000000000, 0x0000000000000000, 0x0000100000000000,
+    0x0000000000000000, 0x0820000010410400, 0x0100084100000000, 0x0000802480800010,
+    0x0000000400012000, 0x0004000000080000, 0x0000000000400400, 0x0800000410090000,
+    0x0000100000000080, 0x0000000000010410, 0x0000000000000000, 0x0004000000000002,
+    0x0800020000010000, 0x0800000010000000, 0x0004000000000000, 0x0000800020000000,
+    0x0000004000000000, 0x4000000000000000, 0x0000000000000000, 0x0000004000404000,
+    0x0000000000000000, 0x0000800020000000, 0x0000000004004000, 0x0100000000800000,
+    0x0000020000000000, 0x0000004004004000, 0x0100000000000000, 0x0020820800010010,
+    0x0204000000100000, 0x0800000020820010, 0x0800104000000000, 0x0000000004004000,
+    0x0100800800000000, 0x0800020000000100, 0x0000000004100000, 0x1000020820000020,
+    0x0000020000100000, 0x0000004000000000, 0x0000020000000800, 0x0020000000000004,
+    0x0000000000000000, 0x0040000000020800, 0x0000000000000000, 0x0000000020000020,
+    0x0001000000000800, 0x0000000100000000, 0x0000000000040000, 0x1000000000820000,
+    0x0200000200000000, 0x0000000000000020, 0x0000000800004000, 0x0000000000000000,
+    0x0000000000040020, 0x1000020000000000, 0x0000000000004104, 0x0001040000040000,
+    0x0000000008900800, 0x0000208000100020, 0x1000040000040000, 0x8000008200028100,
+    0x0000000000000004, 0x1000000000000000, 0x000020000020000c, 0x8000000000000004,
+    0x0000001000000000, 0x0000000000800000, 0x0200000000004000, 0x0000001040000000,
+    0x0000000000000000, 0x0001000000000100, 0x0001001000000000, 0x0010000000000000,
+    0x0200000001000101, 0x0000000040000000, 0x0000000000200000, 0x0000000000001000,
+    0x0000040000000000, 0x0000000000008000, 0x0000000001000001, 0x0000040000000200,
+    0x0000008200000000, 0x2000081000000000, 0x0410041400208208, 0x0000208000040004,
+    0x0000080000001000, 0x0000010200200000, 0x0000000001000001, 0x2080000000041001,
+    0x0400000018000000, 0x0000000008000000, 0x2000000000000000, 0x0001400000000000,
+    0x0000008000000000, 0x2080000000040001, 0x0400000000000008, 0x0080000200000080,
+    0x2002001000008000, 0x0000000000000000, 0x0100000008000040, 0x0000000000040000,
+    0x0000000400000208, 0x0080080000080000, 0x0000000010001000, 0x0000400000200008,
+    0x0080000000000000, 0x0000000000010008, 0x0000000400000000, 0x0000000080000001,
+    0x0800030400000000, 0x0000480008080200, 0x0000000080080040, 0x0400410000000010,
+    0x0010000000000001, 0x0000080082000000, 0x0000000000010000, 0x0012000000080000,
+    0x0000002000000000, 0x0000000000400000, 0x0000000000002080, 0x0000000000000001,
+    0x0000000000010000, 0x4400000002080000, 0x0000002400000000, 0x0020000400400000,
+    0x0000004080000000, 0x0000800000000400, 0x0000000010010000, 0x0000004000000002,
+    0x0800000000400000, 0x0000000010010000, 0x0004000000002080, 0x0000000000000000,
+    0x0810000010000000, 0x0100000002000002, 0x0800000020000000, 0x0000000110010006,
+    0x4004100080000000, 0x0000800020800000, 0x0000000012180000, 0x0100000000002012,
+    0x0000020800000000, 0x0100000100000000, 0x4004000000002400, 0x0000800000000400,
+    0x0000004000000000, 0x0100000002000000, 0x0020820000000000, 0x0204000000000100,
+    0x0004020000082000, 0x0000800800000002, 0x0200000000000000, 0x4040020000002000,
+    0x0020000800400000, 0x0000000104000000, 0x0000020000002020, 0x0020800000000000,
+    0x0000000000100080, 0x4060000000000000, 0x0000000000000000, 0x0004000100102000,
+    0x0040040000000020, 0x0a00028000000000, 0x0100100000100080, 0x0041000000000800,
+    0x002c300000004000, 0x0100004824000800, 0x0000000000000020, 0x0200800000000004,
+    0x0000000000020000, 0x0000000800020800, 0x0000000000000100, 0x0004000000000000,
+    0x0000000000000020, 0x0000000000000004, 0x1001104000000000, 0x0040020000020000,
+    0x0000000000100100, 0x3000000001000000, 0x0000000020800000, 0x0200000000004004,
+    0x0000000001000000, 0x0000000200100100, 0x0000000000000104, 0x0000001001000000,
+    0x0000000000000000, 0x8000200004004000, 0x0000001000000800, 0x0200400200008008,
+    0x0048200140024141, 0x0001000001020800, 0x0000000000000200, 0x9008001000001804,
+    0x0040001040020000, 0x0000000008000000, 0x0000000040000100, 0x0001000000000000,
+    0x0000000200200000, 0x0000040000000000, 0x1000001000000800, 0x0000008208000200,
+    0x8000080000004000, 0x0008040008010020, 0x0000000200200100, 0x0000080000000000,
+    0x0040001018000000, 0x0000008000200000, 0x8080000000000040, 0x0000000000000000,
+    0x0200000200000000, 0x0000000000001000, 0x0010000000000000, 0x0008000000000000,
+    0x2080001040000000, 0x0001010010000008, 0x008024a280200080, 0x0002040000008040,
+    0x0000000400000000, 0x0000000208001000, 0x0000040001001000, 0x0010000000008000,
+    0x0080000000002000, 0x0080000000040000, 0x0400000000000008, 0x0000000200000000,
+    0x2000001000000008, 0x0410000000000000, 0x0000008000002000, 0x0080400041040000,
+    0x0400010000000200, 0x4000000002000040, 0x0000000001000400, 0x0010090000000000,
+    0x0000002000000000, 0x0000000000000000, 0x0410000080000240, 0x0002000000000000,
+    0x0000000000400400, 0x0000000000000008, 0x2080000001000001, 0x0020800010000000,
+    0x4000400080000002, 0x0000000082001018, 0x0000000400000008, 0x0000000100000000,
+    0x2002000000010000, 0x0000000400400408, 0x0010004000002080, 0x0000000010000000,
+    0x0800400400410000, 0x0400000000080000, 0x2000010000000000, 0x0800000010000400,
+    0x0000100002082000, 0x0080080020020000, 0x0800002000402000, 0x0100000000000080,
+    0x0000002000000000, 0x0000000004000004, 0x0000000080000000, 0x0000020000000010,
+    0x0000000000400000, 0x0100080000082000, 0x0000000020000000, 0x0000000000000000,
+    0x0002000080000000, 0x0000020000410000, 0x4008000004000100, 0x00040828b0080000,
+    0x0020000010000000, 0x0200000400100000, 0x4000820000080080, 0x0000020800400480,
+    0x0000004000000000, 0x0020000000082000, 0x0000800000000400, 0x8000100000100080,
+    0x0000000000000000, 0x0000020000400002, 0x0000100000004002, 0x0000000000000000,
+    0x0100000000000010, 0x8100000004000000, 0x1001000000000000, 0x0000000000000410,
+    0x0000004004000002, 0x0001000020000800, 0x0020000000000000, 0x0000104024000020,
+    0x1000000000820000, 0x0000000200000100, 0x0104004000020000, 0x0000820800000000,
+    0x0028008200000000, 0x1004000024041000, 0x1020800004820c14, 0x0008200200100000,
+    0x0000004100000000, 0x0000800900800900, 0x8000000004100004, 0x0000100000000000,
+    0x0000000800800000, 0x0000200000000000, 0x0000000001000020, 0x0000800000000000,
+    0x0000200000000000, 0x0000000000040820, 0x1000000020008008, 0x0208300000800000,
+    0x0000000040000000, 0x0000000020800000, 0x0001000004000000, 0x0000001000020000,
+    0x0010000008800000, 0x8200001000104000, 0x0041000020000020, 0x0000000000008000,
+    0x8000000000000100, 0x1000000000800000, 0x0400008008008100, 0x0008080000101001,
+    0x0008041808020020, 0x0400008000204000, 0x8000002000001041, 0x1001041000000000,
+    0x0008000000000104, 0x0000000001000000, 0x1200000000000000, 0x0000000000000000,
+    0x0000000040000040, 0x0000040000000000, 0x0000000000000100, 0x0002000000000041,
+    0x0040000000000000, 0x0008240008000000, 0x0080040000001000, 0x1040440400000000,
+    0x0080000000000000, 0x0002001001001000, 0x0000400400000200, 0x0000200008000000,
+    0x0000000041041008, 0x0400400400208000, 0x0000008000000000, 0x1003001001001000,
+    0x0000000200200000, 0x0000002002080001, 0x0000001000040000, 0x0000008401200008,
+    0x400000a000080040, 0x2000000000001000, 0x0000400208201000, 0x0002080000080000,
+    0x0000000000000000, 0x0400000000240000, 0x0080000080040000, 0x0000000000000010,
+    0x0400000000000008, 0x2080000080080001, 0x0020000000000400, 0x0010000000008002,
+    0x0000082000000200, 0x0000000000000010, 0x0000404000008002, 0x0000080040000040,
+    0x0020010400000000, 0x0000000000202200, 0x0080080080000000, 0x0000000000008000,
+    0x0000000000000000, 0x0002080000000000, 0x0400410010210400, 0x4400000000000002,
+    0x0000080080000000, 0x0812400000000008, 0x4000104000000001, 0x0000082000000000,
+    0x0410482000410000, 0x0000100000002001, 0x0080000000000400, 0x0000000000000000,
+    0x0100080000080002, 0x0000000820010000, 0x0000000010000000, 0x0104000000002000,
+    0x0000000000000000, 0x0000010000000000, 0x4102000002000000, 0x0000000810000000,
+    0x0000400510100000, 0x4000002080000000, 0x0000000000400400, 0x0200000100000002,
+    0x0000000000080000, 0x0000000000410412, 0x0000100000100000, 0x4000000000000000,
+    0x0020800c00400410, 0x0200100000080002, 0x0140000000800000, 0x4800000000000000,
+    0x8100000102080080, 0x0000000000000000, 0x0000820000000400, 0x4000004102082410,
+    0x0000000800000020, 0x0800000000010000, 0x0100000104000000, 0x1000020020020000,
+    0x0020800000000000, 0x4000100000000000, 0x0040820000000030, 0x0008008008400000,
+    0x0104000100000002, 0x0000000020010100, 0x0008000000000000, 0x4000000000001000,
+    0x0040000020100000, 0x8800008000000000, 0x0000000100000080, 0x0000020020020000,
+    0x0000008000000006, 0x0000000000000000, 0x1040000820020000, 0x0008104104004100,
+    0x2101000000040820, 0x0000000000008000, 0x0204008120800000, 0x0000101041000000,
+    0x0000000820000200, 0x000c100800004104, 0x1000000040800800, 0x0000000000200000,
+    0x8000000004000004, 0x0000040000020000, 0x0000020000000204, 0x8000000000000100,
+    0x0000001000000820, 0x0000800020000004, 0x0000000004000000, 0x0001040000020000,
+    0x0000000000004000, 0x0000000100144000, 0x1008000000000000, 0x0400200000000000,
+    0x8000000000100000, 0x1040001000000000, 0x0200000008000104, 0x0000080040000040,
+    0x1040000000000800, 0x8608008100108000, 0x8080081000000020, 0x0040001000400200,
+    0x1040000000000000, 0x00820c0040000801, 0x0001010400000808, 0x0000200208000000,
+    0x0080041000040800, 0x1000400000208000, 0x0200000208000000, 0x0040000041040000,
+    0x0001000408000008, 0x0000000200000004, 0x0000001000000000, 0x0040000218000008,
+    0x0008002000002000, 0x0000000000000040, 0x0000010008008008, 0x0000208000000001,
+    0x0080000000040400, 0x0000008200000000, 0x4002200002001000, 0x0000000001000000,
+    0x0008010048208000, 0x0000000002000000, 0x0000000000000000, 0x0210410000200208,
+    0x40000020410c1000, 0x2800000440400010, 0x0000010000008008, 0x2080001080200001,
+    0x0000040000010410, 0x0410000000008202, 0x0002082000000000, 0x0000400000000000,
+    0x0010000008200008, 0x0080000000000000, 0x0010000010008400, 0x0400000000000200,
+    0x0002000000000000, 0x0810400000000200, 0x0000000100000000, 0x0000000000001000,
+    0x0010000410000000, 0x0000000000000083, 0x0000080000061051, 0x0800000000400000,
+    0x0000102000000000, 0x0080002020000040, 0x0410410010400000, 0x0100000080000080,
+    0x0002020000010000, 0x0800000000000000, 0x0082000000000082, 0x2800000000800000,
+    0x0000000414100004, 0x2180000080040082, 0x0802020030000010, 0x0000410000400000,
+    0x0100080000080000, 0x0000000410010400, 0x0010000410000080, 0x0000000000002000,
+    0x0020000800000010, 0x0000000000002000, 0x0100000000080000, 0x0000000400010000,
+    0x8000000004182080, 0x0000002000800000, 0x0830000000000010, 0x0000000000002000,
+    0x0101002082000000, 0x0000820000000000, 0x0004000006080000, 0x1000000800000800,
+    0x0000020000000000, 0x4000002100082080, 0x0000000000000000, 0x0820000000010400,
+    0x8004000100100000, 0x0001800000810000, 0x0808000018100010, 0x4000080004000000,
+    0x0040800820400800, 0x0020800000100004, 0x0000100000000080, 0x1800820000800000,
+    0x0000000100000000, 0x0000004000000000, 0x1020000000000000, 0x0020204000004000,
+    0x0004100000000000, 0x1000000800020000, 0x0000000300004000, 0x4000000000040002,
+    0x0000000000000800, 0x0004204000104000, 0x4140000000040020, 0x0000000000800010,
+    0x0200100000000100, 0x0040000000020000, 0x0000000820000000, 0x0000104000004100,
+    0x0000040020800000, 0x0000800000000004, 0x0000200000004000, 0x0000020041020810,
+    0x0000200000000308, 0x0008000004000000, 0x1000840000000000, 0x000020800800c004,
+    0x0200000000000000, 0x0040040040000020, 0x0208000000000100, 0x8000000000100100,
+    0x0040000000000020, 0x8000008004204000, 0x0000000000104040, 0x0000000000000820,
+    0x8200008000200004, 0x0002000005000020, 0x0000000000800200, 0x8000200000008000,
+    0x0000000000001040, 0x0041400000c00200, 0x0000004208000000, 0x2001040000000021,
+    0x0000000020200000, 0x0000000208000000, 0x0000001000801020, 0x0000000000000000,
+    0x0000208000000000, 0x0001000020000040, 0x0400000010200204, 0x00080000000000c0,
+    0x3000000000800000, 0x0010010008000100, 0x0008008008000041, 0x0080000040000000,
+    0x0010000400000300, 0x8000008200040000, 0x0000000001000000, 0x0008400200000000,
+    0x0000200001080001, 0x0000001041000000, 0x0000400000000000, 0x0000000002040001,
+    0x0001000000000000, 0x0200400000000008, 0x0000081001000000, 0x2040010000000010,
+    0x0410000000000000, 0x0000000040000000, 0x0000010000000210, 0x0400000008000000,
+    0x0000082000000001, 0x0010010010000000, 0x0000000000200000, 0x00800c0000000001,
+    0x0830000008018000, 0x4000004180080202, 0x0001082000000000, 0x0020000218000000,
+    0x0410000002202083, 0x0082000000040000, 0x0810010200000000, 0x0000080000002040,
+    0x0002000001000001, 0x0000000000000000, 0x0002102002000001, 0x2000000000000000,
+    0x0000000010000000, 0x4082084002001080, 0x0800000840000000, 0x0000010000000200,
+    0x0006000001000082, 0x0800000000000000, 0x0000000010000000, 0x0080000000082002,
+    0x0000800000400000, 0x0000010000008080, 0x0004000000080000, 0x0000800010000610,
+    0x0000400000000000, 0x0100000082000000, 0x0810400800410018, 0x8100110004186180,
+    0x4004000000802001, 0x0400000000000000, 0x0204004104104000, 0x0000082000080000,
+    0x0400000000010400, 0x0000004080100000, 0x0000000020000010, 0x0000000000000000,
+    0x0004000000080000, 0x4002000000020400, 0x0000000000000000, 0x0000000000000000,
+    0x0080042020000430, 0x0000000400000000, 0x0000000100000000, 0x0040020000420c00,
+    0x0820010004004000, 0x0000104000000000, 0x0060000000000020, 0x0000800000000004,
+    0x0004104000000082, 0x0040000800000000, 0x0800004004004002, 0x0000000100080000,
+    0x1000810020020000, 0x8008308200002080, 0x4000000000021020, 0x0040000010820800,
+    0x8204008000080000, 0x0001000000040000, 0x0000020800000000, 0x800420c000082000,
+    0x0040100020800000, 0x0000020000020010, 0x4004000000000000, 0x0100040800000000,
+    0x0000000020800400, 0x0200008000004004, 0x5000021860000000, 0x0800200200200000,
+    0x0208000000004000, 0x0001040000000000, 0x0420000000000100, 0x8008000004140000,
+    0x0000801000000020, 0x0200000200100000, 0x0008000100000000, 0x0800000000000000,
+    0x0000200200000100, 0x8000000000100000, 0x0041840000020800, 0x0600204100000202,
+    0x8000042001001000, 0x0820001000800008, 0x0000100000008000, 0x8004001001040021,
+    0x1000000000800200, 0x0000004000000004, 0x0040000000000041, 0x0000040820000800,
+    0x0000000000200000, 0x1080000000840000, 0x1001000000028000, 0x0000000208000000,
+    0x3000080000060000, 0x0000010010000008, 0x0000008000100000, 0x0080000000000000,
+    0x0000010000000300, 0x0008000204101001, 0x0000000040000000, 0x0010200008000000,
+    0x8000000000000001, 0x0000001041040000, 0x0000018200000000, 0x0002000001001001,
+    0x0000000001040000, 0x8208010400008308, 0x0082000000082000, 0x2000040410040428,
+    0x8000000004108000, 0x6200001000000020, 0x0041000040000008, 0x8000000008000008,
+    0x0000041082000000, 0x1000001040008000, 0x0010400000000008, 0x0000000002000000,
+    0x0000010000210000, 0x0000000000000200, 0x2000080002000001, 0x0000800018200000,
+    0x0000200080080080, 0x1000002000001000, 0x0000400000010000, 0x0000208100000000,
+    0x0082002000000000, 0x0010000200000400, 0x0000088000080000, 0x0002082000000010,
+    0x0800200000400000, 0x0000000080000041, 0x2002002001000000, 0x0000018410000008,
+    0x0080184001001080, 0x0802001810011400, 0x0008000000400008, 0x0184000040000000,
+    0x00a2000030c10000, 0x0010000000008000, 0x0104100001040000, 0x0000010000000018,
+    0x0000000010000000, 0x0104000000000000, 0x2020400020000000, 0x0400000000000000,
+    0x0000000002080080, 0x0000000800000010, 0x0000000004000202, 0x0100000000000040,
+    0x0400000000000008, 0x4404000000000080, 0x4000000000001040, 0x0010020000010000,
+    0x4004000002000000, 0x0100080000000000, 0x0000800000410410, 0xc000002084000000,
+    0x0080000080000400, 0x0800000000400400, 0x8002002104005003, 0x0021020800010820,
+    0x0020800400000010, 0x8080012000000000, 0x00e0000000400800, 0x0000000010004010,
+    0x0002084000002002, 0x0801000800020020, 0x0000000000010002, 0x0000004000002000,
+    0x0040000400000800, 0x0000000010504080, 0x0002004100080000, 0x0000000020000800,
+    0x0200000604002080, 0x0000000080020020, 0x1000000000020000, 0x0000000000000000,
+    0x40400040c0020000, 0x0000420800800800, 0x0104000000000104, 0x0000002022000000,
+    0x0000020820800000, 0x0000200080000000, 0x0100000000000000, 0x0020800020800000,
+    0x410020a004000000, 0x0000040041000400, 0x080080080000c600, 0x4008000000100100,
+    0x0040001001010012, 0x0820000800004008, 0x8000004000000000, 0x0000040040010410,
+    0x0000820004000200, 0x0200000000000080, 0x0800001000000000, 0x0028200100000000,
+    0x0000100000000000, 0x0040001000000420, 0x0000000000008000, 0x0100000000000000,
+    0x0040040000000000, 0x0000100008200002, 0xc005103000000000, 0x1001040000000020,
+    0x0000004000000004, 0x0001000000020000, 0x1000040800000200, 0x0000004008000104,
+    0x1000000000001000, 0x0000040000000000, 0x000c008000000100, 0x1080000800020040,
+    0x0000418000020000, 0x0008024200000140, 0x2002020800801000, 0x1040000030200100,
+    0x8200000004000004, 0x0080020824800000, 0x0008000000004008, 0x0000000100000100,
+    0x2000000001000000, 0x0200200000000100, 0x8000008000001000, 0x2000001801000020,
+    0x820001000010c000, 0x0180004000080000, 0x2001000000020008, 0x0010000100008908,
+    0x0000000000000000, 0x2000000010000018, 0x0000000008200008, 0x0000000000080000,
+    0x2001000001808800, 0x0410000008000200, 0x0000000000020000, 0x0000000000000010,
+    0x0000008200008200, 0x10410c0000081000, 0x0001000008210000, 0x8400000080202181,
+    0x0001200080000024, 0x1010000000400414, 0x000820000a000000, 0x1009000001040000,
+    0x0000008018000004, 0x0000002000001000, 0x0000080080040000, 0x0000000210400000,
+    0x0200000000040002, 0x1080080001000040, 0x0000010008410000, 0x8000000000040080,
+    0x0000040000000000, 0x0000010000400000, 0x0000004040000000, 0x0041001440000000,
+    0x0010400010000000, 0x2000000000002080, 0x0082001000000000, 0x0410000000008000,
+    0x4000000001000002, 0x0800400000000200, 0x0000000418000008, 0x0005103001000000,
+    0x0010020000208218, 0x0010004002008080, 0x4000000008080040, 0x0400c00028001040,
+    0x0104010008080000, 0x0006080001002001, 0x0430820008000200, 0x4010002080100000,
+    0x0102080002001000, 0x0000800000000600, 0x0000080004000040, 0x0002000080040000,
+    0x0000820000600010, 0x0202080000000002, 0x0020000000000020, 0x2000800800400008,
+    0x0000080000000002, 0x2080040000010000, 0x0020000404000004, 0x0082100000002080,
+    0x2000000010000020, 0x0800810000004002, 0x0000400000000000, 0x0000000000000028,
+    0x0800000010000000, 0x4100000002080002, 0x0040410410000200, 0x0004404008000004,
+    0x0190180180082880, 0x1050002000020020, 0x0400008000002180, 0x0002000082020080,
+    0x0000000480000c10, 0x4000200000004104, 0x0004082000000000, 0x0000000400000010,
+    0x0000000080000104, 0x0180000000000010, 0x0000400000000400, 0x4008000006000004,
+    0x0000000000000010, 0x0000010010000000, 0x8000008000010104, 0x0000000001010010,
+    0x0800030610510000, 0x4008004002004000, 0x0020000000000000, 0x0020200000400000,
+    0x8000104000000080, 0x0001800000000400, 0x0200000100000000, 0x0008000000104002,
+    0x0001001000820400, 0x0010000008200082, 0x0004141004002800, 0x0005000440820430,
+    0x000010020020a080, 0x4100000004000801, 0x0100000800000000, 0x0004108308200000,
+    0x0004004000800000, 0x0800001020000810, 0x0000000000000000, 0x0000000020000000,
+    0x0001000800030000, 0x000c000108200180, 0x0080080820800000, 0x0020008000000000,
+    0x0000800208300002, 0x0000000021020050, 0x0820820000000000, 0x8204008000000101,
+    0x0080020800041000, 0x1008000010000004, 0x0200300000001000, 0x00a0000841000040,
+    0x0000010000004008, 0x0008200104000000, 0x0081040000040820, 0x8010400110008004,
+    0x0200901000000000, 0x0800060040000808, 0x8408404000004800, 0x0200001000002000,
+    0x1080000100800020, 0x8600000000100000, 0x0001000000000801, 0x2040001000000000,
+    0x0000010000120008, 0x0040002000000021, 0x1000000000000000, 0x0000400300008004,
+    0x4001080002001000, 0x0000000000000000, 0x041000000c004000, 0x0040000080000024,
+    0x0000800000200404, 0x0010008000104140, 0x1003080003000001, 0x0000000200000000,
+    0x0408200000200040, 0x1081002041000001, 0x0000208400000400, 0x8000008000040000,
+    0x3000080040000001, 0x0220010608110208, 0x2000000001002080, 0x1081042000001100,
+    0x8000000000000008, 0x20800040400c1000, 0x1200000000000000, 0x0000601200610200,
+    0x0100000003000000, 0x0000001000000208, 0x0000240000208000, 0x4000040040040082,
+    0x0000000000008000, 0x0200010408200000, 0x0004002001082080, 0x0000030020208200,
+    0x0008208002000000, 0x00000002800c2080, 0x0000000000008018, 0x4000608002004000,
+    0x0142080042082041, 0x0400020020210408, 0x0000002000000001, 0x0002080080000000,
+    0x0030008000208000, 0x0000000000004000, 0x20800020c0000001, 0x0010200400400000,
+    0x0000006100044001, 0x0000080000400400, 0x0010008008010208, 0x0000180001001000,
+    0x0080000000410800, 0x2400400000240008, 0x80040020050000c8, 0x0000000400020000,
+    0x0020010000000000, 0x0002084004000000, 0x0000010000000008, 0x0030410000000000,
+    0x4000006100040000, 0x0001000400800000, 0x0000000000000000, 0x4002100000081041,
+    0x1011010820400200, 0x0100000000000000, 0x20840040000000c0, 0x0000400400020410,
+    0x0100000202000000, 0x0014180000000000, 0x1000010400010c00, 0x0100000000100000,
+    0x0000002000000010, 0x1c00400000810000, 0x8000088280002000, 0x0000800800000010,
+    0x0822430000004000, 0x0102280000482001, 0x0000021000010020, 0x0000082000000000,
+    0x4202000090080182, 0x0800000001400810, 0x0000000000000000, 0x0008000000000102,
+    0x0020000000010000, 0x0000800000000002, 0x8200180000002000, 0x0801020010800010,
+    0x0000000008008082, 0x0300002082000800, 0x10200110008a0420, 0x0000000000000002,
+    0x8200100000000800, 0x0020000820420830, 0x0000004000008080, 0x0100000000800000,
+    0x0000000800020020, 0x0000100002000006, 0x4000000000001000, 0x0041820000820000,
+    0x4408000008180100, 0x1000002821800010, 0x0020008000080100, 0x0000804000006482,
+    0x0002000040020400, 0x4020000000000004, 0x0100100104000002, 0x0000801001000400,
+    0x0000200000100000, 0x0004008200000000, 0x0000001800801000, 0x0000000004000200,
+    0x0008008000000000, 0x0001000021000010, 0x0000200004108000, 0x4100000000000000,
+    0x0001020040800000, 0x0200004404000100, 0x0000040040000000, 0x3040001001040820,
+    0x0000000400300900, 0x0000000000000020, 0x1040024040820000, 0x0000400004100204,
+    0x00400400000a0040, 0x0001040000000000, 0x0110600300008000, 0x5022000022881000,
+    0x0000100200208000, 0x8200108a00000200, 0x0080080800000040, 0x0000000000000404,
+    0x0618000000a00001, 0x20c00408a0020000, 0x0000208010000014, 0x0200000000000040,
+    0x0041000000000001, 0x0008000408004004, 0x0000200000000000, 0x1082040060041100,
+    0x0208200008000200, 0x2002000000803000, 0x00c0000040800000, 0x0020008008010228,
+    0x0004000001000000, 0x20020c1400000100, 0x8010000010608008, 0x0000001000000002,
+    0x00c1000000000200, 0x0008200000000000, 0x2004000001000800, 0x0000000000000000,
+    0x8400000208008208, 0x4045100002020041, 0x0400010800004600, 0x9008018000080000,
+    0x1004000080042820, 0x0010800000210000, 0x0009000002000000, 0x00400480400c3000,
+    0x0020000020410208, 0x0000000000000040, 0x0004042000000000, 0x0010008000008000,
+    0x0000000080001000, 0x4042080202001000, 0x0800400e00000600, 0x0000000000001000,
+    0x0080000000000000, 0x0400020001010400, 0x0200000081100080, 0x0000000010010000,
+    0x0420400200400418, 0x2000080000100040, 0x0000000000010020, 0x2410410400218000,
+    0x0182000100001040, 0x0000010010000000, 0x0800010410000002, 0x01040000830c0040,
+    0x1000008820008a28, 0x0004100000080082, 0x04820c5080040000, 0x0010020010208000,
+    0x0000000000082102, 0x0080000000080200, 0x0410820400008009, 0x0000000080000000,
+    0x4080000000000000, 0x0000020000020818, 0x0100002000002000, 0x0100000000000000,
+    0x1800020800018000, 0x8004082001002000, 0x0000800800000200, 0x00a0020880000000,
+    0x4000008102083100, 0x0000000000000200, 0x0420800800000000, 0x000a008004010086,
+    0x0c00001000000010, 0x0810400000100000, 0x2000006082004102, 0x0001800000410800,
+    0x0800080000000000, 0x8184100084082002, 0x1840811010800000, 0x0080100104000081,
+    0x0002002000000800, 0x0020400001080418, 0x0000104104000084, 0x8000000400000020,
+    0x1000010410010400, 0x0100000000080304, 0x0000000010000000, 0x0000001012000800,
+    0x4000204000000104, 0x0000000000000000, 0x1040030800000800, 0x0008004100100000,
+    0x0000000000020000, 0x1800000000000000, 0x0000100000282504, 0x0080000000800440,
+    0x0000000000004004, 0x0300100308004100, 0x0000020000020010, 0x4000000000004004,
+    0xc104304104102000, 0x0020840000040000, 0x0000000004004004, 0x0108104004000000,
+    0x2021040051010850, 0x0208000102208002, 0x0000000000010020, 0x0122000800000030,
+    0x0008004008008084, 0x0800800040000020, 0x0860100040020000, 0x000801060020400a,
+    0x0020000000020801, 0x2064000120000000, 0x800400000c000200, 0x0000001000800040,
+    0x0040040000000000, 0x800820010c208000, 0x0002040020802000, 0x0000000200200000,
+    0x0000420208320000, 0x4800001000001820, 0x0000000200400000, 0x420800c200020040,
+    0x0000002003040801, 0x0000200000200104, 0x8208200000000000, 0x2000800000800800,
+    0x0000000000000000, 0x8000000020000000, 0x38a1000000020820, 0x0004410204404100,
+    0x0000000040000000, 0x0000001800000004, 0x0010110108524408, 0x0000000040081040,
+    0x000b000000100100, 0x040060010410c820, 0x0000040022001002, 0x2200000000004000,
+    0x0818000600400200, 0x2001000001000800, 0x0000000000004008, 0x0010610000200000,
+    0x0000000081040040, 0x0000000000000000, 0x0610001000000000, 0x1100082041020040,
+    0x0010000000000008, 0x8000000000000001, 0x00401400420410a1, 0x8000000200000000,
+    0x0001000002000001, 0x00410c12010c1000, 0x0010000030000010, 0x0000000000001000,
+    0x01c00410c1000000, 0x8008800200708014, 0x0080000002001880, 0x0000000208400000,
+    0x0208008858000048, 0x0000100081002023, 0x0000200200000000, 0x0208241000011000,
+    0x00400020400840c1, 0x0000008200000008, 0x0c00010008000000, 0x2002000001102001,
+    0x0000008010000200, 0x0010010000040000, 0x81000030c0003082, 0x0800000800408200,
+    0x0000000000080080, 0xa0011100810c0008, 0x0000200000000010, 0x00001000000c0000,
+    0x2010086140000008, 0x0001000800c00800, 0x00000000c0000080, 0x2002180000000008,
+    0x0000000600000610, 0x0000000000100080, 0x6180000000000000, 0x0c00410000400008,
+    0x0004083101081145, 0x0010800028010000, 0x2080800080000000, 0x4100004046140081,
+    0x0400020000000010, 0x0000400000000040, 0x40801880000c1008, 0x0420000010000000,
+    0x0c00000000000001, 0x0002104080100000, 0x0000020400020400, 0x0000080000000001,
+    0x0100086080000080, 0x0000000400840010, 0x0000000000000000, 0x0302000000400000,
+    0x0040440000018400, 0x0004000000000008, 0xc000000000400000, 0x0000040070880030,
+    0x0000000004002000, 0x0000000000000000, 0x0840010410400480, 0x4002004002004000,
+    0x0000000000000800, 0x0070800010c00000, 0x221200228e180084, 0x0050020400800810,
+    0x0000000000002080, 0xc080280110404000, 0x0000000800820c00, 0x0100000080080104,
+    0x8204088000002000, 0x0020801000801430, 0x0000000002084000, 0xc10400000e000010,
+    0x0800040800400001, 0x0000000002000004, 0x8000004000000000, 0x1802000c00020010,
+    0x0000200000200202, 0x0020000000000020, 0x2802800801060000, 0x0400000580000100,
+    0x0000040000010000, 0x3000001060000000, 0x0200100000200282, 0x0020000040020000,
+    0x4820800000000000, 0x0200000400280100, 0x0020000000000000, 0x0020000000000000,
+    0x451c100000100000, 0x0040081040442460, 0x0000000108000004, 0x8420000300000800,
+    0x00a1041021811050, 0x0100100200000000, 0x0000400000820000, 0x20000020410e0820,
+    0x0004104000004002, 0x0400000020000000, 0x1000840083840000, 0x0000000000100000,
+    0x0000000000000000, 0x00000e0000020040, 0x0000000008104018, 0x0000000000000001,
+    0x1082000000000000, 0x0000018310108004, 0x0002001000000000, 0x2000000000000100,
+    0x0020000018000008, 0x2000000000001020, 0x0000000000000000, 0x800001800410c100,
+    0x0001000001000001, 0x0200000000000200, 0x0010000008000000, 0x00808828008a2001,
+    0x8610000408100110, 0x8000041000000020, 0x30020230a1800003, 0x0000000008014200,
+    0x0001000000020020, 0x10871c2001000800, 0x8000008004000004, 0x0000040000000800,
+    0x0100001002000000, 0x0000200000300008, 0x0000000000000000, 0x0001002000000000,
+    0x0c1820890000001c, 0x00800000800000a0, 0x0000008000000000, 0x0000a10008610000,
+    0x0040000000020100, 0x0000000000010000, 0x0e08000410000000, 0x0000180000040080,
+    0x0000008010000008, 0x0008618000001000, 0x01000000400800a1, 0x0008008000000000,
+    0x0410000000000000, 0x5081000000040000, 0x8810000200018110, 0x00000000010c1000,
+    0x6000080040000008, 0x001000042040840c, 0x0000040041000000, 0x0080000000000000,
+    0x0000000018038e00, 0x0000000001000001, 0x0100000000000008, 0x0020600010210000,
+    0x0000000000002040, 0x0000000000000000, 0x0800420000000018, 0x0004000040004003,
+    0x0000400000000200, 0x0010c00000000000, 0x8004002004046040, 0x0400000000408000,
+    0x0800000080000000, 0x0002000002001002, 0x0000000000400200, 0x0000080000000040,
+    0x20840000000010c0, 0x0400000400200400, 0x0000000000000000, 0x4006080002080000,
+    0x0000030a21a00000, 0x0002082001002000, 0x0000000000010000, 0x0840c00c18000400,
+    0x0000000000002080, 0x0000000000000008, 0x0001070000400c00, 0x0002000002080100,
+    0x0000010000000000, 0x0420400000c00000, 0x0000004080004080, 0x0000000000000000,
+    0x0000000c00000000, 0x0380282082000084, 0x0000020400020000, 0x0000000002000000,
+    0x0000104080102000, 0x0040020050000048, 0x0000000002004000, 0x0600002004000000,
+    0x0020000020010400, 0x0000000002002000, 0x0000102000000000, 0x0000000040000070,
+    0x0000002000000000, 0x0004000000000000, 0x1463420000010000, 0x400000c004000000,
+    0x0000020000000000, 0x1800000070000000, 0x000000410a100100, 0x0800000010000000,
+    0x0000060000000000, 0x0004002200108200, 0x0000000000000400, 0x0040000000000002,
+    0x0108000102084002, 0x0000000000000000, 0x0000000000000000, 0x0204008100000000,
+    0x0000000000010001, 0x0000100100000000, 0x0108200000000000, 0x0000001000003810,
+    0x0000100000000100, 0x4000000000000000, 0x0002021002000000, 0x0100000000000100,
+    0x0000000000020000, 0x1800003000000820, 0x0000100000100100, 0x0000000000000000,
+    0x0003800000800000, 0x8008000208388000, 0x0000020040800041, 0x3800000000004000,
+    0x0210208300000000, 0x0020000000000000, 0x0000000000100000, 0x4000214000100300,
+    0x0000000800000800, 0x0000000000004000, 0x0304000100000000, 0x0000000020021020,
+    0x0000000004000000, 0x0200000000000000, 0x00000801e0820841, 0x0000000008004008,
+    0x0000000000000800, 0x0020100020061000, 0x0000000008000000, 0x0000000000000800,
+    0x0140000000800000, 0x8000008000008000, 0x0000000000000000, 0x1000041000000000,
+    0x0000000010008000, 0x0000000000000000, 0x0041000000000000, 0x0438708008010000,
+    0x0081000881001000, 0x0000008000200000, 0x8410000000000000, 0x1000000002040843,
+    0x0000000000004000, 0x8000010000000000, 0x3000001000000040, 0x0000008000000000,
+    0x0018000000000000, 0x0042040002060000, 0x0008000200000000, 0x0000000000000000,
+    0x2001043080000001, 0x0000008000000400, 0x0000000040000000, 0x00420c0000000000,
+    0x0400400200400a00, 0x0000000000000000, 0x0000000000000000, 0x0000000400600000,
+    0x0000000000040000, 0x0000000000008000, 0x0600400a00000008, 0x0000000040000040,
+    0x0000000000000000, 0x0000000000008000, 0x1002102080087080, 0x0010400000008200,
+    0x0800000000000000, 0x00040000c7040000, 0x0000008000010008, 0x0000000000000001,
+    0x2080100080040040, 0x0010000000600000, 0x0000000000001000, 0x0004000000040000,
+    0x0400000000008408, 0x0000000000000000, 0x6000000000000000, 0x0020008030008218,
+    0x2000000000002002, 0x0000000000000000, 0x0000058000018400, 0x0004000005000004,
+    0x0000000000000000, 0x0010000000000000, 0x0002000002000002, 0x0000000000000000,
+    0x0000010000000000, 0x0000000000002006, 0x0000000000000000, 0x0000000000000000,
+    0x0108100002006000, 0x0000400c00000420, 0x0000000000082000, 0xa104002000000000,
+    0x0000020410000010, 0x0000000082000081, 0x0002000000000000, 0x0000400000400220,
+    0x0000002000002000, 0x0004000000000000, 0x0000810410800000, 0x0000000000000000,
+    0x0000000000000000, 0x1000010c30000000, 0x0000000000000100, 0x0000000000000000,
+    0x0000030000000000, 0x0000100100000300, 0x0000000000000000, 0x0800000000000000,
+    0x0000000100382000, 0x0020000000000010, 0x0000000000000000, 0x0100100180000082,
+    0x0000000000030010, 0x0000000000000002, 0x0000000000000000, 0x1820040800831000,
+    0x0004100004002004, 0x0200000000000000, 0x0020000861410010, 0x0100000102000000,
+    0x0000000000000000, 0x0020800400010010, 0x0000000000000080, 0x0000000000000000,
+    0x0821000000000000, 0x0000004000000102, 0x0000000000000000, 0x1800000020000000,
+    0x800c008002002002, 0x0000800000000000, 0x0000000000000000, 0x0000000000004000,
+    0x0000000000000001, 0x0000000000000000, 0x001c000000100000, 0x0000001800000c00,
+    0x0000000000100000, 0x8000004100000000, 0x0800000000000001, 0x0000000000000100,
+    0x0000000000000000, 0x1001060000000800, 0x0008000008100088, 0x0000000000000800,
+    0x30c1000801800000, 0x0004000000204000, 0x0000000000000000, 0x0000001800000000,
+    0x0000100100004100, 0x0000000000000000, 0x0000000000000000, 0x8000000004304000,
+    0x0000000000000020, 0x0000000000000000, 0x0400004204000000, 0x0000000000000800,
+    0x0000000000000000, 0x0000008000000000, 0x00000400400400a0, 0x0000008000000000,
+    0x0200000000000000, 0x0000000040060800, 0x0008000000004000, 0x8000000000000000,
+    0x00400400e0000000, 0x0000000000000004, 0x0000000000000000, 0x0040080000000000,
+    0x0000200108210208, 0x0000000000000000, 0x0040000000000100, 0x8000400000600004,
+    0x0000000000001800, 0x0000000000000000, 0x020000820800000c, 0x0001000001000020,
+    0x0000000000000000, 0x0018600000000000, 0x0000000040000000, 0x0000000000000000,
+    0x8400000000000000, 0x0002002002000000, 0x0000000200000000, 0x0000000000000000,
+    0x4002005000000000, 0x0000400200400000, 0x0000000000000000, 0x2000000001040000,
+    0x0000000000200000, 0x0000000000000000, 0x0000000000000000, 0x0000200000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0010c20000000400, 0x0000000080040040,
+    0x0000000200000000, 0x0000400200600000, 0x0000083001000041, 0x0000000000000008,
+    0x0000000000000000, 0x0002000040040001, 0x0000000000000200, 0x0000000000000000,
+    0x0000000040083000, 0x0000000000000000, 0x0000000000000000, 0x0000000001000000,
+    0x0010000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000010010030,
+    0x0000000000000000, 0x0002000000000000, 0x0000020010018000, 0x0000000000000001,
+    0x0000000000000000, 0x0010010010000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000010000000000, 0x4102084082006180, 0x0000000000400200, 0x0030000000000000,
+    0x2002000002000000, 0x0400000010000010, 0x0000000000000000, 0x0082080002000000,
+    0x0000000000000000, 0x0000000000000000, 0x0080100000002000, 0x0000000400000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000801c00000, 0x0000080000080000,
+    0x0000000000000000, 0x1000001c00000000, 0x0000000000080000, 0x0000000000000000,
+    0x0000c00000400000, 0x0000000000080080, 0x0000000000000000, 0x0000000000000000,
+    0x0000100000000000, 0x0000000000000000, 0x0000000000000000, 0x810028c000000000,
+    0x0000800400800010, 0x0000000000002000, 0x0204000000180000, 0x0000000000400400,
+    0x0000000000080000, 0x0000000000000000, 0x0000000000010430, 0x0000000000000000,
+    0x0000000000000000, 0x0800020000010000, 0x0000000000000000, 0x0000000000000000,
+    0x0000800020000000, 0x0000004000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000004000004004, 0x0000000000000000, 0x0000800000000000, 0x0000000004004000,
+    0x0000000000800000, 0x0000000000000000, 0x000000400c000000, 0x0000000000000000,
+    0x0000000000000000, 0x0004008000000000, 0x0800000020820050, 0x0000104000000000,
+    0x0008000000000000, 0x0000800801040000, 0x0000000000000100, 0x8000000000000000,
+    0x1000020860000000, 0x0000000000100000, 0x0000000000000000, 0x0000020000000000,
+    0x0000000000000004, 0x0000000000000000, 0x0040000000000000, 0x0000000000200000,
+    0x0000000020000020, 0x0000000000000000, 0x0400000500000000, 0x0000000000040000,
+    0x0000000000000000, 0x0200400000000000, 0x0000000000000020, 0x0000000000004000,
+    0x0000000000000000, 0x0000000000040020, 0x0000000000000000, 0x0000000000000000,
+    0x00010c0000000040, 0x0000000008100000, 0x0000000000000020, 0x1002040000040000,
+    0x8000008200008100, 0x0000000000000000, 0x1000000040000000, 0x000020000020000c,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0001000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000001000003, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000003000, 0x0000000000000000, 0x0000000000000000, 0x0000000003000000,
+    0x0000000000000200, 0x0000000000000000, 0x2000001000000000, 0x0410000400208618,
+    0x0000000000040000, 0x0000000000000000, 0x0000010200200000, 0x0000000001000001,
+    0x2000000000000000, 0x0400000018000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000400000000000, 0x0000000000000000, 0x0000000000000000, 0x0400000000000000,
+    0x0080000000000000, 0x0000000000008000, 0x0000000000000000, 0x0100000100000040,
+    0x0000000000000000, 0x0000000000000000, 0x0080180000000000, 0x0000000010000000,
+    0x0000000000000000, 0x0080000000000000, 0x0000000000010008, 0x0000000000000000,
+    0x0000000000000000, 0x0800030c00000010, 0x0000080000080000, 0x0000000000000000,
+    0x0400410000000000, 0x0000000000000001, 0x0000000000000000, 0x0000000000000000,
+    0x0002000000080000, 0x0000000000000000, 0x0000000000000000, 0x0000000000002080,
+    0x0000000000000000, 0x0000000000000000, 0x4000000002080000, 0x0000000400000000,
+    0x0000000000000000, 0x0000000080000000, 0x0000800000000400, 0x0000000000000000,
+    0x0000000000000000, 0x0800000000400000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0800000000000000,
+    0x0000000100000006, 0x0000000000000000, 0x0000800000000000, 0x0000000002180000,
+    0x0000000000000010, 0x0000000000000000, 0x0100000100000000, 0x0000000000000400,
+    0x0000000000000000, 0x0000004000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0004000000000000, 0x0000020000000000, 0x0000000000000002, 0x0000000000000000,
+    0x0040020040000000, 0x0000000000000000, 0x0000000000000000, 0x0000020000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0060000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0200008000000000, 0x0000000000000000,
+    0x0000000000000000, 0x000c300000004000, 0x0000000820000800, 0x0000000000000000,
+    0x0200000000000000, 0x0000000000020000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x1001000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x3000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000200100100, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0200000200000000, 0x0040000040020041, 0x0000000000000000, 0x0000000000000000,
+    0x1000001000001800, 0x0000000000000000, 0x0000000000000000, 0x0000000040000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000040000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0008000008010000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000018000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0010000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0080042080000000,
+    0x0000000000008000, 0x0000000000000000, 0x0000000000001000, 0x0000000000000000,
+    0x0000000000000000, 0x0080000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000008, 0x0000000000000000, 0x0000000000000000,
+    0x0000400000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000080000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000080000040,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x4000000080000000, 0x0000000000000018, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000010000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000010000000, 0x0000000000000000, 0x0000000000000000, 0x0000010000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000002000002000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000004000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x4000000000000000,
+    0x0000000830000000, 0x0000000000000000, 0x0000000000000000, 0x0000820000000000,
+    0x0000000000000080, 0x0000000000000000, 0x0020000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000002, 0x0000000000000000,
+    0x0000000000000000, 0x0100000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000020000020, 0x0000000000000000, 0x0000000000000000, 0x0000000000020000,
+    0x0000000000000000, 0x0000000000000000, 0x1000000020000000, 0x0000000004000004,
+    0x0000000000000000, 0x0000000000000000, 0x0000000100000100, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000800000, 0x0000000000000000, 0x0000000000000000, 0x0001000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000001000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0008000008000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0200000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000040000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0080000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000008, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000001000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000001000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000040000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000200, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0002000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000082000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000002, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x4000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000410, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000100, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000100000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000020800000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000800000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0008000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x1040000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000040000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000200000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000001,
+]
@@ -0,0 +1,12 @@
+[
+    0x0000000000000001, 0x0000000000000000, 0xc420120404210021, 0x624a16bed5851401,
+    0xea02ac40a6e11b91, 0xde375e681c5aeab7, 0xa3b04000d39be304, 0x6040b8b458ae4e26,
+    0x616269952bbdb99f, 0x284a5c7975731afe, 0x8c4874a745f99f84, 0x6c50c918cd4ce8b4,
+    0x110ba3ffb9ef2a0c, 0x6eccc9c26278410c, 0x77e2801926a3d330, 0x117e59611e78bb96,
+    0x7c88d340c1ce3e13, 0x060b86dd2b185b4e, 0xef67be982df49896, 0x25c11a53b1c1479e,
+    0xc84e5b002dfe905f, 0x5f8a1330ec0c0d90, 0x193b15335d3da867, 0x4a2a0adfea80b8d5,
+    0xf3be9dae5cf68653, 0xb10817af19ec9c91, 0x1184a4799aebc10a, 0x2a93717c620eca92,
+    0xed88d872a69a42f1, 0x9474ce4c044a99d8, 0x22625192f6649e1b, 0x97a8c312584fc77a,
+    0xc188f7c851f51382, 0x06e750fd04f0614b, 0x0001f000f6001a88, 0x0000000000000008,
+    0x0000000000000001,
+]
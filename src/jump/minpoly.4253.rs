@@ -0,0 +1,20 @@
+[
+    0x0000000000000001, 0x0000002000000000, 0x0000060000000100, 0x0000242000000000,
+    0x000267200001a101, 0x001a1200001d2020, 0x078d10a001096120, 0x7a76d180153647a0,
+    0x7c0395204fa5fb21, 0xb7d7958f35e8b303, 0x2271c1748d747634, 0x391a464544e7d20a,
+    0xa74fb93cd9cb64e7, 0x03c2b3b26ca9c977, 0xd5a5c7aef82505b8, 0x3ca552f03076cb54,
+    0xf5139b552e9ff405, 0xb885a02beb03000a, 0x8a12a5039a712b02, 0x82c3bc394830accf,
+    0xfae0d3602863e545, 0x929d449a6370033f, 0xcc8e418a1f16ae05, 0xe9949cb56ab8e165,
+    0xecb1ea71b17da630, 0x4f53dc8707f8354d, 0x0364a037d0315ed3, 0x88a990a6995d21f5,
+    0x80ce159430d96615, 0x803af51e188ad3da, 0xcbfa96bd537ca10d, 0xf6ea56ff796d02ed,
+    0x539c8316021974d6, 0xc6bfe34c93a7e900, 0x53eea1c151b655ca, 0xe2875545396a234d,
+    0x0a63179877acc950, 0x8bb7cd0697559ceb, 0x3bdfdc9f7544654c, 0x0339c1f13794be26,
+    0xfcd2fe5257078030, 0x206403fa524518fd, 0x00e03848d81f761c, 0x6c144714249f119c,
+    0xf763f3fd887e3375, 0xb86b192e9a1c99da, 0xcc6182f43a4f591e, 0xe94d359837f17766,
+    0x7a7406bd0cdd1006, 0xab122453c8592afc, 0x0f76abe96313ff53, 0x5214422afcef0a1a,
+    0xdb6ab409d0e4cca3, 0x521398708e1f3245, 0x7ade6776d4bb6dd8, 0x696dee141c4430c0,
+    0x5331ea465bedafee, 0xf15a91ca3fd9651f, 0xf039ec56d0d96d15, 0x94ddc5f13e038312,
+    0xb17605ab8bd507c8, 0x484d2b017df6607b, 0x1688011ecf9040ce, 0xa80018a90a04285a,
+    0x4200a04242002894, 0x0000000420220000, 0x0000000002000000, 0x0000000000000000,
+    0x0000000000000001,
+]
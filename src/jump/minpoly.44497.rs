@@ -0,0 +1,177 @@
+[
+    0x0000000000000001, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000010000, 0x0000000000000000, 0x0000000000010000,
+    0x0000000000000000, 0x0000000000000000, 0x0000100000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000800000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0002000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000801,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0100022010000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000100000000000, 0x0000000000100080, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000020000140,
+    0x0000000000000000, 0x0000000000000000, 0x0000000100000000, 0x0000000000000000,
+    0x0008000400000000, 0x0000000000000040, 0x0000000000000000, 0x0400000000000000,
+    0x0000000000000000, 0x4000000000000000, 0x0000000400200000, 0x0000000000000000,
+    0x0000000000000000, 0x0000008001000000, 0x0000000000000000, 0x4000008004100000,
+    0x0000000000002000, 0x0000000000000000, 0x0000400000000000, 0x0000000000000008,
+    0x0041400000000000, 0x0400008004002002, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000400000040400, 0x0000008000400020,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000100, 0x0200404000000000,
+    0x0004000008000520, 0x0000000000000002, 0x1000000000000000, 0x0020000400000000,
+    0x1000000000000000, 0x0020005000809404, 0x0000000008004000, 0x0000000000000000,
+    0x0000000040000000, 0x0000000000020001, 0x3000010041400000, 0x0201000000000004,
+    0x0000000000000000, 0x0010000000000000, 0x0000200000000001, 0x0405000000000000,
+    0x1000204001490000, 0x0001000400801000, 0x0000000000010000, 0x0101000024000000,
+    0x0000000000400200, 0x0024100000405000, 0x5000031001000804, 0x0000000000000000,
+    0x0380101000000000, 0x0001002020040080, 0x8004040000000008, 0x0000200060804400,
+    0x0000000005420019, 0x0000000000000000, 0x0281010800280000, 0x0000000080000002,
+    0x0402345000024040, 0x0044080335000080, 0x0000000000000000, 0x0002081011000000,
+    0x00800110202a0000, 0x4000040501000000, 0x1154003000200904, 0x0000000001044080,
+    0x0004000000000000, 0x42022001000000a0, 0x50100010000a0040, 0x0100020055000260,
+    0x0014540a01274001, 0x0000000000000020, 0x0004000290110000, 0x4008200010002200,
+    0x0450800414001000, 0x4810584000100080, 0x0000000000035502, 0x2a00000000000000,
+    0x000402a081000000, 0x6300014011008220, 0x0000000090540000, 0x0000110006092304,
+    0x0000000000000000, 0x080000000a381000, 0x0000002284100028, 0x0345004004080104,
+    0x00c0301480001000, 0x0000004000000215, 0x2082a00000000000, 0x2800404200090001,
+    0x00620010c0011002, 0x40100100000c4005, 0x0020200104041931, 0x0010000000080005,
+    0x2000a00402803200, 0x0e00000022041000, 0x20024000d4041080, 0x1042011310010000,
+    0x0000200014000002, 0x0138022000000000, 0x0200010006808380, 0x0440600200604110,
+    0x3800310000001480, 0x0150000001404801, 0x0000100000000800, 0x003a082a0002403a,
+    0x0906010004200094, 0x0020030000044410, 0x0314050011208184, 0x0000000200114000,
+    0x2801100280200000, 0x0002800a40426003, 0x080065600230e0c5, 0x113a002050000008,
+    0x080115202035009c, 0x3002001210000000, 0x3440330022800004, 0x00929c0a10002800,
+    0x19400002000086d0, 0x0003c00101130a00, 0x0040000000201514, 0x03a0810002002200,
+    0x240003a0032402a0, 0x1000806c23404100, 0x5c41230821350000, 0x0008014150001000,
+    0x0078022010310000, 0x8032443900280a00, 0x005d10000225042e, 0x2095940013414246,
+    0x1141028806651014, 0x2000040010030240, 0x400300292002a006, 0xa0a7800328026012,
+    0x010c190d40025010, 0x0004012048316140, 0x0148000d01340020, 0x8284361062801310,
+    0x2242324430002002, 0xf002d0118a082e00, 0x0603101060824402, 0x0115000208802202,
+    0x022a004040152800, 0x22800780a1b80020, 0x400a077003012300, 0x040214d116020a50,
+    0x0002404039403821, 0x34011c2008114010, 0x080a483a11222830, 0x01240414423a002a,
+    0x424a027050038827, 0x0030019094a40b54, 0x0210100103088706, 0x8002068000c41140,
+    0xc0226003280018a2, 0x70008a27f0078100, 0x204042201419aa02, 0x1034003c00ab0029,
+    0x1016515022010920, 0x238410a2b4156062, 0x3c0228003c023340, 0xe452b8a066508902,
+    0x02020c424a0000c2, 0x080800340107d801, 0xaa0212024840cd14, 0x02ac028083a40082,
+    0x83300028464027e1, 0x250900a4b6950268, 0x540041405c083b44, 0x2d39346100022009,
+    0x4c29001922101960, 0x8008020f801e803b, 0x8177520422734140, 0x066a203480d024de,
+    0x000012c0110003d4, 0x2780000726940448, 0xed0178c2601b0840, 0x482a412088057025,
+    0x402408c480226909, 0x0130603404243820, 0xf6e1e1040218036f, 0x3244c08403b4340c,
+    0xde0020800a5432ca, 0x30202222ae105542, 0x004029802a122350, 0x0819201c14156507,
+    0x00ab800214781348, 0x05050319a6808100, 0x07e15000327005fa, 0x3da948a5880212a0,
+    0x0f51445040400040, 0x01ae012128206903, 0x880a58c4025a3830, 0x03670103ac040494,
+    0xc0b0c1fc200e8435, 0x1304028880589532, 0x1488091212515044, 0x08444a0108027017,
+    0xa805848bc940a049, 0x10258a5b2023c1c3, 0x7017039d3788474a, 0x0b431624291420aa,
+    0x10a0ac9371c00144, 0x08a82a1045405580, 0x5333ef0e3980880c, 0x03151070e8e0bdac,
+    0x400652824101c83b, 0x5250801c003c1508, 0x052d00d441408045, 0x720a0e6441872308,
+    0x0a76264ae1305262, 0x06013809fd42e1ac, 0x352f23422018d508, 0x001068a48c102321,
+    0x841c28828013c01d, 0xa63339762f6da4a3, 0x2af0125ea0bf7600, 0x0080131488c111a8,
+    0x65261034185340d0, 0x790122004c000706, 0xe367e808c8814303, 0xc2cec14d204b51aa,
+    0x58a40304021e8bbc, 0x614c042402020190, 0x1425043866486651, 0x8a0d6a708f8452c0,
+    0x4aa5981eebac4e08, 0x2210ab2715aae312, 0x0009200744c88018, 0x027b0758320c2018,
+    0x41490d0721d9d621, 0x501455c08e604732, 0xc90c029e9002cfb1, 0xd458881308eb513a,
+    0x1921402644021220, 0x101c0240702ea819, 0x40012d0721a814a4, 0x1cc62e53920b3504,
+    0x5141b38c00948ce8, 0x40d0011012449000, 0x0e06578335161c00, 0x20d5d91a092b0084,
+    0x680b08933406a6a1, 0x2318163c38ff7a44, 0x2384512a55304317, 0xbdc560720701521b,
+    0x87130091458c60f2, 0x735ca95ef11a8d58, 0x2a9200c4d21fc195, 0x8c01d8a302d71986,
+    0x021c8548b00545c0, 0x92b0a2868952420b, 0x8d9a2de15c01126b, 0x41e1a01445248c27,
+    0x3c41c8042aa8265b, 0x2d06c0748a511f09, 0xe8101561130f6547, 0xc8a7e189fc02082c,
+    0x665f2de160a4af0b, 0xc0dae6831a2991f0, 0x1a1801b0147e82a1, 0x1b32e09054501140,
+    0x2310800267891066, 0xc41d2ad5705e7250, 0x03635863084ef5af, 0x814f1311c62aa8ec,
+    0x4e6d53d72d217004, 0x2e3c14c4f4231426, 0x279087491c650568, 0xf213dfe2e801e3e8,
+    0x7552eb504afe49a0, 0x12808983b0109bc7, 0x574e513800cd9421, 0x1d6bb531f6c9f1d2,
+    0x745445abae454842, 0x8123e0226421184c, 0x1418098c4d1b0788, 0x4243db5453240254,
+    0x4f269b510da56c1c, 0xab34d4240d8d5b54, 0x398983ec9fe941f6, 0xfe90b1a1c0268eed,
+    0x084092bec191220d, 0x1d635e4140c0cb81, 0x549a31a3148b411c, 0xb6cb24d189961556,
+    0x158569ed4a27ea1a, 0x2c0d502197e00184, 0x0f4688385a53472a, 0x51351cdb0550b6d3,
+    0xb1b0359b861c81d9, 0xc960a923baf099cc, 0x79e29833c21c210e, 0x8d690108886084a0,
+    0x3250212930dcc04b, 0x5428cd5fb790dae0, 0x0f24916001110e65, 0x01504d6bcf5232c1,
+    0x081e84522e484a01, 0x992223010ccd5706, 0x91001034812103a7, 0x74d534279aa06c64,
+    0x04a3a380cb56a15c, 0xa31d0634b5d99216, 0x6ef2d451520323a1, 0x6930361d1f303480,
+    0xf55e4b4ca3302a5a, 0x32c6fbac10c2a14a, 0x33cf8c86034bb394, 0x1f6307a5982880a1,
+    0x64a81a67906c6654, 0x07284c515ef54fe3, 0xa9ba4952a7c3c654, 0x64c7d765f2b2d384,
+    0x8513b40a7e7fdb96, 0x202682c440ec8c07, 0x9f5be540638a42ec, 0x0e88103249ea88a7,
+    0xb480c875da014ac0, 0x0c234f9d8229e8f1, 0x510c94b4ad4032d8, 0x6265e6204155ca47,
+    0x10a50355f722f010, 0x4e8372c011a8afa6, 0xba80f8c84bb52f4b, 0x07c1938de60ef986,
+    0xb440e2324492d8ea, 0x2e8113210baf1864, 0x259e8c5e260e709d, 0xc770509da099186a,
+    0x9ae161776f227b17, 0x260a67d896ad6a31, 0x367c748003875c70, 0x874b49d91b8129d2,
+    0x2e9c25d44cf6aa05, 0x3694127414498383, 0xa922d6a36eda8833, 0x060030b329443a65,
+    0x113e01a6b1458118, 0xaa9146a65c36136b, 0x4ac9d070ca7f1e9f, 0x4e5213256914e612,
+    0xbdab161c89a5b538, 0x6e1b6905c802081c, 0x24e57b00dc53db61, 0x2d1e414609c9d6e2,
+    0x55a454936a5dbfd2, 0x1f0143751301e679, 0x692052e46145e4fa, 0xc403ad6364ca01f2,
+    0xd79e09caa40a01da, 0x4263c0e96b54be0d, 0x804043d4c7feee02, 0xc1250cdf2761d49e,
+    0x2a6a1dda22fa824b, 0xa348c26aed74160d, 0xd610fd81e50ca8be, 0xaf4a05d7e8a2f0d4,
+    0x5a80d8e0ff871640, 0x065f0a8858fe3eed, 0x9cd105900bc8bb46, 0x586706dae78e0c6b,
+    0x536129cff9dcbc6c, 0x29a91ac5d20a7c42, 0x656423b15e069652, 0x4f7204b83824ddab,
+    0xfa9440e2f20e6f0b, 0x45e350df7d4785cb, 0xe61aa5c74092b274, 0x4508160bca0bbd50,
+    0x0ea70bd556025589, 0xa461c193067b1729, 0xd75b19f69f89e85a, 0xe3523aa6ce38ce93,
+    0x3d30a92b5dd93426, 0xb300b91bdd510d79, 0x44b87cbf9ca8f5b8, 0x9d1020dcce7941fb,
+    0x9918a4e8226cb0f8, 0x015218797c911768, 0xa505f3009c3e3937, 0x817f18fdeddae058,
+    0x10711173993747e6, 0x380bd5cd44598680, 0x1dc0275c245b1cb0, 0x0aaf7c552d42989d,
+    0x81974628d628552c, 0x9ea1c3f45b8b157f, 0xf4ae761351e46914, 0x70bdba97028c5f38,
+    0x147857ff66c5d520, 0xd0c49d21f73755ad, 0x2f9c78d609d05626, 0x041393a0645ba5eb,
+    0xa8449b69d3e0b5c0, 0xa2ccab7625c9867f, 0x4f211342a056fbd4, 0x00c215a223f64550,
+    0x16fbce2413d9d260, 0x9a8eb4240a95ca18, 0x6647f742f1378fe0, 0x3582aa430d2f8181,
+    0x285d48accec7de1f, 0x145baec7b81d2652, 0x85e30940b14d259e, 0x2581cd210f93bedc,
+    0xc9265316bf4b2a7c, 0x41550a534f2df1aa, 0x7e88dc26e347b8ce, 0x87d22ee94e5f61a1,
+    0x7d3cbaf6f7679de8, 0x6e84c0d7d08affb4, 0x5688bf92bafda852, 0xeaf150c99cdec348,
+    0x2ee4467acd8e08e4, 0x75228553ac329023, 0x7c0c6459e0018a98, 0x780dc4582e857f18,
+    0xa1ca7d21b77a03ae, 0xc2f275da0f4606f8, 0x5e2146ab1d910194, 0x0475ebdaa5a04715,
+    0xcc420130043eede0, 0xcf62324100850d21, 0x06dc4408f7467e47, 0x1e2466a503ce4c2e,
+    0x8a5f3f3cd7b0165e, 0x0aa3861d953cd8e9, 0x8db3cae4b8a4043c, 0xc5254b202f7882df,
+    0x987b408389660d4a, 0x913396bcb821a0cd, 0x5a917c9f71d67163, 0x43fdb4e406a81436,
+    0xdbf1232c94bde093, 0x5efe4e51a1816b69, 0x3bc0990a370a81b7, 0xa9507dc60c496f49,
+    0x2709cc516300f323, 0x61eaa33342e0a23b, 0xc4c43a8425ed8d04, 0x6056d9c4918bf3af,
+    0x0398f60238bb66f9, 0xbc8c11e308b4c23c, 0x780d8f12787a8e70, 0xcb8714b36e31da67,
+    0xc6893bc83f030ef6, 0x06462ec9974b57e5, 0x72d6d76cfca08bcf, 0x530b812107c52050,
+    0x86382c3f45406934, 0x213217f759508127, 0x7462b3b26118d49a, 0x3a0b75fb029e0bb9,
+    0xa419dcc77384b053, 0x55a188c282a6ca1c, 0x5e4a48b14857b38e, 0x2568d1cb47f3d39d,
+    0x658431027f6841b9, 0xa89cf8e3dde82ea7, 0x4c4f08030b6565d4, 0x32be513024c092b0,
+    0xab752117eb2418f7, 0x7ea364f113cc1adf, 0x45623ae8e5f940c9, 0x33e713428574f678,
+    0xe0a5140f048eb7ce, 0x9f1a6885a080ceb1, 0x11203622f8bf87bf, 0x79fd57bf9d049966,
+    0x1621d6195a6539cf, 0x5b090763669425a3, 0x1764a74b1ecc1140, 0xb5cf136f70ba4d34,
+    0x3bcf3cc6632375d3, 0x140fe36ee04462f8, 0xc442722065316f7d, 0x914f958537ab4362,
+    0x8b22226517fe38f7, 0x559b67decc7b04d6, 0x6fd9ea6b19290bc7, 0x986d645b0ef5435a,
+    0x83e4c05d64c924c4, 0x6b7c267cd1250081, 0xad4461cbf50522bc, 0x0e9aafe04885e1f8,
+    0xde4e320697f30602, 0xb097d640783d2e31, 0xe323a12c28eeab87, 0x195718260129bf1a,
+    0xea7bc8952edca096, 0x39ca1bf7ed1090b5, 0xd52e5af4c0720410, 0x514a5a654a084aa1,
+    0xc43ad9655c419a1a, 0x019c5603ab4023f0, 0x0c9e7b164976e0b0, 0x7701c03b43bebe29,
+    0xcec808498920fd43, 0x89b7901a2df1c361, 0x1840920f120688be, 0x9d40470cd3f94bcc,
+    0x5092baf51d00eb9d, 0x7776c7ffabd1710f, 0x49c5905a38b5e229, 0xe0f410f612e74aa1,
+    0x13c5917a09e60003, 0x9d9986173cb048e8, 0x0b6fd151362234be, 0x54d2b9e6c2297ce6,
+    0x9bc79ce3a0d80ac2, 0xa6dc30f61903c2e1, 0x1a2b7690f091ca1f, 0x7bc43972f9251663,
+    0xe07bf7285f03f194, 0xea390aa0b9900e2d, 0x1195d55ed53dea0c, 0x9721004548200411,
+    0xbb594c929058d8e4, 0x87600bb755be2459, 0x449d10267af64e70, 0xd67a74c39055b055,
+    0x3aa8465415e4239b, 0xcc91056d409f9fe8, 0x7b47e011128f0e00, 0x4e6153ab9110dc6f,
+    0xa0ec3a07c23eac15, 0x419d21214f4c0d05, 0xd1d159ce4560200b, 0x153491814af83818,
+    0x081112309ac07f00, 0xf623a523a49078e2, 0x5b8434423fb6d090, 0x9c17a8c4249fb332,
+    0x0c70348356008911, 0xc37007001dc8c946, 0x02c1154ce83046ac, 0x30806603470062ec,
+    0x4c1d434785524954, 0xdb9989906c313e01, 0x421a899057c25428, 0x5606056b90850171,
+    0x8db3473f221c00e0, 0x1b1cce00136faa4a, 0x0029134e4701c085, 0x89140885ace0249d,
+    0x74431451c0e53045, 0x8039e2132528a895, 0x542222ae41405766, 0x201618a1719700c2,
+    0x16098869c04c0172, 0x150a17890a144c28, 0x62722f0f19d8d154, 0x06440a7224e3be80,
+    0xb13b4b46d9444c8e, 0x62110774aca13501, 0x052500b8e142c602, 0x71168175199602a0,
+    0x2a87022a0768d890, 0x4f04fe60e16361a4, 0x000055448b021208, 0x0210038004006863,
+    0xc0112ea99852c40b, 0x0076124107198c16, 0x3c0807d806373082, 0x1095020c9c400644,
+    0x0c0130a044838450, 0x0501a810850000a4, 0x81e1460d2858f240, 0xc0267f2100620095,
+    0x598c0265e3115a7c, 0x000a080044294000, 0x491c000040c0000a, 0xe20153a089841884,
+    0x0806b0301a181948, 0x5a80a43652842680, 0x0000b00204c8000c, 0x8010100080440080,
+    0x00410d0010a1c606, 0xd13003004210043a, 0x0c0263e131002808, 0x105c80c2508010e3,
+    0x0000080804400a00, 0x020a10100a004020, 0x0c804103208810c0, 0x00c0021011530001,
+    0x45480c26a0120c39, 0x000000800204c080, 0x08a4000020004000, 0x0206014008018004,
+    0x0814a01114081040, 0x6900c24a11302000, 0x00007c0245442000, 0x0200100200440000,
+    0x0000100000884042, 0x4148000040088014, 0x8000028010010800, 0x0850408006101084,
+    0x2000000000000700, 0x0208001002000000, 0x0400200100080100, 0x0000000010108000,
+    0x0000080040010028, 0x0010004002040020, 0x0020000000004000, 0x0000001000008000,
+    0x0000002000000800, 0x0000400200100000, 0x0080000000000000, 0x0000000000000000,
+    0x0000000000000001,
+]
@@ -0,0 +1,5 @@
+[
+    0x7694aeb652080001, 0xac73f24e6c97cdba, 0x2fea8839f424b3fb, 0x02c6de5b62400646,
+    0xc61956887e99c7d0, 0xdd9728818451796a, 0x849fa116f22626a7, 0x82bf7581a75565ff,
+    0xa28084502e9e6665, 0x0000000011835b37, 0x0000000000000001,
+]
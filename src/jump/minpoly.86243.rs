@@ -0,0 +1,340 @@
+[
+    0x0000000000000001, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000020000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x8000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0400000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x4000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000200000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000008000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000800000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0200000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000004000000, 0x0000000008000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000220000000, 0x0000000040000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x8000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x2000002000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000080000000, 0x0800000000000000, 0x0000000000000008, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000008000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000040, 0x0000000000000280,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000050000000000, 0x0000080000000000, 0x0000000000000000, 0x0000000002200000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000004000000,
+    0x0000000000002808, 0x0800000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0002200000000000, 0x0000400000000000, 0x0000000000000000, 0x0000000111100000,
+    0x0000000022000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000008000,
+    0x0000000040004000, 0x0400000000008000, 0x8000000000000004, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0020000000000000, 0x0001000000002000, 0x0000008000000000,
+    0x0000001100000000, 0x0000000220000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000800000000000, 0x0000000000000000, 0x0000000000000200, 0x0000000000000040,
+    0x4404000000000008, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000004000000,
+    0x0000080000000000, 0x0000011110000000, 0x0000002200000000, 0x0000000010100000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000002000,
+    0x0202000000000404, 0x0040000000000080, 0x0000000000000004, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000200000000000,
+    0x0000000040000000, 0x0000800000000000, 0x0000110000000000, 0x0000022008080000,
+    0x0000000001000000, 0x0000000000000000, 0x0000000000010000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000008000, 0x0000000000000000,
+    0x1000000000020000, 0x2000000000004001, 0x0400000000000800, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0008000000000000, 0x0001111400000000,
+    0x0000220080000000, 0x0000000010000000, 0x0000000008088000, 0x0000000000100000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x8080000000000000, 0x0000000000200800, 0x0200000000040411, 0x4000000000008002,
+    0x2020000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0020000000000000, 0x0000000020000000, 0x0080000202000000,
+    0x0011004000000000, 0x0002200808000000, 0x0000000500040000, 0x0000000080000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xc400000000800000, 0x0800000000004004, 0x0000000002000000, 0x0000000000400110,
+    0x0010000000080020, 0x0000000000004410, 0x0000000000000002, 0x0002220000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0001040100000000,
+    0x0800000020000000, 0x0111140000000000, 0x0022008020200000, 0x0000104000400000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000008000, 0x0000000000000000,
+    0x0000000000000000, 0x4000000000000000, 0x0000000000040004, 0x0000000020000080,
+    0x0000000004041100, 0x0100000000800000, 0x0000000000040100, 0x0110000000000000,
+    0x0002200000000000, 0x0000000000000000, 0x0000000000000000, 0x2000000000000000,
+    0x0010401000000000, 0x8000020000000000, 0x1100410010000000, 0x0220000002000000,
+    0x0000000004000000, 0x0000000000000000, 0x0000000000400000, 0x0000000000080000,
+    0x0000000000000000, 0x0000000000000880, 0x0000000080000000, 0x0400000000400400,
+    0x0000000200000000, 0x0000000040000400, 0x1000000008000000, 0x8008000000000000,
+    0x0100000000000000, 0x0200000000000000, 0x0000000000000000, 0x0000020000000000,
+    0x0000000000000000, 0x0100000000000000, 0x0000880080000000, 0x1110000100000008,
+    0x2200000020000001, 0x0000000040000000, 0x0000000000200000, 0x0000000004000000,
+    0x0000000000000000, 0x0000000000044000, 0x0000000000008000, 0x0000000000000000,
+    0x4000000004000000, 0x0000002008020002, 0x0000000404000000, 0x0404000080000000,
+    0x0000000000000045, 0x0000000000000000, 0x0000000000000001, 0x0001000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x1000200044000020, 0x0000000000000000,
+    0x0000001000000080, 0x2000000200000011, 0x0000001410100002, 0x0000000000000000,
+    0x0000000000000000, 0x0000000002200000, 0x0000000000400000, 0x0000000000088000,
+    0x0000008000000000, 0x0000000000008001, 0x0000020000000000, 0x0002004000000000,
+    0x0040000800000022, 0x0000000044040050, 0x0000000000000080, 0x2280000000000000,
+    0x0000000000000002, 0x0002000000000000, 0x0002123020000000, 0x0400000440000000,
+    0x0000008000000000, 0x1000010000800800, 0x0000002800080111, 0x1010014001000022,
+    0x0000000000000000, 0x0000000011101000, 0x00000000a0000000, 0x0000000004400000,
+    0x8000000000800000, 0x0000000000000080, 0x0000000000000010, 0x0310200000000200,
+    0x0000040400001101, 0x0400008002020200, 0x0000000400404100, 0x4040400000000000,
+    0x2000000000000110, 0x0100000000000002, 0x0000800000000000, 0x0020030000000000,
+    0x4000000000002000, 0x0000000004000000, 0x000014040c008000, 0x0808020000001100,
+    0x0100040010000220, 0x0000000880808000, 0x0001004001000000, 0x0000000a20000000,
+    0x0000000040000000, 0x0000000000020000, 0x0000800000000800, 0x8800000000000000,
+    0x200200000000080c, 0x0200400110010010, 0x4000080020000000, 0x0000000004001000,
+    0x0400000000008028, 0x8000000000000100, 0x0000004000000200, 0x0008000000000000,
+    0x0202100000000000, 0x0000000220000000, 0x0000801240000000, 0x0001004000080000,
+    0x8000200008011114, 0x1000440504406200, 0x0808802008000000, 0x0010041100000000,
+    0x0000002000000000, 0x0000000000000100, 0x0000000000200000, 0xc000000000000000,
+    0x0000000000040224, 0x1020080080820080, 0x0004041100000101, 0x1000800202000000,
+    0x0000000040451410, 0x0000000020200000, 0x2200020000010040, 0x0000040000000000,
+    0x0080000000000000, 0x2001000000000000, 0x20004c2100200000, 0x0200000000000000,
+    0x0010040400800002, 0x0802000200330040, 0x0010104000062008, 0x8000088000000005,
+    0x0000000000000000, 0x0000000080000000, 0x0000000000001000, 0x0000000002000000,
+    0x0080000000001110, 0x00004004c4010004, 0x0a00000008000888, 0x0048011000001008,
+    0x0008002000220008, 0x0000441000400100, 0x1111100200802000, 0x2000200000000010,
+    0x0000000000000002, 0x0800000000000000, 0x0230240000000000, 0x0004401000000002,
+    0x2080020000010401, 0x0101418019000000, 0x2008000003111400, 0x0104000000620080,
+    0x0000000000001040, 0x0000004040404000, 0x0000000800000000, 0x0000000000000000,
+    0x0000000020008801, 0x0000000000809100, 0x0004000440100400, 0x2400008002008040,
+    0x0404110011040680, 0x0080000002000000, 0x808c810040100000, 0x0110000000000000,
+    0x0000000000000100, 0x0000000000000022, 0x8002000000000000, 0x0300400000000000,
+    0x0000000020000020, 0x0000094480904010, 0x0410080180000200, 0x0000000033004100,
+    0x0000000004000000, 0x0000000000200000, 0x0000040004000000, 0x0000008000000000,
+    0x0000000000404040, 0x0000000200088000, 0x8000000008011000, 0x0240040000000000,
+    0x000000000c041012, 0x480004010040280a, 0x0000000000000000, 0x0800000000000040,
+    0x0000000000000000, 0x0000000000001011, 0x0141010000000220, 0x0020000000000000,
+    0x1004000000000008, 0x0006424044000202, 0x0010040009000000, 0x0000001900008800,
+    0x0000000200000000, 0x0000000040000000, 0x0000000000000000, 0x0000404000000000,
+    0x0000080200020000, 0x0000000005000400, 0x0000002000880000, 0x0000000000110000,
+    0x2400000402218901, 0x0802000040014000, 0x3200000000008024, 0x0000000000020002,
+    0x0000000000000000, 0x0000000000000080, 0x0200000000010000, 0x1400100000002200,
+    0x0200000000000000, 0x2051311000000080, 0x0826002440002001, 0x0001008010002000,
+    0x0000010000000000, 0x0008002a48000000, 0x0000000400000000, 0x0000000000000000,
+    0x0004001010001000, 0x0000800000000000, 0x0000000050400000, 0x0000020008800000,
+    0x0000000090048400, 0x0000800000100090, 0x0010000000000200, 0x2000000000100011,
+    0x0000000000000009, 0x0000000000004000, 0x5000400000000000, 0x2000000000101151,
+    0x0100000000022000, 0x2000880000000001, 0x0100110000000800, 0x0040400000000010,
+    0x0000000000000000, 0x0000110400000000, 0x0000020480000000, 0x0000004000000000,
+    0x0000080008008000, 0x0040414040000000, 0x0008000002000000, 0x0000000400000000,
+    0x0000200000020000, 0x0000000000004000, 0xc880000001010000, 0x0000000000080c44,
+    0x0000000000000410, 0x0000000002000002, 0xa000000000000000, 0x000000000000a088,
+    0x0000000001000510, 0x0044040000220000, 0x0008800000000010, 0x0021100000008000,
+    0x0400000000000100, 0x0000909202000000, 0x0001004000000000, 0x0800220800000000,
+    0x0004400646404000, 0x0000802080000000, 0x0400141000000000, 0x0080000000000000,
+    0x0000000001000000, 0x0002000000200000, 0x0400000000840000, 0x0800000014604642,
+    0x1000000400824600, 0x0000000010000100, 0x1000000000000820, 0x0000000000501010,
+    0x0000000000020802, 0x2222220010114040, 0x0440000002200000, 0x0088000000000040,
+    0x0011000000080000, 0x41414d0800001000, 0x0001080020000000, 0x0011040000000010,
+    0x002202a112220000, 0x0044406400040000, 0x0008000000000000, 0x4041000000000000,
+    0x0802000080808000, 0x0000010010000000, 0x0020000002000000, 0x4000000011501100,
+    0x0000022141054020, 0x0000000000002088, 0x4808000000041000, 0x0000000200023800,
+    0x0000000001000100, 0x0011100000a00000, 0x2002200100041000, 0x4404000022000202,
+    0x0880000000000400, 0x2510040000800000, 0x0014408000010000, 0x0080020020000804,
+    0x0110d89191100000, 0x022a620122000000, 0x0440020200400008, 0x0080000000000000,
+    0x0000400000404000, 0x8000000800080004, 0x0000100100000000, 0x02000000a8808880,
+    0x0000111095001000, 0x8000001020544200, 0x0400000002000000, 0x8000001000102004,
+    0x0000000000098004, 0xc808000050100000, 0x0110000002008880, 0x2200001011111000,
+    0x4000000220002022, 0x8a02020000000004, 0x5100400008000002, 0x4101080000100400,
+    0x008840c048000040, 0x1109001900000000, 0x2204010222000000, 0x4440200004000000,
+    0x0000200120200000, 0x4040400004000000, 0x0000008080000040, 0x0000001404040408,
+    0x2000880a80088000, 0x0000111040010000, 0x0000010104402000, 0x000000080e444200,
+    0x0800000004000040, 0x6644420000000008, 0x8000000100404464, 0x0000000080880804,
+    0x0000010001100000, 0x0400002200000220, 0x8020000000000044, 0x1004000080020028,
+    0x1002c24645004021, 0x98100c0000000400, 0x1088811110000444, 0x2200002200000001,
+    0x04001090d0100802, 0x0022001200000000, 0x0400000000000000, 0x00000a2002022600,
+    0x0040410000400080, 0x0008802000888000, 0x0001108400100002, 0x0000146002090909,
+    0x0404109266002000, 0x2002100000200404, 0x00440000080303b3, 0x0000004000040244,
+    0x00000000080880c0, 0x2200901100000000, 0x00000220000022a2, 0x0202000001410540,
+    0x2142023820200088, 0x4c24002450040012, 0x0840800800006340, 0x0800110000104401,
+    0x00080a6a00080011, 0x4000090401000002, 0x0020010000000000, 0x0000111191811000,
+    0x0002a080a0026040, 0x0500140004040802, 0x8088020008800000, 0x00111154940000a0,
+    0x0221490100900090, 0x5011000020020000, 0x0000000410014019, 0x0442000222003a08,
+    0x0000000000446022, 0x5200408000880400, 0x2009000000004053, 0x0000220002022002,
+    0x2808880014005404, 0x1020038002000888, 0x4040460100513420, 0x0008200008060004,
+    0x0005113400000080, 0x0800a20000000100, 0x001080c01008002a, 0x0208940c88808004,
+    0x1000115048100000, 0x0008000a02040010, 0x5041000040008000, 0x0880a88000020000,
+    0x1004910040000a00, 0x8010201001010800, 0x0000020808280884, 0x0210001000040491,
+    0x4400000822019100, 0x2000400004020000, 0x2000000008a0c828, 0x0010115150004510,
+    0x0406204020222200, 0x8008800101044404, 0x0202180020020200, 0x0400201001004100,
+    0x00820a1200404000, 0x0051024000000000, 0x8802620008801104, 0x044c046342404200,
+    0x2801e02088000040, 0x4000151180000800, 0x0280802000404140, 0x0014040000080100,
+    0x0008800000200004, 0x4010040000002000, 0x010100405440a266, 0x100808808a000800,
+    0x0200040000400110, 0x0200000200180800, 0x00000000547466c0, 0x0000a08828048002,
+    0x2002051000004042, 0x0062040002200002, 0x0088001000000040, 0x2001800000200800,
+    0x41464c0900200000, 0x0a00200004000101, 0x0111140004008082, 0x0203010308030040,
+    0x5490922408042208, 0x8012088000044004, 0x0001100800008022, 0x0a20000080001410,
+    0x0040000000801000, 0x0000000002000000, 0x0080001101020100, 0x14404445450b0024,
+    0x00820a0828008080, 0x3008000014041104, 0x000008220bb32008, 0x0050101413440800,
+    0x9002080a80c02000, 0x1011404000051120, 0x0620000000800002, 0x0880004000040000,
+    0x0638240000000000, 0x0024509000000006, 0xa00a020261414514, 0x0001589941000800,
+    0x2a48100000110400, 0x0d006202006202a8, 0x0100000000444044, 0x1100c00240080020,
+    0x2000000840410000, 0x0000000008020000, 0x0000000820080980, 0x0000010010a09000,
+    0xc105001040904600, 0x0400a08a00080221, 0x00040001441c2024, 0x00022800b2000000,
+    0x0500014470228002, 0x00a0008804880480, 0x0204100040120000, 0x6200020000000021,
+    0x8800060000000000, 0x4380400000000000, 0x4600080020000020, 0x206cc86800154040,
+    0x0031009000800288, 0xa001010003105015, 0xc0822008062a2202, 0x9424000004400010,
+    0x0000000000800208, 0x0000008400004000, 0x00004404c0404440, 0x0000000200009000,
+    0x800011000a010000, 0x0054410108003110, 0x02080a2808160000, 0x0011001000c00240,
+    0x4048c20911000018, 0x10100403440c4640, 0x0200802048004800, 0x1111100000000010,
+    0x2141210000000210, 0x8000000000010007, 0x1804000000000008, 0x6072a66604000602,
+    0x049004c041010404, 0x0208810100880802, 0x4010008011010051, 0x1010100062000822,
+    0x4000000044400448, 0x0000004000000001, 0x0000284072426200, 0x0000004805000400,
+    0x0000082000088000, 0x0001000020108800, 0x04005441a3281808, 0x0a80208000000101,
+    0x163200040020044c, 0x0004009200232093, 0x0040102422440204, 0x8088800400008005,
+    0x8110800000000000, 0x140010000000a102, 0x0002000000000062, 0xb043310020000088,
+    0x0922400040000100, 0x44400c0011010106, 0x0000114488008000, 0x0008004b58080100,
+    0x8100080622000009, 0x0000000006200080, 0x0010001101311010, 0x0002840324022000,
+    0x0044008050440000, 0x8000020000c04040, 0x00118506800c0400, 0x0004401a00811000,
+    0x3a0a000010001440, 0x230000111d8cd88b, 0x0840011102001801, 0x0800020004444000,
+    0x0840404000080000, 0x0008000000401040, 0x01000000000a1019, 0x01c1018001000621,
+    0x0020100000000008, 0x3004460220041418, 0x0002c34044100420, 0x2431252408080004,
+    0x0000801580000004, 0x1010004311100040, 0x00084c0462888800, 0x8180411001100100,
+    0x0008406202000000, 0x0000080600020000, 0x0008220807000600, 0x8100502000884000,
+    0x4880008000100800, 0xa020080c4ae109c1, 0x22000019408d4491, 0x3200100022209106,
+    0x8020202244020006, 0x0400040000a12400, 0x0000000206000480, 0x4640044000210280,
+    0x1400100000046610, 0x0221000000000800, 0x0051113000214090, 0x0a2c020542000201,
+    0x021102c000002010, 0x0800438808000060, 0x004e441b48040000, 0x0004c00628008081,
+    0x0800110100001404, 0x0084023010001000, 0x4404c00101000100, 0x0002000050400000,
+    0x5184060008040000, 0x0800484610040444, 0x000080840c100c10, 0x0010019108c80310,
+    0x2011110200100a33, 0x0002000444504089, 0x4404400008120008, 0x5020620020104000,
+    0x2400040002303b51, 0x0100800100472040, 0x2010880000000801, 0x0146140104101800,
+    0x20c0600020012118, 0x2121040004000010, 0x23310ba422200604, 0x1264411590404022,
+    0x0008006200080032, 0x804118001811c000, 0x28c0d14040808000, 0x0048010012001000,
+    0x0822000400020000, 0x0040311000421110, 0x0004a20201084005, 0xc880080841418008,
+    0x080011008c0c1d44, 0x0110002a2a537c10, 0x022222c403040c06, 0xb145000080240080,
+    0x000220020114a09c, 0x40040000a0002510, 0x0044040004660400, 0x2108a40000080010,
+    0x0001501000018400, 0x0c02200000120988, 0x0908500342000000, 0x3a4ae24022004112,
+    0x0c44130804010309, 0x008442264ec8c320, 0x00408060c1100000, 0x8009141000080018,
+    0x0080100100010002, 0x02000888818088c4, 0x0102100084209100, 0x044a462000044411,
+    0x080080041468c642, 0x9001150cecba4604, 0x01110200b00f8100, 0xba088c445000c821,
+    0x0450000882181898, 0x2000006091020902, 0x2222220a30114040, 0x06c2030046200004,
+    0x1088400008800242, 0x4610000404184400, 0xcd21c10809211880, 0x21a9081020008840,
+    0xa00d040220040cb8, 0x446113b552621002, 0x44606244a8243212, 0x4408040011000008,
+    0xc041000088080102, 0x08060450c0948408, 0x2008898812088000, 0x1021000042011008,
+    0x4480220119501000, 0x000a0ae191475020, 0x809920ac068020c8, 0x5848622811641808,
+    0xa222c407040e7c50, 0x440004a101008902, 0x2033140100a01011, 0x2442202102041000,
+    0x4424000062000343, 0x0884000088000620, 0x6112640441844021, 0x4434008012018e04,
+    0x0a00830020444e4a, 0x4290d8b193700281, 0x163b624522010922, 0x4442220888c3205a,
+    0x0080404514000004, 0x00224200a2626000, 0xc000414801480084, 0x000818010088c404,
+    0x02100084a8918882, 0x4846391115090100, 0xc4940c0124d54200, 0x0f910acc22004404,
+    0x910600b22e300286, 0x000c4600400dc105, 0x88184890501888a2, 0xa39080100201cc90,
+    0x2600021011911022, 0xc201220721413522, 0x8b50002a80020004, 0x1526440018460320,
+    0x01010809213ae662, 0x888954c14c1024cc, 0x19090a312e0ca021, 0x6315154262322119,
+    0x4462008824301120, 0x1015304070710000, 0x62424282a4020040, 0x0104148094042840,
+    0x008880160c848448, 0x21008cca940888a0, 0xa208180040900000, 0x5808415104402004,
+    0x31208d0f1e400305, 0x086b2042242228c0, 0x4400420404405018, 0xc140800100c84444,
+    0x20080000c0dc1801, 0x0110a1028110800a, 0xac8a206214005224, 0xb10002a8000288cc,
+    0x5044050094632308, 0x3002c2544da64401, 0x9801481444400c44, 0x98802111d1121840,
+    0x330045231208180b, 0x86a01818db980806, 0x0063500205100000, 0x0602202a22100011,
+    0x10450a204240a602, 0x084ac382020c0080, 0x110c4820c0888a00, 0x4e81108401010d86,
+    0x90041de08a0901c3, 0x1984709276442411, 0x2602300a02008407, 0xa42040004c4316a2,
+    0x14080040088ca86c, 0x004810400d48c090, 0x230a901980082080, 0x83000621010422a2,
+    0x0000000803cb8748, 0x2546142862231088, 0x4c240b0578444612, 0x0950804c8840e140,
+    0x8802b90ca4114545, 0x55184a2b44088019, 0x6aa82124b9100043, 0x0530001040080800,
+    0x0200933110215100, 0x0452e080a40a6062, 0x8728142004040802, 0x908c0200088c6208,
+    0x20145514850494e1, 0x4aa1c18108100c10, 0x501505012442011e, 0x322000243a0b4022,
+    0x4042000672412a48, 0x04a420008866c002, 0x540044c014990005, 0x2029028000004851,
+    0x0004661047032003, 0x0809800014a8dc04, 0x5024429422310888, 0x4240740182503520,
+    0x110824104a0c2224, 0x282713f6201004c0, 0x0800a214080001aa, 0x281a08c3104c443f,
+    0x4248801c98900086, 0x1200115040101101, 0x0408000a02840212, 0xda4300000624c041,
+    0x0840a88000400008, 0x1800d1451108ce11, 0x8810a8980901c04c, 0x004012180c281901,
+    0x0210021a22860d08, 0x0052400c22008002, 0x284240088ca02026, 0x001040014ca48878,
+    0x02303bd150004590, 0x26c3200030222200, 0x822a880309844407, 0x0600084020020202,
+    0x2409132021544405, 0x14824a0660c26000, 0x285926c401014400, 0x980223222aa039a4,
+    0x24c40479500c0311, 0x3810a16089080062, 0x6000151100410815, 0x0280802039d0d140,
+    0x20140400a0480405, 0x000880000cc20085, 0xc154441500847000, 0x01098048544c00c4,
+    0x140d0980cb000018, 0x2912ae0020409210, 0x030404464008192b, 0x8420008854564604,
+    0x0114e0cc2840c102, 0xc142651000004040, 0x2426000002200112, 0x03a0001000000042,
+    0x2501000020202820, 0x4154470101214050, 0x4e0020000c222101, 0x811155100504c092,
+    0x0202160e2609a240, 0x5c1813040c403308, 0x81020c801084c2a4, 0x0001101880c00132,
+    0x0a21004205083610, 0x0040004e20804000, 0x8000800048000000, 0x04c0450004400000,
+    0x14c8cc454d414c04, 0xcc830a0828000080, 0x9002a02014050c08, 0x52004c231aa32218,
+    0xc258181431440444, 0x01020c0a84803008, 0x3411c04000059168, 0xc220000000800108,
+    0x0000000000040300, 0x1220200000000202, 0x0307401054010402, 0xa00a8a02eb61cd14,
+    0x0005d40340400800, 0x2a090042a0190400, 0x850070104c2357bc, 0x1000440d04242a44,
+    0x1908c1200c180020, 0x20100411c0410000, 0x0000002008020000, 0x0000000000000000,
+    0x4440100400808000, 0x0907001044104401, 0x0110a08a00060885, 0x10260801c4870080,
+    0x04063c11a2002329, 0x1100814652004407, 0x00e0008840cd1430, 0x0004100060300401,
+    0x0000000000010041, 0x0000000000000000, 0x0200002000002000, 0x7401040565000020,
+    0x2028c62720354040, 0x0251001104800288, 0xa0100c2603b27017, 0x4803005a121d2242,
+    0x8030104004c62018, 0x800008800080020d, 0x0000000400004000, 0x0000000000000000,
+    0x0000000000000000, 0xc000000008000080, 0x80dc410140440005, 0x13084a2c4c830814,
+    0x221900100951088c, 0x00408318000210ba, 0x5018042144220048, 0x0200c43008400180,
+    0x0000000200802000, 0x0000000000000000, 0x0000000000000000, 0x0002022200000000,
+    0x4360601440000202, 0x0084605a41010407, 0x2484030040890c03, 0x012361882a010051,
+    0x3008010021111c22, 0x01040000442204c0, 0x0000000000001040, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x4000000000000081, 0x0400544501808054,
+    0x0a84208448180509, 0xa010008413008148, 0x04041182110c0480, 0x00c0100402000200,
+    0x0004010040100001, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x2020022000000000, 0x0204052100000000, 0x0000042031010134, 0x411009000890c010,
+    0x0610080090000300, 0x0000080211004108, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000800080000800,
+    0x800440504c0004c0, 0x284a040080001c40, 0x0100001008000080, 0x400004010040380a,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0202000000000000, 0x0040100220000000, 0x8806210000100703,
+    0x0000400001080000, 0x0000000800008800, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x8000000000000000, 0x4000040000040000, 0x842008060000c000, 0x0802000040004000,
+    0x0000000000008020, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x2000000000000000, 0x2000002000200000,
+    0x0020002100003000, 0x0001000010002000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0080000000000000, 0x0000000080000000, 0x0000800000000080,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000100000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000001,
+]
@@ -9,12 +9,66 @@
 //! let r = rng.next_u32();
 //! println!("random u32 number = {}", r);
 //! ```
+//!
+//! Seeding
+//! --------
+//!
+//! There are several ways to initialize the generator, and they generate different streams
+//! for the same seed value:
+//!
+//! | Rust                                  | Original C implementation                     |
+//! |:--------------------------------------|:----------------------------------------------|
+//! | `SFMT::new(seed: u32)`                | `sfmt_init_gen_rand(&sfmt, seed)`             |
+//! | `SFMT::new_u64(seed: u64)`            | `sfmt_init_by_array(&sfmt, {lo, hi}, 2)`      |
+//! | `SFMT::init_by_array(key: &[u32])`    | `sfmt_init_by_array(&sfmt, key, len)`         |
+//! | `SeedableRng::from_seed([u8; 32])`    | `sfmt_init_by_array(&sfmt, key, 8)`           |
+//! | `SeedableRng::seed_from_u64(u64)`     | (none)                                        |
+//!
+//! where `lo` and `hi` are the lower and upper 32 bits of the seed, and `key` of `from_seed`
+//! is eight `u32` read in little endian.
+//! `seed_from_u64` expands the seed into `[u8; 32]` by `rand_core`, and its stream does not
+//! match with the C implementation.
+//! Use `SFMT::new` or `SFMT::new_u64` to port C codes.
+//!
+//! Parallel streams
+//! -----------------
+//!
+//! `SFMT::streams(seed, n)` creates `n` generators spaced by `2^128` regenerations of the state
+//! using the jump-ahead by polynomial arithmetic, which never overlap in practice
+//! unlike the generators seeded by `seed + i`.
+//! The stream `i` is reproducible alone by `SFMT::stream(seed, i)`.
+//!
+//! Reproducibility
+//! ----------------
+//!
+//! `SFMT::manifest()` records the parameter, the seed, the stream index, and the position
+//! of a generator into a small `Manifest`, which is written and read as a line of text.
+//! `SFMT::from_manifest` recreates the generator at the same position.
+//! They require the `alloc` feature, and an example is in the documentation of `Manifest`.
+//!
+//! This crate is `no_std` if the default `std` feature is disabled.
+//! Then `thread_rng` is not available, and the SIMD kernel is selected only by
+//! the target features enabled at compile time.
+//! Jumping ahead requires the `alloc` feature, which is enabled by `std`.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+mod jump;
+#[cfg(feature = "alloc")]
+mod manifest;
 mod packed;
+#[cfg(feature = "alloc")]
+pub mod poly;
 mod sfmt;
 #[cfg(feature = "thread_rng")]
 mod thread_rng;
 
+#[cfg(feature = "alloc")]
+pub use self::manifest::{Manifest, ManifestError, Seed};
 #[cfg(feature = "thread_rng")]
 pub use self::thread_rng::{thread_rng, ThreadRng};
 
@@ -47,7 +101,12 @@ pub mod paramed {
         packed::*,
         sfmt::{SfmtParams, SFMTMEXP},
     };
-    use rand_core::{impls, Error, RngCore, SeedableRng};
+    use rand_core::{Error, RngCore, SeedableRng};
+
+    #[cfg(all(target_arch = "x86", target_feature = "sse2", not(miri)))]
+    use core::arch::x86::__m128i;
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2", not(miri)))]
+    use core::arch::x86_64::__m128i;
 
     /// State of SFMT
     ///
@@ -56,6 +115,9 @@ pub mod paramed {
     /// MEXP is limted to be a known value, and it is checked at compile time.
     /// MEXP can only be `607,1279,2281,4253,11213,19937,44497,86243,132049,216091`.
     /// Since there is a limitation to const generics, we also need the `MEXP_N = {MEXP / 128 + 1}`
+    ///
+    /// It is initialized by a 256-bit seed through `rand_core::SeedableRng`,
+    /// or by [SFMT::new] and [SFMT::init_by_array] compatible with the original C implementation.
     /// ```
     /// # use rand_core::SeedableRng;
     /// let s = sfmt::SFMT19937::seed_from_u64(23);
@@ -66,30 +128,560 @@ pub mod paramed {
         pub(crate) state: [i32x4; MEXP_N],
         /// index counter to the 32-bit internal state array
         pub(crate) idx: usize,
+        /// number of regenerations of the state since the initialization, modulo `2^128`
+        pub(crate) blocks: u128,
+        /// shift of the state from the boundary of blocks in 32-bit words by [SFMT::fill_array32]
+        pub(crate) shift: usize,
+        /// stream index of `SFMT::streams`, i.e. the carry of `blocks` over `2^128`
+        pub(crate) stream: u64,
+        /// seed to reproduce the state by [SFMT::from_manifest], or `None` if it is lost
+        #[cfg(feature = "alloc")]
+        pub(crate) seed: Option<crate::manifest::SeedRecord>,
+        /// kernel to regenerate the state, selected by the CPU features at runtime
+        pub(crate) gen_rand_all: fn(&mut [i32x4; MEXP_N]),
     }
 
     impl<const MEXP: usize, const MEXP_N: usize> SFMT<MEXP, MEXP_N>
     where
         SFMTMEXP<MEXP, MEXP_N>: SfmtParams<MEXP, MEXP_N>,
     {
+        fn zeroed() -> Self {
+            Self {
+                state: [zero(); MEXP_N],
+                idx: 0,
+                blocks: 0,
+                shift: 0,
+                stream: 0,
+                #[cfg(feature = "alloc")]
+                seed: None,
+                gen_rand_all: SFMTMEXP::<MEXP, MEXP_N>::gen_rand_all_kernel(),
+            }
+        }
+
+        /// Initialize by a 32-bit seed as `sfmt_init_gen_rand` of the original C implementation
+        ///
+        /// This generates the same stream as the C implementation for the same seed,
+        /// unlike [SeedableRng::seed_from_u64] which expands the seed by `rand_core`.
+        /// Use [SFMT::new_u64] for 64-bit seeds,
+        /// or [SeedableRng] to initialize the whole state from larger entropy.
+        ///
+        /// ```
+        /// use rand_core::RngCore;
+        /// let mut rng = sfmt::SFMT19937::new(1234);
+        /// assert_eq!(rng.next_u32(), 3440181298);
+        /// ```
+        pub fn new(seed: u32) -> Self {
+            let mut sfmt = Self::zeroed();
+            SFMTMEXP::<MEXP, MEXP_N>::sfmt_init_gen_rand(&mut sfmt, seed);
+            #[cfg(feature = "alloc")]
+            {
+                sfmt.seed = Some(crate::manifest::SeedRecord::U32(seed));
+            }
+            sfmt
+        }
+
+        /// Initialize by a 64-bit seed using all its bits
+        ///
+        /// This is [SFMT::init_by_array] with the key `[lower 32 bits, upper 32 bits]`,
+        /// i.e. it is equivalent to the following C code:
+        ///
+        /// ```c
+        /// uint32_t key[2] = {(uint32_t)seed, (uint32_t)(seed >> 32)};
+        /// sfmt_init_by_array(&sfmt, key, 2);
+        /// ```
+        ///
+        /// Note that this differs from `sfmt_init_gen_rand` even if the seed fits in 32 bits,
+        /// and from [SeedableRng::seed_from_u64] which expands the seed by `rand_core`.
+        pub fn new_u64(seed: u64) -> Self {
+            Self::init_by_array(&[seed as u32, (seed >> 32) as u32])
+        }
+
+        /// Initialize by an array of `u32` as `sfmt_init_by_array` of the original C implementation
+        ///
+        /// The key can be arbitrary length,
+        /// and generates the same stream as the C implementation for the same key.
+        ///
+        /// ```
+        /// use rand_core::RngCore;
+        /// let mut rng = sfmt::SFMT19937::init_by_array(&[0x1234, 0x5678, 0x9abc, 0xdef0]);
+        /// assert_eq!(rng.next_u32(), 2920711183);
+        /// ```
+        pub fn init_by_array(key: &[u32]) -> Self {
+            let mut sfmt = Self::zeroed();
+            SFMTMEXP::<MEXP, MEXP_N>::sfmt_init_by_array(&mut sfmt, key);
+            #[cfg(feature = "alloc")]
+            {
+                sfmt.seed = Some(crate::manifest::SeedRecord::key(key));
+            }
+            sfmt
+        }
+
         fn pop32(&mut self) -> u32 {
-            let val = extract(self.state[self.idx / 4], self.idx % 4);
+            let val = as_u32_slice(&self.state)[self.idx];
             self.idx += 1;
             val
         }
 
         fn pop64(&mut self) -> u64 {
-            let p = self.state.as_ptr() as *const u32;
-            let val = unsafe {
-                let p = p.offset(self.idx as isize);
-                *(p as *const u64) // reinterpret cast [u32; 2] -> u64
-            };
+            // Two u32 are combined in little endian order regardless of the target endian
+            let st = as_u32_slice(&self.state);
+            let val = st[self.idx] as u64 | (st[self.idx + 1] as u64) << 32;
             self.idx += 2;
             val
         }
 
         fn gen_all(&mut self) {
             SFMTMEXP::<MEXP, MEXP_N>::sfmt_gen_rand_all(self);
+            self.count_blocks(1);
+            self.idx = 0;
+        }
+
+        /// Count regenerations of the state, carrying over `2^128` to the stream index
+        pub(crate) fn count_blocks(&mut self, steps: u128) {
+            let (blocks, carry) = self.blocks.overflowing_add(steps);
+            self.blocks = blocks;
+            self.stream = self.stream.wrapping_add(carry as u64);
+        }
+
+        /// Number of 32-bit outputs consumed since the initialization, modulo `2^128`
+        ///
+        /// [RngCore::next_u64] consumes two, and also the last one of the state dropped by it.
+        /// Jumps move the position by the skipped outputs.
+        ///
+        /// ```
+        /// use rand_core::RngCore;
+        /// let mut rng = sfmt::SFMT19937::new(1234);
+        /// rng.next_u32();
+        /// rng.next_u64();
+        /// assert_eq!(rng.position(), 3);
+        /// ```
+        pub fn position(&self) -> u128 {
+            let n32 = SFMTMEXP::<MEXP, MEXP_N>::SFMT_N32 as u128;
+            self.blocks
+                .wrapping_mul(n32)
+                .wrapping_add((self.shift + self.idx) as u128)
+                .wrapping_sub(n32)
+        }
+
+        /// Skip `n` 32-bit outputs
+        ///
+        /// This moves the index in the current state, and regenerates the state without extracting outputs
+        /// for whole blocks. With the `alloc` feature, very large `n` is skipped by the polynomial jump.
+        ///
+        /// ```
+        /// use rand_core::RngCore;
+        /// let mut rng = sfmt::SFMT19937::new(1234);
+        /// let mut skipped = rng.clone();
+        /// skipped.discard(10_000);
+        /// for _ in 0..10_000 {
+        ///     rng.next_u32();
+        /// }
+        /// assert_eq!(rng.next_u32(), skipped.next_u32());
+        /// assert_eq!(skipped.position(), 10_001);
+        /// ```
+        pub fn discard(&mut self, n: u128) {
+            let n32 = SFMTMEXP::<MEXP, MEXP_N>::SFMT_N32 as u128;
+            let mut blocks = n / n32;
+            let mut offset = self.idx as u128 + n % n32;
+            if offset > n32 {
+                blocks += 1;
+                offset -= n32;
+            }
+            self.idx = offset as usize;
+            if blocks == 0 {
+                return;
+            }
+            #[cfg(feature = "alloc")]
+            {
+                // The polynomial jump takes about `128 * MEXP_N` regenerations by Horner's method
+                if blocks > 2 * 128 * MEXP_N as u128 {
+                    self.jump(blocks);
+                    return;
+                }
+            }
+            for _ in 0..blocks {
+                (self.gen_rand_all)(&mut self.state);
+            }
+            self.count_blocks(blocks);
+        }
+
+        /// Fill `array` with 32-bit outputs as `sfmt_fill_array32` of the original C implementation
+        ///
+        /// This runs the recursion directly in `array` without copying from the internal state,
+        /// and `array` gets the same numbers as [RngCore::next_u32] would return.
+        /// The following outputs continue from the end of `array`.
+        ///
+        /// # Panics
+        ///
+        /// As the C implementation, this panics
+        ///
+        /// - if an output is taken from the current state, i.e. this is not called at a block boundary
+        /// - if the length of `array` is smaller than `N32 = 4 * MEXP_N` or not a multiple of 4
+        ///
+        /// ```
+        /// use rand_core::RngCore;
+        /// let mut rng = sfmt::SFMT19937::new(1234);
+        /// let mut array = [0_u32; 1000];
+        /// rng.fill_array32(&mut array);
+        /// assert_eq!(array[0], 3440181298);
+        /// ```
+        pub fn fill_array32(&mut self, array: &mut [u32]) {
+            let n32 = SFMTMEXP::<MEXP, MEXP_N>::SFMT_N32;
+            assert_eq!(
+                self.idx, n32,
+                "fill_array32 requires the state to be consumed"
+            );
+            assert!(
+                array.len() >= n32 && array.len() % 4 == 0,
+                "fill_array32 requires the length to be a multiple of 4 not smaller than {}",
+                n32
+            );
+            SFMTMEXP::<MEXP, MEXP_N>::sfmt_gen_rand_array(&mut self.state, array);
+            self.count_words(array.len());
+        }
+
+        /// Fill `array` with 64-bit outputs as `sfmt_fill_array64` of the original C implementation
+        ///
+        /// This is the 64-bit version of [SFMT::fill_array32],
+        /// and `array` gets the same numbers as [RngCore::next_u64] would return.
+        ///
+        /// # Panics
+        ///
+        /// - if an output is taken from the current state, i.e. this is not called at a block boundary
+        /// - if the length of `array` is smaller than `N64 = 2 * MEXP_N` or not a multiple of 2
+        ///
+        /// ```
+        /// use rand_core::RngCore;
+        /// let mut rng = sfmt::SFMT19937::new(1234);
+        /// let mut array = [0_u64; 1000];
+        /// rng.fill_array64(&mut array);
+        /// let mut rng = sfmt::SFMT19937::new(1234);
+        /// assert_eq!(array[0], rng.next_u64());
+        /// ```
+        pub fn fill_array64(&mut self, array: &mut [u64]) {
+            let n32 = SFMTMEXP::<MEXP, MEXP_N>::SFMT_N32;
+            assert_eq!(
+                self.idx, n32,
+                "fill_array64 requires the state to be consumed"
+            );
+            assert!(
+                array.len() >= n32 / 2 && array.len() % 2 == 0,
+                "fill_array64 requires the length to be a multiple of 2 not smaller than {}",
+                n32 / 2
+            );
+            // Safe since the alignment of `u64` is not smaller than `u32`, and any bit pattern is valid
+            let array32 = unsafe {
+                core::slice::from_raw_parts_mut(array.as_mut_ptr() as *mut u32, array.len() * 2)
+            };
+            SFMTMEXP::<MEXP, MEXP_N>::sfmt_gen_rand_array(&mut self.state, array32);
+            // Two u32 are combined in little endian order regardless of the target endian as [SFMT::pop64]
+            if cfg!(target_endian = "big") {
+                for val in array.iter_mut() {
+                    *val = val.rotate_left(32);
+                }
+            }
+            self.count_words(array.len() * 2);
+        }
+
+        /// Count the shift of the state by `words` 32-bit words
+        fn count_words(&mut self, words: usize) {
+            let n32 = SFMTMEXP::<MEXP, MEXP_N>::SFMT_N32;
+            let shift = self.shift + words;
+            self.count_blocks((shift / n32) as u128);
+            self.shift = shift % n32;
+        }
+
+        /// Rewind the state by one regeneration
+        ///
+        /// This restores the previous 128-bit block state by inverting the recursion,
+        /// and the position in the state is kept, i.e. this is the inverse of `jump(1)`.
+        /// Rewinding `n` blocks takes `O(n * MEXP)` time.
+        ///
+        /// ```
+        /// use rand_core::RngCore;
+        /// let mut rng = sfmt::SFMT19937::new(1234);
+        /// let first: Vec<u32> = (0..2000).map(|_| rng.next_u32()).collect();
+        /// rng.step_back();
+        /// rng.step_back();
+        /// let again: Vec<u32> = (0..(2 * 624)).map(|_| rng.next_u32()).collect();
+        /// assert_eq!(first[(2000 - 2 * 624)..], again[..]);
+        /// ```
+        pub fn step_back(&mut self) {
+            SFMTMEXP::<MEXP, MEXP_N>::sfmt_gen_rand_all_inverse(&mut self.state);
+            let (blocks, borrow) = self.blocks.overflowing_sub(1);
+            self.blocks = blocks;
+            self.stream = self.stream.wrapping_sub(borrow as u64);
+        }
+
+        /// Move back by one 32-bit output and return it
+        ///
+        /// This is the inverse of [RngCore::next_u32],
+        /// i.e. `next_u32` returns the same value again after `prev_u32`.
+        ///
+        /// ```
+        /// use rand_core::RngCore;
+        /// let mut rng = sfmt::SFMT19937::new(1234);
+        /// let a = rng.next_u32();
+        /// let b = rng.next_u32();
+        /// assert_eq!(rng.prev_u32(), b);
+        /// assert_eq!(rng.prev_u32(), a);
+        /// assert_eq!(rng.next_u32(), a);
+        /// ```
+        pub fn prev_u32(&mut self) -> u32 {
+            if self.idx == 0 {
+                self.step_back();
+                self.idx = SFMTMEXP::<MEXP, MEXP_N>::SFMT_N32;
+            }
+            self.idx -= 1;
+            as_u32_slice(&self.state)[self.idx]
+        }
+
+        /// Generate a 128-bit word of the state as the lanes in little endian order
+        ///
+        /// If 32-bit outputs of the current 128-bit word have already been consumed,
+        /// the remaining 32-bit outputs of the current 128-bit word are discarded.
+        ///
+        /// ```
+        /// use rand_core::RngCore;
+        /// let mut rng = sfmt::SFMT19937::new(1234);
+        /// let mut rng_ = rng.clone();
+        /// assert_eq!(rng.next_u128(), rng_.next_u64() as u128 | (rng_.next_u64() as u128) << 64);
+        /// ```
+        pub fn next_u128(&mut self) -> u128 {
+            self.align128();
+            let st = &as_u32_slice(&self.state)[self.idx..(self.idx + 4)];
+            self.idx += 4;
+            st.iter().rev().fold(0, |acc, &val| acc << 32 | val as u128)
+        }
+
+        /// Generate a 128-bit word of the state as `__m128i` to feed SIMD codes directly
+        ///
+        /// This is the same word as [SFMT::next_u128] with the same alignment,
+        /// and available on x86 and x86_64 with SSE2.
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2",
+            not(miri)
+        ))]
+        pub fn next_m128i(&mut self) -> __m128i {
+            self.align128();
+            let val = self.state[self.idx / 4];
+            self.idx += 4;
+            val
+        }
+
+        /// Regenerate the state and lend the whole block of `N32 = 4 * MEXP_N` 32-bit outputs
+        ///
+        /// The block is the next `N32` outputs of [RngCore::next_u32] when the state is consumed,
+        /// and the rest outputs of the current state are discarded otherwise.
+        /// The block is consumed, i.e. the next output is taken from the next block.
+        ///
+        /// The length is `N32`, which cannot be a type `[u32; 4 * MEXP_N]` with the stable const generics.
+        ///
+        /// ```
+        /// use rand_core::RngCore;
+        /// let mut rng = sfmt::SFMT19937::new(1234);
+        /// let block = rng.next_block();
+        /// assert_eq!(block.len(), 624);
+        /// assert_eq!(block[0], 3440181298);
+        /// ```
+        pub fn next_block(&mut self) -> &[u32] {
+            self.gen_all();
+            self.idx = SFMTMEXP::<MEXP, MEXP_N>::SFMT_N32;
+            as_u32_slice(&self.state)
+        }
+
+        /// 64-bit version of [SFMT::next_block] of `N32 / 2` outputs of [RngCore::next_u64]
+        ///
+        /// This is available on little endian targets, where the state is viewed as `u64` without copying.
+        #[cfg(target_endian = "little")]
+        pub fn next_block_u64(&mut self) -> &[u64] {
+            let block = self.next_block();
+            // Safe since the state is aligned as `i32x4`, and a pair of `u32` is a `u64` in little endian
+            unsafe { core::slice::from_raw_parts(block.as_ptr() as *const u64, block.len() / 2) }
+        }
+
+        /// Call `f` with [SFMT::next_block]
+        ///
+        /// ```
+        /// let mut rng = sfmt::SFMT19937::new(1234);
+        /// let sum: u64 = rng.with_block(|block| block.iter().map(|&x| x as u64).sum());
+        /// ```
+        pub fn with_block<R, F>(&mut self, f: F) -> R
+        where
+            F: FnOnce(&[u32]) -> R,
+        {
+            f(self.next_block())
+        }
+
+        /// Move the index to the next boundary of 128-bit words, and regenerate the state if consumed
+        fn align128(&mut self) {
+            self.idx = (self.idx + 3) / 4 * 4;
+            if self.idx >= SFMTMEXP::<MEXP, MEXP_N>::SFMT_N32 {
+                self.gen_all();
+            }
+        }
+
+        /// Generate a floating point number in `[0, 1]` with 32-bit resolution as `sfmt_genrand_real1`
+        ///
+        /// This and the following methods are bit-exact with the original C implementation,
+        /// unlike `rand::Rng::gen::<f64>()`.
+        ///
+        /// ```
+        /// let mut rng = sfmt::SFMT19937::new(1234);
+        /// assert_eq!(rng.real1(), 3440181298.0 / 4294967295.0);
+        /// ```
+        pub fn real1(&mut self) -> f64 {
+            self.next_u32() as f64 * (1.0 / 4294967295.0)
+        }
+
+        /// Generate a floating point number in `[0, 1)` with 32-bit resolution as `sfmt_genrand_real2`
+        pub fn real2(&mut self) -> f64 {
+            self.next_u32() as f64 * (1.0 / 4294967296.0)
+        }
+
+        /// Generate a floating point number in `(0, 1)` with 32-bit resolution as `sfmt_genrand_real3`
+        pub fn real3(&mut self) -> f64 {
+            (self.next_u32() as f64 + 0.5) * (1.0 / 4294967296.0)
+        }
+
+        /// Generate a floating point number in `[0, 1)` with 53-bit resolution as `sfmt_genrand_res53`
+        ///
+        /// This takes the upper 53 bits of [RngCore::next_u64].
+        pub fn res53(&mut self) -> f64 {
+            (self.next_u64() >> 11) as f64 * (1.0 / 9007199254740992.0)
+        }
+
+        /// Generate a floating point number in `[0, 1)` with 53-bit resolution as `sfmt_genrand_res53_mix`
+        ///
+        /// This takes two [RngCore::next_u32] as the lower and upper 32 bits,
+        /// which differs from [SFMT::res53] when the last 32-bit output of the state is left.
+        pub fn res53_mix(&mut self) -> f64 {
+            let x = self.next_u32() as u64;
+            let y = self.next_u32() as u64;
+            ((x | y << 32) >> 11) as f64 * (1.0 / 9007199254740992.0)
+        }
+
+        /// Fill `dest` with floating point numbers in `[0, 1)`
+        ///
+        /// Each number is made from [RngCore::next_u64] by setting its upper 52 bits
+        /// to the mantissa of `1.0` and subtracting `1.0`, and the state is converted by SIMD.
+        /// The resolution is `2^-52`, which differs from `rand::Rng::gen::<f64>()` and [SFMT::res53].
+        ///
+        /// ```
+        /// let mut rng = sfmt::SFMT19937::new(1234);
+        /// let mut dest = [0.0; 1000];
+        /// rng.fill_f64_co(&mut dest);
+        /// assert!(dest.iter().all(|&x| 0.0 <= x && x < 1.0));
+        /// ```
+        pub fn fill_f64_co(&mut self, dest: &mut [f64]) {
+            self.fill_f64(dest, Interval::CloseOpen);
+        }
+
+        /// Fill `dest` with floating point numbers in `(0, 1]` as `2.0 - x` of [SFMT::fill_f64_co]
+        pub fn fill_f64_oc(&mut self, dest: &mut [f64]) {
+            self.fill_f64(dest, Interval::OpenClose);
+        }
+
+        /// Fill `dest` with floating point numbers in `(0, 1)`
+        ///
+        /// The lowest bit of the mantissa is set in [SFMT::fill_f64_co], i.e. the resolution is `2^-51`.
+        pub fn fill_f64_oo(&mut self, dest: &mut [f64]) {
+            self.fill_f64(dest, Interval::OpenOpen);
+        }
+
+        /// Fill `dest` with floating point numbers in `[0, 1)` made from [RngCore::next_u32]
+        ///
+        /// This is the `f32` version of [SFMT::fill_f64_co] by the upper 23 bits,
+        /// and the resolution is `2^-23`.
+        pub fn fill_f32_co(&mut self, dest: &mut [f32]) {
+            self.fill_f32(dest, Interval::CloseOpen);
+        }
+
+        /// Fill `dest` with floating point numbers in `(0, 1]`, see [SFMT::fill_f64_oc]
+        pub fn fill_f32_oc(&mut self, dest: &mut [f32]) {
+            self.fill_f32(dest, Interval::OpenClose);
+        }
+
+        /// Fill `dest` with floating point numbers in `(0, 1)`, see [SFMT::fill_f64_oo]
+        pub fn fill_f32_oo(&mut self, dest: &mut [f32]) {
+            self.fill_f32(dest, Interval::OpenOpen);
+        }
+
+        fn fill_f64(&mut self, mut dest: &mut [f64], interval: Interval) {
+            let n32 = SFMTMEXP::<MEXP, MEXP_N>::SFMT_N32;
+            while !dest.is_empty() {
+                // Drop the last 32-bit output left alone as `next_u64`
+                if self.idx >= n32 - 1 {
+                    self.gen_all();
+                }
+                // Convert 128-bit words from the position, which may be unaligned
+                let vals = &as_u32_slice(&self.state)[self.idx..];
+                let len = dest.len().min(vals.len() / 2);
+                let (head, tail) = dest.split_at_mut(len);
+                let mut chunks = head.chunks_exact_mut(2);
+                for (d, w) in (&mut chunks).zip(vals.chunks_exact(4)) {
+                    d.copy_from_slice(&to_f64(load(w), interval));
+                }
+                let rest = chunks.into_remainder();
+                if !rest.is_empty() {
+                    let w = &vals[(2 * len - 2)..];
+                    rest[0] = to_f64(new(w[0] as i32, w[1] as i32, 0, 0), interval)[0];
+                }
+                self.idx += 2 * len;
+                dest = tail;
+            }
+        }
+
+        fn fill_f32(&mut self, mut dest: &mut [f32], interval: Interval) {
+            let n32 = SFMTMEXP::<MEXP, MEXP_N>::SFMT_N32;
+            while !dest.is_empty() {
+                if self.idx >= n32 {
+                    self.gen_all();
+                }
+                let vals = &as_u32_slice(&self.state)[self.idx..];
+                let len = dest.len().min(vals.len());
+                let (head, tail) = dest.split_at_mut(len);
+                let mut chunks = head.chunks_exact_mut(4);
+                for (d, w) in (&mut chunks).zip(vals.chunks_exact(4)) {
+                    d.copy_from_slice(&to_f32(load(w), interval));
+                }
+                let rest = chunks.into_remainder();
+                if !rest.is_empty() {
+                    let mut w = [0; 4];
+                    w[..rest.len()].copy_from_slice(&vals[(len - rest.len())..len]);
+                    rest.copy_from_slice(&to_f32(load(&w), interval)[..rest.len()]);
+                }
+                self.idx += len;
+                dest = tail;
+            }
+        }
+
+        /// Regenerate the state by the 128-bit kernel, exposed only for benchmarks
+        #[doc(hidden)]
+        #[cfg(feature = "bench")]
+        pub fn bench_gen_all_128(&mut self) {
+            SFMTMEXP::<MEXP, MEXP_N>::sfmt_gen_rand_all_128(&mut self.state);
+            self.count_blocks(1);
+            self.idx = 0;
+        }
+
+        /// Regenerate the state by the AVX2 kernel, exposed only for benchmarks
+        #[doc(hidden)]
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2",
+            not(miri),
+            feature = "std",
+            feature = "bench"
+        ))]
+        pub fn bench_gen_all_avx2(&mut self) {
+            assert!(is_x86_feature_detected!("avx2"));
+            assert!(avx2::is_applicable::<SFMTMEXP<MEXP, MEXP_N>, MEXP, MEXP_N>());
+            unsafe {
+                avx2::sfmt_gen_rand_all::<SFMTMEXP<MEXP, MEXP_N>, MEXP, MEXP_N>(&mut self.state)
+            };
+            self.count_blocks(1);
             self.idx = 0;
         }
     }
@@ -98,16 +690,30 @@ pub mod paramed {
     where
         SFMTMEXP<MEXP, MEXP_N>: SfmtParams<MEXP, MEXP_N>,
     {
-        type Seed = [u8; 4];
+        /// 256-bit seed used as the key of [SFMT::init_by_array]
+        type Seed = [u8; 32];
 
-        fn from_seed(seed: [u8; 4]) -> Self {
-            let mut sfmt = Self {
-                state: [zero(); MEXP_N],
-                idx: 0,
-            };
-            let seed = unsafe { *(seed.as_ptr() as *const u32) };
-            SFMTMEXP::<MEXP, MEXP_N>::sfmt_init_gen_rand(&mut sfmt, seed);
-            sfmt
+        fn from_seed(seed: [u8; 32]) -> Self {
+            // The seed is read as eight u32 in little endian
+            let mut key = [0_u32; 8];
+            for (k, s) in key.iter_mut().zip(seed.chunks_exact(4)) {
+                *k = u32::from_le_bytes([s[0], s[1], s[2], s[3]]);
+            }
+            Self::init_by_array(&key)
+        }
+    }
+
+    /// Copy 32-bit words into bytes in little endian order, where the last word may be truncated
+    fn copy_le_bytes(src: &[u32], dest: &mut [u8]) {
+        if cfg!(target_endian = "little") {
+            // Safe since `u32` consists of four bytes without padding
+            let bytes =
+                unsafe { core::slice::from_raw_parts(src.as_ptr() as *const u8, src.len() * 4) };
+            dest.copy_from_slice(&bytes[..dest.len()]);
+        } else {
+            for (bytes, word) in dest.chunks_mut(4).zip(src) {
+                bytes.copy_from_slice(&word.to_le_bytes()[..bytes.len()]);
+            }
         }
     }
 
@@ -130,12 +736,28 @@ pub mod paramed {
             self.pop64()
         }
 
-        fn fill_bytes(&mut self, dest: &mut [u8]) {
-            impls::fill_bytes_via_next(self, dest)
+        /// Fill bytes by copying the state block by block
+        ///
+        /// The bytes are the 32-bit outputs of [RngCore::next_u32] in little endian order
+        /// on every target and backend, and the rest bytes of the last output are discarded.
+        fn fill_bytes(&mut self, mut dest: &mut [u8]) {
+            let n32 = SFMTMEXP::<MEXP, MEXP_N>::SFMT_N32;
+            while !dest.is_empty() {
+                if self.idx >= n32 {
+                    self.gen_all();
+                }
+                let words = &as_u32_slice(&self.state)[self.idx..];
+                let len = dest.len().min(4 * words.len());
+                let (head, tail) = dest.split_at_mut(len);
+                copy_le_bytes(words, head);
+                self.idx += (len + 3) / 4;
+                dest = tail;
+            }
         }
 
         fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-            Ok(self.fill_bytes(dest))
+            self.fill_bytes(dest);
+            Ok(())
         }
     }
 }
@@ -144,6 +766,325 @@ mod tests {
     use super::*;
     use rand_core::{RngCore, SeedableRng};
 
+    #[test]
+    fn discard() {
+        for &n in &[
+            0,
+            1,
+            19,
+            20,
+            21,
+            1000,
+            20 * 1280,
+            20 * 1281 + 7,
+            20 * 2000 + 3,
+        ] {
+            for &skip in &[0, 1, 19, 20] {
+                let mut rng = SFMT607::new(1234);
+                for _ in 0..skip {
+                    rng.next_u32();
+                }
+                let mut skipped = rng.clone();
+                skipped.discard(n);
+                for _ in 0..n {
+                    rng.next_u32();
+                }
+                assert_eq!(skipped.position(), rng.position());
+                for _ in 0..100 {
+                    assert_eq!(rng.next_u32(), skipped.next_u32());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn position() {
+        let mut rng = SFMT607::new(1234);
+        assert_eq!(rng.position(), 0);
+        for i in 1..=100 {
+            rng.next_u32();
+            assert_eq!(rng.position(), i);
+        }
+        rng.step_back();
+        assert_eq!(rng.position(), 100 - 20);
+        rng.prev_u32();
+        assert_eq!(rng.position(), 100 - 21);
+        // 19 is the last of the state, which is dropped
+        let mut rng = SFMT607::new(1234);
+        rng.discard(19);
+        rng.next_u64();
+        assert_eq!(rng.position(), 22);
+    }
+
+    #[test]
+    fn fill_array() {
+        for &(len, skip) in &[(20, 0), (24, 0), (40, 0), (44, 20), (100, 40), (1000, 20)] {
+            let mut rng = SFMT607::new(1234);
+            let mut filled = rng.clone();
+            for _ in 0..skip {
+                rng.next_u32();
+            }
+            filled.discard(skip);
+            let mut array = vec![0_u32; len];
+            filled.fill_array32(&mut array);
+            for &val in &array {
+                assert_eq!(rng.next_u32(), val);
+            }
+            assert_eq!(filled.position(), rng.position());
+            // The following outputs continue from the array
+            for _ in 0..100 {
+                assert_eq!(rng.next_u32(), filled.next_u32());
+            }
+        }
+        for &len in &[10, 12, 20, 22, 500] {
+            let mut rng = SFMT607::new(1234);
+            let mut filled = rng.clone();
+            let mut array = vec![0_u64; len];
+            filled.fill_array64(&mut array);
+            for &val in &array {
+                assert_eq!(rng.next_u64(), val);
+            }
+            assert_eq!(filled.position(), rng.position());
+            for _ in 0..100 {
+                assert_eq!(rng.next_u64(), filled.next_u64());
+            }
+        }
+    }
+
+    #[test]
+    fn fill_array_unaligned() {
+        let mut rng = SFMT607::new(1234);
+        let mut filled = rng.clone();
+        let mut array = [0_u32; 45];
+        filled.fill_array32(&mut array[1..]);
+        for &val in &array[1..] {
+            assert_eq!(rng.next_u32(), val);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn fill_array_consumed() {
+        let mut rng = SFMT607::new(1234);
+        rng.next_u32();
+        rng.fill_array32(&mut [0; 20]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fill_array_short() {
+        let mut rng = SFMT607::new(1234);
+        rng.fill_array32(&mut [0; 16]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fill_array_not_multiple() {
+        let mut rng = SFMT607::new(1234);
+        rng.fill_array64(&mut [0; 11]);
+    }
+
+    #[test]
+    fn fill_bytes() {
+        for &skip in &[0, 1, 19, 20] {
+            for &len in &[0, 1, 3, 4, 5, 79, 80, 81, 1001] {
+                let mut rng = SFMT607::new(1234);
+                for _ in 0..skip {
+                    rng.next_u32();
+                }
+                let mut filled = rng.clone();
+                let mut bytes = vec![0_u8; len];
+                filled.fill_bytes(&mut bytes);
+                for chunk in bytes.chunks(4) {
+                    assert_eq!(chunk, &rng.next_u32().to_le_bytes()[..chunk.len()]);
+                }
+                assert_eq!(filled.position(), rng.position());
+                assert_eq!(filled.next_u32(), rng.next_u32());
+            }
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)] // the conversions are exact
+    fn fill_float() {
+        let to_f64 = |v: u64| f64::from_bits(v >> 12 | 0x3ff0_0000_0000_0000);
+        let to_f32 = |v: u32| f32::from_bits(v >> 9 | 0x3f80_0000);
+        for &skip in &[0, 1, 2, 3, 19, 20] {
+            for &len in &[0, 1, 2, 3, 9, 10, 11, 21, 1001] {
+                let mut rng = SFMT607::new(1234);
+                for _ in 0..skip {
+                    rng.next_u32();
+                }
+                let mut dest = vec![0.0; len];
+                let mut filled = rng.clone();
+                filled.fill_f64_co(&mut dest);
+                let mut expected = rng.clone();
+                for &x in &dest {
+                    assert_eq!(x, to_f64(expected.next_u64()) - 1.0);
+                }
+                assert_eq!(filled.position(), expected.position());
+                let mut filled = rng.clone();
+                filled.fill_f64_oc(&mut dest);
+                let mut expected = rng.clone();
+                for &x in &dest {
+                    assert_eq!(x, 2.0 - to_f64(expected.next_u64()));
+                }
+                let mut filled = rng.clone();
+                filled.fill_f64_oo(&mut dest);
+                let mut expected = rng.clone();
+                for &x in &dest {
+                    assert_eq!(x, to_f64(expected.next_u64() | 1 << 12) - 1.0);
+                }
+
+                let mut dest = vec![0.0; len];
+                let mut filled = rng.clone();
+                filled.fill_f32_co(&mut dest);
+                let mut expected = rng.clone();
+                for &x in &dest {
+                    assert_eq!(x, to_f32(expected.next_u32()) - 1.0);
+                }
+                assert_eq!(filled.position(), expected.position());
+                let mut filled = rng.clone();
+                filled.fill_f32_oc(&mut dest);
+                let mut expected = rng.clone();
+                for &x in &dest {
+                    assert_eq!(x, 2.0 - to_f32(expected.next_u32()));
+                }
+                let mut filled = rng.clone();
+                filled.fill_f32_oo(&mut dest);
+                let mut expected = rng.clone();
+                for &x in &dest {
+                    assert_eq!(x, to_f32(expected.next_u32() | 1 << 9) - 1.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn next_u128() {
+        let mut rng = SFMT607::new(1234);
+        let mut rng_ = rng.clone();
+        for _ in 0..20 {
+            let val = rng.next_u128();
+            assert_eq!(val as u64, rng_.next_u64());
+            assert_eq!((val >> 64) as u64, rng_.next_u64());
+        }
+        assert_eq!(rng.position(), rng_.position());
+        // The rest of the 128-bit word is discarded
+        for &skip in &[1, 2, 3, 17, 19] {
+            let mut rng = SFMT607::new(1234);
+            let mut rng_ = rng.clone();
+            for _ in 0..skip {
+                rng.next_u32();
+            }
+            let val = rng.next_u128();
+            rng_.discard((skip + 3) / 4 * 4);
+            assert_eq!(val as u32, rng_.next_u32());
+            rng_.discard(3);
+            assert_eq!(rng.position(), rng_.position());
+        }
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2",
+        not(miri)
+    ))]
+    #[test]
+    fn next_m128i() {
+        let mut rng = SFMT607::new(1234);
+        let mut rng_ = rng.clone();
+        rng.next_u32();
+        rng_.next_u32();
+        for _ in 0..20 {
+            let val = rng.next_m128i();
+            let val = (0..4).fold(0, |acc, i| {
+                acc | (packed::extract(val, i) as u128) << (32 * i)
+            });
+            assert_eq!(val, rng_.next_u128());
+        }
+    }
+
+    #[test]
+    fn next_block() {
+        for &skip in &[0, 1, 20, 21] {
+            let mut rng = SFMT607::new(1234);
+            for _ in 0..skip {
+                rng.next_u32();
+            }
+            let mut rng_ = rng.clone();
+            rng_.discard((20 - skip % 20) % 20);
+            for _ in 0..3 {
+                let block = rng.next_block().to_vec();
+                assert_eq!(block.len(), 20);
+                for &val in &block {
+                    assert_eq!(val, rng_.next_u32());
+                }
+                assert_eq!(rng.position(), rng_.position());
+            }
+            assert_eq!(
+                rng.with_block(|block| block.to_vec()),
+                (0..20).map(|_| rng_.next_u32()).collect::<Vec<_>>()
+            );
+            assert_eq!(rng.next_u32(), rng_.next_u32());
+        }
+    }
+
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn next_block_u64() {
+        let mut rng = SFMT607::new(1234);
+        let mut rng_ = rng.clone();
+        for _ in 0..3 {
+            let block = rng.next_block_u64().to_vec();
+            assert_eq!(block.len(), 10);
+            for &val in &block {
+                assert_eq!(val, rng_.next_u64());
+            }
+        }
+        assert_eq!(rng.position(), rng_.position());
+    }
+
+    #[test]
+    fn prev_u32() {
+        let mut rng = SFMT607::new(1234);
+        let forward: Vec<u32> = (0..1000).map(|_| rng.next_u32()).collect();
+        let backward: Vec<u32> = (0..1000).map(|_| rng.prev_u32()).collect();
+        assert!(forward.iter().eq(backward.iter().rev()));
+        assert_eq!(rng.next_u32(), forward[0]);
+    }
+
+    #[test]
+    fn from_seed_by_array() {
+        let mut seed = [0_u8; 32];
+        for (i, s) in seed.iter_mut().enumerate() {
+            *s = i as u8;
+        }
+        let mut rng = SFMT607::from_seed(seed);
+        let mut rng_ = SFMT607::init_by_array(&[
+            0x0302_0100,
+            0x0706_0504,
+            0x0b0a_0908,
+            0x0f0e_0d0c,
+            0x1312_1110,
+            0x1716_1514,
+            0x1b1a_1918,
+            0x1f1e_1d1c,
+        ]);
+        for _ in 0..100 {
+            assert_eq!(rng.next_u32(), rng_.next_u32());
+        }
+    }
+
+    #[test]
+    fn new_u64_by_array() {
+        let mut rng = SFMT607::new_u64(0x1234_5678_9abc_def0);
+        let mut rng_ = SFMT607::init_by_array(&[0x9abc_def0, 0x1234_5678]);
+        for _ in 0..100 {
+            assert_eq!(rng.next_u32(), rng_.next_u32());
+        }
+    }
+
     #[test]
     fn random_607() {
         let mut rng = SFMT607::seed_from_u64(0);
@@ -156,6 +1097,7 @@ mod tests {
         }
     }
     #[test]
+    #[cfg_attr(miri, ignore)] // too slow on Miri
     fn random_19937() {
         let mut rng = SFMT::seed_from_u64(0);
         for _ in 0..19937 * 20 {
@@ -167,6 +1109,7 @@ mod tests {
         }
     }
     #[test]
+    #[cfg_attr(miri, ignore)] // too slow on Miri
     fn random_44497() {
         let mut rng = SFMT44497::seed_from_u64(0);
         for _ in 0..44497 * 20 {
@@ -178,6 +1121,7 @@ mod tests {
         }
     }
     #[test]
+    #[cfg_attr(miri, ignore)] // too slow on Miri
     fn random_86243() {
         let mut rng = SFMT86243::seed_from_u64(0);
         for _ in 0..86243 * 20 {
@@ -189,6 +1133,7 @@ mod tests {
         }
     }
     #[test]
+    #[cfg_attr(miri, ignore)] // too slow on Miri
     fn random_216091() {
         let mut rng = SFMT216091::seed_from_u64(0);
         for _ in 0..216091 * 20 {
@@ -0,0 +1,425 @@
+//! Reproducibility manifest recording a generator by its seed and position
+//!
+//! A manifest is written as a line of text, e.g.
+//!
+//! ```text
+//! SFMT19937 seed=1234 stream=0 position=1000
+//! SFMT607 key=1,2,3 stream=5 position=0
+//! SFMT607 seed=1234 stream=0 position=25 shift=4
+//! ```
+//!
+//! where `seed` is the 32-bit seed of [SFMT::new], and `key` is the key of [SFMT::init_by_array].
+//! The `shift` is written only if the blocks are shifted by [SFMT::fill_array32] or [SFMT::fill_array64].
+
+use crate::{
+    paramed::SFMT,
+    sfmt::{SfmtParams, SFMTMEXP},
+};
+use alloc::{vec, vec::Vec};
+use core::{fmt, str::FromStr};
+
+/// Seed of a generator recorded in [Manifest]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Seed {
+    /// 32-bit seed of [SFMT::new]
+    U32(u32),
+    /// Key of [SFMT::init_by_array], which is also used by [SFMT::new_u64] and [rand_core::SeedableRng]
+    Key(Vec<u32>),
+}
+
+/// [Seed] kept in the generator, which does not allocate for keys up to 8 words
+///
+/// The keys of [SFMT::new_u64] and [rand_core::SeedableRng] are kept inline,
+/// and cloning the generator does not allocate either.
+#[derive(Clone)]
+pub(crate) enum SeedRecord {
+    U32(u32),
+    /// The first `len` words of `key`
+    Short {
+        key: [u32; 8],
+        len: usize,
+    },
+    Long(Vec<u32>),
+}
+
+impl SeedRecord {
+    pub(crate) fn key(key: &[u32]) -> Self {
+        if key.len() <= 8 {
+            let mut short = [0; 8];
+            short[..key.len()].copy_from_slice(key);
+            SeedRecord::Short {
+                key: short,
+                len: key.len(),
+            }
+        } else {
+            SeedRecord::Long(key.to_vec())
+        }
+    }
+
+    fn to_seed(&self) -> Seed {
+        match self {
+            SeedRecord::U32(seed) => Seed::U32(*seed),
+            SeedRecord::Short { key, len } => Seed::Key(key[..*len].to_vec()),
+            SeedRecord::Long(key) => Seed::Key(key.clone()),
+        }
+    }
+}
+
+/// Parameter set, seed, stream index, and position to reproduce a generator
+///
+/// This is taken by [SFMT::manifest], and the generator is recreated by [SFMT::from_manifest].
+/// It is converted to and from a line of text by [fmt::Display] and [FromStr].
+///
+/// ```
+/// use rand_core::RngCore;
+/// let mut rng = sfmt::SFMT607::stream(42, 3);
+/// rng.discard(1000);
+/// let manifest = rng.manifest().unwrap();
+/// assert_eq!(manifest.to_string(), "SFMT607 key=42,0 stream=3 position=1000");
+///
+/// let mut again = sfmt::SFMT607::from_manifest(&manifest).unwrap();
+/// assert_eq!(rng.next_u32(), again.next_u32());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Manifest {
+    /// Mersenne exponent `MEXP` of the generator
+    pub mexp: usize,
+    /// Seed used for the initialization
+    pub seed: Seed,
+    /// Stream index of [SFMT::streams], i.e. the number of `2^128` regenerations of the state
+    pub stream: u64,
+    /// Number of 32-bit outputs consumed in the stream, see [SFMT::position]
+    pub position: u128,
+    /// Offset of the boundaries of blocks in 32-bit outputs, which is moved by [SFMT::fill_array32]
+    /// and [SFMT::fill_array64] unless the length of the array is a multiple of `N32 = 4 * MEXP_N`
+    pub shift: usize,
+}
+
+/// Error for [Manifest]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManifestError {
+    /// The manifest is recorded for another Mersenne exponent
+    Mexp { expected: usize, found: usize },
+    /// The text is not a manifest
+    Syntax,
+    /// The shift is not a multiple of 4 less than `N32 = 4 * MEXP_N` of the generator
+    Shift(usize),
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ManifestError::Mexp { expected, found } => {
+                write!(f, "manifest for SFMT{} is given to SFMT{}", found, expected)
+            }
+            ManifestError::Syntax => write!(f, "invalid manifest"),
+            ManifestError::Shift(shift) => write!(f, "invalid shift {} of blocks", shift),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ManifestError {}
+
+impl fmt::Display for Manifest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SFMT{} ", self.mexp)?;
+        match &self.seed {
+            Seed::U32(seed) => write!(f, "seed={}", seed)?,
+            Seed::Key(key) => {
+                write!(f, "key=")?;
+                for (i, k) in key.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", k)?;
+                }
+            }
+        }
+        write!(f, " stream={} position={}", self.stream, self.position)?;
+        if self.shift > 0 {
+            write!(f, " shift={}", self.shift)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Manifest {
+    type Err = ManifestError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn field<T: FromStr>(token: Option<&str>, name: &str) -> Result<T, ManifestError> {
+            token
+                .and_then(|t| t.strip_prefix(name))
+                .and_then(|t| t.parse().ok())
+                .ok_or(ManifestError::Syntax)
+        }
+
+        let mut tokens = s.split_whitespace();
+        let mexp = field(tokens.next(), "SFMT")?;
+        let seed = match tokens.next() {
+            Some(t) if t.starts_with("seed=") => Seed::U32(field(Some(t), "seed=")?),
+            Some(t) if t == "key=" => Seed::Key(Vec::new()),
+            Some(t) if t.starts_with("key=") => Seed::Key(
+                t["key=".len()..]
+                    .split(',')
+                    .map(|k| k.parse().map_err(|_| ManifestError::Syntax))
+                    .collect::<Result<_, _>>()?,
+            ),
+            _ => return Err(ManifestError::Syntax),
+        };
+        let stream = field(tokens.next(), "stream=")?;
+        let position = field(tokens.next(), "position=")?;
+        let shift = match tokens.next() {
+            Some(t) => field(Some(t), "shift=")?,
+            None => 0,
+        };
+        if tokens.next().is_some() {
+            return Err(ManifestError::Syntax);
+        }
+        Ok(Manifest {
+            mexp,
+            seed,
+            stream,
+            position,
+            shift,
+        })
+    }
+}
+
+impl<const MEXP: usize, const MEXP_N: usize> SFMT<MEXP, MEXP_N>
+where
+    SFMTMEXP<MEXP, MEXP_N>: SfmtParams<MEXP, MEXP_N>,
+{
+    /// Record the seed and the position to reproduce this generator by [SFMT::from_manifest]
+    ///
+    /// This returns `None` if the generator cannot be reproduced from its seed,
+    /// i.e. after [SFMT::jump_2_256], or when the position is before the seed
+    /// or out of 128 bits in the stream.
+    pub fn manifest(&self) -> Option<Manifest> {
+        let n32 = SFMTMEXP::<MEXP, MEXP_N>::SFMT_N32 as u128;
+        let seed = self.seed.as_ref()?.to_seed();
+        let position = self
+            .blocks
+            .checked_mul(n32)?
+            .checked_add((self.shift + self.idx) as u128)?
+            .checked_sub(n32)?;
+        Some(Manifest {
+            mexp: MEXP,
+            seed,
+            stream: self.stream,
+            position,
+            shift: self.shift,
+        })
+    }
+
+    /// Recreate the generator recorded by [SFMT::manifest]
+    ///
+    /// This initializes by the seed, jumps to the stream, and discards outputs to the position
+    /// by [SFMT::discard], which takes `O(MEXP^2)` time at most.
+    /// The shifted blocks are restored by [SFMT::fill_array32] to a temporary array.
+    pub fn from_manifest(manifest: &Manifest) -> Result<Self, ManifestError> {
+        if manifest.mexp != MEXP {
+            return Err(ManifestError::Mexp {
+                expected: MEXP,
+                found: manifest.mexp,
+            });
+        }
+        let n32 = SFMTMEXP::<MEXP, MEXP_N>::SFMT_N32;
+        let shift = manifest.shift;
+        if shift >= n32 || shift % 4 != 0 {
+            return Err(ManifestError::Shift(shift));
+        }
+        let mut sfmt = match &manifest.seed {
+            Seed::U32(seed) => Self::new(*seed),
+            Seed::Key(key) => Self::init_by_array(key),
+        };
+        if manifest.stream > 0 {
+            sfmt.jump_streams(manifest.stream);
+        }
+        if shift == 0 {
+            sfmt.discard(manifest.position);
+            return Ok(sfmt);
+        }
+
+        // The position is `blocks * N32 + shift + idx - N32` with `0 < idx <= N32`
+        let rest = (manifest.position % n32 as u128) as usize + n32 - shift;
+        let idx = match rest % n32 {
+            0 => n32,
+            r => r,
+        };
+        let blocks = manifest.position / n32 as u128 + ((rest - idx) / n32) as u128;
+        // Shift the blocks from the previous boundary, which is before the seed if `blocks == 0`
+        sfmt.discard(blocks.saturating_sub(1) * n32 as u128);
+        sfmt.fill_array32(&mut vec![0; n32 + shift]);
+        if blocks == 0 {
+            sfmt.step_back();
+        }
+        sfmt.idx = idx;
+        Ok(sfmt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use rand_core::{RngCore, SeedableRng};
+
+    fn check_round_trip(rng: &mut SFMT607) {
+        let manifest = rng.manifest().unwrap();
+        assert_eq!(
+            manifest.to_string().parse::<Manifest>(),
+            Ok(manifest.clone())
+        );
+        let mut again = SFMT607::from_manifest(&manifest).unwrap();
+        assert_eq!(again.manifest(), Some(manifest));
+        for _ in 0..100 {
+            assert_eq!(rng.next_u32(), again.next_u32());
+        }
+        // The boundaries of blocks are also kept, where `next_u64` drops an output
+        for _ in 0..100 {
+            assert_eq!(rng.next_u64(), again.next_u64());
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        check_round_trip(&mut SFMT607::new(1234));
+        check_round_trip(&mut SFMT607::new_u64(1234));
+        check_round_trip(&mut SFMT607::init_by_array(&[]));
+        check_round_trip(&mut SFMT607::init_by_array(&[1, 2, 3, 4, 5, 6, 7, 8, 9]));
+        check_round_trip(&mut SFMT607::seed_from_u64(1234));
+        check_round_trip(&mut SFMT607::stream(1234, 7));
+        check_round_trip(&mut SFMT607::streams(1234, 3).pop().unwrap());
+        for &n in &[1, 19, 20, 21, 1000, 100_000] {
+            let mut rng = SFMT607::new(1234);
+            rng.discard(n);
+            check_round_trip(&mut rng);
+        }
+        for &len in &[20, 24, 36, 40, 100] {
+            let mut rng = SFMT607::new(1234);
+            rng.fill_array32(&mut vec![0; len]);
+            check_round_trip(&mut rng);
+            let mut rng = SFMT607::stream(1234, 2);
+            rng.fill_array32(&mut vec![0; len]);
+            rng.next_u32();
+            check_round_trip(&mut rng);
+            let mut rng = SFMT607::new(1234);
+            rng.fill_array64(&mut vec![0; len / 2]);
+            rng.discard(1000);
+            check_round_trip(&mut rng);
+        }
+        let mut rng = SFMT607::new(1234);
+        rng.next_u64();
+        rng.jump_2_64();
+        rng.jump(123);
+        rng.jump_2_128();
+        rng.next_u32();
+        check_round_trip(&mut rng);
+    }
+
+    #[test]
+    fn carry_to_stream() {
+        let mut rng = SFMT607::stream(1234, 2);
+        rng.jump(u128::MAX);
+        rng.jump(1);
+        let manifest = rng.manifest().unwrap();
+        assert_eq!(manifest.stream, 3);
+        assert_eq!(manifest.position, 0);
+        check_round_trip(&mut rng);
+    }
+
+    #[test]
+    fn not_reproducible() {
+        let mut rng = SFMT607::new(1234);
+        rng.next_u32();
+        rng.step_back();
+        assert_eq!(rng.manifest(), None);
+        let mut rng = SFMT607::new(1234);
+        rng.jump_2_256();
+        assert_eq!(rng.manifest(), None);
+    }
+
+    #[test]
+    fn parse() {
+        let manifest: Manifest = "SFMT19937 key=1,2,3 stream=4 position=5".parse().unwrap();
+        assert_eq!(
+            manifest,
+            Manifest {
+                mexp: 19937,
+                seed: Seed::Key(vec![1, 2, 3]),
+                stream: 4,
+                position: 5,
+                shift: 0,
+            }
+        );
+        let manifest: Manifest = "SFMT607 seed=1 stream=0 position=25 shift=4"
+            .parse()
+            .unwrap();
+        assert_eq!(manifest.shift, 4);
+        assert_eq!(
+            manifest.to_string(),
+            "SFMT607 seed=1 stream=0 position=25 shift=4"
+        );
+        for s in &[
+            "",
+            "SFMT19937",
+            "SFMT19937 seed=1 stream=0",
+            "SFMT19937 seed=-1 stream=0 position=0",
+            "SFMT19937 key=1,,2 stream=0 position=0",
+            "SFMT19937 seed=1 stream=0 position=0 extra",
+            "SFMT19937 seed=1 stream=0 position=0 shift=4 extra",
+            "MT19937 seed=1 stream=0 position=0",
+        ] {
+            assert_eq!(s.parse::<Manifest>(), Err(ManifestError::Syntax));
+        }
+    }
+
+    #[test]
+    fn shift() {
+        let mut rng = SFMT607::new(1234);
+        rng.fill_array32(&mut [0; 24]);
+        rng.next_u32();
+        let manifest = rng.manifest().unwrap();
+        assert_eq!(manifest.shift, 4);
+        assert_eq!(manifest.position, 25);
+        let mut again = SFMT607::from_manifest(&manifest).unwrap();
+        for _ in 0..19 {
+            assert_eq!(rng.next_u32(), again.next_u32());
+        }
+        let mut a = [0; 40];
+        let mut b = [0; 40];
+        rng.fill_array32(&mut a);
+        again.fill_array32(&mut b);
+        assert_eq!(a, b);
+
+        // Shifted before the seed
+        let mut rng = SFMT607::new(1234);
+        rng.fill_array32(&mut [0; 24]);
+        rng.step_back();
+        assert_eq!(rng.manifest().unwrap().position, 4);
+        check_round_trip(&mut rng);
+
+        for &shift in &[1, 20, 21] {
+            let mut manifest = SFMT607::new(1234).manifest().unwrap();
+            manifest.shift = shift;
+            assert_eq!(
+                SFMT607::from_manifest(&manifest).err(),
+                Some(ManifestError::Shift(shift))
+            );
+        }
+    }
+
+    #[test]
+    fn mexp_mismatch() {
+        let manifest = SFMT19937::new(1234).manifest().unwrap();
+        assert_eq!(
+            SFMT607::from_manifest(&manifest).err(),
+            Some(ManifestError::Mexp {
+                expected: 607,
+                found: 19937,
+            })
+        );
+    }
+}
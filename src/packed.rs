@@ -1,44 +1,127 @@
 //! packed_simd-like wrapper layer
+//!
+//! The 128-bit state word `i32x4` and the SFMT recursion on it are provided by a backend
+//! selected from the target. x86/x86_64 uses SSE2, aarch64 uses NEON, wasm32 uses simd128
+//! if it is enabled, and other targets use a portable scalar implementation.
+//! NEON and simd128 require rustc 1.59 and 1.54 respectively, which are probed by `build.rs`,
+//! and the scalar one is used with older rustc.
+//! The scalar one is also used on Miri to check the unsafe codes of the generator.
+//! All backends generate the same stream.
 
-#[cfg(target_arch = "x86")]
-use std::arch::x86::*;
-#[cfg(target_arch = "x86_64")]
-use std::arch::x86_64::*;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2",
+    not(miri)
+))]
+mod x86;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2",
+    not(miri)
+))]
+pub(crate) use self::x86::*;
 
-#[allow(non_camel_case_types)]
-pub(crate) type i32x4 = __m128i;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon", sfmt_neon, not(miri)))]
+mod aarch64;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon", sfmt_neon, not(miri)))]
+pub(crate) use self::aarch64::*;
 
-pub(crate) fn new(e0: i32, e1: i32, e2: i32, e3: i32) -> i32x4 {
-    unsafe { _mm_set_epi32(e3, e2, e1, e0) }
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    sfmt_simd128,
+    not(miri)
+))]
+mod wasm32;
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    sfmt_simd128,
+    not(miri)
+))]
+pub(crate) use self::wasm32::*;
+
+// The scalar backend is always compiled for tests to check it against the SIMD one.
+#[cfg(any(
+    test,
+    not(any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2",
+            not(miri)
+        ),
+        all(target_arch = "aarch64", target_feature = "neon", sfmt_neon, not(miri)),
+        all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            sfmt_simd128,
+            not(miri)
+        ),
+    ))
+))]
+mod scalar;
+#[cfg(not(any(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2",
+        not(miri)
+    ),
+    all(target_arch = "aarch64", target_feature = "neon", sfmt_neon, not(miri)),
+    all(
+        target_arch = "wasm32",
+        target_feature = "simd128",
+        sfmt_simd128,
+        not(miri)
+    ),
+)))]
+pub(crate) use self::scalar::*;
+
+/// Interval of floating point numbers converted by `to_f64` and `to_f32` of the backends
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Interval {
+    /// `[0, 1)`
+    CloseOpen,
+    /// `(0, 1]`
+    OpenClose,
+    /// `(0, 1)`
+    OpenOpen,
+}
+
+/// Bits of `1.0_f64`, whose mantissa is filled by random bits to make `[1, 2)`
+pub(crate) const ONE_F64: u64 = 0x3ff0_0000_0000_0000;
+/// Bits of `1.0_f32`
+pub(crate) const ONE_F32: u32 = 0x3f80_0000;
+
+// `i32x4` of every backend has the layout of `[u32; 4]`
+const _: [(); 16] = [(); core::mem::size_of::<i32x4>()];
+
+/// View 128-bit words as 32-bit words, where the lane `i` of `vals[j]` is at `4 * j + i`.
+#[inline]
+pub(crate) fn as_u32_slice(vals: &[i32x4]) -> &[u32] {
+    // Safe since `i32x4` consists of four `u32` lanes from the lowest one,
+    // and its alignment is not smaller than `u32`.
+    unsafe { core::slice::from_raw_parts(vals.as_ptr() as *const u32, vals.len() * 4) }
 }
 
-pub(crate) fn zero() -> i32x4 {
-    unsafe { _mm_setzero_si128() }
+/// Load a 128-bit word from the first four 32-bit words, which may be unaligned
+#[inline]
+pub(crate) fn load(vals: &[u32]) -> i32x4 {
+    let vals = &vals[..4];
+    // Safe since `i32x4` has the layout of `[u32; 4]`
+    unsafe { core::ptr::read_unaligned(vals.as_ptr() as *const i32x4) }
 }
 
-pub(crate) fn extract(vals: i32x4, imm: usize) -> u32 {
-    unsafe {
-        match imm {
-            0 => _mm_extract_epi32(vals, 0) as u32,
-            1 => _mm_extract_epi32(vals, 1) as u32,
-            2 => _mm_extract_epi32(vals, 2) as u32,
-            3 => _mm_extract_epi32(vals, 3) as u32,
-            _ => core::hint::unreachable_unchecked(),
-        }
-    }
+/// Store a 128-bit word into the first four 32-bit words, which may be unaligned
+#[inline]
+pub(crate) fn store(vals: &mut [u32], val: i32x4) {
+    let vals = &mut vals[..4];
+    // Safe as [load]
+    unsafe { core::ptr::write_unaligned(vals.as_mut_ptr() as *mut i32x4, val) }
 }
 
-pub(crate) fn insert(vals: &mut i32x4, val: i32, imm: usize) {
-    let updated = unsafe {
-        match imm {
-            0 => _mm_insert_epi32(*vals, val, 0),
-            1 => _mm_insert_epi32(*vals, val, 1),
-            2 => _mm_insert_epi32(*vals, val, 2),
-            3 => _mm_insert_epi32(*vals, val, 3),
-            _ => core::hint::unreachable_unchecked(),
-        }
-    };
-    unsafe {
-        ::std::ptr::write(vals, updated);
-    }
+/// Mutable version of [as_u32_slice]
+#[inline]
+pub(crate) fn as_u32_slice_mut(vals: &mut [i32x4]) -> &mut [u32] {
+    // Safe as [as_u32_slice], and any bit pattern is a valid `i32x4`
+    unsafe { core::slice::from_raw_parts_mut(vals.as_mut_ptr() as *mut u32, vals.len() * 4) }
 }
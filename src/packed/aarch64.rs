@@ -0,0 +1,129 @@
+//! NEON backend for aarch64
+//!
+//! The NEON intrinsics are safe to call here since this module is compiled only if NEON is enabled.
+
+use core::arch::aarch64::*;
+
+use super::{Interval, ONE_F32, ONE_F64};
+use crate::sfmt::SfmtParams;
+
+#[allow(non_camel_case_types)]
+pub(crate) type i32x4 = uint32x4_t;
+
+pub(crate) fn new(e0: i32, e1: i32, e2: i32, e3: i32) -> i32x4 {
+    let vals = [e0 as u32, e1 as u32, e2 as u32, e3 as u32];
+    unsafe { vld1q_u32(vals.as_ptr()) }
+}
+
+pub(crate) fn zero() -> i32x4 {
+    unsafe { vdupq_n_u32(0) }
+}
+
+pub(crate) fn extract(vals: i32x4, imm: usize) -> u32 {
+    unsafe {
+        match imm {
+            0 => vgetq_lane_u32(vals, 0),
+            1 => vgetq_lane_u32(vals, 1),
+            2 => vgetq_lane_u32(vals, 2),
+            3 => vgetq_lane_u32(vals, 3),
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub(crate) fn insert(vals: &mut i32x4, val: i32, imm: usize) {
+    let val = val as u32;
+    *vals = unsafe {
+        match imm {
+            0 => vsetq_lane_u32(val, *vals, 0),
+            1 => vsetq_lane_u32(val, *vals, 1),
+            2 => vsetq_lane_u32(val, *vals, 2),
+            3 => vsetq_lane_u32(val, *vals, 3),
+            _ => unreachable!(),
+        }
+    };
+}
+
+/// Shift 128-bit word to left by `bytes` bytes as `_mm_slli_si128` does.
+///
+/// The shift is composed from 64-bit lane shifts, so `bytes` must be less than 8,
+/// which holds for all SFMT parameters.
+unsafe fn lshift128(a: i32x4, bytes: i32) -> i32x4 {
+    let a = vreinterpretq_u64_u32(a);
+    let s = 8 * bytes as i64;
+    let t = vshlq_u64(a, vdupq_n_s64(s));
+    let u = vshlq_u64(a, vdupq_n_s64(s - 64)); // carry from the lower lane
+    vreinterpretq_u32_u64(vorrq_u64(t, vextq_u64(vdupq_n_u64(0), u, 1)))
+}
+
+/// Shift 128-bit word to right by `bytes` bytes as `_mm_srli_si128` does.
+///
+/// `bytes` must be less than 8 as [lshift128].
+unsafe fn rshift128(a: i32x4, bytes: i32) -> i32x4 {
+    let a = vreinterpretq_u64_u32(a);
+    let s = 8 * bytes as i64;
+    let t = vshlq_u64(a, vdupq_n_s64(-s));
+    let u = vshlq_u64(a, vdupq_n_s64(64 - s)); // carry from the upper lane
+    vreinterpretq_u32_u64(vorrq_u64(t, vextq_u64(u, vdupq_n_u64(0), 1)))
+}
+
+#[allow(clippy::many_single_char_names)]
+pub(crate) fn mm_recursion<P, const MEXP: usize, const MEXP_N: usize>(
+    a: i32x4,
+    b: i32x4,
+    c: i32x4,
+    d: i32x4,
+) -> i32x4
+where
+    P: SfmtParams<MEXP, MEXP_N>,
+{
+    unsafe {
+        let mask = new(P::SFMT_MSK1, P::SFMT_MSK2, P::SFMT_MSK3, P::SFMT_MSK4);
+        // NEON shifts a vector by a vector, where a negative count means a right shift
+        let y = vshlq_u32(b, vdupq_n_s32(-P::SFMT_SR1));
+        let z = rshift128(c, P::SFMT_SR2);
+        let v = vshlq_u32(d, vdupq_n_s32(P::SFMT_SL1));
+        let z = veorq_u32(z, a);
+        let z = veorq_u32(z, v);
+        let x = lshift128(a, P::SFMT_SL2);
+        let y = vandq_u32(y, mask);
+        let z = veorq_u32(z, x);
+        veorq_u32(z, y)
+    }
+}
+
+/// Convert two 64-bit lanes into `f64` in the interval by their upper 52 bits
+///
+/// The bits are set to the mantissa of `1.0` to make `[1, 2)`, and it is moved to the interval.
+#[inline]
+pub(crate) fn to_f64(vals: i32x4, interval: Interval) -> [f64; 2] {
+    unsafe {
+        let mut bits = vshlq_u64(vreinterpretq_u64_u32(vals), vdupq_n_s64(-12));
+        if interval == Interval::OpenOpen {
+            bits = vorrq_u64(bits, vdupq_n_u64(1));
+        }
+        let x = vreinterpretq_f64_u64(vorrq_u64(bits, vdupq_n_u64(ONE_F64)));
+        let y = match interval {
+            Interval::OpenClose => vsubq_f64(vdupq_n_f64(2.0), x),
+            _ => vsubq_f64(x, vdupq_n_f64(1.0)),
+        };
+        core::mem::transmute(y)
+    }
+}
+
+/// Convert four 32-bit lanes into `f32` in the interval by their upper 23 bits as [to_f64]
+#[inline]
+pub(crate) fn to_f32(vals: i32x4, interval: Interval) -> [f32; 4] {
+    unsafe {
+        let mut bits = vshlq_u32(vals, vdupq_n_s32(-9));
+        if interval == Interval::OpenOpen {
+            bits = vorrq_u32(bits, vdupq_n_u32(1));
+        }
+        let x = vreinterpretq_f32_u32(vorrq_u32(bits, vdupq_n_u32(ONE_F32)));
+        let y = match interval {
+            Interval::OpenClose => vsubq_f32(vdupq_n_f32(2.0), x),
+            _ => vsubq_f32(x, vdupq_n_f32(1.0)),
+        };
+        core::mem::transmute(y)
+    }
+}
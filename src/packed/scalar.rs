@@ -0,0 +1,269 @@
+//! Portable scalar backend for targets without a supported SIMD instruction set
+
+use super::{Interval, ONE_F32, ONE_F64};
+use crate::sfmt::SfmtParams;
+
+/// 128-bit word as four 32-bit lanes, where the lane 0 is the least significant one.
+///
+/// This is aligned as `__m128i` to keep the layout of the state array same as the SIMD backends.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy)]
+#[repr(C, align(16))]
+pub struct i32x4([u32; 4]);
+
+pub(crate) fn new(e0: i32, e1: i32, e2: i32, e3: i32) -> i32x4 {
+    i32x4([e0 as u32, e1 as u32, e2 as u32, e3 as u32])
+}
+
+pub(crate) fn zero() -> i32x4 {
+    i32x4([0; 4])
+}
+
+pub(crate) fn extract(vals: i32x4, imm: usize) -> u32 {
+    vals.0[imm]
+}
+
+pub(crate) fn insert(vals: &mut i32x4, val: i32, imm: usize) {
+    vals.0[imm] = val as u32;
+}
+
+fn to_u128(v: i32x4) -> u128 {
+    let [e0, e1, e2, e3] = v.0;
+    (e3 as u128) << 96 | (e2 as u128) << 64 | (e1 as u128) << 32 | e0 as u128
+}
+
+fn from_u128(v: u128) -> i32x4 {
+    i32x4([
+        v as u32,
+        (v >> 32) as u32,
+        (v >> 64) as u32,
+        (v >> 96) as u32,
+    ])
+}
+
+#[allow(clippy::many_single_char_names)]
+pub(crate) fn mm_recursion<P, const MEXP: usize, const MEXP_N: usize>(
+    a: i32x4,
+    b: i32x4,
+    c: i32x4,
+    d: i32x4,
+) -> i32x4
+where
+    P: SfmtParams<MEXP, MEXP_N>,
+{
+    let mask = [P::SFMT_MSK1, P::SFMT_MSK2, P::SFMT_MSK3, P::SFMT_MSK4];
+    let x = from_u128(to_u128(a) << (8 * P::SFMT_SL2));
+    let z = from_u128(to_u128(c) >> (8 * P::SFMT_SR2));
+    let mut r = zero();
+    for (i, r) in r.0.iter_mut().enumerate() {
+        let y = (b.0[i] >> P::SFMT_SR1) & mask[i] as u32;
+        let v = d.0[i] << P::SFMT_SL1;
+        *r = a.0[i] ^ x.0[i] ^ y ^ z.0[i] ^ v;
+    }
+    r
+}
+
+/// Convert two 64-bit lanes into `f64` in the interval by their upper 52 bits
+///
+/// The bits are set to the mantissa of `1.0` to make `[1, 2)`, and it is moved to the interval.
+#[inline]
+pub(crate) fn to_f64(vals: i32x4, interval: Interval) -> [f64; 2] {
+    let mut r = [0.0; 2];
+    for (i, r) in r.iter_mut().enumerate() {
+        let mut bits = (vals.0[2 * i] as u64 | (vals.0[2 * i + 1] as u64) << 32) >> 12;
+        if interval == Interval::OpenOpen {
+            bits |= 1;
+        }
+        let x = f64::from_bits(bits | ONE_F64);
+        *r = match interval {
+            Interval::OpenClose => 2.0 - x,
+            _ => x - 1.0,
+        };
+    }
+    r
+}
+
+/// Convert four 32-bit lanes into `f32` in the interval by their upper 23 bits as [to_f64]
+#[inline]
+pub(crate) fn to_f32(vals: i32x4, interval: Interval) -> [f32; 4] {
+    let mut r = [0.0; 4];
+    for (r, &val) in r.iter_mut().zip(vals.0.iter()) {
+        let mut bits = val >> 9;
+        if interval == Interval::OpenOpen {
+            bits |= 1;
+        }
+        let x = f32::from_bits(bits | ONE_F32);
+        *r = match interval {
+            Interval::OpenClose => 2.0 - x,
+            _ => x - 1.0,
+        };
+    }
+    r
+}
+
+#[cfg(test)]
+#[allow(clippy::many_single_char_names)]
+mod tests {
+    use super::*;
+    use crate::sfmt::SFMTMEXP;
+
+    #[test]
+    fn insert_extract() {
+        let mut a = zero();
+        for i in 0..4 {
+            insert(&mut a, i as i32 + 1, i);
+        }
+        let b = new(1, 2, 3, 4);
+        for i in 0..4 {
+            assert_eq!(extract(a, i), extract(b, i));
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)] // the conversions are exact
+    fn float_interval() {
+        // The extreme outputs
+        for &(val, co, oc, oo) in &[
+            (0, 0.0, 1.0, f64::EPSILON),
+            (
+                u64::MAX,
+                1.0 - f64::EPSILON,
+                f64::EPSILON,
+                1.0 - f64::EPSILON,
+            ),
+        ] {
+            let val = new(val as i32, (val >> 32) as i32, 0, 0);
+            assert_eq!(to_f64(val, Interval::CloseOpen)[0], co);
+            assert_eq!(to_f64(val, Interval::OpenClose)[0], oc);
+            assert_eq!(to_f64(val, Interval::OpenOpen)[0], oo);
+        }
+        for &(val, co, oc, oo) in &[
+            (0, 0.0, 1.0, f32::EPSILON),
+            (
+                u32::MAX,
+                1.0 - f32::EPSILON,
+                f32::EPSILON,
+                1.0 - f32::EPSILON,
+            ),
+        ] {
+            let val = new(val as i32, 0, 0, 0);
+            assert_eq!(to_f32(val, Interval::CloseOpen)[0], co);
+            assert_eq!(to_f32(val, Interval::OpenClose)[0], oc);
+            assert_eq!(to_f32(val, Interval::OpenOpen)[0], oo);
+        }
+    }
+
+    #[test]
+    fn mm_recursion_19937() {
+        type P = SFMTMEXP<19937, { 19937 / 128 + 1 }>;
+        let a = new(1, 2, 3, 4);
+        let z = mm_recursion::<P, 19937, { 19937 / 128 + 1 }>(a, a, a, a);
+        assert_eq!(z.0, [33816833, 50856450, 67896067, 1049604]); // calculated by C code
+
+        let b = new(431, 232, 83, 14);
+        let c = new(213, 22, 93, 234);
+        let d = new(112, 882, 23, 124);
+        let z = mm_recursion::<P, 19937, { 19937 / 128 + 1 }>(a, b, c, d);
+        let zc = new(398459137, 1355284994, -363068669, 32506884); // calculated by C code
+        assert_eq!(z.0, zc.0);
+    }
+
+    #[cfg(any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2",
+            not(miri)
+        ),
+        all(target_arch = "aarch64", target_feature = "neon", sfmt_neon, not(miri)),
+        all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            sfmt_simd128,
+            not(miri)
+        ),
+    ))]
+    macro_rules! compare_to_simd {
+        ($name:ident, $mexp:expr) => {
+            #[test]
+            fn $name() {
+                use crate::packed as simd;
+                type P = SFMTMEXP<$mexp, { $mexp / 128 + 1 }>;
+                let to_simd = |v: i32x4| {
+                    simd::new(v.0[0] as i32, v.0[1] as i32, v.0[2] as i32, v.0[3] as i32)
+                };
+                // xorshift32 to make inputs
+                let mut seed = 2463534242_u32;
+                let mut next = || {
+                    seed ^= seed << 13;
+                    seed ^= seed >> 17;
+                    seed ^= seed << 5;
+                    seed as i32
+                };
+                for _ in 0..100 {
+                    let mut v = [zero(); 4];
+                    for v in v.iter_mut() {
+                        *v = new(next(), next(), next(), next());
+                    }
+                    let z = mm_recursion::<P, $mexp, { $mexp / 128 + 1 }>(v[0], v[1], v[2], v[3]);
+                    let zs = simd::mm_recursion::<P, $mexp, { $mexp / 128 + 1 }>(
+                        to_simd(v[0]),
+                        to_simd(v[1]),
+                        to_simd(v[2]),
+                        to_simd(v[3]),
+                    );
+                    for i in 0..4 {
+                        assert_eq!(extract(z, i), simd::extract(zs, i));
+                    }
+                }
+            }
+        };
+    }
+
+    #[cfg(any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2",
+            not(miri)
+        ),
+        all(target_arch = "aarch64", target_feature = "neon", sfmt_neon, not(miri)),
+        all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            sfmt_simd128,
+            not(miri)
+        ),
+    ))]
+    mod simd {
+        use super::*;
+
+        #[test]
+        #[allow(clippy::float_cmp)] // the conversions are exact
+        fn to_float() {
+            use crate::packed::{self as simd, Interval};
+            let vals = [
+                new(0, 0, 0, 0),
+                new(-1, -1, -1, -1),
+                new(1234, -5678, 0x0800, -0x1000),
+                new(0x7fff_ffff, 0x1234_5678, -0x8000_0000, 0x0000_0fff),
+            ];
+            for &v in &vals {
+                let vs = simd::new(v.0[0] as i32, v.0[1] as i32, v.0[2] as i32, v.0[3] as i32);
+                for &interval in &[Interval::CloseOpen, Interval::OpenClose, Interval::OpenOpen] {
+                    assert_eq!(to_f64(v, interval), simd::to_f64(vs, interval));
+                    assert_eq!(to_f32(v, interval), simd::to_f32(vs, interval));
+                }
+            }
+        }
+
+        compare_to_simd!(compare_to_simd_607, 607);
+        compare_to_simd!(compare_to_simd_1279, 1279);
+        compare_to_simd!(compare_to_simd_2281, 2281);
+        compare_to_simd!(compare_to_simd_4253, 4253);
+        compare_to_simd!(compare_to_simd_11213, 11213);
+        compare_to_simd!(compare_to_simd_19937, 19937);
+        compare_to_simd!(compare_to_simd_44497, 44497);
+        compare_to_simd!(compare_to_simd_86243, 86243);
+        compare_to_simd!(compare_to_simd_132049, 132049);
+        compare_to_simd!(compare_to_simd_216091, 216091);
+    }
+}
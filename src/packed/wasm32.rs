@@ -0,0 +1,119 @@
+//! simd128 backend for wasm32
+
+use core::arch::wasm32::{
+    f32x4_splat, f32x4_sub, f64x2_splat, f64x2_sub, i32x4_shl, i32x4_splat, i64x2_shl,
+    i64x2_shuffle, i64x2_splat, u32x4, u32x4_extract_lane, u32x4_replace_lane, u32x4_shr,
+    u64x2_shr, v128, v128_and, v128_or, v128_xor,
+};
+
+use super::{Interval, ONE_F32, ONE_F64};
+use crate::sfmt::SfmtParams;
+
+#[allow(non_camel_case_types)]
+pub(crate) type i32x4 = v128;
+
+pub(crate) fn new(e0: i32, e1: i32, e2: i32, e3: i32) -> i32x4 {
+    u32x4(e0 as u32, e1 as u32, e2 as u32, e3 as u32)
+}
+
+pub(crate) fn zero() -> i32x4 {
+    u32x4(0, 0, 0, 0)
+}
+
+pub(crate) fn extract(vals: i32x4, imm: usize) -> u32 {
+    match imm {
+        0 => u32x4_extract_lane::<0>(vals),
+        1 => u32x4_extract_lane::<1>(vals),
+        2 => u32x4_extract_lane::<2>(vals),
+        3 => u32x4_extract_lane::<3>(vals),
+        _ => unreachable!(),
+    }
+}
+
+pub(crate) fn insert(vals: &mut i32x4, val: i32, imm: usize) {
+    let val = val as u32;
+    *vals = match imm {
+        0 => u32x4_replace_lane::<0>(*vals, val),
+        1 => u32x4_replace_lane::<1>(*vals, val),
+        2 => u32x4_replace_lane::<2>(*vals, val),
+        3 => u32x4_replace_lane::<3>(*vals, val),
+        _ => unreachable!(),
+    };
+}
+
+/// Shift 128-bit word to left by `bytes` bytes as `_mm_slli_si128` does.
+///
+/// The shift is composed from 64-bit lane shifts whose count is taken modulo 64,
+/// so `bytes` must be in `1..8`, which holds for all SFMT parameters.
+fn lshift128(a: i32x4, bytes: i32) -> i32x4 {
+    let s = 8 * bytes as u32;
+    let t = i64x2_shl(a, s);
+    let u = u64x2_shr(a, 64 - s); // carry from the lower lane
+    v128_or(t, i64x2_shuffle::<2, 0>(u, zero()))
+}
+
+/// Shift 128-bit word to right by `bytes` bytes as `_mm_srli_si128` does.
+///
+/// `bytes` must be in `1..8` as [lshift128].
+fn rshift128(a: i32x4, bytes: i32) -> i32x4 {
+    let s = 8 * bytes as u32;
+    let t = u64x2_shr(a, s);
+    let u = i64x2_shl(a, 64 - s); // carry from the upper lane
+    v128_or(t, i64x2_shuffle::<1, 2>(u, zero()))
+}
+
+#[allow(clippy::many_single_char_names)]
+pub(crate) fn mm_recursion<P, const MEXP: usize, const MEXP_N: usize>(
+    a: i32x4,
+    b: i32x4,
+    c: i32x4,
+    d: i32x4,
+) -> i32x4
+where
+    P: SfmtParams<MEXP, MEXP_N>,
+{
+    let mask = new(P::SFMT_MSK1, P::SFMT_MSK2, P::SFMT_MSK3, P::SFMT_MSK4);
+    let y = u32x4_shr(b, P::SFMT_SR1 as u32);
+    let z = rshift128(c, P::SFMT_SR2);
+    let v = i32x4_shl(d, P::SFMT_SL1 as u32);
+    let z = v128_xor(z, a);
+    let z = v128_xor(z, v);
+    let x = lshift128(a, P::SFMT_SL2);
+    let y = v128_and(y, mask);
+    let z = v128_xor(z, x);
+    v128_xor(z, y)
+}
+
+/// Convert two 64-bit lanes into `f64` in the interval by their upper 52 bits
+///
+/// The bits are set to the mantissa of `1.0` to make `[1, 2)`, and it is moved to the interval.
+#[inline]
+pub(crate) fn to_f64(vals: i32x4, interval: Interval) -> [f64; 2] {
+    let mut bits = u64x2_shr(vals, 12);
+    if interval == Interval::OpenOpen {
+        bits = v128_or(bits, i64x2_splat(1));
+    }
+    let x = v128_or(bits, i64x2_splat(ONE_F64 as i64));
+    let y = match interval {
+        Interval::OpenClose => f64x2_sub(f64x2_splat(2.0), x),
+        _ => f64x2_sub(x, f64x2_splat(1.0)),
+    };
+    // Safe since `v128` has the layout of `[f64; 2]`
+    unsafe { core::mem::transmute(y) }
+}
+
+/// Convert four 32-bit lanes into `f32` in the interval by their upper 23 bits as [to_f64]
+#[inline]
+pub(crate) fn to_f32(vals: i32x4, interval: Interval) -> [f32; 4] {
+    let mut bits = u32x4_shr(vals, 9);
+    if interval == Interval::OpenOpen {
+        bits = v128_or(bits, i32x4_splat(1));
+    }
+    let x = v128_or(bits, i32x4_splat(ONE_F32 as i32));
+    let y = match interval {
+        Interval::OpenClose => f32x4_sub(f32x4_splat(2.0), x),
+        _ => f32x4_sub(x, f32x4_splat(1.0)),
+    };
+    // Safe since `v128` has the layout of `[f32; 4]`
+    unsafe { core::mem::transmute(y) }
+}
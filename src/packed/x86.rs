@@ -0,0 +1,136 @@
+//! SSE2 backend for x86/x86_64
+//!
+//! The SSE2 intrinsics are safe to call here since this module is compiled only if SSE2 is enabled.
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use super::{Interval, ONE_F32, ONE_F64};
+use crate::sfmt::SfmtParams;
+
+pub(crate) mod avx2;
+
+#[allow(non_camel_case_types)]
+pub(crate) type i32x4 = __m128i;
+
+pub(crate) fn new(e0: i32, e1: i32, e2: i32, e3: i32) -> i32x4 {
+    unsafe { _mm_set_epi32(e3, e2, e1, e0) }
+}
+
+pub(crate) fn zero() -> i32x4 {
+    unsafe { _mm_setzero_si128() }
+}
+
+pub(crate) fn extract(vals: i32x4, imm: usize) -> u32 {
+    // `_mm_extract_epi32` requires SSE4.1, so the lane is moved to the lowest one by SSE2
+    unsafe {
+        let vals = match imm {
+            0 => vals,
+            1 => _mm_shuffle_epi32(vals, 1),
+            2 => _mm_shuffle_epi32(vals, 2),
+            3 => _mm_shuffle_epi32(vals, 3),
+            _ => unreachable!(),
+        };
+        _mm_cvtsi128_si32(vals) as u32
+    }
+}
+
+pub(crate) fn insert(vals: &mut i32x4, val: i32, imm: usize) {
+    // `_mm_insert_epi32` requires SSE4.1, so the lane is blended by a mask
+    let mut mask = [0; 4];
+    mask[imm] = -1;
+    let mask = new(mask[0], mask[1], mask[2], mask[3]);
+    unsafe {
+        *vals = _mm_or_si128(
+            _mm_andnot_si128(mask, *vals),
+            _mm_and_si128(mask, _mm_set1_epi32(val)),
+        );
+    }
+}
+
+#[allow(clippy::many_single_char_names)]
+pub(crate) fn mm_recursion<P, const MEXP: usize, const MEXP_N: usize>(
+    a: i32x4,
+    b: i32x4,
+    c: i32x4,
+    d: i32x4,
+) -> i32x4
+where
+    P: SfmtParams<MEXP, MEXP_N>,
+{
+    unsafe {
+        let mask = new(P::SFMT_MSK1, P::SFMT_MSK2, P::SFMT_MSK3, P::SFMT_MSK4);
+        let y = _mm_srli_epi32(b, P::SFMT_SR1);
+        let z = _mm_srli_si128(c, P::SFMT_SR2);
+        let v = _mm_slli_epi32(d, P::SFMT_SL1);
+        let z = _mm_xor_si128(z, a);
+        let z = _mm_xor_si128(z, v);
+        let x = _mm_slli_si128(a, P::SFMT_SL2);
+        let y = _mm_and_si128(y, mask);
+        let z = _mm_xor_si128(z, x);
+        _mm_xor_si128(z, y)
+    }
+}
+
+/// Convert two 64-bit lanes into `f64` in the interval by their upper 52 bits
+///
+/// The bits are set to the mantissa of `1.0` to make `[1, 2)`, and it is moved to the interval.
+#[inline]
+pub(crate) fn to_f64(vals: i32x4, interval: Interval) -> [f64; 2] {
+    unsafe {
+        let mut bits = _mm_srli_epi64(vals, 12);
+        if interval == Interval::OpenOpen {
+            bits = _mm_or_si128(bits, _mm_set1_epi64x(1));
+        }
+        let x = _mm_castsi128_pd(_mm_or_si128(bits, _mm_set1_epi64x(ONE_F64 as i64)));
+        let y = match interval {
+            Interval::OpenClose => _mm_sub_pd(_mm_set1_pd(2.0), x),
+            _ => _mm_sub_pd(x, _mm_set1_pd(1.0)),
+        };
+        core::mem::transmute(y)
+    }
+}
+
+/// Convert four 32-bit lanes into `f32` in the interval by their upper 23 bits as [to_f64]
+#[inline]
+pub(crate) fn to_f32(vals: i32x4, interval: Interval) -> [f32; 4] {
+    unsafe {
+        let mut bits = _mm_srli_epi32(vals, 9);
+        if interval == Interval::OpenOpen {
+            bits = _mm_or_si128(bits, _mm_set1_epi32(1));
+        }
+        let x = _mm_castsi128_ps(_mm_or_si128(bits, _mm_set1_epi32(ONE_F32 as i32)));
+        let y = match interval {
+            Interval::OpenClose => _mm_sub_ps(_mm_set1_ps(2.0), x),
+            _ => _mm_sub_ps(x, _mm_set1_ps(1.0)),
+        };
+        core::mem::transmute(y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_insert() {
+        let mut a = new(1, 2, 3, -4);
+        assert_eq!(extract(a, 0), 1);
+        assert_eq!(extract(a, 1), 2);
+        assert_eq!(extract(a, 2), 3);
+        assert_eq!(extract(a, 3), -4_i32 as u32);
+        for i in 0..4 {
+            insert(&mut a, 10 + i as i32, i);
+            for j in 0..4 {
+                let expected = if j <= i {
+                    10 + j as u32
+                } else {
+                    extract(new(1, 2, 3, -4), j)
+                };
+                assert_eq!(extract(a, j), expected);
+            }
+        }
+    }
+}
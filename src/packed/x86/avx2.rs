@@ -0,0 +1,126 @@
+//! AVX2 kernel regenerating two 128-bit state words per instruction
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use super::{i32x4, mm_recursion, new};
+use crate::sfmt::SfmtParams;
+
+/// Check if the pairwise kernel is applicable to the parameter set.
+///
+/// `b` of a pair must not be updated in the pair itself (`2 <= POS1`),
+/// and must be updated before the pair after wrapping around (`POS1 <= N - 2`).
+pub(crate) fn is_applicable<P, const MEXP: usize, const MEXP_N: usize>() -> bool
+where
+    P: SfmtParams<MEXP, MEXP_N>,
+{
+    P::SFMT_POS1 >= 2 && P::SFMT_POS1 + 2 <= P::SFMT_N
+}
+
+/// Regenerate the whole state as [SfmtParams::sfmt_gen_rand_all] does.
+///
+/// `st[i]` depends on `st[i - 1]` only through the `d << SL1` term of the recursion.
+/// The other terms of `st[i]` and `st[i + 1]` are computed in a 256-bit word,
+/// and then the `d` terms are chained in 128-bit.
+///
+/// # Safety
+/// AVX2 must be available, and the parameter set must satisfy [is_applicable].
+#[allow(clippy::many_single_char_names)]
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn sfmt_gen_rand_all<P, const MEXP: usize, const MEXP_N: usize>(
+    st: &mut [i32x4; MEXP_N],
+) where
+    P: SfmtParams<MEXP, MEXP_N>,
+{
+    let n = P::SFMT_N;
+    let pos1 = P::SFMT_POS1;
+    debug_assert!(is_applicable::<P, MEXP, MEXP_N>());
+
+    let mask =
+        _mm256_broadcastsi128_si256(new(P::SFMT_MSK1, P::SFMT_MSK2, P::SFMT_MSK3, P::SFMT_MSK4));
+    // `p` is accessed only in `0..N`, since `i + 1 < N` for a pair,
+    // and `j + 1 < N` as a pair does not straddle `N - POS1`.
+    let p = st.as_mut_ptr();
+    let mut r1 = *p.add(n - 2);
+    let mut r2 = *p.add(n - 1);
+    let mut i = 0;
+    while i < n {
+        // `b` is taken from the already updated words after `N - POS1`
+        let j = if i < n - pos1 { i + pos1 } else { i + pos1 - n };
+        if i + 1 < n && i + 1 != n - pos1 {
+            let a = _mm256_loadu_si256(p.add(i) as *const __m256i);
+            let b = _mm256_loadu_si256(p.add(j) as *const __m256i);
+            let c = _mm256_inserti128_si256(_mm256_castsi128_si256(r1), r2, 1);
+            let y = _mm256_srli_epi32(b, P::SFMT_SR1);
+            let z = _mm256_srli_si256(c, P::SFMT_SR2);
+            let z = _mm256_xor_si256(z, a);
+            let x = _mm256_slli_si256(a, P::SFMT_SL2);
+            let y = _mm256_and_si256(y, mask);
+            let z = _mm256_xor_si256(z, x);
+            let z = _mm256_xor_si256(z, y);
+            let lo = _mm_xor_si128(_mm256_castsi256_si128(z), _mm_slli_epi32(r2, P::SFMT_SL1));
+            let hi = _mm_xor_si128(
+                _mm256_extracti128_si256(z, 1),
+                _mm_slli_epi32(lo, P::SFMT_SL1),
+            );
+            let z = _mm256_inserti128_si256(_mm256_castsi128_si256(lo), hi, 1);
+            _mm256_storeu_si256(p.add(i) as *mut __m256i, z);
+            r1 = lo;
+            r2 = hi;
+            i += 2;
+        } else {
+            // The last word, or the pair straddling `N - POS1`
+            let z = mm_recursion::<P, MEXP, MEXP_N>(*p.add(i), *p.add(j), r1, r2);
+            *p.add(i) = z;
+            r1 = r2;
+            r2 = z;
+            i += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{paramed::SFMT, sfmt::SFMTMEXP};
+    use rand_core::SeedableRng;
+
+    macro_rules! compare_to_sse2 {
+        ($name:ident, $mexp:expr) => {
+            #[test]
+            fn $name() {
+                type P = SFMTMEXP<$mexp, { $mexp / 128 + 1 }>;
+                if !is_x86_feature_detected!("avx2") {
+                    return;
+                }
+                assert!(is_applicable::<P, $mexp, { $mexp / 128 + 1 }>());
+                let mut sse2 = SFMT::<$mexp, { $mexp / 128 + 1 }>::seed_from_u64(0);
+                let mut avx2 = sse2.clone();
+                for _ in 0..10 {
+                    P::sfmt_gen_rand_all_128(&mut sse2.state);
+                    unsafe {
+                        sfmt_gen_rand_all::<P, $mexp, { $mexp / 128 + 1 }>(&mut avx2.state);
+                    }
+                    for (s, a) in sse2.state.iter().zip(avx2.state.iter()) {
+                        for i in 0..4 {
+                            assert_eq!(super::super::extract(*s, i), super::super::extract(*a, i));
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    compare_to_sse2!(compare_to_sse2_607, 607);
+    compare_to_sse2!(compare_to_sse2_1279, 1279);
+    compare_to_sse2!(compare_to_sse2_2281, 2281);
+    compare_to_sse2!(compare_to_sse2_4253, 4253);
+    compare_to_sse2!(compare_to_sse2_11213, 11213);
+    compare_to_sse2!(compare_to_sse2_19937, 19937);
+    compare_to_sse2!(compare_to_sse2_44497, 44497);
+    compare_to_sse2!(compare_to_sse2_86243, 86243);
+    compare_to_sse2!(compare_to_sse2_132049, 132049);
+    compare_to_sse2!(compare_to_sse2_216091, 216091);
+}
@@ -0,0 +1,373 @@
+//! Polynomials over GF(2) for the jump-ahead and the analysis of the generators
+//!
+//! ```
+//! use sfmt::poly::Poly;
+//! // x^4 + x + 1 is primitive, and x has the order 15
+//! let p = Poly::from_words(vec![0b10011]);
+//! assert_eq!(p.pow_x_mod(15), Poly::one());
+//! ```
+
+use alloc::{vec, vec::Vec};
+
+/// Polynomial over GF(2)
+///
+/// The coefficient of `x^i` is the bit `i % 64` of `words[i / 64]`,
+/// and `words` does not have trailing zero words.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Poly {
+    words: Vec<u64>,
+}
+
+impl Poly {
+    /// Create from the coefficients packed in words, see [Poly::words]
+    pub fn from_words(words: Vec<u64>) -> Self {
+        let mut p = Poly { words };
+        p.normalize();
+        p
+    }
+
+    /// Coefficients packed in words, where the coefficient of `x^i` is the bit `i % 64` of the word `i / 64`
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// The polynomial `1`
+    pub fn one() -> Self {
+        Poly { words: vec![1] }
+    }
+
+    /// Degree of the polynomial, or `None` for zero
+    pub fn degree(&self) -> Option<usize> {
+        let last = *self.words.last()?;
+        Some(64 * self.words.len() - 1 - last.leading_zeros() as usize)
+    }
+
+    /// Coefficient of `x^i`
+    pub fn coeff(&self, i: usize) -> bool {
+        self.words
+            .get(i / 64)
+            .map_or(false, |w| (w >> (i % 64)) & 1 == 1)
+    }
+
+    fn normalize(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+
+    /// `self^2`, which is spreading bits since `(a + b)^2 = a^2 + b^2` over GF(2)
+    fn square(&self) -> Vec<u64> {
+        let mut sq = Vec::with_capacity(2 * self.words.len());
+        for w in &self.words {
+            sq.push(spread(*w as u32));
+            sq.push(spread((*w >> 32) as u32));
+        }
+        sq
+    }
+
+    /// Apply to a sequence, i.e. `t_n = Σ p_i s_{n + i}` for `n < len`
+    ///
+    /// The sequence `s` is given as bits like [berlekamp_massey],
+    /// and it must have `len + deg` terms or more.
+    pub fn apply(&self, seq: &[u64], len: usize) -> Vec<u64> {
+        let mut t = vec![0; len / 64 + 1];
+        for n in 0..len {
+            let (nw, nb) = (n / 64, n % 64);
+            let mut d = 0;
+            for (k, pk) in self.words.iter().enumerate() {
+                let s = if nb == 0 {
+                    seq[nw + k]
+                } else {
+                    (seq[nw + k] >> nb) | (seq.get(nw + k + 1).unwrap_or(&0) << (64 - nb))
+                };
+                d ^= pk & s;
+            }
+            t[nw] |= ((d.count_ones() % 2) as u64) << nb;
+        }
+        t
+    }
+
+    /// `x^e mod self`
+    ///
+    /// Panics if `self` is zero.
+    pub fn pow_x_mod(&self, e: u128) -> Poly {
+        let m = Modulus::new(self);
+        let mut r = Poly::one();
+        m.reduce(&mut r.words);
+        for i in (0..(128 - e.leading_zeros())).rev() {
+            r.words = r.square();
+            m.reduce(&mut r.words);
+            if (e >> i) & 1 == 1 {
+                r.words.push(0);
+                shl1(&mut r.words);
+                m.reduce(&mut r.words);
+            }
+        }
+        r.normalize();
+        r
+    }
+
+    /// `self^e mod modulus`
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn pow_mod(&self, e: u64, modulus: &Poly) -> Poly {
+        let m = Modulus::new(modulus);
+        let mut base = self.clone();
+        m.reduce(&mut base.words);
+        let mut r = Poly::one();
+        m.reduce(&mut r.words);
+        for i in (0..(64 - e.leading_zeros())).rev() {
+            r.words = r.square();
+            m.reduce(&mut r.words);
+            if (e >> i) & 1 == 1 {
+                r = &r * &base;
+                m.reduce(&mut r.words);
+            }
+        }
+        r.normalize();
+        r
+    }
+
+    /// `x^(2^k) mod self`
+    ///
+    /// Panics if `self` is zero.
+    pub fn pow_x_2k_mod(&self, k: usize) -> Poly {
+        let m = Modulus::new(self);
+        let mut r = Poly::from_words(vec![0b10]);
+        m.reduce(&mut r.words);
+        for _ in 0..k {
+            r.words = r.square();
+            m.reduce(&mut r.words);
+        }
+        r.normalize();
+        r
+    }
+}
+
+impl core::ops::Mul for &Poly {
+    type Output = Poly;
+
+    fn mul(self, other: &Poly) -> Poly {
+        let mut words = vec![0; self.words.len() + other.words.len()];
+        for i in 0..(64 * other.words.len()) {
+            if other.coeff(i) {
+                xor_shifted(&mut words, &self.words, i);
+            }
+        }
+        Poly::from_words(words)
+    }
+}
+
+/// Insert a zero bit after each bit, i.e. the bit `i` moves to `2i`
+fn spread(x: u32) -> u64 {
+    let mut x = x as u64;
+    x = (x | (x << 16)) & 0x0000_ffff_0000_ffff;
+    x = (x | (x << 8)) & 0x00ff_00ff_00ff_00ff;
+    x = (x | (x << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+    x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+    x = (x | (x << 1)) & 0x5555_5555_5555_5555;
+    x
+}
+
+/// Multiply by `x` in place. The last word must have room for the carry.
+fn shl1(words: &mut [u64]) {
+    let mut carry = 0;
+    for w in words.iter_mut() {
+        let next = *w >> 63;
+        *w = (*w << 1) | carry;
+        carry = next;
+    }
+}
+
+/// XOR `src << shift` into `dest`
+fn xor_shifted(dest: &mut [u64], src: &[u64], shift: usize) {
+    let (w, b) = (shift / 64, shift % 64);
+    if b == 0 {
+        for (d, s) in dest[w..].iter_mut().zip(src) {
+            *d ^= s;
+        }
+    } else {
+        let mut carry = 0;
+        for (d, s) in dest[w..].iter_mut().zip(src) {
+            *d ^= (s << b) | carry;
+            carry = s >> (64 - b);
+        }
+        if let Some(d) = dest.get_mut(w + src.len()) {
+            *d ^= carry;
+        }
+    }
+}
+
+/// Modulus polynomial with its copies shifted by `0..64` bits for fast reduction
+struct Modulus {
+    degree: usize,
+    shifted: Vec<Vec<u64>>,
+}
+
+impl Modulus {
+    fn new(p: &Poly) -> Self {
+        let degree = p.degree().expect("Modulus must not be zero");
+        let shifted = (0..64)
+            .map(|b| {
+                let mut s = vec![0; (degree + b) / 64 + 1];
+                xor_shifted(&mut s, &p.words, b);
+                s
+            })
+            .collect();
+        Modulus { degree, shifted }
+    }
+
+    /// Reduce the polynomial given as words modulo `self`
+    fn reduce(&self, words: &mut Vec<u64>) {
+        let d = self.degree;
+        let mut top = 64 * words.len(); // bits at `top` and above are zero
+        while top > d {
+            let word = words[(top - 1) / 64] & (!0 >> (63 - (top - 1) % 64));
+            if word == 0 {
+                top = (top - 1) / 64 * 64;
+                continue;
+            }
+            let i = (top - 1) / 64 * 64 + 63 - word.leading_zeros() as usize;
+            if i < d {
+                break;
+            }
+            let shift = i - d;
+            let shifted = &self.shifted[shift % 64];
+            for (dest, m) in words[(shift / 64)..].iter_mut().zip(shifted) {
+                *dest ^= m;
+            }
+            top = i;
+        }
+        words.truncate(d / 64 + 1);
+        while words.last() == Some(&0) {
+            words.pop();
+        }
+    }
+}
+
+/// Minimal polynomial of a sequence over GF(2) by Berlekamp-Massey algorithm
+///
+/// The term `n` of the sequence is the bit `n % 64` of `seq[n / 64]` for `n < len`.
+/// The result `P(x) = Σ p_i x^i` of degree `L` satisfies `Σ p_i s_{n + i} = 0` for `n + L < len`,
+/// and it is the minimal polynomial of the sequence if `len` is twice of its linear complexity or more.
+#[allow(clippy::many_single_char_names)]
+pub fn berlekamp_massey(seq: &[u64], len: usize) -> Poly {
+    let words = len / 64 + 2;
+    // Reversed sequence `r_j = s_{len - 1 - j}`, to compute the discrepancy word by word
+    let mut rev = vec![0_u64; words + 1];
+    for n in 0..len {
+        if (seq[n / 64] >> (n % 64)) & 1 == 1 {
+            let j = len - 1 - n;
+            rev[j / 64] |= 1 << (j % 64);
+        }
+    }
+
+    // Connection polynomial `C(x) = 1 + c_1 x + ... + c_L x^L`
+    let mut c = vec![0_u64; words];
+    let mut b = vec![0_u64; words];
+    c[0] = 1;
+    b[0] = 1;
+    let mut l = 0;
+    let mut m = 1;
+    for n in 0..len {
+        // discrepancy `d = Σ_{i=0}^{L} c_i s_{n - i} = Σ c_i r_{len - 1 - n + i}`
+        let o = len - 1 - n;
+        let (ow, ob) = (o / 64, o % 64);
+        // `B(x)` has the degree `L` or less
+        let mut d = 0;
+        for (k, ck) in c[..=(l / 64)].iter().enumerate() {
+            let r = if ob == 0 {
+                rev[ow + k]
+            } else {
+                (rev[ow + k] >> ob) | (rev[ow + k + 1] << (64 - ob))
+            };
+            d ^= ck & r;
+        }
+        if d.count_ones() % 2 == 0 {
+            m += 1;
+        } else if 2 * l <= n {
+            let t = c.clone();
+            xor_shifted(&mut c, &b[..=(l / 64)], m);
+            l = n + 1 - l;
+            b = t;
+            m = 1;
+        } else {
+            xor_shifted(&mut c, &b[..=(l / 64)], m);
+            m += 1;
+        }
+    }
+
+    // The minimal polynomial is the reciprocal `x^L C(1/x)`
+    let mut p = vec![0_u64; l / 64 + 1];
+    for i in 0..=l {
+        if (c[i / 64] >> (i % 64)) & 1 == 1 {
+            let j = l - i;
+            p[j / 64] |= 1 << (j % 64);
+        }
+    }
+    Poly::from_words(p)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pow_x_mod() {
+        // x^4 + x + 1 is primitive, and x has the order 15
+        let p = Poly::from_words(vec![0b10011]);
+        assert_eq!(p.pow_x_mod(0), Poly::one());
+        assert_eq!(p.pow_x_mod(4), Poly::from_words(vec![0b0011]));
+        assert_eq!(p.pow_x_mod(15), Poly::one());
+        assert_eq!(p.pow_x_mod(15 * 12345 + 4), Poly::from_words(vec![0b0011]));
+    }
+
+    #[test]
+    fn pow_x_mod_large() {
+        // Compare to the naive multiplication by x
+        let p = Poly::from_words(vec![0x1234_5678_9abc_def1, 0x0fed_cba9_8765_4321, 0x5]);
+        let m = Modulus::new(&p);
+        let mut r = vec![1_u64];
+        for e in 0..1000_u128 {
+            assert_eq!(p.pow_x_mod(e), Poly::from_words(r.clone()));
+            r.push(0);
+            shl1(&mut r);
+            m.reduce(&mut r);
+        }
+    }
+
+    #[test]
+    fn pow_x_2k_mod() {
+        let p = Poly::from_words(vec![0x1234_5678_9abc_def1, 0x0fed_cba9_8765_4321, 0x5]);
+        for k in 0..128 {
+            assert_eq!(p.pow_x_2k_mod(k), p.pow_x_mod(1 << k));
+        }
+    }
+
+    #[test]
+    fn pow_mod() {
+        let p = Poly::from_words(vec![0x1234_5678_9abc_def1, 0x0fed_cba9_8765_4321, 0x5]);
+        let x3 = Poly::from_words(vec![0b1000]);
+        for e in 0..100 {
+            assert_eq!(x3.pow_mod(e, &p), p.pow_x_mod(3 * e as u128));
+        }
+    }
+
+    #[test]
+    fn berlekamp_massey_lfsr() {
+        // s_{n + 5} = s_{n + 2} + s_n, i.e. P(x) = x^5 + x^2 + 1
+        let len = 200;
+        let mut s = vec![1_u8, 0, 0, 1, 1];
+        for n in 0..(len - 5) {
+            s.push(s[n + 2] ^ s[n]);
+        }
+        let mut seq = vec![0_u64; 4];
+        for (n, s) in s.iter().enumerate() {
+            seq[n / 64] |= (*s as u64) << (n % 64);
+        }
+        assert_eq!(
+            berlekamp_massey(&seq, len),
+            Poly::from_words(vec![0b100101])
+        );
+    }
+}
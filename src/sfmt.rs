@@ -1,7 +1,7 @@
 //! Rust re-implementation of SFMT
 
 use super::*;
-use crate::packed::*;
+use crate::packed::{self, *};
 
 /// Parameters used in sfmt.
 pub trait SfmtParams<const MEXP: usize, const MEXP_N: usize>: Sized {
@@ -22,34 +22,44 @@ pub trait SfmtParams<const MEXP: usize, const MEXP_N: usize>: Sized {
     const SFMT_PARITY2: u32;
     const SFMT_PARITY3: u32;
     const SFMT_PARITY4: u32;
+    /// Minimal polynomial of the recursion generating a 128-bit word as the words of `Poly`, see `crate::jump`
+    const MINIMAL_POLYNOMIAL: &'static [u64];
+    /// Polynomials to jump `2^64`, `2^128`, and `2^256` regenerations as the words of `Poly`
+    const JUMP_2_64: &'static [u64];
+    const JUMP_2_128: &'static [u64];
+    const JUMP_2_256: &'static [u64];
 
     fn mm_recursion(a: i32x4, b: i32x4, c: i32x4, d: i32x4) -> i32x4 {
-        #[cfg(target_arch = "x86")]
-        use std::arch::x86::*;
-        #[cfg(target_arch = "x86_64")]
-        use std::arch::x86_64::*;
+        packed::mm_recursion::<Self, MEXP, MEXP_N>(a, b, c, d)
+    }
 
-        unsafe {
-            let mask = new(
-                Self::SFMT_MSK1,
-                Self::SFMT_MSK2,
-                Self::SFMT_MSK3,
-                Self::SFMT_MSK4,
-            );
-            let y = _mm_srli_epi32(b, Self::SFMT_SR1);
-            let z = _mm_srli_si128(c, Self::SFMT_SR2);
-            let v = _mm_slli_epi32(d, Self::SFMT_SL1);
-            let z = _mm_xor_si128(z, a);
-            let z = _mm_xor_si128(z, v);
-            let x = _mm_slli_si128(a, Self::SFMT_SL2);
-            let y = _mm_and_si128(y, mask);
-            let z = _mm_xor_si128(z, x);
-            _mm_xor_si128(z, y)
+    fn sfmt_gen_rand_all(sfmt: &mut paramed::SFMT<MEXP, MEXP_N>) {
+        (sfmt.gen_rand_all)(&mut sfmt.state);
+    }
+
+    /// Select the fastest kernel of [Self::sfmt_gen_rand_all] available on the running CPU
+    fn gen_rand_all_kernel() -> fn(&mut [i32x4; MEXP_N]) {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2",
+            not(miri)
+        ))]
+        {
+            #[cfg(feature = "std")]
+            let avx2 = is_x86_feature_detected!("avx2");
+            // CPU features cannot be detected at runtime without std
+            #[cfg(not(feature = "std"))]
+            let avx2 = cfg!(target_feature = "avx2");
+            if avx2 && packed::avx2::is_applicable::<Self, MEXP, MEXP_N>() {
+                // Safe since AVX2 is detected
+                return |st| unsafe { packed::avx2::sfmt_gen_rand_all::<Self, MEXP, MEXP_N>(st) };
+            }
         }
+        Self::sfmt_gen_rand_all_128
     }
 
-    fn sfmt_gen_rand_all(sfmt: &mut paramed::SFMT<MEXP, MEXP_N>) {
-        let st = &mut sfmt.state;
+    /// Regenerate the state one 128-bit word at a time
+    fn sfmt_gen_rand_all_128(st: &mut [i32x4; MEXP_N]) {
         let mut r1 = st[Self::SFMT_N - 2];
         let mut r2 = st[Self::SFMT_N - 1];
         for i in 0..(Self::SFMT_N - Self::SFMT_POS1) {
@@ -64,6 +74,71 @@ pub trait SfmtParams<const MEXP: usize, const MEXP_N: usize>: Sized {
         }
     }
 
+    /// Generate the 128-bit words following the state into `array` as `gen_rand_array` of the C implementation
+    ///
+    /// The length of `array` must be a multiple of 4 not smaller than `N32`.
+    /// The state is replaced by the last `N` words of `array`.
+    fn sfmt_gen_rand_array(st: &mut [i32x4; MEXP_N], array: &mut [u32]) {
+        let n = Self::SFMT_N;
+        let pos1 = Self::SFMT_POS1;
+        let size = array.len() / 4;
+        let mut r1 = st[n - 2];
+        let mut r2 = st[n - 1];
+        for i in 0..(n - pos1) {
+            let r = Self::mm_recursion(st[i], st[i + pos1], r1, r2);
+            store(&mut array[4 * i..], r);
+            r1 = r2;
+            r2 = r;
+        }
+        for i in (n - pos1)..n {
+            let r = Self::mm_recursion(st[i], load(&array[4 * (i + pos1 - n)..]), r1, r2);
+            store(&mut array[4 * i..], r);
+            r1 = r2;
+            r2 = r;
+        }
+        for i in n..size {
+            let a = load(&array[4 * (i - n)..]);
+            let r = Self::mm_recursion(a, load(&array[4 * (i + pos1 - n)..]), r1, r2);
+            store(&mut array[4 * i..], r);
+            r1 = r2;
+            r2 = r;
+        }
+        for (j, s) in st.iter_mut().enumerate() {
+            *s = load(&array[4 * (size - n + j)..]);
+        }
+    }
+
+    /// Inverse of [Self::sfmt_gen_rand_all_128], which restores the previous state
+    ///
+    /// The recursion `w[i + N] = A(w[i]) ^ B(w[i + POS1]) ^ C(w[i + N - 2]) ^ D(w[i + N - 1])`
+    /// is solved for `w[i]` from the last word, since `A(x) = x ^ (x << 8 * SL2)` is invertible.
+    fn sfmt_gen_rand_all_inverse(st: &mut [i32x4; MEXP_N]) {
+        let n = Self::SFMT_N;
+        let next = *st;
+        for i in (0..n).rev() {
+            // The words after `w[i]` in the previous state are already restored
+            let b = if i + Self::SFMT_POS1 < n {
+                st[i + Self::SFMT_POS1]
+            } else {
+                next[i + Self::SFMT_POS1 - n]
+            };
+            let c = if i >= 2 { next[i - 2] } else { st[i + n - 2] };
+            let d = if i >= 1 { next[i - 1] } else { st[n - 1] };
+            let a = xor(next[i], Self::mm_recursion(zero(), b, c, d));
+            // A^{-1}(y) = y ^ (y << s) ^ (y << 2s) ^ ... where `y << s = A(y) ^ y`
+            let mut w = a;
+            let mut shifted = a;
+            loop {
+                shifted = xor(Self::mm_recursion(shifted, zero(), zero(), zero()), shifted);
+                if (0..4).all(|k| extract(shifted, k) == 0) {
+                    break;
+                }
+                w = xor(w, shifted);
+            }
+            st[i] = w;
+        }
+    }
+
     fn period_certification(sfmt: &mut paramed::SFMT<MEXP, MEXP_N>) {
         let mut inner = 0_u32;
         let st = &mut sfmt.state[0];
@@ -73,8 +148,8 @@ pub trait SfmtParams<const MEXP: usize, const MEXP_N: usize>: Sized {
             Self::SFMT_PARITY3,
             Self::SFMT_PARITY4,
         ];
-        for i in 0..4 {
-            inner ^= extract(*st, i) & parity[i];
+        for (i, parity) in parity.iter().enumerate() {
+            inner ^= extract(*st, i) & parity;
         }
         for i in [16, 8, 4, 2, 1].iter() {
             inner ^= inner >> i;
@@ -83,21 +158,21 @@ pub trait SfmtParams<const MEXP: usize, const MEXP_N: usize>: Sized {
         if inner == 1 {
             return;
         }
-        for i in 0..4 {
+        for (i, parity) in parity.iter().enumerate() {
             let mut work = 1_u32;
             for _ in 0..32 {
-                if (work & parity[i]) != 0 {
+                if (work & parity) != 0 {
                     let val = extract(*st, i) ^ work;
                     insert(st, val as i32, i);
                     return;
                 }
-                work = work << 1;
+                work <<= 1;
             }
         }
     }
 
     fn iterate(pre: i32, i: i32) -> i32 {
-        use std::num::Wrapping;
+        use core::num::Wrapping;
         let pre = Wrapping(pre as u32);
         let i = Wrapping(i as u32);
         (Wrapping(1812433253) * (pre ^ (pre >> 30)) + i).0 as i32
@@ -121,10 +196,79 @@ pub trait SfmtParams<const MEXP: usize, const MEXP_N: usize>: Sized {
         sfmt.idx = Self::SFMT_N32;
         Self::period_certification(sfmt);
     }
+
+    fn func1(x: u32) -> u32 {
+        (x ^ (x >> 27)).wrapping_mul(1664525)
+    }
+
+    fn func2(x: u32) -> u32 {
+        (x ^ (x >> 27)).wrapping_mul(1566083941)
+    }
+
+    fn sfmt_init_by_array(sfmt: &mut paramed::SFMT<MEXP, MEXP_N>, key: &[u32]) {
+        let size = Self::SFMT_N32;
+        let lag = if size >= 623 {
+            11
+        } else if size >= 68 {
+            7
+        } else if size >= 39 {
+            5
+        } else {
+            3
+        };
+        let mid = (size - lag) / 2;
+        let count = if key.len() + 1 > size {
+            key.len() + 1
+        } else {
+            size
+        };
+
+        let st = as_u32_slice_mut(&mut sfmt.state);
+        for v in st.iter_mut() {
+            *v = 0x8b8b_8b8b;
+        }
+        let mut r = Self::func1(st[0] ^ st[mid] ^ st[size - 1]);
+        st[mid] = st[mid].wrapping_add(r);
+        r = r.wrapping_add(key.len() as u32);
+        st[mid + lag] = st[mid + lag].wrapping_add(r);
+        st[0] = r;
+
+        let mut i = 1;
+        for j in 0..(count - 1) {
+            r = Self::func1(st[i] ^ st[(i + mid) % size] ^ st[(i + size - 1) % size]);
+            st[(i + mid) % size] = st[(i + mid) % size].wrapping_add(r);
+            // The key is padded by zeros if it is shorter than the state
+            let k = key.get(j).cloned().unwrap_or(0);
+            r = r.wrapping_add(k).wrapping_add(i as u32);
+            st[(i + mid + lag) % size] = st[(i + mid + lag) % size].wrapping_add(r);
+            st[i] = r;
+            i = (i + 1) % size;
+        }
+        for _ in 0..size {
+            r = Self::func2(
+                st[i]
+                    .wrapping_add(st[(i + mid) % size])
+                    .wrapping_add(st[(i + size - 1) % size]),
+            );
+            st[(i + mid) % size] ^= r;
+            r = r.wrapping_sub(i as u32);
+            st[(i + mid + lag) % size] ^= r;
+            st[i] = r;
+            i = (i + 1) % size;
+        }
+
+        sfmt.idx = Self::SFMT_N32;
+        Self::period_certification(sfmt);
+    }
 }
 /// Wrapper for `MEXP` parameter.
 pub struct SFMTMEXP<const MEXP: usize, const MEXP_N: usize>;
 
+fn xor(a: i32x4, b: i32x4) -> i32x4 {
+    let lane = |k| (extract(a, k) ^ extract(b, k)) as i32;
+    new(lane(0), lane(1), lane(2), lane(3))
+}
+
 macro_rules! parms_impl {
     ($mexp : expr, $n : expr, $pos1 : expr, $sl1 : expr, $sl2 : expr, $sr1 : expr, $sr2 : expr,
         $msk1 : expr, $msk2 : expr, $msk3 : expr, $msk4 : expr,
@@ -143,6 +287,14 @@ macro_rules! parms_impl {
             const SFMT_PARITY2: u32 = $parity2;
             const SFMT_PARITY3: u32 = $parity3;
             const SFMT_PARITY4: u32 = $parity4;
+            const MINIMAL_POLYNOMIAL: &'static [u64] =
+                &include!(concat!("jump/minpoly.", stringify!($mexp), ".rs"));
+            const JUMP_2_64: &'static [u64] =
+                &include!(concat!("jump/jump_2_64.", stringify!($mexp), ".rs"));
+            const JUMP_2_128: &'static [u64] =
+                &include!(concat!("jump/jump_2_128.", stringify!($mexp), ".rs"));
+            const JUMP_2_256: &'static [u64] =
+                &include!(concat!("jump/jump_2_256.", stringify!($mexp), ".rs"));
         }
     };
 }
@@ -229,8 +381,8 @@ parms_impl!(
     0x7fff_dbfd_u32,
     0x0000_0001_u32,
     0x0000_0000_u32,
-    0xb740_b3f8_u32,
-    0x6c11_486d_u32
+    0xe814_8000_u32,
+    0xd0c7_afa3_u32
 );
 parms_impl!(
     19937,
@@ -319,6 +471,7 @@ parms_impl!(
 );
 
 #[cfg(test)]
+#[allow(clippy::many_single_char_names)]
 mod tests {
     use super::*;
 
@@ -340,4 +493,50 @@ mod tests {
         let zc = new(398459137, 1355284994, -363068669, 32506884); // calculated by C code
         assert_eq!(split(z), split(zc));
     }
+
+    #[test]
+    fn period_certification_11213() {
+        use rand_core::RngCore;
+        // The parity check fails for this seed, and the period certification flips a bit
+        let mut sfmt = paramed::SFMT::<11213, { 11213 / 128 + 1 }>::new(9);
+        assert_eq!(extract(sfmt.state[0], 0), 8); // calculated by C code
+        let r: [u32; 4] = [
+            sfmt.next_u32(),
+            sfmt.next_u32(),
+            sfmt.next_u32(),
+            sfmt.next_u32(),
+        ];
+        assert_eq!(r, [118018215, 2478014985, 267001067, 120495962]); // calculated by C code
+    }
+
+    macro_rules! gen_rand_all_inverse {
+        ($mexp:expr) => {
+            paste::item! {
+                #[test]
+                #[cfg_attr(miri, ignore)] // too slow on Miri
+                fn [<gen_rand_all_inverse_ $mexp>]() {
+                    type P = SFMTMEXP<$mexp, { $mexp / 128 + 1 }>;
+                    let sfmt = paramed::SFMT::<$mexp, { $mexp / 128 + 1 }>::new(1234);
+                    let mut st = sfmt.state;
+                    P::sfmt_gen_rand_all_128(&mut st);
+                    P::sfmt_gen_rand_all_inverse(&mut st);
+                    assert_eq!(as_u32_slice(&st), as_u32_slice(&sfmt.state));
+                    P::sfmt_gen_rand_all_inverse(&mut st);
+                    P::sfmt_gen_rand_all_128(&mut st);
+                    assert_eq!(as_u32_slice(&st), as_u32_slice(&sfmt.state));
+                }
+            }
+        };
+    }
+
+    gen_rand_all_inverse!(607);
+    gen_rand_all_inverse!(1279);
+    gen_rand_all_inverse!(2281);
+    gen_rand_all_inverse!(4253);
+    gen_rand_all_inverse!(11213);
+    gen_rand_all_inverse!(19937);
+    gen_rand_all_inverse!(44497);
+    gen_rand_all_inverse!(86243);
+    gen_rand_all_inverse!(132049);
+    gen_rand_all_inverse!(216091);
 }
@@ -1,28 +1,44 @@
 use paste::paste;
-use rand_core::{RngCore, SeedableRng};
+use rand_core::RngCore;
 use sfmt::*;
-use std::{fs, io, io::BufRead};
+use std::{fs, io, io::BufRead, str::FromStr};
 
 // Read random numbers generated by original SFMT implementation
-fn read_reference(filename: &str) -> Result<Vec<u64>, io::Error> {
+fn read_reference<T: FromStr>(filename: &str) -> Result<Vec<T>, io::Error> {
     let f = io::BufReader::new(fs::File::open(filename)?);
     Ok(f.lines()
         .map(|line| {
             line.unwrap()
-                .parse::<u64>()
-                .expect("Failed to parse as u64")
+                .parse::<T>()
+                .ok()
+                .expect("Failed to parse a reference")
         })
         .collect())
 }
 
 macro_rules! compare_to_original {
+    ($mexp:expr) => {
+        paste! {
+            #[test]
+            fn [< compare_to_original_ $mexp >]() {
+                let mut rng = paramed::SFMT::<$mexp, { $mexp / 128 + 1 }>::new(1234);
+                let answer = read_reference::<u64>(&format!("check/u64_{}.txt", $mexp)).unwrap();
+                for ans in answer {
+                    let r = rng.next_u64();
+                    assert_eq!(r, ans);
+                }
+            }
+        } // paste
+    };
+}
+
+macro_rules! compare_to_original_by_array {
     ($mexp:expr) => {
     paste! {
         #[test]
-        fn [< compare_to_original_ $mexp >]() {
-            // This crate only works on x86/x86_64, which must be Little Endition
-            let mut rng = paramed::SFMT::<$mexp, { $mexp / 128 + 1 }>::from_seed(1234_u32.to_le_bytes());
-            let answer = read_reference(&format!("check/u64_{}.txt", $mexp)).unwrap();
+        fn [< compare_to_original_by_array_ $mexp >]() {
+            let mut rng = paramed::SFMT::<$mexp, { $mexp / 128 + 1 }>::init_by_array(&[0x1234, 0x5678, 0x9abc, 0xdef0]);
+            let answer = read_reference::<u64>(&format!("check/u64_by_array_{}.txt", $mexp)).unwrap();
             for ans in answer {
                 let r = rng.next_u64();
                 assert_eq!(r, ans);
@@ -32,6 +48,69 @@ macro_rules! compare_to_original {
     };
 }
 
+macro_rules! compare_to_original_fill_array {
+    ($mexp:expr) => {
+        paste! {
+            #[test]
+            fn [< compare_to_original_fill_array32_ $mexp >]() {
+                type Sfmt = paramed::SFMT<$mexp, { $mexp / 128 + 1 }>;
+                let n32 = 4 * ($mexp / 128 + 1);
+                let mut rng = Sfmt::new(1234);
+                let answer = read_reference::<u64>(&format!("check/u32_fill_array_{}.txt", $mexp)).unwrap();
+                let mut array = vec![0_u32; 3 * n32 + 4];
+                rng.fill_array32(&mut array);
+                let mut result: Vec<u32> = array;
+                let mut array = vec![0_u32; n32 + 4];
+                rng.fill_array32(&mut array);
+                result.extend(array);
+                result.extend((0..100).map(|_| rng.next_u32()));
+                assert_eq!(result.len(), answer.len());
+                for (r, ans) in result.into_iter().zip(answer) {
+                    assert_eq!(r as u64, ans);
+                }
+            }
+
+            #[test]
+            fn [< compare_to_original_fill_array64_ $mexp >]() {
+                type Sfmt = paramed::SFMT<$mexp, { $mexp / 128 + 1 }>;
+                let n64 = 2 * ($mexp / 128 + 1);
+                let mut rng = Sfmt::init_by_array(&[0x1234, 0x5678, 0x9abc, 0xdef0]);
+                let answer = read_reference::<u64>(&format!("check/u64_fill_array_{}.txt", $mexp)).unwrap();
+                let mut array = vec![0_u64; 3 * n64 + 2];
+                rng.fill_array64(&mut array);
+                let mut result: Vec<u64> = array;
+                let mut array = vec![0_u64; n64 + 2];
+                rng.fill_array64(&mut array);
+                result.extend(array);
+                result.extend((0..100).map(|_| rng.next_u64()));
+                assert_eq!(result, answer);
+            }
+        } // paste
+    };
+}
+
+macro_rules! compare_to_original_real {
+    ($mexp:expr) => {
+        paste! {
+            #[test]
+            fn [< compare_to_original_real_ $mexp >]() {
+                let mut rng = paramed::SFMT::<$mexp, { $mexp / 128 + 1 }>::new(1234);
+                let answer = read_reference::<f64>(&format!("check/real_{}.txt", $mexp)).unwrap();
+                let mut result = Vec::new();
+                result.extend((0..200).map(|_| rng.real1()));
+                result.extend((0..200).map(|_| rng.real2()));
+                result.extend((0..200).map(|_| rng.real3()));
+                result.extend((0..200).map(|_| rng.res53()));
+                result.extend((0..200).map(|_| rng.res53_mix()));
+                assert_eq!(result.len(), answer.len());
+                for (r, ans) in result.into_iter().zip(answer) {
+                    assert_eq!(r.to_bits(), ans.to_bits());
+                }
+            }
+        } // paste
+    };
+}
+
 compare_to_original!(607);
 compare_to_original!(1279);
 compare_to_original!(2281);
@@ -42,3 +121,36 @@ compare_to_original!(44497);
 compare_to_original!(86243);
 compare_to_original!(132049);
 compare_to_original!(216091);
+
+compare_to_original_by_array!(607);
+compare_to_original_by_array!(1279);
+compare_to_original_by_array!(2281);
+compare_to_original_by_array!(4253);
+compare_to_original_by_array!(11213);
+compare_to_original_by_array!(19937);
+compare_to_original_by_array!(44497);
+compare_to_original_by_array!(86243);
+compare_to_original_by_array!(132049);
+compare_to_original_by_array!(216091);
+
+compare_to_original_fill_array!(607);
+compare_to_original_fill_array!(1279);
+compare_to_original_fill_array!(2281);
+compare_to_original_fill_array!(4253);
+compare_to_original_fill_array!(11213);
+compare_to_original_fill_array!(19937);
+compare_to_original_fill_array!(44497);
+compare_to_original_fill_array!(86243);
+compare_to_original_fill_array!(132049);
+compare_to_original_fill_array!(216091);
+
+compare_to_original_real!(607);
+compare_to_original_real!(1279);
+compare_to_original_real!(2281);
+compare_to_original_real!(4253);
+compare_to_original_real!(11213);
+compare_to_original_real!(19937);
+compare_to_original_real!(44497);
+compare_to_original_real!(86243);
+compare_to_original_real!(132049);
+compare_to_original_real!(216091);